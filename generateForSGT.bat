@@ -1,5 +1,9 @@
-target\debug\rustspvgen.exe spirv.core.grammar.json --defs > ..\SpvGenTwo\lib\include\spvgentwo\Spv.h
-target\debug\rustspvgen.exe spirv.core.grammar.json --header > ..\SpvGenTwo\lib\include\spvgentwo\Grammar.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --class-overrides class_overrides.json --control-flow-overrides control_flow.json --defs > ..\SpvGenTwo\lib\include\spvgentwo\Spv.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --header > ..\SpvGenTwo\lib\include\spvgentwo\Grammar.h
 target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --cpp > ..\SpvGenTwo\lib\source\Grammar.cpp
 target\debug\rustspvgen.exe extinst.glsl.std.450.grammar.json --defs > ..\SpvGenTwo\lib\include\spvgentwo\Glsl.h
-target\debug\rustspvgen.exe extinst.opencl.std.100.grammar.json --defs > ..\SpvGenTwo\lib\include\spvgentwo\OpenCl.h
\ No newline at end of file
+target\debug\rustspvgen.exe extinst.opencl.std.100.grammar.json --defs > ..\SpvGenTwo\lib\include\spvgentwo\OpenCl.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --visitor > ..\SpvGenTwo\lib\include\spvgentwo\OperandVisitor.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --builder > ..\SpvGenTwo\lib\include\spvgentwo\InstructionBuilder.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --printer > ..\SpvGenTwo\lib\include\spvgentwo\InstructionPrinter.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --class-overrides class_overrides.json --traits > ..\SpvGenTwo\lib\include\spvgentwo\OpTraits.h
\ No newline at end of file
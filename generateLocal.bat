@@ -1,3 +1,15 @@
-target\debug\rustspvgen.exe spirv.core.grammar.json --defs > generated\Spv.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --class-overrides class_overrides.json --control-flow-overrides control_flow.json --defs > generated\Spv.h
 target\debug\rustspvgen.exe extinst.glsl.std.450.grammar.json --defs > generated\Glsl.h
-target\debug\rustspvgen.exe extinst.opencl.std.100.grammar.json --defs > generated\OpenCl.h
\ No newline at end of file
+target\debug\rustspvgen.exe extinst.opencl.std.100.grammar.json --defs > generated\OpenCl.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --header > generated\Grammar.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --cpp > generated\Grammar.cpp
+target\debug\rustspvgen.exe spirv.core.grammar.json --visitor > generated\OperandVisitor.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --builder > generated\InstructionBuilder.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --printer > generated\InstructionPrinter.h
+target\debug\rustspvgen.exe spirv.core.grammar.json --class-overrides class_overrides.json --traits > generated\OpTraits.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --markdown > generated\SpirvReference.md
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --html > generated\SpirvReference.html
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --emit json > generated\Grammar.json
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --rust > generated\spirv.rs
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --template templates\Intrinsics.h.j2 > generated\Intrinsics.h
+target\debug\rustspvgen.exe spirv.core.grammar.json extinst.glsl.std.450.grammar.json extinst.opencl.std.100.grammar.json --template templates\spirv.dict.j2 > generated\spirv.dict
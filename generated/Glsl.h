@@ -1,4 +1,6 @@
 // Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// extinst.glsl.std.450.grammar.json sha256:3bcf78c13b71a9ebba51e890c55f41a5e0f47ba283bc7c08fd7e1344ea5f47a6 version 100 revision 2
 // Copyright (c) 2014-2016 The Khronos Group Inc.
 // 
 // Permission is hereby granted, free of charge, to any person obtaining a copy
@@ -28,6 +30,7 @@ namespace spvgentwo::glslstd450
 {
 	static constexpr unsigned int Version = 100;
 	static constexpr unsigned int Revision = 2;
+	static constexpr const char* ExtInstImportName = "GLSL.std.450";
 	enum class Op : unsigned
 	{
 		Round = 1,
@@ -113,4 +116,665 @@ namespace spvgentwo::glslstd450
 		NClamp = 81,
 		Max = 0x7fffffff
 	};
+	static constexpr unsigned int UnboundedWordCount = 0xffffffff;
+	inline constexpr unsigned int MinWordCount(Op opcode) {
+		switch (opcode) {
+		default: return 0u; // unknown opcode
+		case Op::Round: return 1u;
+		case Op::RoundEven: return 1u;
+		case Op::Trunc: return 1u;
+		case Op::FAbs: return 1u;
+		case Op::SAbs: return 1u;
+		case Op::FSign: return 1u;
+		case Op::SSign: return 1u;
+		case Op::Floor: return 1u;
+		case Op::Ceil: return 1u;
+		case Op::Fract: return 1u;
+		case Op::Radians: return 1u;
+		case Op::Degrees: return 1u;
+		case Op::Sin: return 1u;
+		case Op::Cos: return 1u;
+		case Op::Tan: return 1u;
+		case Op::Asin: return 1u;
+		case Op::Acos: return 1u;
+		case Op::Atan: return 1u;
+		case Op::Sinh: return 1u;
+		case Op::Cosh: return 1u;
+		case Op::Tanh: return 1u;
+		case Op::Asinh: return 1u;
+		case Op::Acosh: return 1u;
+		case Op::Atanh: return 1u;
+		case Op::Atan2: return 2u;
+		case Op::Pow: return 2u;
+		case Op::Exp: return 1u;
+		case Op::Log: return 1u;
+		case Op::Exp2: return 1u;
+		case Op::Log2: return 1u;
+		case Op::Sqrt: return 1u;
+		case Op::InverseSqrt: return 1u;
+		case Op::Determinant: return 1u;
+		case Op::MatrixInverse: return 1u;
+		case Op::Modf: return 2u;
+		case Op::ModfStruct: return 1u;
+		case Op::FMin: return 2u;
+		case Op::UMin: return 2u;
+		case Op::SMin: return 2u;
+		case Op::FMax: return 2u;
+		case Op::UMax: return 2u;
+		case Op::SMax: return 2u;
+		case Op::FClamp: return 3u;
+		case Op::UClamp: return 3u;
+		case Op::SClamp: return 3u;
+		case Op::FMix: return 3u;
+		case Op::IMix: return 3u;
+		case Op::Step: return 2u;
+		case Op::SmoothStep: return 3u;
+		case Op::Fma: return 3u;
+		case Op::Frexp: return 2u;
+		case Op::FrexpStruct: return 1u;
+		case Op::Ldexp: return 2u;
+		case Op::PackSnorm4x8: return 1u;
+		case Op::PackUnorm4x8: return 1u;
+		case Op::PackSnorm2x16: return 1u;
+		case Op::PackUnorm2x16: return 1u;
+		case Op::PackHalf2x16: return 1u;
+		case Op::PackDouble2x32: return 1u;
+		case Op::UnpackSnorm2x16: return 1u;
+		case Op::UnpackUnorm2x16: return 1u;
+		case Op::UnpackHalf2x16: return 1u;
+		case Op::UnpackSnorm4x8: return 1u;
+		case Op::UnpackUnorm4x8: return 1u;
+		case Op::UnpackDouble2x32: return 1u;
+		case Op::Length: return 1u;
+		case Op::Distance: return 2u;
+		case Op::Cross: return 2u;
+		case Op::Normalize: return 1u;
+		case Op::FaceForward: return 3u;
+		case Op::Reflect: return 2u;
+		case Op::Refract: return 3u;
+		case Op::FindILsb: return 1u;
+		case Op::FindSMsb: return 1u;
+		case Op::FindUMsb: return 1u;
+		case Op::InterpolateAtCentroid: return 1u;
+		case Op::InterpolateAtSample: return 2u;
+		case Op::InterpolateAtOffset: return 2u;
+		case Op::NMin: return 2u;
+		case Op::NMax: return 2u;
+		case Op::NClamp: return 3u;
+		}
+	}
+	inline constexpr unsigned int MaxWordCount(Op opcode) {
+		switch (opcode) {
+		default: return UnboundedWordCount; // unknown opcode
+		case Op::Round: return 1u;
+		case Op::RoundEven: return 1u;
+		case Op::Trunc: return 1u;
+		case Op::FAbs: return 1u;
+		case Op::SAbs: return 1u;
+		case Op::FSign: return 1u;
+		case Op::SSign: return 1u;
+		case Op::Floor: return 1u;
+		case Op::Ceil: return 1u;
+		case Op::Fract: return 1u;
+		case Op::Radians: return 1u;
+		case Op::Degrees: return 1u;
+		case Op::Sin: return 1u;
+		case Op::Cos: return 1u;
+		case Op::Tan: return 1u;
+		case Op::Asin: return 1u;
+		case Op::Acos: return 1u;
+		case Op::Atan: return 1u;
+		case Op::Sinh: return 1u;
+		case Op::Cosh: return 1u;
+		case Op::Tanh: return 1u;
+		case Op::Asinh: return 1u;
+		case Op::Acosh: return 1u;
+		case Op::Atanh: return 1u;
+		case Op::Atan2: return 2u;
+		case Op::Pow: return 2u;
+		case Op::Exp: return 1u;
+		case Op::Log: return 1u;
+		case Op::Exp2: return 1u;
+		case Op::Log2: return 1u;
+		case Op::Sqrt: return 1u;
+		case Op::InverseSqrt: return 1u;
+		case Op::Determinant: return 1u;
+		case Op::MatrixInverse: return 1u;
+		case Op::Modf: return 2u;
+		case Op::ModfStruct: return 1u;
+		case Op::FMin: return 2u;
+		case Op::UMin: return 2u;
+		case Op::SMin: return 2u;
+		case Op::FMax: return 2u;
+		case Op::UMax: return 2u;
+		case Op::SMax: return 2u;
+		case Op::FClamp: return 3u;
+		case Op::UClamp: return 3u;
+		case Op::SClamp: return 3u;
+		case Op::FMix: return 3u;
+		case Op::IMix: return 3u;
+		case Op::Step: return 2u;
+		case Op::SmoothStep: return 3u;
+		case Op::Fma: return 3u;
+		case Op::Frexp: return 2u;
+		case Op::FrexpStruct: return 1u;
+		case Op::Ldexp: return 2u;
+		case Op::PackSnorm4x8: return 1u;
+		case Op::PackUnorm4x8: return 1u;
+		case Op::PackSnorm2x16: return 1u;
+		case Op::PackUnorm2x16: return 1u;
+		case Op::PackHalf2x16: return 1u;
+		case Op::PackDouble2x32: return 1u;
+		case Op::UnpackSnorm2x16: return 1u;
+		case Op::UnpackUnorm2x16: return 1u;
+		case Op::UnpackHalf2x16: return 1u;
+		case Op::UnpackSnorm4x8: return 1u;
+		case Op::UnpackUnorm4x8: return 1u;
+		case Op::UnpackDouble2x32: return 1u;
+		case Op::Length: return 1u;
+		case Op::Distance: return 2u;
+		case Op::Cross: return 2u;
+		case Op::Normalize: return 1u;
+		case Op::FaceForward: return 3u;
+		case Op::Reflect: return 2u;
+		case Op::Refract: return 3u;
+		case Op::FindILsb: return 1u;
+		case Op::FindSMsb: return 1u;
+		case Op::FindUMsb: return 1u;
+		case Op::InterpolateAtCentroid: return 1u;
+		case Op::InterpolateAtSample: return 2u;
+		case Op::InterpolateAtOffset: return 2u;
+		case Op::NMin: return 2u;
+		case Op::NMax: return 2u;
+		case Op::NClamp: return 3u;
+		}
+	}
+	inline constexpr const char* ToString(Op opcode) {
+		switch (opcode) {
+		default: return nullptr; // unknown opcode
+		case Op::Round: return "Round";
+		case Op::RoundEven: return "RoundEven";
+		case Op::Trunc: return "Trunc";
+		case Op::FAbs: return "FAbs";
+		case Op::SAbs: return "SAbs";
+		case Op::FSign: return "FSign";
+		case Op::SSign: return "SSign";
+		case Op::Floor: return "Floor";
+		case Op::Ceil: return "Ceil";
+		case Op::Fract: return "Fract";
+		case Op::Radians: return "Radians";
+		case Op::Degrees: return "Degrees";
+		case Op::Sin: return "Sin";
+		case Op::Cos: return "Cos";
+		case Op::Tan: return "Tan";
+		case Op::Asin: return "Asin";
+		case Op::Acos: return "Acos";
+		case Op::Atan: return "Atan";
+		case Op::Sinh: return "Sinh";
+		case Op::Cosh: return "Cosh";
+		case Op::Tanh: return "Tanh";
+		case Op::Asinh: return "Asinh";
+		case Op::Acosh: return "Acosh";
+		case Op::Atanh: return "Atanh";
+		case Op::Atan2: return "Atan2";
+		case Op::Pow: return "Pow";
+		case Op::Exp: return "Exp";
+		case Op::Log: return "Log";
+		case Op::Exp2: return "Exp2";
+		case Op::Log2: return "Log2";
+		case Op::Sqrt: return "Sqrt";
+		case Op::InverseSqrt: return "InverseSqrt";
+		case Op::Determinant: return "Determinant";
+		case Op::MatrixInverse: return "MatrixInverse";
+		case Op::Modf: return "Modf";
+		case Op::ModfStruct: return "ModfStruct";
+		case Op::FMin: return "FMin";
+		case Op::UMin: return "UMin";
+		case Op::SMin: return "SMin";
+		case Op::FMax: return "FMax";
+		case Op::UMax: return "UMax";
+		case Op::SMax: return "SMax";
+		case Op::FClamp: return "FClamp";
+		case Op::UClamp: return "UClamp";
+		case Op::SClamp: return "SClamp";
+		case Op::FMix: return "FMix";
+		case Op::IMix: return "IMix";
+		case Op::Step: return "Step";
+		case Op::SmoothStep: return "SmoothStep";
+		case Op::Fma: return "Fma";
+		case Op::Frexp: return "Frexp";
+		case Op::FrexpStruct: return "FrexpStruct";
+		case Op::Ldexp: return "Ldexp";
+		case Op::PackSnorm4x8: return "PackSnorm4x8";
+		case Op::PackUnorm4x8: return "PackUnorm4x8";
+		case Op::PackSnorm2x16: return "PackSnorm2x16";
+		case Op::PackUnorm2x16: return "PackUnorm2x16";
+		case Op::PackHalf2x16: return "PackHalf2x16";
+		case Op::PackDouble2x32: return "PackDouble2x32";
+		case Op::UnpackSnorm2x16: return "UnpackSnorm2x16";
+		case Op::UnpackUnorm2x16: return "UnpackUnorm2x16";
+		case Op::UnpackHalf2x16: return "UnpackHalf2x16";
+		case Op::UnpackSnorm4x8: return "UnpackSnorm4x8";
+		case Op::UnpackUnorm4x8: return "UnpackUnorm4x8";
+		case Op::UnpackDouble2x32: return "UnpackDouble2x32";
+		case Op::Length: return "Length";
+		case Op::Distance: return "Distance";
+		case Op::Cross: return "Cross";
+		case Op::Normalize: return "Normalize";
+		case Op::FaceForward: return "FaceForward";
+		case Op::Reflect: return "Reflect";
+		case Op::Refract: return "Refract";
+		case Op::FindILsb: return "FindILsb";
+		case Op::FindSMsb: return "FindSMsb";
+		case Op::FindUMsb: return "FindUMsb";
+		case Op::InterpolateAtCentroid: return "InterpolateAtCentroid";
+		case Op::InterpolateAtSample: return "InterpolateAtSample";
+		case Op::InterpolateAtOffset: return "InterpolateAtOffset";
+		case Op::NMin: return "NMin";
+		case Op::NMax: return "NMax";
+		case Op::NClamp: return "NClamp";
+		}
+	}
+	enum class OperandKind : unsigned short
+	{
+		IdRef,
+	};
+	enum class Quantifier : unsigned short
+	{
+		ZeroOrOne, // zero or one
+		ZeroOrAny, // zero or any
+		One, // exactly once
+	};
+	struct Operand
+	{
+		OperandKind kind;
+		Quantifier quantifier;
+		const char* name;
+	};
+	struct OperandSpan
+	{
+		const Operand* operands;
+		unsigned int count;
+		constexpr const Operand* begin() const { return operands; }
+		constexpr const Operand* end() const { return operands + count; }
+	};
+	static constexpr Operand Operands[] =
+	{
+		// Round
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// RoundEven
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Trunc
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// FAbs
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// SAbs
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// FSign
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// SSign
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Floor
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Ceil
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Fract
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Radians
+		{ OperandKind::IdRef, Quantifier::One, "degrees" },
+		// Degrees
+		{ OperandKind::IdRef, Quantifier::One, "radians" },
+		// Sin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Cos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Tan
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Asin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Acos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Atan
+		{ OperandKind::IdRef, Quantifier::One, "y_over_x" },
+		// Sinh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Cosh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Tanh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Asinh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Acosh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Atanh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Atan2
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Pow
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// Exp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Log
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Exp2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Log2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Sqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// InverseSqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Determinant
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// MatrixInverse
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Modf
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "i" },
+		// ModfStruct
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// FMin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// UMin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// SMin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// FMax
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// UMax
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// SMax
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// FClamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minVal" },
+		{ OperandKind::IdRef, Quantifier::One, "maxVal" },
+		// UClamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minVal" },
+		{ OperandKind::IdRef, Quantifier::One, "maxVal" },
+		// SClamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minVal" },
+		{ OperandKind::IdRef, Quantifier::One, "maxVal" },
+		// FMix
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		// IMix
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		// Step
+		{ OperandKind::IdRef, Quantifier::One, "edge" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// SmoothStep
+		{ OperandKind::IdRef, Quantifier::One, "edge0" },
+		{ OperandKind::IdRef, Quantifier::One, "edge1" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Fma
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// Frexp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "exp" },
+		// FrexpStruct
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Ldexp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "exp" },
+		// PackSnorm4x8
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// PackUnorm4x8
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// PackSnorm2x16
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// PackUnorm2x16
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// PackHalf2x16
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// PackDouble2x32
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// UnpackSnorm2x16
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// UnpackUnorm2x16
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// UnpackHalf2x16
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// UnpackSnorm4x8
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// UnpackUnorm4x8
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// UnpackDouble2x32
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		// Length
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// Distance
+		{ OperandKind::IdRef, Quantifier::One, "p0" },
+		{ OperandKind::IdRef, Quantifier::One, "p1" },
+		// Cross
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// Normalize
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// FaceForward
+		{ OperandKind::IdRef, Quantifier::One, "N" },
+		{ OperandKind::IdRef, Quantifier::One, "I" },
+		{ OperandKind::IdRef, Quantifier::One, "Nref" },
+		// Reflect
+		{ OperandKind::IdRef, Quantifier::One, "I" },
+		{ OperandKind::IdRef, Quantifier::One, "N" },
+		// Refract
+		{ OperandKind::IdRef, Quantifier::One, "I" },
+		{ OperandKind::IdRef, Quantifier::One, "N" },
+		{ OperandKind::IdRef, Quantifier::One, "eta" },
+		// FindILsb
+		{ OperandKind::IdRef, Quantifier::One, "Value" },
+		// FindSMsb
+		{ OperandKind::IdRef, Quantifier::One, "Value" },
+		// FindUMsb
+		{ OperandKind::IdRef, Quantifier::One, "Value" },
+		// InterpolateAtCentroid
+		{ OperandKind::IdRef, Quantifier::One, "interpolant" },
+		// InterpolateAtSample
+		{ OperandKind::IdRef, Quantifier::One, "interpolant" },
+		{ OperandKind::IdRef, Quantifier::One, "sample" },
+		// InterpolateAtOffset
+		{ OperandKind::IdRef, Quantifier::One, "interpolant" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		// NMin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// NMax
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// NClamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minVal" },
+		{ OperandKind::IdRef, Quantifier::One, "maxVal" },
+	};
+	inline constexpr OperandSpan GetOperands(Op opcode) {
+		switch (opcode) {
+		default: return { Operands, 0u }; // unknown opcode
+		case Op::Round: return { Operands + 0, 1u };
+		case Op::RoundEven: return { Operands + 1, 1u };
+		case Op::Trunc: return { Operands + 2, 1u };
+		case Op::FAbs: return { Operands + 3, 1u };
+		case Op::SAbs: return { Operands + 4, 1u };
+		case Op::FSign: return { Operands + 5, 1u };
+		case Op::SSign: return { Operands + 6, 1u };
+		case Op::Floor: return { Operands + 7, 1u };
+		case Op::Ceil: return { Operands + 8, 1u };
+		case Op::Fract: return { Operands + 9, 1u };
+		case Op::Radians: return { Operands + 10, 1u };
+		case Op::Degrees: return { Operands + 11, 1u };
+		case Op::Sin: return { Operands + 12, 1u };
+		case Op::Cos: return { Operands + 13, 1u };
+		case Op::Tan: return { Operands + 14, 1u };
+		case Op::Asin: return { Operands + 15, 1u };
+		case Op::Acos: return { Operands + 16, 1u };
+		case Op::Atan: return { Operands + 17, 1u };
+		case Op::Sinh: return { Operands + 18, 1u };
+		case Op::Cosh: return { Operands + 19, 1u };
+		case Op::Tanh: return { Operands + 20, 1u };
+		case Op::Asinh: return { Operands + 21, 1u };
+		case Op::Acosh: return { Operands + 22, 1u };
+		case Op::Atanh: return { Operands + 23, 1u };
+		case Op::Atan2: return { Operands + 24, 2u };
+		case Op::Pow: return { Operands + 26, 2u };
+		case Op::Exp: return { Operands + 28, 1u };
+		case Op::Log: return { Operands + 29, 1u };
+		case Op::Exp2: return { Operands + 30, 1u };
+		case Op::Log2: return { Operands + 31, 1u };
+		case Op::Sqrt: return { Operands + 32, 1u };
+		case Op::InverseSqrt: return { Operands + 33, 1u };
+		case Op::Determinant: return { Operands + 34, 1u };
+		case Op::MatrixInverse: return { Operands + 35, 1u };
+		case Op::Modf: return { Operands + 36, 2u };
+		case Op::ModfStruct: return { Operands + 38, 1u };
+		case Op::FMin: return { Operands + 39, 2u };
+		case Op::UMin: return { Operands + 41, 2u };
+		case Op::SMin: return { Operands + 43, 2u };
+		case Op::FMax: return { Operands + 45, 2u };
+		case Op::UMax: return { Operands + 47, 2u };
+		case Op::SMax: return { Operands + 49, 2u };
+		case Op::FClamp: return { Operands + 51, 3u };
+		case Op::UClamp: return { Operands + 54, 3u };
+		case Op::SClamp: return { Operands + 57, 3u };
+		case Op::FMix: return { Operands + 60, 3u };
+		case Op::IMix: return { Operands + 63, 3u };
+		case Op::Step: return { Operands + 66, 2u };
+		case Op::SmoothStep: return { Operands + 68, 3u };
+		case Op::Fma: return { Operands + 71, 3u };
+		case Op::Frexp: return { Operands + 74, 2u };
+		case Op::FrexpStruct: return { Operands + 76, 1u };
+		case Op::Ldexp: return { Operands + 77, 2u };
+		case Op::PackSnorm4x8: return { Operands + 79, 1u };
+		case Op::PackUnorm4x8: return { Operands + 80, 1u };
+		case Op::PackSnorm2x16: return { Operands + 81, 1u };
+		case Op::PackUnorm2x16: return { Operands + 82, 1u };
+		case Op::PackHalf2x16: return { Operands + 83, 1u };
+		case Op::PackDouble2x32: return { Operands + 84, 1u };
+		case Op::UnpackSnorm2x16: return { Operands + 85, 1u };
+		case Op::UnpackUnorm2x16: return { Operands + 86, 1u };
+		case Op::UnpackHalf2x16: return { Operands + 87, 1u };
+		case Op::UnpackSnorm4x8: return { Operands + 88, 1u };
+		case Op::UnpackUnorm4x8: return { Operands + 89, 1u };
+		case Op::UnpackDouble2x32: return { Operands + 90, 1u };
+		case Op::Length: return { Operands + 91, 1u };
+		case Op::Distance: return { Operands + 92, 2u };
+		case Op::Cross: return { Operands + 94, 2u };
+		case Op::Normalize: return { Operands + 96, 1u };
+		case Op::FaceForward: return { Operands + 97, 3u };
+		case Op::Reflect: return { Operands + 100, 2u };
+		case Op::Refract: return { Operands + 102, 3u };
+		case Op::FindILsb: return { Operands + 105, 1u };
+		case Op::FindSMsb: return { Operands + 106, 1u };
+		case Op::FindUMsb: return { Operands + 107, 1u };
+		case Op::InterpolateAtCentroid: return { Operands + 108, 1u };
+		case Op::InterpolateAtSample: return { Operands + 109, 2u };
+		case Op::InterpolateAtOffset: return { Operands + 111, 2u };
+		case Op::NMin: return { Operands + 113, 2u };
+		case Op::NMax: return { Operands + 115, 2u };
+		case Op::NClamp: return { Operands + 117, 3u };
+		}
+	}
+	// number of operands listed in the grammar, optional and variadic ones count once
+	inline constexpr unsigned int OperandCount(Op opcode) { return GetOperands(opcode).count; }
+	// true if every call passes exactly OperandCount(opcode) operands, false for optional or variadic operands and unknown opcodes
+	inline constexpr bool HasFixedArity(Op opcode) {
+		switch (opcode) {
+		default: return false; // unknown opcode
+		case Op::Round: return true;
+		case Op::RoundEven: return true;
+		case Op::Trunc: return true;
+		case Op::FAbs: return true;
+		case Op::SAbs: return true;
+		case Op::FSign: return true;
+		case Op::SSign: return true;
+		case Op::Floor: return true;
+		case Op::Ceil: return true;
+		case Op::Fract: return true;
+		case Op::Radians: return true;
+		case Op::Degrees: return true;
+		case Op::Sin: return true;
+		case Op::Cos: return true;
+		case Op::Tan: return true;
+		case Op::Asin: return true;
+		case Op::Acos: return true;
+		case Op::Atan: return true;
+		case Op::Sinh: return true;
+		case Op::Cosh: return true;
+		case Op::Tanh: return true;
+		case Op::Asinh: return true;
+		case Op::Acosh: return true;
+		case Op::Atanh: return true;
+		case Op::Atan2: return true;
+		case Op::Pow: return true;
+		case Op::Exp: return true;
+		case Op::Log: return true;
+		case Op::Exp2: return true;
+		case Op::Log2: return true;
+		case Op::Sqrt: return true;
+		case Op::InverseSqrt: return true;
+		case Op::Determinant: return true;
+		case Op::MatrixInverse: return true;
+		case Op::Modf: return true;
+		case Op::ModfStruct: return true;
+		case Op::FMin: return true;
+		case Op::UMin: return true;
+		case Op::SMin: return true;
+		case Op::FMax: return true;
+		case Op::UMax: return true;
+		case Op::SMax: return true;
+		case Op::FClamp: return true;
+		case Op::UClamp: return true;
+		case Op::SClamp: return true;
+		case Op::FMix: return true;
+		case Op::IMix: return true;
+		case Op::Step: return true;
+		case Op::SmoothStep: return true;
+		case Op::Fma: return true;
+		case Op::Frexp: return true;
+		case Op::FrexpStruct: return true;
+		case Op::Ldexp: return true;
+		case Op::PackSnorm4x8: return true;
+		case Op::PackUnorm4x8: return true;
+		case Op::PackSnorm2x16: return true;
+		case Op::PackUnorm2x16: return true;
+		case Op::PackHalf2x16: return true;
+		case Op::PackDouble2x32: return true;
+		case Op::UnpackSnorm2x16: return true;
+		case Op::UnpackUnorm2x16: return true;
+		case Op::UnpackHalf2x16: return true;
+		case Op::UnpackSnorm4x8: return true;
+		case Op::UnpackUnorm4x8: return true;
+		case Op::UnpackDouble2x32: return true;
+		case Op::Length: return true;
+		case Op::Distance: return true;
+		case Op::Cross: return true;
+		case Op::Normalize: return true;
+		case Op::FaceForward: return true;
+		case Op::Reflect: return true;
+		case Op::Refract: return true;
+		case Op::FindILsb: return true;
+		case Op::FindSMsb: return true;
+		case Op::FindUMsb: return true;
+		case Op::InterpolateAtCentroid: return true;
+		case Op::InterpolateAtSample: return true;
+		case Op::InterpolateAtOffset: return true;
+		case Op::NMin: return true;
+		case Op::NMax: return true;
+		case Op::NClamp: return true;
+		}
+	}
 } // spvgentwo::glslstd450
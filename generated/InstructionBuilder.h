@@ -0,0 +1,7378 @@
+// Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+#pragma once
+
+#include "Spv.h"
+
+namespace spvgentwo::spv
+{
+	template <class T>
+	struct Span
+	{
+		const T* data = nullptr;
+		unsigned int size = 0u;
+	};
+	template <class T>
+	struct Optional
+	{
+		Optional() = default;
+		Optional(T _value) : value(_value), valid(true) {}
+		T value{};
+		bool valid = false;
+	};
+	struct PairLiteralIntegerIdRef
+	{
+		unsigned int first;
+		Id second;
+	};
+	struct PairIdRefLiteralInteger
+	{
+		Id first;
+		unsigned int second;
+	};
+	struct PairIdRefIdRef
+	{
+		Id first;
+		Id second;
+	};
+	namespace detail
+	{
+		template <class T>
+		constexpr unsigned int wordCount(const T&) { return 1u; }
+		inline unsigned int wordCount(const char* _str) { unsigned int len = 0u; while (_str[len] != '\0') { ++len; } return len / 4u + 1u; }
+		template <class Sink, class T>
+		void write(Sink& _sink, const T& _value) { _sink.emplace_back(static_cast<unsigned int>(_value)); }
+		template <class Sink>
+		void write(Sink& _sink, const char* _str)
+		{
+			unsigned int word = 0u, shift = 0u;
+			for (; *_str != '\0'; ++_str)
+			{
+				word |= static_cast<unsigned int>(static_cast<unsigned char>(*_str)) << shift;
+				if ((shift += 8u) == 32u) { _sink.emplace_back(word); word = 0u; shift = 0u; }
+			}
+			_sink.emplace_back(word); // null terminator
+		}
+		constexpr unsigned int wordCount(const PairLiteralIntegerIdRef&) { return 2u; }
+		template <class Sink>
+		void write(Sink& _sink, const PairLiteralIntegerIdRef& _value) { write(_sink, _value.first); write(_sink, _value.second); }
+		constexpr unsigned int wordCount(const PairIdRefLiteralInteger&) { return 2u; }
+		template <class Sink>
+		void write(Sink& _sink, const PairIdRefLiteralInteger& _value) { write(_sink, _value.first); write(_sink, _value.second); }
+		constexpr unsigned int wordCount(const PairIdRefIdRef&) { return 2u; }
+		template <class Sink>
+		void write(Sink& _sink, const PairIdRefIdRef& _value) { write(_sink, _value.first); write(_sink, _value.second); }
+		template <class T>
+		unsigned int wordCount(const Optional<T>& _value) { return _value.valid ? wordCount(_value.value) : 0u; }
+		template <class T>
+		unsigned int wordCount(const Span<T>& _values) { unsigned int count = 0u; for (unsigned int i = 0u; i < _values.size; ++i) { count += wordCount(_values.data[i]); } return count; }
+		template <class Sink, class T>
+		void write(Sink& _sink, const Optional<T>& _value) { if (_value.valid) { write(_sink, _value.value); } }
+		template <class Sink, class T>
+		void write(Sink& _sink, const Span<T>& _values) { for (unsigned int i = 0u; i < _values.size; ++i) { write(_sink, _values.data[i]); } }
+	} // detail
+
+	// Sink needs to provide emplace_back(unsigned int), enumerant parameters are passed as raw words
+	template <class Sink>
+	void opNop(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpNop));
+	}
+	template <class Sink>
+	void opUndef(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUndef));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSourceContinued(Sink& _sink, const char* _continuedSource)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_continuedSource);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSourceContinued));
+		detail::write(_sink, _continuedSource);
+	}
+	template <class Sink>
+	void opSource(Sink& _sink, SourceLanguage _sourceLanguage, unsigned int _version, Optional<Id> _file = {}, Optional<const char*> _source = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_sourceLanguage) + detail::wordCount(_version) + detail::wordCount(_file) + detail::wordCount(_source);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSource));
+		detail::write(_sink, _sourceLanguage);
+		detail::write(_sink, _version);
+		detail::write(_sink, _file);
+		detail::write(_sink, _source);
+	}
+	template <class Sink>
+	void opSourceExtension(Sink& _sink, const char* _extension)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_extension);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSourceExtension));
+		detail::write(_sink, _extension);
+	}
+	template <class Sink>
+	void opName(Sink& _sink, Id _target, const char* _name)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpName));
+		detail::write(_sink, _target);
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opMemberName(Sink& _sink, Id _type, unsigned int _member, const char* _name)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_type) + detail::wordCount(_member) + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemberName));
+		detail::write(_sink, _type);
+		detail::write(_sink, _member);
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opString(Sink& _sink, Id _result, const char* _string)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_string);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpString));
+		detail::write(_sink, _result);
+		detail::write(_sink, _string);
+	}
+	template <class Sink>
+	void opLine(Sink& _sink, Id _file, unsigned int _line, unsigned int _column)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_file) + detail::wordCount(_line) + detail::wordCount(_column);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLine));
+		detail::write(_sink, _file);
+		detail::write(_sink, _line);
+		detail::write(_sink, _column);
+	}
+	template <class Sink>
+	void opExtension(Sink& _sink, const char* _name)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExtension));
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opExtInstImport(Sink& _sink, Id _result, const char* _name)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExtInstImport));
+		detail::write(_sink, _result);
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opExtInst(Sink& _sink, Id _resultType, Id _result, Id _set, unsigned int _instruction, Span<Id> _operand1 = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_set) + detail::wordCount(_instruction) + detail::wordCount(_operand1);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExtInst));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _set);
+		detail::write(_sink, _instruction);
+		detail::write(_sink, _operand1);
+	}
+	template <class Sink>
+	void opMemoryModel(Sink& _sink, AddressingModel _addressingModel, MemoryModel _memoryModel)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_addressingModel) + detail::wordCount(_memoryModel);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemoryModel));
+		detail::write(_sink, _addressingModel);
+		detail::write(_sink, _memoryModel);
+	}
+	template <class Sink>
+	void opEntryPoint(Sink& _sink, ExecutionModel _executionModel, Id _entryPoint, const char* _name, Span<Id> _interface = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_executionModel) + detail::wordCount(_entryPoint) + detail::wordCount(_name) + detail::wordCount(_interface);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEntryPoint));
+		detail::write(_sink, _executionModel);
+		detail::write(_sink, _entryPoint);
+		detail::write(_sink, _name);
+		detail::write(_sink, _interface);
+	}
+	template <class Sink>
+	void opExecutionMode(Sink& _sink, Id _entryPoint, ExecutionMode _mode, Span<unsigned int> _modeParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_entryPoint) + detail::wordCount(_mode) + detail::wordCount(_modeParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExecutionMode));
+		detail::write(_sink, _entryPoint);
+		detail::write(_sink, _mode);
+		detail::write(_sink, _modeParameters);
+	}
+	template <class Sink>
+	void opCapability(Sink& _sink, Capability _capability)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_capability);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCapability));
+		detail::write(_sink, _capability);
+	}
+	template <class Sink>
+	void opTypeVoid(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeVoid));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeBool(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeBool));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeInt(Sink& _sink, Id _result, unsigned int _width, unsigned int _signedness)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_width) + detail::wordCount(_signedness);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeInt));
+		detail::write(_sink, _result);
+		detail::write(_sink, _width);
+		detail::write(_sink, _signedness);
+	}
+	template <class Sink>
+	void opTypeFloat(Sink& _sink, Id _result, unsigned int _width)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_width);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeFloat));
+		detail::write(_sink, _result);
+		detail::write(_sink, _width);
+	}
+	template <class Sink>
+	void opTypeVector(Sink& _sink, Id _result, Id _componentType, unsigned int _componentCount)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_componentType) + detail::wordCount(_componentCount);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeVector));
+		detail::write(_sink, _result);
+		detail::write(_sink, _componentType);
+		detail::write(_sink, _componentCount);
+	}
+	template <class Sink>
+	void opTypeMatrix(Sink& _sink, Id _result, Id _columnType, unsigned int _columnCount)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_columnType) + detail::wordCount(_columnCount);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeMatrix));
+		detail::write(_sink, _result);
+		detail::write(_sink, _columnType);
+		detail::write(_sink, _columnCount);
+	}
+	template <class Sink>
+	void opTypeImage(Sink& _sink, Id _result, Id _sampledType, Dim _dim, unsigned int _depth, unsigned int _arrayed, unsigned int _mS, unsigned int _sampled, ImageFormat _imageFormat, Optional<AccessQualifier> _accessQualifier = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_sampledType) + detail::wordCount(_dim) + detail::wordCount(_depth) + detail::wordCount(_arrayed) + detail::wordCount(_mS) + detail::wordCount(_sampled) + detail::wordCount(_imageFormat) + detail::wordCount(_accessQualifier);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeImage));
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledType);
+		detail::write(_sink, _dim);
+		detail::write(_sink, _depth);
+		detail::write(_sink, _arrayed);
+		detail::write(_sink, _mS);
+		detail::write(_sink, _sampled);
+		detail::write(_sink, _imageFormat);
+		detail::write(_sink, _accessQualifier);
+	}
+	template <class Sink>
+	void opTypeSampler(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeSampler));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeSampledImage(Sink& _sink, Id _result, Id _imageType)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_imageType);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeSampledImage));
+		detail::write(_sink, _result);
+		detail::write(_sink, _imageType);
+	}
+	template <class Sink>
+	void opTypeArray(Sink& _sink, Id _result, Id _elementType, Id _length)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_elementType) + detail::wordCount(_length);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeArray));
+		detail::write(_sink, _result);
+		detail::write(_sink, _elementType);
+		detail::write(_sink, _length);
+	}
+	template <class Sink>
+	void opTypeRuntimeArray(Sink& _sink, Id _result, Id _elementType)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_elementType);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeRuntimeArray));
+		detail::write(_sink, _result);
+		detail::write(_sink, _elementType);
+	}
+	template <class Sink>
+	void opTypeStruct(Sink& _sink, Id _result, Span<Id> _member0Type = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_member0Type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeStruct));
+		detail::write(_sink, _result);
+		detail::write(_sink, _member0Type);
+	}
+	template <class Sink>
+	void opTypeOpaque(Sink& _sink, Id _result, const char* _theNameOfTheOpaqueType)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_theNameOfTheOpaqueType);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeOpaque));
+		detail::write(_sink, _result);
+		detail::write(_sink, _theNameOfTheOpaqueType);
+	}
+	template <class Sink>
+	void opTypePointer(Sink& _sink, Id _result, StorageClass _storageClass, Id _type)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_storageClass) + detail::wordCount(_type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypePointer));
+		detail::write(_sink, _result);
+		detail::write(_sink, _storageClass);
+		detail::write(_sink, _type);
+	}
+	template <class Sink>
+	void opTypeFunction(Sink& _sink, Id _result, Id _returnType, Span<Id> _parameter0Type = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_returnType) + detail::wordCount(_parameter0Type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeFunction));
+		detail::write(_sink, _result);
+		detail::write(_sink, _returnType);
+		detail::write(_sink, _parameter0Type);
+	}
+	template <class Sink>
+	void opTypeEvent(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeEvent));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeDeviceEvent(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeDeviceEvent));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeReserveId(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeReserveId));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeQueue(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeQueue));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypePipe(Sink& _sink, Id _result, AccessQualifier _qualifier)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_qualifier);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypePipe));
+		detail::write(_sink, _result);
+		detail::write(_sink, _qualifier);
+	}
+	template <class Sink>
+	void opTypeForwardPointer(Sink& _sink, Id _pointerType, StorageClass _storageClass)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointerType) + detail::wordCount(_storageClass);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeForwardPointer));
+		detail::write(_sink, _pointerType);
+		detail::write(_sink, _storageClass);
+	}
+	template <class Sink>
+	void opConstantTrue(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantTrue));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opConstantFalse(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantFalse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opConstant(Sink& _sink, Id _resultType, Id _result, Span<unsigned int> _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstant));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opConstantComposite(Sink& _sink, Id _resultType, Id _result, Span<Id> _constituents = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_constituents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantComposite));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _constituents);
+	}
+	template <class Sink>
+	void opConstantSampler(Sink& _sink, Id _resultType, Id _result, SamplerAddressingMode _samplerAddressingMode, unsigned int _param, SamplerFilterMode _samplerFilterMode)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_samplerAddressingMode) + detail::wordCount(_param) + detail::wordCount(_samplerFilterMode);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantSampler));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _samplerAddressingMode);
+		detail::write(_sink, _param);
+		detail::write(_sink, _samplerFilterMode);
+	}
+	template <class Sink>
+	void opConstantNull(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantNull));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSpecConstantTrue(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstantTrue));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSpecConstantFalse(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstantFalse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSpecConstant(Sink& _sink, Id _resultType, Id _result, Span<unsigned int> _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstant));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opSpecConstantComposite(Sink& _sink, Id _resultType, Id _result, Span<Id> _constituents = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_constituents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstantComposite));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _constituents);
+	}
+	template <class Sink>
+	void opSpecConstantOp(Sink& _sink, Id _resultType, Id _result, unsigned int _opcode)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_opcode);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstantOp));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _opcode);
+	}
+	template <class Sink>
+	void opFunction(Sink& _sink, Id _resultType, Id _result, FunctionControlMask _functionControl, Id _functionType)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_functionControl) + detail::wordCount(_functionType);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFunction));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _functionControl);
+		detail::write(_sink, _functionType);
+	}
+	template <class Sink>
+	void opFunctionParameter(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFunctionParameter));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opFunctionEnd(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFunctionEnd));
+	}
+	template <class Sink>
+	void opFunctionCall(Sink& _sink, Id _resultType, Id _result, Id _function, Span<Id> _argument0 = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_function) + detail::wordCount(_argument0);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFunctionCall));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _function);
+		detail::write(_sink, _argument0);
+	}
+	template <class Sink>
+	void opVariable(Sink& _sink, Id _resultType, Id _result, StorageClass _storageClass, Optional<Id> _initializer = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_storageClass) + detail::wordCount(_initializer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVariable));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _storageClass);
+		detail::write(_sink, _initializer);
+	}
+	template <class Sink>
+	void opImageTexelPointer(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Id _sample)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_sample);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageTexelPointer));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _sample);
+	}
+	template <class Sink>
+	void opLoad(Sink& _sink, Id _resultType, Id _result, Id _pointer, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLoad));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+	}
+	template <class Sink>
+	void opStore(Sink& _sink, Id _pointer, Id _object, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_object) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpStore));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _object);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+	}
+	template <class Sink>
+	void opCopyMemory(Sink& _sink, Id _target, Id _source, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {}, Optional<MemoryAccessMask> _memoryAccess2 = {}, Span<unsigned int> _memoryAccess2Parameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_source) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters) + detail::wordCount(_memoryAccess2) + detail::wordCount(_memoryAccess2Parameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCopyMemory));
+		detail::write(_sink, _target);
+		detail::write(_sink, _source);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+		detail::write(_sink, _memoryAccess2);
+		detail::write(_sink, _memoryAccess2Parameters);
+	}
+	template <class Sink>
+	void opCopyMemorySized(Sink& _sink, Id _target, Id _source, Id _size, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {}, Optional<MemoryAccessMask> _memoryAccess2 = {}, Span<unsigned int> _memoryAccess2Parameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_source) + detail::wordCount(_size) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters) + detail::wordCount(_memoryAccess2) + detail::wordCount(_memoryAccess2Parameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCopyMemorySized));
+		detail::write(_sink, _target);
+		detail::write(_sink, _source);
+		detail::write(_sink, _size);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+		detail::write(_sink, _memoryAccess2);
+		detail::write(_sink, _memoryAccess2Parameters);
+	}
+	template <class Sink>
+	void opAccessChain(Sink& _sink, Id _resultType, Id _result, Id _base, Span<Id> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAccessChain));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opInBoundsAccessChain(Sink& _sink, Id _resultType, Id _result, Id _base, Span<Id> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpInBoundsAccessChain));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opPtrAccessChain(Sink& _sink, Id _resultType, Id _result, Id _base, Id _element, Span<Id> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_element) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrAccessChain));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _element);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opArrayLength(Sink& _sink, Id _resultType, Id _result, Id _structure, unsigned int _arrayMember)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_structure) + detail::wordCount(_arrayMember);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArrayLength));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _structure);
+		detail::write(_sink, _arrayMember);
+	}
+	template <class Sink>
+	void opGenericPtrMemSemantics(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGenericPtrMemSemantics));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opInBoundsPtrAccessChain(Sink& _sink, Id _resultType, Id _result, Id _base, Id _element, Span<Id> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_element) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpInBoundsPtrAccessChain));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _element);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opDecorate(Sink& _sink, Id _target, Decoration _decoration, Span<unsigned int> _decorationParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_decoration) + detail::wordCount(_decorationParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDecorate));
+		detail::write(_sink, _target);
+		detail::write(_sink, _decoration);
+		detail::write(_sink, _decorationParameters);
+	}
+	template <class Sink>
+	void opMemberDecorate(Sink& _sink, Id _structureType, unsigned int _member, Decoration _decoration, Span<unsigned int> _decorationParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_structureType) + detail::wordCount(_member) + detail::wordCount(_decoration) + detail::wordCount(_decorationParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemberDecorate));
+		detail::write(_sink, _structureType);
+		detail::write(_sink, _member);
+		detail::write(_sink, _decoration);
+		detail::write(_sink, _decorationParameters);
+	}
+	template <class Sink>
+	void opDecorationGroup(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDecorationGroup));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opGroupDecorate(Sink& _sink, Id _decorationGroup, Span<Id> _targets = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_decorationGroup) + detail::wordCount(_targets);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupDecorate));
+		detail::write(_sink, _decorationGroup);
+		detail::write(_sink, _targets);
+	}
+	template <class Sink>
+	void opGroupMemberDecorate(Sink& _sink, Id _decorationGroup, Span<PairIdRefLiteralInteger> _targets = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_decorationGroup) + detail::wordCount(_targets);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupMemberDecorate));
+		detail::write(_sink, _decorationGroup);
+		detail::write(_sink, _targets);
+	}
+	template <class Sink>
+	void opVectorExtractDynamic(Sink& _sink, Id _resultType, Id _result, Id _vector, Id _index)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector) + detail::wordCount(_index);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVectorExtractDynamic));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+		detail::write(_sink, _index);
+	}
+	template <class Sink>
+	void opVectorInsertDynamic(Sink& _sink, Id _resultType, Id _result, Id _vector, Id _component, Id _index)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector) + detail::wordCount(_component) + detail::wordCount(_index);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVectorInsertDynamic));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+		detail::write(_sink, _component);
+		detail::write(_sink, _index);
+	}
+	template <class Sink>
+	void opVectorShuffle(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Span<unsigned int> _components = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_components);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVectorShuffle));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _components);
+	}
+	template <class Sink>
+	void opCompositeConstruct(Sink& _sink, Id _resultType, Id _result, Span<Id> _constituents = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_constituents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCompositeConstruct));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _constituents);
+	}
+	template <class Sink>
+	void opCompositeExtract(Sink& _sink, Id _resultType, Id _result, Id _composite, Span<unsigned int> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_composite) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCompositeExtract));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _composite);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opCompositeInsert(Sink& _sink, Id _resultType, Id _result, Id _object, Id _composite, Span<unsigned int> _indexes = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_object) + detail::wordCount(_composite) + detail::wordCount(_indexes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCompositeInsert));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _object);
+		detail::write(_sink, _composite);
+		detail::write(_sink, _indexes);
+	}
+	template <class Sink>
+	void opCopyObject(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCopyObject));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opTranspose(Sink& _sink, Id _resultType, Id _result, Id _matrix)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_matrix);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTranspose));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _matrix);
+	}
+	template <class Sink>
+	void opSampledImage(Sink& _sink, Id _resultType, Id _result, Id _image, Id _sampler)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_sampler);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSampledImage));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _sampler);
+	}
+	template <class Sink>
+	void opImageSampleImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleDrefImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleDrefImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleDrefExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleDrefExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleProjImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleProjImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleProjExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleProjExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleProjDrefImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleProjDrefImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSampleProjDrefExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleProjDrefExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageFetch(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageFetch));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageGather(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _component, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_component) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageGather));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _component);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageDrefGather(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageDrefGather));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageRead(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageRead));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageWrite(Sink& _sink, Id _image, Id _coordinate, Id _texel, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_texel) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageWrite));
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _texel);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImage(Sink& _sink, Id _resultType, Id _result, Id _sampledImage)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImage));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+	}
+	template <class Sink>
+	void opImageQueryFormat(Sink& _sink, Id _resultType, Id _result, Id _image)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQueryFormat));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+	}
+	template <class Sink>
+	void opImageQueryOrder(Sink& _sink, Id _resultType, Id _result, Id _image)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQueryOrder));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+	}
+	template <class Sink>
+	void opImageQuerySizeLod(Sink& _sink, Id _resultType, Id _result, Id _image, Id _levelOfDetail)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_levelOfDetail);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQuerySizeLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _levelOfDetail);
+	}
+	template <class Sink>
+	void opImageQuerySize(Sink& _sink, Id _resultType, Id _result, Id _image)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQuerySize));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+	}
+	template <class Sink>
+	void opImageQueryLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQueryLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+	}
+	template <class Sink>
+	void opImageQueryLevels(Sink& _sink, Id _resultType, Id _result, Id _image)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQueryLevels));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+	}
+	template <class Sink>
+	void opImageQuerySamples(Sink& _sink, Id _resultType, Id _result, Id _image)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageQuerySamples));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+	}
+	template <class Sink>
+	void opConvertFToU(Sink& _sink, Id _resultType, Id _result, Id _floatValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_floatValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertFToU));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _floatValue);
+	}
+	template <class Sink>
+	void opConvertFToS(Sink& _sink, Id _resultType, Id _result, Id _floatValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_floatValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertFToS));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _floatValue);
+	}
+	template <class Sink>
+	void opConvertSToF(Sink& _sink, Id _resultType, Id _result, Id _signedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_signedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertSToF));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _signedValue);
+	}
+	template <class Sink>
+	void opConvertUToF(Sink& _sink, Id _resultType, Id _result, Id _unsignedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_unsignedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToF));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _unsignedValue);
+	}
+	template <class Sink>
+	void opUConvert(Sink& _sink, Id _resultType, Id _result, Id _unsignedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_unsignedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUConvert));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _unsignedValue);
+	}
+	template <class Sink>
+	void opSConvert(Sink& _sink, Id _resultType, Id _result, Id _signedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_signedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSConvert));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _signedValue);
+	}
+	template <class Sink>
+	void opFConvert(Sink& _sink, Id _resultType, Id _result, Id _floatValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_floatValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFConvert));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _floatValue);
+	}
+	template <class Sink>
+	void opQuantizeToF16(Sink& _sink, Id _resultType, Id _result, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpQuantizeToF16));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opConvertPtrToU(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertPtrToU));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opSatConvertSToU(Sink& _sink, Id _resultType, Id _result, Id _signedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_signedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSatConvertSToU));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _signedValue);
+	}
+	template <class Sink>
+	void opSatConvertUToS(Sink& _sink, Id _resultType, Id _result, Id _unsignedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_unsignedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSatConvertUToS));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _unsignedValue);
+	}
+	template <class Sink>
+	void opConvertUToPtr(Sink& _sink, Id _resultType, Id _result, Id _integerValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_integerValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToPtr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _integerValue);
+	}
+	template <class Sink>
+	void opPtrCastToGeneric(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrCastToGeneric));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opGenericCastToPtr(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGenericCastToPtr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opGenericCastToPtrExplicit(Sink& _sink, Id _resultType, Id _result, Id _pointer, StorageClass _storage)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_storage);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGenericCastToPtrExplicit));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _storage);
+	}
+	template <class Sink>
+	void opBitcast(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitcast));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opSNegate(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSNegate));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opFNegate(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFNegate));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opIAdd(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFAdd(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opISub(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpISub));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFSub(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFSub));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opIMul(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIMul));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFMul(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFMul));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUDiv(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUDiv));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSDiv(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSDiv));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFDiv(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFDiv));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUMod(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUMod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSRem(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSRem));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSMod(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSMod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFRem(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFRem));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFMod(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFMod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opVectorTimesScalar(Sink& _sink, Id _resultType, Id _result, Id _vector, Id _scalar)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector) + detail::wordCount(_scalar);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVectorTimesScalar));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+		detail::write(_sink, _scalar);
+	}
+	template <class Sink>
+	void opMatrixTimesScalar(Sink& _sink, Id _resultType, Id _result, Id _matrix, Id _scalar)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_matrix) + detail::wordCount(_scalar);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMatrixTimesScalar));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _matrix);
+		detail::write(_sink, _scalar);
+	}
+	template <class Sink>
+	void opVectorTimesMatrix(Sink& _sink, Id _resultType, Id _result, Id _vector, Id _matrix)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector) + detail::wordCount(_matrix);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVectorTimesMatrix));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+		detail::write(_sink, _matrix);
+	}
+	template <class Sink>
+	void opMatrixTimesVector(Sink& _sink, Id _resultType, Id _result, Id _matrix, Id _vector)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_matrix) + detail::wordCount(_vector);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMatrixTimesVector));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _matrix);
+		detail::write(_sink, _vector);
+	}
+	template <class Sink>
+	void opMatrixTimesMatrix(Sink& _sink, Id _resultType, Id _result, Id _leftMatrix, Id _rightMatrix)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_leftMatrix) + detail::wordCount(_rightMatrix);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMatrixTimesMatrix));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _leftMatrix);
+		detail::write(_sink, _rightMatrix);
+	}
+	template <class Sink>
+	void opOuterProduct(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpOuterProduct));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+	}
+	template <class Sink>
+	void opDot(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDot));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+	}
+	template <class Sink>
+	void opIAddCarry(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIAddCarry));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opISubBorrow(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpISubBorrow));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUMulExtended(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUMulExtended));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSMulExtended(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSMulExtended));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opAny(Sink& _sink, Id _resultType, Id _result, Id _vector)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAny));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+	}
+	template <class Sink>
+	void opAll(Sink& _sink, Id _resultType, Id _result, Id _vector)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAll));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector);
+	}
+	template <class Sink>
+	void opIsNan(Sink& _sink, Id _resultType, Id _result, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsNan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opIsInf(Sink& _sink, Id _resultType, Id _result, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsInf));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opIsFinite(Sink& _sink, Id _resultType, Id _result, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsFinite));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opIsNormal(Sink& _sink, Id _resultType, Id _result, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsNormal));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opSignBitSet(Sink& _sink, Id _resultType, Id _result, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSignBitSet));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opLessOrGreater(Sink& _sink, Id _resultType, Id _result, Id _x, Id _y)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x) + detail::wordCount(_y);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLessOrGreater));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+		detail::write(_sink, _y);
+	}
+	template <class Sink>
+	void opOrdered(Sink& _sink, Id _resultType, Id _result, Id _x, Id _y)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x) + detail::wordCount(_y);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpOrdered));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+		detail::write(_sink, _y);
+	}
+	template <class Sink>
+	void opUnordered(Sink& _sink, Id _resultType, Id _result, Id _x, Id _y)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_x) + detail::wordCount(_y);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUnordered));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _x);
+		detail::write(_sink, _y);
+	}
+	template <class Sink>
+	void opLogicalEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLogicalEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opLogicalNotEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLogicalNotEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opLogicalOr(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLogicalOr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opLogicalAnd(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLogicalAnd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opLogicalNot(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLogicalNot));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opSelect(Sink& _sink, Id _resultType, Id _result, Id _condition, Id _object1, Id _object2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_condition) + detail::wordCount(_object1) + detail::wordCount(_object2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSelect));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _condition);
+		detail::write(_sink, _object1);
+		detail::write(_sink, _object2);
+	}
+	template <class Sink>
+	void opIEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opINotEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpINotEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUGreaterThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUGreaterThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSGreaterThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSGreaterThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUGreaterThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUGreaterThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSGreaterThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSGreaterThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opULessThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpULessThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSLessThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSLessThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opULessThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpULessThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opSLessThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSLessThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdNotEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdNotEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordNotEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordNotEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdLessThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdLessThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordLessThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordLessThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdGreaterThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdGreaterThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordGreaterThan(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordGreaterThan));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdLessThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdLessThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordLessThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordLessThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFOrdGreaterThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFOrdGreaterThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opFUnordGreaterThanEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFUnordGreaterThanEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opShiftRightLogical(Sink& _sink, Id _resultType, Id _result, Id _base, Id _shift)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_shift);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpShiftRightLogical));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _shift);
+	}
+	template <class Sink>
+	void opShiftRightArithmetic(Sink& _sink, Id _resultType, Id _result, Id _base, Id _shift)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_shift);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpShiftRightArithmetic));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _shift);
+	}
+	template <class Sink>
+	void opShiftLeftLogical(Sink& _sink, Id _resultType, Id _result, Id _base, Id _shift)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_shift);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpShiftLeftLogical));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _shift);
+	}
+	template <class Sink>
+	void opBitwiseOr(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitwiseOr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opBitwiseXor(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitwiseXor));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opBitwiseAnd(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitwiseAnd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opNot(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpNot));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opBitFieldInsert(Sink& _sink, Id _resultType, Id _result, Id _base, Id _insert, Id _offset, Id _count)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_insert) + detail::wordCount(_offset) + detail::wordCount(_count);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitFieldInsert));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _insert);
+		detail::write(_sink, _offset);
+		detail::write(_sink, _count);
+	}
+	template <class Sink>
+	void opBitFieldSExtract(Sink& _sink, Id _resultType, Id _result, Id _base, Id _offset, Id _count)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_offset) + detail::wordCount(_count);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitFieldSExtract));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _offset);
+		detail::write(_sink, _count);
+	}
+	template <class Sink>
+	void opBitFieldUExtract(Sink& _sink, Id _resultType, Id _result, Id _base, Id _offset, Id _count)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base) + detail::wordCount(_offset) + detail::wordCount(_count);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitFieldUExtract));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+		detail::write(_sink, _offset);
+		detail::write(_sink, _count);
+	}
+	template <class Sink>
+	void opBitReverse(Sink& _sink, Id _resultType, Id _result, Id _base)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitReverse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+	}
+	template <class Sink>
+	void opBitCount(Sink& _sink, Id _resultType, Id _result, Id _base)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_base);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBitCount));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _base);
+	}
+	template <class Sink>
+	void opDPdx(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdx));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opDPdy(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdy));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opFwidth(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFwidth));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opDPdxFine(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdxFine));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opDPdyFine(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdyFine));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opFwidthFine(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFwidthFine));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opDPdxCoarse(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdxCoarse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opDPdyCoarse(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDPdyCoarse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opFwidthCoarse(Sink& _sink, Id _resultType, Id _result, Id _p)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_p);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFwidthCoarse));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _p);
+	}
+	template <class Sink>
+	void opEmitVertex(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEmitVertex));
+	}
+	template <class Sink>
+	void opEndPrimitive(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEndPrimitive));
+	}
+	template <class Sink>
+	void opEmitStreamVertex(Sink& _sink, Id _stream)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_stream);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEmitStreamVertex));
+		detail::write(_sink, _stream);
+	}
+	template <class Sink>
+	void opEndStreamPrimitive(Sink& _sink, Id _stream)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_stream);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEndStreamPrimitive));
+		detail::write(_sink, _stream);
+	}
+	template <class Sink>
+	void opControlBarrier(Sink& _sink, Id _execution, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpControlBarrier));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opMemoryBarrier(Sink& _sink, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemoryBarrier));
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opAtomicLoad(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicLoad));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opAtomicStore(Sink& _sink, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicStore));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicExchange(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicExchange));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicCompareExchange(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _equal, Id _unequal, Id _value, Id _comparator)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_equal) + detail::wordCount(_unequal) + detail::wordCount(_value) + detail::wordCount(_comparator);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicCompareExchange));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _equal);
+		detail::write(_sink, _unequal);
+		detail::write(_sink, _value);
+		detail::write(_sink, _comparator);
+	}
+	template <class Sink>
+	void opAtomicCompareExchangeWeak(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _equal, Id _unequal, Id _value, Id _comparator)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_equal) + detail::wordCount(_unequal) + detail::wordCount(_value) + detail::wordCount(_comparator);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicCompareExchangeWeak));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _equal);
+		detail::write(_sink, _unequal);
+		detail::write(_sink, _value);
+		detail::write(_sink, _comparator);
+	}
+	template <class Sink>
+	void opAtomicIIncrement(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicIIncrement));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opAtomicIDecrement(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicIDecrement));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opAtomicIAdd(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicIAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicISub(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicISub));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicSMin(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicSMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicUMin(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicUMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicSMax(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicSMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicUMax(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicUMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicAnd(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicAnd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicOr(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicOr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicXor(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicXor));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opPhi(Sink& _sink, Id _resultType, Id _result, Span<PairIdRefIdRef> _variable = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_variable);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPhi));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _variable);
+	}
+	template <class Sink>
+	void opLoopMerge(Sink& _sink, Id _mergeBlock, Id _continueTarget, LoopControlMask _loopControl, Span<unsigned int> _loopControlParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_mergeBlock) + detail::wordCount(_continueTarget) + detail::wordCount(_loopControl) + detail::wordCount(_loopControlParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLoopMerge));
+		detail::write(_sink, _mergeBlock);
+		detail::write(_sink, _continueTarget);
+		detail::write(_sink, _loopControl);
+		detail::write(_sink, _loopControlParameters);
+	}
+	template <class Sink>
+	void opSelectionMerge(Sink& _sink, Id _mergeBlock, SelectionControlMask _selectionControl)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_mergeBlock) + detail::wordCount(_selectionControl);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSelectionMerge));
+		detail::write(_sink, _mergeBlock);
+		detail::write(_sink, _selectionControl);
+	}
+	template <class Sink>
+	void opLabel(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLabel));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opBranch(Sink& _sink, Id _targetLabel)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_targetLabel);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBranch));
+		detail::write(_sink, _targetLabel);
+	}
+	template <class Sink>
+	void opBranchConditional(Sink& _sink, Id _condition, Id _trueLabel, Id _falseLabel, Span<unsigned int> _branchWeights = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_condition) + detail::wordCount(_trueLabel) + detail::wordCount(_falseLabel) + detail::wordCount(_branchWeights);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBranchConditional));
+		detail::write(_sink, _condition);
+		detail::write(_sink, _trueLabel);
+		detail::write(_sink, _falseLabel);
+		detail::write(_sink, _branchWeights);
+	}
+	template <class Sink>
+	void opSwitch(Sink& _sink, Id _selector, Id _default, Span<PairLiteralIntegerIdRef> _target = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_selector) + detail::wordCount(_default) + detail::wordCount(_target);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSwitch));
+		detail::write(_sink, _selector);
+		detail::write(_sink, _default);
+		detail::write(_sink, _target);
+	}
+	template <class Sink>
+	void opKill(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpKill));
+	}
+	template <class Sink>
+	void opReturn(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReturn));
+	}
+	template <class Sink>
+	void opReturnValue(Sink& _sink, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReturnValue));
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opUnreachable(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUnreachable));
+	}
+	template <class Sink>
+	void opLifetimeStart(Sink& _sink, Id _pointer, unsigned int _size)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_size);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLifetimeStart));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _size);
+	}
+	template <class Sink>
+	void opLifetimeStop(Sink& _sink, Id _pointer, unsigned int _size)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_size);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLifetimeStop));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _size);
+	}
+	template <class Sink>
+	void opGroupAsyncCopy(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _destination, Id _source, Id _numElements, Id _stride, Id _event)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_destination) + detail::wordCount(_source) + detail::wordCount(_numElements) + detail::wordCount(_stride) + detail::wordCount(_event);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupAsyncCopy));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _destination);
+		detail::write(_sink, _source);
+		detail::write(_sink, _numElements);
+		detail::write(_sink, _stride);
+		detail::write(_sink, _event);
+	}
+	template <class Sink>
+	void opGroupWaitEvents(Sink& _sink, Id _execution, Id _numEvents, Id _eventsList)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_numEvents) + detail::wordCount(_eventsList);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupWaitEvents));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _numEvents);
+		detail::write(_sink, _eventsList);
+	}
+	template <class Sink>
+	void opGroupAll(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupAll));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupAny(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupAny));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupBroadcast(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _localId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_localId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupBroadcast));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _localId);
+	}
+	template <class Sink>
+	void opGroupIAdd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupIAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFAdd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupUMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupUMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupSMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupSMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupUMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupUMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupSMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupSMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opReadPipe(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _pointer, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_pointer) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReadPipe));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opWritePipe(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _pointer, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_pointer) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpWritePipe));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opReservedReadPipe(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _reserveId, Id _index, Id _pointer, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_index) + detail::wordCount(_pointer) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReservedReadPipe));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _index);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opReservedWritePipe(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _reserveId, Id _index, Id _pointer, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_index) + detail::wordCount(_pointer) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReservedWritePipe));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _index);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opReserveReadPipePackets(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _numPackets, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_numPackets) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReserveReadPipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _numPackets);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opReserveWritePipePackets(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _numPackets, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_numPackets) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReserveWritePipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _numPackets);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opCommitReadPipe(Sink& _sink, Id _pipe, Id _reserveId, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCommitReadPipe));
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opCommitWritePipe(Sink& _sink, Id _pipe, Id _reserveId, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCommitWritePipe));
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opIsValidReserveId(Sink& _sink, Id _resultType, Id _result, Id _reserveId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_reserveId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsValidReserveId));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _reserveId);
+	}
+	template <class Sink>
+	void opGetNumPipePackets(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetNumPipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opGetMaxPipePackets(Sink& _sink, Id _resultType, Id _result, Id _pipe, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipe) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetMaxPipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opGroupReserveReadPipePackets(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _pipe, Id _numPackets, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_pipe) + detail::wordCount(_numPackets) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupReserveReadPipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _numPackets);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opGroupReserveWritePipePackets(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _pipe, Id _numPackets, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_pipe) + detail::wordCount(_numPackets) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupReserveWritePipePackets));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _numPackets);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opGroupCommitReadPipe(Sink& _sink, Id _execution, Id _pipe, Id _reserveId, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupCommitReadPipe));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opGroupCommitWritePipe(Sink& _sink, Id _execution, Id _pipe, Id _reserveId, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_pipe) + detail::wordCount(_reserveId) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupCommitWritePipe));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _pipe);
+		detail::write(_sink, _reserveId);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opEnqueueMarker(Sink& _sink, Id _resultType, Id _result, Id _queue, Id _numEvents, Id _waitEvents, Id _retEvent)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_queue) + detail::wordCount(_numEvents) + detail::wordCount(_waitEvents) + detail::wordCount(_retEvent);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEnqueueMarker));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _queue);
+		detail::write(_sink, _numEvents);
+		detail::write(_sink, _waitEvents);
+		detail::write(_sink, _retEvent);
+	}
+	template <class Sink>
+	void opEnqueueKernel(Sink& _sink, Id _resultType, Id _result, Id _queue, Id _flags, Id _nDRange, Id _numEvents, Id _waitEvents, Id _retEvent, Id _invoke, Id _param, Id _paramSize, Id _paramAlign, Span<Id> _localSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_queue) + detail::wordCount(_flags) + detail::wordCount(_nDRange) + detail::wordCount(_numEvents) + detail::wordCount(_waitEvents) + detail::wordCount(_retEvent) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign) + detail::wordCount(_localSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEnqueueKernel));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _queue);
+		detail::write(_sink, _flags);
+		detail::write(_sink, _nDRange);
+		detail::write(_sink, _numEvents);
+		detail::write(_sink, _waitEvents);
+		detail::write(_sink, _retEvent);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+		detail::write(_sink, _localSize);
+	}
+	template <class Sink>
+	void opGetKernelNDrangeSubGroupCount(Sink& _sink, Id _resultType, Id _result, Id _nDRange, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_nDRange) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelNDrangeSubGroupCount));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _nDRange);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opGetKernelNDrangeMaxSubGroupSize(Sink& _sink, Id _resultType, Id _result, Id _nDRange, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_nDRange) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelNDrangeMaxSubGroupSize));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _nDRange);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opGetKernelWorkGroupSize(Sink& _sink, Id _resultType, Id _result, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelWorkGroupSize));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opGetKernelPreferredWorkGroupSizeMultiple(Sink& _sink, Id _resultType, Id _result, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelPreferredWorkGroupSizeMultiple));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opRetainEvent(Sink& _sink, Id _event)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_event);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRetainEvent));
+		detail::write(_sink, _event);
+	}
+	template <class Sink>
+	void opReleaseEvent(Sink& _sink, Id _event)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_event);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReleaseEvent));
+		detail::write(_sink, _event);
+	}
+	template <class Sink>
+	void opCreateUserEvent(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCreateUserEvent));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opIsValidEvent(Sink& _sink, Id _resultType, Id _result, Id _event)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_event);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsValidEvent));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _event);
+	}
+	template <class Sink>
+	void opSetUserEventStatus(Sink& _sink, Id _event, Id _status)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_event) + detail::wordCount(_status);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSetUserEventStatus));
+		detail::write(_sink, _event);
+		detail::write(_sink, _status);
+	}
+	template <class Sink>
+	void opCaptureEventProfilingInfo(Sink& _sink, Id _event, Id _profilingInfo, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_event) + detail::wordCount(_profilingInfo) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCaptureEventProfilingInfo));
+		detail::write(_sink, _event);
+		detail::write(_sink, _profilingInfo);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGetDefaultQueue(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetDefaultQueue));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opBuildNDRange(Sink& _sink, Id _resultType, Id _result, Id _globalWorkSize, Id _localWorkSize, Id _globalWorkOffset)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_globalWorkSize) + detail::wordCount(_localWorkSize) + detail::wordCount(_globalWorkOffset);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBuildNDRange));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _globalWorkSize);
+		detail::write(_sink, _localWorkSize);
+		detail::write(_sink, _globalWorkOffset);
+	}
+	template <class Sink>
+	void opImageSparseSampleImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleDrefImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleDrefImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleDrefExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleDrefExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleProjImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleProjImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleProjExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleProjExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleProjDrefImplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleProjDrefImplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseSampleProjDrefExplicitLod(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, ImageOperandsMask _imageOperands, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseSampleProjDrefExplicitLod));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseFetch(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseFetch));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseGather(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _component, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_component) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseGather));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _component);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseDrefGather(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _dRef, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_dRef) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseDrefGather));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _dRef);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opImageSparseTexelsResident(Sink& _sink, Id _resultType, Id _result, Id _residentCode)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_residentCode);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseTexelsResident));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _residentCode);
+	}
+	template <class Sink>
+	void opNoLine(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpNoLine));
+	}
+	template <class Sink>
+	void opAtomicFlagTestAndSet(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicFlagTestAndSet));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opAtomicFlagClear(Sink& _sink, Id _pointer, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicFlagClear));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opImageSparseRead(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSparseRead));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opSizeOf(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSizeOf));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opTypePipeStorage(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypePipeStorage));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opConstantPipeStorage(Sink& _sink, Id _resultType, Id _result, unsigned int _packetSize, unsigned int _packetAlignment, unsigned int _capacity)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment) + detail::wordCount(_capacity);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantPipeStorage));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+		detail::write(_sink, _capacity);
+	}
+	template <class Sink>
+	void opCreatePipeFromPipeStorage(Sink& _sink, Id _resultType, Id _result, Id _pipeStorage)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pipeStorage);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCreatePipeFromPipeStorage));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pipeStorage);
+	}
+	template <class Sink>
+	void opGetKernelLocalSizeForSubgroupCount(Sink& _sink, Id _resultType, Id _result, Id _subgroupCount, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_subgroupCount) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelLocalSizeForSubgroupCount));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _subgroupCount);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opGetKernelMaxNumSubgroups(Sink& _sink, Id _resultType, Id _result, Id _invoke, Id _param, Id _paramSize, Id _paramAlign)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_invoke) + detail::wordCount(_param) + detail::wordCount(_paramSize) + detail::wordCount(_paramAlign);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGetKernelMaxNumSubgroups));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _invoke);
+		detail::write(_sink, _param);
+		detail::write(_sink, _paramSize);
+		detail::write(_sink, _paramAlign);
+	}
+	template <class Sink>
+	void opTypeNamedBarrier(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeNamedBarrier));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opNamedBarrierInitialize(Sink& _sink, Id _resultType, Id _result, Id _subgroupCount)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_subgroupCount);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpNamedBarrierInitialize));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _subgroupCount);
+	}
+	template <class Sink>
+	void opMemoryNamedBarrier(Sink& _sink, Id _namedBarrier, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_namedBarrier) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemoryNamedBarrier));
+		detail::write(_sink, _namedBarrier);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opModuleProcessed(Sink& _sink, const char* _process)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_process);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpModuleProcessed));
+		detail::write(_sink, _process);
+	}
+	template <class Sink>
+	void opExecutionModeId(Sink& _sink, Id _entryPoint, ExecutionMode _mode, Span<unsigned int> _modeParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_entryPoint) + detail::wordCount(_mode) + detail::wordCount(_modeParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExecutionModeId));
+		detail::write(_sink, _entryPoint);
+		detail::write(_sink, _mode);
+		detail::write(_sink, _modeParameters);
+	}
+	template <class Sink>
+	void opDecorateId(Sink& _sink, Id _target, Decoration _decoration, Span<unsigned int> _decorationParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_decoration) + detail::wordCount(_decorationParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDecorateId));
+		detail::write(_sink, _target);
+		detail::write(_sink, _decoration);
+		detail::write(_sink, _decorationParameters);
+	}
+	template <class Sink>
+	void opGroupNonUniformElect(Sink& _sink, Id _resultType, Id _result, Id _execution)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformElect));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+	}
+	template <class Sink>
+	void opGroupNonUniformAll(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformAll));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupNonUniformAny(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformAny));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupNonUniformAllEqual(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformAllEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformBroadcast(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _id)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_id);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBroadcast));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _id);
+	}
+	template <class Sink>
+	void opGroupNonUniformBroadcastFirst(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBroadcastFirst));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformBallot(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBallot));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupNonUniformInverseBallot(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformInverseBallot));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformBallotBitExtract(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _index)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_index);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBallotBitExtract));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _index);
+	}
+	template <class Sink>
+	void opGroupNonUniformBallotBitCount(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBallotBitCount));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformBallotFindLSB(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBallotFindLSB));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformBallotFindMSB(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBallotFindMSB));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opGroupNonUniformShuffle(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _id)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_id);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformShuffle));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _id);
+	}
+	template <class Sink>
+	void opGroupNonUniformShuffleXor(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _mask)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_mask);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformShuffleXor));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _mask);
+	}
+	template <class Sink>
+	void opGroupNonUniformShuffleUp(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _delta)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_delta);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformShuffleUp));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _delta);
+	}
+	template <class Sink>
+	void opGroupNonUniformShuffleDown(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _delta)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_delta);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformShuffleDown));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _delta);
+	}
+	template <class Sink>
+	void opGroupNonUniformIAdd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformIAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformFAdd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformFAdd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformIMul(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformIMul));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformFMul(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformFMul));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformSMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformSMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformUMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformUMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformFMin(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformFMin));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformSMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformSMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformUMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformUMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformFMax(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformFMax));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformBitwiseAnd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBitwiseAnd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformBitwiseOr(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBitwiseOr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformBitwiseXor(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformBitwiseXor));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformLogicalAnd(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformLogicalAnd));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformLogicalOr(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformLogicalOr));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformLogicalXor(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _value, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_value) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformLogicalXor));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _value);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opGroupNonUniformQuadBroadcast(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _index)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_index);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformQuadBroadcast));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _index);
+	}
+	template <class Sink>
+	void opGroupNonUniformQuadSwap(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _direction)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_direction);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformQuadSwap));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _direction);
+	}
+	template <class Sink>
+	void opCopyLogical(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCopyLogical));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opPtrEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opPtrNotEqual(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrNotEqual));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opPtrDiff(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrDiff));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opColorAttachmentReadEXT(Sink& _sink, Id _resultType, Id _result, Id _attachment, Optional<Id> _sample = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_attachment) + detail::wordCount(_sample);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpColorAttachmentReadEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _attachment);
+		detail::write(_sink, _sample);
+	}
+	template <class Sink>
+	void opDepthAttachmentReadEXT(Sink& _sink, Id _resultType, Id _result, Optional<Id> _sample = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sample);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDepthAttachmentReadEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sample);
+	}
+	template <class Sink>
+	void opStencilAttachmentReadEXT(Sink& _sink, Id _resultType, Id _result, Optional<Id> _sample = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sample);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpStencilAttachmentReadEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sample);
+	}
+	template <class Sink>
+	void opTerminateInvocation(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTerminateInvocation));
+	}
+	template <class Sink>
+	void opSubgroupBallotKHR(Sink& _sink, Id _resultType, Id _result, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupBallotKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opSubgroupFirstInvocationKHR(Sink& _sink, Id _resultType, Id _result, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupFirstInvocationKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opSubgroupAllKHR(Sink& _sink, Id _resultType, Id _result, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAllKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opSubgroupAnyKHR(Sink& _sink, Id _resultType, Id _result, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAnyKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opSubgroupAllEqualKHR(Sink& _sink, Id _resultType, Id _result, Id _predicate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_predicate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAllEqualKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _predicate);
+	}
+	template <class Sink>
+	void opGroupNonUniformRotateKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, Id _value, Id _delta, Optional<Id> _clusterSize = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_value) + detail::wordCount(_delta) + detail::wordCount(_clusterSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformRotateKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _value);
+		detail::write(_sink, _delta);
+		detail::write(_sink, _clusterSize);
+	}
+	template <class Sink>
+	void opSubgroupReadInvocationKHR(Sink& _sink, Id _resultType, Id _result, Id _value, Id _index)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value) + detail::wordCount(_index);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupReadInvocationKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+		detail::write(_sink, _index);
+	}
+	template <class Sink>
+	void opTraceRayKHR(Sink& _sink, Id _accel, Id _rayFlags, Id _cullMask, Id _sBTOffset, Id _sBTStride, Id _missIndex, Id _rayOrigin, Id _rayTmin, Id _rayDirection, Id _rayTmax, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_accel) + detail::wordCount(_rayFlags) + detail::wordCount(_cullMask) + detail::wordCount(_sBTOffset) + detail::wordCount(_sBTStride) + detail::wordCount(_missIndex) + detail::wordCount(_rayOrigin) + detail::wordCount(_rayTmin) + detail::wordCount(_rayDirection) + detail::wordCount(_rayTmax) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTraceRayKHR));
+		detail::write(_sink, _accel);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullMask);
+		detail::write(_sink, _sBTOffset);
+		detail::write(_sink, _sBTStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _rayOrigin);
+		detail::write(_sink, _rayTmin);
+		detail::write(_sink, _rayDirection);
+		detail::write(_sink, _rayTmax);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opExecuteCallableKHR(Sink& _sink, Id _sBTIndex, Id _callableData)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_sBTIndex) + detail::wordCount(_callableData);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExecuteCallableKHR));
+		detail::write(_sink, _sBTIndex);
+		detail::write(_sink, _callableData);
+	}
+	template <class Sink>
+	void opConvertUToAccelerationStructureKHR(Sink& _sink, Id _resultType, Id _result, Id _accel)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_accel);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToAccelerationStructureKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _accel);
+	}
+	template <class Sink>
+	void opIgnoreIntersectionKHR(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIgnoreIntersectionKHR));
+	}
+	template <class Sink>
+	void opTerminateRayKHR(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTerminateRayKHR));
+	}
+	template <class Sink>
+	void opSDotKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSDotKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opUDotKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUDotKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opSUDotKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSUDotKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opSDotAccSatKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Id _accumulator, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_accumulator) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSDotAccSatKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _accumulator);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opUDotAccSatKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Id _accumulator, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_accumulator) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUDotAccSatKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _accumulator);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opSUDotAccSatKHR(Sink& _sink, Id _resultType, Id _result, Id _vector1, Id _vector2, Id _accumulator, Optional<PackedVectorFormat> _packedVectorFormat = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_vector1) + detail::wordCount(_vector2) + detail::wordCount(_accumulator) + detail::wordCount(_packedVectorFormat);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSUDotAccSatKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _vector1);
+		detail::write(_sink, _vector2);
+		detail::write(_sink, _accumulator);
+		detail::write(_sink, _packedVectorFormat);
+	}
+	template <class Sink>
+	void opTypeCooperativeMatrixKHR(Sink& _sink, Id _result, Id _componentType, Id _scope, Id _rows, Id _columns, Id _use)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_componentType) + detail::wordCount(_scope) + detail::wordCount(_rows) + detail::wordCount(_columns) + detail::wordCount(_use);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeCooperativeMatrixKHR));
+		detail::write(_sink, _result);
+		detail::write(_sink, _componentType);
+		detail::write(_sink, _scope);
+		detail::write(_sink, _rows);
+		detail::write(_sink, _columns);
+		detail::write(_sink, _use);
+	}
+	template <class Sink>
+	void opCooperativeMatrixLoadKHR(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memoryLayout, Optional<Id> _stride = {}, Optional<MemoryAccessMask> _memoryOperand = {}, Span<unsigned int> _memoryOperandParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memoryLayout) + detail::wordCount(_stride) + detail::wordCount(_memoryOperand) + detail::wordCount(_memoryOperandParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixLoadKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memoryLayout);
+		detail::write(_sink, _stride);
+		detail::write(_sink, _memoryOperand);
+		detail::write(_sink, _memoryOperandParameters);
+	}
+	template <class Sink>
+	void opCooperativeMatrixStoreKHR(Sink& _sink, Id _pointer, Id _object, Id _memoryLayout, Optional<Id> _stride = {}, Optional<MemoryAccessMask> _memoryOperand = {}, Span<unsigned int> _memoryOperandParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_object) + detail::wordCount(_memoryLayout) + detail::wordCount(_stride) + detail::wordCount(_memoryOperand) + detail::wordCount(_memoryOperandParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixStoreKHR));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _object);
+		detail::write(_sink, _memoryLayout);
+		detail::write(_sink, _stride);
+		detail::write(_sink, _memoryOperand);
+		detail::write(_sink, _memoryOperandParameters);
+	}
+	template <class Sink>
+	void opCooperativeMatrixMulAddKHR(Sink& _sink, Id _resultType, Id _result, Id _a, Id _b, Id _c, Optional<CooperativeMatrixOperandsMask> _cooperativeMatrixOperands = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_b) + detail::wordCount(_c) + detail::wordCount(_cooperativeMatrixOperands);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixMulAddKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _b);
+		detail::write(_sink, _c);
+		detail::write(_sink, _cooperativeMatrixOperands);
+	}
+	template <class Sink>
+	void opCooperativeMatrixLengthKHR(Sink& _sink, Id _resultType, Id _result, Id _type)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixLengthKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _type);
+	}
+	template <class Sink>
+	void opTypeRayQueryKHR(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeRayQueryKHR));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opRayQueryInitializeKHR(Sink& _sink, Id _rayQuery, Id _accel, Id _rayFlags, Id _cullMask, Id _rayOrigin, Id _rayTMin, Id _rayDirection, Id _rayTMax)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_rayQuery) + detail::wordCount(_accel) + detail::wordCount(_rayFlags) + detail::wordCount(_cullMask) + detail::wordCount(_rayOrigin) + detail::wordCount(_rayTMin) + detail::wordCount(_rayDirection) + detail::wordCount(_rayTMax);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryInitializeKHR));
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _accel);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullMask);
+		detail::write(_sink, _rayOrigin);
+		detail::write(_sink, _rayTMin);
+		detail::write(_sink, _rayDirection);
+		detail::write(_sink, _rayTMax);
+	}
+	template <class Sink>
+	void opRayQueryTerminateKHR(Sink& _sink, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryTerminateKHR));
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGenerateIntersectionKHR(Sink& _sink, Id _rayQuery, Id _hitT)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_rayQuery) + detail::wordCount(_hitT);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGenerateIntersectionKHR));
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _hitT);
+	}
+	template <class Sink>
+	void opRayQueryConfirmIntersectionKHR(Sink& _sink, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryConfirmIntersectionKHR));
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryProceedKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryProceedKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionTypeKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionTypeKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opImageSampleWeightedQCOM(Sink& _sink, Id _resultType, Id _result, Id _texture, Id _coordinates, Id _weights)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_texture) + detail::wordCount(_coordinates) + detail::wordCount(_weights);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleWeightedQCOM));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _texture);
+		detail::write(_sink, _coordinates);
+		detail::write(_sink, _weights);
+	}
+	template <class Sink>
+	void opImageBoxFilterQCOM(Sink& _sink, Id _resultType, Id _result, Id _texture, Id _coordinates, Id _boxSize)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_texture) + detail::wordCount(_coordinates) + detail::wordCount(_boxSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageBoxFilterQCOM));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _texture);
+		detail::write(_sink, _coordinates);
+		detail::write(_sink, _boxSize);
+	}
+	template <class Sink>
+	void opImageBlockMatchSSDQCOM(Sink& _sink, Id _resultType, Id _result, Id _target, Id _targetCoordinates, Id _reference, Id _referenceCoordinates, Id _blockSize)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_target) + detail::wordCount(_targetCoordinates) + detail::wordCount(_reference) + detail::wordCount(_referenceCoordinates) + detail::wordCount(_blockSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageBlockMatchSSDQCOM));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _target);
+		detail::write(_sink, _targetCoordinates);
+		detail::write(_sink, _reference);
+		detail::write(_sink, _referenceCoordinates);
+		detail::write(_sink, _blockSize);
+	}
+	template <class Sink>
+	void opImageBlockMatchSADQCOM(Sink& _sink, Id _resultType, Id _result, Id _target, Id _targetCoordinates, Id _reference, Id _referenceCoordinates, Id _blockSize)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_target) + detail::wordCount(_targetCoordinates) + detail::wordCount(_reference) + detail::wordCount(_referenceCoordinates) + detail::wordCount(_blockSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageBlockMatchSADQCOM));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _target);
+		detail::write(_sink, _targetCoordinates);
+		detail::write(_sink, _reference);
+		detail::write(_sink, _referenceCoordinates);
+		detail::write(_sink, _blockSize);
+	}
+	template <class Sink>
+	void opGroupIAddNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupIAddNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFAddNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFAddNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFMinNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFMinNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupUMinNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupUMinNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupSMinNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupSMinNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFMaxNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFMaxNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupUMaxNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupUMaxNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupSMaxNonUniformAMD(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupSMaxNonUniformAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opFragmentMaskFetchAMD(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFragmentMaskFetchAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+	}
+	template <class Sink>
+	void opFragmentFetchAMD(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Id _fragmentIndex)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_fragmentIndex);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFragmentFetchAMD));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _fragmentIndex);
+	}
+	template <class Sink>
+	void opReadClockKHR(Sink& _sink, Id _resultType, Id _result, Id _scope)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_scope);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReadClockKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _scope);
+	}
+	template <class Sink>
+	void opFinalizeNodePayloadsAMDX(Sink& _sink, Id _payloadArray)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_payloadArray);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFinalizeNodePayloadsAMDX));
+		detail::write(_sink, _payloadArray);
+	}
+	template <class Sink>
+	void opFinishWritingNodePayloadAMDX(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFinishWritingNodePayloadAMDX));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opInitializeNodePayloadsAMDX(Sink& _sink, Id _payloadArray, Id _visibility, Id _payloadCount, Id _nodeIndex)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_payloadArray) + detail::wordCount(_visibility) + detail::wordCount(_payloadCount) + detail::wordCount(_nodeIndex);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpInitializeNodePayloadsAMDX));
+		detail::write(_sink, _payloadArray);
+		detail::write(_sink, _visibility);
+		detail::write(_sink, _payloadCount);
+		detail::write(_sink, _nodeIndex);
+	}
+	template <class Sink>
+	void opHitObjectRecordHitMotionNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _instanceId, Id _primitiveId, Id _geometryIndex, Id _hitKind, Id _sBTRecordOffset, Id _sBTRecordStride, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _currentTime, Id _hitObjectAttributes)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_instanceId) + detail::wordCount(_primitiveId) + detail::wordCount(_geometryIndex) + detail::wordCount(_hitKind) + detail::wordCount(_sBTRecordOffset) + detail::wordCount(_sBTRecordStride) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_currentTime) + detail::wordCount(_hitObjectAttributes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordHitMotionNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _instanceId);
+		detail::write(_sink, _primitiveId);
+		detail::write(_sink, _geometryIndex);
+		detail::write(_sink, _hitKind);
+		detail::write(_sink, _sBTRecordOffset);
+		detail::write(_sink, _sBTRecordStride);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _currentTime);
+		detail::write(_sink, _hitObjectAttributes);
+	}
+	template <class Sink>
+	void opHitObjectRecordHitWithIndexMotionNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _instanceId, Id _primitiveId, Id _geometryIndex, Id _hitKind, Id _sBTRecordIndex, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _currentTime, Id _hitObjectAttributes)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_instanceId) + detail::wordCount(_primitiveId) + detail::wordCount(_geometryIndex) + detail::wordCount(_hitKind) + detail::wordCount(_sBTRecordIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_currentTime) + detail::wordCount(_hitObjectAttributes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordHitWithIndexMotionNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _instanceId);
+		detail::write(_sink, _primitiveId);
+		detail::write(_sink, _geometryIndex);
+		detail::write(_sink, _hitKind);
+		detail::write(_sink, _sBTRecordIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _currentTime);
+		detail::write(_sink, _hitObjectAttributes);
+	}
+	template <class Sink>
+	void opHitObjectRecordMissMotionNV(Sink& _sink, Id _hitObject, Id _sBTIndex, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _currentTime)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_sBTIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_currentTime);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordMissMotionNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _sBTIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _currentTime);
+	}
+	template <class Sink>
+	void opHitObjectGetWorldToObjectNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetWorldToObjectNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetObjectToWorldNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetObjectToWorldNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetObjectRayDirectionNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetObjectRayDirectionNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetObjectRayOriginNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetObjectRayOriginNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectTraceRayMotionNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _rayFlags, Id _cullmask, Id _sBTRecordOffset, Id _sBTRecordStride, Id _missIndex, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _time, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_rayFlags) + detail::wordCount(_cullmask) + detail::wordCount(_sBTRecordOffset) + detail::wordCount(_sBTRecordStride) + detail::wordCount(_missIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_time) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectTraceRayMotionNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullmask);
+		detail::write(_sink, _sBTRecordOffset);
+		detail::write(_sink, _sBTRecordStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _time);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opHitObjectGetShaderRecordBufferHandleNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetShaderRecordBufferHandleNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetShaderBindingTableRecordIndexNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetShaderBindingTableRecordIndexNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectRecordEmptyNV(Sink& _sink, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordEmptyNV));
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectTraceRayNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _rayFlags, Id _cullmask, Id _sBTRecordOffset, Id _sBTRecordStride, Id _missIndex, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_rayFlags) + detail::wordCount(_cullmask) + detail::wordCount(_sBTRecordOffset) + detail::wordCount(_sBTRecordStride) + detail::wordCount(_missIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectTraceRayNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullmask);
+		detail::write(_sink, _sBTRecordOffset);
+		detail::write(_sink, _sBTRecordStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opHitObjectRecordHitNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _instanceId, Id _primitiveId, Id _geometryIndex, Id _hitKind, Id _sBTRecordOffset, Id _sBTRecordStride, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _hitObjectAttributes)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_instanceId) + detail::wordCount(_primitiveId) + detail::wordCount(_geometryIndex) + detail::wordCount(_hitKind) + detail::wordCount(_sBTRecordOffset) + detail::wordCount(_sBTRecordStride) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_hitObjectAttributes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordHitNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _instanceId);
+		detail::write(_sink, _primitiveId);
+		detail::write(_sink, _geometryIndex);
+		detail::write(_sink, _hitKind);
+		detail::write(_sink, _sBTRecordOffset);
+		detail::write(_sink, _sBTRecordStride);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _hitObjectAttributes);
+	}
+	template <class Sink>
+	void opHitObjectRecordHitWithIndexNV(Sink& _sink, Id _hitObject, Id _accelerationStructure, Id _instanceId, Id _primitiveId, Id _geometryIndex, Id _hitKind, Id _sBTRecordIndex, Id _origin, Id _tMin, Id _direction, Id _tMax, Id _hitObjectAttributes)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_accelerationStructure) + detail::wordCount(_instanceId) + detail::wordCount(_primitiveId) + detail::wordCount(_geometryIndex) + detail::wordCount(_hitKind) + detail::wordCount(_sBTRecordIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax) + detail::wordCount(_hitObjectAttributes);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordHitWithIndexNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _accelerationStructure);
+		detail::write(_sink, _instanceId);
+		detail::write(_sink, _primitiveId);
+		detail::write(_sink, _geometryIndex);
+		detail::write(_sink, _hitKind);
+		detail::write(_sink, _sBTRecordIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+		detail::write(_sink, _hitObjectAttributes);
+	}
+	template <class Sink>
+	void opHitObjectRecordMissNV(Sink& _sink, Id _hitObject, Id _sBTIndex, Id _origin, Id _tMin, Id _direction, Id _tMax)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_sBTIndex) + detail::wordCount(_origin) + detail::wordCount(_tMin) + detail::wordCount(_direction) + detail::wordCount(_tMax);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectRecordMissNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _sBTIndex);
+		detail::write(_sink, _origin);
+		detail::write(_sink, _tMin);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _tMax);
+	}
+	template <class Sink>
+	void opHitObjectExecuteShaderNV(Sink& _sink, Id _hitObject, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectExecuteShaderNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opHitObjectGetCurrentTimeNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetCurrentTimeNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetAttributesNV(Sink& _sink, Id _hitObject, Id _hitObjectAttribute)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_hitObjectAttribute);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetAttributesNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _hitObjectAttribute);
+	}
+	template <class Sink>
+	void opHitObjectGetHitKindNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetHitKindNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetPrimitiveIndexNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetPrimitiveIndexNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetGeometryIndexNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetGeometryIndexNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetInstanceIdNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetInstanceIdNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetInstanceCustomIndexNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetInstanceCustomIndexNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetWorldRayDirectionNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetWorldRayDirectionNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetWorldRayOriginNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetWorldRayOriginNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetRayTMaxNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetRayTMaxNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectGetRayTMinNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectGetRayTMinNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectIsEmptyNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectIsEmptyNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectIsHitNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectIsHitNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opHitObjectIsMissNV(Sink& _sink, Id _resultType, Id _result, Id _hitObject)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hitObject);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpHitObjectIsMissNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hitObject);
+	}
+	template <class Sink>
+	void opReorderThreadWithHitObjectNV(Sink& _sink, Id _hitObject, Optional<Id> _hint = {}, Optional<Id> _bits = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hitObject) + detail::wordCount(_hint) + detail::wordCount(_bits);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReorderThreadWithHitObjectNV));
+		detail::write(_sink, _hitObject);
+		detail::write(_sink, _hint);
+		detail::write(_sink, _bits);
+	}
+	template <class Sink>
+	void opReorderThreadWithHintNV(Sink& _sink, Id _hint, Id _bits)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_hint) + detail::wordCount(_bits);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReorderThreadWithHintNV));
+		detail::write(_sink, _hint);
+		detail::write(_sink, _bits);
+	}
+	template <class Sink>
+	void opTypeHitObjectNV(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeHitObjectNV));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opImageSampleFootprintNV(Sink& _sink, Id _resultType, Id _result, Id _sampledImage, Id _coordinate, Id _granularity, Id _coarse, Optional<ImageOperandsMask> _imageOperands = {}, Span<unsigned int> _imageOperandsParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sampledImage) + detail::wordCount(_coordinate) + detail::wordCount(_granularity) + detail::wordCount(_coarse) + detail::wordCount(_imageOperands) + detail::wordCount(_imageOperandsParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpImageSampleFootprintNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sampledImage);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _granularity);
+		detail::write(_sink, _coarse);
+		detail::write(_sink, _imageOperands);
+		detail::write(_sink, _imageOperandsParameters);
+	}
+	template <class Sink>
+	void opEmitMeshTasksEXT(Sink& _sink, Id _groupCountX, Id _groupCountY, Id _groupCountZ, Optional<Id> _payload = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_groupCountX) + detail::wordCount(_groupCountY) + detail::wordCount(_groupCountZ) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEmitMeshTasksEXT));
+		detail::write(_sink, _groupCountX);
+		detail::write(_sink, _groupCountY);
+		detail::write(_sink, _groupCountZ);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSetMeshOutputsEXT(Sink& _sink, Id _vertexCount, Id _primitiveCount)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_vertexCount) + detail::wordCount(_primitiveCount);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSetMeshOutputsEXT));
+		detail::write(_sink, _vertexCount);
+		detail::write(_sink, _primitiveCount);
+	}
+	template <class Sink>
+	void opGroupNonUniformPartitionNV(Sink& _sink, Id _resultType, Id _result, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupNonUniformPartitionNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opWritePackedPrimitiveIndices4x8NV(Sink& _sink, Id _indexOffset, Id _packedIndices)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_indexOffset) + detail::wordCount(_packedIndices);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpWritePackedPrimitiveIndices4x8NV));
+		detail::write(_sink, _indexOffset);
+		detail::write(_sink, _packedIndices);
+	}
+	template <class Sink>
+	void opReportIntersectionKHR(Sink& _sink, Id _resultType, Id _result, Id _hit, Id _hitKind)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_hit) + detail::wordCount(_hitKind);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReportIntersectionKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _hit);
+		detail::write(_sink, _hitKind);
+	}
+	template <class Sink>
+	void opIgnoreIntersectionNV(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIgnoreIntersectionNV));
+	}
+	template <class Sink>
+	void opTerminateRayNV(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTerminateRayNV));
+	}
+	template <class Sink>
+	void opTraceNV(Sink& _sink, Id _accel, Id _rayFlags, Id _cullMask, Id _sBTOffset, Id _sBTStride, Id _missIndex, Id _rayOrigin, Id _rayTmin, Id _rayDirection, Id _rayTmax, Id _payloadId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_accel) + detail::wordCount(_rayFlags) + detail::wordCount(_cullMask) + detail::wordCount(_sBTOffset) + detail::wordCount(_sBTStride) + detail::wordCount(_missIndex) + detail::wordCount(_rayOrigin) + detail::wordCount(_rayTmin) + detail::wordCount(_rayDirection) + detail::wordCount(_rayTmax) + detail::wordCount(_payloadId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTraceNV));
+		detail::write(_sink, _accel);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullMask);
+		detail::write(_sink, _sBTOffset);
+		detail::write(_sink, _sBTStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _rayOrigin);
+		detail::write(_sink, _rayTmin);
+		detail::write(_sink, _rayDirection);
+		detail::write(_sink, _rayTmax);
+		detail::write(_sink, _payloadId);
+	}
+	template <class Sink>
+	void opTraceMotionNV(Sink& _sink, Id _accel, Id _rayFlags, Id _cullMask, Id _sBTOffset, Id _sBTStride, Id _missIndex, Id _rayOrigin, Id _rayTmin, Id _rayDirection, Id _rayTmax, Id _time, Id _payloadId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_accel) + detail::wordCount(_rayFlags) + detail::wordCount(_cullMask) + detail::wordCount(_sBTOffset) + detail::wordCount(_sBTStride) + detail::wordCount(_missIndex) + detail::wordCount(_rayOrigin) + detail::wordCount(_rayTmin) + detail::wordCount(_rayDirection) + detail::wordCount(_rayTmax) + detail::wordCount(_time) + detail::wordCount(_payloadId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTraceMotionNV));
+		detail::write(_sink, _accel);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullMask);
+		detail::write(_sink, _sBTOffset);
+		detail::write(_sink, _sBTStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _rayOrigin);
+		detail::write(_sink, _rayTmin);
+		detail::write(_sink, _rayDirection);
+		detail::write(_sink, _rayTmax);
+		detail::write(_sink, _time);
+		detail::write(_sink, _payloadId);
+	}
+	template <class Sink>
+	void opTraceRayMotionNV(Sink& _sink, Id _accel, Id _rayFlags, Id _cullMask, Id _sBTOffset, Id _sBTStride, Id _missIndex, Id _rayOrigin, Id _rayTmin, Id _rayDirection, Id _rayTmax, Id _time, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_accel) + detail::wordCount(_rayFlags) + detail::wordCount(_cullMask) + detail::wordCount(_sBTOffset) + detail::wordCount(_sBTStride) + detail::wordCount(_missIndex) + detail::wordCount(_rayOrigin) + detail::wordCount(_rayTmin) + detail::wordCount(_rayDirection) + detail::wordCount(_rayTmax) + detail::wordCount(_time) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTraceRayMotionNV));
+		detail::write(_sink, _accel);
+		detail::write(_sink, _rayFlags);
+		detail::write(_sink, _cullMask);
+		detail::write(_sink, _sBTOffset);
+		detail::write(_sink, _sBTStride);
+		detail::write(_sink, _missIndex);
+		detail::write(_sink, _rayOrigin);
+		detail::write(_sink, _rayTmin);
+		detail::write(_sink, _rayDirection);
+		detail::write(_sink, _rayTmax);
+		detail::write(_sink, _time);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionTriangleVertexPositionsKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opTypeAccelerationStructureKHR(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAccelerationStructureKHR));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opExecuteCallableNV(Sink& _sink, Id _sBTIndex, Id _callableDataId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_sBTIndex) + detail::wordCount(_callableDataId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExecuteCallableNV));
+		detail::write(_sink, _sBTIndex);
+		detail::write(_sink, _callableDataId);
+	}
+	template <class Sink>
+	void opTypeCooperativeMatrixNV(Sink& _sink, Id _result, Id _componentType, Id _execution, Id _rows, Id _columns)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_componentType) + detail::wordCount(_execution) + detail::wordCount(_rows) + detail::wordCount(_columns);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeCooperativeMatrixNV));
+		detail::write(_sink, _result);
+		detail::write(_sink, _componentType);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _rows);
+		detail::write(_sink, _columns);
+	}
+	template <class Sink>
+	void opCooperativeMatrixLoadNV(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _stride, Id _columnMajor, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_stride) + detail::wordCount(_columnMajor) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixLoadNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _stride);
+		detail::write(_sink, _columnMajor);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+	}
+	template <class Sink>
+	void opCooperativeMatrixStoreNV(Sink& _sink, Id _pointer, Id _object, Id _stride, Id _columnMajor, Optional<MemoryAccessMask> _memoryAccess = {}, Span<unsigned int> _memoryAccessParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_pointer) + detail::wordCount(_object) + detail::wordCount(_stride) + detail::wordCount(_columnMajor) + detail::wordCount(_memoryAccess) + detail::wordCount(_memoryAccessParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixStoreNV));
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _object);
+		detail::write(_sink, _stride);
+		detail::write(_sink, _columnMajor);
+		detail::write(_sink, _memoryAccess);
+		detail::write(_sink, _memoryAccessParameters);
+	}
+	template <class Sink>
+	void opCooperativeMatrixMulAddNV(Sink& _sink, Id _resultType, Id _result, Id _a, Id _b, Id _c)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_b) + detail::wordCount(_c);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixMulAddNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _b);
+		detail::write(_sink, _c);
+	}
+	template <class Sink>
+	void opCooperativeMatrixLengthNV(Sink& _sink, Id _resultType, Id _result, Id _type)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCooperativeMatrixLengthNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _type);
+	}
+	template <class Sink>
+	void opBeginInvocationInterlockEXT(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpBeginInvocationInterlockEXT));
+	}
+	template <class Sink>
+	void opEndInvocationInterlockEXT(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpEndInvocationInterlockEXT));
+	}
+	template <class Sink>
+	void opDemoteToHelperInvocationEXT(Sink& _sink)
+	{
+		const unsigned int wordCount = 1u;
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDemoteToHelperInvocationEXT));
+	}
+	template <class Sink>
+	void opIsHelperInvocationEXT(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIsHelperInvocationEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opConvertUToImageNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToImageNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opConvertUToSamplerNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToSamplerNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opConvertImageToUNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertImageToUNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opConvertSamplerToUNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertSamplerToUNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opConvertUToSampledImageNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertUToSampledImageNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opConvertSampledImageToUNV(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertSampledImageToUNV));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opSamplerImageAddressingModeNV(Sink& _sink, unsigned int _bitWidth)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_bitWidth);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSamplerImageAddressingModeNV));
+		detail::write(_sink, _bitWidth);
+	}
+	template <class Sink>
+	void opSubgroupShuffleINTEL(Sink& _sink, Id _resultType, Id _result, Id _data, Id _invocationId)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_data) + detail::wordCount(_invocationId);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupShuffleINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _data);
+		detail::write(_sink, _invocationId);
+	}
+	template <class Sink>
+	void opSubgroupShuffleDownINTEL(Sink& _sink, Id _resultType, Id _result, Id _current, Id _next, Id _delta)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_current) + detail::wordCount(_next) + detail::wordCount(_delta);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupShuffleDownINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _current);
+		detail::write(_sink, _next);
+		detail::write(_sink, _delta);
+	}
+	template <class Sink>
+	void opSubgroupShuffleUpINTEL(Sink& _sink, Id _resultType, Id _result, Id _previous, Id _current, Id _delta)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_previous) + detail::wordCount(_current) + detail::wordCount(_delta);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupShuffleUpINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _previous);
+		detail::write(_sink, _current);
+		detail::write(_sink, _delta);
+	}
+	template <class Sink>
+	void opSubgroupShuffleXorINTEL(Sink& _sink, Id _resultType, Id _result, Id _data, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_data) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupShuffleXorINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _data);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opSubgroupBlockReadINTEL(Sink& _sink, Id _resultType, Id _result, Id _ptr)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_ptr);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupBlockReadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _ptr);
+	}
+	template <class Sink>
+	void opSubgroupBlockWriteINTEL(Sink& _sink, Id _ptr, Id _data)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_ptr) + detail::wordCount(_data);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupBlockWriteINTEL));
+		detail::write(_sink, _ptr);
+		detail::write(_sink, _data);
+	}
+	template <class Sink>
+	void opSubgroupImageBlockReadINTEL(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupImageBlockReadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+	}
+	template <class Sink>
+	void opSubgroupImageBlockWriteINTEL(Sink& _sink, Id _image, Id _coordinate, Id _data)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_data);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupImageBlockWriteINTEL));
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _data);
+	}
+	template <class Sink>
+	void opSubgroupImageMediaBlockReadINTEL(Sink& _sink, Id _resultType, Id _result, Id _image, Id _coordinate, Id _width, Id _height)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_width) + detail::wordCount(_height);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupImageMediaBlockReadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _width);
+		detail::write(_sink, _height);
+	}
+	template <class Sink>
+	void opSubgroupImageMediaBlockWriteINTEL(Sink& _sink, Id _image, Id _coordinate, Id _width, Id _height, Id _data)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_image) + detail::wordCount(_coordinate) + detail::wordCount(_width) + detail::wordCount(_height) + detail::wordCount(_data);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupImageMediaBlockWriteINTEL));
+		detail::write(_sink, _image);
+		detail::write(_sink, _coordinate);
+		detail::write(_sink, _width);
+		detail::write(_sink, _height);
+		detail::write(_sink, _data);
+	}
+	template <class Sink>
+	void opUCountLeadingZerosINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUCountLeadingZerosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opUCountTrailingZerosINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUCountTrailingZerosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand);
+	}
+	template <class Sink>
+	void opAbsISubINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAbsISubINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opAbsUSubINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAbsUSubINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opIAddSatINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIAddSatINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUAddSatINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUAddSatINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opIAverageINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIAverageINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUAverageINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUAverageINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opIAverageRoundedINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIAverageRoundedINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUAverageRoundedINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUAverageRoundedINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opISubSatINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpISubSatINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUSubSatINTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUSubSatINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opIMul32x16INTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpIMul32x16INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opUMul32x16INTEL(Sink& _sink, Id _resultType, Id _result, Id _operand1, Id _operand2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1) + detail::wordCount(_operand2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpUMul32x16INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+		detail::write(_sink, _operand2);
+	}
+	template <class Sink>
+	void opConstantFunctionPointerINTEL(Sink& _sink, Id _resultType, Id _result, Id _function)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_function);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantFunctionPointerINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _function);
+	}
+	template <class Sink>
+	void opFunctionPointerCallINTEL(Sink& _sink, Id _resultType, Id _result, Span<Id> _operand1 = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_operand1);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFunctionPointerCallINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _operand1);
+	}
+	template <class Sink>
+	void opAsmTargetINTEL(Sink& _sink, Id _resultType, Id _result, const char* _asmTarget)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_asmTarget);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAsmTargetINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _asmTarget);
+	}
+	template <class Sink>
+	void opAsmINTEL(Sink& _sink, Id _resultType, Id _result, Id _asmType, Id _target, const char* _asmInstructions, const char* _constraints)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_asmType) + detail::wordCount(_target) + detail::wordCount(_asmInstructions) + detail::wordCount(_constraints);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAsmINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _asmType);
+		detail::write(_sink, _target);
+		detail::write(_sink, _asmInstructions);
+		detail::write(_sink, _constraints);
+	}
+	template <class Sink>
+	void opAsmCallINTEL(Sink& _sink, Id _resultType, Id _result, Id _asm, Span<Id> _argument0 = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_asm) + detail::wordCount(_argument0);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAsmCallINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _asm);
+		detail::write(_sink, _argument0);
+	}
+	template <class Sink>
+	void opAtomicFMinEXT(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicFMinEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAtomicFMaxEXT(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicFMaxEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opAssumeTrueKHR(Sink& _sink, Id _condition)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_condition);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAssumeTrueKHR));
+		detail::write(_sink, _condition);
+	}
+	template <class Sink>
+	void opExpectKHR(Sink& _sink, Id _resultType, Id _result, Id _value, Id _expectedValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_value) + detail::wordCount(_expectedValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpExpectKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _value);
+		detail::write(_sink, _expectedValue);
+	}
+	template <class Sink>
+	void opDecorateString(Sink& _sink, Id _target, Decoration _decoration, Span<unsigned int> _decorationParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_target) + detail::wordCount(_decoration) + detail::wordCount(_decorationParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpDecorateString));
+		detail::write(_sink, _target);
+		detail::write(_sink, _decoration);
+		detail::write(_sink, _decorationParameters);
+	}
+	template <class Sink>
+	void opMemberDecorateString(Sink& _sink, Id _structType, unsigned int _member, Decoration _decoration, Span<unsigned int> _decorationParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_structType) + detail::wordCount(_member) + detail::wordCount(_decoration) + detail::wordCount(_decorationParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpMemberDecorateString));
+		detail::write(_sink, _structType);
+		detail::write(_sink, _member);
+		detail::write(_sink, _decoration);
+		detail::write(_sink, _decorationParameters);
+	}
+	template <class Sink>
+	void opVmeImageINTEL(Sink& _sink, Id _resultType, Id _result, Id _imageType, Id _sampler)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_imageType) + detail::wordCount(_sampler);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVmeImageINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _imageType);
+		detail::write(_sink, _sampler);
+	}
+	template <class Sink>
+	void opTypeVmeImageINTEL(Sink& _sink, Id _result, Id _imageType)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_imageType);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeVmeImageINTEL));
+		detail::write(_sink, _result);
+		detail::write(_sink, _imageType);
+	}
+	template <class Sink>
+	void opTypeAvcImePayloadINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImePayloadINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcRefPayloadINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcRefPayloadINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcSicPayloadINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcSicPayloadINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcMcePayloadINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcMcePayloadINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcMceResultINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcMceResultINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcImeResultINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImeResultINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcImeResultSingleReferenceStreamoutINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcImeResultDualReferenceStreamoutINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcImeSingleReferenceStreaminINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImeSingleReferenceStreaminINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcImeDualReferenceStreaminINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcImeDualReferenceStreaminINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcRefResultINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcRefResultINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opTypeAvcSicResultINTEL(Sink& _sink, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeAvcSicResultINTEL));
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _referenceBasePenalty, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_referenceBasePenalty) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _referenceBasePenalty);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultInterShapePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetInterShapePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedShapePenalty, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedShapePenalty) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedShapePenalty);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetInterDirectionPenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _directionCost, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_directionCost) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _directionCost);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetMotionVectorCostFunctionINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedCostCenterDelta, Id _packedCostTable, Id _costPrecision, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedCostCenterDelta) + detail::wordCount(_packedCostTable) + detail::wordCount(_costPrecision) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedCostCenterDelta);
+		detail::write(_sink, _packedCostTable);
+		detail::write(_sink, _costPrecision);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _sliceType, Id _qp)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sliceType) + detail::wordCount(_qp);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sliceType);
+		detail::write(_sink, _qp);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetAcOnlyHaarINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL(Sink& _sink, Id _resultType, Id _result, Id _sourceFieldPolarity, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_sourceFieldPolarity) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _sourceFieldPolarity);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL(Sink& _sink, Id _resultType, Id _result, Id _referenceFieldPolarity, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_referenceFieldPolarity) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _referenceFieldPolarity);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL(Sink& _sink, Id _resultType, Id _result, Id _forwardReferenceFieldPolarity, Id _backwardReferenceFieldPolarity, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_forwardReferenceFieldPolarity) + detail::wordCount(_backwardReferenceFieldPolarity) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _forwardReferenceFieldPolarity);
+		detail::write(_sink, _backwardReferenceFieldPolarity);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToImePayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToImePayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToImeResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToImeResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToRefPayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToRefPayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToRefResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToRefResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToSicPayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToSicPayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceConvertToSicResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceConvertToSicResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetMotionVectorsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetMotionVectorsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterDistortionsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterDistortionsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetBestInterDistortionsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterMajorShapeINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterMajorShapeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterMinorShapeINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterMinorShapeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterDirectionsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterDirectionsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterMotionVectorCountINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterReferenceIdsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedReferenceIds, Id _packedReferenceParameterFieldPolarities, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedReferenceIds) + detail::wordCount(_packedReferenceParameterFieldPolarities) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedReferenceIds);
+		detail::write(_sink, _packedReferenceParameterFieldPolarities);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeInitializeINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcCoord, Id _partitionMask, Id _sADAdjustment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcCoord) + detail::wordCount(_partitionMask) + detail::wordCount(_sADAdjustment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeInitializeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcCoord);
+		detail::write(_sink, _partitionMask);
+		detail::write(_sink, _sADAdjustment);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetSingleReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _refOffset, Id _searchWindowConfig, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_refOffset) + detail::wordCount(_searchWindowConfig) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetSingleReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _refOffset);
+		detail::write(_sink, _searchWindowConfig);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetDualReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _fwdRefOffset, Id _bwdRefOffset, Id _idSearchWindowConfig, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_fwdRefOffset) + detail::wordCount(_bwdRefOffset) + detail::wordCount(_idSearchWindowConfig) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetDualReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _fwdRefOffset);
+		detail::write(_sink, _bwdRefOffset);
+		detail::write(_sink, _idSearchWindowConfig);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeRefWindowSizeINTEL(Sink& _sink, Id _resultType, Id _result, Id _searchWindowConfig, Id _dualRef)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_searchWindowConfig) + detail::wordCount(_dualRef);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeRefWindowSizeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _searchWindowConfig);
+		detail::write(_sink, _dualRef);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeAdjustRefOffsetINTEL(Sink& _sink, Id _resultType, Id _result, Id _refOffset, Id _srcCoord, Id _refWindowSize, Id _imageSize)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_refOffset) + detail::wordCount(_srcCoord) + detail::wordCount(_refWindowSize) + detail::wordCount(_imageSize);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeAdjustRefOffsetINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _refOffset);
+		detail::write(_sink, _srcCoord);
+		detail::write(_sink, _refWindowSize);
+		detail::write(_sink, _imageSize);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeConvertToMcePayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeConvertToMcePayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetMaxMotionVectorCountINTEL(Sink& _sink, Id _resultType, Id _result, Id _maxMotionVectorCount, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_maxMotionVectorCount) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _maxMotionVectorCount);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetUnidirectionalMixDisableINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL(Sink& _sink, Id _resultType, Id _result, Id _threshold, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_threshold) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _threshold);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeSetWeightedSadINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedSadWeights, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedSadWeights) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeSetWeightedSadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedSadWeights);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithSingleReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithDualReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload, Id _streaminComponents)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload) + detail::wordCount(_streaminComponents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _streaminComponents);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload, Id _streaminComponents)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload) + detail::wordCount(_streaminComponents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _streaminComponents);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload, Id _streaminComponents)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload) + detail::wordCount(_streaminComponents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _streaminComponents);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload, Id _streaminComponents)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload) + detail::wordCount(_streaminComponents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _streaminComponents);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeConvertToMceResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeConvertToMceResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetSingleReferenceStreaminINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetDualReferenceStreaminINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeStripSingleReferenceStreamoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeStripDualReferenceStreamoutINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape, Id _direction)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape) + detail::wordCount(_direction);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+		detail::write(_sink, _direction);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape, Id _direction)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape) + detail::wordCount(_direction);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+		detail::write(_sink, _direction);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload, Id _majorShape, Id _direction)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload) + detail::wordCount(_majorShape) + detail::wordCount(_direction);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+		detail::write(_sink, _majorShape);
+		detail::write(_sink, _direction);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetBorderReachedINTEL(Sink& _sink, Id _resultType, Id _result, Id _imageSelect, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_imageSelect) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetBorderReachedINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _imageSelect);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetTruncatedSearchIndicationINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcFmeInitializeINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcCoord, Id _motionVectors, Id _majorShapes, Id _minorShapes, Id _direction, Id _pixelResolution, Id _sadAdjustment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcCoord) + detail::wordCount(_motionVectors) + detail::wordCount(_majorShapes) + detail::wordCount(_minorShapes) + detail::wordCount(_direction) + detail::wordCount(_pixelResolution) + detail::wordCount(_sadAdjustment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcFmeInitializeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcCoord);
+		detail::write(_sink, _motionVectors);
+		detail::write(_sink, _majorShapes);
+		detail::write(_sink, _minorShapes);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _pixelResolution);
+		detail::write(_sink, _sadAdjustment);
+	}
+	template <class Sink>
+	void opSubgroupAvcBmeInitializeINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcCoord, Id _motionVectors, Id _majorShapes, Id _minorShapes, Id _direction, Id _pixelResolution, Id _bidirectionalWeight, Id _sadAdjustment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcCoord) + detail::wordCount(_motionVectors) + detail::wordCount(_majorShapes) + detail::wordCount(_minorShapes) + detail::wordCount(_direction) + detail::wordCount(_pixelResolution) + detail::wordCount(_bidirectionalWeight) + detail::wordCount(_sadAdjustment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcBmeInitializeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcCoord);
+		detail::write(_sink, _motionVectors);
+		detail::write(_sink, _majorShapes);
+		detail::write(_sink, _minorShapes);
+		detail::write(_sink, _direction);
+		detail::write(_sink, _pixelResolution);
+		detail::write(_sink, _bidirectionalWeight);
+		detail::write(_sink, _sadAdjustment);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefConvertToMcePayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefConvertToMcePayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefSetBidirectionalMixDisableINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefSetBilinearFilterEnableINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefEvaluateWithSingleReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefEvaluateWithDualReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefEvaluateWithMultiReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _packedReferenceIds, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_packedReferenceIds) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _packedReferenceIds);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _packedReferenceIds, Id _packedReferenceFieldPolarities, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_packedReferenceIds) + detail::wordCount(_packedReferenceFieldPolarities) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _packedReferenceIds);
+		detail::write(_sink, _packedReferenceFieldPolarities);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcRefConvertToMceResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcRefConvertToMceResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicInitializeINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcCoord)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcCoord);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicInitializeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcCoord);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicConfigureSkcINTEL(Sink& _sink, Id _resultType, Id _result, Id _skipBlockPartitionType, Id _skipMotionVectorMask, Id _motionVectors, Id _bidirectionalWeight, Id _sadAdjustment, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_skipBlockPartitionType) + detail::wordCount(_skipMotionVectorMask) + detail::wordCount(_motionVectors) + detail::wordCount(_bidirectionalWeight) + detail::wordCount(_sadAdjustment) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicConfigureSkcINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _skipBlockPartitionType);
+		detail::write(_sink, _skipMotionVectorMask);
+		detail::write(_sink, _motionVectors);
+		detail::write(_sink, _bidirectionalWeight);
+		detail::write(_sink, _sadAdjustment);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicConfigureIpeLumaINTEL(Sink& _sink, Id _resultType, Id _result, Id _lumaIntraPartitionMask, Id _intraNeighbourAvailabilty, Id _leftEdgeLumaPixels, Id _upperLeftCornerLumaPixel, Id _upperEdgeLumaPixels, Id _upperRightEdgeLumaPixels, Id _sadAdjustment, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_lumaIntraPartitionMask) + detail::wordCount(_intraNeighbourAvailabilty) + detail::wordCount(_leftEdgeLumaPixels) + detail::wordCount(_upperLeftCornerLumaPixel) + detail::wordCount(_upperEdgeLumaPixels) + detail::wordCount(_upperRightEdgeLumaPixels) + detail::wordCount(_sadAdjustment) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicConfigureIpeLumaINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _lumaIntraPartitionMask);
+		detail::write(_sink, _intraNeighbourAvailabilty);
+		detail::write(_sink, _leftEdgeLumaPixels);
+		detail::write(_sink, _upperLeftCornerLumaPixel);
+		detail::write(_sink, _upperEdgeLumaPixels);
+		detail::write(_sink, _upperRightEdgeLumaPixels);
+		detail::write(_sink, _sadAdjustment);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicConfigureIpeLumaChromaINTEL(Sink& _sink, Id _resultType, Id _result, Id _lumaIntraPartitionMask, Id _intraNeighbourAvailabilty, Id _leftEdgeLumaPixels, Id _upperLeftCornerLumaPixel, Id _upperEdgeLumaPixels, Id _upperRightEdgeLumaPixels, Id _leftEdgeChromaPixels, Id _upperLeftCornerChromaPixel, Id _upperEdgeChromaPixels, Id _sadAdjustment, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_lumaIntraPartitionMask) + detail::wordCount(_intraNeighbourAvailabilty) + detail::wordCount(_leftEdgeLumaPixels) + detail::wordCount(_upperLeftCornerLumaPixel) + detail::wordCount(_upperEdgeLumaPixels) + detail::wordCount(_upperRightEdgeLumaPixels) + detail::wordCount(_leftEdgeChromaPixels) + detail::wordCount(_upperLeftCornerChromaPixel) + detail::wordCount(_upperEdgeChromaPixels) + detail::wordCount(_sadAdjustment) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _lumaIntraPartitionMask);
+		detail::write(_sink, _intraNeighbourAvailabilty);
+		detail::write(_sink, _leftEdgeLumaPixels);
+		detail::write(_sink, _upperLeftCornerLumaPixel);
+		detail::write(_sink, _upperEdgeLumaPixels);
+		detail::write(_sink, _upperRightEdgeLumaPixels);
+		detail::write(_sink, _leftEdgeChromaPixels);
+		detail::write(_sink, _upperLeftCornerChromaPixel);
+		detail::write(_sink, _upperEdgeChromaPixels);
+		detail::write(_sink, _sadAdjustment);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetMotionVectorMaskINTEL(Sink& _sink, Id _resultType, Id _result, Id _skipBlockPartitionType, Id _direction)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_skipBlockPartitionType) + detail::wordCount(_direction);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _skipBlockPartitionType);
+		detail::write(_sink, _direction);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicConvertToMcePayloadINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicConvertToMcePayloadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetIntraLumaShapePenaltyINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedShapePenalty, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedShapePenalty) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedShapePenalty);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL(Sink& _sink, Id _resultType, Id _result, Id _lumaModePenalty, Id _lumaPackedNeighborModes, Id _lumaPackedNonDcPenalty, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_lumaModePenalty) + detail::wordCount(_lumaPackedNeighborModes) + detail::wordCount(_lumaPackedNonDcPenalty) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _lumaModePenalty);
+		detail::write(_sink, _lumaPackedNeighborModes);
+		detail::write(_sink, _lumaPackedNonDcPenalty);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL(Sink& _sink, Id _resultType, Id _result, Id _chromaModeBasePenalty, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_chromaModeBasePenalty) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _chromaModeBasePenalty);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetBilinearFilterEnableINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetSkcForwardTransformEnableINTEL(Sink& _sink, Id _resultType, Id _result, Id _packedSadCoefficients, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packedSadCoefficients) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packedSadCoefficients);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicSetBlockBasedRawSkipSadINTEL(Sink& _sink, Id _resultType, Id _result, Id _blockBasedSkipType, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_blockBasedSkipType) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _blockBasedSkipType);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicEvaluateIpeINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicEvaluateIpeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicEvaluateWithSingleReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _refImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_refImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _refImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicEvaluateWithDualReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _fwdRefImage, Id _bwdRefImage, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_fwdRefImage) + detail::wordCount(_bwdRefImage) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _fwdRefImage);
+		detail::write(_sink, _bwdRefImage);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicEvaluateWithMultiReferenceINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _packedReferenceIds, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_packedReferenceIds) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _packedReferenceIds);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL(Sink& _sink, Id _resultType, Id _result, Id _srcImage, Id _packedReferenceIds, Id _packedReferenceFieldPolarities, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_srcImage) + detail::wordCount(_packedReferenceIds) + detail::wordCount(_packedReferenceFieldPolarities) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _srcImage);
+		detail::write(_sink, _packedReferenceIds);
+		detail::write(_sink, _packedReferenceFieldPolarities);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicConvertToMceResultINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicConvertToMceResultINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetIpeLumaShapeINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetBestIpeLumaDistortionINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetBestIpeChromaDistortionINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetPackedIpeLumaModesINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetIpeChromaModeINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetIpeChromaModeINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opSubgroupAvcSicGetInterRawSadsINTEL(Sink& _sink, Id _resultType, Id _result, Id _payload)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_payload);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSubgroupAvcSicGetInterRawSadsINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _payload);
+	}
+	template <class Sink>
+	void opVariableLengthArrayINTEL(Sink& _sink, Id _resultType, Id _result, Id _lenght)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_lenght);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpVariableLengthArrayINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _lenght);
+	}
+	template <class Sink>
+	void opSaveMemoryINTEL(Sink& _sink, Id _resultType, Id _result)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSaveMemoryINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+	}
+	template <class Sink>
+	void opRestoreMemoryINTEL(Sink& _sink, Id _ptr)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_ptr);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRestoreMemoryINTEL));
+		detail::write(_sink, _ptr);
+	}
+	template <class Sink>
+	void opArbitraryFloatSinCosPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _fromSign, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_fromSign) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSinCosPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _fromSign);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCastINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCastINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCastFromIntINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _mout, unsigned int _fromSign, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_mout) + detail::wordCount(_fromSign) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCastFromIntINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _fromSign);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCastToIntINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCastToIntINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatAddINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatAddINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatSubINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSubINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatMulINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatMulINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatDivINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatDivINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatGTINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatGTINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+	}
+	template <class Sink>
+	void opArbitraryFloatGEINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatGEINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+	}
+	template <class Sink>
+	void opArbitraryFloatLTINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLTINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+	}
+	template <class Sink>
+	void opArbitraryFloatLEINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLEINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+	}
+	template <class Sink>
+	void opArbitraryFloatEQINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatEQINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+	}
+	template <class Sink>
+	void opArbitraryFloatRecipINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatRecipINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatRSqrtINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatRSqrtINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCbrtINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCbrtINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatHypotINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatHypotINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatSqrtINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSqrtINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatLogINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLogINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatLog2INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLog2INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatLog10INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLog10INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatLog1pINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatLog1pINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatExpINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatExpINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatExp2INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatExp2INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatExp10INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatExp10INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatExpm1INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatExpm1INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatSinINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSinINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCosINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatSinCosINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSinCosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatSinPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatSinPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatCosPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatCosPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatASinINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatASinINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatASinPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatASinPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatACosINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatACosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatACosPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatACosPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatATanINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatATanINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatATanPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatATanPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatATan2INTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatATan2INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatPowINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatPowINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatPowRINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _m2, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_m2) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatPowRINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _m2);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opArbitraryFloatPowNINTEL(Sink& _sink, Id _resultType, Id _result, Id _a, unsigned int _m1, Id _b, unsigned int _mout, unsigned int _enableSubnormals, unsigned int _roundingMode, unsigned int _roundingAccuracy)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_a) + detail::wordCount(_m1) + detail::wordCount(_b) + detail::wordCount(_mout) + detail::wordCount(_enableSubnormals) + detail::wordCount(_roundingMode) + detail::wordCount(_roundingAccuracy);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpArbitraryFloatPowNINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _a);
+		detail::write(_sink, _m1);
+		detail::write(_sink, _b);
+		detail::write(_sink, _mout);
+		detail::write(_sink, _enableSubnormals);
+		detail::write(_sink, _roundingMode);
+		detail::write(_sink, _roundingAccuracy);
+	}
+	template <class Sink>
+	void opLoopControlINTEL(Sink& _sink, Span<unsigned int> _loopControlParameters = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_loopControlParameters);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpLoopControlINTEL));
+		detail::write(_sink, _loopControlParameters);
+	}
+	template <class Sink>
+	void opAliasDomainDeclINTEL(Sink& _sink, Id _result, Optional<Id> _name = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAliasDomainDeclINTEL));
+		detail::write(_sink, _result);
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opAliasScopeDeclINTEL(Sink& _sink, Id _result, Id _aliasDomain, Optional<Id> _name = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_aliasDomain) + detail::wordCount(_name);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAliasScopeDeclINTEL));
+		detail::write(_sink, _result);
+		detail::write(_sink, _aliasDomain);
+		detail::write(_sink, _name);
+	}
+	template <class Sink>
+	void opAliasScopeListDeclINTEL(Sink& _sink, Id _result, Span<Id> _aliasScope1 = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_aliasScope1);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAliasScopeListDeclINTEL));
+		detail::write(_sink, _result);
+		detail::write(_sink, _aliasScope1);
+	}
+	template <class Sink>
+	void opFixedSqrtINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedSqrtINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedRecipINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedRecipINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedRsqrtINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedRsqrtINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedSinINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedSinINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedCosINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedCosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedSinCosINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedSinCosINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedSinPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedSinPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedCosPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedCosPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedSinCosPiINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedSinCosPiINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedLogINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedLogINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opFixedExpINTEL(Sink& _sink, Id _resultType, Id _result, Id _inputType, Id _input, unsigned int _s, unsigned int _i, unsigned int _rI, unsigned int _q, unsigned int _o)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_inputType) + detail::wordCount(_input) + detail::wordCount(_s) + detail::wordCount(_i) + detail::wordCount(_rI) + detail::wordCount(_q) + detail::wordCount(_o);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFixedExpINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _inputType);
+		detail::write(_sink, _input);
+		detail::write(_sink, _s);
+		detail::write(_sink, _i);
+		detail::write(_sink, _rI);
+		detail::write(_sink, _q);
+		detail::write(_sink, _o);
+	}
+	template <class Sink>
+	void opPtrCastToCrossWorkgroupINTEL(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpPtrCastToCrossWorkgroupINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opCrossWorkgroupCastToPtrINTEL(Sink& _sink, Id _resultType, Id _result, Id _pointer)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpCrossWorkgroupCastToPtrINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+	}
+	template <class Sink>
+	void opReadPipeBlockingINTEL(Sink& _sink, Id _resultType, Id _result, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpReadPipeBlockingINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opWritePipeBlockingINTEL(Sink& _sink, Id _resultType, Id _result, Id _packetSize, Id _packetAlignment)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_packetSize) + detail::wordCount(_packetAlignment);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpWritePipeBlockingINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _packetSize);
+		detail::write(_sink, _packetAlignment);
+	}
+	template <class Sink>
+	void opFPGARegINTEL(Sink& _sink, Id _resultType, Id _result, Id _result2, Id _input)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_result2) + detail::wordCount(_input);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpFPGARegINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _result2);
+		detail::write(_sink, _input);
+	}
+	template <class Sink>
+	void opRayQueryGetRayTMinKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetRayTMinKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetRayFlagsKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetRayFlagsKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionTKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionTKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionInstanceCustomIndexKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionInstanceIdKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionInstanceIdKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionGeometryIndexKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionGeometryIndexKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionPrimitiveIndexKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionPrimitiveIndexKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionBarycentricsKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionBarycentricsKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionFrontFaceKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionFrontFaceKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionCandidateAABBOpaqueKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionObjectRayDirectionKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionObjectRayDirectionKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionObjectRayOriginKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionObjectRayOriginKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetWorldRayDirectionKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetWorldRayDirectionKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetWorldRayOriginKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetWorldRayOriginKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionObjectToWorldKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionObjectToWorldKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opRayQueryGetIntersectionWorldToObjectKHR(Sink& _sink, Id _resultType, Id _result, Id _rayQuery, Id _intersection)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_rayQuery) + detail::wordCount(_intersection);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpRayQueryGetIntersectionWorldToObjectKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _rayQuery);
+		detail::write(_sink, _intersection);
+	}
+	template <class Sink>
+	void opAtomicFAddEXT(Sink& _sink, Id _resultType, Id _result, Id _pointer, Id _memory, Id _semantics, Id _value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_pointer) + detail::wordCount(_memory) + detail::wordCount(_semantics) + detail::wordCount(_value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpAtomicFAddEXT));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _pointer);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+		detail::write(_sink, _value);
+	}
+	template <class Sink>
+	void opTypeBufferSurfaceINTEL(Sink& _sink, Id _result, AccessQualifier _accessQualifier)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_result) + detail::wordCount(_accessQualifier);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeBufferSurfaceINTEL));
+		detail::write(_sink, _result);
+		detail::write(_sink, _accessQualifier);
+	}
+	template <class Sink>
+	void opTypeStructContinuedINTEL(Sink& _sink, Span<Id> _member0Type = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_member0Type);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpTypeStructContinuedINTEL));
+		detail::write(_sink, _member0Type);
+	}
+	template <class Sink>
+	void opConstantCompositeContinuedINTEL(Sink& _sink, Span<Id> _constituents = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_constituents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConstantCompositeContinuedINTEL));
+		detail::write(_sink, _constituents);
+	}
+	template <class Sink>
+	void opSpecConstantCompositeContinuedINTEL(Sink& _sink, Span<Id> _constituents = {})
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_constituents);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpSpecConstantCompositeContinuedINTEL));
+		detail::write(_sink, _constituents);
+	}
+	template <class Sink>
+	void opConvertFToBF16INTEL(Sink& _sink, Id _resultType, Id _result, Id _floatValue)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_floatValue);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertFToBF16INTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _floatValue);
+	}
+	template <class Sink>
+	void opConvertBF16ToFINTEL(Sink& _sink, Id _resultType, Id _result, Id _bFloat16Value)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_bFloat16Value);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpConvertBF16ToFINTEL));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _bFloat16Value);
+	}
+	template <class Sink>
+	void opControlBarrierArriveINTEL(Sink& _sink, Id _execution, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpControlBarrierArriveINTEL));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opControlBarrierWaitINTEL(Sink& _sink, Id _execution, Id _memory, Id _semantics)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_execution) + detail::wordCount(_memory) + detail::wordCount(_semantics);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpControlBarrierWaitINTEL));
+		detail::write(_sink, _execution);
+		detail::write(_sink, _memory);
+		detail::write(_sink, _semantics);
+	}
+	template <class Sink>
+	void opGroupIMulKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupIMulKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupFMulKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupFMulKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupBitwiseAndKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupBitwiseAndKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupBitwiseOrKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupBitwiseOrKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupBitwiseXorKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupBitwiseXorKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupLogicalAndKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupLogicalAndKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupLogicalOrKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupLogicalOrKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+	template <class Sink>
+	void opGroupLogicalXorKHR(Sink& _sink, Id _resultType, Id _result, Id _execution, GroupOperation _operation, Id _x)
+	{
+		const unsigned int wordCount = 1u + detail::wordCount(_resultType) + detail::wordCount(_result) + detail::wordCount(_execution) + detail::wordCount(_operation) + detail::wordCount(_x);
+		_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::OpGroupLogicalXorKHR));
+		detail::write(_sink, _resultType);
+		detail::write(_sink, _result);
+		detail::write(_sink, _execution);
+		detail::write(_sink, _operation);
+		detail::write(_sink, _x);
+	}
+} // spvgentwo::spv
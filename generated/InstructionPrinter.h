@@ -0,0 +1,3898 @@
+// Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+// extinst.glsl.std.450.grammar.json sha256:3bcf78c13b71a9ebba51e890c55f41a5e0f47ba283bc7c08fd7e1344ea5f47a6 version 100 revision 2
+// extinst.opencl.std.100.grammar.json sha256:b6be3248af8e6150332ec5d9df2e578b364d588c762533831b7550f607a73ff8 version 100 revision 2
+#pragma once
+
+// GetOperandLayout is only declared with SPVGENTWO_OPERAND_LAYOUT, include this header before any other include of Spv.h
+#ifndef SPVGENTWO_OPERAND_LAYOUT
+#define SPVGENTWO_OPERAND_LAYOUT
+#endif
+#include "Spv.h"
+
+namespace spvgentwo
+{
+	static constexpr unsigned int ExtInstSetCount = 2u;
+	struct PrinterState
+	{
+		enum class NumberKind : unsigned char { Unsigned, Signed, Float };
+		struct NumberType
+		{
+			unsigned int id;
+			unsigned int width;
+			NumberKind kind;
+		};
+		static constexpr unsigned int MaxNumberTypes = 32u;
+
+		unsigned int extInstSets[ExtInstSetCount + 1u] = {};
+		// OpTypeInt and OpTypeFloat results, constants of types beyond MaxNumberTypes are printed as unsigned integers
+		NumberType numberTypes[MaxNumberTypes] = {};
+		unsigned int numberTypeCount = 0u;
+	};
+	namespace detail
+	{
+		using namespace spv;
+		static constexpr const char* ExtInstSetNames[] =
+		{
+			"GLSL.std.450",
+			"OpenCL.std",
+			nullptr
+		};
+		struct PrintCursor
+		{
+			const unsigned int* it;
+			const unsigned int* end;
+			unsigned int resultType = 0u; // id of the instruction's result type, 0 if it has none
+			bool empty() const { return it == end; }
+		};
+		template <class Sink>
+		void printUnsigned(unsigned long long _value, Sink& _sink)
+		{
+			char buffer[21] = {};
+			char* pos = buffer + 20;
+			do { *--pos = static_cast<char>('0' + _value % 10u); _value /= 10u; } while (_value != 0u);
+			_sink(pos);
+		}
+		template <class Sink>
+		void printHex(unsigned int _value, Sink& _sink)
+		{
+			char buffer[11] = { '0', 'x' };
+			for (int i = 0; i < 8; ++i) { buffer[9 - i] = "0123456789abcdef"[(_value >> (i * 4)) & 0xfu]; }
+			_sink(buffer);
+		}
+		template <class Sink>
+		void printSigned(long long _value, Sink& _sink)
+		{
+			if (_value < 0) { _sink("-"); printUnsigned(0ull - static_cast<unsigned long long>(_value), _sink); }
+			else printUnsigned(static_cast<unsigned long long>(_value), _sink);
+		}
+		template <class Sink>
+		void printHexFloat(unsigned long long _bits, unsigned int _exponentBits, unsigned int _fractionBits, Sink& _sink)
+		{
+			const unsigned int nibbles = (_fractionBits + 3u) / 4u;
+			const unsigned long long exponent = (_bits >> _fractionBits) & ((1ull << _exponentBits) - 1u);
+			unsigned long long fraction = (_bits & ((1ull << _fractionBits) - 1u)) << (nibbles * 4u - _fractionBits);
+			const bool zero = exponent == 0u && fraction == 0u;
+			int power = zero ? 0 : static_cast<int>(exponent) - ((1 << (_exponentBits - 1u)) - 1);
+			if (exponent == 0u && !zero)
+			{
+				// subnormal, shift the leading 1 out so it becomes implicit
+				while ((fraction & (1ull << (nibbles * 4u - 1u))) == 0u) { fraction <<= 1u; --power; }
+				fraction = (fraction << 1u) & ((1ull << (nibbles * 4u)) - 1u);
+			}
+			unsigned int digits = nibbles;
+			while (digits != 0u && (fraction & 0xfu) == 0u) { fraction >>= 4u; --digits; }
+			char buffer[24] = { '0', 'x', zero ? '0' : '1', '.' };
+			for (unsigned int i = 0u; i < digits; ++i) { buffer[3u + digits - i] = "0123456789abcdef"[(fraction >> (i * 4u)) & 0xfu]; }
+			buffer[digits != 0u ? 4u + digits : 3u] = '\0';
+			if ((_bits >> (_exponentBits + _fractionBits)) & 1u) _sink("-");
+			_sink(buffer);
+			_sink(power < 0 ? "p-" : "p+");
+			printUnsigned(static_cast<unsigned long long>(power < 0 ? -power : power), _sink);
+		}
+		// big enough for the mantissa of the smallest normal double times 5^1074
+		struct BigUnsigned
+		{
+			unsigned int words[84] = {};
+			unsigned int count = 0u;
+			void multiply(unsigned int _factor)
+			{
+				unsigned long long carry = 0u;
+				for (unsigned int i = 0u; i < count; ++i) { carry += static_cast<unsigned long long>(words[i]) * _factor; words[i] = static_cast<unsigned int>(carry); carry >>= 32u; }
+				if (carry != 0u) words[count++] = static_cast<unsigned int>(carry);
+			}
+			unsigned int divide(unsigned int _divisor) // returns the remainder
+			{
+				unsigned long long rest = 0u;
+				for (unsigned int i = count; i-- > 0u;) { rest = rest << 32u | words[i]; words[i] = static_cast<unsigned int>(rest / _divisor); rest %= _divisor; }
+				while (count != 0u && words[count - 1u] == 0u) --count;
+				return static_cast<unsigned int>(rest);
+			}
+		};
+		// normal or zero value formatted like printf %.<_precision>g, from its exact decimal expansion
+		template <class Sink>
+		void printDecimalFloat(unsigned long long _bits, unsigned int _exponentBits, unsigned int _fractionBits, unsigned int _precision, Sink& _sink)
+		{
+			if ((_bits >> (_exponentBits + _fractionBits)) & 1u) _sink("-");
+			const unsigned long long exponent = (_bits >> _fractionBits) & ((1ull << _exponentBits) - 1u);
+			if (exponent == 0u) { _sink("0"); return; }
+			const unsigned long long mantissa = (_bits & ((1ull << _fractionBits) - 1u)) | 1ull << _fractionBits;
+			BigUnsigned value;
+			value.words[0] = static_cast<unsigned int>(mantissa);
+			value.words[1] = static_cast<unsigned int>(mantissa >> 32u);
+			value.count = value.words[1] != 0u ? 2u : 1u;
+			// mantissa * 2^power is value * 10^point, for negative powers mantissa * 5^-power * 10^power
+			int power = static_cast<int>(exponent) - ((1 << (_exponentBits - 1u)) - 1) - static_cast<int>(_fractionBits);
+			int point = 0;
+			for (; power > 0; --power) value.multiply(2u);
+			for (; power < 0; ++power, --point) value.multiply(5u);
+			char digits[800] = {};
+			unsigned int count = 0u;
+			while (value.count != 0u) digits[count++] = static_cast<char>('0' + value.divide(10u));
+			for (unsigned int i = 0u; i < count / 2u; ++i) { const char c = digits[i]; digits[i] = digits[count - 1u - i]; digits[count - 1u - i] = c; }
+			int decimalExponent = static_cast<int>(count) - 1 + point;
+
+			// round half to even to _precision significant digits
+			if (count > _precision)
+			{
+				bool sticky = false;
+				for (unsigned int i = _precision + 1u; i < count; ++i) sticky |= digits[i] != '0';
+				const char next = digits[_precision];
+				count = _precision;
+				if (next > '5' || (next == '5' && (sticky || (digits[count - 1u] - '0') % 2 != 0)))
+				{
+					unsigned int i = count;
+					while (i != 0u && digits[i - 1u] == '9') digits[--i] = '0';
+					if (i != 0u) ++digits[i - 1u]; else { digits[0] = '1'; ++decimalExponent; }
+				}
+			}
+			while (count > 1u && digits[count - 1u] == '0') --count;
+
+			char text[48] = {};
+			unsigned int length = 0u;
+			if (decimalExponent < -4 || decimalExponent >= static_cast<int>(_precision))
+			{
+				text[length++] = digits[0];
+				if (count > 1u) text[length++] = '.';
+				for (unsigned int i = 1u; i < count; ++i) text[length++] = digits[i];
+				text[length++] = 'e';
+				text[length++] = decimalExponent < 0 ? '-' : '+';
+				const unsigned int magnitude = static_cast<unsigned int>(decimalExponent < 0 ? -decimalExponent : decimalExponent);
+				if (magnitude >= 100u) text[length++] = static_cast<char>('0' + magnitude / 100u);
+				text[length++] = static_cast<char>('0' + magnitude / 10u % 10u);
+				text[length++] = static_cast<char>('0' + magnitude % 10u);
+			}
+			else if (decimalExponent < 0)
+			{
+				text[length++] = '0';
+				text[length++] = '.';
+				for (int i = -1; i > decimalExponent; --i) text[length++] = '0';
+				for (unsigned int i = 0u; i < count; ++i) text[length++] = digits[i];
+			}
+			else
+			{
+				const unsigned int integers = static_cast<unsigned int>(decimalExponent) + 1u;
+				for (unsigned int i = 0u; i < integers; ++i) text[length++] = i < count ? digits[i] : '0';
+				if (count > integers) text[length++] = '.';
+				for (unsigned int i = integers; i < count; ++i) text[length++] = digits[i];
+			}
+			_sink(text);
+		}
+		template <class Sink>
+		void printFloat(unsigned long long _bits, unsigned int _width, Sink& _sink)
+		{
+			if (_width == 16u) { printHexFloat(_bits, 5u, 10u, _sink); return; }
+			if (_width != 32u && _width != 64u) { printUnsigned(_bits, _sink); return; }
+			const unsigned int exponentBits = _width == 32u ? 8u : 11u;
+			const unsigned int fractionBits = _width == 32u ? 23u : 52u;
+			const unsigned long long exponent = (_bits >> fractionBits) & ((1ull << exponentBits) - 1u);
+			const bool subnormal = exponent == 0u && (_bits & ((1ull << fractionBits) - 1u)) != 0u;
+			if (subnormal || exponent == (1ull << exponentBits) - 1u) printHexFloat(_bits, exponentBits, fractionBits, _sink);
+			else printDecimalFloat(_bits, exponentBits, fractionBits, _width == 32u ? 9u : 17u, _sink);
+		}
+		template <class Sink>
+		void printNumber(const PrinterState::NumberType& _type, unsigned long long _bits, Sink& _sink)
+		{
+			const unsigned long long mask = _type.width >= 64u ? ~0ull : (1ull << _type.width) - 1u;
+			switch (_type.kind)
+			{
+			case PrinterState::NumberKind::Unsigned: printUnsigned(_bits & mask, _sink); break;
+			case PrinterState::NumberKind::Signed:
+				// sign extend narrower integers
+				printSigned(static_cast<long long>(_type.width != 0u && (_bits >> (_type.width - 1u)) & 1u ? _bits | ~mask : _bits & mask), _sink);
+				break;
+			case PrinterState::NumberKind::Float: printFloat(_bits, _type.width, _sink); break;
+			}
+		}
+		inline const PrinterState::NumberType* numberType(const PrinterState& _state, unsigned int _id)
+		{
+			for (unsigned int i = 0u; i < _state.numberTypeCount; ++i) { if (_state.numberTypes[i].id == _id) return _state.numberTypes + i; }
+			return nullptr;
+		}
+		template <class Sink>
+		bool printString(PrintCursor& _cursor, Sink& _sink)
+		{
+			_sink("\"");
+			for (; !_cursor.empty(); ++_cursor.it)
+			{
+				for (unsigned int i = 0u; i < 4u; ++i)
+				{
+					const char c[3] = { static_cast<char>((*_cursor.it >> (i * 8u)) & 0xffu), '\0', '\0' };
+					if (c[0] == '\0') { ++_cursor.it; _sink("\""); return true; }
+					if (c[0] == '"' || c[0] == '\\') { const char escaped[3] = { '\\', c[0], '\0' }; _sink(escaped); }
+					else { _sink(c); }
+				}
+			}
+			return false;
+		}
+		inline const char* opName(Op _opcode)
+		{
+			switch (_opcode)
+			{
+			default: return nullptr;
+			case Op::OpNop: return "OpNop";
+			case Op::OpUndef: return "OpUndef";
+			case Op::OpSourceContinued: return "OpSourceContinued";
+			case Op::OpSource: return "OpSource";
+			case Op::OpSourceExtension: return "OpSourceExtension";
+			case Op::OpName: return "OpName";
+			case Op::OpMemberName: return "OpMemberName";
+			case Op::OpString: return "OpString";
+			case Op::OpLine: return "OpLine";
+			case Op::OpExtension: return "OpExtension";
+			case Op::OpExtInstImport: return "OpExtInstImport";
+			case Op::OpExtInst: return "OpExtInst";
+			case Op::OpMemoryModel: return "OpMemoryModel";
+			case Op::OpEntryPoint: return "OpEntryPoint";
+			case Op::OpExecutionMode: return "OpExecutionMode";
+			case Op::OpCapability: return "OpCapability";
+			case Op::OpTypeVoid: return "OpTypeVoid";
+			case Op::OpTypeBool: return "OpTypeBool";
+			case Op::OpTypeInt: return "OpTypeInt";
+			case Op::OpTypeFloat: return "OpTypeFloat";
+			case Op::OpTypeVector: return "OpTypeVector";
+			case Op::OpTypeMatrix: return "OpTypeMatrix";
+			case Op::OpTypeImage: return "OpTypeImage";
+			case Op::OpTypeSampler: return "OpTypeSampler";
+			case Op::OpTypeSampledImage: return "OpTypeSampledImage";
+			case Op::OpTypeArray: return "OpTypeArray";
+			case Op::OpTypeRuntimeArray: return "OpTypeRuntimeArray";
+			case Op::OpTypeStruct: return "OpTypeStruct";
+			case Op::OpTypeOpaque: return "OpTypeOpaque";
+			case Op::OpTypePointer: return "OpTypePointer";
+			case Op::OpTypeFunction: return "OpTypeFunction";
+			case Op::OpTypeEvent: return "OpTypeEvent";
+			case Op::OpTypeDeviceEvent: return "OpTypeDeviceEvent";
+			case Op::OpTypeReserveId: return "OpTypeReserveId";
+			case Op::OpTypeQueue: return "OpTypeQueue";
+			case Op::OpTypePipe: return "OpTypePipe";
+			case Op::OpTypeForwardPointer: return "OpTypeForwardPointer";
+			case Op::OpConstantTrue: return "OpConstantTrue";
+			case Op::OpConstantFalse: return "OpConstantFalse";
+			case Op::OpConstant: return "OpConstant";
+			case Op::OpConstantComposite: return "OpConstantComposite";
+			case Op::OpConstantSampler: return "OpConstantSampler";
+			case Op::OpConstantNull: return "OpConstantNull";
+			case Op::OpSpecConstantTrue: return "OpSpecConstantTrue";
+			case Op::OpSpecConstantFalse: return "OpSpecConstantFalse";
+			case Op::OpSpecConstant: return "OpSpecConstant";
+			case Op::OpSpecConstantComposite: return "OpSpecConstantComposite";
+			case Op::OpSpecConstantOp: return "OpSpecConstantOp";
+			case Op::OpFunction: return "OpFunction";
+			case Op::OpFunctionParameter: return "OpFunctionParameter";
+			case Op::OpFunctionEnd: return "OpFunctionEnd";
+			case Op::OpFunctionCall: return "OpFunctionCall";
+			case Op::OpVariable: return "OpVariable";
+			case Op::OpImageTexelPointer: return "OpImageTexelPointer";
+			case Op::OpLoad: return "OpLoad";
+			case Op::OpStore: return "OpStore";
+			case Op::OpCopyMemory: return "OpCopyMemory";
+			case Op::OpCopyMemorySized: return "OpCopyMemorySized";
+			case Op::OpAccessChain: return "OpAccessChain";
+			case Op::OpInBoundsAccessChain: return "OpInBoundsAccessChain";
+			case Op::OpPtrAccessChain: return "OpPtrAccessChain";
+			case Op::OpArrayLength: return "OpArrayLength";
+			case Op::OpGenericPtrMemSemantics: return "OpGenericPtrMemSemantics";
+			case Op::OpInBoundsPtrAccessChain: return "OpInBoundsPtrAccessChain";
+			case Op::OpDecorate: return "OpDecorate";
+			case Op::OpMemberDecorate: return "OpMemberDecorate";
+			case Op::OpDecorationGroup: return "OpDecorationGroup";
+			case Op::OpGroupDecorate: return "OpGroupDecorate";
+			case Op::OpGroupMemberDecorate: return "OpGroupMemberDecorate";
+			case Op::OpVectorExtractDynamic: return "OpVectorExtractDynamic";
+			case Op::OpVectorInsertDynamic: return "OpVectorInsertDynamic";
+			case Op::OpVectorShuffle: return "OpVectorShuffle";
+			case Op::OpCompositeConstruct: return "OpCompositeConstruct";
+			case Op::OpCompositeExtract: return "OpCompositeExtract";
+			case Op::OpCompositeInsert: return "OpCompositeInsert";
+			case Op::OpCopyObject: return "OpCopyObject";
+			case Op::OpTranspose: return "OpTranspose";
+			case Op::OpSampledImage: return "OpSampledImage";
+			case Op::OpImageSampleImplicitLod: return "OpImageSampleImplicitLod";
+			case Op::OpImageSampleExplicitLod: return "OpImageSampleExplicitLod";
+			case Op::OpImageSampleDrefImplicitLod: return "OpImageSampleDrefImplicitLod";
+			case Op::OpImageSampleDrefExplicitLod: return "OpImageSampleDrefExplicitLod";
+			case Op::OpImageSampleProjImplicitLod: return "OpImageSampleProjImplicitLod";
+			case Op::OpImageSampleProjExplicitLod: return "OpImageSampleProjExplicitLod";
+			case Op::OpImageSampleProjDrefImplicitLod: return "OpImageSampleProjDrefImplicitLod";
+			case Op::OpImageSampleProjDrefExplicitLod: return "OpImageSampleProjDrefExplicitLod";
+			case Op::OpImageFetch: return "OpImageFetch";
+			case Op::OpImageGather: return "OpImageGather";
+			case Op::OpImageDrefGather: return "OpImageDrefGather";
+			case Op::OpImageRead: return "OpImageRead";
+			case Op::OpImageWrite: return "OpImageWrite";
+			case Op::OpImage: return "OpImage";
+			case Op::OpImageQueryFormat: return "OpImageQueryFormat";
+			case Op::OpImageQueryOrder: return "OpImageQueryOrder";
+			case Op::OpImageQuerySizeLod: return "OpImageQuerySizeLod";
+			case Op::OpImageQuerySize: return "OpImageQuerySize";
+			case Op::OpImageQueryLod: return "OpImageQueryLod";
+			case Op::OpImageQueryLevels: return "OpImageQueryLevels";
+			case Op::OpImageQuerySamples: return "OpImageQuerySamples";
+			case Op::OpConvertFToU: return "OpConvertFToU";
+			case Op::OpConvertFToS: return "OpConvertFToS";
+			case Op::OpConvertSToF: return "OpConvertSToF";
+			case Op::OpConvertUToF: return "OpConvertUToF";
+			case Op::OpUConvert: return "OpUConvert";
+			case Op::OpSConvert: return "OpSConvert";
+			case Op::OpFConvert: return "OpFConvert";
+			case Op::OpQuantizeToF16: return "OpQuantizeToF16";
+			case Op::OpConvertPtrToU: return "OpConvertPtrToU";
+			case Op::OpSatConvertSToU: return "OpSatConvertSToU";
+			case Op::OpSatConvertUToS: return "OpSatConvertUToS";
+			case Op::OpConvertUToPtr: return "OpConvertUToPtr";
+			case Op::OpPtrCastToGeneric: return "OpPtrCastToGeneric";
+			case Op::OpGenericCastToPtr: return "OpGenericCastToPtr";
+			case Op::OpGenericCastToPtrExplicit: return "OpGenericCastToPtrExplicit";
+			case Op::OpBitcast: return "OpBitcast";
+			case Op::OpSNegate: return "OpSNegate";
+			case Op::OpFNegate: return "OpFNegate";
+			case Op::OpIAdd: return "OpIAdd";
+			case Op::OpFAdd: return "OpFAdd";
+			case Op::OpISub: return "OpISub";
+			case Op::OpFSub: return "OpFSub";
+			case Op::OpIMul: return "OpIMul";
+			case Op::OpFMul: return "OpFMul";
+			case Op::OpUDiv: return "OpUDiv";
+			case Op::OpSDiv: return "OpSDiv";
+			case Op::OpFDiv: return "OpFDiv";
+			case Op::OpUMod: return "OpUMod";
+			case Op::OpSRem: return "OpSRem";
+			case Op::OpSMod: return "OpSMod";
+			case Op::OpFRem: return "OpFRem";
+			case Op::OpFMod: return "OpFMod";
+			case Op::OpVectorTimesScalar: return "OpVectorTimesScalar";
+			case Op::OpMatrixTimesScalar: return "OpMatrixTimesScalar";
+			case Op::OpVectorTimesMatrix: return "OpVectorTimesMatrix";
+			case Op::OpMatrixTimesVector: return "OpMatrixTimesVector";
+			case Op::OpMatrixTimesMatrix: return "OpMatrixTimesMatrix";
+			case Op::OpOuterProduct: return "OpOuterProduct";
+			case Op::OpDot: return "OpDot";
+			case Op::OpIAddCarry: return "OpIAddCarry";
+			case Op::OpISubBorrow: return "OpISubBorrow";
+			case Op::OpUMulExtended: return "OpUMulExtended";
+			case Op::OpSMulExtended: return "OpSMulExtended";
+			case Op::OpAny: return "OpAny";
+			case Op::OpAll: return "OpAll";
+			case Op::OpIsNan: return "OpIsNan";
+			case Op::OpIsInf: return "OpIsInf";
+			case Op::OpIsFinite: return "OpIsFinite";
+			case Op::OpIsNormal: return "OpIsNormal";
+			case Op::OpSignBitSet: return "OpSignBitSet";
+			case Op::OpLessOrGreater: return "OpLessOrGreater";
+			case Op::OpOrdered: return "OpOrdered";
+			case Op::OpUnordered: return "OpUnordered";
+			case Op::OpLogicalEqual: return "OpLogicalEqual";
+			case Op::OpLogicalNotEqual: return "OpLogicalNotEqual";
+			case Op::OpLogicalOr: return "OpLogicalOr";
+			case Op::OpLogicalAnd: return "OpLogicalAnd";
+			case Op::OpLogicalNot: return "OpLogicalNot";
+			case Op::OpSelect: return "OpSelect";
+			case Op::OpIEqual: return "OpIEqual";
+			case Op::OpINotEqual: return "OpINotEqual";
+			case Op::OpUGreaterThan: return "OpUGreaterThan";
+			case Op::OpSGreaterThan: return "OpSGreaterThan";
+			case Op::OpUGreaterThanEqual: return "OpUGreaterThanEqual";
+			case Op::OpSGreaterThanEqual: return "OpSGreaterThanEqual";
+			case Op::OpULessThan: return "OpULessThan";
+			case Op::OpSLessThan: return "OpSLessThan";
+			case Op::OpULessThanEqual: return "OpULessThanEqual";
+			case Op::OpSLessThanEqual: return "OpSLessThanEqual";
+			case Op::OpFOrdEqual: return "OpFOrdEqual";
+			case Op::OpFUnordEqual: return "OpFUnordEqual";
+			case Op::OpFOrdNotEqual: return "OpFOrdNotEqual";
+			case Op::OpFUnordNotEqual: return "OpFUnordNotEqual";
+			case Op::OpFOrdLessThan: return "OpFOrdLessThan";
+			case Op::OpFUnordLessThan: return "OpFUnordLessThan";
+			case Op::OpFOrdGreaterThan: return "OpFOrdGreaterThan";
+			case Op::OpFUnordGreaterThan: return "OpFUnordGreaterThan";
+			case Op::OpFOrdLessThanEqual: return "OpFOrdLessThanEqual";
+			case Op::OpFUnordLessThanEqual: return "OpFUnordLessThanEqual";
+			case Op::OpFOrdGreaterThanEqual: return "OpFOrdGreaterThanEqual";
+			case Op::OpFUnordGreaterThanEqual: return "OpFUnordGreaterThanEqual";
+			case Op::OpShiftRightLogical: return "OpShiftRightLogical";
+			case Op::OpShiftRightArithmetic: return "OpShiftRightArithmetic";
+			case Op::OpShiftLeftLogical: return "OpShiftLeftLogical";
+			case Op::OpBitwiseOr: return "OpBitwiseOr";
+			case Op::OpBitwiseXor: return "OpBitwiseXor";
+			case Op::OpBitwiseAnd: return "OpBitwiseAnd";
+			case Op::OpNot: return "OpNot";
+			case Op::OpBitFieldInsert: return "OpBitFieldInsert";
+			case Op::OpBitFieldSExtract: return "OpBitFieldSExtract";
+			case Op::OpBitFieldUExtract: return "OpBitFieldUExtract";
+			case Op::OpBitReverse: return "OpBitReverse";
+			case Op::OpBitCount: return "OpBitCount";
+			case Op::OpDPdx: return "OpDPdx";
+			case Op::OpDPdy: return "OpDPdy";
+			case Op::OpFwidth: return "OpFwidth";
+			case Op::OpDPdxFine: return "OpDPdxFine";
+			case Op::OpDPdyFine: return "OpDPdyFine";
+			case Op::OpFwidthFine: return "OpFwidthFine";
+			case Op::OpDPdxCoarse: return "OpDPdxCoarse";
+			case Op::OpDPdyCoarse: return "OpDPdyCoarse";
+			case Op::OpFwidthCoarse: return "OpFwidthCoarse";
+			case Op::OpEmitVertex: return "OpEmitVertex";
+			case Op::OpEndPrimitive: return "OpEndPrimitive";
+			case Op::OpEmitStreamVertex: return "OpEmitStreamVertex";
+			case Op::OpEndStreamPrimitive: return "OpEndStreamPrimitive";
+			case Op::OpControlBarrier: return "OpControlBarrier";
+			case Op::OpMemoryBarrier: return "OpMemoryBarrier";
+			case Op::OpAtomicLoad: return "OpAtomicLoad";
+			case Op::OpAtomicStore: return "OpAtomicStore";
+			case Op::OpAtomicExchange: return "OpAtomicExchange";
+			case Op::OpAtomicCompareExchange: return "OpAtomicCompareExchange";
+			case Op::OpAtomicCompareExchangeWeak: return "OpAtomicCompareExchangeWeak";
+			case Op::OpAtomicIIncrement: return "OpAtomicIIncrement";
+			case Op::OpAtomicIDecrement: return "OpAtomicIDecrement";
+			case Op::OpAtomicIAdd: return "OpAtomicIAdd";
+			case Op::OpAtomicISub: return "OpAtomicISub";
+			case Op::OpAtomicSMin: return "OpAtomicSMin";
+			case Op::OpAtomicUMin: return "OpAtomicUMin";
+			case Op::OpAtomicSMax: return "OpAtomicSMax";
+			case Op::OpAtomicUMax: return "OpAtomicUMax";
+			case Op::OpAtomicAnd: return "OpAtomicAnd";
+			case Op::OpAtomicOr: return "OpAtomicOr";
+			case Op::OpAtomicXor: return "OpAtomicXor";
+			case Op::OpPhi: return "OpPhi";
+			case Op::OpLoopMerge: return "OpLoopMerge";
+			case Op::OpSelectionMerge: return "OpSelectionMerge";
+			case Op::OpLabel: return "OpLabel";
+			case Op::OpBranch: return "OpBranch";
+			case Op::OpBranchConditional: return "OpBranchConditional";
+			case Op::OpSwitch: return "OpSwitch";
+			case Op::OpKill: return "OpKill";
+			case Op::OpReturn: return "OpReturn";
+			case Op::OpReturnValue: return "OpReturnValue";
+			case Op::OpUnreachable: return "OpUnreachable";
+			case Op::OpLifetimeStart: return "OpLifetimeStart";
+			case Op::OpLifetimeStop: return "OpLifetimeStop";
+			case Op::OpGroupAsyncCopy: return "OpGroupAsyncCopy";
+			case Op::OpGroupWaitEvents: return "OpGroupWaitEvents";
+			case Op::OpGroupAll: return "OpGroupAll";
+			case Op::OpGroupAny: return "OpGroupAny";
+			case Op::OpGroupBroadcast: return "OpGroupBroadcast";
+			case Op::OpGroupIAdd: return "OpGroupIAdd";
+			case Op::OpGroupFAdd: return "OpGroupFAdd";
+			case Op::OpGroupFMin: return "OpGroupFMin";
+			case Op::OpGroupUMin: return "OpGroupUMin";
+			case Op::OpGroupSMin: return "OpGroupSMin";
+			case Op::OpGroupFMax: return "OpGroupFMax";
+			case Op::OpGroupUMax: return "OpGroupUMax";
+			case Op::OpGroupSMax: return "OpGroupSMax";
+			case Op::OpReadPipe: return "OpReadPipe";
+			case Op::OpWritePipe: return "OpWritePipe";
+			case Op::OpReservedReadPipe: return "OpReservedReadPipe";
+			case Op::OpReservedWritePipe: return "OpReservedWritePipe";
+			case Op::OpReserveReadPipePackets: return "OpReserveReadPipePackets";
+			case Op::OpReserveWritePipePackets: return "OpReserveWritePipePackets";
+			case Op::OpCommitReadPipe: return "OpCommitReadPipe";
+			case Op::OpCommitWritePipe: return "OpCommitWritePipe";
+			case Op::OpIsValidReserveId: return "OpIsValidReserveId";
+			case Op::OpGetNumPipePackets: return "OpGetNumPipePackets";
+			case Op::OpGetMaxPipePackets: return "OpGetMaxPipePackets";
+			case Op::OpGroupReserveReadPipePackets: return "OpGroupReserveReadPipePackets";
+			case Op::OpGroupReserveWritePipePackets: return "OpGroupReserveWritePipePackets";
+			case Op::OpGroupCommitReadPipe: return "OpGroupCommitReadPipe";
+			case Op::OpGroupCommitWritePipe: return "OpGroupCommitWritePipe";
+			case Op::OpEnqueueMarker: return "OpEnqueueMarker";
+			case Op::OpEnqueueKernel: return "OpEnqueueKernel";
+			case Op::OpGetKernelNDrangeSubGroupCount: return "OpGetKernelNDrangeSubGroupCount";
+			case Op::OpGetKernelNDrangeMaxSubGroupSize: return "OpGetKernelNDrangeMaxSubGroupSize";
+			case Op::OpGetKernelWorkGroupSize: return "OpGetKernelWorkGroupSize";
+			case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return "OpGetKernelPreferredWorkGroupSizeMultiple";
+			case Op::OpRetainEvent: return "OpRetainEvent";
+			case Op::OpReleaseEvent: return "OpReleaseEvent";
+			case Op::OpCreateUserEvent: return "OpCreateUserEvent";
+			case Op::OpIsValidEvent: return "OpIsValidEvent";
+			case Op::OpSetUserEventStatus: return "OpSetUserEventStatus";
+			case Op::OpCaptureEventProfilingInfo: return "OpCaptureEventProfilingInfo";
+			case Op::OpGetDefaultQueue: return "OpGetDefaultQueue";
+			case Op::OpBuildNDRange: return "OpBuildNDRange";
+			case Op::OpImageSparseSampleImplicitLod: return "OpImageSparseSampleImplicitLod";
+			case Op::OpImageSparseSampleExplicitLod: return "OpImageSparseSampleExplicitLod";
+			case Op::OpImageSparseSampleDrefImplicitLod: return "OpImageSparseSampleDrefImplicitLod";
+			case Op::OpImageSparseSampleDrefExplicitLod: return "OpImageSparseSampleDrefExplicitLod";
+			case Op::OpImageSparseSampleProjImplicitLod: return "OpImageSparseSampleProjImplicitLod";
+			case Op::OpImageSparseSampleProjExplicitLod: return "OpImageSparseSampleProjExplicitLod";
+			case Op::OpImageSparseSampleProjDrefImplicitLod: return "OpImageSparseSampleProjDrefImplicitLod";
+			case Op::OpImageSparseSampleProjDrefExplicitLod: return "OpImageSparseSampleProjDrefExplicitLod";
+			case Op::OpImageSparseFetch: return "OpImageSparseFetch";
+			case Op::OpImageSparseGather: return "OpImageSparseGather";
+			case Op::OpImageSparseDrefGather: return "OpImageSparseDrefGather";
+			case Op::OpImageSparseTexelsResident: return "OpImageSparseTexelsResident";
+			case Op::OpNoLine: return "OpNoLine";
+			case Op::OpAtomicFlagTestAndSet: return "OpAtomicFlagTestAndSet";
+			case Op::OpAtomicFlagClear: return "OpAtomicFlagClear";
+			case Op::OpImageSparseRead: return "OpImageSparseRead";
+			case Op::OpSizeOf: return "OpSizeOf";
+			case Op::OpTypePipeStorage: return "OpTypePipeStorage";
+			case Op::OpConstantPipeStorage: return "OpConstantPipeStorage";
+			case Op::OpCreatePipeFromPipeStorage: return "OpCreatePipeFromPipeStorage";
+			case Op::OpGetKernelLocalSizeForSubgroupCount: return "OpGetKernelLocalSizeForSubgroupCount";
+			case Op::OpGetKernelMaxNumSubgroups: return "OpGetKernelMaxNumSubgroups";
+			case Op::OpTypeNamedBarrier: return "OpTypeNamedBarrier";
+			case Op::OpNamedBarrierInitialize: return "OpNamedBarrierInitialize";
+			case Op::OpMemoryNamedBarrier: return "OpMemoryNamedBarrier";
+			case Op::OpModuleProcessed: return "OpModuleProcessed";
+			case Op::OpExecutionModeId: return "OpExecutionModeId";
+			case Op::OpDecorateId: return "OpDecorateId";
+			case Op::OpGroupNonUniformElect: return "OpGroupNonUniformElect";
+			case Op::OpGroupNonUniformAll: return "OpGroupNonUniformAll";
+			case Op::OpGroupNonUniformAny: return "OpGroupNonUniformAny";
+			case Op::OpGroupNonUniformAllEqual: return "OpGroupNonUniformAllEqual";
+			case Op::OpGroupNonUniformBroadcast: return "OpGroupNonUniformBroadcast";
+			case Op::OpGroupNonUniformBroadcastFirst: return "OpGroupNonUniformBroadcastFirst";
+			case Op::OpGroupNonUniformBallot: return "OpGroupNonUniformBallot";
+			case Op::OpGroupNonUniformInverseBallot: return "OpGroupNonUniformInverseBallot";
+			case Op::OpGroupNonUniformBallotBitExtract: return "OpGroupNonUniformBallotBitExtract";
+			case Op::OpGroupNonUniformBallotBitCount: return "OpGroupNonUniformBallotBitCount";
+			case Op::OpGroupNonUniformBallotFindLSB: return "OpGroupNonUniformBallotFindLSB";
+			case Op::OpGroupNonUniformBallotFindMSB: return "OpGroupNonUniformBallotFindMSB";
+			case Op::OpGroupNonUniformShuffle: return "OpGroupNonUniformShuffle";
+			case Op::OpGroupNonUniformShuffleXor: return "OpGroupNonUniformShuffleXor";
+			case Op::OpGroupNonUniformShuffleUp: return "OpGroupNonUniformShuffleUp";
+			case Op::OpGroupNonUniformShuffleDown: return "OpGroupNonUniformShuffleDown";
+			case Op::OpGroupNonUniformIAdd: return "OpGroupNonUniformIAdd";
+			case Op::OpGroupNonUniformFAdd: return "OpGroupNonUniformFAdd";
+			case Op::OpGroupNonUniformIMul: return "OpGroupNonUniformIMul";
+			case Op::OpGroupNonUniformFMul: return "OpGroupNonUniformFMul";
+			case Op::OpGroupNonUniformSMin: return "OpGroupNonUniformSMin";
+			case Op::OpGroupNonUniformUMin: return "OpGroupNonUniformUMin";
+			case Op::OpGroupNonUniformFMin: return "OpGroupNonUniformFMin";
+			case Op::OpGroupNonUniformSMax: return "OpGroupNonUniformSMax";
+			case Op::OpGroupNonUniformUMax: return "OpGroupNonUniformUMax";
+			case Op::OpGroupNonUniformFMax: return "OpGroupNonUniformFMax";
+			case Op::OpGroupNonUniformBitwiseAnd: return "OpGroupNonUniformBitwiseAnd";
+			case Op::OpGroupNonUniformBitwiseOr: return "OpGroupNonUniformBitwiseOr";
+			case Op::OpGroupNonUniformBitwiseXor: return "OpGroupNonUniformBitwiseXor";
+			case Op::OpGroupNonUniformLogicalAnd: return "OpGroupNonUniformLogicalAnd";
+			case Op::OpGroupNonUniformLogicalOr: return "OpGroupNonUniformLogicalOr";
+			case Op::OpGroupNonUniformLogicalXor: return "OpGroupNonUniformLogicalXor";
+			case Op::OpGroupNonUniformQuadBroadcast: return "OpGroupNonUniformQuadBroadcast";
+			case Op::OpGroupNonUniformQuadSwap: return "OpGroupNonUniformQuadSwap";
+			case Op::OpCopyLogical: return "OpCopyLogical";
+			case Op::OpPtrEqual: return "OpPtrEqual";
+			case Op::OpPtrNotEqual: return "OpPtrNotEqual";
+			case Op::OpPtrDiff: return "OpPtrDiff";
+			case Op::OpColorAttachmentReadEXT: return "OpColorAttachmentReadEXT";
+			case Op::OpDepthAttachmentReadEXT: return "OpDepthAttachmentReadEXT";
+			case Op::OpStencilAttachmentReadEXT: return "OpStencilAttachmentReadEXT";
+			case Op::OpTerminateInvocation: return "OpTerminateInvocation";
+			case Op::OpSubgroupBallotKHR: return "OpSubgroupBallotKHR";
+			case Op::OpSubgroupFirstInvocationKHR: return "OpSubgroupFirstInvocationKHR";
+			case Op::OpSubgroupAllKHR: return "OpSubgroupAllKHR";
+			case Op::OpSubgroupAnyKHR: return "OpSubgroupAnyKHR";
+			case Op::OpSubgroupAllEqualKHR: return "OpSubgroupAllEqualKHR";
+			case Op::OpGroupNonUniformRotateKHR: return "OpGroupNonUniformRotateKHR";
+			case Op::OpSubgroupReadInvocationKHR: return "OpSubgroupReadInvocationKHR";
+			case Op::OpTraceRayKHR: return "OpTraceRayKHR";
+			case Op::OpExecuteCallableKHR: return "OpExecuteCallableKHR";
+			case Op::OpConvertUToAccelerationStructureKHR: return "OpConvertUToAccelerationStructureKHR";
+			case Op::OpIgnoreIntersectionKHR: return "OpIgnoreIntersectionKHR";
+			case Op::OpTerminateRayKHR: return "OpTerminateRayKHR";
+			case Op::OpSDot: return "OpSDot";
+			case Op::OpUDot: return "OpUDot";
+			case Op::OpSUDot: return "OpSUDot";
+			case Op::OpSDotAccSat: return "OpSDotAccSat";
+			case Op::OpUDotAccSat: return "OpUDotAccSat";
+			case Op::OpSUDotAccSat: return "OpSUDotAccSat";
+			case Op::OpTypeCooperativeMatrixKHR: return "OpTypeCooperativeMatrixKHR";
+			case Op::OpCooperativeMatrixLoadKHR: return "OpCooperativeMatrixLoadKHR";
+			case Op::OpCooperativeMatrixStoreKHR: return "OpCooperativeMatrixStoreKHR";
+			case Op::OpCooperativeMatrixMulAddKHR: return "OpCooperativeMatrixMulAddKHR";
+			case Op::OpCooperativeMatrixLengthKHR: return "OpCooperativeMatrixLengthKHR";
+			case Op::OpTypeRayQueryKHR: return "OpTypeRayQueryKHR";
+			case Op::OpRayQueryInitializeKHR: return "OpRayQueryInitializeKHR";
+			case Op::OpRayQueryTerminateKHR: return "OpRayQueryTerminateKHR";
+			case Op::OpRayQueryGenerateIntersectionKHR: return "OpRayQueryGenerateIntersectionKHR";
+			case Op::OpRayQueryConfirmIntersectionKHR: return "OpRayQueryConfirmIntersectionKHR";
+			case Op::OpRayQueryProceedKHR: return "OpRayQueryProceedKHR";
+			case Op::OpRayQueryGetIntersectionTypeKHR: return "OpRayQueryGetIntersectionTypeKHR";
+			case Op::OpImageSampleWeightedQCOM: return "OpImageSampleWeightedQCOM";
+			case Op::OpImageBoxFilterQCOM: return "OpImageBoxFilterQCOM";
+			case Op::OpImageBlockMatchSSDQCOM: return "OpImageBlockMatchSSDQCOM";
+			case Op::OpImageBlockMatchSADQCOM: return "OpImageBlockMatchSADQCOM";
+			case Op::OpGroupIAddNonUniformAMD: return "OpGroupIAddNonUniformAMD";
+			case Op::OpGroupFAddNonUniformAMD: return "OpGroupFAddNonUniformAMD";
+			case Op::OpGroupFMinNonUniformAMD: return "OpGroupFMinNonUniformAMD";
+			case Op::OpGroupUMinNonUniformAMD: return "OpGroupUMinNonUniformAMD";
+			case Op::OpGroupSMinNonUniformAMD: return "OpGroupSMinNonUniformAMD";
+			case Op::OpGroupFMaxNonUniformAMD: return "OpGroupFMaxNonUniformAMD";
+			case Op::OpGroupUMaxNonUniformAMD: return "OpGroupUMaxNonUniformAMD";
+			case Op::OpGroupSMaxNonUniformAMD: return "OpGroupSMaxNonUniformAMD";
+			case Op::OpFragmentMaskFetchAMD: return "OpFragmentMaskFetchAMD";
+			case Op::OpFragmentFetchAMD: return "OpFragmentFetchAMD";
+			case Op::OpReadClockKHR: return "OpReadClockKHR";
+			case Op::OpFinalizeNodePayloadsAMDX: return "OpFinalizeNodePayloadsAMDX";
+			case Op::OpFinishWritingNodePayloadAMDX: return "OpFinishWritingNodePayloadAMDX";
+			case Op::OpInitializeNodePayloadsAMDX: return "OpInitializeNodePayloadsAMDX";
+			case Op::OpHitObjectRecordHitMotionNV: return "OpHitObjectRecordHitMotionNV";
+			case Op::OpHitObjectRecordHitWithIndexMotionNV: return "OpHitObjectRecordHitWithIndexMotionNV";
+			case Op::OpHitObjectRecordMissMotionNV: return "OpHitObjectRecordMissMotionNV";
+			case Op::OpHitObjectGetWorldToObjectNV: return "OpHitObjectGetWorldToObjectNV";
+			case Op::OpHitObjectGetObjectToWorldNV: return "OpHitObjectGetObjectToWorldNV";
+			case Op::OpHitObjectGetObjectRayDirectionNV: return "OpHitObjectGetObjectRayDirectionNV";
+			case Op::OpHitObjectGetObjectRayOriginNV: return "OpHitObjectGetObjectRayOriginNV";
+			case Op::OpHitObjectTraceRayMotionNV: return "OpHitObjectTraceRayMotionNV";
+			case Op::OpHitObjectGetShaderRecordBufferHandleNV: return "OpHitObjectGetShaderRecordBufferHandleNV";
+			case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return "OpHitObjectGetShaderBindingTableRecordIndexNV";
+			case Op::OpHitObjectRecordEmptyNV: return "OpHitObjectRecordEmptyNV";
+			case Op::OpHitObjectTraceRayNV: return "OpHitObjectTraceRayNV";
+			case Op::OpHitObjectRecordHitNV: return "OpHitObjectRecordHitNV";
+			case Op::OpHitObjectRecordHitWithIndexNV: return "OpHitObjectRecordHitWithIndexNV";
+			case Op::OpHitObjectRecordMissNV: return "OpHitObjectRecordMissNV";
+			case Op::OpHitObjectExecuteShaderNV: return "OpHitObjectExecuteShaderNV";
+			case Op::OpHitObjectGetCurrentTimeNV: return "OpHitObjectGetCurrentTimeNV";
+			case Op::OpHitObjectGetAttributesNV: return "OpHitObjectGetAttributesNV";
+			case Op::OpHitObjectGetHitKindNV: return "OpHitObjectGetHitKindNV";
+			case Op::OpHitObjectGetPrimitiveIndexNV: return "OpHitObjectGetPrimitiveIndexNV";
+			case Op::OpHitObjectGetGeometryIndexNV: return "OpHitObjectGetGeometryIndexNV";
+			case Op::OpHitObjectGetInstanceIdNV: return "OpHitObjectGetInstanceIdNV";
+			case Op::OpHitObjectGetInstanceCustomIndexNV: return "OpHitObjectGetInstanceCustomIndexNV";
+			case Op::OpHitObjectGetWorldRayDirectionNV: return "OpHitObjectGetWorldRayDirectionNV";
+			case Op::OpHitObjectGetWorldRayOriginNV: return "OpHitObjectGetWorldRayOriginNV";
+			case Op::OpHitObjectGetRayTMaxNV: return "OpHitObjectGetRayTMaxNV";
+			case Op::OpHitObjectGetRayTMinNV: return "OpHitObjectGetRayTMinNV";
+			case Op::OpHitObjectIsEmptyNV: return "OpHitObjectIsEmptyNV";
+			case Op::OpHitObjectIsHitNV: return "OpHitObjectIsHitNV";
+			case Op::OpHitObjectIsMissNV: return "OpHitObjectIsMissNV";
+			case Op::OpReorderThreadWithHitObjectNV: return "OpReorderThreadWithHitObjectNV";
+			case Op::OpReorderThreadWithHintNV: return "OpReorderThreadWithHintNV";
+			case Op::OpTypeHitObjectNV: return "OpTypeHitObjectNV";
+			case Op::OpImageSampleFootprintNV: return "OpImageSampleFootprintNV";
+			case Op::OpEmitMeshTasksEXT: return "OpEmitMeshTasksEXT";
+			case Op::OpSetMeshOutputsEXT: return "OpSetMeshOutputsEXT";
+			case Op::OpGroupNonUniformPartitionNV: return "OpGroupNonUniformPartitionNV";
+			case Op::OpWritePackedPrimitiveIndices4x8NV: return "OpWritePackedPrimitiveIndices4x8NV";
+			case Op::OpReportIntersectionNV: return "OpReportIntersectionNV";
+			case Op::OpIgnoreIntersectionNV: return "OpIgnoreIntersectionNV";
+			case Op::OpTerminateRayNV: return "OpTerminateRayNV";
+			case Op::OpTraceNV: return "OpTraceNV";
+			case Op::OpTraceMotionNV: return "OpTraceMotionNV";
+			case Op::OpTraceRayMotionNV: return "OpTraceRayMotionNV";
+			case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return "OpRayQueryGetIntersectionTriangleVertexPositionsKHR";
+			case Op::OpTypeAccelerationStructureNV: return "OpTypeAccelerationStructureNV";
+			case Op::OpExecuteCallableNV: return "OpExecuteCallableNV";
+			case Op::OpTypeCooperativeMatrixNV: return "OpTypeCooperativeMatrixNV";
+			case Op::OpCooperativeMatrixLoadNV: return "OpCooperativeMatrixLoadNV";
+			case Op::OpCooperativeMatrixStoreNV: return "OpCooperativeMatrixStoreNV";
+			case Op::OpCooperativeMatrixMulAddNV: return "OpCooperativeMatrixMulAddNV";
+			case Op::OpCooperativeMatrixLengthNV: return "OpCooperativeMatrixLengthNV";
+			case Op::OpBeginInvocationInterlockEXT: return "OpBeginInvocationInterlockEXT";
+			case Op::OpEndInvocationInterlockEXT: return "OpEndInvocationInterlockEXT";
+			case Op::OpDemoteToHelperInvocation: return "OpDemoteToHelperInvocation";
+			case Op::OpIsHelperInvocationEXT: return "OpIsHelperInvocationEXT";
+			case Op::OpConvertUToImageNV: return "OpConvertUToImageNV";
+			case Op::OpConvertUToSamplerNV: return "OpConvertUToSamplerNV";
+			case Op::OpConvertImageToUNV: return "OpConvertImageToUNV";
+			case Op::OpConvertSamplerToUNV: return "OpConvertSamplerToUNV";
+			case Op::OpConvertUToSampledImageNV: return "OpConvertUToSampledImageNV";
+			case Op::OpConvertSampledImageToUNV: return "OpConvertSampledImageToUNV";
+			case Op::OpSamplerImageAddressingModeNV: return "OpSamplerImageAddressingModeNV";
+			case Op::OpSubgroupShuffleINTEL: return "OpSubgroupShuffleINTEL";
+			case Op::OpSubgroupShuffleDownINTEL: return "OpSubgroupShuffleDownINTEL";
+			case Op::OpSubgroupShuffleUpINTEL: return "OpSubgroupShuffleUpINTEL";
+			case Op::OpSubgroupShuffleXorINTEL: return "OpSubgroupShuffleXorINTEL";
+			case Op::OpSubgroupBlockReadINTEL: return "OpSubgroupBlockReadINTEL";
+			case Op::OpSubgroupBlockWriteINTEL: return "OpSubgroupBlockWriteINTEL";
+			case Op::OpSubgroupImageBlockReadINTEL: return "OpSubgroupImageBlockReadINTEL";
+			case Op::OpSubgroupImageBlockWriteINTEL: return "OpSubgroupImageBlockWriteINTEL";
+			case Op::OpSubgroupImageMediaBlockReadINTEL: return "OpSubgroupImageMediaBlockReadINTEL";
+			case Op::OpSubgroupImageMediaBlockWriteINTEL: return "OpSubgroupImageMediaBlockWriteINTEL";
+			case Op::OpUCountLeadingZerosINTEL: return "OpUCountLeadingZerosINTEL";
+			case Op::OpUCountTrailingZerosINTEL: return "OpUCountTrailingZerosINTEL";
+			case Op::OpAbsISubINTEL: return "OpAbsISubINTEL";
+			case Op::OpAbsUSubINTEL: return "OpAbsUSubINTEL";
+			case Op::OpIAddSatINTEL: return "OpIAddSatINTEL";
+			case Op::OpUAddSatINTEL: return "OpUAddSatINTEL";
+			case Op::OpIAverageINTEL: return "OpIAverageINTEL";
+			case Op::OpUAverageINTEL: return "OpUAverageINTEL";
+			case Op::OpIAverageRoundedINTEL: return "OpIAverageRoundedINTEL";
+			case Op::OpUAverageRoundedINTEL: return "OpUAverageRoundedINTEL";
+			case Op::OpISubSatINTEL: return "OpISubSatINTEL";
+			case Op::OpUSubSatINTEL: return "OpUSubSatINTEL";
+			case Op::OpIMul32x16INTEL: return "OpIMul32x16INTEL";
+			case Op::OpUMul32x16INTEL: return "OpUMul32x16INTEL";
+			case Op::OpConstantFunctionPointerINTEL: return "OpConstantFunctionPointerINTEL";
+			case Op::OpFunctionPointerCallINTEL: return "OpFunctionPointerCallINTEL";
+			case Op::OpAsmTargetINTEL: return "OpAsmTargetINTEL";
+			case Op::OpAsmINTEL: return "OpAsmINTEL";
+			case Op::OpAsmCallINTEL: return "OpAsmCallINTEL";
+			case Op::OpAtomicFMinEXT: return "OpAtomicFMinEXT";
+			case Op::OpAtomicFMaxEXT: return "OpAtomicFMaxEXT";
+			case Op::OpAssumeTrueKHR: return "OpAssumeTrueKHR";
+			case Op::OpExpectKHR: return "OpExpectKHR";
+			case Op::OpDecorateString: return "OpDecorateString";
+			case Op::OpMemberDecorateString: return "OpMemberDecorateString";
+			case Op::OpVmeImageINTEL: return "OpVmeImageINTEL";
+			case Op::OpTypeVmeImageINTEL: return "OpTypeVmeImageINTEL";
+			case Op::OpTypeAvcImePayloadINTEL: return "OpTypeAvcImePayloadINTEL";
+			case Op::OpTypeAvcRefPayloadINTEL: return "OpTypeAvcRefPayloadINTEL";
+			case Op::OpTypeAvcSicPayloadINTEL: return "OpTypeAvcSicPayloadINTEL";
+			case Op::OpTypeAvcMcePayloadINTEL: return "OpTypeAvcMcePayloadINTEL";
+			case Op::OpTypeAvcMceResultINTEL: return "OpTypeAvcMceResultINTEL";
+			case Op::OpTypeAvcImeResultINTEL: return "OpTypeAvcImeResultINTEL";
+			case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return "OpTypeAvcImeResultSingleReferenceStreamoutINTEL";
+			case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return "OpTypeAvcImeResultDualReferenceStreamoutINTEL";
+			case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return "OpTypeAvcImeSingleReferenceStreaminINTEL";
+			case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return "OpTypeAvcImeDualReferenceStreaminINTEL";
+			case Op::OpTypeAvcRefResultINTEL: return "OpTypeAvcRefResultINTEL";
+			case Op::OpTypeAvcSicResultINTEL: return "OpTypeAvcSicResultINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return "OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL";
+			case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return "OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return "OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL";
+			case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return "OpSubgroupAvcMceSetInterShapePenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return "OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL";
+			case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return "OpSubgroupAvcMceSetInterDirectionPenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return "OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return "OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return "OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return "OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return "OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL";
+			case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return "OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return "OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return "OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL";
+			case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return "OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL";
+			case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return "OpSubgroupAvcMceSetAcOnlyHaarINTEL";
+			case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return "OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL";
+			case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return "OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL";
+			case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return "OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL";
+			case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return "OpSubgroupAvcMceConvertToImePayloadINTEL";
+			case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return "OpSubgroupAvcMceConvertToImeResultINTEL";
+			case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return "OpSubgroupAvcMceConvertToRefPayloadINTEL";
+			case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return "OpSubgroupAvcMceConvertToRefResultINTEL";
+			case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return "OpSubgroupAvcMceConvertToSicPayloadINTEL";
+			case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return "OpSubgroupAvcMceConvertToSicResultINTEL";
+			case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return "OpSubgroupAvcMceGetMotionVectorsINTEL";
+			case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return "OpSubgroupAvcMceGetInterDistortionsINTEL";
+			case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return "OpSubgroupAvcMceGetBestInterDistortionsINTEL";
+			case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return "OpSubgroupAvcMceGetInterMajorShapeINTEL";
+			case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return "OpSubgroupAvcMceGetInterMinorShapeINTEL";
+			case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return "OpSubgroupAvcMceGetInterDirectionsINTEL";
+			case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return "OpSubgroupAvcMceGetInterMotionVectorCountINTEL";
+			case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return "OpSubgroupAvcMceGetInterReferenceIdsINTEL";
+			case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return "OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL";
+			case Op::OpSubgroupAvcImeInitializeINTEL: return "OpSubgroupAvcImeInitializeINTEL";
+			case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return "OpSubgroupAvcImeSetSingleReferenceINTEL";
+			case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return "OpSubgroupAvcImeSetDualReferenceINTEL";
+			case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return "OpSubgroupAvcImeRefWindowSizeINTEL";
+			case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return "OpSubgroupAvcImeAdjustRefOffsetINTEL";
+			case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return "OpSubgroupAvcImeConvertToMcePayloadINTEL";
+			case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return "OpSubgroupAvcImeSetMaxMotionVectorCountINTEL";
+			case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return "OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL";
+			case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return "OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL";
+			case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return "OpSubgroupAvcImeSetWeightedSadINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return "OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return "OpSubgroupAvcImeEvaluateWithDualReferenceINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return "OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return "OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return "OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return "OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return "OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL";
+			case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return "OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL";
+			case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return "OpSubgroupAvcImeConvertToMceResultINTEL";
+			case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return "OpSubgroupAvcImeGetSingleReferenceStreaminINTEL";
+			case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return "OpSubgroupAvcImeGetDualReferenceStreaminINTEL";
+			case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return "OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL";
+			case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return "OpSubgroupAvcImeStripDualReferenceStreamoutINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL";
+			case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL";
+			case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return "OpSubgroupAvcImeGetBorderReachedINTEL";
+			case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return "OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL";
+			case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return "OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL";
+			case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return "OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL";
+			case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return "OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL";
+			case Op::OpSubgroupAvcFmeInitializeINTEL: return "OpSubgroupAvcFmeInitializeINTEL";
+			case Op::OpSubgroupAvcBmeInitializeINTEL: return "OpSubgroupAvcBmeInitializeINTEL";
+			case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return "OpSubgroupAvcRefConvertToMcePayloadINTEL";
+			case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return "OpSubgroupAvcRefSetBidirectionalMixDisableINTEL";
+			case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return "OpSubgroupAvcRefSetBilinearFilterEnableINTEL";
+			case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return "OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL";
+			case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return "OpSubgroupAvcRefEvaluateWithDualReferenceINTEL";
+			case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return "OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL";
+			case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return "OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL";
+			case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return "OpSubgroupAvcRefConvertToMceResultINTEL";
+			case Op::OpSubgroupAvcSicInitializeINTEL: return "OpSubgroupAvcSicInitializeINTEL";
+			case Op::OpSubgroupAvcSicConfigureSkcINTEL: return "OpSubgroupAvcSicConfigureSkcINTEL";
+			case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return "OpSubgroupAvcSicConfigureIpeLumaINTEL";
+			case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return "OpSubgroupAvcSicConfigureIpeLumaChromaINTEL";
+			case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return "OpSubgroupAvcSicGetMotionVectorMaskINTEL";
+			case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return "OpSubgroupAvcSicConvertToMcePayloadINTEL";
+			case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return "OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL";
+			case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return "OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL";
+			case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return "OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL";
+			case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return "OpSubgroupAvcSicSetBilinearFilterEnableINTEL";
+			case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return "OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL";
+			case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return "OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL";
+			case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return "OpSubgroupAvcSicEvaluateIpeINTEL";
+			case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return "OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL";
+			case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return "OpSubgroupAvcSicEvaluateWithDualReferenceINTEL";
+			case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return "OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL";
+			case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return "OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL";
+			case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return "OpSubgroupAvcSicConvertToMceResultINTEL";
+			case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return "OpSubgroupAvcSicGetIpeLumaShapeINTEL";
+			case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return "OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL";
+			case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return "OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL";
+			case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return "OpSubgroupAvcSicGetPackedIpeLumaModesINTEL";
+			case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return "OpSubgroupAvcSicGetIpeChromaModeINTEL";
+			case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return "OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL";
+			case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return "OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL";
+			case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return "OpSubgroupAvcSicGetInterRawSadsINTEL";
+			case Op::OpVariableLengthArrayINTEL: return "OpVariableLengthArrayINTEL";
+			case Op::OpSaveMemoryINTEL: return "OpSaveMemoryINTEL";
+			case Op::OpRestoreMemoryINTEL: return "OpRestoreMemoryINTEL";
+			case Op::OpArbitraryFloatSinCosPiINTEL: return "OpArbitraryFloatSinCosPiINTEL";
+			case Op::OpArbitraryFloatCastINTEL: return "OpArbitraryFloatCastINTEL";
+			case Op::OpArbitraryFloatCastFromIntINTEL: return "OpArbitraryFloatCastFromIntINTEL";
+			case Op::OpArbitraryFloatCastToIntINTEL: return "OpArbitraryFloatCastToIntINTEL";
+			case Op::OpArbitraryFloatAddINTEL: return "OpArbitraryFloatAddINTEL";
+			case Op::OpArbitraryFloatSubINTEL: return "OpArbitraryFloatSubINTEL";
+			case Op::OpArbitraryFloatMulINTEL: return "OpArbitraryFloatMulINTEL";
+			case Op::OpArbitraryFloatDivINTEL: return "OpArbitraryFloatDivINTEL";
+			case Op::OpArbitraryFloatGTINTEL: return "OpArbitraryFloatGTINTEL";
+			case Op::OpArbitraryFloatGEINTEL: return "OpArbitraryFloatGEINTEL";
+			case Op::OpArbitraryFloatLTINTEL: return "OpArbitraryFloatLTINTEL";
+			case Op::OpArbitraryFloatLEINTEL: return "OpArbitraryFloatLEINTEL";
+			case Op::OpArbitraryFloatEQINTEL: return "OpArbitraryFloatEQINTEL";
+			case Op::OpArbitraryFloatRecipINTEL: return "OpArbitraryFloatRecipINTEL";
+			case Op::OpArbitraryFloatRSqrtINTEL: return "OpArbitraryFloatRSqrtINTEL";
+			case Op::OpArbitraryFloatCbrtINTEL: return "OpArbitraryFloatCbrtINTEL";
+			case Op::OpArbitraryFloatHypotINTEL: return "OpArbitraryFloatHypotINTEL";
+			case Op::OpArbitraryFloatSqrtINTEL: return "OpArbitraryFloatSqrtINTEL";
+			case Op::OpArbitraryFloatLogINTEL: return "OpArbitraryFloatLogINTEL";
+			case Op::OpArbitraryFloatLog2INTEL: return "OpArbitraryFloatLog2INTEL";
+			case Op::OpArbitraryFloatLog10INTEL: return "OpArbitraryFloatLog10INTEL";
+			case Op::OpArbitraryFloatLog1pINTEL: return "OpArbitraryFloatLog1pINTEL";
+			case Op::OpArbitraryFloatExpINTEL: return "OpArbitraryFloatExpINTEL";
+			case Op::OpArbitraryFloatExp2INTEL: return "OpArbitraryFloatExp2INTEL";
+			case Op::OpArbitraryFloatExp10INTEL: return "OpArbitraryFloatExp10INTEL";
+			case Op::OpArbitraryFloatExpm1INTEL: return "OpArbitraryFloatExpm1INTEL";
+			case Op::OpArbitraryFloatSinINTEL: return "OpArbitraryFloatSinINTEL";
+			case Op::OpArbitraryFloatCosINTEL: return "OpArbitraryFloatCosINTEL";
+			case Op::OpArbitraryFloatSinCosINTEL: return "OpArbitraryFloatSinCosINTEL";
+			case Op::OpArbitraryFloatSinPiINTEL: return "OpArbitraryFloatSinPiINTEL";
+			case Op::OpArbitraryFloatCosPiINTEL: return "OpArbitraryFloatCosPiINTEL";
+			case Op::OpArbitraryFloatASinINTEL: return "OpArbitraryFloatASinINTEL";
+			case Op::OpArbitraryFloatASinPiINTEL: return "OpArbitraryFloatASinPiINTEL";
+			case Op::OpArbitraryFloatACosINTEL: return "OpArbitraryFloatACosINTEL";
+			case Op::OpArbitraryFloatACosPiINTEL: return "OpArbitraryFloatACosPiINTEL";
+			case Op::OpArbitraryFloatATanINTEL: return "OpArbitraryFloatATanINTEL";
+			case Op::OpArbitraryFloatATanPiINTEL: return "OpArbitraryFloatATanPiINTEL";
+			case Op::OpArbitraryFloatATan2INTEL: return "OpArbitraryFloatATan2INTEL";
+			case Op::OpArbitraryFloatPowINTEL: return "OpArbitraryFloatPowINTEL";
+			case Op::OpArbitraryFloatPowRINTEL: return "OpArbitraryFloatPowRINTEL";
+			case Op::OpArbitraryFloatPowNINTEL: return "OpArbitraryFloatPowNINTEL";
+			case Op::OpLoopControlINTEL: return "OpLoopControlINTEL";
+			case Op::OpAliasDomainDeclINTEL: return "OpAliasDomainDeclINTEL";
+			case Op::OpAliasScopeDeclINTEL: return "OpAliasScopeDeclINTEL";
+			case Op::OpAliasScopeListDeclINTEL: return "OpAliasScopeListDeclINTEL";
+			case Op::OpFixedSqrtINTEL: return "OpFixedSqrtINTEL";
+			case Op::OpFixedRecipINTEL: return "OpFixedRecipINTEL";
+			case Op::OpFixedRsqrtINTEL: return "OpFixedRsqrtINTEL";
+			case Op::OpFixedSinINTEL: return "OpFixedSinINTEL";
+			case Op::OpFixedCosINTEL: return "OpFixedCosINTEL";
+			case Op::OpFixedSinCosINTEL: return "OpFixedSinCosINTEL";
+			case Op::OpFixedSinPiINTEL: return "OpFixedSinPiINTEL";
+			case Op::OpFixedCosPiINTEL: return "OpFixedCosPiINTEL";
+			case Op::OpFixedSinCosPiINTEL: return "OpFixedSinCosPiINTEL";
+			case Op::OpFixedLogINTEL: return "OpFixedLogINTEL";
+			case Op::OpFixedExpINTEL: return "OpFixedExpINTEL";
+			case Op::OpPtrCastToCrossWorkgroupINTEL: return "OpPtrCastToCrossWorkgroupINTEL";
+			case Op::OpCrossWorkgroupCastToPtrINTEL: return "OpCrossWorkgroupCastToPtrINTEL";
+			case Op::OpReadPipeBlockingINTEL: return "OpReadPipeBlockingINTEL";
+			case Op::OpWritePipeBlockingINTEL: return "OpWritePipeBlockingINTEL";
+			case Op::OpFPGARegINTEL: return "OpFPGARegINTEL";
+			case Op::OpRayQueryGetRayTMinKHR: return "OpRayQueryGetRayTMinKHR";
+			case Op::OpRayQueryGetRayFlagsKHR: return "OpRayQueryGetRayFlagsKHR";
+			case Op::OpRayQueryGetIntersectionTKHR: return "OpRayQueryGetIntersectionTKHR";
+			case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return "OpRayQueryGetIntersectionInstanceCustomIndexKHR";
+			case Op::OpRayQueryGetIntersectionInstanceIdKHR: return "OpRayQueryGetIntersectionInstanceIdKHR";
+			case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return "OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR";
+			case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return "OpRayQueryGetIntersectionGeometryIndexKHR";
+			case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return "OpRayQueryGetIntersectionPrimitiveIndexKHR";
+			case Op::OpRayQueryGetIntersectionBarycentricsKHR: return "OpRayQueryGetIntersectionBarycentricsKHR";
+			case Op::OpRayQueryGetIntersectionFrontFaceKHR: return "OpRayQueryGetIntersectionFrontFaceKHR";
+			case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return "OpRayQueryGetIntersectionCandidateAABBOpaqueKHR";
+			case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return "OpRayQueryGetIntersectionObjectRayDirectionKHR";
+			case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return "OpRayQueryGetIntersectionObjectRayOriginKHR";
+			case Op::OpRayQueryGetWorldRayDirectionKHR: return "OpRayQueryGetWorldRayDirectionKHR";
+			case Op::OpRayQueryGetWorldRayOriginKHR: return "OpRayQueryGetWorldRayOriginKHR";
+			case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return "OpRayQueryGetIntersectionObjectToWorldKHR";
+			case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return "OpRayQueryGetIntersectionWorldToObjectKHR";
+			case Op::OpAtomicFAddEXT: return "OpAtomicFAddEXT";
+			case Op::OpTypeBufferSurfaceINTEL: return "OpTypeBufferSurfaceINTEL";
+			case Op::OpTypeStructContinuedINTEL: return "OpTypeStructContinuedINTEL";
+			case Op::OpConstantCompositeContinuedINTEL: return "OpConstantCompositeContinuedINTEL";
+			case Op::OpSpecConstantCompositeContinuedINTEL: return "OpSpecConstantCompositeContinuedINTEL";
+			case Op::OpConvertFToBF16INTEL: return "OpConvertFToBF16INTEL";
+			case Op::OpConvertBF16ToFINTEL: return "OpConvertBF16ToFINTEL";
+			case Op::OpControlBarrierArriveINTEL: return "OpControlBarrierArriveINTEL";
+			case Op::OpControlBarrierWaitINTEL: return "OpControlBarrierWaitINTEL";
+			case Op::OpGroupIMulKHR: return "OpGroupIMulKHR";
+			case Op::OpGroupFMulKHR: return "OpGroupFMulKHR";
+			case Op::OpGroupBitwiseAndKHR: return "OpGroupBitwiseAndKHR";
+			case Op::OpGroupBitwiseOrKHR: return "OpGroupBitwiseOrKHR";
+			case Op::OpGroupBitwiseXorKHR: return "OpGroupBitwiseXorKHR";
+			case Op::OpGroupLogicalAndKHR: return "OpGroupLogicalAndKHR";
+			case Op::OpGroupLogicalOrKHR: return "OpGroupLogicalOrKHR";
+			case Op::OpGroupLogicalXorKHR: return "OpGroupLogicalXorKHR";
+			}
+		}
+		inline const char* enumerantName(OperandKind _kind, unsigned int _value)
+		{
+			switch (_kind)
+			{
+			default: break;
+			case OperandKind::ImageOperands:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "Bias";
+				case 2u: return "Lod";
+				case 4u: return "Grad";
+				case 8u: return "ConstOffset";
+				case 16u: return "Offset";
+				case 32u: return "ConstOffsets";
+				case 64u: return "Sample";
+				case 128u: return "MinLod";
+				case 256u: return "MakeTexelAvailable";
+				case 512u: return "MakeTexelVisible";
+				case 1024u: return "NonPrivateTexel";
+				case 2048u: return "VolatileTexel";
+				case 4096u: return "SignExtend";
+				case 8192u: return "ZeroExtend";
+				case 16384u: return "Nontemporal";
+				case 65536u: return "Offsets";
+				}
+			case OperandKind::FPFastMathMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "NotNaN";
+				case 2u: return "NotInf";
+				case 4u: return "NSZ";
+				case 8u: return "AllowRecip";
+				case 16u: return "Fast";
+				case 65536u: return "AllowContractFastINTEL";
+				case 131072u: return "AllowReassocINTEL";
+				}
+			case OperandKind::SelectionControl:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "Flatten";
+				case 2u: return "DontFlatten";
+				}
+			case OperandKind::LoopControl:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "Unroll";
+				case 2u: return "DontUnroll";
+				case 4u: return "DependencyInfinite";
+				case 8u: return "DependencyLength";
+				case 16u: return "MinIterations";
+				case 32u: return "MaxIterations";
+				case 64u: return "IterationMultiple";
+				case 128u: return "PeelCount";
+				case 256u: return "PartialCount";
+				case 65536u: return "InitiationIntervalINTEL";
+				case 131072u: return "MaxConcurrencyINTEL";
+				case 262144u: return "DependencyArrayINTEL";
+				case 524288u: return "PipelineEnableINTEL";
+				case 1048576u: return "LoopCoalesceINTEL";
+				case 2097152u: return "MaxInterleavingINTEL";
+				case 4194304u: return "SpeculatedIterationsINTEL";
+				case 8388608u: return "NoFusionINTEL";
+				case 16777216u: return "LoopCountINTEL";
+				case 33554432u: return "MaxReinvocationDelayINTEL";
+				}
+			case OperandKind::FunctionControl:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "Inline";
+				case 2u: return "DontInline";
+				case 4u: return "Pure";
+				case 8u: return "Const";
+				case 65536u: return "OptNoneINTEL";
+				}
+			case OperandKind::MemorySemantics:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Relaxed";
+				case 2u: return "Acquire";
+				case 4u: return "Release";
+				case 8u: return "AcquireRelease";
+				case 16u: return "SequentiallyConsistent";
+				case 64u: return "UniformMemory";
+				case 128u: return "SubgroupMemory";
+				case 256u: return "WorkgroupMemory";
+				case 512u: return "CrossWorkgroupMemory";
+				case 1024u: return "AtomicCounterMemory";
+				case 2048u: return "ImageMemory";
+				case 4096u: return "OutputMemory";
+				case 8192u: return "MakeAvailable";
+				case 16384u: return "MakeVisible";
+				case 32768u: return "Volatile";
+				}
+			case OperandKind::MemoryAccess:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "Volatile";
+				case 2u: return "Aligned";
+				case 4u: return "Nontemporal";
+				case 8u: return "MakePointerAvailable";
+				case 16u: return "MakePointerVisible";
+				case 32u: return "NonPrivatePointer";
+				case 65536u: return "AliasScopeINTELMask";
+				case 131072u: return "NoAliasINTELMask";
+				}
+			case OperandKind::KernelProfilingInfo:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "CmdExecTime";
+				}
+			case OperandKind::RayFlags:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "NoneKHR";
+				case 1u: return "OpaqueKHR";
+				case 2u: return "NoOpaqueKHR";
+				case 4u: return "TerminateOnFirstHitKHR";
+				case 8u: return "SkipClosestHitShaderKHR";
+				case 16u: return "CullBackFacingTrianglesKHR";
+				case 32u: return "CullFrontFacingTrianglesKHR";
+				case 64u: return "CullOpaqueKHR";
+				case 128u: return "CullNoOpaqueKHR";
+				case 256u: return "SkipTrianglesKHR";
+				case 512u: return "SkipAABBsKHR";
+				case 1024u: return "ForceOpacityMicromap2StateEXT";
+				}
+			case OperandKind::FragmentShadingRate:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 1u: return "Vertical2Pixels";
+				case 2u: return "Vertical4Pixels";
+				case 4u: return "Horizontal2Pixels";
+				case 8u: return "Horizontal4Pixels";
+				}
+			case OperandKind::SourceLanguage:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Unknown";
+				case 1u: return "ESSL";
+				case 2u: return "GLSL";
+				case 3u: return "OpenCL_C";
+				case 4u: return "OpenCL_CPP";
+				case 5u: return "HLSL";
+				case 6u: return "CPP_for_OpenCL";
+				case 7u: return "SYCL";
+				case 8u: return "HERO_C";
+				case 9u: return "NZSL";
+				case 10u: return "WGSL";
+				}
+			case OperandKind::ExecutionModel:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Vertex";
+				case 1u: return "TessellationControl";
+				case 2u: return "TessellationEvaluation";
+				case 3u: return "Geometry";
+				case 4u: return "Fragment";
+				case 5u: return "GLCompute";
+				case 6u: return "Kernel";
+				case 5267u: return "TaskNV";
+				case 5268u: return "MeshNV";
+				case 5313u: return "RayGenerationNV";
+				case 5314u: return "IntersectionNV";
+				case 5315u: return "AnyHitNV";
+				case 5316u: return "ClosestHitNV";
+				case 5317u: return "MissNV";
+				case 5318u: return "CallableNV";
+				case 5364u: return "TaskEXT";
+				case 5365u: return "MeshEXT";
+				}
+			case OperandKind::AddressingModel:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Logical";
+				case 1u: return "Physical32";
+				case 2u: return "Physical64";
+				case 5348u: return "PhysicalStorageBuffer64";
+				}
+			case OperandKind::MemoryModel:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Simple";
+				case 1u: return "GLSL450";
+				case 2u: return "OpenCL";
+				case 3u: return "Vulkan";
+				}
+			case OperandKind::ExecutionMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Invocations";
+				case 1u: return "SpacingEqual";
+				case 2u: return "SpacingFractionalEven";
+				case 3u: return "SpacingFractionalOdd";
+				case 4u: return "VertexOrderCw";
+				case 5u: return "VertexOrderCcw";
+				case 6u: return "PixelCenterInteger";
+				case 7u: return "OriginUpperLeft";
+				case 8u: return "OriginLowerLeft";
+				case 9u: return "EarlyFragmentTests";
+				case 10u: return "PointMode";
+				case 11u: return "Xfb";
+				case 12u: return "DepthReplacing";
+				case 14u: return "DepthGreater";
+				case 15u: return "DepthLess";
+				case 16u: return "DepthUnchanged";
+				case 17u: return "LocalSize";
+				case 18u: return "LocalSizeHint";
+				case 19u: return "InputPoints";
+				case 20u: return "InputLines";
+				case 21u: return "InputLinesAdjacency";
+				case 22u: return "Triangles";
+				case 23u: return "InputTrianglesAdjacency";
+				case 24u: return "Quads";
+				case 25u: return "Isolines";
+				case 26u: return "OutputVertices";
+				case 27u: return "OutputPoints";
+				case 28u: return "OutputLineStrip";
+				case 29u: return "OutputTriangleStrip";
+				case 30u: return "VecTypeHint";
+				case 31u: return "ContractionOff";
+				case 33u: return "Initializer";
+				case 34u: return "Finalizer";
+				case 35u: return "SubgroupSize";
+				case 36u: return "SubgroupsPerWorkgroup";
+				case 37u: return "SubgroupsPerWorkgroupId";
+				case 38u: return "LocalSizeId";
+				case 39u: return "LocalSizeHintId";
+				case 4169u: return "NonCoherentColorAttachmentReadEXT";
+				case 4170u: return "NonCoherentDepthAttachmentReadEXT";
+				case 4171u: return "NonCoherentStencilAttachmentReadEXT";
+				case 4421u: return "SubgroupUniformControlFlowKHR";
+				case 4446u: return "PostDepthCoverage";
+				case 4459u: return "DenormPreserve";
+				case 4460u: return "DenormFlushToZero";
+				case 4461u: return "SignedZeroInfNanPreserve";
+				case 4462u: return "RoundingModeRTE";
+				case 4463u: return "RoundingModeRTZ";
+				case 5017u: return "EarlyAndLateFragmentTestsAMD";
+				case 5027u: return "StencilRefReplacingEXT";
+				case 5069u: return "CoalescingAMDX";
+				case 5071u: return "MaxNodeRecursionAMDX";
+				case 5072u: return "StaticNumWorkgroupsAMDX";
+				case 5073u: return "ShaderIndexAMDX";
+				case 5077u: return "MaxNumWorkgroupsAMDX";
+				case 5079u: return "StencilRefUnchangedFrontAMD";
+				case 5080u: return "StencilRefGreaterFrontAMD";
+				case 5081u: return "StencilRefLessFrontAMD";
+				case 5082u: return "StencilRefUnchangedBackAMD";
+				case 5083u: return "StencilRefGreaterBackAMD";
+				case 5084u: return "StencilRefLessBackAMD";
+				case 5269u: return "OutputLinesNV";
+				case 5270u: return "OutputPrimitivesNV";
+				case 5289u: return "DerivativeGroupQuadsNV";
+				case 5290u: return "DerivativeGroupLinearNV";
+				case 5298u: return "OutputTrianglesNV";
+				case 5366u: return "PixelInterlockOrderedEXT";
+				case 5367u: return "PixelInterlockUnorderedEXT";
+				case 5368u: return "SampleInterlockOrderedEXT";
+				case 5369u: return "SampleInterlockUnorderedEXT";
+				case 5370u: return "ShadingRateInterlockOrderedEXT";
+				case 5371u: return "ShadingRateInterlockUnorderedEXT";
+				case 5618u: return "SharedLocalMemorySizeINTEL";
+				case 5620u: return "RoundingModeRTPINTEL";
+				case 5621u: return "RoundingModeRTNINTEL";
+				case 5622u: return "FloatingPointModeALTINTEL";
+				case 5623u: return "FloatingPointModeIEEEINTEL";
+				case 5893u: return "MaxWorkgroupSizeINTEL";
+				case 5894u: return "MaxWorkDimINTEL";
+				case 5895u: return "NoGlobalOffsetINTEL";
+				case 5896u: return "NumSIMDWorkitemsINTEL";
+				case 5903u: return "SchedulerTargetFmaxMhzINTEL";
+				case 6154u: return "StreamingInterfaceINTEL";
+				case 6160u: return "RegisterMapInterfaceINTEL";
+				case 6417u: return "NamedBarrierCountINTEL";
+				}
+			case OperandKind::StorageClass:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "UniformConstant";
+				case 1u: return "Input";
+				case 2u: return "Uniform";
+				case 3u: return "Output";
+				case 4u: return "Workgroup";
+				case 5u: return "CrossWorkgroup";
+				case 6u: return "Private";
+				case 7u: return "Function";
+				case 8u: return "Generic";
+				case 9u: return "PushConstant";
+				case 10u: return "AtomicCounter";
+				case 11u: return "Image";
+				case 12u: return "StorageBuffer";
+				case 4172u: return "TileImageEXT";
+				case 5068u: return "NodePayloadAMDX";
+				case 5076u: return "NodeOutputPayloadAMDX";
+				case 5328u: return "CallableDataNV";
+				case 5329u: return "IncomingCallableDataNV";
+				case 5338u: return "RayPayloadNV";
+				case 5339u: return "HitAttributeNV";
+				case 5342u: return "IncomingRayPayloadNV";
+				case 5343u: return "ShaderRecordBufferNV";
+				case 5349u: return "PhysicalStorageBuffer";
+				case 5385u: return "HitObjectAttributeNV";
+				case 5402u: return "TaskPayloadWorkgroupEXT";
+				case 5605u: return "CodeSectionINTEL";
+				case 5936u: return "DeviceOnlyINTEL";
+				case 5937u: return "HostOnlyINTEL";
+				}
+			case OperandKind::Dim:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "1D";
+				case 1u: return "2D";
+				case 2u: return "3D";
+				case 3u: return "Cube";
+				case 4u: return "Rect";
+				case 5u: return "Buffer";
+				case 6u: return "SubpassData";
+				case 4173u: return "TileImageDataEXT";
+				}
+			case OperandKind::SamplerAddressingMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "None";
+				case 1u: return "ClampToEdge";
+				case 2u: return "Clamp";
+				case 3u: return "Repeat";
+				case 4u: return "RepeatMirrored";
+				}
+			case OperandKind::SamplerFilterMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Nearest";
+				case 1u: return "Linear";
+				}
+			case OperandKind::ImageFormat:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Unknown";
+				case 1u: return "Rgba32f";
+				case 2u: return "Rgba16f";
+				case 3u: return "R32f";
+				case 4u: return "Rgba8";
+				case 5u: return "Rgba8Snorm";
+				case 6u: return "Rg32f";
+				case 7u: return "Rg16f";
+				case 8u: return "R11fG11fB10f";
+				case 9u: return "R16f";
+				case 10u: return "Rgba16";
+				case 11u: return "Rgb10A2";
+				case 12u: return "Rg16";
+				case 13u: return "Rg8";
+				case 14u: return "R16";
+				case 15u: return "R8";
+				case 16u: return "Rgba16Snorm";
+				case 17u: return "Rg16Snorm";
+				case 18u: return "Rg8Snorm";
+				case 19u: return "R16Snorm";
+				case 20u: return "R8Snorm";
+				case 21u: return "Rgba32i";
+				case 22u: return "Rgba16i";
+				case 23u: return "Rgba8i";
+				case 24u: return "R32i";
+				case 25u: return "Rg32i";
+				case 26u: return "Rg16i";
+				case 27u: return "Rg8i";
+				case 28u: return "R16i";
+				case 29u: return "R8i";
+				case 30u: return "Rgba32ui";
+				case 31u: return "Rgba16ui";
+				case 32u: return "Rgba8ui";
+				case 33u: return "R32ui";
+				case 34u: return "Rgb10a2ui";
+				case 35u: return "Rg32ui";
+				case 36u: return "Rg16ui";
+				case 37u: return "Rg8ui";
+				case 38u: return "R16ui";
+				case 39u: return "R8ui";
+				case 40u: return "R64ui";
+				case 41u: return "R64i";
+				}
+			case OperandKind::ImageChannelOrder:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "R";
+				case 1u: return "A";
+				case 2u: return "RG";
+				case 3u: return "RA";
+				case 4u: return "RGB";
+				case 5u: return "RGBA";
+				case 6u: return "BGRA";
+				case 7u: return "ARGB";
+				case 8u: return "Intensity";
+				case 9u: return "Luminance";
+				case 10u: return "Rx";
+				case 11u: return "RGx";
+				case 12u: return "RGBx";
+				case 13u: return "Depth";
+				case 14u: return "DepthStencil";
+				case 15u: return "sRGB";
+				case 16u: return "sRGBx";
+				case 17u: return "sRGBA";
+				case 18u: return "sBGRA";
+				case 19u: return "ABGR";
+				}
+			case OperandKind::ImageChannelDataType:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "SnormInt8";
+				case 1u: return "SnormInt16";
+				case 2u: return "UnormInt8";
+				case 3u: return "UnormInt16";
+				case 4u: return "UnormShort565";
+				case 5u: return "UnormShort555";
+				case 6u: return "UnormInt101010";
+				case 7u: return "SignedInt8";
+				case 8u: return "SignedInt16";
+				case 9u: return "SignedInt32";
+				case 10u: return "UnsignedInt8";
+				case 11u: return "UnsignedInt16";
+				case 12u: return "UnsignedInt32";
+				case 13u: return "HalfFloat";
+				case 14u: return "Float";
+				case 15u: return "UnormInt24";
+				case 16u: return "UnormInt101010_2";
+				case 19u: return "UnsignedIntRaw10EXT";
+				case 20u: return "UnsignedIntRaw12EXT";
+				}
+			case OperandKind::FPRoundingMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RTE";
+				case 1u: return "RTZ";
+				case 2u: return "RTP";
+				case 3u: return "RTN";
+				}
+			case OperandKind::FPDenormMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Preserve";
+				case 1u: return "FlushToZero";
+				}
+			case OperandKind::QuantizationModes:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "TRN";
+				case 1u: return "TRN_ZERO";
+				case 2u: return "RND";
+				case 3u: return "RND_ZERO";
+				case 4u: return "RND_INF";
+				case 5u: return "RND_MIN_INF";
+				case 6u: return "RND_CONV";
+				case 7u: return "RND_CONV_ODD";
+				}
+			case OperandKind::FPOperationMode:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "IEEE";
+				case 1u: return "ALT";
+				}
+			case OperandKind::OverflowModes:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "WRAP";
+				case 1u: return "SAT";
+				case 2u: return "SAT_ZERO";
+				case 3u: return "SAT_SYM";
+				}
+			case OperandKind::LinkageType:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Export";
+				case 1u: return "Import";
+				case 2u: return "LinkOnceODR";
+				}
+			case OperandKind::AccessQualifier:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "ReadOnly";
+				case 1u: return "WriteOnly";
+				case 2u: return "ReadWrite";
+				}
+			case OperandKind::HostAccessQualifier:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "NoneINTEL";
+				case 1u: return "ReadINTEL";
+				case 2u: return "WriteINTEL";
+				case 3u: return "ReadWriteINTEL";
+				}
+			case OperandKind::FunctionParameterAttribute:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Zext";
+				case 1u: return "Sext";
+				case 2u: return "ByVal";
+				case 3u: return "Sret";
+				case 4u: return "NoAlias";
+				case 5u: return "NoCapture";
+				case 6u: return "NoWrite";
+				case 7u: return "NoReadWrite";
+				case 5940u: return "RuntimeAlignedINTEL";
+				}
+			case OperandKind::Decoration:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RelaxedPrecision";
+				case 1u: return "SpecId";
+				case 2u: return "Block";
+				case 3u: return "BufferBlock";
+				case 4u: return "RowMajor";
+				case 5u: return "ColMajor";
+				case 6u: return "ArrayStride";
+				case 7u: return "MatrixStride";
+				case 8u: return "GLSLShared";
+				case 9u: return "GLSLPacked";
+				case 10u: return "CPacked";
+				case 11u: return "BuiltIn";
+				case 13u: return "NoPerspective";
+				case 14u: return "Flat";
+				case 15u: return "Patch";
+				case 16u: return "Centroid";
+				case 17u: return "Sample";
+				case 18u: return "Invariant";
+				case 19u: return "Restrict";
+				case 20u: return "Aliased";
+				case 21u: return "Volatile";
+				case 22u: return "Constant";
+				case 23u: return "Coherent";
+				case 24u: return "NonWritable";
+				case 25u: return "NonReadable";
+				case 26u: return "Uniform";
+				case 27u: return "UniformId";
+				case 28u: return "SaturatedConversion";
+				case 29u: return "Stream";
+				case 30u: return "Location";
+				case 31u: return "Component";
+				case 32u: return "Index";
+				case 33u: return "Binding";
+				case 34u: return "DescriptorSet";
+				case 35u: return "Offset";
+				case 36u: return "XfbBuffer";
+				case 37u: return "XfbStride";
+				case 38u: return "FuncParamAttr";
+				case 39u: return "FPRoundingMode";
+				case 40u: return "FPFastMathMode";
+				case 41u: return "LinkageAttributes";
+				case 42u: return "NoContraction";
+				case 43u: return "InputAttachmentIndex";
+				case 44u: return "Alignment";
+				case 45u: return "MaxByteOffset";
+				case 46u: return "AlignmentId";
+				case 47u: return "MaxByteOffsetId";
+				case 4469u: return "NoSignedWrap";
+				case 4470u: return "NoUnsignedWrap";
+				case 4487u: return "WeightTextureQCOM";
+				case 4488u: return "BlockMatchTextureQCOM";
+				case 4999u: return "ExplicitInterpAMD";
+				case 5019u: return "NodeSharesPayloadLimitsWithAMDX";
+				case 5020u: return "NodeMaxPayloadsAMDX";
+				case 5078u: return "TrackFinishWritingAMDX";
+				case 5091u: return "PayloadNodeNameAMDX";
+				case 5248u: return "OverrideCoverageNV";
+				case 5250u: return "PassthroughNV";
+				case 5252u: return "ViewportRelativeNV";
+				case 5256u: return "SecondaryViewportRelativeNV";
+				case 5271u: return "PerPrimitiveNV";
+				case 5272u: return "PerViewNV";
+				case 5273u: return "PerTaskNV";
+				case 5285u: return "PerVertexKHR";
+				case 5300u: return "NonUniform";
+				case 5355u: return "RestrictPointer";
+				case 5356u: return "AliasedPointer";
+				case 5386u: return "HitObjectShaderRecordBufferNV";
+				case 5398u: return "BindlessSamplerNV";
+				case 5399u: return "BindlessImageNV";
+				case 5400u: return "BoundSamplerNV";
+				case 5401u: return "BoundImageNV";
+				case 5599u: return "SIMTCallINTEL";
+				case 5602u: return "ReferencedIndirectlyINTEL";
+				case 5607u: return "ClobberINTEL";
+				case 5608u: return "SideEffectsINTEL";
+				case 5624u: return "VectorComputeVariableINTEL";
+				case 5625u: return "FuncParamIOKindINTEL";
+				case 5626u: return "VectorComputeFunctionINTEL";
+				case 5627u: return "StackCallINTEL";
+				case 5628u: return "GlobalVariableOffsetINTEL";
+				case 5634u: return "CounterBuffer";
+				case 5635u: return "UserSemantic";
+				case 5636u: return "UserTypeGOOGLE";
+				case 5822u: return "FunctionRoundingModeINTEL";
+				case 5823u: return "FunctionDenormModeINTEL";
+				case 5825u: return "RegisterINTEL";
+				case 5826u: return "MemoryINTEL";
+				case 5827u: return "NumbanksINTEL";
+				case 5828u: return "BankwidthINTEL";
+				case 5829u: return "MaxPrivateCopiesINTEL";
+				case 5830u: return "SinglepumpINTEL";
+				case 5831u: return "DoublepumpINTEL";
+				case 5832u: return "MaxReplicatesINTEL";
+				case 5833u: return "SimpleDualPortINTEL";
+				case 5834u: return "MergeINTEL";
+				case 5835u: return "BankBitsINTEL";
+				case 5836u: return "ForcePow2DepthINTEL";
+				case 5899u: return "BurstCoalesceINTEL";
+				case 5900u: return "CacheSizeINTEL";
+				case 5901u: return "DontStaticallyCoalesceINTEL";
+				case 5902u: return "PrefetchINTEL";
+				case 5905u: return "StallEnableINTEL";
+				case 5907u: return "FuseLoopsInFunctionINTEL";
+				case 5909u: return "MathOpDSPModeINTEL";
+				case 5914u: return "AliasScopeINTEL";
+				case 5915u: return "NoAliasINTEL";
+				case 5917u: return "InitiationIntervalINTEL";
+				case 5918u: return "MaxConcurrencyINTEL";
+				case 5919u: return "PipelineEnableINTEL";
+				case 5921u: return "BufferLocationINTEL";
+				case 5944u: return "IOPipeStorageINTEL";
+				case 6080u: return "FunctionFloatingPointModeINTEL";
+				case 6085u: return "SingleElementVectorINTEL";
+				case 6087u: return "VectorComputeCallableFunctionINTEL";
+				case 6140u: return "MediaBlockIOINTEL";
+				case 6147u: return "InitModeINTEL";
+				case 6148u: return "ImplementInRegisterMapINTEL";
+				case 6168u: return "HostAccessINTEL";
+				case 6170u: return "FPMaxErrorDecorationINTEL";
+				case 6172u: return "LatencyControlLabelINTEL";
+				case 6173u: return "LatencyControlConstraintINTEL";
+				case 6175u: return "ConduitKernelArgumentINTEL";
+				case 6176u: return "RegisterMapKernelArgumentINTEL";
+				case 6177u: return "MMHostInterfaceAddressWidthINTEL";
+				case 6178u: return "MMHostInterfaceDataWidthINTEL";
+				case 6179u: return "MMHostInterfaceLatencyINTEL";
+				case 6180u: return "MMHostInterfaceReadWriteModeINTEL";
+				case 6181u: return "MMHostInterfaceMaxBurstINTEL";
+				case 6182u: return "MMHostInterfaceWaitRequestINTEL";
+				case 6183u: return "StableKernelArgumentINTEL";
+				case 6442u: return "CacheControlLoadINTEL";
+				case 6443u: return "CacheControlStoreINTEL";
+				}
+			case OperandKind::BuiltIn:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Position";
+				case 1u: return "PointSize";
+				case 3u: return "ClipDistance";
+				case 4u: return "CullDistance";
+				case 5u: return "VertexId";
+				case 6u: return "InstanceId";
+				case 7u: return "PrimitiveId";
+				case 8u: return "InvocationId";
+				case 9u: return "Layer";
+				case 10u: return "ViewportIndex";
+				case 11u: return "TessLevelOuter";
+				case 12u: return "TessLevelInner";
+				case 13u: return "TessCoord";
+				case 14u: return "PatchVertices";
+				case 15u: return "FragCoord";
+				case 16u: return "PointCoord";
+				case 17u: return "FrontFacing";
+				case 18u: return "SampleId";
+				case 19u: return "SamplePosition";
+				case 20u: return "SampleMask";
+				case 22u: return "FragDepth";
+				case 23u: return "HelperInvocation";
+				case 24u: return "NumWorkgroups";
+				case 25u: return "WorkgroupSize";
+				case 26u: return "WorkgroupId";
+				case 27u: return "LocalInvocationId";
+				case 28u: return "GlobalInvocationId";
+				case 29u: return "LocalInvocationIndex";
+				case 30u: return "WorkDim";
+				case 31u: return "GlobalSize";
+				case 32u: return "EnqueuedWorkgroupSize";
+				case 33u: return "GlobalOffset";
+				case 34u: return "GlobalLinearId";
+				case 36u: return "SubgroupSize";
+				case 37u: return "SubgroupMaxSize";
+				case 38u: return "NumSubgroups";
+				case 39u: return "NumEnqueuedSubgroups";
+				case 40u: return "SubgroupId";
+				case 41u: return "SubgroupLocalInvocationId";
+				case 42u: return "VertexIndex";
+				case 43u: return "InstanceIndex";
+				case 4160u: return "CoreIDARM";
+				case 4161u: return "CoreCountARM";
+				case 4162u: return "CoreMaxIDARM";
+				case 4163u: return "WarpIDARM";
+				case 4164u: return "WarpMaxIDARM";
+				case 4416u: return "SubgroupEqMask";
+				case 4417u: return "SubgroupGeMask";
+				case 4418u: return "SubgroupGtMask";
+				case 4419u: return "SubgroupLeMask";
+				case 4420u: return "SubgroupLtMask";
+				case 4424u: return "BaseVertex";
+				case 4425u: return "BaseInstance";
+				case 4426u: return "DrawIndex";
+				case 4432u: return "PrimitiveShadingRateKHR";
+				case 4438u: return "DeviceIndex";
+				case 4440u: return "ViewIndex";
+				case 4444u: return "ShadingRateKHR";
+				case 4992u: return "BaryCoordNoPerspAMD";
+				case 4993u: return "BaryCoordNoPerspCentroidAMD";
+				case 4994u: return "BaryCoordNoPerspSampleAMD";
+				case 4995u: return "BaryCoordSmoothAMD";
+				case 4996u: return "BaryCoordSmoothCentroidAMD";
+				case 4997u: return "BaryCoordSmoothSampleAMD";
+				case 4998u: return "BaryCoordPullModelAMD";
+				case 5014u: return "FragStencilRefEXT";
+				case 5021u: return "CoalescedInputCountAMDX";
+				case 5073u: return "ShaderIndexAMDX";
+				case 5253u: return "ViewportMaskNV";
+				case 5257u: return "SecondaryPositionNV";
+				case 5258u: return "SecondaryViewportMaskNV";
+				case 5261u: return "PositionPerViewNV";
+				case 5262u: return "ViewportMaskPerViewNV";
+				case 5264u: return "FullyCoveredEXT";
+				case 5274u: return "TaskCountNV";
+				case 5275u: return "PrimitiveCountNV";
+				case 5276u: return "PrimitiveIndicesNV";
+				case 5277u: return "ClipDistancePerViewNV";
+				case 5278u: return "CullDistancePerViewNV";
+				case 5279u: return "LayerPerViewNV";
+				case 5280u: return "MeshViewCountNV";
+				case 5281u: return "MeshViewIndicesNV";
+				case 5286u: return "BaryCoordKHR";
+				case 5287u: return "BaryCoordNoPerspKHR";
+				case 5292u: return "FragSizeEXT";
+				case 5293u: return "FragInvocationCountEXT";
+				case 5294u: return "PrimitivePointIndicesEXT";
+				case 5295u: return "PrimitiveLineIndicesEXT";
+				case 5296u: return "PrimitiveTriangleIndicesEXT";
+				case 5299u: return "CullPrimitiveEXT";
+				case 5319u: return "LaunchIdNV";
+				case 5320u: return "LaunchSizeNV";
+				case 5321u: return "WorldRayOriginNV";
+				case 5322u: return "WorldRayDirectionNV";
+				case 5323u: return "ObjectRayOriginNV";
+				case 5324u: return "ObjectRayDirectionNV";
+				case 5325u: return "RayTminNV";
+				case 5326u: return "RayTmaxNV";
+				case 5327u: return "InstanceCustomIndexNV";
+				case 5330u: return "ObjectToWorldNV";
+				case 5331u: return "WorldToObjectNV";
+				case 5332u: return "HitTNV";
+				case 5333u: return "HitKindNV";
+				case 5334u: return "CurrentRayTimeNV";
+				case 5335u: return "HitTriangleVertexPositionsKHR";
+				case 5351u: return "IncomingRayFlagsNV";
+				case 5352u: return "RayGeometryIndexKHR";
+				case 5374u: return "WarpsPerSMNV";
+				case 5375u: return "SMCountNV";
+				case 5376u: return "WarpIDNV";
+				case 5377u: return "SMIDNV";
+				case 6021u: return "CullMaskKHR";
+				}
+			case OperandKind::Scope:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "CrossDevice";
+				case 1u: return "Device";
+				case 2u: return "Workgroup";
+				case 3u: return "Subgroup";
+				case 4u: return "Invocation";
+				case 5u: return "QueueFamily";
+				case 6u: return "ShaderCallKHR";
+				}
+			case OperandKind::GroupOperation:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Reduce";
+				case 1u: return "InclusiveScan";
+				case 2u: return "ExclusiveScan";
+				case 3u: return "ClusteredReduce";
+				case 6u: return "PartitionedReduceNV";
+				case 7u: return "PartitionedInclusiveScanNV";
+				case 8u: return "PartitionedExclusiveScanNV";
+				}
+			case OperandKind::KernelEnqueueFlags:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "NoWait";
+				case 1u: return "WaitKernel";
+				case 2u: return "WaitWorkGroup";
+				}
+			case OperandKind::Capability:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "Matrix";
+				case 1u: return "Shader";
+				case 2u: return "Geometry";
+				case 3u: return "Tessellation";
+				case 4u: return "Addresses";
+				case 5u: return "Linkage";
+				case 6u: return "Kernel";
+				case 7u: return "Vector16";
+				case 8u: return "Float16Buffer";
+				case 9u: return "Float16";
+				case 10u: return "Float64";
+				case 11u: return "Int64";
+				case 12u: return "Int64Atomics";
+				case 13u: return "ImageBasic";
+				case 14u: return "ImageReadWrite";
+				case 15u: return "ImageMipmap";
+				case 17u: return "Pipes";
+				case 18u: return "Groups";
+				case 19u: return "DeviceEnqueue";
+				case 20u: return "LiteralSampler";
+				case 21u: return "AtomicStorage";
+				case 22u: return "Int16";
+				case 23u: return "TessellationPointSize";
+				case 24u: return "GeometryPointSize";
+				case 25u: return "ImageGatherExtended";
+				case 27u: return "StorageImageMultisample";
+				case 28u: return "UniformBufferArrayDynamicIndexing";
+				case 29u: return "SampledImageArrayDynamicIndexing";
+				case 30u: return "StorageBufferArrayDynamicIndexing";
+				case 31u: return "StorageImageArrayDynamicIndexing";
+				case 32u: return "ClipDistance";
+				case 33u: return "CullDistance";
+				case 34u: return "ImageCubeArray";
+				case 35u: return "SampleRateShading";
+				case 36u: return "ImageRect";
+				case 37u: return "SampledRect";
+				case 38u: return "GenericPointer";
+				case 39u: return "Int8";
+				case 40u: return "InputAttachment";
+				case 41u: return "SparseResidency";
+				case 42u: return "MinLod";
+				case 43u: return "Sampled1D";
+				case 44u: return "Image1D";
+				case 45u: return "SampledCubeArray";
+				case 46u: return "SampledBuffer";
+				case 47u: return "ImageBuffer";
+				case 48u: return "ImageMSArray";
+				case 49u: return "StorageImageExtendedFormats";
+				case 50u: return "ImageQuery";
+				case 51u: return "DerivativeControl";
+				case 52u: return "InterpolationFunction";
+				case 53u: return "TransformFeedback";
+				case 54u: return "GeometryStreams";
+				case 55u: return "StorageImageReadWithoutFormat";
+				case 56u: return "StorageImageWriteWithoutFormat";
+				case 57u: return "MultiViewport";
+				case 58u: return "SubgroupDispatch";
+				case 59u: return "NamedBarrier";
+				case 60u: return "PipeStorage";
+				case 61u: return "GroupNonUniform";
+				case 62u: return "GroupNonUniformVote";
+				case 63u: return "GroupNonUniformArithmetic";
+				case 64u: return "GroupNonUniformBallot";
+				case 65u: return "GroupNonUniformShuffle";
+				case 66u: return "GroupNonUniformShuffleRelative";
+				case 67u: return "GroupNonUniformClustered";
+				case 68u: return "GroupNonUniformQuad";
+				case 69u: return "ShaderLayer";
+				case 70u: return "ShaderViewportIndex";
+				case 71u: return "UniformDecoration";
+				case 4165u: return "CoreBuiltinsARM";
+				case 4166u: return "TileImageColorReadAccessEXT";
+				case 4167u: return "TileImageDepthReadAccessEXT";
+				case 4168u: return "TileImageStencilReadAccessEXT";
+				case 4422u: return "FragmentShadingRateKHR";
+				case 4423u: return "SubgroupBallotKHR";
+				case 4427u: return "DrawParameters";
+				case 4428u: return "WorkgroupMemoryExplicitLayoutKHR";
+				case 4429u: return "WorkgroupMemoryExplicitLayout8BitAccessKHR";
+				case 4430u: return "WorkgroupMemoryExplicitLayout16BitAccessKHR";
+				case 4431u: return "SubgroupVoteKHR";
+				case 4433u: return "StorageBuffer16BitAccess";
+				case 4434u: return "UniformAndStorageBuffer16BitAccess";
+				case 4435u: return "StoragePushConstant16";
+				case 4436u: return "StorageInputOutput16";
+				case 4437u: return "DeviceGroup";
+				case 4439u: return "MultiView";
+				case 4441u: return "VariablePointersStorageBuffer";
+				case 4442u: return "VariablePointers";
+				case 4445u: return "AtomicStorageOps";
+				case 4447u: return "SampleMaskPostDepthCoverage";
+				case 4448u: return "StorageBuffer8BitAccess";
+				case 4449u: return "UniformAndStorageBuffer8BitAccess";
+				case 4450u: return "StoragePushConstant8";
+				case 4464u: return "DenormPreserve";
+				case 4465u: return "DenormFlushToZero";
+				case 4466u: return "SignedZeroInfNanPreserve";
+				case 4467u: return "RoundingModeRTE";
+				case 4468u: return "RoundingModeRTZ";
+				case 4471u: return "RayQueryProvisionalKHR";
+				case 4472u: return "RayQueryKHR";
+				case 4478u: return "RayTraversalPrimitiveCullingKHR";
+				case 4479u: return "RayTracingKHR";
+				case 4484u: return "TextureSampleWeightedQCOM";
+				case 4485u: return "TextureBoxFilterQCOM";
+				case 4486u: return "TextureBlockMatchQCOM";
+				case 5008u: return "Float16ImageAMD";
+				case 5009u: return "ImageGatherBiasLodAMD";
+				case 5010u: return "FragmentMaskAMD";
+				case 5013u: return "StencilExportEXT";
+				case 5015u: return "ImageReadWriteLodAMD";
+				case 5016u: return "Int64ImageEXT";
+				case 5055u: return "ShaderClockKHR";
+				case 5067u: return "ShaderEnqueueAMDX";
+				case 5249u: return "SampleMaskOverrideCoverageNV";
+				case 5251u: return "GeometryShaderPassthroughNV";
+				case 5254u: return "ShaderViewportIndexLayerEXT";
+				case 5255u: return "ShaderViewportMaskNV";
+				case 5259u: return "ShaderStereoViewNV";
+				case 5260u: return "PerViewAttributesNV";
+				case 5265u: return "FragmentFullyCoveredEXT";
+				case 5266u: return "MeshShadingNV";
+				case 5282u: return "ImageFootprintNV";
+				case 5283u: return "MeshShadingEXT";
+				case 5284u: return "FragmentBarycentricKHR";
+				case 5288u: return "ComputeDerivativeGroupQuadsNV";
+				case 5291u: return "FragmentDensityEXT";
+				case 5297u: return "GroupNonUniformPartitionedNV";
+				case 5301u: return "ShaderNonUniform";
+				case 5302u: return "RuntimeDescriptorArray";
+				case 5303u: return "InputAttachmentArrayDynamicIndexing";
+				case 5304u: return "UniformTexelBufferArrayDynamicIndexing";
+				case 5305u: return "StorageTexelBufferArrayDynamicIndexing";
+				case 5306u: return "UniformBufferArrayNonUniformIndexing";
+				case 5307u: return "SampledImageArrayNonUniformIndexing";
+				case 5308u: return "StorageBufferArrayNonUniformIndexing";
+				case 5309u: return "StorageImageArrayNonUniformIndexing";
+				case 5310u: return "InputAttachmentArrayNonUniformIndexing";
+				case 5311u: return "UniformTexelBufferArrayNonUniformIndexing";
+				case 5312u: return "StorageTexelBufferArrayNonUniformIndexing";
+				case 5336u: return "RayTracingPositionFetchKHR";
+				case 5340u: return "RayTracingNV";
+				case 5341u: return "RayTracingMotionBlurNV";
+				case 5345u: return "VulkanMemoryModel";
+				case 5346u: return "VulkanMemoryModelDeviceScope";
+				case 5347u: return "PhysicalStorageBufferAddresses";
+				case 5350u: return "ComputeDerivativeGroupLinearNV";
+				case 5353u: return "RayTracingProvisionalKHR";
+				case 5357u: return "CooperativeMatrixNV";
+				case 5363u: return "FragmentShaderSampleInterlockEXT";
+				case 5372u: return "FragmentShaderShadingRateInterlockEXT";
+				case 5373u: return "ShaderSMBuiltinsNV";
+				case 5378u: return "FragmentShaderPixelInterlockEXT";
+				case 5379u: return "DemoteToHelperInvocation";
+				case 5381u: return "RayTracingOpacityMicromapEXT";
+				case 5383u: return "ShaderInvocationReorderNV";
+				case 5390u: return "BindlessTextureNV";
+				case 5391u: return "RayQueryPositionFetchKHR";
+				case 5568u: return "SubgroupShuffleINTEL";
+				case 5569u: return "SubgroupBufferBlockIOINTEL";
+				case 5570u: return "SubgroupImageBlockIOINTEL";
+				case 5579u: return "SubgroupImageMediaBlockIOINTEL";
+				case 5582u: return "RoundToInfinityINTEL";
+				case 5583u: return "FloatingPointModeINTEL";
+				case 5584u: return "IntegerFunctions2INTEL";
+				case 5603u: return "FunctionPointersINTEL";
+				case 5604u: return "IndirectReferencesINTEL";
+				case 5606u: return "AsmINTEL";
+				case 5612u: return "AtomicFloat32MinMaxEXT";
+				case 5613u: return "AtomicFloat64MinMaxEXT";
+				case 5616u: return "AtomicFloat16MinMaxEXT";
+				case 5617u: return "VectorComputeINTEL";
+				case 5619u: return "VectorAnyINTEL";
+				case 5629u: return "ExpectAssumeKHR";
+				case 5696u: return "SubgroupAvcMotionEstimationINTEL";
+				case 5697u: return "SubgroupAvcMotionEstimationIntraINTEL";
+				case 5698u: return "SubgroupAvcMotionEstimationChromaINTEL";
+				case 5817u: return "VariableLengthArrayINTEL";
+				case 5821u: return "FunctionFloatControlINTEL";
+				case 5824u: return "FPGAMemoryAttributesINTEL";
+				case 5837u: return "FPFastMathModeINTEL";
+				case 5844u: return "ArbitraryPrecisionIntegersINTEL";
+				case 5845u: return "ArbitraryPrecisionFloatingPointINTEL";
+				case 5886u: return "UnstructuredLoopControlsINTEL";
+				case 5888u: return "FPGALoopControlsINTEL";
+				case 5892u: return "KernelAttributesINTEL";
+				case 5897u: return "FPGAKernelAttributesINTEL";
+				case 5898u: return "FPGAMemoryAccessesINTEL";
+				case 5904u: return "FPGAClusterAttributesINTEL";
+				case 5906u: return "LoopFuseINTEL";
+				case 5908u: return "FPGADSPControlINTEL";
+				case 5910u: return "MemoryAccessAliasingINTEL";
+				case 5916u: return "FPGAInvocationPipeliningAttributesINTEL";
+				case 5920u: return "FPGABufferLocationINTEL";
+				case 5922u: return "ArbitraryPrecisionFixedPointINTEL";
+				case 5935u: return "USMStorageClassesINTEL";
+				case 5939u: return "RuntimeAlignedAttributeINTEL";
+				case 5943u: return "IOPipesINTEL";
+				case 5945u: return "BlockingPipesINTEL";
+				case 5948u: return "FPGARegINTEL";
+				case 6016u: return "DotProductInputAll";
+				case 6017u: return "DotProductInput4x8Bit";
+				case 6018u: return "DotProductInput4x8BitPacked";
+				case 6019u: return "DotProduct";
+				case 6020u: return "RayCullMaskKHR";
+				case 6022u: return "CooperativeMatrixKHR";
+				case 6025u: return "BitInstructions";
+				case 6026u: return "GroupNonUniformRotateKHR";
+				case 6033u: return "AtomicFloat32AddEXT";
+				case 6034u: return "AtomicFloat64AddEXT";
+				case 6089u: return "LongConstantCompositeINTEL";
+				case 6094u: return "OptNoneINTEL";
+				case 6095u: return "AtomicFloat16AddEXT";
+				case 6114u: return "DebugInfoModuleINTEL";
+				case 6115u: return "BFloat16ConversionINTEL";
+				case 6141u: return "SplitBarrierINTEL";
+				case 6146u: return "GlobalVariableFPGADecorationsINTEL";
+				case 6161u: return "FPGAKernelAttributesv2INTEL";
+				case 6167u: return "GlobalVariableHostAccessINTEL";
+				case 6169u: return "FPMaxErrorINTEL";
+				case 6171u: return "FPGALatencyControlINTEL";
+				case 6174u: return "FPGAArgumentInterfacesINTEL";
+				case 6400u: return "GroupUniformArithmeticKHR";
+				case 6441u: return "CacheControlsINTEL";
+				}
+			case OperandKind::RayQueryIntersection:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RayQueryCandidateIntersectionKHR";
+				case 1u: return "RayQueryCommittedIntersectionKHR";
+				}
+			case OperandKind::RayQueryCommittedIntersectionType:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RayQueryCommittedIntersectionNoneKHR";
+				case 1u: return "RayQueryCommittedIntersectionTriangleKHR";
+				case 2u: return "RayQueryCommittedIntersectionGeneratedKHR";
+				}
+			case OperandKind::RayQueryCandidateIntersectionType:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RayQueryCandidateIntersectionTriangleKHR";
+				case 1u: return "RayQueryCandidateIntersectionAABBKHR";
+				}
+			case OperandKind::PackedVectorFormat:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "PackedVectorFormat4x8Bit";
+				}
+			case OperandKind::CooperativeMatrixOperands:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "NoneKHR";
+				case 1u: return "MatrixASignedComponentsKHR";
+				case 2u: return "MatrixBSignedComponentsKHR";
+				case 4u: return "MatrixCSignedComponentsKHR";
+				case 8u: return "MatrixResultSignedComponentsKHR";
+				case 16u: return "SaturatingAccumulationKHR";
+				}
+			case OperandKind::CooperativeMatrixLayout:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "RowMajorKHR";
+				case 1u: return "ColumnMajorKHR";
+				}
+			case OperandKind::CooperativeMatrixUse:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "MatrixAKHR";
+				case 1u: return "MatrixBKHR";
+				case 2u: return "MatrixAccumulatorKHR";
+				}
+			case OperandKind::InitializationModeQualifier:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "InitOnDeviceReprogramINTEL";
+				case 1u: return "InitOnDeviceResetINTEL";
+				}
+			case OperandKind::LoadCacheControl:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "UncachedINTEL";
+				case 1u: return "CachedINTEL";
+				case 2u: return "StreamingINTEL";
+				case 3u: return "InvalidateAfterReadINTEL";
+				case 4u: return "ConstCachedINTEL";
+				}
+			case OperandKind::StoreCacheControl:
+				switch (_value)
+				{
+				default: return nullptr;
+				case 0u: return "UncachedINTEL";
+				case 1u: return "WriteThroughINTEL";
+				case 2u: return "WriteBackINTEL";
+				case 3u: return "StreamingINTEL";
+				}
+			}
+			return nullptr;
+		}
+		static constexpr OperandLayout EnumerantParameters[] =
+		{
+			// ImageOperands Bias
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands Lod
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands Grad
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands ConstOffset
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands Offset
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands ConstOffsets
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands Sample
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands MinLod
+			{ OperandKind::IdRef, Quantifier::One },
+			// ImageOperands MakeTexelAvailable
+			{ OperandKind::IdScope, Quantifier::One },
+			// ImageOperands MakeTexelVisible
+			{ OperandKind::IdScope, Quantifier::One },
+			// ImageOperands Offsets
+			{ OperandKind::IdRef, Quantifier::One },
+			// LoopControl DependencyLength
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl MinIterations
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl MaxIterations
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl IterationMultiple
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl PeelCount
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl PartialCount
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl InitiationIntervalINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl MaxConcurrencyINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl DependencyArrayINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl PipelineEnableINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl LoopCoalesceINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl MaxInterleavingINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl SpeculatedIterationsINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl LoopCountINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// LoopControl MaxReinvocationDelayINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// MemoryAccess Aligned
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// MemoryAccess MakePointerAvailable
+			{ OperandKind::IdScope, Quantifier::One },
+			// MemoryAccess MakePointerVisible
+			{ OperandKind::IdScope, Quantifier::One },
+			// MemoryAccess AliasScopeINTELMask
+			{ OperandKind::IdRef, Quantifier::One },
+			// MemoryAccess NoAliasINTELMask
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode Invocations
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode LocalSize
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode LocalSizeHint
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode OutputVertices
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode VecTypeHint
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SubgroupSize
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SubgroupsPerWorkgroup
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SubgroupsPerWorkgroupId
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode LocalSizeId
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode LocalSizeHintId
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode DenormPreserve
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode DenormFlushToZero
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SignedZeroInfNanPreserve
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode RoundingModeRTE
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode RoundingModeRTZ
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode MaxNodeRecursionAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode StaticNumWorkgroupsAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode ShaderIndexAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode MaxNumWorkgroupsAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ExecutionMode OutputPrimitivesNV
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SharedLocalMemorySizeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode RoundingModeRTPINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode RoundingModeRTNINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode FloatingPointModeALTINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode FloatingPointModeIEEEINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode MaxWorkgroupSizeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode MaxWorkDimINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode NumSIMDWorkitemsINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode SchedulerTargetFmaxMhzINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode StreamingInterfaceINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode RegisterMapInterfaceINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// ExecutionMode NamedBarrierCountINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration SpecId
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration ArrayStride
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MatrixStride
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration BuiltIn
+			{ OperandKind::BuiltIn, Quantifier::One },
+			// Decoration UniformId
+			{ OperandKind::IdScope, Quantifier::One },
+			// Decoration Stream
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Location
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Component
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Index
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Binding
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration DescriptorSet
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Offset
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration XfbBuffer
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration XfbStride
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration FuncParamAttr
+			{ OperandKind::FunctionParameterAttribute, Quantifier::One },
+			// Decoration FPRoundingMode
+			{ OperandKind::FPRoundingMode, Quantifier::One },
+			// Decoration FPFastMathMode
+			{ OperandKind::FPFastMathMode, Quantifier::One },
+			// Decoration LinkageAttributes
+			{ OperandKind::LiteralString, Quantifier::One },
+			{ OperandKind::LinkageType, Quantifier::One },
+			// Decoration InputAttachmentIndex
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration Alignment
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MaxByteOffset
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration AlignmentId
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration MaxByteOffsetId
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration NodeSharesPayloadLimitsWithAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration NodeMaxPayloadsAMDX
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration PayloadNodeNameAMDX
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration SecondaryViewportRelativeNV
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration SIMTCallINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration ClobberINTEL
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration FuncParamIOKindINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration GlobalVariableOffsetINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration CounterBuffer
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration UserSemantic
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration UserTypeGOOGLE
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration FunctionRoundingModeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::FPRoundingMode, Quantifier::One },
+			// Decoration FunctionDenormModeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::FPDenormMode, Quantifier::One },
+			// Decoration MemoryINTEL
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration NumbanksINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration BankwidthINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MaxPrivateCopiesINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MaxReplicatesINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MergeINTEL
+			{ OperandKind::LiteralString, Quantifier::One },
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration BankBitsINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration ForcePow2DepthINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration CacheSizeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration PrefetchINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MathOpDSPModeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration AliasScopeINTEL
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration NoAliasINTEL
+			{ OperandKind::IdRef, Quantifier::One },
+			// Decoration InitiationIntervalINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MaxConcurrencyINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration PipelineEnableINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration BufferLocationINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration IOPipeStorageINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration FunctionFloatingPointModeINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::FPOperationMode, Quantifier::One },
+			// Decoration InitModeINTEL
+			{ OperandKind::InitializationModeQualifier, Quantifier::One },
+			// Decoration ImplementInRegisterMapINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration HostAccessINTEL
+			{ OperandKind::HostAccessQualifier, Quantifier::One },
+			{ OperandKind::LiteralString, Quantifier::One },
+			// Decoration FPMaxErrorDecorationINTEL
+			{ OperandKind::LiteralFloat, Quantifier::One },
+			// Decoration LatencyControlLabelINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration LatencyControlConstraintINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MMHostInterfaceAddressWidthINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MMHostInterfaceDataWidthINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MMHostInterfaceLatencyINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MMHostInterfaceReadWriteModeINTEL
+			{ OperandKind::AccessQualifier, Quantifier::One },
+			// Decoration MMHostInterfaceMaxBurstINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration MMHostInterfaceWaitRequestINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// Decoration CacheControlLoadINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::LoadCacheControl, Quantifier::One },
+			// Decoration CacheControlStoreINTEL
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			{ OperandKind::StoreCacheControl, Quantifier::One },
+		};
+		inline OperandLayoutSpan enumerantParameters(OperandKind _kind, unsigned int _value)
+		{
+			switch (_kind)
+			{
+			default: break;
+			case OperandKind::Decoration:
+				switch (_value)
+				{
+				default: break;
+				case 1u: return { EnumerantParameters + 78, 1u };
+				case 6u: return { EnumerantParameters + 79, 1u };
+				case 7u: return { EnumerantParameters + 80, 1u };
+				case 11u: return { EnumerantParameters + 81, 1u };
+				case 27u: return { EnumerantParameters + 82, 1u };
+				case 29u: return { EnumerantParameters + 83, 1u };
+				case 30u: return { EnumerantParameters + 84, 1u };
+				case 31u: return { EnumerantParameters + 85, 1u };
+				case 32u: return { EnumerantParameters + 86, 1u };
+				case 33u: return { EnumerantParameters + 87, 1u };
+				case 34u: return { EnumerantParameters + 88, 1u };
+				case 35u: return { EnumerantParameters + 89, 1u };
+				case 36u: return { EnumerantParameters + 90, 1u };
+				case 37u: return { EnumerantParameters + 91, 1u };
+				case 38u: return { EnumerantParameters + 92, 1u };
+				case 39u: return { EnumerantParameters + 93, 1u };
+				case 40u: return { EnumerantParameters + 94, 1u };
+				case 41u: return { EnumerantParameters + 95, 2u };
+				case 43u: return { EnumerantParameters + 97, 1u };
+				case 44u: return { EnumerantParameters + 98, 1u };
+				case 45u: return { EnumerantParameters + 99, 1u };
+				case 46u: return { EnumerantParameters + 100, 1u };
+				case 47u: return { EnumerantParameters + 101, 1u };
+				case 5019u: return { EnumerantParameters + 102, 1u };
+				case 5020u: return { EnumerantParameters + 103, 1u };
+				case 5091u: return { EnumerantParameters + 104, 1u };
+				case 5256u: return { EnumerantParameters + 105, 1u };
+				case 5599u: return { EnumerantParameters + 106, 1u };
+				case 5607u: return { EnumerantParameters + 107, 1u };
+				case 5625u: return { EnumerantParameters + 108, 1u };
+				case 5628u: return { EnumerantParameters + 109, 1u };
+				case 5634u: return { EnumerantParameters + 110, 1u };
+				case 5635u: return { EnumerantParameters + 111, 1u };
+				case 5636u: return { EnumerantParameters + 112, 1u };
+				case 5822u: return { EnumerantParameters + 113, 2u };
+				case 5823u: return { EnumerantParameters + 115, 2u };
+				case 5826u: return { EnumerantParameters + 117, 1u };
+				case 5827u: return { EnumerantParameters + 118, 1u };
+				case 5828u: return { EnumerantParameters + 119, 1u };
+				case 5829u: return { EnumerantParameters + 120, 1u };
+				case 5832u: return { EnumerantParameters + 121, 1u };
+				case 5834u: return { EnumerantParameters + 122, 2u };
+				case 5835u: return { EnumerantParameters + 124, 1u };
+				case 5836u: return { EnumerantParameters + 125, 1u };
+				case 5900u: return { EnumerantParameters + 126, 1u };
+				case 5902u: return { EnumerantParameters + 127, 1u };
+				case 5909u: return { EnumerantParameters + 128, 2u };
+				case 5914u: return { EnumerantParameters + 130, 1u };
+				case 5915u: return { EnumerantParameters + 131, 1u };
+				case 5917u: return { EnumerantParameters + 132, 1u };
+				case 5918u: return { EnumerantParameters + 133, 1u };
+				case 5919u: return { EnumerantParameters + 134, 1u };
+				case 5921u: return { EnumerantParameters + 135, 1u };
+				case 5944u: return { EnumerantParameters + 136, 1u };
+				case 6080u: return { EnumerantParameters + 137, 2u };
+				case 6147u: return { EnumerantParameters + 139, 1u };
+				case 6148u: return { EnumerantParameters + 140, 1u };
+				case 6168u: return { EnumerantParameters + 141, 2u };
+				case 6170u: return { EnumerantParameters + 143, 1u };
+				case 6172u: return { EnumerantParameters + 144, 1u };
+				case 6173u: return { EnumerantParameters + 145, 3u };
+				case 6177u: return { EnumerantParameters + 148, 1u };
+				case 6178u: return { EnumerantParameters + 149, 1u };
+				case 6179u: return { EnumerantParameters + 150, 1u };
+				case 6180u: return { EnumerantParameters + 151, 1u };
+				case 6181u: return { EnumerantParameters + 152, 1u };
+				case 6182u: return { EnumerantParameters + 153, 1u };
+				case 6442u: return { EnumerantParameters + 154, 2u };
+				case 6443u: return { EnumerantParameters + 156, 2u };
+				}
+				break;
+			case OperandKind::ExecutionMode:
+				switch (_value)
+				{
+				default: break;
+				case 0u: return { EnumerantParameters + 32, 1u };
+				case 17u: return { EnumerantParameters + 33, 3u };
+				case 18u: return { EnumerantParameters + 36, 3u };
+				case 26u: return { EnumerantParameters + 39, 1u };
+				case 30u: return { EnumerantParameters + 40, 1u };
+				case 35u: return { EnumerantParameters + 41, 1u };
+				case 36u: return { EnumerantParameters + 42, 1u };
+				case 37u: return { EnumerantParameters + 43, 1u };
+				case 38u: return { EnumerantParameters + 44, 3u };
+				case 39u: return { EnumerantParameters + 47, 3u };
+				case 4459u: return { EnumerantParameters + 50, 1u };
+				case 4460u: return { EnumerantParameters + 51, 1u };
+				case 4461u: return { EnumerantParameters + 52, 1u };
+				case 4462u: return { EnumerantParameters + 53, 1u };
+				case 4463u: return { EnumerantParameters + 54, 1u };
+				case 5071u: return { EnumerantParameters + 55, 1u };
+				case 5072u: return { EnumerantParameters + 56, 3u };
+				case 5073u: return { EnumerantParameters + 59, 1u };
+				case 5077u: return { EnumerantParameters + 60, 3u };
+				case 5270u: return { EnumerantParameters + 63, 1u };
+				case 5618u: return { EnumerantParameters + 64, 1u };
+				case 5620u: return { EnumerantParameters + 65, 1u };
+				case 5621u: return { EnumerantParameters + 66, 1u };
+				case 5622u: return { EnumerantParameters + 67, 1u };
+				case 5623u: return { EnumerantParameters + 68, 1u };
+				case 5893u: return { EnumerantParameters + 69, 3u };
+				case 5894u: return { EnumerantParameters + 72, 1u };
+				case 5896u: return { EnumerantParameters + 73, 1u };
+				case 5903u: return { EnumerantParameters + 74, 1u };
+				case 6154u: return { EnumerantParameters + 75, 1u };
+				case 6160u: return { EnumerantParameters + 76, 1u };
+				case 6417u: return { EnumerantParameters + 77, 1u };
+				}
+				break;
+			case OperandKind::ImageOperands:
+				switch (_value)
+				{
+				default: break;
+				case 1u: return { EnumerantParameters + 0, 1u };
+				case 2u: return { EnumerantParameters + 1, 1u };
+				case 4u: return { EnumerantParameters + 2, 2u };
+				case 8u: return { EnumerantParameters + 4, 1u };
+				case 16u: return { EnumerantParameters + 5, 1u };
+				case 32u: return { EnumerantParameters + 6, 1u };
+				case 64u: return { EnumerantParameters + 7, 1u };
+				case 128u: return { EnumerantParameters + 8, 1u };
+				case 256u: return { EnumerantParameters + 9, 1u };
+				case 512u: return { EnumerantParameters + 10, 1u };
+				case 65536u: return { EnumerantParameters + 11, 1u };
+				}
+				break;
+			case OperandKind::LoopControl:
+				switch (_value)
+				{
+				default: break;
+				case 8u: return { EnumerantParameters + 12, 1u };
+				case 16u: return { EnumerantParameters + 13, 1u };
+				case 32u: return { EnumerantParameters + 14, 1u };
+				case 64u: return { EnumerantParameters + 15, 1u };
+				case 128u: return { EnumerantParameters + 16, 1u };
+				case 256u: return { EnumerantParameters + 17, 1u };
+				case 65536u: return { EnumerantParameters + 18, 1u };
+				case 131072u: return { EnumerantParameters + 19, 1u };
+				case 262144u: return { EnumerantParameters + 20, 1u };
+				case 524288u: return { EnumerantParameters + 21, 1u };
+				case 1048576u: return { EnumerantParameters + 22, 1u };
+				case 2097152u: return { EnumerantParameters + 23, 1u };
+				case 4194304u: return { EnumerantParameters + 24, 1u };
+				case 16777216u: return { EnumerantParameters + 25, 1u };
+				case 33554432u: return { EnumerantParameters + 26, 1u };
+				}
+				break;
+			case OperandKind::MemoryAccess:
+				switch (_value)
+				{
+				default: break;
+				case 2u: return { EnumerantParameters + 27, 1u };
+				case 8u: return { EnumerantParameters + 28, 1u };
+				case 16u: return { EnumerantParameters + 29, 1u };
+				case 65536u: return { EnumerantParameters + 30, 1u };
+				case 131072u: return { EnumerantParameters + 31, 1u };
+				}
+				break;
+			}
+			return { EnumerantParameters, 0u };
+		}
+		inline const char* extInstName(unsigned int _set, unsigned int _instruction)
+		{
+			switch (_set)
+			{
+			default: break;
+			case 0u:
+				switch (_instruction)
+				{
+				default: break;
+				case 1u: return "Round";
+				case 2u: return "RoundEven";
+				case 3u: return "Trunc";
+				case 4u: return "FAbs";
+				case 5u: return "SAbs";
+				case 6u: return "FSign";
+				case 7u: return "SSign";
+				case 8u: return "Floor";
+				case 9u: return "Ceil";
+				case 10u: return "Fract";
+				case 11u: return "Radians";
+				case 12u: return "Degrees";
+				case 13u: return "Sin";
+				case 14u: return "Cos";
+				case 15u: return "Tan";
+				case 16u: return "Asin";
+				case 17u: return "Acos";
+				case 18u: return "Atan";
+				case 19u: return "Sinh";
+				case 20u: return "Cosh";
+				case 21u: return "Tanh";
+				case 22u: return "Asinh";
+				case 23u: return "Acosh";
+				case 24u: return "Atanh";
+				case 25u: return "Atan2";
+				case 26u: return "Pow";
+				case 27u: return "Exp";
+				case 28u: return "Log";
+				case 29u: return "Exp2";
+				case 30u: return "Log2";
+				case 31u: return "Sqrt";
+				case 32u: return "InverseSqrt";
+				case 33u: return "Determinant";
+				case 34u: return "MatrixInverse";
+				case 35u: return "Modf";
+				case 36u: return "ModfStruct";
+				case 37u: return "FMin";
+				case 38u: return "UMin";
+				case 39u: return "SMin";
+				case 40u: return "FMax";
+				case 41u: return "UMax";
+				case 42u: return "SMax";
+				case 43u: return "FClamp";
+				case 44u: return "UClamp";
+				case 45u: return "SClamp";
+				case 46u: return "FMix";
+				case 47u: return "IMix";
+				case 48u: return "Step";
+				case 49u: return "SmoothStep";
+				case 50u: return "Fma";
+				case 51u: return "Frexp";
+				case 52u: return "FrexpStruct";
+				case 53u: return "Ldexp";
+				case 54u: return "PackSnorm4x8";
+				case 55u: return "PackUnorm4x8";
+				case 56u: return "PackSnorm2x16";
+				case 57u: return "PackUnorm2x16";
+				case 58u: return "PackHalf2x16";
+				case 59u: return "PackDouble2x32";
+				case 60u: return "UnpackSnorm2x16";
+				case 61u: return "UnpackUnorm2x16";
+				case 62u: return "UnpackHalf2x16";
+				case 63u: return "UnpackSnorm4x8";
+				case 64u: return "UnpackUnorm4x8";
+				case 65u: return "UnpackDouble2x32";
+				case 66u: return "Length";
+				case 67u: return "Distance";
+				case 68u: return "Cross";
+				case 69u: return "Normalize";
+				case 70u: return "FaceForward";
+				case 71u: return "Reflect";
+				case 72u: return "Refract";
+				case 73u: return "FindILsb";
+				case 74u: return "FindSMsb";
+				case 75u: return "FindUMsb";
+				case 76u: return "InterpolateAtCentroid";
+				case 77u: return "InterpolateAtSample";
+				case 78u: return "InterpolateAtOffset";
+				case 79u: return "NMin";
+				case 80u: return "NMax";
+				case 81u: return "NClamp";
+				}
+				break;
+			case 1u:
+				switch (_instruction)
+				{
+				default: break;
+				case 0u: return "acos";
+				case 1u: return "acosh";
+				case 2u: return "acospi";
+				case 3u: return "asin";
+				case 4u: return "asinh";
+				case 5u: return "asinpi";
+				case 6u: return "atan";
+				case 7u: return "atan2";
+				case 8u: return "atanh";
+				case 9u: return "atanpi";
+				case 10u: return "atan2pi";
+				case 11u: return "cbrt";
+				case 12u: return "ceil";
+				case 13u: return "copysign";
+				case 14u: return "cos";
+				case 15u: return "cosh";
+				case 16u: return "cospi";
+				case 17u: return "erfc";
+				case 18u: return "erf";
+				case 19u: return "exp";
+				case 20u: return "exp2";
+				case 21u: return "exp10";
+				case 22u: return "expm1";
+				case 23u: return "fabs";
+				case 24u: return "fdim";
+				case 25u: return "floor";
+				case 26u: return "fma";
+				case 27u: return "fmax";
+				case 28u: return "fmin";
+				case 29u: return "fmod";
+				case 30u: return "fract";
+				case 31u: return "frexp";
+				case 32u: return "hypot";
+				case 33u: return "ilogb";
+				case 34u: return "ldexp";
+				case 35u: return "lgamma";
+				case 36u: return "lgamma_r";
+				case 37u: return "log";
+				case 38u: return "log2";
+				case 39u: return "log10";
+				case 40u: return "log1p";
+				case 41u: return "logb";
+				case 42u: return "mad";
+				case 43u: return "maxmag";
+				case 44u: return "minmag";
+				case 45u: return "modf";
+				case 46u: return "nan";
+				case 47u: return "nextafter";
+				case 48u: return "pow";
+				case 49u: return "pown";
+				case 50u: return "powr";
+				case 51u: return "remainder";
+				case 52u: return "remquo";
+				case 53u: return "rint";
+				case 54u: return "rootn";
+				case 55u: return "round";
+				case 56u: return "rsqrt";
+				case 57u: return "sin";
+				case 58u: return "sincos";
+				case 59u: return "sinh";
+				case 60u: return "sinpi";
+				case 61u: return "sqrt";
+				case 62u: return "tan";
+				case 63u: return "tanh";
+				case 64u: return "tanpi";
+				case 65u: return "tgamma";
+				case 66u: return "trunc";
+				case 67u: return "half_cos";
+				case 68u: return "half_divide";
+				case 69u: return "half_exp";
+				case 70u: return "half_exp2";
+				case 71u: return "half_exp10";
+				case 72u: return "half_log";
+				case 73u: return "half_log2";
+				case 74u: return "half_log10";
+				case 75u: return "half_powr";
+				case 76u: return "half_recip";
+				case 77u: return "half_rsqrt";
+				case 78u: return "half_sin";
+				case 79u: return "half_sqrt";
+				case 80u: return "half_tan";
+				case 81u: return "native_cos";
+				case 82u: return "native_divide";
+				case 83u: return "native_exp";
+				case 84u: return "native_exp2";
+				case 85u: return "native_exp10";
+				case 86u: return "native_log";
+				case 87u: return "native_log2";
+				case 88u: return "native_log10";
+				case 89u: return "native_powr";
+				case 90u: return "native_recip";
+				case 91u: return "native_rsqrt";
+				case 92u: return "native_sin";
+				case 93u: return "native_sqrt";
+				case 94u: return "native_tan";
+				case 141u: return "s_abs";
+				case 142u: return "s_abs_diff";
+				case 143u: return "s_add_sat";
+				case 144u: return "u_add_sat";
+				case 145u: return "s_hadd";
+				case 146u: return "u_hadd";
+				case 147u: return "s_rhadd";
+				case 148u: return "u_rhadd";
+				case 149u: return "s_clamp";
+				case 150u: return "u_clamp";
+				case 151u: return "clz";
+				case 152u: return "ctz";
+				case 153u: return "s_mad_hi";
+				case 154u: return "u_mad_sat";
+				case 155u: return "s_mad_sat";
+				case 156u: return "s_max";
+				case 157u: return "u_max";
+				case 158u: return "s_min";
+				case 159u: return "u_min";
+				case 160u: return "s_mul_hi";
+				case 161u: return "rotate";
+				case 162u: return "s_sub_sat";
+				case 163u: return "u_sub_sat";
+				case 164u: return "u_upsample";
+				case 165u: return "s_upsample";
+				case 166u: return "popcount";
+				case 167u: return "s_mad24";
+				case 168u: return "u_mad24";
+				case 169u: return "s_mul24";
+				case 170u: return "u_mul24";
+				case 201u: return "u_abs";
+				case 202u: return "u_abs_diff";
+				case 203u: return "u_mul_hi";
+				case 204u: return "u_mad_hi";
+				case 95u: return "fclamp";
+				case 96u: return "degrees";
+				case 97u: return "fmax_common";
+				case 98u: return "fmin_common";
+				case 99u: return "mix";
+				case 100u: return "radians";
+				case 101u: return "step";
+				case 102u: return "smoothstep";
+				case 103u: return "sign";
+				case 104u: return "cross";
+				case 105u: return "distance";
+				case 106u: return "length";
+				case 107u: return "normalize";
+				case 108u: return "fast_distance";
+				case 109u: return "fast_length";
+				case 110u: return "fast_normalize";
+				case 186u: return "bitselect";
+				case 187u: return "select";
+				case 171u: return "vloadn";
+				case 172u: return "vstoren";
+				case 173u: return "vload_half";
+				case 174u: return "vload_halfn";
+				case 175u: return "vstore_half";
+				case 176u: return "vstore_half_r";
+				case 177u: return "vstore_halfn";
+				case 178u: return "vstore_halfn_r";
+				case 179u: return "vloada_halfn";
+				case 180u: return "vstorea_halfn";
+				case 181u: return "vstorea_halfn_r";
+				case 182u: return "shuffle";
+				case 183u: return "shuffle2";
+				case 184u: return "printf";
+				case 185u: return "prefetch";
+				}
+				break;
+			}
+			return nullptr;
+		}
+		static constexpr OperandLayout ExtInstOperands[] =
+		{
+			// Round
+			{ OperandKind::IdRef, Quantifier::One },
+			// RoundEven
+			{ OperandKind::IdRef, Quantifier::One },
+			// Trunc
+			{ OperandKind::IdRef, Quantifier::One },
+			// FAbs
+			{ OperandKind::IdRef, Quantifier::One },
+			// SAbs
+			{ OperandKind::IdRef, Quantifier::One },
+			// FSign
+			{ OperandKind::IdRef, Quantifier::One },
+			// SSign
+			{ OperandKind::IdRef, Quantifier::One },
+			// Floor
+			{ OperandKind::IdRef, Quantifier::One },
+			// Ceil
+			{ OperandKind::IdRef, Quantifier::One },
+			// Fract
+			{ OperandKind::IdRef, Quantifier::One },
+			// Radians
+			{ OperandKind::IdRef, Quantifier::One },
+			// Degrees
+			{ OperandKind::IdRef, Quantifier::One },
+			// Sin
+			{ OperandKind::IdRef, Quantifier::One },
+			// Cos
+			{ OperandKind::IdRef, Quantifier::One },
+			// Tan
+			{ OperandKind::IdRef, Quantifier::One },
+			// Asin
+			{ OperandKind::IdRef, Quantifier::One },
+			// Acos
+			{ OperandKind::IdRef, Quantifier::One },
+			// Atan
+			{ OperandKind::IdRef, Quantifier::One },
+			// Sinh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Cosh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Tanh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Asinh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Acosh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Atanh
+			{ OperandKind::IdRef, Quantifier::One },
+			// Atan2
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Pow
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Exp
+			{ OperandKind::IdRef, Quantifier::One },
+			// Log
+			{ OperandKind::IdRef, Quantifier::One },
+			// Exp2
+			{ OperandKind::IdRef, Quantifier::One },
+			// Log2
+			{ OperandKind::IdRef, Quantifier::One },
+			// Sqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// InverseSqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// Determinant
+			{ OperandKind::IdRef, Quantifier::One },
+			// MatrixInverse
+			{ OperandKind::IdRef, Quantifier::One },
+			// Modf
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ModfStruct
+			{ OperandKind::IdRef, Quantifier::One },
+			// FMin
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// UMin
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// SMin
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// FMax
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// UMax
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// SMax
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// FClamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// UClamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// SClamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// FMix
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// IMix
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Step
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// SmoothStep
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Fma
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Frexp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// FrexpStruct
+			{ OperandKind::IdRef, Quantifier::One },
+			// Ldexp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackSnorm4x8
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackUnorm4x8
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackSnorm2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackUnorm2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackHalf2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// PackDouble2x32
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackSnorm2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackUnorm2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackHalf2x16
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackSnorm4x8
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackUnorm4x8
+			{ OperandKind::IdRef, Quantifier::One },
+			// UnpackDouble2x32
+			{ OperandKind::IdRef, Quantifier::One },
+			// Length
+			{ OperandKind::IdRef, Quantifier::One },
+			// Distance
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Cross
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Normalize
+			{ OperandKind::IdRef, Quantifier::One },
+			// FaceForward
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Reflect
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// Refract
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// FindILsb
+			{ OperandKind::IdRef, Quantifier::One },
+			// FindSMsb
+			{ OperandKind::IdRef, Quantifier::One },
+			// FindUMsb
+			{ OperandKind::IdRef, Quantifier::One },
+			// InterpolateAtCentroid
+			{ OperandKind::IdRef, Quantifier::One },
+			// InterpolateAtSample
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// InterpolateAtOffset
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// NMin
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// NMax
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// NClamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// acos
+			{ OperandKind::IdRef, Quantifier::One },
+			// acosh
+			{ OperandKind::IdRef, Quantifier::One },
+			// acospi
+			{ OperandKind::IdRef, Quantifier::One },
+			// asin
+			{ OperandKind::IdRef, Quantifier::One },
+			// asinh
+			{ OperandKind::IdRef, Quantifier::One },
+			// asinpi
+			{ OperandKind::IdRef, Quantifier::One },
+			// atan
+			{ OperandKind::IdRef, Quantifier::One },
+			// atan2
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// atanh
+			{ OperandKind::IdRef, Quantifier::One },
+			// atanpi
+			{ OperandKind::IdRef, Quantifier::One },
+			// atan2pi
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// cbrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// ceil
+			{ OperandKind::IdRef, Quantifier::One },
+			// copysign
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// cos
+			{ OperandKind::IdRef, Quantifier::One },
+			// cosh
+			{ OperandKind::IdRef, Quantifier::One },
+			// cospi
+			{ OperandKind::IdRef, Quantifier::One },
+			// erfc
+			{ OperandKind::IdRef, Quantifier::One },
+			// erf
+			{ OperandKind::IdRef, Quantifier::One },
+			// exp
+			{ OperandKind::IdRef, Quantifier::One },
+			// exp2
+			{ OperandKind::IdRef, Quantifier::One },
+			// exp10
+			{ OperandKind::IdRef, Quantifier::One },
+			// expm1
+			{ OperandKind::IdRef, Quantifier::One },
+			// fabs
+			{ OperandKind::IdRef, Quantifier::One },
+			// fdim
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// floor
+			{ OperandKind::IdRef, Quantifier::One },
+			// fma
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fmax
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fmin
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fmod
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fract
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// frexp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// hypot
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// ilogb
+			{ OperandKind::IdRef, Quantifier::One },
+			// ldexp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// lgamma
+			{ OperandKind::IdRef, Quantifier::One },
+			// lgamma_r
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// log
+			{ OperandKind::IdRef, Quantifier::One },
+			// log2
+			{ OperandKind::IdRef, Quantifier::One },
+			// log10
+			{ OperandKind::IdRef, Quantifier::One },
+			// log1p
+			{ OperandKind::IdRef, Quantifier::One },
+			// logb
+			{ OperandKind::IdRef, Quantifier::One },
+			// mad
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// maxmag
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// minmag
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// modf
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// nan
+			{ OperandKind::IdRef, Quantifier::One },
+			// nextafter
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// pow
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// pown
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// powr
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// remainder
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// remquo
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// rint
+			{ OperandKind::IdRef, Quantifier::One },
+			// rootn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// round
+			{ OperandKind::IdRef, Quantifier::One },
+			// rsqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// sin
+			{ OperandKind::IdRef, Quantifier::One },
+			// sincos
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// sinh
+			{ OperandKind::IdRef, Quantifier::One },
+			// sinpi
+			{ OperandKind::IdRef, Quantifier::One },
+			// sqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// tan
+			{ OperandKind::IdRef, Quantifier::One },
+			// tanh
+			{ OperandKind::IdRef, Quantifier::One },
+			// tanpi
+			{ OperandKind::IdRef, Quantifier::One },
+			// tgamma
+			{ OperandKind::IdRef, Quantifier::One },
+			// trunc
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_cos
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_divide
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_exp
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_exp2
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_exp10
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_log
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_log2
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_log10
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_powr
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_recip
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_rsqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_sin
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_sqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// half_tan
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_cos
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_divide
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_exp
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_exp2
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_exp10
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_log
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_log2
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_log10
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_powr
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_recip
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_rsqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_sin
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_sqrt
+			{ OperandKind::IdRef, Quantifier::One },
+			// native_tan
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_abs
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_abs_diff
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_add_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_add_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_hadd
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_hadd
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_rhadd
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_rhadd
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_clamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_clamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// clz
+			{ OperandKind::IdRef, Quantifier::One },
+			// ctz
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_mad_hi
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_mad_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_mad_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_max
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_max
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_min
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_min
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_mul_hi
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// rotate
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_sub_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_sub_sat
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_upsample
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_upsample
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// popcount
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_mad24
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_mad24
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// s_mul24
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_mul24
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_abs
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_abs_diff
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_mul_hi
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// u_mad_hi
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fclamp
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// degrees
+			{ OperandKind::IdRef, Quantifier::One },
+			// fmax_common
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fmin_common
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// mix
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// radians
+			{ OperandKind::IdRef, Quantifier::One },
+			// step
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// smoothstep
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// sign
+			{ OperandKind::IdRef, Quantifier::One },
+			// cross
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// distance
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// length
+			{ OperandKind::IdRef, Quantifier::One },
+			// normalize
+			{ OperandKind::IdRef, Quantifier::One },
+			// fast_distance
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// fast_length
+			{ OperandKind::IdRef, Quantifier::One },
+			// fast_normalize
+			{ OperandKind::IdRef, Quantifier::One },
+			// bitselect
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// select
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vloadn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// vstoren
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vload_half
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vload_halfn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// vstore_half
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vstore_half_r
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::FPRoundingMode, Quantifier::One },
+			// vstore_halfn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vstore_halfn_r
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::FPRoundingMode, Quantifier::One },
+			// vloada_halfn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::LiteralInteger, Quantifier::One },
+			// vstorea_halfn
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// vstorea_halfn_r
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::FPRoundingMode, Quantifier::One },
+			// shuffle
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// shuffle2
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+			// printf
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+			// prefetch
+			{ OperandKind::IdRef, Quantifier::One },
+			{ OperandKind::IdRef, Quantifier::One },
+		};
+		// false for instructions of unknown sets, their operands are printed as ids
+		inline bool extInstOperands(unsigned int _set, unsigned int _instruction, OperandLayoutSpan& _operands)
+		{
+			switch (_set)
+			{
+			default: break;
+			case 0u:
+				switch (_instruction)
+				{
+				default: break;
+				case 1u: _operands = { ExtInstOperands + 0, 1u }; return true;
+				case 2u: _operands = { ExtInstOperands + 1, 1u }; return true;
+				case 3u: _operands = { ExtInstOperands + 2, 1u }; return true;
+				case 4u: _operands = { ExtInstOperands + 3, 1u }; return true;
+				case 5u: _operands = { ExtInstOperands + 4, 1u }; return true;
+				case 6u: _operands = { ExtInstOperands + 5, 1u }; return true;
+				case 7u: _operands = { ExtInstOperands + 6, 1u }; return true;
+				case 8u: _operands = { ExtInstOperands + 7, 1u }; return true;
+				case 9u: _operands = { ExtInstOperands + 8, 1u }; return true;
+				case 10u: _operands = { ExtInstOperands + 9, 1u }; return true;
+				case 11u: _operands = { ExtInstOperands + 10, 1u }; return true;
+				case 12u: _operands = { ExtInstOperands + 11, 1u }; return true;
+				case 13u: _operands = { ExtInstOperands + 12, 1u }; return true;
+				case 14u: _operands = { ExtInstOperands + 13, 1u }; return true;
+				case 15u: _operands = { ExtInstOperands + 14, 1u }; return true;
+				case 16u: _operands = { ExtInstOperands + 15, 1u }; return true;
+				case 17u: _operands = { ExtInstOperands + 16, 1u }; return true;
+				case 18u: _operands = { ExtInstOperands + 17, 1u }; return true;
+				case 19u: _operands = { ExtInstOperands + 18, 1u }; return true;
+				case 20u: _operands = { ExtInstOperands + 19, 1u }; return true;
+				case 21u: _operands = { ExtInstOperands + 20, 1u }; return true;
+				case 22u: _operands = { ExtInstOperands + 21, 1u }; return true;
+				case 23u: _operands = { ExtInstOperands + 22, 1u }; return true;
+				case 24u: _operands = { ExtInstOperands + 23, 1u }; return true;
+				case 25u: _operands = { ExtInstOperands + 24, 2u }; return true;
+				case 26u: _operands = { ExtInstOperands + 26, 2u }; return true;
+				case 27u: _operands = { ExtInstOperands + 28, 1u }; return true;
+				case 28u: _operands = { ExtInstOperands + 29, 1u }; return true;
+				case 29u: _operands = { ExtInstOperands + 30, 1u }; return true;
+				case 30u: _operands = { ExtInstOperands + 31, 1u }; return true;
+				case 31u: _operands = { ExtInstOperands + 32, 1u }; return true;
+				case 32u: _operands = { ExtInstOperands + 33, 1u }; return true;
+				case 33u: _operands = { ExtInstOperands + 34, 1u }; return true;
+				case 34u: _operands = { ExtInstOperands + 35, 1u }; return true;
+				case 35u: _operands = { ExtInstOperands + 36, 2u }; return true;
+				case 36u: _operands = { ExtInstOperands + 38, 1u }; return true;
+				case 37u: _operands = { ExtInstOperands + 39, 2u }; return true;
+				case 38u: _operands = { ExtInstOperands + 41, 2u }; return true;
+				case 39u: _operands = { ExtInstOperands + 43, 2u }; return true;
+				case 40u: _operands = { ExtInstOperands + 45, 2u }; return true;
+				case 41u: _operands = { ExtInstOperands + 47, 2u }; return true;
+				case 42u: _operands = { ExtInstOperands + 49, 2u }; return true;
+				case 43u: _operands = { ExtInstOperands + 51, 3u }; return true;
+				case 44u: _operands = { ExtInstOperands + 54, 3u }; return true;
+				case 45u: _operands = { ExtInstOperands + 57, 3u }; return true;
+				case 46u: _operands = { ExtInstOperands + 60, 3u }; return true;
+				case 47u: _operands = { ExtInstOperands + 63, 3u }; return true;
+				case 48u: _operands = { ExtInstOperands + 66, 2u }; return true;
+				case 49u: _operands = { ExtInstOperands + 68, 3u }; return true;
+				case 50u: _operands = { ExtInstOperands + 71, 3u }; return true;
+				case 51u: _operands = { ExtInstOperands + 74, 2u }; return true;
+				case 52u: _operands = { ExtInstOperands + 76, 1u }; return true;
+				case 53u: _operands = { ExtInstOperands + 77, 2u }; return true;
+				case 54u: _operands = { ExtInstOperands + 79, 1u }; return true;
+				case 55u: _operands = { ExtInstOperands + 80, 1u }; return true;
+				case 56u: _operands = { ExtInstOperands + 81, 1u }; return true;
+				case 57u: _operands = { ExtInstOperands + 82, 1u }; return true;
+				case 58u: _operands = { ExtInstOperands + 83, 1u }; return true;
+				case 59u: _operands = { ExtInstOperands + 84, 1u }; return true;
+				case 60u: _operands = { ExtInstOperands + 85, 1u }; return true;
+				case 61u: _operands = { ExtInstOperands + 86, 1u }; return true;
+				case 62u: _operands = { ExtInstOperands + 87, 1u }; return true;
+				case 63u: _operands = { ExtInstOperands + 88, 1u }; return true;
+				case 64u: _operands = { ExtInstOperands + 89, 1u }; return true;
+				case 65u: _operands = { ExtInstOperands + 90, 1u }; return true;
+				case 66u: _operands = { ExtInstOperands + 91, 1u }; return true;
+				case 67u: _operands = { ExtInstOperands + 92, 2u }; return true;
+				case 68u: _operands = { ExtInstOperands + 94, 2u }; return true;
+				case 69u: _operands = { ExtInstOperands + 96, 1u }; return true;
+				case 70u: _operands = { ExtInstOperands + 97, 3u }; return true;
+				case 71u: _operands = { ExtInstOperands + 100, 2u }; return true;
+				case 72u: _operands = { ExtInstOperands + 102, 3u }; return true;
+				case 73u: _operands = { ExtInstOperands + 105, 1u }; return true;
+				case 74u: _operands = { ExtInstOperands + 106, 1u }; return true;
+				case 75u: _operands = { ExtInstOperands + 107, 1u }; return true;
+				case 76u: _operands = { ExtInstOperands + 108, 1u }; return true;
+				case 77u: _operands = { ExtInstOperands + 109, 2u }; return true;
+				case 78u: _operands = { ExtInstOperands + 111, 2u }; return true;
+				case 79u: _operands = { ExtInstOperands + 113, 2u }; return true;
+				case 80u: _operands = { ExtInstOperands + 115, 2u }; return true;
+				case 81u: _operands = { ExtInstOperands + 117, 3u }; return true;
+				}
+				break;
+			case 1u:
+				switch (_instruction)
+				{
+				default: break;
+				case 0u: _operands = { ExtInstOperands + 120, 1u }; return true;
+				case 1u: _operands = { ExtInstOperands + 121, 1u }; return true;
+				case 2u: _operands = { ExtInstOperands + 122, 1u }; return true;
+				case 3u: _operands = { ExtInstOperands + 123, 1u }; return true;
+				case 4u: _operands = { ExtInstOperands + 124, 1u }; return true;
+				case 5u: _operands = { ExtInstOperands + 125, 1u }; return true;
+				case 6u: _operands = { ExtInstOperands + 126, 1u }; return true;
+				case 7u: _operands = { ExtInstOperands + 127, 2u }; return true;
+				case 8u: _operands = { ExtInstOperands + 129, 1u }; return true;
+				case 9u: _operands = { ExtInstOperands + 130, 1u }; return true;
+				case 10u: _operands = { ExtInstOperands + 131, 2u }; return true;
+				case 11u: _operands = { ExtInstOperands + 133, 1u }; return true;
+				case 12u: _operands = { ExtInstOperands + 134, 1u }; return true;
+				case 13u: _operands = { ExtInstOperands + 135, 2u }; return true;
+				case 14u: _operands = { ExtInstOperands + 137, 1u }; return true;
+				case 15u: _operands = { ExtInstOperands + 138, 1u }; return true;
+				case 16u: _operands = { ExtInstOperands + 139, 1u }; return true;
+				case 17u: _operands = { ExtInstOperands + 140, 1u }; return true;
+				case 18u: _operands = { ExtInstOperands + 141, 1u }; return true;
+				case 19u: _operands = { ExtInstOperands + 142, 1u }; return true;
+				case 20u: _operands = { ExtInstOperands + 143, 1u }; return true;
+				case 21u: _operands = { ExtInstOperands + 144, 1u }; return true;
+				case 22u: _operands = { ExtInstOperands + 145, 1u }; return true;
+				case 23u: _operands = { ExtInstOperands + 146, 1u }; return true;
+				case 24u: _operands = { ExtInstOperands + 147, 2u }; return true;
+				case 25u: _operands = { ExtInstOperands + 149, 1u }; return true;
+				case 26u: _operands = { ExtInstOperands + 150, 3u }; return true;
+				case 27u: _operands = { ExtInstOperands + 153, 2u }; return true;
+				case 28u: _operands = { ExtInstOperands + 155, 2u }; return true;
+				case 29u: _operands = { ExtInstOperands + 157, 2u }; return true;
+				case 30u: _operands = { ExtInstOperands + 159, 2u }; return true;
+				case 31u: _operands = { ExtInstOperands + 161, 2u }; return true;
+				case 32u: _operands = { ExtInstOperands + 163, 2u }; return true;
+				case 33u: _operands = { ExtInstOperands + 165, 1u }; return true;
+				case 34u: _operands = { ExtInstOperands + 166, 2u }; return true;
+				case 35u: _operands = { ExtInstOperands + 168, 1u }; return true;
+				case 36u: _operands = { ExtInstOperands + 169, 2u }; return true;
+				case 37u: _operands = { ExtInstOperands + 171, 1u }; return true;
+				case 38u: _operands = { ExtInstOperands + 172, 1u }; return true;
+				case 39u: _operands = { ExtInstOperands + 173, 1u }; return true;
+				case 40u: _operands = { ExtInstOperands + 174, 1u }; return true;
+				case 41u: _operands = { ExtInstOperands + 175, 1u }; return true;
+				case 42u: _operands = { ExtInstOperands + 176, 3u }; return true;
+				case 43u: _operands = { ExtInstOperands + 179, 2u }; return true;
+				case 44u: _operands = { ExtInstOperands + 181, 2u }; return true;
+				case 45u: _operands = { ExtInstOperands + 183, 2u }; return true;
+				case 46u: _operands = { ExtInstOperands + 185, 1u }; return true;
+				case 47u: _operands = { ExtInstOperands + 186, 2u }; return true;
+				case 48u: _operands = { ExtInstOperands + 188, 2u }; return true;
+				case 49u: _operands = { ExtInstOperands + 190, 2u }; return true;
+				case 50u: _operands = { ExtInstOperands + 192, 2u }; return true;
+				case 51u: _operands = { ExtInstOperands + 194, 2u }; return true;
+				case 52u: _operands = { ExtInstOperands + 196, 3u }; return true;
+				case 53u: _operands = { ExtInstOperands + 199, 1u }; return true;
+				case 54u: _operands = { ExtInstOperands + 200, 2u }; return true;
+				case 55u: _operands = { ExtInstOperands + 202, 1u }; return true;
+				case 56u: _operands = { ExtInstOperands + 203, 1u }; return true;
+				case 57u: _operands = { ExtInstOperands + 204, 1u }; return true;
+				case 58u: _operands = { ExtInstOperands + 205, 2u }; return true;
+				case 59u: _operands = { ExtInstOperands + 207, 1u }; return true;
+				case 60u: _operands = { ExtInstOperands + 208, 1u }; return true;
+				case 61u: _operands = { ExtInstOperands + 209, 1u }; return true;
+				case 62u: _operands = { ExtInstOperands + 210, 1u }; return true;
+				case 63u: _operands = { ExtInstOperands + 211, 1u }; return true;
+				case 64u: _operands = { ExtInstOperands + 212, 1u }; return true;
+				case 65u: _operands = { ExtInstOperands + 213, 1u }; return true;
+				case 66u: _operands = { ExtInstOperands + 214, 1u }; return true;
+				case 67u: _operands = { ExtInstOperands + 215, 1u }; return true;
+				case 68u: _operands = { ExtInstOperands + 216, 2u }; return true;
+				case 69u: _operands = { ExtInstOperands + 218, 1u }; return true;
+				case 70u: _operands = { ExtInstOperands + 219, 1u }; return true;
+				case 71u: _operands = { ExtInstOperands + 220, 1u }; return true;
+				case 72u: _operands = { ExtInstOperands + 221, 1u }; return true;
+				case 73u: _operands = { ExtInstOperands + 222, 1u }; return true;
+				case 74u: _operands = { ExtInstOperands + 223, 1u }; return true;
+				case 75u: _operands = { ExtInstOperands + 224, 2u }; return true;
+				case 76u: _operands = { ExtInstOperands + 226, 1u }; return true;
+				case 77u: _operands = { ExtInstOperands + 227, 1u }; return true;
+				case 78u: _operands = { ExtInstOperands + 228, 1u }; return true;
+				case 79u: _operands = { ExtInstOperands + 229, 1u }; return true;
+				case 80u: _operands = { ExtInstOperands + 230, 1u }; return true;
+				case 81u: _operands = { ExtInstOperands + 231, 1u }; return true;
+				case 82u: _operands = { ExtInstOperands + 232, 2u }; return true;
+				case 83u: _operands = { ExtInstOperands + 234, 1u }; return true;
+				case 84u: _operands = { ExtInstOperands + 235, 1u }; return true;
+				case 85u: _operands = { ExtInstOperands + 236, 1u }; return true;
+				case 86u: _operands = { ExtInstOperands + 237, 1u }; return true;
+				case 87u: _operands = { ExtInstOperands + 238, 1u }; return true;
+				case 88u: _operands = { ExtInstOperands + 239, 1u }; return true;
+				case 89u: _operands = { ExtInstOperands + 240, 2u }; return true;
+				case 90u: _operands = { ExtInstOperands + 242, 1u }; return true;
+				case 91u: _operands = { ExtInstOperands + 243, 1u }; return true;
+				case 92u: _operands = { ExtInstOperands + 244, 1u }; return true;
+				case 93u: _operands = { ExtInstOperands + 245, 1u }; return true;
+				case 94u: _operands = { ExtInstOperands + 246, 1u }; return true;
+				case 141u: _operands = { ExtInstOperands + 247, 1u }; return true;
+				case 142u: _operands = { ExtInstOperands + 248, 2u }; return true;
+				case 143u: _operands = { ExtInstOperands + 250, 2u }; return true;
+				case 144u: _operands = { ExtInstOperands + 252, 2u }; return true;
+				case 145u: _operands = { ExtInstOperands + 254, 2u }; return true;
+				case 146u: _operands = { ExtInstOperands + 256, 2u }; return true;
+				case 147u: _operands = { ExtInstOperands + 258, 2u }; return true;
+				case 148u: _operands = { ExtInstOperands + 260, 2u }; return true;
+				case 149u: _operands = { ExtInstOperands + 262, 3u }; return true;
+				case 150u: _operands = { ExtInstOperands + 265, 3u }; return true;
+				case 151u: _operands = { ExtInstOperands + 268, 1u }; return true;
+				case 152u: _operands = { ExtInstOperands + 269, 1u }; return true;
+				case 153u: _operands = { ExtInstOperands + 270, 3u }; return true;
+				case 154u: _operands = { ExtInstOperands + 273, 3u }; return true;
+				case 155u: _operands = { ExtInstOperands + 276, 3u }; return true;
+				case 156u: _operands = { ExtInstOperands + 279, 2u }; return true;
+				case 157u: _operands = { ExtInstOperands + 281, 2u }; return true;
+				case 158u: _operands = { ExtInstOperands + 283, 2u }; return true;
+				case 159u: _operands = { ExtInstOperands + 285, 2u }; return true;
+				case 160u: _operands = { ExtInstOperands + 287, 2u }; return true;
+				case 161u: _operands = { ExtInstOperands + 289, 2u }; return true;
+				case 162u: _operands = { ExtInstOperands + 291, 2u }; return true;
+				case 163u: _operands = { ExtInstOperands + 293, 2u }; return true;
+				case 164u: _operands = { ExtInstOperands + 295, 2u }; return true;
+				case 165u: _operands = { ExtInstOperands + 297, 2u }; return true;
+				case 166u: _operands = { ExtInstOperands + 299, 1u }; return true;
+				case 167u: _operands = { ExtInstOperands + 300, 3u }; return true;
+				case 168u: _operands = { ExtInstOperands + 303, 3u }; return true;
+				case 169u: _operands = { ExtInstOperands + 306, 2u }; return true;
+				case 170u: _operands = { ExtInstOperands + 308, 2u }; return true;
+				case 201u: _operands = { ExtInstOperands + 310, 1u }; return true;
+				case 202u: _operands = { ExtInstOperands + 311, 2u }; return true;
+				case 203u: _operands = { ExtInstOperands + 313, 2u }; return true;
+				case 204u: _operands = { ExtInstOperands + 315, 3u }; return true;
+				case 95u: _operands = { ExtInstOperands + 318, 3u }; return true;
+				case 96u: _operands = { ExtInstOperands + 321, 1u }; return true;
+				case 97u: _operands = { ExtInstOperands + 322, 2u }; return true;
+				case 98u: _operands = { ExtInstOperands + 324, 2u }; return true;
+				case 99u: _operands = { ExtInstOperands + 326, 3u }; return true;
+				case 100u: _operands = { ExtInstOperands + 329, 1u }; return true;
+				case 101u: _operands = { ExtInstOperands + 330, 2u }; return true;
+				case 102u: _operands = { ExtInstOperands + 332, 3u }; return true;
+				case 103u: _operands = { ExtInstOperands + 335, 1u }; return true;
+				case 104u: _operands = { ExtInstOperands + 336, 2u }; return true;
+				case 105u: _operands = { ExtInstOperands + 338, 2u }; return true;
+				case 106u: _operands = { ExtInstOperands + 340, 1u }; return true;
+				case 107u: _operands = { ExtInstOperands + 341, 1u }; return true;
+				case 108u: _operands = { ExtInstOperands + 342, 2u }; return true;
+				case 109u: _operands = { ExtInstOperands + 344, 1u }; return true;
+				case 110u: _operands = { ExtInstOperands + 345, 1u }; return true;
+				case 186u: _operands = { ExtInstOperands + 346, 3u }; return true;
+				case 187u: _operands = { ExtInstOperands + 349, 3u }; return true;
+				case 171u: _operands = { ExtInstOperands + 352, 3u }; return true;
+				case 172u: _operands = { ExtInstOperands + 355, 3u }; return true;
+				case 173u: _operands = { ExtInstOperands + 358, 2u }; return true;
+				case 174u: _operands = { ExtInstOperands + 360, 3u }; return true;
+				case 175u: _operands = { ExtInstOperands + 363, 3u }; return true;
+				case 176u: _operands = { ExtInstOperands + 366, 4u }; return true;
+				case 177u: _operands = { ExtInstOperands + 370, 3u }; return true;
+				case 178u: _operands = { ExtInstOperands + 373, 4u }; return true;
+				case 179u: _operands = { ExtInstOperands + 377, 3u }; return true;
+				case 180u: _operands = { ExtInstOperands + 380, 3u }; return true;
+				case 181u: _operands = { ExtInstOperands + 383, 4u }; return true;
+				case 182u: _operands = { ExtInstOperands + 387, 2u }; return true;
+				case 183u: _operands = { ExtInstOperands + 389, 3u }; return true;
+				case 184u: _operands = { ExtInstOperands + 392, 2u }; return true;
+				case 185u: _operands = { ExtInstOperands + 394, 2u }; return true;
+				}
+				break;
+			}
+			return false;
+		}
+		template <class Sink>
+		bool printOperands(OperandLayoutSpan _operands, PrintCursor& _cursor, PrinterState& _state, Sink& _sink);
+
+		template <class Sink>
+		bool printOperand(OperandKind _kind, PrintCursor& _cursor, PrinterState& _state, Sink& _sink)
+		{
+			if (_cursor.empty()) return false;
+			const OperandEncoding& encoding = GetOperandEncoding(_kind);
+			if (encoding.encoding == WordEncoding::Composite)
+			{
+				for (unsigned short i = 0u; i < encoding.baseCount; ++i)
+				{
+					if (i != 0u) _sink(" ");
+					if (!printOperand(encoding.bases[i], _cursor, _state, _sink)) return false;
+				}
+				return true;
+			}
+			if (encoding.encoding == WordEncoding::String) return printString(_cursor, _sink);
+			if (encoding.encoding == WordEncoding::ContextDependent)
+			{
+				// the width and signedness come from the result type
+				const unsigned int words = static_cast<unsigned int>(_cursor.end - _cursor.it);
+				const PrinterState::NumberType* type = numberType(_state, _cursor.resultType);
+				if (type != nullptr && words == (type->width + 31u) / 32u)
+				{
+					printNumber(*type, _cursor.it[0] | (words == 2u ? static_cast<unsigned long long>(_cursor.it[1]) << 32u : 0u), _sink);
+					_cursor.it = _cursor.end;
+					return true;
+				}
+				// unknown result type, one or two words are printed as unsigned integers
+				if (words == 2u) printUnsigned(_cursor.it[0] | static_cast<unsigned long long>(_cursor.it[1]) << 32u, _sink);
+				else for (unsigned int i = 0u; i < words; ++i) { if (i != 0u) _sink(" "); printUnsigned(_cursor.it[i], _sink); }
+				_cursor.it = _cursor.end;
+				return true;
+			}
+			const unsigned int word = *_cursor.it++;
+			switch (GetOperandCategory(_kind))
+			{
+			case OperandCategory::Id:
+				_sink("%");
+				printUnsigned(word, _sink);
+				return true;
+			case OperandCategory::ValueEnum:
+			{
+				const char* name = enumerantName(_kind, word);
+				if (name != nullptr) _sink(name); else printUnsigned(word, _sink);
+				const OperandLayoutSpan parameters = enumerantParameters(_kind, word);
+				if (parameters.count != 0u) _sink(" ");
+				return printOperands(parameters, _cursor, _state, _sink);
+			}
+			case OperandCategory::BitEnum:
+			{
+				if (word == 0u) { const char* name = enumerantName(_kind, 0u); _sink(name != nullptr ? name : "None"); return true; }
+				unsigned int unknown = 0u;
+				bool first = true;
+				for (unsigned int bits = word; bits != 0u; bits &= bits - 1u)
+				{
+					const unsigned int bit = bits & (~bits + 1u);
+					const char* name = enumerantName(_kind, bit);
+					if (name == nullptr) { unknown |= bit; continue; }
+					if (!first) _sink("|");
+					_sink(name);
+					first = false;
+				}
+				if (unknown != 0u) { if (!first) _sink("|"); printHex(unknown, _sink); }
+				// parameters of all set bits follow in ascending bit order
+				for (unsigned int bits = word; bits != 0u; bits &= bits - 1u)
+				{
+					const OperandLayoutSpan parameters = enumerantParameters(_kind, bits & (~bits + 1u));
+					if (parameters.count == 0u) continue;
+					_sink(" ");
+					if (!printOperands(parameters, _cursor, _state, _sink)) return false;
+				}
+				return true;
+			}
+			default: break;
+			}
+			switch (_kind)
+			{
+			case OperandKind::LiteralFloat: printFloat(word, 32u, _sink); return true;
+			case OperandKind::LiteralSpecConstantOpInteger:
+			{
+				const char* name = opName(static_cast<Op>(word));
+				if (name != nullptr) _sink(name + 2); else printUnsigned(word, _sink); // without the Op prefix
+				// operands of the operation aren't part of the grammar, spec constant operations only take ids
+				for (; !_cursor.empty(); ++_cursor.it) { _sink(" %"); printUnsigned(*_cursor.it, _sink); }
+				return true;
+			}
+			case OperandKind::LiteralExtInstInteger:
+			{
+				// the set id precedes the instruction number in OpExtInst
+				unsigned int set = 0u;
+				while (set < ExtInstSetCount && _state.extInstSets[set] != _cursor.it[-2]) ++set;
+				const char* name = extInstName(set, word);
+				if (name != nullptr) _sink(name); else printUnsigned(word, _sink);
+				OperandLayoutSpan operands{};
+				if (!extInstOperands(set, word, operands)) return true; // remaining IdRef operands of OpExtInst
+				if (operands.count != 0u && !_cursor.empty()) _sink(" ");
+				return printOperands(operands, _cursor, _state, _sink) && _cursor.empty();
+			}
+			default: printUnsigned(word, _sink); return true;
+			}
+		}
+		template <class Sink>
+		bool printOperands(OperandLayoutSpan _operands, PrintCursor& _cursor, PrinterState& _state, Sink& _sink)
+		{
+			bool first = true;
+			for (const OperandLayout& operand : _operands)
+			{
+				const bool optional = operand.quantifier != Quantifier::One;
+				do
+				{
+					if (optional && _cursor.empty()) break;
+					if (!first) _sink(" ");
+					first = false;
+					if (!printOperand(operand.kind, _cursor, _state, _sink)) return false;
+				} while (operand.quantifier == Quantifier::ZeroOrAny);
+			}
+			return true;
+		}
+	} // detail
+
+	// prints the instruction starting at _pWords (the opcode and word count word) like `spirv-dis --raw-id --no-indent` without a line break,
+	// _sink(const char*) is called for every piece of text. _state remembers OpExtInstImport ids to name OpExtInst instructions
+	// and the OpTypeInt and OpTypeFloat ids to print OpConstant and OpSpecConstant values as signed, unsigned or float numbers.
+	// Returns false if the words don't match the grammar.
+	template <class Sink>
+	bool printInstruction(const unsigned int* _pWords, Sink& _sink, PrinterState& _state)
+	{
+		using namespace spv;
+		const unsigned int wordCount = _pWords[0] >> WordCountShift;
+		const Op opcode = static_cast<Op>(_pWords[0] & OpCodeMask);
+		const char* name = detail::opName(opcode);
+		if (wordCount == 0u || name == nullptr) return false;
+
+		// scalar types giving constants their width and signedness, floats with an FP encoding operand are not tracked
+		if (((opcode == Op::OpTypeInt && wordCount == 4u) || (opcode == Op::OpTypeFloat && wordCount == 3u)) && _state.numberTypeCount < PrinterState::MaxNumberTypes)
+		{
+			const PrinterState::NumberKind kind = opcode == Op::OpTypeFloat ? PrinterState::NumberKind::Float : _pWords[3] != 0u ? PrinterState::NumberKind::Signed : PrinterState::NumberKind::Unsigned;
+			_state.numberTypes[_state.numberTypeCount++] = { _pWords[1], _pWords[2], kind };
+		}
+
+		const OperandLayoutSpan operands = GetOperandLayout(opcode);
+		detail::PrintCursor cursor{ _pWords + 1, _pWords + wordCount };
+		const InstructionInfo info = GetInfo(opcode);
+		if (info.hasResultType) { if (cursor.empty()) return false; cursor.resultType = *cursor.it++; }
+		if (info.hasResult)
+		{
+			if (cursor.empty()) return false;
+			if (opcode == Op::OpExtInstImport)
+			{
+				for (unsigned int set = 0u; set < ExtInstSetCount; ++set)
+				{
+					const char* setName = detail::ExtInstSetNames[set];
+					const char* it = reinterpret_cast<const char*>(cursor.it + 1);
+					const char* end = reinterpret_cast<const char*>(cursor.end);
+					while (it != end && *setName != '\0' && *it == *setName) { ++it; ++setName; }
+					if (it != end && *it == '\0' && *setName == '\0') _state.extInstSets[set] = *cursor.it;
+				}
+			}
+			_sink("%");
+			detail::printUnsigned(*cursor.it++, _sink);
+			_sink(" = ");
+		}
+		_sink(name);
+		if (info.hasResultType) { _sink(" %"); detail::printUnsigned(cursor.resultType, _sink); }
+
+		// result type and result are the first operands of every instruction that has them
+		const unsigned int skip = (info.hasResultType ? 1u : 0u) + (info.hasResult ? 1u : 0u);
+		const OperandLayoutSpan rest{ operands.operands + skip, operands.count - skip };
+		if (rest.count != 0u && !cursor.empty()) _sink(" ");
+		return detail::printOperands(rest, cursor, _state, _sink) && cursor.empty();
+	}
+
+	// single instruction without module context, OpExtInst instructions are printed with their instruction number and constants as unsigned integers
+	template <class Sink>
+	bool printInstruction(const unsigned int* _pWords, Sink& _sink)
+	{
+		PrinterState state;
+		return printInstruction(_pWords, _sink, state);
+	}
+} // spvgentwo
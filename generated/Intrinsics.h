@@ -0,0 +1,328 @@
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+// extinst.glsl.std.450.grammar.json sha256:3bcf78c13b71a9ebba51e890c55f41a5e0f47ba283bc7c08fd7e1344ea5f47a6 version 100 revision 2
+// extinst.opencl.std.100.grammar.json sha256:b6be3248af8e6150332ec5d9df2e578b364d588c762533831b7550f607a73ff8 version 100 revision 2
+#pragma once
+
+// GLSL.std.450 instruction table for shader compiler frontends
+namespace intrinsics
+{
+	struct Intrinsic
+	{
+		const char* name;
+		unsigned int opcode;
+		unsigned int operandCount;
+	};
+
+	static constexpr Intrinsic Glsl[] = {
+		{ "round", 1u, 1u },
+		{ "roundEven", 2u, 1u },
+		{ "trunc", 3u, 1u },
+		{ "fAbs", 4u, 1u },
+		{ "sAbs", 5u, 1u },
+		{ "fSign", 6u, 1u },
+		{ "sSign", 7u, 1u },
+		{ "floor", 8u, 1u },
+		{ "ceil", 9u, 1u },
+		{ "fract", 10u, 1u },
+		{ "radians", 11u, 1u },
+		{ "degrees", 12u, 1u },
+		{ "sin", 13u, 1u },
+		{ "cos", 14u, 1u },
+		{ "tan", 15u, 1u },
+		{ "asin", 16u, 1u },
+		{ "acos", 17u, 1u },
+		{ "atan", 18u, 1u },
+		{ "sinh", 19u, 1u },
+		{ "cosh", 20u, 1u },
+		{ "tanh", 21u, 1u },
+		{ "asinh", 22u, 1u },
+		{ "acosh", 23u, 1u },
+		{ "atanh", 24u, 1u },
+		{ "atan2", 25u, 2u },
+		{ "pow", 26u, 2u },
+		{ "exp", 27u, 1u },
+		{ "log", 28u, 1u },
+		{ "exp2", 29u, 1u },
+		{ "log2", 30u, 1u },
+		{ "sqrt", 31u, 1u },
+		{ "inverseSqrt", 32u, 1u },
+		{ "determinant", 33u, 1u },
+		{ "matrixInverse", 34u, 1u },
+		{ "modf", 35u, 2u },
+		{ "modfStruct", 36u, 1u },
+		{ "fMin", 37u, 2u },
+		{ "uMin", 38u, 2u },
+		{ "sMin", 39u, 2u },
+		{ "fMax", 40u, 2u },
+		{ "uMax", 41u, 2u },
+		{ "sMax", 42u, 2u },
+		{ "fClamp", 43u, 3u },
+		{ "uClamp", 44u, 3u },
+		{ "sClamp", 45u, 3u },
+		{ "fMix", 46u, 3u },
+		{ "iMix", 47u, 3u },
+		{ "step", 48u, 2u },
+		{ "smoothStep", 49u, 3u },
+		{ "fma", 50u, 3u },
+		{ "frexp", 51u, 2u },
+		{ "frexpStruct", 52u, 1u },
+		{ "ldexp", 53u, 2u },
+		{ "packSnorm4x8", 54u, 1u },
+		{ "packUnorm4x8", 55u, 1u },
+		{ "packSnorm2x16", 56u, 1u },
+		{ "packUnorm2x16", 57u, 1u },
+		{ "packHalf2x16", 58u, 1u },
+		{ "packDouble2x32", 59u, 1u },
+		{ "unpackSnorm2x16", 60u, 1u },
+		{ "unpackUnorm2x16", 61u, 1u },
+		{ "unpackHalf2x16", 62u, 1u },
+		{ "unpackSnorm4x8", 63u, 1u },
+		{ "unpackUnorm4x8", 64u, 1u },
+		{ "unpackDouble2x32", 65u, 1u },
+		{ "length", 66u, 1u },
+		{ "distance", 67u, 2u },
+		{ "cross", 68u, 2u },
+		{ "normalize", 69u, 1u },
+		{ "faceForward", 70u, 3u },
+		{ "reflect", 71u, 2u },
+		{ "refract", 72u, 3u },
+		{ "findILsb", 73u, 1u },
+		{ "findSMsb", 74u, 1u },
+		{ "findUMsb", 75u, 1u },
+		{ "interpolateAtCentroid", 76u, 1u },
+		{ "interpolateAtSample", 77u, 2u },
+		{ "interpolateAtOffset", 78u, 2u },
+		{ "nMin", 79u, 2u },
+		{ "nMax", 80u, 2u },
+		{ "nClamp", 81u, 3u },
+	};
+
+	enum class Capability : unsigned int
+	{
+		MATRIX = 0,
+		SHADER = 1,
+		GEOMETRY = 2,
+		TESSELLATION = 3,
+		ADDRESSES = 4,
+		LINKAGE = 5,
+		KERNEL = 6,
+		VECTOR16 = 7,
+		FLOAT16_BUFFER = 8,
+		FLOAT16 = 9,
+		FLOAT64 = 10,
+		INT64 = 11,
+		INT64_ATOMICS = 12,
+		IMAGE_BASIC = 13,
+		IMAGE_READ_WRITE = 14,
+		IMAGE_MIPMAP = 15,
+		PIPES = 17,
+		GROUPS = 18,
+		DEVICE_ENQUEUE = 19,
+		LITERAL_SAMPLER = 20,
+		ATOMIC_STORAGE = 21,
+		INT16 = 22,
+		TESSELLATION_POINT_SIZE = 23,
+		GEOMETRY_POINT_SIZE = 24,
+		IMAGE_GATHER_EXTENDED = 25,
+		STORAGE_IMAGE_MULTISAMPLE = 27,
+		UNIFORM_BUFFER_ARRAY_DYNAMIC_INDEXING = 28,
+		SAMPLED_IMAGE_ARRAY_DYNAMIC_INDEXING = 29,
+		STORAGE_BUFFER_ARRAY_DYNAMIC_INDEXING = 30,
+		STORAGE_IMAGE_ARRAY_DYNAMIC_INDEXING = 31,
+		CLIP_DISTANCE = 32,
+		CULL_DISTANCE = 33,
+		IMAGE_CUBE_ARRAY = 34,
+		SAMPLE_RATE_SHADING = 35,
+		IMAGE_RECT = 36,
+		SAMPLED_RECT = 37,
+		GENERIC_POINTER = 38,
+		INT8 = 39,
+		INPUT_ATTACHMENT = 40,
+		SPARSE_RESIDENCY = 41,
+		MIN_LOD = 42,
+		SAMPLED1_D = 43,
+		IMAGE1_D = 44,
+		SAMPLED_CUBE_ARRAY = 45,
+		SAMPLED_BUFFER = 46,
+		IMAGE_BUFFER = 47,
+		IMAGE_MS_ARRAY = 48,
+		STORAGE_IMAGE_EXTENDED_FORMATS = 49,
+		IMAGE_QUERY = 50,
+		DERIVATIVE_CONTROL = 51,
+		INTERPOLATION_FUNCTION = 52,
+		TRANSFORM_FEEDBACK = 53,
+		GEOMETRY_STREAMS = 54,
+		STORAGE_IMAGE_READ_WITHOUT_FORMAT = 55,
+		STORAGE_IMAGE_WRITE_WITHOUT_FORMAT = 56,
+		MULTI_VIEWPORT = 57,
+		SUBGROUP_DISPATCH = 58,
+		NAMED_BARRIER = 59,
+		PIPE_STORAGE = 60,
+		GROUP_NON_UNIFORM = 61,
+		GROUP_NON_UNIFORM_VOTE = 62,
+		GROUP_NON_UNIFORM_ARITHMETIC = 63,
+		GROUP_NON_UNIFORM_BALLOT = 64,
+		GROUP_NON_UNIFORM_SHUFFLE = 65,
+		GROUP_NON_UNIFORM_SHUFFLE_RELATIVE = 66,
+		GROUP_NON_UNIFORM_CLUSTERED = 67,
+		GROUP_NON_UNIFORM_QUAD = 68,
+		SHADER_LAYER = 69,
+		SHADER_VIEWPORT_INDEX = 70,
+		UNIFORM_DECORATION = 71,
+		CORE_BUILTINS_ARM = 4165,
+		TILE_IMAGE_COLOR_READ_ACCESS_EXT = 4166,
+		TILE_IMAGE_DEPTH_READ_ACCESS_EXT = 4167,
+		TILE_IMAGE_STENCIL_READ_ACCESS_EXT = 4168,
+		FRAGMENT_SHADING_RATE_KHR = 4422,
+		SUBGROUP_BALLOT_KHR = 4423,
+		DRAW_PARAMETERS = 4427,
+		WORKGROUP_MEMORY_EXPLICIT_LAYOUT_KHR = 4428,
+		WORKGROUP_MEMORY_EXPLICIT_LAYOUT8_BIT_ACCESS_KHR = 4429,
+		WORKGROUP_MEMORY_EXPLICIT_LAYOUT16_BIT_ACCESS_KHR = 4430,
+		SUBGROUP_VOTE_KHR = 4431,
+		STORAGE_BUFFER16_BIT_ACCESS = 4433,
+		UNIFORM_AND_STORAGE_BUFFER16_BIT_ACCESS = 4434,
+		STORAGE_PUSH_CONSTANT16 = 4435,
+		STORAGE_INPUT_OUTPUT16 = 4436,
+		DEVICE_GROUP = 4437,
+		MULTI_VIEW = 4439,
+		VARIABLE_POINTERS_STORAGE_BUFFER = 4441,
+		VARIABLE_POINTERS = 4442,
+		ATOMIC_STORAGE_OPS = 4445,
+		SAMPLE_MASK_POST_DEPTH_COVERAGE = 4447,
+		STORAGE_BUFFER8_BIT_ACCESS = 4448,
+		UNIFORM_AND_STORAGE_BUFFER8_BIT_ACCESS = 4449,
+		STORAGE_PUSH_CONSTANT8 = 4450,
+		DENORM_PRESERVE = 4464,
+		DENORM_FLUSH_TO_ZERO = 4465,
+		SIGNED_ZERO_INF_NAN_PRESERVE = 4466,
+		ROUNDING_MODE_RTE = 4467,
+		ROUNDING_MODE_RTZ = 4468,
+		RAY_QUERY_PROVISIONAL_KHR = 4471,
+		RAY_QUERY_KHR = 4472,
+		RAY_TRAVERSAL_PRIMITIVE_CULLING_KHR = 4478,
+		RAY_TRACING_KHR = 4479,
+		TEXTURE_SAMPLE_WEIGHTED_QCOM = 4484,
+		TEXTURE_BOX_FILTER_QCOM = 4485,
+		TEXTURE_BLOCK_MATCH_QCOM = 4486,
+		FLOAT16_IMAGE_AMD = 5008,
+		IMAGE_GATHER_BIAS_LOD_AMD = 5009,
+		FRAGMENT_MASK_AMD = 5010,
+		STENCIL_EXPORT_EXT = 5013,
+		IMAGE_READ_WRITE_LOD_AMD = 5015,
+		INT64_IMAGE_EXT = 5016,
+		SHADER_CLOCK_KHR = 5055,
+		SHADER_ENQUEUE_AMDX = 5067,
+		SAMPLE_MASK_OVERRIDE_COVERAGE_NV = 5249,
+		GEOMETRY_SHADER_PASSTHROUGH_NV = 5251,
+		SHADER_VIEWPORT_INDEX_LAYER_EXT = 5254,
+		SHADER_VIEWPORT_MASK_NV = 5255,
+		SHADER_STEREO_VIEW_NV = 5259,
+		PER_VIEW_ATTRIBUTES_NV = 5260,
+		FRAGMENT_FULLY_COVERED_EXT = 5265,
+		MESH_SHADING_NV = 5266,
+		IMAGE_FOOTPRINT_NV = 5282,
+		MESH_SHADING_EXT = 5283,
+		FRAGMENT_BARYCENTRIC_KHR = 5284,
+		COMPUTE_DERIVATIVE_GROUP_QUADS_NV = 5288,
+		FRAGMENT_DENSITY_EXT = 5291,
+		GROUP_NON_UNIFORM_PARTITIONED_NV = 5297,
+		SHADER_NON_UNIFORM = 5301,
+		RUNTIME_DESCRIPTOR_ARRAY = 5302,
+		INPUT_ATTACHMENT_ARRAY_DYNAMIC_INDEXING = 5303,
+		UNIFORM_TEXEL_BUFFER_ARRAY_DYNAMIC_INDEXING = 5304,
+		STORAGE_TEXEL_BUFFER_ARRAY_DYNAMIC_INDEXING = 5305,
+		UNIFORM_BUFFER_ARRAY_NON_UNIFORM_INDEXING = 5306,
+		SAMPLED_IMAGE_ARRAY_NON_UNIFORM_INDEXING = 5307,
+		STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING = 5308,
+		STORAGE_IMAGE_ARRAY_NON_UNIFORM_INDEXING = 5309,
+		INPUT_ATTACHMENT_ARRAY_NON_UNIFORM_INDEXING = 5310,
+		UNIFORM_TEXEL_BUFFER_ARRAY_NON_UNIFORM_INDEXING = 5311,
+		STORAGE_TEXEL_BUFFER_ARRAY_NON_UNIFORM_INDEXING = 5312,
+		RAY_TRACING_POSITION_FETCH_KHR = 5336,
+		RAY_TRACING_NV = 5340,
+		RAY_TRACING_MOTION_BLUR_NV = 5341,
+		VULKAN_MEMORY_MODEL = 5345,
+		VULKAN_MEMORY_MODEL_DEVICE_SCOPE = 5346,
+		PHYSICAL_STORAGE_BUFFER_ADDRESSES = 5347,
+		COMPUTE_DERIVATIVE_GROUP_LINEAR_NV = 5350,
+		RAY_TRACING_PROVISIONAL_KHR = 5353,
+		COOPERATIVE_MATRIX_NV = 5357,
+		FRAGMENT_SHADER_SAMPLE_INTERLOCK_EXT = 5363,
+		FRAGMENT_SHADER_SHADING_RATE_INTERLOCK_EXT = 5372,
+		SHADER_SM_BUILTINS_NV = 5373,
+		FRAGMENT_SHADER_PIXEL_INTERLOCK_EXT = 5378,
+		DEMOTE_TO_HELPER_INVOCATION = 5379,
+		RAY_TRACING_OPACITY_MICROMAP_EXT = 5381,
+		SHADER_INVOCATION_REORDER_NV = 5383,
+		BINDLESS_TEXTURE_NV = 5390,
+		RAY_QUERY_POSITION_FETCH_KHR = 5391,
+		SUBGROUP_SHUFFLE_INTEL = 5568,
+		SUBGROUP_BUFFER_BLOCK_IOINTEL = 5569,
+		SUBGROUP_IMAGE_BLOCK_IOINTEL = 5570,
+		SUBGROUP_IMAGE_MEDIA_BLOCK_IOINTEL = 5579,
+		ROUND_TO_INFINITY_INTEL = 5582,
+		FLOATING_POINT_MODE_INTEL = 5583,
+		INTEGER_FUNCTIONS2_INTEL = 5584,
+		FUNCTION_POINTERS_INTEL = 5603,
+		INDIRECT_REFERENCES_INTEL = 5604,
+		ASM_INTEL = 5606,
+		ATOMIC_FLOAT32_MIN_MAX_EXT = 5612,
+		ATOMIC_FLOAT64_MIN_MAX_EXT = 5613,
+		ATOMIC_FLOAT16_MIN_MAX_EXT = 5616,
+		VECTOR_COMPUTE_INTEL = 5617,
+		VECTOR_ANY_INTEL = 5619,
+		EXPECT_ASSUME_KHR = 5629,
+		SUBGROUP_AVC_MOTION_ESTIMATION_INTEL = 5696,
+		SUBGROUP_AVC_MOTION_ESTIMATION_INTRA_INTEL = 5697,
+		SUBGROUP_AVC_MOTION_ESTIMATION_CHROMA_INTEL = 5698,
+		VARIABLE_LENGTH_ARRAY_INTEL = 5817,
+		FUNCTION_FLOAT_CONTROL_INTEL = 5821,
+		FPGA_MEMORY_ATTRIBUTES_INTEL = 5824,
+		FP_FAST_MATH_MODE_INTEL = 5837,
+		ARBITRARY_PRECISION_INTEGERS_INTEL = 5844,
+		ARBITRARY_PRECISION_FLOATING_POINT_INTEL = 5845,
+		UNSTRUCTURED_LOOP_CONTROLS_INTEL = 5886,
+		FPGA_LOOP_CONTROLS_INTEL = 5888,
+		KERNEL_ATTRIBUTES_INTEL = 5892,
+		FPGA_KERNEL_ATTRIBUTES_INTEL = 5897,
+		FPGA_MEMORY_ACCESSES_INTEL = 5898,
+		FPGA_CLUSTER_ATTRIBUTES_INTEL = 5904,
+		LOOP_FUSE_INTEL = 5906,
+		FPGADSP_CONTROL_INTEL = 5908,
+		MEMORY_ACCESS_ALIASING_INTEL = 5910,
+		FPGA_INVOCATION_PIPELINING_ATTRIBUTES_INTEL = 5916,
+		FPGA_BUFFER_LOCATION_INTEL = 5920,
+		ARBITRARY_PRECISION_FIXED_POINT_INTEL = 5922,
+		USM_STORAGE_CLASSES_INTEL = 5935,
+		RUNTIME_ALIGNED_ATTRIBUTE_INTEL = 5939,
+		IO_PIPES_INTEL = 5943,
+		BLOCKING_PIPES_INTEL = 5945,
+		FPGA_REG_INTEL = 5948,
+		DOT_PRODUCT_INPUT_ALL = 6016,
+		DOT_PRODUCT_INPUT4X8_BIT = 6017,
+		DOT_PRODUCT_INPUT4X8_BIT_PACKED = 6018,
+		DOT_PRODUCT = 6019,
+		RAY_CULL_MASK_KHR = 6020,
+		COOPERATIVE_MATRIX_KHR = 6022,
+		BIT_INSTRUCTIONS = 6025,
+		GROUP_NON_UNIFORM_ROTATE_KHR = 6026,
+		ATOMIC_FLOAT32_ADD_EXT = 6033,
+		ATOMIC_FLOAT64_ADD_EXT = 6034,
+		LONG_CONSTANT_COMPOSITE_INTEL = 6089,
+		OPT_NONE_INTEL = 6094,
+		ATOMIC_FLOAT16_ADD_EXT = 6095,
+		DEBUG_INFO_MODULE_INTEL = 6114,
+		B_FLOAT16_CONVERSION_INTEL = 6115,
+		SPLIT_BARRIER_INTEL = 6141,
+		GLOBAL_VARIABLE_FPGA_DECORATIONS_INTEL = 6146,
+		FPGA_KERNEL_ATTRIBUTESV2_INTEL = 6161,
+		GLOBAL_VARIABLE_HOST_ACCESS_INTEL = 6167,
+		FP_MAX_ERROR_INTEL = 6169,
+		FPGA_LATENCY_CONTROL_INTEL = 6171,
+		FPGA_ARGUMENT_INTERFACES_INTEL = 6174,
+		GROUP_UNIFORM_ARITHMETIC_KHR = 6400,
+		CACHE_CONTROLS_INTEL = 6441,
+	};
+} // intrinsics
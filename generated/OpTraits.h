@@ -0,0 +1,9273 @@
+// Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+#pragma once
+
+// OperandKind is only declared with SPVGENTWO_OPERAND_LAYOUT, include this header before any other include of Spv.h
+#ifndef SPVGENTWO_OPERAND_LAYOUT
+#define SPVGENTWO_OPERAND_LAYOUT
+#endif
+#include "Spv.h"
+
+namespace spvgentwo::spv
+{
+	template <class T, T... Values>
+	struct EnumList
+	{
+		static constexpr unsigned int count = sizeof...(Values);
+		static constexpr T values[count != 0u ? count : 1u] = { Values... };
+		static constexpr bool contains([[maybe_unused]] T value) { return ((Values == value) || ...); }
+	};
+	// opcodes not in the grammar, Dispatch passes OpTraits<Op::Max> for them
+	template <Op opcode>
+	struct OpTraits
+	{
+		static constexpr Op op = opcode;
+		static constexpr bool valid = false;
+		static constexpr const char* name = nullptr;
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{};
+	};
+	template <>
+	struct OpTraits<Op::OpNop>
+	{
+		static constexpr Op op = Op::OpNop;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpNop";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUndef>
+	{
+		static constexpr Op op = Op::OpUndef;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUndef";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSourceContinued>
+	{
+		static constexpr Op op = Op::OpSourceContinued;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSourceContinued";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSource>
+	{
+		static constexpr Op op = Op::OpSource;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSource";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::SourceLanguage, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSourceExtension>
+	{
+		static constexpr Op op = Op::OpSourceExtension;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSourceExtension";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpName>
+	{
+		static constexpr Op op = Op::OpName;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpName";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemberName>
+	{
+		static constexpr Op op = Op::OpMemberName;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemberName";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpString>
+	{
+		static constexpr Op op = Op::OpString;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpString";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLine>
+	{
+		static constexpr Op op = Op::OpLine;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLine";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExtension>
+	{
+		static constexpr Op op = Op::OpExtension;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExtension";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Extension;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExtInstImport>
+	{
+		static constexpr Op op = Op::OpExtInstImport;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExtInstImport";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Extension;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExtInst>
+	{
+		static constexpr Op op = Op::OpExtInst;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExtInst";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Extension;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralExtInstInteger, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemoryModel>
+	{
+		static constexpr Op op = Op::OpMemoryModel;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemoryModel";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ModeSetting;
+		static constexpr EnumList<OperandKind, OperandKind::AddressingModel, OperandKind::MemoryModel> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEntryPoint>
+	{
+		static constexpr Op op = Op::OpEntryPoint;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEntryPoint";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ModeSetting;
+		static constexpr EnumList<OperandKind, OperandKind::ExecutionModel, OperandKind::IdRef, OperandKind::LiteralString, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExecutionMode>
+	{
+		static constexpr Op op = Op::OpExecutionMode;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExecutionMode";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ModeSetting;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::ExecutionMode> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCapability>
+	{
+		static constexpr Op op = Op::OpCapability;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCapability";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ModeSetting;
+		static constexpr EnumList<OperandKind, OperandKind::Capability> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeVoid>
+	{
+		static constexpr Op op = Op::OpTypeVoid;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeVoid";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeBool>
+	{
+		static constexpr Op op = Op::OpTypeBool;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeBool";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeInt>
+	{
+		static constexpr Op op = Op::OpTypeInt;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeInt";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeFloat>
+	{
+		static constexpr Op op = Op::OpTypeFloat;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeFloat";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeVector>
+	{
+		static constexpr Op op = Op::OpTypeVector;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeVector";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeMatrix>
+	{
+		static constexpr Op op = Op::OpTypeMatrix;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeMatrix";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeImage>
+	{
+		static constexpr Op op = Op::OpTypeImage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeImage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::Dim, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::ImageFormat, OperandKind::AccessQualifier> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeSampler>
+	{
+		static constexpr Op op = Op::OpTypeSampler;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeSampler";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeSampledImage>
+	{
+		static constexpr Op op = Op::OpTypeSampledImage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeSampledImage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeArray>
+	{
+		static constexpr Op op = Op::OpTypeArray;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeArray";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeRuntimeArray>
+	{
+		static constexpr Op op = Op::OpTypeRuntimeArray;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeRuntimeArray";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeStruct>
+	{
+		static constexpr Op op = Op::OpTypeStruct;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeStruct";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeOpaque>
+	{
+		static constexpr Op op = Op::OpTypeOpaque;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeOpaque";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypePointer>
+	{
+		static constexpr Op op = Op::OpTypePointer;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypePointer";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::StorageClass, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeFunction>
+	{
+		static constexpr Op op = Op::OpTypeFunction;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeFunction";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeEvent>
+	{
+		static constexpr Op op = Op::OpTypeEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeEvent";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeDeviceEvent>
+	{
+		static constexpr Op op = Op::OpTypeDeviceEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeDeviceEvent";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeReserveId>
+	{
+		static constexpr Op op = Op::OpTypeReserveId;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeReserveId";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeQueue>
+	{
+		static constexpr Op op = Op::OpTypeQueue;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeQueue";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypePipe>
+	{
+		static constexpr Op op = Op::OpTypePipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypePipe";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::AccessQualifier> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeForwardPointer>
+	{
+		static constexpr Op op = Op::OpTypeForwardPointer;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeForwardPointer";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::StorageClass> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses, Capability::PhysicalStorageBufferAddresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantTrue>
+	{
+		static constexpr Op op = Op::OpConstantTrue;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantTrue";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantFalse>
+	{
+		static constexpr Op op = Op::OpConstantFalse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantFalse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstant>
+	{
+		static constexpr Op op = Op::OpConstant;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstant";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::LiteralContextDependentNumber> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantComposite>
+	{
+		static constexpr Op op = Op::OpConstantComposite;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantComposite";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantSampler>
+	{
+		static constexpr Op op = Op::OpConstantSampler;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantSampler";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::SamplerAddressingMode, OperandKind::LiteralInteger, OperandKind::SamplerFilterMode> operandKinds{};
+		static constexpr EnumList<Capability, Capability::LiteralSampler> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantNull>
+	{
+		static constexpr Op op = Op::OpConstantNull;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantNull";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstantTrue>
+	{
+		static constexpr Op op = Op::OpSpecConstantTrue;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstantTrue";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstantFalse>
+	{
+		static constexpr Op op = Op::OpSpecConstantFalse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstantFalse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstant>
+	{
+		static constexpr Op op = Op::OpSpecConstant;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstant";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::LiteralContextDependentNumber> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstantComposite>
+	{
+		static constexpr Op op = Op::OpSpecConstantComposite;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstantComposite";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstantOp>
+	{
+		static constexpr Op op = Op::OpSpecConstantOp;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstantOp";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::LiteralSpecConstantOpInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFunction>
+	{
+		static constexpr Op op = Op::OpFunction;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFunction";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Function;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::FunctionControl, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFunctionParameter>
+	{
+		static constexpr Op op = Op::OpFunctionParameter;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFunctionParameter";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Function;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFunctionEnd>
+	{
+		static constexpr Op op = Op::OpFunctionEnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFunctionEnd";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Function;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFunctionCall>
+	{
+		static constexpr Op op = Op::OpFunctionCall;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFunctionCall";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Function;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVariable>
+	{
+		static constexpr Op op = Op::OpVariable;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVariable";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::StorageClass, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageTexelPointer>
+	{
+		static constexpr Op op = Op::OpImageTexelPointer;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageTexelPointer";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLoad>
+	{
+		static constexpr Op op = Op::OpLoad;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLoad";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpStore>
+	{
+		static constexpr Op op = Op::OpStore;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpStore";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCopyMemory>
+	{
+		static constexpr Op op = Op::OpCopyMemory;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCopyMemory";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCopyMemorySized>
+	{
+		static constexpr Op op = Op::OpCopyMemorySized;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCopyMemorySized";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAccessChain>
+	{
+		static constexpr Op op = Op::OpAccessChain;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAccessChain";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpInBoundsAccessChain>
+	{
+		static constexpr Op op = Op::OpInBoundsAccessChain;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpInBoundsAccessChain";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrAccessChain>
+	{
+		static constexpr Op op = Op::OpPtrAccessChain;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrAccessChain";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses, Capability::VariablePointers, Capability::VariablePointersStorageBuffer, Capability::PhysicalStorageBufferAddresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArrayLength>
+	{
+		static constexpr Op op = Op::OpArrayLength;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArrayLength";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGenericPtrMemSemantics>
+	{
+		static constexpr Op op = Op::OpGenericPtrMemSemantics;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGenericPtrMemSemantics";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpInBoundsPtrAccessChain>
+	{
+		static constexpr Op op = Op::OpInBoundsPtrAccessChain;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpInBoundsPtrAccessChain";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDecorate>
+	{
+		static constexpr Op op = Op::OpDecorate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDecorate";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::Decoration> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemberDecorate>
+	{
+		static constexpr Op op = Op::OpMemberDecorate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemberDecorate";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::Decoration> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDecorationGroup>
+	{
+		static constexpr Op op = Op::OpDecorationGroup;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDecorationGroup";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupDecorate>
+	{
+		static constexpr Op op = Op::OpGroupDecorate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupDecorate";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupMemberDecorate>
+	{
+		static constexpr Op op = Op::OpGroupMemberDecorate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupMemberDecorate";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::PairIdRefLiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVectorExtractDynamic>
+	{
+		static constexpr Op op = Op::OpVectorExtractDynamic;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVectorExtractDynamic";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVectorInsertDynamic>
+	{
+		static constexpr Op op = Op::OpVectorInsertDynamic;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVectorInsertDynamic";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVectorShuffle>
+	{
+		static constexpr Op op = Op::OpVectorShuffle;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVectorShuffle";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCompositeConstruct>
+	{
+		static constexpr Op op = Op::OpCompositeConstruct;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCompositeConstruct";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCompositeExtract>
+	{
+		static constexpr Op op = Op::OpCompositeExtract;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCompositeExtract";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCompositeInsert>
+	{
+		static constexpr Op op = Op::OpCompositeInsert;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCompositeInsert";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCopyObject>
+	{
+		static constexpr Op op = Op::OpCopyObject;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCopyObject";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTranspose>
+	{
+		static constexpr Op op = Op::OpTranspose;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTranspose";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSampledImage>
+	{
+		static constexpr Op op = Op::OpSampledImage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSampledImage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleDrefImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleDrefImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleDrefImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleDrefExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleDrefExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleDrefExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleProjImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleProjImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleProjImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleProjExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleProjExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleProjExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleProjDrefImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleProjDrefImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleProjDrefImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleProjDrefExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSampleProjDrefExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleProjDrefExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageFetch>
+	{
+		static constexpr Op op = Op::OpImageFetch;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageFetch";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageGather>
+	{
+		static constexpr Op op = Op::OpImageGather;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageGather";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageDrefGather>
+	{
+		static constexpr Op op = Op::OpImageDrefGather;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageDrefGather";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageRead>
+	{
+		static constexpr Op op = Op::OpImageRead;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageRead";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageWrite>
+	{
+		static constexpr Op op = Op::OpImageWrite;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageWrite";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImage>
+	{
+		static constexpr Op op = Op::OpImage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQueryFormat>
+	{
+		static constexpr Op op = Op::OpImageQueryFormat;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQueryFormat";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQueryOrder>
+	{
+		static constexpr Op op = Op::OpImageQueryOrder;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQueryOrder";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQuerySizeLod>
+	{
+		static constexpr Op op = Op::OpImageQuerySizeLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQuerySizeLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel, Capability::ImageQuery> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQuerySize>
+	{
+		static constexpr Op op = Op::OpImageQuerySize;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQuerySize";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel, Capability::ImageQuery> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQueryLod>
+	{
+		static constexpr Op op = Op::OpImageQueryLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQueryLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ImageQuery> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQueryLevels>
+	{
+		static constexpr Op op = Op::OpImageQueryLevels;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQueryLevels";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel, Capability::ImageQuery> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageQuerySamples>
+	{
+		static constexpr Op op = Op::OpImageQuerySamples;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageQuerySamples";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel, Capability::ImageQuery> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertFToU>
+	{
+		static constexpr Op op = Op::OpConvertFToU;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertFToU";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertFToS>
+	{
+		static constexpr Op op = Op::OpConvertFToS;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertFToS";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertSToF>
+	{
+		static constexpr Op op = Op::OpConvertSToF;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertSToF";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToF>
+	{
+		static constexpr Op op = Op::OpConvertUToF;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToF";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUConvert>
+	{
+		static constexpr Op op = Op::OpUConvert;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUConvert";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSConvert>
+	{
+		static constexpr Op op = Op::OpSConvert;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSConvert";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFConvert>
+	{
+		static constexpr Op op = Op::OpFConvert;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFConvert";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpQuantizeToF16>
+	{
+		static constexpr Op op = Op::OpQuantizeToF16;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpQuantizeToF16";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertPtrToU>
+	{
+		static constexpr Op op = Op::OpConvertPtrToU;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertPtrToU";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses, Capability::PhysicalStorageBufferAddresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSatConvertSToU>
+	{
+		static constexpr Op op = Op::OpSatConvertSToU;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSatConvertSToU";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSatConvertUToS>
+	{
+		static constexpr Op op = Op::OpSatConvertUToS;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSatConvertUToS";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToPtr>
+	{
+		static constexpr Op op = Op::OpConvertUToPtr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToPtr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses, Capability::PhysicalStorageBufferAddresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrCastToGeneric>
+	{
+		static constexpr Op op = Op::OpPtrCastToGeneric;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrCastToGeneric";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGenericCastToPtr>
+	{
+		static constexpr Op op = Op::OpGenericCastToPtr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGenericCastToPtr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGenericCastToPtrExplicit>
+	{
+		static constexpr Op op = Op::OpGenericCastToPtrExplicit;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGenericCastToPtrExplicit";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::StorageClass> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitcast>
+	{
+		static constexpr Op op = Op::OpBitcast;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitcast";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSNegate>
+	{
+		static constexpr Op op = Op::OpSNegate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSNegate";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFNegate>
+	{
+		static constexpr Op op = Op::OpFNegate;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFNegate";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIAdd>
+	{
+		static constexpr Op op = Op::OpIAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFAdd>
+	{
+		static constexpr Op op = Op::OpFAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpISub>
+	{
+		static constexpr Op op = Op::OpISub;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpISub";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFSub>
+	{
+		static constexpr Op op = Op::OpFSub;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFSub";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIMul>
+	{
+		static constexpr Op op = Op::OpIMul;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIMul";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFMul>
+	{
+		static constexpr Op op = Op::OpFMul;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFMul";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUDiv>
+	{
+		static constexpr Op op = Op::OpUDiv;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUDiv";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSDiv>
+	{
+		static constexpr Op op = Op::OpSDiv;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSDiv";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFDiv>
+	{
+		static constexpr Op op = Op::OpFDiv;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFDiv";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUMod>
+	{
+		static constexpr Op op = Op::OpUMod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUMod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSRem>
+	{
+		static constexpr Op op = Op::OpSRem;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSRem";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSMod>
+	{
+		static constexpr Op op = Op::OpSMod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSMod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFRem>
+	{
+		static constexpr Op op = Op::OpFRem;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFRem";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFMod>
+	{
+		static constexpr Op op = Op::OpFMod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFMod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVectorTimesScalar>
+	{
+		static constexpr Op op = Op::OpVectorTimesScalar;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVectorTimesScalar";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMatrixTimesScalar>
+	{
+		static constexpr Op op = Op::OpMatrixTimesScalar;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMatrixTimesScalar";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVectorTimesMatrix>
+	{
+		static constexpr Op op = Op::OpVectorTimesMatrix;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVectorTimesMatrix";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMatrixTimesVector>
+	{
+		static constexpr Op op = Op::OpMatrixTimesVector;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMatrixTimesVector";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMatrixTimesMatrix>
+	{
+		static constexpr Op op = Op::OpMatrixTimesMatrix;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMatrixTimesMatrix";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpOuterProduct>
+	{
+		static constexpr Op op = Op::OpOuterProduct;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpOuterProduct";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Matrix> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDot>
+	{
+		static constexpr Op op = Op::OpDot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIAddCarry>
+	{
+		static constexpr Op op = Op::OpIAddCarry;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIAddCarry";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpISubBorrow>
+	{
+		static constexpr Op op = Op::OpISubBorrow;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpISubBorrow";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUMulExtended>
+	{
+		static constexpr Op op = Op::OpUMulExtended;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUMulExtended";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSMulExtended>
+	{
+		static constexpr Op op = Op::OpSMulExtended;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSMulExtended";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAny>
+	{
+		static constexpr Op op = Op::OpAny;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAny";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAll>
+	{
+		static constexpr Op op = Op::OpAll;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAll";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsNan>
+	{
+		static constexpr Op op = Op::OpIsNan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsNan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsInf>
+	{
+		static constexpr Op op = Op::OpIsInf;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsInf";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsFinite>
+	{
+		static constexpr Op op = Op::OpIsFinite;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsFinite";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsNormal>
+	{
+		static constexpr Op op = Op::OpIsNormal;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsNormal";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSignBitSet>
+	{
+		static constexpr Op op = Op::OpSignBitSet;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSignBitSet";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLessOrGreater>
+	{
+		static constexpr Op op = Op::OpLessOrGreater;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLessOrGreater";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpOrdered>
+	{
+		static constexpr Op op = Op::OpOrdered;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpOrdered";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUnordered>
+	{
+		static constexpr Op op = Op::OpUnordered;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUnordered";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLogicalEqual>
+	{
+		static constexpr Op op = Op::OpLogicalEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLogicalEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLogicalNotEqual>
+	{
+		static constexpr Op op = Op::OpLogicalNotEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLogicalNotEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLogicalOr>
+	{
+		static constexpr Op op = Op::OpLogicalOr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLogicalOr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLogicalAnd>
+	{
+		static constexpr Op op = Op::OpLogicalAnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLogicalAnd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLogicalNot>
+	{
+		static constexpr Op op = Op::OpLogicalNot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLogicalNot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSelect>
+	{
+		static constexpr Op op = Op::OpSelect;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSelect";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIEqual>
+	{
+		static constexpr Op op = Op::OpIEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpINotEqual>
+	{
+		static constexpr Op op = Op::OpINotEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpINotEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUGreaterThan>
+	{
+		static constexpr Op op = Op::OpUGreaterThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUGreaterThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSGreaterThan>
+	{
+		static constexpr Op op = Op::OpSGreaterThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSGreaterThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUGreaterThanEqual>
+	{
+		static constexpr Op op = Op::OpUGreaterThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUGreaterThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSGreaterThanEqual>
+	{
+		static constexpr Op op = Op::OpSGreaterThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSGreaterThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpULessThan>
+	{
+		static constexpr Op op = Op::OpULessThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpULessThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSLessThan>
+	{
+		static constexpr Op op = Op::OpSLessThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSLessThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpULessThanEqual>
+	{
+		static constexpr Op op = Op::OpULessThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpULessThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSLessThanEqual>
+	{
+		static constexpr Op op = Op::OpSLessThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSLessThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdEqual>
+	{
+		static constexpr Op op = Op::OpFOrdEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordEqual>
+	{
+		static constexpr Op op = Op::OpFUnordEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdNotEqual>
+	{
+		static constexpr Op op = Op::OpFOrdNotEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdNotEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordNotEqual>
+	{
+		static constexpr Op op = Op::OpFUnordNotEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordNotEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdLessThan>
+	{
+		static constexpr Op op = Op::OpFOrdLessThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdLessThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordLessThan>
+	{
+		static constexpr Op op = Op::OpFUnordLessThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordLessThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdGreaterThan>
+	{
+		static constexpr Op op = Op::OpFOrdGreaterThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdGreaterThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordGreaterThan>
+	{
+		static constexpr Op op = Op::OpFUnordGreaterThan;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordGreaterThan";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdLessThanEqual>
+	{
+		static constexpr Op op = Op::OpFOrdLessThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdLessThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordLessThanEqual>
+	{
+		static constexpr Op op = Op::OpFUnordLessThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordLessThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFOrdGreaterThanEqual>
+	{
+		static constexpr Op op = Op::OpFOrdGreaterThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFOrdGreaterThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFUnordGreaterThanEqual>
+	{
+		static constexpr Op op = Op::OpFUnordGreaterThanEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFUnordGreaterThanEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::RelationalAndLogical;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpShiftRightLogical>
+	{
+		static constexpr Op op = Op::OpShiftRightLogical;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpShiftRightLogical";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpShiftRightArithmetic>
+	{
+		static constexpr Op op = Op::OpShiftRightArithmetic;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpShiftRightArithmetic";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpShiftLeftLogical>
+	{
+		static constexpr Op op = Op::OpShiftLeftLogical;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpShiftLeftLogical";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitwiseOr>
+	{
+		static constexpr Op op = Op::OpBitwiseOr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitwiseOr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitwiseXor>
+	{
+		static constexpr Op op = Op::OpBitwiseXor;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitwiseXor";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitwiseAnd>
+	{
+		static constexpr Op op = Op::OpBitwiseAnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitwiseAnd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpNot>
+	{
+		static constexpr Op op = Op::OpNot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpNot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitFieldInsert>
+	{
+		static constexpr Op op = Op::OpBitFieldInsert;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitFieldInsert";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader, Capability::BitInstructions> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitFieldSExtract>
+	{
+		static constexpr Op op = Op::OpBitFieldSExtract;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitFieldSExtract";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader, Capability::BitInstructions> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitFieldUExtract>
+	{
+		static constexpr Op op = Op::OpBitFieldUExtract;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitFieldUExtract";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader, Capability::BitInstructions> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitReverse>
+	{
+		static constexpr Op op = Op::OpBitReverse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitReverse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader, Capability::BitInstructions> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBitCount>
+	{
+		static constexpr Op op = Op::OpBitCount;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBitCount";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Bit;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdx>
+	{
+		static constexpr Op op = Op::OpDPdx;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdx";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdy>
+	{
+		static constexpr Op op = Op::OpDPdy;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdy";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFwidth>
+	{
+		static constexpr Op op = Op::OpFwidth;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFwidth";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdxFine>
+	{
+		static constexpr Op op = Op::OpDPdxFine;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdxFine";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdyFine>
+	{
+		static constexpr Op op = Op::OpDPdyFine;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdyFine";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFwidthFine>
+	{
+		static constexpr Op op = Op::OpFwidthFine;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFwidthFine";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdxCoarse>
+	{
+		static constexpr Op op = Op::OpDPdxCoarse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdxCoarse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDPdyCoarse>
+	{
+		static constexpr Op op = Op::OpDPdyCoarse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDPdyCoarse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFwidthCoarse>
+	{
+		static constexpr Op op = Op::OpFwidthCoarse;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFwidthCoarse";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Derivative;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DerivativeControl> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEmitVertex>
+	{
+		static constexpr Op op = Op::OpEmitVertex;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEmitVertex";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Primitive;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Geometry> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEndPrimitive>
+	{
+		static constexpr Op op = Op::OpEndPrimitive;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEndPrimitive";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Primitive;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Geometry> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEmitStreamVertex>
+	{
+		static constexpr Op op = Op::OpEmitStreamVertex;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEmitStreamVertex";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Primitive;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GeometryStreams> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEndStreamPrimitive>
+	{
+		static constexpr Op op = Op::OpEndStreamPrimitive;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEndStreamPrimitive";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Primitive;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GeometryStreams> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpControlBarrier>
+	{
+		static constexpr Op op = Op::OpControlBarrier;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpControlBarrier";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemoryBarrier>
+	{
+		static constexpr Op op = Op::OpMemoryBarrier;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemoryBarrier";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicLoad>
+	{
+		static constexpr Op op = Op::OpAtomicLoad;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicLoad";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicStore>
+	{
+		static constexpr Op op = Op::OpAtomicStore;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicStore";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicExchange>
+	{
+		static constexpr Op op = Op::OpAtomicExchange;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicExchange";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicCompareExchange>
+	{
+		static constexpr Op op = Op::OpAtomicCompareExchange;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicCompareExchange";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdMemorySemantics, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicCompareExchangeWeak>
+	{
+		static constexpr Op op = Op::OpAtomicCompareExchangeWeak;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicCompareExchangeWeak";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdMemorySemantics, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicIIncrement>
+	{
+		static constexpr Op op = Op::OpAtomicIIncrement;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicIIncrement";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicIDecrement>
+	{
+		static constexpr Op op = Op::OpAtomicIDecrement;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicIDecrement";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicIAdd>
+	{
+		static constexpr Op op = Op::OpAtomicIAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicIAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicISub>
+	{
+		static constexpr Op op = Op::OpAtomicISub;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicISub";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicSMin>
+	{
+		static constexpr Op op = Op::OpAtomicSMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicSMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicUMin>
+	{
+		static constexpr Op op = Op::OpAtomicUMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicUMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicSMax>
+	{
+		static constexpr Op op = Op::OpAtomicSMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicSMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicUMax>
+	{
+		static constexpr Op op = Op::OpAtomicUMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicUMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicAnd>
+	{
+		static constexpr Op op = Op::OpAtomicAnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicAnd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicOr>
+	{
+		static constexpr Op op = Op::OpAtomicOr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicOr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicXor>
+	{
+		static constexpr Op op = Op::OpAtomicXor;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicXor";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPhi>
+	{
+		static constexpr Op op = Op::OpPhi;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPhi";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::PairIdRefIdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLoopMerge>
+	{
+		static constexpr Op op = Op::OpLoopMerge;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLoopMerge";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LoopControl> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSelectionMerge>
+	{
+		static constexpr Op op = Op::OpSelectionMerge;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSelectionMerge";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::SelectionControl> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLabel>
+	{
+		static constexpr Op op = Op::OpLabel;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLabel";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBranch>
+	{
+		static constexpr Op op = Op::OpBranch;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBranch";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBranchConditional>
+	{
+		static constexpr Op op = Op::OpBranchConditional;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBranchConditional";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSwitch>
+	{
+		static constexpr Op op = Op::OpSwitch;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSwitch";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PairLiteralIntegerIdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpKill>
+	{
+		static constexpr Op op = Op::OpKill;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpKill";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReturn>
+	{
+		static constexpr Op op = Op::OpReturn;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReturn";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReturnValue>
+	{
+		static constexpr Op op = Op::OpReturnValue;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReturnValue";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUnreachable>
+	{
+		static constexpr Op op = Op::OpUnreachable;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUnreachable";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLifetimeStart>
+	{
+		static constexpr Op op = Op::OpLifetimeStart;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLifetimeStart";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLifetimeStop>
+	{
+		static constexpr Op op = Op::OpLifetimeStop;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLifetimeStop";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupAsyncCopy>
+	{
+		static constexpr Op op = Op::OpGroupAsyncCopy;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupAsyncCopy";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupWaitEvents>
+	{
+		static constexpr Op op = Op::OpGroupWaitEvents;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupWaitEvents";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupAll>
+	{
+		static constexpr Op op = Op::OpGroupAll;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupAll";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupAny>
+	{
+		static constexpr Op op = Op::OpGroupAny;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupAny";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupBroadcast>
+	{
+		static constexpr Op op = Op::OpGroupBroadcast;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupBroadcast";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupIAdd>
+	{
+		static constexpr Op op = Op::OpGroupIAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupIAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFAdd>
+	{
+		static constexpr Op op = Op::OpGroupFAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFMin>
+	{
+		static constexpr Op op = Op::OpGroupFMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupUMin>
+	{
+		static constexpr Op op = Op::OpGroupUMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupUMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupSMin>
+	{
+		static constexpr Op op = Op::OpGroupSMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupSMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFMax>
+	{
+		static constexpr Op op = Op::OpGroupFMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupUMax>
+	{
+		static constexpr Op op = Op::OpGroupUMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupUMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupSMax>
+	{
+		static constexpr Op op = Op::OpGroupSMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupSMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReadPipe>
+	{
+		static constexpr Op op = Op::OpReadPipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReadPipe";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpWritePipe>
+	{
+		static constexpr Op op = Op::OpWritePipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpWritePipe";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReservedReadPipe>
+	{
+		static constexpr Op op = Op::OpReservedReadPipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReservedReadPipe";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReservedWritePipe>
+	{
+		static constexpr Op op = Op::OpReservedWritePipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReservedWritePipe";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReserveReadPipePackets>
+	{
+		static constexpr Op op = Op::OpReserveReadPipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReserveReadPipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReserveWritePipePackets>
+	{
+		static constexpr Op op = Op::OpReserveWritePipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReserveWritePipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCommitReadPipe>
+	{
+		static constexpr Op op = Op::OpCommitReadPipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCommitReadPipe";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCommitWritePipe>
+	{
+		static constexpr Op op = Op::OpCommitWritePipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCommitWritePipe";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsValidReserveId>
+	{
+		static constexpr Op op = Op::OpIsValidReserveId;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsValidReserveId";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetNumPipePackets>
+	{
+		static constexpr Op op = Op::OpGetNumPipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetNumPipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetMaxPipePackets>
+	{
+		static constexpr Op op = Op::OpGetMaxPipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetMaxPipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupReserveReadPipePackets>
+	{
+		static constexpr Op op = Op::OpGroupReserveReadPipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupReserveReadPipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupReserveWritePipePackets>
+	{
+		static constexpr Op op = Op::OpGroupReserveWritePipePackets;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupReserveWritePipePackets";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupCommitReadPipe>
+	{
+		static constexpr Op op = Op::OpGroupCommitReadPipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupCommitReadPipe";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupCommitWritePipe>
+	{
+		static constexpr Op op = Op::OpGroupCommitWritePipe;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupCommitWritePipe";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Pipes> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEnqueueMarker>
+	{
+		static constexpr Op op = Op::OpEnqueueMarker;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEnqueueMarker";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEnqueueKernel>
+	{
+		static constexpr Op op = Op::OpEnqueueKernel;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEnqueueKernel";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelNDrangeSubGroupCount>
+	{
+		static constexpr Op op = Op::OpGetKernelNDrangeSubGroupCount;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelNDrangeSubGroupCount";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelNDrangeMaxSubGroupSize>
+	{
+		static constexpr Op op = Op::OpGetKernelNDrangeMaxSubGroupSize;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelNDrangeMaxSubGroupSize";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelWorkGroupSize>
+	{
+		static constexpr Op op = Op::OpGetKernelWorkGroupSize;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelWorkGroupSize";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelPreferredWorkGroupSizeMultiple>
+	{
+		static constexpr Op op = Op::OpGetKernelPreferredWorkGroupSizeMultiple;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelPreferredWorkGroupSizeMultiple";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRetainEvent>
+	{
+		static constexpr Op op = Op::OpRetainEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRetainEvent";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReleaseEvent>
+	{
+		static constexpr Op op = Op::OpReleaseEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReleaseEvent";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCreateUserEvent>
+	{
+		static constexpr Op op = Op::OpCreateUserEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCreateUserEvent";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsValidEvent>
+	{
+		static constexpr Op op = Op::OpIsValidEvent;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsValidEvent";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSetUserEventStatus>
+	{
+		static constexpr Op op = Op::OpSetUserEventStatus;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSetUserEventStatus";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCaptureEventProfilingInfo>
+	{
+		static constexpr Op op = Op::OpCaptureEventProfilingInfo;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCaptureEventProfilingInfo";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetDefaultQueue>
+	{
+		static constexpr Op op = Op::OpGetDefaultQueue;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetDefaultQueue";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBuildNDRange>
+	{
+		static constexpr Op op = Op::OpBuildNDRange;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBuildNDRange";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DeviceEnqueue> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleDrefImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleDrefImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleDrefImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleDrefExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleDrefExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleDrefExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleProjImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleProjImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleProjImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleProjExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleProjExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleProjExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleProjDrefImplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleProjDrefImplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleProjDrefImplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseSampleProjDrefExplicitLod>
+	{
+		static constexpr Op op = Op::OpImageSparseSampleProjDrefExplicitLod;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseSampleProjDrefExplicitLod";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseFetch>
+	{
+		static constexpr Op op = Op::OpImageSparseFetch;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseFetch";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseGather>
+	{
+		static constexpr Op op = Op::OpImageSparseGather;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseGather";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseDrefGather>
+	{
+		static constexpr Op op = Op::OpImageSparseDrefGather;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseDrefGather";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseTexelsResident>
+	{
+		static constexpr Op op = Op::OpImageSparseTexelsResident;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseTexelsResident";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpNoLine>
+	{
+		static constexpr Op op = Op::OpNoLine;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpNoLine";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicFlagTestAndSet>
+	{
+		static constexpr Op op = Op::OpAtomicFlagTestAndSet;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicFlagTestAndSet";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicFlagClear>
+	{
+		static constexpr Op op = Op::OpAtomicFlagClear;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicFlagClear";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Kernel> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSparseRead>
+	{
+		static constexpr Op op = Op::OpImageSparseRead;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSparseRead";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SparseResidency> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSizeOf>
+	{
+		static constexpr Op op = Op::OpSizeOf;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSizeOf";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypePipeStorage>
+	{
+		static constexpr Op op = Op::OpTypePipeStorage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypePipeStorage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::PipeStorage> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantPipeStorage>
+	{
+		static constexpr Op op = Op::OpConstantPipeStorage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantPipeStorage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::PipeStorage> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCreatePipeFromPipeStorage>
+	{
+		static constexpr Op op = Op::OpCreatePipeFromPipeStorage;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCreatePipeFromPipeStorage";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::PipeStorage> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelLocalSizeForSubgroupCount>
+	{
+		static constexpr Op op = Op::OpGetKernelLocalSizeForSubgroupCount;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelLocalSizeForSubgroupCount";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupDispatch> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGetKernelMaxNumSubgroups>
+	{
+		static constexpr Op op = Op::OpGetKernelMaxNumSubgroups;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGetKernelMaxNumSubgroups";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::DeviceSideEnqueue;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupDispatch> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeNamedBarrier>
+	{
+		static constexpr Op op = Op::OpTypeNamedBarrier;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeNamedBarrier";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::NamedBarrier> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpNamedBarrierInitialize>
+	{
+		static constexpr Op op = Op::OpNamedBarrierInitialize;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpNamedBarrierInitialize";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::NamedBarrier> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemoryNamedBarrier>
+	{
+		static constexpr Op op = Op::OpMemoryNamedBarrier;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemoryNamedBarrier";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability, Capability::NamedBarrier> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpModuleProcessed>
+	{
+		static constexpr Op op = Op::OpModuleProcessed;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpModuleProcessed";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Debug;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExecutionModeId>
+	{
+		static constexpr Op op = Op::OpExecutionModeId;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExecutionModeId";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ModeSetting;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::ExecutionMode> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDecorateId>
+	{
+		static constexpr Op op = Op::OpDecorateId;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDecorateId";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::Decoration> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformElect>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformElect;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformElect";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniform> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformAll>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformAll;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformAll";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformVote> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformAny>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformAny;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformAny";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformVote> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformAllEqual>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformAllEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformAllEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformVote> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBroadcast>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBroadcast;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBroadcast";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBroadcastFirst>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBroadcastFirst;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBroadcastFirst";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBallot>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBallot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBallot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformInverseBallot>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformInverseBallot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformInverseBallot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBallotBitExtract>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBallotBitExtract;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBallotBitExtract";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBallotBitCount>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBallotBitCount;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBallotBitCount";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBallotFindLSB>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBallotFindLSB;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBallotFindLSB";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBallotFindMSB>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBallotFindMSB;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBallotFindMSB";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformBallot> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformShuffle>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformShuffle;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformShuffle";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformShuffle> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformShuffleXor>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformShuffleXor;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformShuffleXor";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformShuffle> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformShuffleUp>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformShuffleUp;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformShuffleUp";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformShuffleRelative> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformShuffleDown>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformShuffleDown;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformShuffleDown";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformShuffleRelative> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformIAdd>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformIAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformIAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformFAdd>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformFAdd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformFAdd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformIMul>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformIMul;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformIMul";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformFMul>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformFMul;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformFMul";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformSMin>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformSMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformSMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformUMin>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformUMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformUMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformFMin>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformFMin;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformFMin";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformSMax>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformSMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformSMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformUMax>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformUMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformUMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformFMax>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformFMax;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformFMax";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBitwiseAnd>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBitwiseAnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBitwiseAnd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBitwiseOr>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBitwiseOr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBitwiseOr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformBitwiseXor>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformBitwiseXor;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformBitwiseXor";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformLogicalAnd>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformLogicalAnd;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformLogicalAnd";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformLogicalOr>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformLogicalOr;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformLogicalOr";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformLogicalXor>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformLogicalXor;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformLogicalXor";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformArithmetic, Capability::GroupNonUniformClustered, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformQuadBroadcast>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformQuadBroadcast;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformQuadBroadcast";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformQuad> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformQuadSwap>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformQuadSwap;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformQuadSwap";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformQuad> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCopyLogical>
+	{
+		static constexpr Op op = Op::OpCopyLogical;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCopyLogical";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Composite;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrEqual>
+	{
+		static constexpr Op op = Op::OpPtrEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrNotEqual>
+	{
+		static constexpr Op op = Op::OpPtrNotEqual;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrNotEqual";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrDiff>
+	{
+		static constexpr Op op = Op::OpPtrDiff;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrDiff";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Addresses, Capability::VariablePointers, Capability::VariablePointersStorageBuffer> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpColorAttachmentReadEXT>
+	{
+		static constexpr Op op = Op::OpColorAttachmentReadEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpColorAttachmentReadEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TileImageColorReadAccessEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDepthAttachmentReadEXT>
+	{
+		static constexpr Op op = Op::OpDepthAttachmentReadEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDepthAttachmentReadEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TileImageDepthReadAccessEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpStencilAttachmentReadEXT>
+	{
+		static constexpr Op op = Op::OpStencilAttachmentReadEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpStencilAttachmentReadEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TileImageStencilReadAccessEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTerminateInvocation>
+	{
+		static constexpr Op op = Op::OpTerminateInvocation;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTerminateInvocation";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Shader> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupBallotKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupBallotKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupBallotKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupBallotKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupFirstInvocationKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupFirstInvocationKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupFirstInvocationKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupBallotKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAllKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupAllKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAllKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupVoteKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAnyKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupAnyKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAnyKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupVoteKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAllEqualKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupAllEqualKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAllEqualKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupVoteKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformRotateKHR>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformRotateKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformRotateKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformRotateKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupReadInvocationKHR>
+	{
+		static constexpr Op op = Op::OpSubgroupReadInvocationKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupReadInvocationKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupBallotKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTraceRayKHR>
+	{
+		static constexpr Op op = Op::OpTraceRayKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTraceRayKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExecuteCallableKHR>
+	{
+		static constexpr Op op = Op::OpExecuteCallableKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExecuteCallableKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToAccelerationStructureKHR>
+	{
+		static constexpr Op op = Op::OpConvertUToAccelerationStructureKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToAccelerationStructureKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingKHR, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIgnoreIntersectionKHR>
+	{
+		static constexpr Op op = Op::OpIgnoreIntersectionKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIgnoreIntersectionKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTerminateRayKHR>
+	{
+		static constexpr Op op = Op::OpTerminateRayKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTerminateRayKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSDot>
+	{
+		static constexpr Op op = Op::OpSDot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSDot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUDot>
+	{
+		static constexpr Op op = Op::OpUDot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUDot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSUDot>
+	{
+		static constexpr Op op = Op::OpSUDot;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSUDot";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSDotAccSat>
+	{
+		static constexpr Op op = Op::OpSDotAccSat;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSDotAccSat";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUDotAccSat>
+	{
+		static constexpr Op op = Op::OpUDotAccSat;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUDotAccSat";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSUDotAccSat>
+	{
+		static constexpr Op op = Op::OpSUDotAccSat;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSUDotAccSat";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::PackedVectorFormat> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DotProduct> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeCooperativeMatrixKHR>
+	{
+		static constexpr Op op = Op::OpTypeCooperativeMatrixKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeCooperativeMatrixKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixLoadKHR>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixLoadKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixLoadKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixStoreKHR>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixStoreKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixStoreKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Memory;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixMulAddKHR>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixMulAddKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixMulAddKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Arithmetic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::CooperativeMatrixOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixLengthKHR>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixLengthKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixLengthKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeRayQueryKHR>
+	{
+		static constexpr Op op = Op::OpTypeRayQueryKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeRayQueryKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryInitializeKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryInitializeKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryInitializeKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryTerminateKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryTerminateKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryTerminateKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGenerateIntersectionKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGenerateIntersectionKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGenerateIntersectionKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryConfirmIntersectionKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryConfirmIntersectionKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryConfirmIntersectionKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryProceedKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryProceedKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryProceedKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionTypeKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionTypeKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionTypeKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleWeightedQCOM>
+	{
+		static constexpr Op op = Op::OpImageSampleWeightedQCOM;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleWeightedQCOM";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TextureSampleWeightedQCOM> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageBoxFilterQCOM>
+	{
+		static constexpr Op op = Op::OpImageBoxFilterQCOM;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageBoxFilterQCOM";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TextureBoxFilterQCOM> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageBlockMatchSSDQCOM>
+	{
+		static constexpr Op op = Op::OpImageBlockMatchSSDQCOM;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageBlockMatchSSDQCOM";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TextureBlockMatchQCOM> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageBlockMatchSADQCOM>
+	{
+		static constexpr Op op = Op::OpImageBlockMatchSADQCOM;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageBlockMatchSADQCOM";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::TextureBlockMatchQCOM> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupIAddNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupIAddNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupIAddNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFAddNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupFAddNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFAddNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFMinNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupFMinNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFMinNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupUMinNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupUMinNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupUMinNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupSMinNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupSMinNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupSMinNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFMaxNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupFMaxNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFMaxNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupUMaxNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupUMaxNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupUMaxNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupSMaxNonUniformAMD>
+	{
+		static constexpr Op op = Op::OpGroupSMaxNonUniformAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupSMaxNonUniformAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::Groups> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFragmentMaskFetchAMD>
+	{
+		static constexpr Op op = Op::OpFragmentMaskFetchAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFragmentMaskFetchAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FragmentMaskAMD> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFragmentFetchAMD>
+	{
+		static constexpr Op op = Op::OpFragmentFetchAMD;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFragmentFetchAMD";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FragmentMaskAMD> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReadClockKHR>
+	{
+		static constexpr Op op = Op::OpReadClockKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReadClockKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderClockKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFinalizeNodePayloadsAMDX>
+	{
+		static constexpr Op op = Op::OpFinalizeNodePayloadsAMDX;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFinalizeNodePayloadsAMDX";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderEnqueueAMDX> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFinishWritingNodePayloadAMDX>
+	{
+		static constexpr Op op = Op::OpFinishWritingNodePayloadAMDX;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFinishWritingNodePayloadAMDX";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderEnqueueAMDX> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpInitializeNodePayloadsAMDX>
+	{
+		static constexpr Op op = Op::OpInitializeNodePayloadsAMDX;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpInitializeNodePayloadsAMDX";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderEnqueueAMDX> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordHitMotionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordHitMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordHitMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordHitWithIndexMotionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordHitWithIndexMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordHitWithIndexMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordMissMotionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordMissMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordMissMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetWorldToObjectNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetWorldToObjectNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetWorldToObjectNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetObjectToWorldNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetObjectToWorldNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetObjectToWorldNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetObjectRayDirectionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetObjectRayDirectionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetObjectRayDirectionNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetObjectRayOriginNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetObjectRayOriginNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetObjectRayOriginNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectTraceRayMotionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectTraceRayMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectTraceRayMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetShaderRecordBufferHandleNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetShaderRecordBufferHandleNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetShaderRecordBufferHandleNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetShaderBindingTableRecordIndexNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetShaderBindingTableRecordIndexNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetShaderBindingTableRecordIndexNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordEmptyNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordEmptyNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordEmptyNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectTraceRayNV>
+	{
+		static constexpr Op op = Op::OpHitObjectTraceRayNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectTraceRayNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordHitNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordHitNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordHitNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordHitWithIndexNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordHitWithIndexNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordHitWithIndexNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectRecordMissNV>
+	{
+		static constexpr Op op = Op::OpHitObjectRecordMissNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectRecordMissNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectExecuteShaderNV>
+	{
+		static constexpr Op op = Op::OpHitObjectExecuteShaderNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectExecuteShaderNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetCurrentTimeNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetCurrentTimeNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetCurrentTimeNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetAttributesNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetAttributesNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetAttributesNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetHitKindNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetHitKindNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetHitKindNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetPrimitiveIndexNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetPrimitiveIndexNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetPrimitiveIndexNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetGeometryIndexNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetGeometryIndexNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetGeometryIndexNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetInstanceIdNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetInstanceIdNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetInstanceIdNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetInstanceCustomIndexNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetInstanceCustomIndexNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetInstanceCustomIndexNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetWorldRayDirectionNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetWorldRayDirectionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetWorldRayDirectionNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetWorldRayOriginNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetWorldRayOriginNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetWorldRayOriginNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetRayTMaxNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetRayTMaxNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetRayTMaxNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectGetRayTMinNV>
+	{
+		static constexpr Op op = Op::OpHitObjectGetRayTMinNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectGetRayTMinNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectIsEmptyNV>
+	{
+		static constexpr Op op = Op::OpHitObjectIsEmptyNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectIsEmptyNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectIsHitNV>
+	{
+		static constexpr Op op = Op::OpHitObjectIsHitNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectIsHitNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpHitObjectIsMissNV>
+	{
+		static constexpr Op op = Op::OpHitObjectIsMissNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpHitObjectIsMissNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReorderThreadWithHitObjectNV>
+	{
+		static constexpr Op op = Op::OpReorderThreadWithHitObjectNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReorderThreadWithHitObjectNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReorderThreadWithHintNV>
+	{
+		static constexpr Op op = Op::OpReorderThreadWithHintNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReorderThreadWithHintNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeHitObjectNV>
+	{
+		static constexpr Op op = Op::OpTypeHitObjectNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeHitObjectNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ShaderInvocationReorderNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpImageSampleFootprintNV>
+	{
+		static constexpr Op op = Op::OpImageSampleFootprintNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpImageSampleFootprintNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Image;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::ImageOperands> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ImageFootprintNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEmitMeshTasksEXT>
+	{
+		static constexpr Op op = Op::OpEmitMeshTasksEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEmitMeshTasksEXT";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MeshShadingEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSetMeshOutputsEXT>
+	{
+		static constexpr Op op = Op::OpSetMeshOutputsEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSetMeshOutputsEXT";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MeshShadingEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupNonUniformPartitionNV>
+	{
+		static constexpr Op op = Op::OpGroupNonUniformPartitionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupNonUniformPartitionNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::NonUniform;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupNonUniformPartitionedNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpWritePackedPrimitiveIndices4x8NV>
+	{
+		static constexpr Op op = Op::OpWritePackedPrimitiveIndices4x8NV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpWritePackedPrimitiveIndices4x8NV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MeshShadingNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReportIntersectionNV>
+	{
+		static constexpr Op op = Op::OpReportIntersectionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReportIntersectionNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV, Capability::RayTracingKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIgnoreIntersectionNV>
+	{
+		static constexpr Op op = Op::OpIgnoreIntersectionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIgnoreIntersectionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTerminateRayNV>
+	{
+		static constexpr Op op = Op::OpTerminateRayNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTerminateRayNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTraceNV>
+	{
+		static constexpr Op op = Op::OpTraceNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTraceNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTraceMotionNV>
+	{
+		static constexpr Op op = Op::OpTraceMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTraceMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTraceRayMotionNV>
+	{
+		static constexpr Op op = Op::OpTraceRayMotionNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTraceRayMotionNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingMotionBlurNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionTriangleVertexPositionsKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryPositionFetchKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAccelerationStructureNV>
+	{
+		static constexpr Op op = Op::OpTypeAccelerationStructureNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAccelerationStructureNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV, Capability::RayTracingKHR, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExecuteCallableNV>
+	{
+		static constexpr Op op = Op::OpExecuteCallableNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExecuteCallableNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayTracingNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeCooperativeMatrixNV>
+	{
+		static constexpr Op op = Op::OpTypeCooperativeMatrixNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeCooperativeMatrixNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixLoadNV>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixLoadNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixLoadNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixStoreNV>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixStoreNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixStoreNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::MemoryAccess> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixMulAddNV>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixMulAddNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixMulAddNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCooperativeMatrixLengthNV>
+	{
+		static constexpr Op op = Op::OpCooperativeMatrixLengthNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCooperativeMatrixLengthNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::CooperativeMatrixNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpBeginInvocationInterlockEXT>
+	{
+		static constexpr Op op = Op::OpBeginInvocationInterlockEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpBeginInvocationInterlockEXT";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FragmentShaderSampleInterlockEXT, Capability::FragmentShaderPixelInterlockEXT, Capability::FragmentShaderShadingRateInterlockEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpEndInvocationInterlockEXT>
+	{
+		static constexpr Op op = Op::OpEndInvocationInterlockEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpEndInvocationInterlockEXT";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FragmentShaderSampleInterlockEXT, Capability::FragmentShaderPixelInterlockEXT, Capability::FragmentShaderShadingRateInterlockEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDemoteToHelperInvocation>
+	{
+		static constexpr Op op = Op::OpDemoteToHelperInvocation;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDemoteToHelperInvocation";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ControlFlow;
+		static constexpr EnumList<OperandKind> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DemoteToHelperInvocation> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIsHelperInvocationEXT>
+	{
+		static constexpr Op op = Op::OpIsHelperInvocationEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIsHelperInvocationEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::DemoteToHelperInvocationEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToImageNV>
+	{
+		static constexpr Op op = Op::OpConvertUToImageNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToImageNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToSamplerNV>
+	{
+		static constexpr Op op = Op::OpConvertUToSamplerNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToSamplerNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertImageToUNV>
+	{
+		static constexpr Op op = Op::OpConvertImageToUNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertImageToUNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertSamplerToUNV>
+	{
+		static constexpr Op op = Op::OpConvertSamplerToUNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertSamplerToUNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertUToSampledImageNV>
+	{
+		static constexpr Op op = Op::OpConvertUToSampledImageNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertUToSampledImageNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertSampledImageToUNV>
+	{
+		static constexpr Op op = Op::OpConvertSampledImageToUNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertSampledImageToUNV";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSamplerImageAddressingModeNV>
+	{
+		static constexpr Op op = Op::OpSamplerImageAddressingModeNV;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSamplerImageAddressingModeNV";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BindlessTextureNV> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupShuffleINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupShuffleINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupShuffleINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupShuffleINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupShuffleDownINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupShuffleDownINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupShuffleDownINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupShuffleINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupShuffleUpINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupShuffleUpINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupShuffleUpINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupShuffleINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupShuffleXorINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupShuffleXorINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupShuffleXorINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupShuffleINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupBlockReadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupBlockReadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupBlockReadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupBufferBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupBlockWriteINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupBlockWriteINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupBlockWriteINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupBufferBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupImageBlockReadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupImageBlockReadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupImageBlockReadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupImageBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupImageBlockWriteINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupImageBlockWriteINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupImageBlockWriteINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupImageBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupImageMediaBlockReadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupImageMediaBlockReadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupImageMediaBlockReadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupImageMediaBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupImageMediaBlockWriteINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupImageMediaBlockWriteINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupImageMediaBlockWriteINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupImageMediaBlockIOINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUCountLeadingZerosINTEL>
+	{
+		static constexpr Op op = Op::OpUCountLeadingZerosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUCountLeadingZerosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUCountTrailingZerosINTEL>
+	{
+		static constexpr Op op = Op::OpUCountTrailingZerosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUCountTrailingZerosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAbsISubINTEL>
+	{
+		static constexpr Op op = Op::OpAbsISubINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAbsISubINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAbsUSubINTEL>
+	{
+		static constexpr Op op = Op::OpAbsUSubINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAbsUSubINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIAddSatINTEL>
+	{
+		static constexpr Op op = Op::OpIAddSatINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIAddSatINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUAddSatINTEL>
+	{
+		static constexpr Op op = Op::OpUAddSatINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUAddSatINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIAverageINTEL>
+	{
+		static constexpr Op op = Op::OpIAverageINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIAverageINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUAverageINTEL>
+	{
+		static constexpr Op op = Op::OpUAverageINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUAverageINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIAverageRoundedINTEL>
+	{
+		static constexpr Op op = Op::OpIAverageRoundedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIAverageRoundedINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUAverageRoundedINTEL>
+	{
+		static constexpr Op op = Op::OpUAverageRoundedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUAverageRoundedINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpISubSatINTEL>
+	{
+		static constexpr Op op = Op::OpISubSatINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpISubSatINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUSubSatINTEL>
+	{
+		static constexpr Op op = Op::OpUSubSatINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUSubSatINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpIMul32x16INTEL>
+	{
+		static constexpr Op op = Op::OpIMul32x16INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpIMul32x16INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpUMul32x16INTEL>
+	{
+		static constexpr Op op = Op::OpUMul32x16INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpUMul32x16INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::IntegerFunctions2INTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantFunctionPointerINTEL>
+	{
+		static constexpr Op op = Op::OpConstantFunctionPointerINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantFunctionPointerINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FunctionPointersINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFunctionPointerCallINTEL>
+	{
+		static constexpr Op op = Op::OpFunctionPointerCallINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFunctionPointerCallINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FunctionPointersINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAsmTargetINTEL>
+	{
+		static constexpr Op op = Op::OpAsmTargetINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAsmTargetINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AsmINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAsmINTEL>
+	{
+		static constexpr Op op = Op::OpAsmINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAsmINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralString, OperandKind::LiteralString> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AsmINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAsmCallINTEL>
+	{
+		static constexpr Op op = Op::OpAsmCallINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAsmCallINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AsmINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicFMinEXT>
+	{
+		static constexpr Op op = Op::OpAtomicFMinEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicFMinEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AtomicFloat16MinMaxEXT, Capability::AtomicFloat32MinMaxEXT, Capability::AtomicFloat64MinMaxEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicFMaxEXT>
+	{
+		static constexpr Op op = Op::OpAtomicFMaxEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicFMaxEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AtomicFloat16MinMaxEXT, Capability::AtomicFloat32MinMaxEXT, Capability::AtomicFloat64MinMaxEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAssumeTrueKHR>
+	{
+		static constexpr Op op = Op::OpAssumeTrueKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAssumeTrueKHR";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ExpectAssumeKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpExpectKHR>
+	{
+		static constexpr Op op = Op::OpExpectKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpExpectKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Miscellaneous;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ExpectAssumeKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpDecorateString>
+	{
+		static constexpr Op op = Op::OpDecorateString;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpDecorateString";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::Decoration> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpMemberDecorateString>
+	{
+		static constexpr Op op = Op::OpMemberDecorateString;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpMemberDecorateString";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Annotation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::Decoration> operandKinds{};
+		static constexpr EnumList<Capability> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVmeImageINTEL>
+	{
+		static constexpr Op op = Op::OpVmeImageINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVmeImageINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeVmeImageINTEL>
+	{
+		static constexpr Op op = Op::OpTypeVmeImageINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeVmeImageINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcRefPayloadINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcRefPayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcRefPayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcSicPayloadINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcSicPayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcSicPayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcMcePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcMcePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcMcePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcMceResultINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcMceResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcMceResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImeResultINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImeResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImeResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImeResultSingleReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImeResultDualReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImeSingleReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImeSingleReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImeSingleReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcImeDualReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcImeDualReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcImeDualReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcRefResultINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcRefResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcRefResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeAvcSicResultINTEL>
+	{
+		static constexpr Op op = Op::OpTypeAvcSicResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeAvcSicResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetInterShapePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetInterDirectionPenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationChromaINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetAcOnlyHaarINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToImePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToImePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToImePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToImeResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToImeResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToImeResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToRefPayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToRefPayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToRefPayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToRefResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToRefResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToRefResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToSicPayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToSicPayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToSicPayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceConvertToSicResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceConvertToSicResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceConvertToSicResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetMotionVectorsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetMotionVectorsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetMotionVectorsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterDistortionsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterDistortionsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterDistortionsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetBestInterDistortionsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterMajorShapeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterMajorShapeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterMajorShapeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterMinorShapeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterMinorShapeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterMinorShapeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterDirectionsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterDirectionsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterDirectionsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterMotionVectorCountINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterReferenceIdsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeInitializeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeInitializeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeInitializeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetSingleReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetSingleReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetSingleReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetDualReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetDualReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetDualReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeRefWindowSizeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeRefWindowSizeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeRefWindowSizeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeAdjustRefOffsetINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeAdjustRefOffsetINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeAdjustRefOffsetINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeConvertToMcePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeConvertToMcePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeConvertToMcePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetMaxMotionVectorCountINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeSetWeightedSadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeSetWeightedSadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeSetWeightedSadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithDualReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeConvertToMceResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeConvertToMceResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeConvertToMceResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetSingleReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetDualReferenceStreaminINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeStripDualReferenceStreamoutINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetBorderReachedINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetBorderReachedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetBorderReachedINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcFmeInitializeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcFmeInitializeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcFmeInitializeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcBmeInitializeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcBmeInitializeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcBmeInitializeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefConvertToMcePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefConvertToMcePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefConvertToMcePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefSetBidirectionalMixDisableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefSetBilinearFilterEnableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefEvaluateWithDualReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcRefConvertToMceResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcRefConvertToMceResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcRefConvertToMceResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicInitializeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicInitializeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicInitializeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicConfigureSkcINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicConfigureSkcINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicConfigureSkcINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicConfigureIpeLumaINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicConfigureIpeLumaINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicConfigureIpeLumaINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicConfigureIpeLumaChromaINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationChromaINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetMotionVectorMaskINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicConvertToMcePayloadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicConvertToMcePayloadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicConvertToMcePayloadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationChromaINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetBilinearFilterEnableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicEvaluateIpeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicEvaluateIpeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicEvaluateIpeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicEvaluateWithDualReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicConvertToMceResultINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicConvertToMceResultINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicConvertToMceResultINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetIpeLumaShapeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetPackedIpeLumaModesINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetIpeChromaModeINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetIpeChromaModeINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetIpeChromaModeINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationChromaINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL, Capability::SubgroupAvcMotionEstimationIntraINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSubgroupAvcSicGetInterRawSadsINTEL>
+	{
+		static constexpr Op op = Op::OpSubgroupAvcSicGetInterRawSadsINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSubgroupAvcSicGetInterRawSadsINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SubgroupAvcMotionEstimationINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpVariableLengthArrayINTEL>
+	{
+		static constexpr Op op = Op::OpVariableLengthArrayINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpVariableLengthArrayINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::VariableLengthArrayINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSaveMemoryINTEL>
+	{
+		static constexpr Op op = Op::OpSaveMemoryINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSaveMemoryINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult> operandKinds{};
+		static constexpr EnumList<Capability, Capability::VariableLengthArrayINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRestoreMemoryINTEL>
+	{
+		static constexpr Op op = Op::OpRestoreMemoryINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRestoreMemoryINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::VariableLengthArrayINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSinCosPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSinCosPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSinCosPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCastINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCastINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCastINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCastFromIntINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCastFromIntINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCastFromIntINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCastToIntINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCastToIntINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCastToIntINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatAddINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatAddINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatAddINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSubINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSubINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSubINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatMulINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatMulINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatMulINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatDivINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatDivINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatDivINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatGTINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatGTINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatGTINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatGEINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatGEINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatGEINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLTINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLTINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLTINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLEINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLEINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLEINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatEQINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatEQINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatEQINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatRecipINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatRecipINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatRecipINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatRSqrtINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatRSqrtINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatRSqrtINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCbrtINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCbrtINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCbrtINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatHypotINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatHypotINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatHypotINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSqrtINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSqrtINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSqrtINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLogINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLogINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLogINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLog2INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLog2INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLog2INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLog10INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLog10INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLog10INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatLog1pINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatLog1pINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatLog1pINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatExpINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatExpINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatExpINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatExp2INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatExp2INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatExp2INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatExp10INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatExp10INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatExp10INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatExpm1INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatExpm1INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatExpm1INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSinINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSinINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSinINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCosINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSinCosINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSinCosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSinCosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatSinPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatSinPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatSinPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatCosPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatCosPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatCosPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatASinINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatASinINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatASinINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatASinPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatASinPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatASinPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatACosINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatACosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatACosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatACosPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatACosPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatACosPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatATanINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatATanINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatATanINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatATanPiINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatATanPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatATanPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatATan2INTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatATan2INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatATan2INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatPowINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatPowINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatPowINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatPowRINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatPowRINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatPowRINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpArbitraryFloatPowNINTEL>
+	{
+		static constexpr Op op = Op::OpArbitraryFloatPowNINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpArbitraryFloatPowNINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFloatingPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpLoopControlINTEL>
+	{
+		static constexpr Op op = Op::OpLoopControlINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpLoopControlINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::UnstructuredLoopControlsINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAliasDomainDeclINTEL>
+	{
+		static constexpr Op op = Op::OpAliasDomainDeclINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAliasDomainDeclINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MemoryAccessAliasingINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAliasScopeDeclINTEL>
+	{
+		static constexpr Op op = Op::OpAliasScopeDeclINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAliasScopeDeclINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MemoryAccessAliasingINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAliasScopeListDeclINTEL>
+	{
+		static constexpr Op op = Op::OpAliasScopeListDeclINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAliasScopeListDeclINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::MemoryAccessAliasingINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedSqrtINTEL>
+	{
+		static constexpr Op op = Op::OpFixedSqrtINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedSqrtINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedRecipINTEL>
+	{
+		static constexpr Op op = Op::OpFixedRecipINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedRecipINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedRsqrtINTEL>
+	{
+		static constexpr Op op = Op::OpFixedRsqrtINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedRsqrtINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedSinINTEL>
+	{
+		static constexpr Op op = Op::OpFixedSinINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedSinINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedCosINTEL>
+	{
+		static constexpr Op op = Op::OpFixedCosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedCosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedSinCosINTEL>
+	{
+		static constexpr Op op = Op::OpFixedSinCosINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedSinCosINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedSinPiINTEL>
+	{
+		static constexpr Op op = Op::OpFixedSinPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedSinPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedCosPiINTEL>
+	{
+		static constexpr Op op = Op::OpFixedCosPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedCosPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedSinCosPiINTEL>
+	{
+		static constexpr Op op = Op::OpFixedSinCosPiINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedSinCosPiINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedLogINTEL>
+	{
+		static constexpr Op op = Op::OpFixedLogINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedLogINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFixedExpINTEL>
+	{
+		static constexpr Op op = Op::OpFixedExpINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFixedExpINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger, OperandKind::LiteralInteger> operandKinds{};
+		static constexpr EnumList<Capability, Capability::ArbitraryPrecisionFixedPointINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpPtrCastToCrossWorkgroupINTEL>
+	{
+		static constexpr Op op = Op::OpPtrCastToCrossWorkgroupINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpPtrCastToCrossWorkgroupINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::USMStorageClassesINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpCrossWorkgroupCastToPtrINTEL>
+	{
+		static constexpr Op op = Op::OpCrossWorkgroupCastToPtrINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpCrossWorkgroupCastToPtrINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::USMStorageClassesINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpReadPipeBlockingINTEL>
+	{
+		static constexpr Op op = Op::OpReadPipeBlockingINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpReadPipeBlockingINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BlockingPipesINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpWritePipeBlockingINTEL>
+	{
+		static constexpr Op op = Op::OpWritePipeBlockingINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpWritePipeBlockingINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Pipe;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BlockingPipesINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpFPGARegINTEL>
+	{
+		static constexpr Op op = Op::OpFPGARegINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpFPGARegINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::FPGARegINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetRayTMinKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetRayTMinKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetRayTMinKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetRayFlagsKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetRayFlagsKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetRayFlagsKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionTKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionTKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionTKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionInstanceCustomIndexKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionInstanceIdKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionInstanceIdKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionInstanceIdKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionGeometryIndexKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionGeometryIndexKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionGeometryIndexKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionPrimitiveIndexKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionPrimitiveIndexKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionPrimitiveIndexKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionBarycentricsKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionBarycentricsKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionBarycentricsKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionFrontFaceKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionFrontFaceKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionFrontFaceKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionCandidateAABBOpaqueKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionObjectRayDirectionKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionObjectRayDirectionKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionObjectRayDirectionKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionObjectRayOriginKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionObjectRayOriginKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionObjectRayOriginKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetWorldRayDirectionKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetWorldRayDirectionKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetWorldRayDirectionKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetWorldRayOriginKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetWorldRayOriginKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetWorldRayOriginKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionObjectToWorldKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionObjectToWorldKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionObjectToWorldKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpRayQueryGetIntersectionWorldToObjectKHR>
+	{
+		static constexpr Op op = Op::OpRayQueryGetIntersectionWorldToObjectKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpRayQueryGetIntersectionWorldToObjectKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::None;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::RayQueryKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpAtomicFAddEXT>
+	{
+		static constexpr Op op = Op::OpAtomicFAddEXT;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpAtomicFAddEXT";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Atomic;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef, OperandKind::IdScope, OperandKind::IdMemorySemantics, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::AtomicFloat16AddEXT, Capability::AtomicFloat32AddEXT, Capability::AtomicFloat64AddEXT> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeBufferSurfaceINTEL>
+	{
+		static constexpr Op op = Op::OpTypeBufferSurfaceINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeBufferSurfaceINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdResult, OperandKind::AccessQualifier> operandKinds{};
+		static constexpr EnumList<Capability, Capability::VectorComputeINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpTypeStructContinuedINTEL>
+	{
+		static constexpr Op op = Op::OpTypeStructContinuedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpTypeStructContinuedINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::TypeDeclaration;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::LongConstantCompositeINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConstantCompositeContinuedINTEL>
+	{
+		static constexpr Op op = Op::OpConstantCompositeContinuedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConstantCompositeContinuedINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::LongConstantCompositeINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpSpecConstantCompositeContinuedINTEL>
+	{
+		static constexpr Op op = Op::OpSpecConstantCompositeContinuedINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpSpecConstantCompositeContinuedINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::ConstantCreation;
+		static constexpr EnumList<OperandKind, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::LongConstantCompositeINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertFToBF16INTEL>
+	{
+		static constexpr Op op = Op::OpConvertFToBF16INTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertFToBF16INTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BFloat16ConversionINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpConvertBF16ToFINTEL>
+	{
+		static constexpr Op op = Op::OpConvertBF16ToFINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpConvertBF16ToFINTEL";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Conversion;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::BFloat16ConversionINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpControlBarrierArriveINTEL>
+	{
+		static constexpr Op op = Op::OpControlBarrierArriveINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpControlBarrierArriveINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SplitBarrierINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpControlBarrierWaitINTEL>
+	{
+		static constexpr Op op = Op::OpControlBarrierWaitINTEL;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpControlBarrierWaitINTEL";
+		static constexpr bool hasResult = false;
+		static constexpr bool hasResultType = false;
+		static constexpr InstructionClass instructionClass = InstructionClass::Barrier;
+		static constexpr EnumList<OperandKind, OperandKind::IdScope, OperandKind::IdScope, OperandKind::IdMemorySemantics> operandKinds{};
+		static constexpr EnumList<Capability, Capability::SplitBarrierINTEL> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupIMulKHR>
+	{
+		static constexpr Op op = Op::OpGroupIMulKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupIMulKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupFMulKHR>
+	{
+		static constexpr Op op = Op::OpGroupFMulKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupFMulKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupBitwiseAndKHR>
+	{
+		static constexpr Op op = Op::OpGroupBitwiseAndKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupBitwiseAndKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupBitwiseOrKHR>
+	{
+		static constexpr Op op = Op::OpGroupBitwiseOrKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupBitwiseOrKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupBitwiseXorKHR>
+	{
+		static constexpr Op op = Op::OpGroupBitwiseXorKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupBitwiseXorKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupLogicalAndKHR>
+	{
+		static constexpr Op op = Op::OpGroupLogicalAndKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupLogicalAndKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupLogicalOrKHR>
+	{
+		static constexpr Op op = Op::OpGroupLogicalOrKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupLogicalOrKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	template <>
+	struct OpTraits<Op::OpGroupLogicalXorKHR>
+	{
+		static constexpr Op op = Op::OpGroupLogicalXorKHR;
+		static constexpr bool valid = true;
+		static constexpr const char* name = "OpGroupLogicalXorKHR";
+		static constexpr bool hasResult = true;
+		static constexpr bool hasResultType = true;
+		static constexpr InstructionClass instructionClass = InstructionClass::Group;
+		static constexpr EnumList<OperandKind, OperandKind::IdResultType, OperandKind::IdResult, OperandKind::IdScope, OperandKind::GroupOperation, OperandKind::IdRef> operandKinds{};
+		static constexpr EnumList<Capability, Capability::GroupUniformArithmeticKHR> capabilities{}; // any of them enables the instruction
+	};
+	// calls func(OpTraits<opcode>{}), every call has to return the same type
+	template <class Func>
+	constexpr decltype(auto) Dispatch(Op opcode, Func&& func)
+	{
+		switch (opcode)
+		{
+		case Op::OpNop: return func(OpTraits<Op::OpNop>{});
+		case Op::OpUndef: return func(OpTraits<Op::OpUndef>{});
+		case Op::OpSourceContinued: return func(OpTraits<Op::OpSourceContinued>{});
+		case Op::OpSource: return func(OpTraits<Op::OpSource>{});
+		case Op::OpSourceExtension: return func(OpTraits<Op::OpSourceExtension>{});
+		case Op::OpName: return func(OpTraits<Op::OpName>{});
+		case Op::OpMemberName: return func(OpTraits<Op::OpMemberName>{});
+		case Op::OpString: return func(OpTraits<Op::OpString>{});
+		case Op::OpLine: return func(OpTraits<Op::OpLine>{});
+		case Op::OpExtension: return func(OpTraits<Op::OpExtension>{});
+		case Op::OpExtInstImport: return func(OpTraits<Op::OpExtInstImport>{});
+		case Op::OpExtInst: return func(OpTraits<Op::OpExtInst>{});
+		case Op::OpMemoryModel: return func(OpTraits<Op::OpMemoryModel>{});
+		case Op::OpEntryPoint: return func(OpTraits<Op::OpEntryPoint>{});
+		case Op::OpExecutionMode: return func(OpTraits<Op::OpExecutionMode>{});
+		case Op::OpCapability: return func(OpTraits<Op::OpCapability>{});
+		case Op::OpTypeVoid: return func(OpTraits<Op::OpTypeVoid>{});
+		case Op::OpTypeBool: return func(OpTraits<Op::OpTypeBool>{});
+		case Op::OpTypeInt: return func(OpTraits<Op::OpTypeInt>{});
+		case Op::OpTypeFloat: return func(OpTraits<Op::OpTypeFloat>{});
+		case Op::OpTypeVector: return func(OpTraits<Op::OpTypeVector>{});
+		case Op::OpTypeMatrix: return func(OpTraits<Op::OpTypeMatrix>{});
+		case Op::OpTypeImage: return func(OpTraits<Op::OpTypeImage>{});
+		case Op::OpTypeSampler: return func(OpTraits<Op::OpTypeSampler>{});
+		case Op::OpTypeSampledImage: return func(OpTraits<Op::OpTypeSampledImage>{});
+		case Op::OpTypeArray: return func(OpTraits<Op::OpTypeArray>{});
+		case Op::OpTypeRuntimeArray: return func(OpTraits<Op::OpTypeRuntimeArray>{});
+		case Op::OpTypeStruct: return func(OpTraits<Op::OpTypeStruct>{});
+		case Op::OpTypeOpaque: return func(OpTraits<Op::OpTypeOpaque>{});
+		case Op::OpTypePointer: return func(OpTraits<Op::OpTypePointer>{});
+		case Op::OpTypeFunction: return func(OpTraits<Op::OpTypeFunction>{});
+		case Op::OpTypeEvent: return func(OpTraits<Op::OpTypeEvent>{});
+		case Op::OpTypeDeviceEvent: return func(OpTraits<Op::OpTypeDeviceEvent>{});
+		case Op::OpTypeReserveId: return func(OpTraits<Op::OpTypeReserveId>{});
+		case Op::OpTypeQueue: return func(OpTraits<Op::OpTypeQueue>{});
+		case Op::OpTypePipe: return func(OpTraits<Op::OpTypePipe>{});
+		case Op::OpTypeForwardPointer: return func(OpTraits<Op::OpTypeForwardPointer>{});
+		case Op::OpConstantTrue: return func(OpTraits<Op::OpConstantTrue>{});
+		case Op::OpConstantFalse: return func(OpTraits<Op::OpConstantFalse>{});
+		case Op::OpConstant: return func(OpTraits<Op::OpConstant>{});
+		case Op::OpConstantComposite: return func(OpTraits<Op::OpConstantComposite>{});
+		case Op::OpConstantSampler: return func(OpTraits<Op::OpConstantSampler>{});
+		case Op::OpConstantNull: return func(OpTraits<Op::OpConstantNull>{});
+		case Op::OpSpecConstantTrue: return func(OpTraits<Op::OpSpecConstantTrue>{});
+		case Op::OpSpecConstantFalse: return func(OpTraits<Op::OpSpecConstantFalse>{});
+		case Op::OpSpecConstant: return func(OpTraits<Op::OpSpecConstant>{});
+		case Op::OpSpecConstantComposite: return func(OpTraits<Op::OpSpecConstantComposite>{});
+		case Op::OpSpecConstantOp: return func(OpTraits<Op::OpSpecConstantOp>{});
+		case Op::OpFunction: return func(OpTraits<Op::OpFunction>{});
+		case Op::OpFunctionParameter: return func(OpTraits<Op::OpFunctionParameter>{});
+		case Op::OpFunctionEnd: return func(OpTraits<Op::OpFunctionEnd>{});
+		case Op::OpFunctionCall: return func(OpTraits<Op::OpFunctionCall>{});
+		case Op::OpVariable: return func(OpTraits<Op::OpVariable>{});
+		case Op::OpImageTexelPointer: return func(OpTraits<Op::OpImageTexelPointer>{});
+		case Op::OpLoad: return func(OpTraits<Op::OpLoad>{});
+		case Op::OpStore: return func(OpTraits<Op::OpStore>{});
+		case Op::OpCopyMemory: return func(OpTraits<Op::OpCopyMemory>{});
+		case Op::OpCopyMemorySized: return func(OpTraits<Op::OpCopyMemorySized>{});
+		case Op::OpAccessChain: return func(OpTraits<Op::OpAccessChain>{});
+		case Op::OpInBoundsAccessChain: return func(OpTraits<Op::OpInBoundsAccessChain>{});
+		case Op::OpPtrAccessChain: return func(OpTraits<Op::OpPtrAccessChain>{});
+		case Op::OpArrayLength: return func(OpTraits<Op::OpArrayLength>{});
+		case Op::OpGenericPtrMemSemantics: return func(OpTraits<Op::OpGenericPtrMemSemantics>{});
+		case Op::OpInBoundsPtrAccessChain: return func(OpTraits<Op::OpInBoundsPtrAccessChain>{});
+		case Op::OpDecorate: return func(OpTraits<Op::OpDecorate>{});
+		case Op::OpMemberDecorate: return func(OpTraits<Op::OpMemberDecorate>{});
+		case Op::OpDecorationGroup: return func(OpTraits<Op::OpDecorationGroup>{});
+		case Op::OpGroupDecorate: return func(OpTraits<Op::OpGroupDecorate>{});
+		case Op::OpGroupMemberDecorate: return func(OpTraits<Op::OpGroupMemberDecorate>{});
+		case Op::OpVectorExtractDynamic: return func(OpTraits<Op::OpVectorExtractDynamic>{});
+		case Op::OpVectorInsertDynamic: return func(OpTraits<Op::OpVectorInsertDynamic>{});
+		case Op::OpVectorShuffle: return func(OpTraits<Op::OpVectorShuffle>{});
+		case Op::OpCompositeConstruct: return func(OpTraits<Op::OpCompositeConstruct>{});
+		case Op::OpCompositeExtract: return func(OpTraits<Op::OpCompositeExtract>{});
+		case Op::OpCompositeInsert: return func(OpTraits<Op::OpCompositeInsert>{});
+		case Op::OpCopyObject: return func(OpTraits<Op::OpCopyObject>{});
+		case Op::OpTranspose: return func(OpTraits<Op::OpTranspose>{});
+		case Op::OpSampledImage: return func(OpTraits<Op::OpSampledImage>{});
+		case Op::OpImageSampleImplicitLod: return func(OpTraits<Op::OpImageSampleImplicitLod>{});
+		case Op::OpImageSampleExplicitLod: return func(OpTraits<Op::OpImageSampleExplicitLod>{});
+		case Op::OpImageSampleDrefImplicitLod: return func(OpTraits<Op::OpImageSampleDrefImplicitLod>{});
+		case Op::OpImageSampleDrefExplicitLod: return func(OpTraits<Op::OpImageSampleDrefExplicitLod>{});
+		case Op::OpImageSampleProjImplicitLod: return func(OpTraits<Op::OpImageSampleProjImplicitLod>{});
+		case Op::OpImageSampleProjExplicitLod: return func(OpTraits<Op::OpImageSampleProjExplicitLod>{});
+		case Op::OpImageSampleProjDrefImplicitLod: return func(OpTraits<Op::OpImageSampleProjDrefImplicitLod>{});
+		case Op::OpImageSampleProjDrefExplicitLod: return func(OpTraits<Op::OpImageSampleProjDrefExplicitLod>{});
+		case Op::OpImageFetch: return func(OpTraits<Op::OpImageFetch>{});
+		case Op::OpImageGather: return func(OpTraits<Op::OpImageGather>{});
+		case Op::OpImageDrefGather: return func(OpTraits<Op::OpImageDrefGather>{});
+		case Op::OpImageRead: return func(OpTraits<Op::OpImageRead>{});
+		case Op::OpImageWrite: return func(OpTraits<Op::OpImageWrite>{});
+		case Op::OpImage: return func(OpTraits<Op::OpImage>{});
+		case Op::OpImageQueryFormat: return func(OpTraits<Op::OpImageQueryFormat>{});
+		case Op::OpImageQueryOrder: return func(OpTraits<Op::OpImageQueryOrder>{});
+		case Op::OpImageQuerySizeLod: return func(OpTraits<Op::OpImageQuerySizeLod>{});
+		case Op::OpImageQuerySize: return func(OpTraits<Op::OpImageQuerySize>{});
+		case Op::OpImageQueryLod: return func(OpTraits<Op::OpImageQueryLod>{});
+		case Op::OpImageQueryLevels: return func(OpTraits<Op::OpImageQueryLevels>{});
+		case Op::OpImageQuerySamples: return func(OpTraits<Op::OpImageQuerySamples>{});
+		case Op::OpConvertFToU: return func(OpTraits<Op::OpConvertFToU>{});
+		case Op::OpConvertFToS: return func(OpTraits<Op::OpConvertFToS>{});
+		case Op::OpConvertSToF: return func(OpTraits<Op::OpConvertSToF>{});
+		case Op::OpConvertUToF: return func(OpTraits<Op::OpConvertUToF>{});
+		case Op::OpUConvert: return func(OpTraits<Op::OpUConvert>{});
+		case Op::OpSConvert: return func(OpTraits<Op::OpSConvert>{});
+		case Op::OpFConvert: return func(OpTraits<Op::OpFConvert>{});
+		case Op::OpQuantizeToF16: return func(OpTraits<Op::OpQuantizeToF16>{});
+		case Op::OpConvertPtrToU: return func(OpTraits<Op::OpConvertPtrToU>{});
+		case Op::OpSatConvertSToU: return func(OpTraits<Op::OpSatConvertSToU>{});
+		case Op::OpSatConvertUToS: return func(OpTraits<Op::OpSatConvertUToS>{});
+		case Op::OpConvertUToPtr: return func(OpTraits<Op::OpConvertUToPtr>{});
+		case Op::OpPtrCastToGeneric: return func(OpTraits<Op::OpPtrCastToGeneric>{});
+		case Op::OpGenericCastToPtr: return func(OpTraits<Op::OpGenericCastToPtr>{});
+		case Op::OpGenericCastToPtrExplicit: return func(OpTraits<Op::OpGenericCastToPtrExplicit>{});
+		case Op::OpBitcast: return func(OpTraits<Op::OpBitcast>{});
+		case Op::OpSNegate: return func(OpTraits<Op::OpSNegate>{});
+		case Op::OpFNegate: return func(OpTraits<Op::OpFNegate>{});
+		case Op::OpIAdd: return func(OpTraits<Op::OpIAdd>{});
+		case Op::OpFAdd: return func(OpTraits<Op::OpFAdd>{});
+		case Op::OpISub: return func(OpTraits<Op::OpISub>{});
+		case Op::OpFSub: return func(OpTraits<Op::OpFSub>{});
+		case Op::OpIMul: return func(OpTraits<Op::OpIMul>{});
+		case Op::OpFMul: return func(OpTraits<Op::OpFMul>{});
+		case Op::OpUDiv: return func(OpTraits<Op::OpUDiv>{});
+		case Op::OpSDiv: return func(OpTraits<Op::OpSDiv>{});
+		case Op::OpFDiv: return func(OpTraits<Op::OpFDiv>{});
+		case Op::OpUMod: return func(OpTraits<Op::OpUMod>{});
+		case Op::OpSRem: return func(OpTraits<Op::OpSRem>{});
+		case Op::OpSMod: return func(OpTraits<Op::OpSMod>{});
+		case Op::OpFRem: return func(OpTraits<Op::OpFRem>{});
+		case Op::OpFMod: return func(OpTraits<Op::OpFMod>{});
+		case Op::OpVectorTimesScalar: return func(OpTraits<Op::OpVectorTimesScalar>{});
+		case Op::OpMatrixTimesScalar: return func(OpTraits<Op::OpMatrixTimesScalar>{});
+		case Op::OpVectorTimesMatrix: return func(OpTraits<Op::OpVectorTimesMatrix>{});
+		case Op::OpMatrixTimesVector: return func(OpTraits<Op::OpMatrixTimesVector>{});
+		case Op::OpMatrixTimesMatrix: return func(OpTraits<Op::OpMatrixTimesMatrix>{});
+		case Op::OpOuterProduct: return func(OpTraits<Op::OpOuterProduct>{});
+		case Op::OpDot: return func(OpTraits<Op::OpDot>{});
+		case Op::OpIAddCarry: return func(OpTraits<Op::OpIAddCarry>{});
+		case Op::OpISubBorrow: return func(OpTraits<Op::OpISubBorrow>{});
+		case Op::OpUMulExtended: return func(OpTraits<Op::OpUMulExtended>{});
+		case Op::OpSMulExtended: return func(OpTraits<Op::OpSMulExtended>{});
+		case Op::OpAny: return func(OpTraits<Op::OpAny>{});
+		case Op::OpAll: return func(OpTraits<Op::OpAll>{});
+		case Op::OpIsNan: return func(OpTraits<Op::OpIsNan>{});
+		case Op::OpIsInf: return func(OpTraits<Op::OpIsInf>{});
+		case Op::OpIsFinite: return func(OpTraits<Op::OpIsFinite>{});
+		case Op::OpIsNormal: return func(OpTraits<Op::OpIsNormal>{});
+		case Op::OpSignBitSet: return func(OpTraits<Op::OpSignBitSet>{});
+		case Op::OpLessOrGreater: return func(OpTraits<Op::OpLessOrGreater>{});
+		case Op::OpOrdered: return func(OpTraits<Op::OpOrdered>{});
+		case Op::OpUnordered: return func(OpTraits<Op::OpUnordered>{});
+		case Op::OpLogicalEqual: return func(OpTraits<Op::OpLogicalEqual>{});
+		case Op::OpLogicalNotEqual: return func(OpTraits<Op::OpLogicalNotEqual>{});
+		case Op::OpLogicalOr: return func(OpTraits<Op::OpLogicalOr>{});
+		case Op::OpLogicalAnd: return func(OpTraits<Op::OpLogicalAnd>{});
+		case Op::OpLogicalNot: return func(OpTraits<Op::OpLogicalNot>{});
+		case Op::OpSelect: return func(OpTraits<Op::OpSelect>{});
+		case Op::OpIEqual: return func(OpTraits<Op::OpIEqual>{});
+		case Op::OpINotEqual: return func(OpTraits<Op::OpINotEqual>{});
+		case Op::OpUGreaterThan: return func(OpTraits<Op::OpUGreaterThan>{});
+		case Op::OpSGreaterThan: return func(OpTraits<Op::OpSGreaterThan>{});
+		case Op::OpUGreaterThanEqual: return func(OpTraits<Op::OpUGreaterThanEqual>{});
+		case Op::OpSGreaterThanEqual: return func(OpTraits<Op::OpSGreaterThanEqual>{});
+		case Op::OpULessThan: return func(OpTraits<Op::OpULessThan>{});
+		case Op::OpSLessThan: return func(OpTraits<Op::OpSLessThan>{});
+		case Op::OpULessThanEqual: return func(OpTraits<Op::OpULessThanEqual>{});
+		case Op::OpSLessThanEqual: return func(OpTraits<Op::OpSLessThanEqual>{});
+		case Op::OpFOrdEqual: return func(OpTraits<Op::OpFOrdEqual>{});
+		case Op::OpFUnordEqual: return func(OpTraits<Op::OpFUnordEqual>{});
+		case Op::OpFOrdNotEqual: return func(OpTraits<Op::OpFOrdNotEqual>{});
+		case Op::OpFUnordNotEqual: return func(OpTraits<Op::OpFUnordNotEqual>{});
+		case Op::OpFOrdLessThan: return func(OpTraits<Op::OpFOrdLessThan>{});
+		case Op::OpFUnordLessThan: return func(OpTraits<Op::OpFUnordLessThan>{});
+		case Op::OpFOrdGreaterThan: return func(OpTraits<Op::OpFOrdGreaterThan>{});
+		case Op::OpFUnordGreaterThan: return func(OpTraits<Op::OpFUnordGreaterThan>{});
+		case Op::OpFOrdLessThanEqual: return func(OpTraits<Op::OpFOrdLessThanEqual>{});
+		case Op::OpFUnordLessThanEqual: return func(OpTraits<Op::OpFUnordLessThanEqual>{});
+		case Op::OpFOrdGreaterThanEqual: return func(OpTraits<Op::OpFOrdGreaterThanEqual>{});
+		case Op::OpFUnordGreaterThanEqual: return func(OpTraits<Op::OpFUnordGreaterThanEqual>{});
+		case Op::OpShiftRightLogical: return func(OpTraits<Op::OpShiftRightLogical>{});
+		case Op::OpShiftRightArithmetic: return func(OpTraits<Op::OpShiftRightArithmetic>{});
+		case Op::OpShiftLeftLogical: return func(OpTraits<Op::OpShiftLeftLogical>{});
+		case Op::OpBitwiseOr: return func(OpTraits<Op::OpBitwiseOr>{});
+		case Op::OpBitwiseXor: return func(OpTraits<Op::OpBitwiseXor>{});
+		case Op::OpBitwiseAnd: return func(OpTraits<Op::OpBitwiseAnd>{});
+		case Op::OpNot: return func(OpTraits<Op::OpNot>{});
+		case Op::OpBitFieldInsert: return func(OpTraits<Op::OpBitFieldInsert>{});
+		case Op::OpBitFieldSExtract: return func(OpTraits<Op::OpBitFieldSExtract>{});
+		case Op::OpBitFieldUExtract: return func(OpTraits<Op::OpBitFieldUExtract>{});
+		case Op::OpBitReverse: return func(OpTraits<Op::OpBitReverse>{});
+		case Op::OpBitCount: return func(OpTraits<Op::OpBitCount>{});
+		case Op::OpDPdx: return func(OpTraits<Op::OpDPdx>{});
+		case Op::OpDPdy: return func(OpTraits<Op::OpDPdy>{});
+		case Op::OpFwidth: return func(OpTraits<Op::OpFwidth>{});
+		case Op::OpDPdxFine: return func(OpTraits<Op::OpDPdxFine>{});
+		case Op::OpDPdyFine: return func(OpTraits<Op::OpDPdyFine>{});
+		case Op::OpFwidthFine: return func(OpTraits<Op::OpFwidthFine>{});
+		case Op::OpDPdxCoarse: return func(OpTraits<Op::OpDPdxCoarse>{});
+		case Op::OpDPdyCoarse: return func(OpTraits<Op::OpDPdyCoarse>{});
+		case Op::OpFwidthCoarse: return func(OpTraits<Op::OpFwidthCoarse>{});
+		case Op::OpEmitVertex: return func(OpTraits<Op::OpEmitVertex>{});
+		case Op::OpEndPrimitive: return func(OpTraits<Op::OpEndPrimitive>{});
+		case Op::OpEmitStreamVertex: return func(OpTraits<Op::OpEmitStreamVertex>{});
+		case Op::OpEndStreamPrimitive: return func(OpTraits<Op::OpEndStreamPrimitive>{});
+		case Op::OpControlBarrier: return func(OpTraits<Op::OpControlBarrier>{});
+		case Op::OpMemoryBarrier: return func(OpTraits<Op::OpMemoryBarrier>{});
+		case Op::OpAtomicLoad: return func(OpTraits<Op::OpAtomicLoad>{});
+		case Op::OpAtomicStore: return func(OpTraits<Op::OpAtomicStore>{});
+		case Op::OpAtomicExchange: return func(OpTraits<Op::OpAtomicExchange>{});
+		case Op::OpAtomicCompareExchange: return func(OpTraits<Op::OpAtomicCompareExchange>{});
+		case Op::OpAtomicCompareExchangeWeak: return func(OpTraits<Op::OpAtomicCompareExchangeWeak>{});
+		case Op::OpAtomicIIncrement: return func(OpTraits<Op::OpAtomicIIncrement>{});
+		case Op::OpAtomicIDecrement: return func(OpTraits<Op::OpAtomicIDecrement>{});
+		case Op::OpAtomicIAdd: return func(OpTraits<Op::OpAtomicIAdd>{});
+		case Op::OpAtomicISub: return func(OpTraits<Op::OpAtomicISub>{});
+		case Op::OpAtomicSMin: return func(OpTraits<Op::OpAtomicSMin>{});
+		case Op::OpAtomicUMin: return func(OpTraits<Op::OpAtomicUMin>{});
+		case Op::OpAtomicSMax: return func(OpTraits<Op::OpAtomicSMax>{});
+		case Op::OpAtomicUMax: return func(OpTraits<Op::OpAtomicUMax>{});
+		case Op::OpAtomicAnd: return func(OpTraits<Op::OpAtomicAnd>{});
+		case Op::OpAtomicOr: return func(OpTraits<Op::OpAtomicOr>{});
+		case Op::OpAtomicXor: return func(OpTraits<Op::OpAtomicXor>{});
+		case Op::OpPhi: return func(OpTraits<Op::OpPhi>{});
+		case Op::OpLoopMerge: return func(OpTraits<Op::OpLoopMerge>{});
+		case Op::OpSelectionMerge: return func(OpTraits<Op::OpSelectionMerge>{});
+		case Op::OpLabel: return func(OpTraits<Op::OpLabel>{});
+		case Op::OpBranch: return func(OpTraits<Op::OpBranch>{});
+		case Op::OpBranchConditional: return func(OpTraits<Op::OpBranchConditional>{});
+		case Op::OpSwitch: return func(OpTraits<Op::OpSwitch>{});
+		case Op::OpKill: return func(OpTraits<Op::OpKill>{});
+		case Op::OpReturn: return func(OpTraits<Op::OpReturn>{});
+		case Op::OpReturnValue: return func(OpTraits<Op::OpReturnValue>{});
+		case Op::OpUnreachable: return func(OpTraits<Op::OpUnreachable>{});
+		case Op::OpLifetimeStart: return func(OpTraits<Op::OpLifetimeStart>{});
+		case Op::OpLifetimeStop: return func(OpTraits<Op::OpLifetimeStop>{});
+		case Op::OpGroupAsyncCopy: return func(OpTraits<Op::OpGroupAsyncCopy>{});
+		case Op::OpGroupWaitEvents: return func(OpTraits<Op::OpGroupWaitEvents>{});
+		case Op::OpGroupAll: return func(OpTraits<Op::OpGroupAll>{});
+		case Op::OpGroupAny: return func(OpTraits<Op::OpGroupAny>{});
+		case Op::OpGroupBroadcast: return func(OpTraits<Op::OpGroupBroadcast>{});
+		case Op::OpGroupIAdd: return func(OpTraits<Op::OpGroupIAdd>{});
+		case Op::OpGroupFAdd: return func(OpTraits<Op::OpGroupFAdd>{});
+		case Op::OpGroupFMin: return func(OpTraits<Op::OpGroupFMin>{});
+		case Op::OpGroupUMin: return func(OpTraits<Op::OpGroupUMin>{});
+		case Op::OpGroupSMin: return func(OpTraits<Op::OpGroupSMin>{});
+		case Op::OpGroupFMax: return func(OpTraits<Op::OpGroupFMax>{});
+		case Op::OpGroupUMax: return func(OpTraits<Op::OpGroupUMax>{});
+		case Op::OpGroupSMax: return func(OpTraits<Op::OpGroupSMax>{});
+		case Op::OpReadPipe: return func(OpTraits<Op::OpReadPipe>{});
+		case Op::OpWritePipe: return func(OpTraits<Op::OpWritePipe>{});
+		case Op::OpReservedReadPipe: return func(OpTraits<Op::OpReservedReadPipe>{});
+		case Op::OpReservedWritePipe: return func(OpTraits<Op::OpReservedWritePipe>{});
+		case Op::OpReserveReadPipePackets: return func(OpTraits<Op::OpReserveReadPipePackets>{});
+		case Op::OpReserveWritePipePackets: return func(OpTraits<Op::OpReserveWritePipePackets>{});
+		case Op::OpCommitReadPipe: return func(OpTraits<Op::OpCommitReadPipe>{});
+		case Op::OpCommitWritePipe: return func(OpTraits<Op::OpCommitWritePipe>{});
+		case Op::OpIsValidReserveId: return func(OpTraits<Op::OpIsValidReserveId>{});
+		case Op::OpGetNumPipePackets: return func(OpTraits<Op::OpGetNumPipePackets>{});
+		case Op::OpGetMaxPipePackets: return func(OpTraits<Op::OpGetMaxPipePackets>{});
+		case Op::OpGroupReserveReadPipePackets: return func(OpTraits<Op::OpGroupReserveReadPipePackets>{});
+		case Op::OpGroupReserveWritePipePackets: return func(OpTraits<Op::OpGroupReserveWritePipePackets>{});
+		case Op::OpGroupCommitReadPipe: return func(OpTraits<Op::OpGroupCommitReadPipe>{});
+		case Op::OpGroupCommitWritePipe: return func(OpTraits<Op::OpGroupCommitWritePipe>{});
+		case Op::OpEnqueueMarker: return func(OpTraits<Op::OpEnqueueMarker>{});
+		case Op::OpEnqueueKernel: return func(OpTraits<Op::OpEnqueueKernel>{});
+		case Op::OpGetKernelNDrangeSubGroupCount: return func(OpTraits<Op::OpGetKernelNDrangeSubGroupCount>{});
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return func(OpTraits<Op::OpGetKernelNDrangeMaxSubGroupSize>{});
+		case Op::OpGetKernelWorkGroupSize: return func(OpTraits<Op::OpGetKernelWorkGroupSize>{});
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return func(OpTraits<Op::OpGetKernelPreferredWorkGroupSizeMultiple>{});
+		case Op::OpRetainEvent: return func(OpTraits<Op::OpRetainEvent>{});
+		case Op::OpReleaseEvent: return func(OpTraits<Op::OpReleaseEvent>{});
+		case Op::OpCreateUserEvent: return func(OpTraits<Op::OpCreateUserEvent>{});
+		case Op::OpIsValidEvent: return func(OpTraits<Op::OpIsValidEvent>{});
+		case Op::OpSetUserEventStatus: return func(OpTraits<Op::OpSetUserEventStatus>{});
+		case Op::OpCaptureEventProfilingInfo: return func(OpTraits<Op::OpCaptureEventProfilingInfo>{});
+		case Op::OpGetDefaultQueue: return func(OpTraits<Op::OpGetDefaultQueue>{});
+		case Op::OpBuildNDRange: return func(OpTraits<Op::OpBuildNDRange>{});
+		case Op::OpImageSparseSampleImplicitLod: return func(OpTraits<Op::OpImageSparseSampleImplicitLod>{});
+		case Op::OpImageSparseSampleExplicitLod: return func(OpTraits<Op::OpImageSparseSampleExplicitLod>{});
+		case Op::OpImageSparseSampleDrefImplicitLod: return func(OpTraits<Op::OpImageSparseSampleDrefImplicitLod>{});
+		case Op::OpImageSparseSampleDrefExplicitLod: return func(OpTraits<Op::OpImageSparseSampleDrefExplicitLod>{});
+		case Op::OpImageSparseSampleProjImplicitLod: return func(OpTraits<Op::OpImageSparseSampleProjImplicitLod>{});
+		case Op::OpImageSparseSampleProjExplicitLod: return func(OpTraits<Op::OpImageSparseSampleProjExplicitLod>{});
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return func(OpTraits<Op::OpImageSparseSampleProjDrefImplicitLod>{});
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return func(OpTraits<Op::OpImageSparseSampleProjDrefExplicitLod>{});
+		case Op::OpImageSparseFetch: return func(OpTraits<Op::OpImageSparseFetch>{});
+		case Op::OpImageSparseGather: return func(OpTraits<Op::OpImageSparseGather>{});
+		case Op::OpImageSparseDrefGather: return func(OpTraits<Op::OpImageSparseDrefGather>{});
+		case Op::OpImageSparseTexelsResident: return func(OpTraits<Op::OpImageSparseTexelsResident>{});
+		case Op::OpNoLine: return func(OpTraits<Op::OpNoLine>{});
+		case Op::OpAtomicFlagTestAndSet: return func(OpTraits<Op::OpAtomicFlagTestAndSet>{});
+		case Op::OpAtomicFlagClear: return func(OpTraits<Op::OpAtomicFlagClear>{});
+		case Op::OpImageSparseRead: return func(OpTraits<Op::OpImageSparseRead>{});
+		case Op::OpSizeOf: return func(OpTraits<Op::OpSizeOf>{});
+		case Op::OpTypePipeStorage: return func(OpTraits<Op::OpTypePipeStorage>{});
+		case Op::OpConstantPipeStorage: return func(OpTraits<Op::OpConstantPipeStorage>{});
+		case Op::OpCreatePipeFromPipeStorage: return func(OpTraits<Op::OpCreatePipeFromPipeStorage>{});
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return func(OpTraits<Op::OpGetKernelLocalSizeForSubgroupCount>{});
+		case Op::OpGetKernelMaxNumSubgroups: return func(OpTraits<Op::OpGetKernelMaxNumSubgroups>{});
+		case Op::OpTypeNamedBarrier: return func(OpTraits<Op::OpTypeNamedBarrier>{});
+		case Op::OpNamedBarrierInitialize: return func(OpTraits<Op::OpNamedBarrierInitialize>{});
+		case Op::OpMemoryNamedBarrier: return func(OpTraits<Op::OpMemoryNamedBarrier>{});
+		case Op::OpModuleProcessed: return func(OpTraits<Op::OpModuleProcessed>{});
+		case Op::OpExecutionModeId: return func(OpTraits<Op::OpExecutionModeId>{});
+		case Op::OpDecorateId: return func(OpTraits<Op::OpDecorateId>{});
+		case Op::OpGroupNonUniformElect: return func(OpTraits<Op::OpGroupNonUniformElect>{});
+		case Op::OpGroupNonUniformAll: return func(OpTraits<Op::OpGroupNonUniformAll>{});
+		case Op::OpGroupNonUniformAny: return func(OpTraits<Op::OpGroupNonUniformAny>{});
+		case Op::OpGroupNonUniformAllEqual: return func(OpTraits<Op::OpGroupNonUniformAllEqual>{});
+		case Op::OpGroupNonUniformBroadcast: return func(OpTraits<Op::OpGroupNonUniformBroadcast>{});
+		case Op::OpGroupNonUniformBroadcastFirst: return func(OpTraits<Op::OpGroupNonUniformBroadcastFirst>{});
+		case Op::OpGroupNonUniformBallot: return func(OpTraits<Op::OpGroupNonUniformBallot>{});
+		case Op::OpGroupNonUniformInverseBallot: return func(OpTraits<Op::OpGroupNonUniformInverseBallot>{});
+		case Op::OpGroupNonUniformBallotBitExtract: return func(OpTraits<Op::OpGroupNonUniformBallotBitExtract>{});
+		case Op::OpGroupNonUniformBallotBitCount: return func(OpTraits<Op::OpGroupNonUniformBallotBitCount>{});
+		case Op::OpGroupNonUniformBallotFindLSB: return func(OpTraits<Op::OpGroupNonUniformBallotFindLSB>{});
+		case Op::OpGroupNonUniformBallotFindMSB: return func(OpTraits<Op::OpGroupNonUniformBallotFindMSB>{});
+		case Op::OpGroupNonUniformShuffle: return func(OpTraits<Op::OpGroupNonUniformShuffle>{});
+		case Op::OpGroupNonUniformShuffleXor: return func(OpTraits<Op::OpGroupNonUniformShuffleXor>{});
+		case Op::OpGroupNonUniformShuffleUp: return func(OpTraits<Op::OpGroupNonUniformShuffleUp>{});
+		case Op::OpGroupNonUniformShuffleDown: return func(OpTraits<Op::OpGroupNonUniformShuffleDown>{});
+		case Op::OpGroupNonUniformIAdd: return func(OpTraits<Op::OpGroupNonUniformIAdd>{});
+		case Op::OpGroupNonUniformFAdd: return func(OpTraits<Op::OpGroupNonUniformFAdd>{});
+		case Op::OpGroupNonUniformIMul: return func(OpTraits<Op::OpGroupNonUniformIMul>{});
+		case Op::OpGroupNonUniformFMul: return func(OpTraits<Op::OpGroupNonUniformFMul>{});
+		case Op::OpGroupNonUniformSMin: return func(OpTraits<Op::OpGroupNonUniformSMin>{});
+		case Op::OpGroupNonUniformUMin: return func(OpTraits<Op::OpGroupNonUniformUMin>{});
+		case Op::OpGroupNonUniformFMin: return func(OpTraits<Op::OpGroupNonUniformFMin>{});
+		case Op::OpGroupNonUniformSMax: return func(OpTraits<Op::OpGroupNonUniformSMax>{});
+		case Op::OpGroupNonUniformUMax: return func(OpTraits<Op::OpGroupNonUniformUMax>{});
+		case Op::OpGroupNonUniformFMax: return func(OpTraits<Op::OpGroupNonUniformFMax>{});
+		case Op::OpGroupNonUniformBitwiseAnd: return func(OpTraits<Op::OpGroupNonUniformBitwiseAnd>{});
+		case Op::OpGroupNonUniformBitwiseOr: return func(OpTraits<Op::OpGroupNonUniformBitwiseOr>{});
+		case Op::OpGroupNonUniformBitwiseXor: return func(OpTraits<Op::OpGroupNonUniformBitwiseXor>{});
+		case Op::OpGroupNonUniformLogicalAnd: return func(OpTraits<Op::OpGroupNonUniformLogicalAnd>{});
+		case Op::OpGroupNonUniformLogicalOr: return func(OpTraits<Op::OpGroupNonUniformLogicalOr>{});
+		case Op::OpGroupNonUniformLogicalXor: return func(OpTraits<Op::OpGroupNonUniformLogicalXor>{});
+		case Op::OpGroupNonUniformQuadBroadcast: return func(OpTraits<Op::OpGroupNonUniformQuadBroadcast>{});
+		case Op::OpGroupNonUniformQuadSwap: return func(OpTraits<Op::OpGroupNonUniformQuadSwap>{});
+		case Op::OpCopyLogical: return func(OpTraits<Op::OpCopyLogical>{});
+		case Op::OpPtrEqual: return func(OpTraits<Op::OpPtrEqual>{});
+		case Op::OpPtrNotEqual: return func(OpTraits<Op::OpPtrNotEqual>{});
+		case Op::OpPtrDiff: return func(OpTraits<Op::OpPtrDiff>{});
+		case Op::OpColorAttachmentReadEXT: return func(OpTraits<Op::OpColorAttachmentReadEXT>{});
+		case Op::OpDepthAttachmentReadEXT: return func(OpTraits<Op::OpDepthAttachmentReadEXT>{});
+		case Op::OpStencilAttachmentReadEXT: return func(OpTraits<Op::OpStencilAttachmentReadEXT>{});
+		case Op::OpTerminateInvocation: return func(OpTraits<Op::OpTerminateInvocation>{});
+		case Op::OpSubgroupBallotKHR: return func(OpTraits<Op::OpSubgroupBallotKHR>{});
+		case Op::OpSubgroupFirstInvocationKHR: return func(OpTraits<Op::OpSubgroupFirstInvocationKHR>{});
+		case Op::OpSubgroupAllKHR: return func(OpTraits<Op::OpSubgroupAllKHR>{});
+		case Op::OpSubgroupAnyKHR: return func(OpTraits<Op::OpSubgroupAnyKHR>{});
+		case Op::OpSubgroupAllEqualKHR: return func(OpTraits<Op::OpSubgroupAllEqualKHR>{});
+		case Op::OpGroupNonUniformRotateKHR: return func(OpTraits<Op::OpGroupNonUniformRotateKHR>{});
+		case Op::OpSubgroupReadInvocationKHR: return func(OpTraits<Op::OpSubgroupReadInvocationKHR>{});
+		case Op::OpTraceRayKHR: return func(OpTraits<Op::OpTraceRayKHR>{});
+		case Op::OpExecuteCallableKHR: return func(OpTraits<Op::OpExecuteCallableKHR>{});
+		case Op::OpConvertUToAccelerationStructureKHR: return func(OpTraits<Op::OpConvertUToAccelerationStructureKHR>{});
+		case Op::OpIgnoreIntersectionKHR: return func(OpTraits<Op::OpIgnoreIntersectionKHR>{});
+		case Op::OpTerminateRayKHR: return func(OpTraits<Op::OpTerminateRayKHR>{});
+		case Op::OpSDot: return func(OpTraits<Op::OpSDot>{});
+		case Op::OpUDot: return func(OpTraits<Op::OpUDot>{});
+		case Op::OpSUDot: return func(OpTraits<Op::OpSUDot>{});
+		case Op::OpSDotAccSat: return func(OpTraits<Op::OpSDotAccSat>{});
+		case Op::OpUDotAccSat: return func(OpTraits<Op::OpUDotAccSat>{});
+		case Op::OpSUDotAccSat: return func(OpTraits<Op::OpSUDotAccSat>{});
+		case Op::OpTypeCooperativeMatrixKHR: return func(OpTraits<Op::OpTypeCooperativeMatrixKHR>{});
+		case Op::OpCooperativeMatrixLoadKHR: return func(OpTraits<Op::OpCooperativeMatrixLoadKHR>{});
+		case Op::OpCooperativeMatrixStoreKHR: return func(OpTraits<Op::OpCooperativeMatrixStoreKHR>{});
+		case Op::OpCooperativeMatrixMulAddKHR: return func(OpTraits<Op::OpCooperativeMatrixMulAddKHR>{});
+		case Op::OpCooperativeMatrixLengthKHR: return func(OpTraits<Op::OpCooperativeMatrixLengthKHR>{});
+		case Op::OpTypeRayQueryKHR: return func(OpTraits<Op::OpTypeRayQueryKHR>{});
+		case Op::OpRayQueryInitializeKHR: return func(OpTraits<Op::OpRayQueryInitializeKHR>{});
+		case Op::OpRayQueryTerminateKHR: return func(OpTraits<Op::OpRayQueryTerminateKHR>{});
+		case Op::OpRayQueryGenerateIntersectionKHR: return func(OpTraits<Op::OpRayQueryGenerateIntersectionKHR>{});
+		case Op::OpRayQueryConfirmIntersectionKHR: return func(OpTraits<Op::OpRayQueryConfirmIntersectionKHR>{});
+		case Op::OpRayQueryProceedKHR: return func(OpTraits<Op::OpRayQueryProceedKHR>{});
+		case Op::OpRayQueryGetIntersectionTypeKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionTypeKHR>{});
+		case Op::OpImageSampleWeightedQCOM: return func(OpTraits<Op::OpImageSampleWeightedQCOM>{});
+		case Op::OpImageBoxFilterQCOM: return func(OpTraits<Op::OpImageBoxFilterQCOM>{});
+		case Op::OpImageBlockMatchSSDQCOM: return func(OpTraits<Op::OpImageBlockMatchSSDQCOM>{});
+		case Op::OpImageBlockMatchSADQCOM: return func(OpTraits<Op::OpImageBlockMatchSADQCOM>{});
+		case Op::OpGroupIAddNonUniformAMD: return func(OpTraits<Op::OpGroupIAddNonUniformAMD>{});
+		case Op::OpGroupFAddNonUniformAMD: return func(OpTraits<Op::OpGroupFAddNonUniformAMD>{});
+		case Op::OpGroupFMinNonUniformAMD: return func(OpTraits<Op::OpGroupFMinNonUniformAMD>{});
+		case Op::OpGroupUMinNonUniformAMD: return func(OpTraits<Op::OpGroupUMinNonUniformAMD>{});
+		case Op::OpGroupSMinNonUniformAMD: return func(OpTraits<Op::OpGroupSMinNonUniformAMD>{});
+		case Op::OpGroupFMaxNonUniformAMD: return func(OpTraits<Op::OpGroupFMaxNonUniformAMD>{});
+		case Op::OpGroupUMaxNonUniformAMD: return func(OpTraits<Op::OpGroupUMaxNonUniformAMD>{});
+		case Op::OpGroupSMaxNonUniformAMD: return func(OpTraits<Op::OpGroupSMaxNonUniformAMD>{});
+		case Op::OpFragmentMaskFetchAMD: return func(OpTraits<Op::OpFragmentMaskFetchAMD>{});
+		case Op::OpFragmentFetchAMD: return func(OpTraits<Op::OpFragmentFetchAMD>{});
+		case Op::OpReadClockKHR: return func(OpTraits<Op::OpReadClockKHR>{});
+		case Op::OpFinalizeNodePayloadsAMDX: return func(OpTraits<Op::OpFinalizeNodePayloadsAMDX>{});
+		case Op::OpFinishWritingNodePayloadAMDX: return func(OpTraits<Op::OpFinishWritingNodePayloadAMDX>{});
+		case Op::OpInitializeNodePayloadsAMDX: return func(OpTraits<Op::OpInitializeNodePayloadsAMDX>{});
+		case Op::OpHitObjectRecordHitMotionNV: return func(OpTraits<Op::OpHitObjectRecordHitMotionNV>{});
+		case Op::OpHitObjectRecordHitWithIndexMotionNV: return func(OpTraits<Op::OpHitObjectRecordHitWithIndexMotionNV>{});
+		case Op::OpHitObjectRecordMissMotionNV: return func(OpTraits<Op::OpHitObjectRecordMissMotionNV>{});
+		case Op::OpHitObjectGetWorldToObjectNV: return func(OpTraits<Op::OpHitObjectGetWorldToObjectNV>{});
+		case Op::OpHitObjectGetObjectToWorldNV: return func(OpTraits<Op::OpHitObjectGetObjectToWorldNV>{});
+		case Op::OpHitObjectGetObjectRayDirectionNV: return func(OpTraits<Op::OpHitObjectGetObjectRayDirectionNV>{});
+		case Op::OpHitObjectGetObjectRayOriginNV: return func(OpTraits<Op::OpHitObjectGetObjectRayOriginNV>{});
+		case Op::OpHitObjectTraceRayMotionNV: return func(OpTraits<Op::OpHitObjectTraceRayMotionNV>{});
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV: return func(OpTraits<Op::OpHitObjectGetShaderRecordBufferHandleNV>{});
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return func(OpTraits<Op::OpHitObjectGetShaderBindingTableRecordIndexNV>{});
+		case Op::OpHitObjectRecordEmptyNV: return func(OpTraits<Op::OpHitObjectRecordEmptyNV>{});
+		case Op::OpHitObjectTraceRayNV: return func(OpTraits<Op::OpHitObjectTraceRayNV>{});
+		case Op::OpHitObjectRecordHitNV: return func(OpTraits<Op::OpHitObjectRecordHitNV>{});
+		case Op::OpHitObjectRecordHitWithIndexNV: return func(OpTraits<Op::OpHitObjectRecordHitWithIndexNV>{});
+		case Op::OpHitObjectRecordMissNV: return func(OpTraits<Op::OpHitObjectRecordMissNV>{});
+		case Op::OpHitObjectExecuteShaderNV: return func(OpTraits<Op::OpHitObjectExecuteShaderNV>{});
+		case Op::OpHitObjectGetCurrentTimeNV: return func(OpTraits<Op::OpHitObjectGetCurrentTimeNV>{});
+		case Op::OpHitObjectGetAttributesNV: return func(OpTraits<Op::OpHitObjectGetAttributesNV>{});
+		case Op::OpHitObjectGetHitKindNV: return func(OpTraits<Op::OpHitObjectGetHitKindNV>{});
+		case Op::OpHitObjectGetPrimitiveIndexNV: return func(OpTraits<Op::OpHitObjectGetPrimitiveIndexNV>{});
+		case Op::OpHitObjectGetGeometryIndexNV: return func(OpTraits<Op::OpHitObjectGetGeometryIndexNV>{});
+		case Op::OpHitObjectGetInstanceIdNV: return func(OpTraits<Op::OpHitObjectGetInstanceIdNV>{});
+		case Op::OpHitObjectGetInstanceCustomIndexNV: return func(OpTraits<Op::OpHitObjectGetInstanceCustomIndexNV>{});
+		case Op::OpHitObjectGetWorldRayDirectionNV: return func(OpTraits<Op::OpHitObjectGetWorldRayDirectionNV>{});
+		case Op::OpHitObjectGetWorldRayOriginNV: return func(OpTraits<Op::OpHitObjectGetWorldRayOriginNV>{});
+		case Op::OpHitObjectGetRayTMaxNV: return func(OpTraits<Op::OpHitObjectGetRayTMaxNV>{});
+		case Op::OpHitObjectGetRayTMinNV: return func(OpTraits<Op::OpHitObjectGetRayTMinNV>{});
+		case Op::OpHitObjectIsEmptyNV: return func(OpTraits<Op::OpHitObjectIsEmptyNV>{});
+		case Op::OpHitObjectIsHitNV: return func(OpTraits<Op::OpHitObjectIsHitNV>{});
+		case Op::OpHitObjectIsMissNV: return func(OpTraits<Op::OpHitObjectIsMissNV>{});
+		case Op::OpReorderThreadWithHitObjectNV: return func(OpTraits<Op::OpReorderThreadWithHitObjectNV>{});
+		case Op::OpReorderThreadWithHintNV: return func(OpTraits<Op::OpReorderThreadWithHintNV>{});
+		case Op::OpTypeHitObjectNV: return func(OpTraits<Op::OpTypeHitObjectNV>{});
+		case Op::OpImageSampleFootprintNV: return func(OpTraits<Op::OpImageSampleFootprintNV>{});
+		case Op::OpEmitMeshTasksEXT: return func(OpTraits<Op::OpEmitMeshTasksEXT>{});
+		case Op::OpSetMeshOutputsEXT: return func(OpTraits<Op::OpSetMeshOutputsEXT>{});
+		case Op::OpGroupNonUniformPartitionNV: return func(OpTraits<Op::OpGroupNonUniformPartitionNV>{});
+		case Op::OpWritePackedPrimitiveIndices4x8NV: return func(OpTraits<Op::OpWritePackedPrimitiveIndices4x8NV>{});
+		case Op::OpReportIntersectionNV: return func(OpTraits<Op::OpReportIntersectionNV>{});
+		case Op::OpIgnoreIntersectionNV: return func(OpTraits<Op::OpIgnoreIntersectionNV>{});
+		case Op::OpTerminateRayNV: return func(OpTraits<Op::OpTerminateRayNV>{});
+		case Op::OpTraceNV: return func(OpTraits<Op::OpTraceNV>{});
+		case Op::OpTraceMotionNV: return func(OpTraits<Op::OpTraceMotionNV>{});
+		case Op::OpTraceRayMotionNV: return func(OpTraits<Op::OpTraceRayMotionNV>{});
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR>{});
+		case Op::OpTypeAccelerationStructureNV: return func(OpTraits<Op::OpTypeAccelerationStructureNV>{});
+		case Op::OpExecuteCallableNV: return func(OpTraits<Op::OpExecuteCallableNV>{});
+		case Op::OpTypeCooperativeMatrixNV: return func(OpTraits<Op::OpTypeCooperativeMatrixNV>{});
+		case Op::OpCooperativeMatrixLoadNV: return func(OpTraits<Op::OpCooperativeMatrixLoadNV>{});
+		case Op::OpCooperativeMatrixStoreNV: return func(OpTraits<Op::OpCooperativeMatrixStoreNV>{});
+		case Op::OpCooperativeMatrixMulAddNV: return func(OpTraits<Op::OpCooperativeMatrixMulAddNV>{});
+		case Op::OpCooperativeMatrixLengthNV: return func(OpTraits<Op::OpCooperativeMatrixLengthNV>{});
+		case Op::OpBeginInvocationInterlockEXT: return func(OpTraits<Op::OpBeginInvocationInterlockEXT>{});
+		case Op::OpEndInvocationInterlockEXT: return func(OpTraits<Op::OpEndInvocationInterlockEXT>{});
+		case Op::OpDemoteToHelperInvocation: return func(OpTraits<Op::OpDemoteToHelperInvocation>{});
+		case Op::OpIsHelperInvocationEXT: return func(OpTraits<Op::OpIsHelperInvocationEXT>{});
+		case Op::OpConvertUToImageNV: return func(OpTraits<Op::OpConvertUToImageNV>{});
+		case Op::OpConvertUToSamplerNV: return func(OpTraits<Op::OpConvertUToSamplerNV>{});
+		case Op::OpConvertImageToUNV: return func(OpTraits<Op::OpConvertImageToUNV>{});
+		case Op::OpConvertSamplerToUNV: return func(OpTraits<Op::OpConvertSamplerToUNV>{});
+		case Op::OpConvertUToSampledImageNV: return func(OpTraits<Op::OpConvertUToSampledImageNV>{});
+		case Op::OpConvertSampledImageToUNV: return func(OpTraits<Op::OpConvertSampledImageToUNV>{});
+		case Op::OpSamplerImageAddressingModeNV: return func(OpTraits<Op::OpSamplerImageAddressingModeNV>{});
+		case Op::OpSubgroupShuffleINTEL: return func(OpTraits<Op::OpSubgroupShuffleINTEL>{});
+		case Op::OpSubgroupShuffleDownINTEL: return func(OpTraits<Op::OpSubgroupShuffleDownINTEL>{});
+		case Op::OpSubgroupShuffleUpINTEL: return func(OpTraits<Op::OpSubgroupShuffleUpINTEL>{});
+		case Op::OpSubgroupShuffleXorINTEL: return func(OpTraits<Op::OpSubgroupShuffleXorINTEL>{});
+		case Op::OpSubgroupBlockReadINTEL: return func(OpTraits<Op::OpSubgroupBlockReadINTEL>{});
+		case Op::OpSubgroupBlockWriteINTEL: return func(OpTraits<Op::OpSubgroupBlockWriteINTEL>{});
+		case Op::OpSubgroupImageBlockReadINTEL: return func(OpTraits<Op::OpSubgroupImageBlockReadINTEL>{});
+		case Op::OpSubgroupImageBlockWriteINTEL: return func(OpTraits<Op::OpSubgroupImageBlockWriteINTEL>{});
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return func(OpTraits<Op::OpSubgroupImageMediaBlockReadINTEL>{});
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return func(OpTraits<Op::OpSubgroupImageMediaBlockWriteINTEL>{});
+		case Op::OpUCountLeadingZerosINTEL: return func(OpTraits<Op::OpUCountLeadingZerosINTEL>{});
+		case Op::OpUCountTrailingZerosINTEL: return func(OpTraits<Op::OpUCountTrailingZerosINTEL>{});
+		case Op::OpAbsISubINTEL: return func(OpTraits<Op::OpAbsISubINTEL>{});
+		case Op::OpAbsUSubINTEL: return func(OpTraits<Op::OpAbsUSubINTEL>{});
+		case Op::OpIAddSatINTEL: return func(OpTraits<Op::OpIAddSatINTEL>{});
+		case Op::OpUAddSatINTEL: return func(OpTraits<Op::OpUAddSatINTEL>{});
+		case Op::OpIAverageINTEL: return func(OpTraits<Op::OpIAverageINTEL>{});
+		case Op::OpUAverageINTEL: return func(OpTraits<Op::OpUAverageINTEL>{});
+		case Op::OpIAverageRoundedINTEL: return func(OpTraits<Op::OpIAverageRoundedINTEL>{});
+		case Op::OpUAverageRoundedINTEL: return func(OpTraits<Op::OpUAverageRoundedINTEL>{});
+		case Op::OpISubSatINTEL: return func(OpTraits<Op::OpISubSatINTEL>{});
+		case Op::OpUSubSatINTEL: return func(OpTraits<Op::OpUSubSatINTEL>{});
+		case Op::OpIMul32x16INTEL: return func(OpTraits<Op::OpIMul32x16INTEL>{});
+		case Op::OpUMul32x16INTEL: return func(OpTraits<Op::OpUMul32x16INTEL>{});
+		case Op::OpConstantFunctionPointerINTEL: return func(OpTraits<Op::OpConstantFunctionPointerINTEL>{});
+		case Op::OpFunctionPointerCallINTEL: return func(OpTraits<Op::OpFunctionPointerCallINTEL>{});
+		case Op::OpAsmTargetINTEL: return func(OpTraits<Op::OpAsmTargetINTEL>{});
+		case Op::OpAsmINTEL: return func(OpTraits<Op::OpAsmINTEL>{});
+		case Op::OpAsmCallINTEL: return func(OpTraits<Op::OpAsmCallINTEL>{});
+		case Op::OpAtomicFMinEXT: return func(OpTraits<Op::OpAtomicFMinEXT>{});
+		case Op::OpAtomicFMaxEXT: return func(OpTraits<Op::OpAtomicFMaxEXT>{});
+		case Op::OpAssumeTrueKHR: return func(OpTraits<Op::OpAssumeTrueKHR>{});
+		case Op::OpExpectKHR: return func(OpTraits<Op::OpExpectKHR>{});
+		case Op::OpDecorateString: return func(OpTraits<Op::OpDecorateString>{});
+		case Op::OpMemberDecorateString: return func(OpTraits<Op::OpMemberDecorateString>{});
+		case Op::OpVmeImageINTEL: return func(OpTraits<Op::OpVmeImageINTEL>{});
+		case Op::OpTypeVmeImageINTEL: return func(OpTraits<Op::OpTypeVmeImageINTEL>{});
+		case Op::OpTypeAvcImePayloadINTEL: return func(OpTraits<Op::OpTypeAvcImePayloadINTEL>{});
+		case Op::OpTypeAvcRefPayloadINTEL: return func(OpTraits<Op::OpTypeAvcRefPayloadINTEL>{});
+		case Op::OpTypeAvcSicPayloadINTEL: return func(OpTraits<Op::OpTypeAvcSicPayloadINTEL>{});
+		case Op::OpTypeAvcMcePayloadINTEL: return func(OpTraits<Op::OpTypeAvcMcePayloadINTEL>{});
+		case Op::OpTypeAvcMceResultINTEL: return func(OpTraits<Op::OpTypeAvcMceResultINTEL>{});
+		case Op::OpTypeAvcImeResultINTEL: return func(OpTraits<Op::OpTypeAvcImeResultINTEL>{});
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return func(OpTraits<Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL>{});
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return func(OpTraits<Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL>{});
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return func(OpTraits<Op::OpTypeAvcImeSingleReferenceStreaminINTEL>{});
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return func(OpTraits<Op::OpTypeAvcImeDualReferenceStreaminINTEL>{});
+		case Op::OpTypeAvcRefResultINTEL: return func(OpTraits<Op::OpTypeAvcRefResultINTEL>{});
+		case Op::OpTypeAvcSicResultINTEL: return func(OpTraits<Op::OpTypeAvcSicResultINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL>{});
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL>{});
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL>{});
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL>{});
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL>{});
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return func(OpTraits<Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToImePayloadINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToImeResultINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToRefPayloadINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToRefResultINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToSicPayloadINTEL>{});
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return func(OpTraits<Op::OpSubgroupAvcMceConvertToSicResultINTEL>{});
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetMotionVectorsINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterDistortionsINTEL>{});
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterMajorShapeINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterMinorShapeINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterDirectionsINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL>{});
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return func(OpTraits<Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL>{});
+		case Op::OpSubgroupAvcImeInitializeINTEL: return func(OpTraits<Op::OpSubgroupAvcImeInitializeINTEL>{});
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetSingleReferenceINTEL>{});
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetDualReferenceINTEL>{});
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return func(OpTraits<Op::OpSubgroupAvcImeRefWindowSizeINTEL>{});
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return func(OpTraits<Op::OpSubgroupAvcImeAdjustRefOffsetINTEL>{});
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcImeConvertToMcePayloadINTEL>{});
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL>{});
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL>{});
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL>{});
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return func(OpTraits<Op::OpSubgroupAvcImeSetWeightedSadINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL>{});
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL>{});
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return func(OpTraits<Op::OpSubgroupAvcImeConvertToMceResultINTEL>{});
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL>{});
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL>{});
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL>{});
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return func(OpTraits<Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL>{});
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL>{});
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetBorderReachedINTEL>{});
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL>{});
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL>{});
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL>{});
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return func(OpTraits<Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL>{});
+		case Op::OpSubgroupAvcFmeInitializeINTEL: return func(OpTraits<Op::OpSubgroupAvcFmeInitializeINTEL>{});
+		case Op::OpSubgroupAvcBmeInitializeINTEL: return func(OpTraits<Op::OpSubgroupAvcBmeInitializeINTEL>{});
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcRefConvertToMcePayloadINTEL>{});
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return func(OpTraits<Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL>{});
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return func(OpTraits<Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL>{});
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL>{});
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL>{});
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL>{});
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return func(OpTraits<Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL>{});
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return func(OpTraits<Op::OpSubgroupAvcRefConvertToMceResultINTEL>{});
+		case Op::OpSubgroupAvcSicInitializeINTEL: return func(OpTraits<Op::OpSubgroupAvcSicInitializeINTEL>{});
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL: return func(OpTraits<Op::OpSubgroupAvcSicConfigureSkcINTEL>{});
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return func(OpTraits<Op::OpSubgroupAvcSicConfigureIpeLumaINTEL>{});
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return func(OpTraits<Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL>{});
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL>{});
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return func(OpTraits<Op::OpSubgroupAvcSicConvertToMcePayloadINTEL>{});
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL>{});
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL>{});
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL>{});
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL>{});
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL>{});
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return func(OpTraits<Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL>{});
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return func(OpTraits<Op::OpSubgroupAvcSicEvaluateIpeINTEL>{});
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL>{});
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL>{});
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return func(OpTraits<Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL>{});
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return func(OpTraits<Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL>{});
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return func(OpTraits<Op::OpSubgroupAvcSicConvertToMceResultINTEL>{});
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL>{});
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL>{});
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL>{});
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL>{});
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetIpeChromaModeINTEL>{});
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL>{});
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL>{});
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return func(OpTraits<Op::OpSubgroupAvcSicGetInterRawSadsINTEL>{});
+		case Op::OpVariableLengthArrayINTEL: return func(OpTraits<Op::OpVariableLengthArrayINTEL>{});
+		case Op::OpSaveMemoryINTEL: return func(OpTraits<Op::OpSaveMemoryINTEL>{});
+		case Op::OpRestoreMemoryINTEL: return func(OpTraits<Op::OpRestoreMemoryINTEL>{});
+		case Op::OpArbitraryFloatSinCosPiINTEL: return func(OpTraits<Op::OpArbitraryFloatSinCosPiINTEL>{});
+		case Op::OpArbitraryFloatCastINTEL: return func(OpTraits<Op::OpArbitraryFloatCastINTEL>{});
+		case Op::OpArbitraryFloatCastFromIntINTEL: return func(OpTraits<Op::OpArbitraryFloatCastFromIntINTEL>{});
+		case Op::OpArbitraryFloatCastToIntINTEL: return func(OpTraits<Op::OpArbitraryFloatCastToIntINTEL>{});
+		case Op::OpArbitraryFloatAddINTEL: return func(OpTraits<Op::OpArbitraryFloatAddINTEL>{});
+		case Op::OpArbitraryFloatSubINTEL: return func(OpTraits<Op::OpArbitraryFloatSubINTEL>{});
+		case Op::OpArbitraryFloatMulINTEL: return func(OpTraits<Op::OpArbitraryFloatMulINTEL>{});
+		case Op::OpArbitraryFloatDivINTEL: return func(OpTraits<Op::OpArbitraryFloatDivINTEL>{});
+		case Op::OpArbitraryFloatGTINTEL: return func(OpTraits<Op::OpArbitraryFloatGTINTEL>{});
+		case Op::OpArbitraryFloatGEINTEL: return func(OpTraits<Op::OpArbitraryFloatGEINTEL>{});
+		case Op::OpArbitraryFloatLTINTEL: return func(OpTraits<Op::OpArbitraryFloatLTINTEL>{});
+		case Op::OpArbitraryFloatLEINTEL: return func(OpTraits<Op::OpArbitraryFloatLEINTEL>{});
+		case Op::OpArbitraryFloatEQINTEL: return func(OpTraits<Op::OpArbitraryFloatEQINTEL>{});
+		case Op::OpArbitraryFloatRecipINTEL: return func(OpTraits<Op::OpArbitraryFloatRecipINTEL>{});
+		case Op::OpArbitraryFloatRSqrtINTEL: return func(OpTraits<Op::OpArbitraryFloatRSqrtINTEL>{});
+		case Op::OpArbitraryFloatCbrtINTEL: return func(OpTraits<Op::OpArbitraryFloatCbrtINTEL>{});
+		case Op::OpArbitraryFloatHypotINTEL: return func(OpTraits<Op::OpArbitraryFloatHypotINTEL>{});
+		case Op::OpArbitraryFloatSqrtINTEL: return func(OpTraits<Op::OpArbitraryFloatSqrtINTEL>{});
+		case Op::OpArbitraryFloatLogINTEL: return func(OpTraits<Op::OpArbitraryFloatLogINTEL>{});
+		case Op::OpArbitraryFloatLog2INTEL: return func(OpTraits<Op::OpArbitraryFloatLog2INTEL>{});
+		case Op::OpArbitraryFloatLog10INTEL: return func(OpTraits<Op::OpArbitraryFloatLog10INTEL>{});
+		case Op::OpArbitraryFloatLog1pINTEL: return func(OpTraits<Op::OpArbitraryFloatLog1pINTEL>{});
+		case Op::OpArbitraryFloatExpINTEL: return func(OpTraits<Op::OpArbitraryFloatExpINTEL>{});
+		case Op::OpArbitraryFloatExp2INTEL: return func(OpTraits<Op::OpArbitraryFloatExp2INTEL>{});
+		case Op::OpArbitraryFloatExp10INTEL: return func(OpTraits<Op::OpArbitraryFloatExp10INTEL>{});
+		case Op::OpArbitraryFloatExpm1INTEL: return func(OpTraits<Op::OpArbitraryFloatExpm1INTEL>{});
+		case Op::OpArbitraryFloatSinINTEL: return func(OpTraits<Op::OpArbitraryFloatSinINTEL>{});
+		case Op::OpArbitraryFloatCosINTEL: return func(OpTraits<Op::OpArbitraryFloatCosINTEL>{});
+		case Op::OpArbitraryFloatSinCosINTEL: return func(OpTraits<Op::OpArbitraryFloatSinCosINTEL>{});
+		case Op::OpArbitraryFloatSinPiINTEL: return func(OpTraits<Op::OpArbitraryFloatSinPiINTEL>{});
+		case Op::OpArbitraryFloatCosPiINTEL: return func(OpTraits<Op::OpArbitraryFloatCosPiINTEL>{});
+		case Op::OpArbitraryFloatASinINTEL: return func(OpTraits<Op::OpArbitraryFloatASinINTEL>{});
+		case Op::OpArbitraryFloatASinPiINTEL: return func(OpTraits<Op::OpArbitraryFloatASinPiINTEL>{});
+		case Op::OpArbitraryFloatACosINTEL: return func(OpTraits<Op::OpArbitraryFloatACosINTEL>{});
+		case Op::OpArbitraryFloatACosPiINTEL: return func(OpTraits<Op::OpArbitraryFloatACosPiINTEL>{});
+		case Op::OpArbitraryFloatATanINTEL: return func(OpTraits<Op::OpArbitraryFloatATanINTEL>{});
+		case Op::OpArbitraryFloatATanPiINTEL: return func(OpTraits<Op::OpArbitraryFloatATanPiINTEL>{});
+		case Op::OpArbitraryFloatATan2INTEL: return func(OpTraits<Op::OpArbitraryFloatATan2INTEL>{});
+		case Op::OpArbitraryFloatPowINTEL: return func(OpTraits<Op::OpArbitraryFloatPowINTEL>{});
+		case Op::OpArbitraryFloatPowRINTEL: return func(OpTraits<Op::OpArbitraryFloatPowRINTEL>{});
+		case Op::OpArbitraryFloatPowNINTEL: return func(OpTraits<Op::OpArbitraryFloatPowNINTEL>{});
+		case Op::OpLoopControlINTEL: return func(OpTraits<Op::OpLoopControlINTEL>{});
+		case Op::OpAliasDomainDeclINTEL: return func(OpTraits<Op::OpAliasDomainDeclINTEL>{});
+		case Op::OpAliasScopeDeclINTEL: return func(OpTraits<Op::OpAliasScopeDeclINTEL>{});
+		case Op::OpAliasScopeListDeclINTEL: return func(OpTraits<Op::OpAliasScopeListDeclINTEL>{});
+		case Op::OpFixedSqrtINTEL: return func(OpTraits<Op::OpFixedSqrtINTEL>{});
+		case Op::OpFixedRecipINTEL: return func(OpTraits<Op::OpFixedRecipINTEL>{});
+		case Op::OpFixedRsqrtINTEL: return func(OpTraits<Op::OpFixedRsqrtINTEL>{});
+		case Op::OpFixedSinINTEL: return func(OpTraits<Op::OpFixedSinINTEL>{});
+		case Op::OpFixedCosINTEL: return func(OpTraits<Op::OpFixedCosINTEL>{});
+		case Op::OpFixedSinCosINTEL: return func(OpTraits<Op::OpFixedSinCosINTEL>{});
+		case Op::OpFixedSinPiINTEL: return func(OpTraits<Op::OpFixedSinPiINTEL>{});
+		case Op::OpFixedCosPiINTEL: return func(OpTraits<Op::OpFixedCosPiINTEL>{});
+		case Op::OpFixedSinCosPiINTEL: return func(OpTraits<Op::OpFixedSinCosPiINTEL>{});
+		case Op::OpFixedLogINTEL: return func(OpTraits<Op::OpFixedLogINTEL>{});
+		case Op::OpFixedExpINTEL: return func(OpTraits<Op::OpFixedExpINTEL>{});
+		case Op::OpPtrCastToCrossWorkgroupINTEL: return func(OpTraits<Op::OpPtrCastToCrossWorkgroupINTEL>{});
+		case Op::OpCrossWorkgroupCastToPtrINTEL: return func(OpTraits<Op::OpCrossWorkgroupCastToPtrINTEL>{});
+		case Op::OpReadPipeBlockingINTEL: return func(OpTraits<Op::OpReadPipeBlockingINTEL>{});
+		case Op::OpWritePipeBlockingINTEL: return func(OpTraits<Op::OpWritePipeBlockingINTEL>{});
+		case Op::OpFPGARegINTEL: return func(OpTraits<Op::OpFPGARegINTEL>{});
+		case Op::OpRayQueryGetRayTMinKHR: return func(OpTraits<Op::OpRayQueryGetRayTMinKHR>{});
+		case Op::OpRayQueryGetRayFlagsKHR: return func(OpTraits<Op::OpRayQueryGetRayFlagsKHR>{});
+		case Op::OpRayQueryGetIntersectionTKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionTKHR>{});
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR>{});
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionInstanceIdKHR>{});
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR>{});
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionGeometryIndexKHR>{});
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionPrimitiveIndexKHR>{});
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionBarycentricsKHR>{});
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionFrontFaceKHR>{});
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR>{});
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionObjectRayDirectionKHR>{});
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionObjectRayOriginKHR>{});
+		case Op::OpRayQueryGetWorldRayDirectionKHR: return func(OpTraits<Op::OpRayQueryGetWorldRayDirectionKHR>{});
+		case Op::OpRayQueryGetWorldRayOriginKHR: return func(OpTraits<Op::OpRayQueryGetWorldRayOriginKHR>{});
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionObjectToWorldKHR>{});
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return func(OpTraits<Op::OpRayQueryGetIntersectionWorldToObjectKHR>{});
+		case Op::OpAtomicFAddEXT: return func(OpTraits<Op::OpAtomicFAddEXT>{});
+		case Op::OpTypeBufferSurfaceINTEL: return func(OpTraits<Op::OpTypeBufferSurfaceINTEL>{});
+		case Op::OpTypeStructContinuedINTEL: return func(OpTraits<Op::OpTypeStructContinuedINTEL>{});
+		case Op::OpConstantCompositeContinuedINTEL: return func(OpTraits<Op::OpConstantCompositeContinuedINTEL>{});
+		case Op::OpSpecConstantCompositeContinuedINTEL: return func(OpTraits<Op::OpSpecConstantCompositeContinuedINTEL>{});
+		case Op::OpConvertFToBF16INTEL: return func(OpTraits<Op::OpConvertFToBF16INTEL>{});
+		case Op::OpConvertBF16ToFINTEL: return func(OpTraits<Op::OpConvertBF16ToFINTEL>{});
+		case Op::OpControlBarrierArriveINTEL: return func(OpTraits<Op::OpControlBarrierArriveINTEL>{});
+		case Op::OpControlBarrierWaitINTEL: return func(OpTraits<Op::OpControlBarrierWaitINTEL>{});
+		case Op::OpGroupIMulKHR: return func(OpTraits<Op::OpGroupIMulKHR>{});
+		case Op::OpGroupFMulKHR: return func(OpTraits<Op::OpGroupFMulKHR>{});
+		case Op::OpGroupBitwiseAndKHR: return func(OpTraits<Op::OpGroupBitwiseAndKHR>{});
+		case Op::OpGroupBitwiseOrKHR: return func(OpTraits<Op::OpGroupBitwiseOrKHR>{});
+		case Op::OpGroupBitwiseXorKHR: return func(OpTraits<Op::OpGroupBitwiseXorKHR>{});
+		case Op::OpGroupLogicalAndKHR: return func(OpTraits<Op::OpGroupLogicalAndKHR>{});
+		case Op::OpGroupLogicalOrKHR: return func(OpTraits<Op::OpGroupLogicalOrKHR>{});
+		case Op::OpGroupLogicalXorKHR: return func(OpTraits<Op::OpGroupLogicalXorKHR>{});
+		default: return func(OpTraits<Op::Max>{});
+		}
+	}
+} // spvgentwo::spv
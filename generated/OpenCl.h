@@ -1,4 +1,6 @@
 // Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// extinst.opencl.std.100.grammar.json sha256:b6be3248af8e6150332ec5d9df2e578b364d588c762533831b7550f607a73ff8 version 100 revision 2
 // Copyright (c) 2014-2016 The Khronos Group Inc.
 // 
 // Permission is hereby granted, free of charge, to any person obtaining a copy
@@ -28,6 +30,7 @@ namespace spvgentwo::opencl100
 {
 	static constexpr unsigned int Version = 100;
 	static constexpr unsigned int Revision = 2;
+	static constexpr const char* ExtInstImportName = "OpenCL.std";
 	enum class Op : unsigned
 	{
 		acos = 0,
@@ -194,4 +197,1308 @@ namespace spvgentwo::opencl100
 		prefetch = 185,
 		Max = 0x7fffffff
 	};
+	static constexpr unsigned int UnboundedWordCount = 0xffffffff;
+	inline constexpr unsigned int MinWordCount(Op opcode) {
+		switch (opcode) {
+		default: return 0u; // unknown opcode
+		case Op::acos: return 1u;
+		case Op::acosh: return 1u;
+		case Op::acospi: return 1u;
+		case Op::asin: return 1u;
+		case Op::asinh: return 1u;
+		case Op::asinpi: return 1u;
+		case Op::atan: return 1u;
+		case Op::atan2: return 2u;
+		case Op::atanh: return 1u;
+		case Op::atanpi: return 1u;
+		case Op::atan2pi: return 2u;
+		case Op::cbrt: return 1u;
+		case Op::ceil: return 1u;
+		case Op::copysign: return 2u;
+		case Op::cos: return 1u;
+		case Op::cosh: return 1u;
+		case Op::cospi: return 1u;
+		case Op::erfc: return 1u;
+		case Op::erf: return 1u;
+		case Op::exp: return 1u;
+		case Op::exp2: return 1u;
+		case Op::exp10: return 1u;
+		case Op::expm1: return 1u;
+		case Op::fabs: return 1u;
+		case Op::fdim: return 2u;
+		case Op::floor: return 1u;
+		case Op::fma: return 3u;
+		case Op::fmax: return 2u;
+		case Op::fmin: return 2u;
+		case Op::fmod: return 2u;
+		case Op::fract: return 2u;
+		case Op::frexp: return 2u;
+		case Op::hypot: return 2u;
+		case Op::ilogb: return 1u;
+		case Op::ldexp: return 2u;
+		case Op::lgamma: return 1u;
+		case Op::lgamma_r: return 2u;
+		case Op::log: return 1u;
+		case Op::log2: return 1u;
+		case Op::log10: return 1u;
+		case Op::log1p: return 1u;
+		case Op::logb: return 1u;
+		case Op::mad: return 3u;
+		case Op::maxmag: return 2u;
+		case Op::minmag: return 2u;
+		case Op::modf: return 2u;
+		case Op::nan: return 1u;
+		case Op::nextafter: return 2u;
+		case Op::pow: return 2u;
+		case Op::pown: return 2u;
+		case Op::powr: return 2u;
+		case Op::remainder: return 2u;
+		case Op::remquo: return 3u;
+		case Op::rint: return 1u;
+		case Op::rootn: return 2u;
+		case Op::round: return 1u;
+		case Op::rsqrt: return 1u;
+		case Op::sin: return 1u;
+		case Op::sincos: return 2u;
+		case Op::sinh: return 1u;
+		case Op::sinpi: return 1u;
+		case Op::sqrt: return 1u;
+		case Op::tan: return 1u;
+		case Op::tanh: return 1u;
+		case Op::tanpi: return 1u;
+		case Op::tgamma: return 1u;
+		case Op::trunc: return 1u;
+		case Op::half_cos: return 1u;
+		case Op::half_divide: return 2u;
+		case Op::half_exp: return 1u;
+		case Op::half_exp2: return 1u;
+		case Op::half_exp10: return 1u;
+		case Op::half_log: return 1u;
+		case Op::half_log2: return 1u;
+		case Op::half_log10: return 1u;
+		case Op::half_powr: return 2u;
+		case Op::half_recip: return 1u;
+		case Op::half_rsqrt: return 1u;
+		case Op::half_sin: return 1u;
+		case Op::half_sqrt: return 1u;
+		case Op::half_tan: return 1u;
+		case Op::native_cos: return 1u;
+		case Op::native_divide: return 2u;
+		case Op::native_exp: return 1u;
+		case Op::native_exp2: return 1u;
+		case Op::native_exp10: return 1u;
+		case Op::native_log: return 1u;
+		case Op::native_log2: return 1u;
+		case Op::native_log10: return 1u;
+		case Op::native_powr: return 2u;
+		case Op::native_recip: return 1u;
+		case Op::native_rsqrt: return 1u;
+		case Op::native_sin: return 1u;
+		case Op::native_sqrt: return 1u;
+		case Op::native_tan: return 1u;
+		case Op::s_abs: return 1u;
+		case Op::s_abs_diff: return 2u;
+		case Op::s_add_sat: return 2u;
+		case Op::u_add_sat: return 2u;
+		case Op::s_hadd: return 2u;
+		case Op::u_hadd: return 2u;
+		case Op::s_rhadd: return 2u;
+		case Op::u_rhadd: return 2u;
+		case Op::s_clamp: return 3u;
+		case Op::u_clamp: return 3u;
+		case Op::clz: return 1u;
+		case Op::ctz: return 1u;
+		case Op::s_mad_hi: return 3u;
+		case Op::u_mad_sat: return 3u;
+		case Op::s_mad_sat: return 3u;
+		case Op::s_max: return 2u;
+		case Op::u_max: return 2u;
+		case Op::s_min: return 2u;
+		case Op::u_min: return 2u;
+		case Op::s_mul_hi: return 2u;
+		case Op::rotate: return 2u;
+		case Op::s_sub_sat: return 2u;
+		case Op::u_sub_sat: return 2u;
+		case Op::u_upsample: return 2u;
+		case Op::s_upsample: return 2u;
+		case Op::popcount: return 1u;
+		case Op::s_mad24: return 3u;
+		case Op::u_mad24: return 3u;
+		case Op::s_mul24: return 2u;
+		case Op::u_mul24: return 2u;
+		case Op::u_abs: return 1u;
+		case Op::u_abs_diff: return 2u;
+		case Op::u_mul_hi: return 2u;
+		case Op::u_mad_hi: return 3u;
+		case Op::fclamp: return 3u;
+		case Op::degrees: return 1u;
+		case Op::fmax_common: return 2u;
+		case Op::fmin_common: return 2u;
+		case Op::mix: return 3u;
+		case Op::radians: return 1u;
+		case Op::step: return 2u;
+		case Op::smoothstep: return 3u;
+		case Op::sign: return 1u;
+		case Op::cross: return 2u;
+		case Op::distance: return 2u;
+		case Op::length: return 1u;
+		case Op::normalize: return 1u;
+		case Op::fast_distance: return 2u;
+		case Op::fast_length: return 1u;
+		case Op::fast_normalize: return 1u;
+		case Op::bitselect: return 3u;
+		case Op::select: return 3u;
+		case Op::vloadn: return 3u;
+		case Op::vstoren: return 3u;
+		case Op::vload_half: return 2u;
+		case Op::vload_halfn: return 3u;
+		case Op::vstore_half: return 3u;
+		case Op::vstore_half_r: return 4u;
+		case Op::vstore_halfn: return 3u;
+		case Op::vstore_halfn_r: return 4u;
+		case Op::vloada_halfn: return 3u;
+		case Op::vstorea_halfn: return 3u;
+		case Op::vstorea_halfn_r: return 4u;
+		case Op::shuffle: return 2u;
+		case Op::shuffle2: return 3u;
+		case Op::printf: return 1u;
+		case Op::prefetch: return 2u;
+		}
+	}
+	inline constexpr unsigned int MaxWordCount(Op opcode) {
+		switch (opcode) {
+		default: return UnboundedWordCount; // unknown opcode
+		case Op::acos: return 1u;
+		case Op::acosh: return 1u;
+		case Op::acospi: return 1u;
+		case Op::asin: return 1u;
+		case Op::asinh: return 1u;
+		case Op::asinpi: return 1u;
+		case Op::atan: return 1u;
+		case Op::atan2: return 2u;
+		case Op::atanh: return 1u;
+		case Op::atanpi: return 1u;
+		case Op::atan2pi: return 2u;
+		case Op::cbrt: return 1u;
+		case Op::ceil: return 1u;
+		case Op::copysign: return 2u;
+		case Op::cos: return 1u;
+		case Op::cosh: return 1u;
+		case Op::cospi: return 1u;
+		case Op::erfc: return 1u;
+		case Op::erf: return 1u;
+		case Op::exp: return 1u;
+		case Op::exp2: return 1u;
+		case Op::exp10: return 1u;
+		case Op::expm1: return 1u;
+		case Op::fabs: return 1u;
+		case Op::fdim: return 2u;
+		case Op::floor: return 1u;
+		case Op::fma: return 3u;
+		case Op::fmax: return 2u;
+		case Op::fmin: return 2u;
+		case Op::fmod: return 2u;
+		case Op::fract: return 2u;
+		case Op::frexp: return 2u;
+		case Op::hypot: return 2u;
+		case Op::ilogb: return 1u;
+		case Op::ldexp: return 2u;
+		case Op::lgamma: return 1u;
+		case Op::lgamma_r: return 2u;
+		case Op::log: return 1u;
+		case Op::log2: return 1u;
+		case Op::log10: return 1u;
+		case Op::log1p: return 1u;
+		case Op::logb: return 1u;
+		case Op::mad: return 3u;
+		case Op::maxmag: return 2u;
+		case Op::minmag: return 2u;
+		case Op::modf: return 2u;
+		case Op::nan: return 1u;
+		case Op::nextafter: return 2u;
+		case Op::pow: return 2u;
+		case Op::pown: return 2u;
+		case Op::powr: return 2u;
+		case Op::remainder: return 2u;
+		case Op::remquo: return 3u;
+		case Op::rint: return 1u;
+		case Op::rootn: return 2u;
+		case Op::round: return 1u;
+		case Op::rsqrt: return 1u;
+		case Op::sin: return 1u;
+		case Op::sincos: return 2u;
+		case Op::sinh: return 1u;
+		case Op::sinpi: return 1u;
+		case Op::sqrt: return 1u;
+		case Op::tan: return 1u;
+		case Op::tanh: return 1u;
+		case Op::tanpi: return 1u;
+		case Op::tgamma: return 1u;
+		case Op::trunc: return 1u;
+		case Op::half_cos: return 1u;
+		case Op::half_divide: return 2u;
+		case Op::half_exp: return 1u;
+		case Op::half_exp2: return 1u;
+		case Op::half_exp10: return 1u;
+		case Op::half_log: return 1u;
+		case Op::half_log2: return 1u;
+		case Op::half_log10: return 1u;
+		case Op::half_powr: return 2u;
+		case Op::half_recip: return 1u;
+		case Op::half_rsqrt: return 1u;
+		case Op::half_sin: return 1u;
+		case Op::half_sqrt: return 1u;
+		case Op::half_tan: return 1u;
+		case Op::native_cos: return 1u;
+		case Op::native_divide: return 2u;
+		case Op::native_exp: return 1u;
+		case Op::native_exp2: return 1u;
+		case Op::native_exp10: return 1u;
+		case Op::native_log: return 1u;
+		case Op::native_log2: return 1u;
+		case Op::native_log10: return 1u;
+		case Op::native_powr: return 2u;
+		case Op::native_recip: return 1u;
+		case Op::native_rsqrt: return 1u;
+		case Op::native_sin: return 1u;
+		case Op::native_sqrt: return 1u;
+		case Op::native_tan: return 1u;
+		case Op::s_abs: return 1u;
+		case Op::s_abs_diff: return 2u;
+		case Op::s_add_sat: return 2u;
+		case Op::u_add_sat: return 2u;
+		case Op::s_hadd: return 2u;
+		case Op::u_hadd: return 2u;
+		case Op::s_rhadd: return 2u;
+		case Op::u_rhadd: return 2u;
+		case Op::s_clamp: return 3u;
+		case Op::u_clamp: return 3u;
+		case Op::clz: return 1u;
+		case Op::ctz: return 1u;
+		case Op::s_mad_hi: return 3u;
+		case Op::u_mad_sat: return 3u;
+		case Op::s_mad_sat: return 3u;
+		case Op::s_max: return 2u;
+		case Op::u_max: return 2u;
+		case Op::s_min: return 2u;
+		case Op::u_min: return 2u;
+		case Op::s_mul_hi: return 2u;
+		case Op::rotate: return 2u;
+		case Op::s_sub_sat: return 2u;
+		case Op::u_sub_sat: return 2u;
+		case Op::u_upsample: return 2u;
+		case Op::s_upsample: return 2u;
+		case Op::popcount: return 1u;
+		case Op::s_mad24: return 3u;
+		case Op::u_mad24: return 3u;
+		case Op::s_mul24: return 2u;
+		case Op::u_mul24: return 2u;
+		case Op::u_abs: return 1u;
+		case Op::u_abs_diff: return 2u;
+		case Op::u_mul_hi: return 2u;
+		case Op::u_mad_hi: return 3u;
+		case Op::fclamp: return 3u;
+		case Op::degrees: return 1u;
+		case Op::fmax_common: return 2u;
+		case Op::fmin_common: return 2u;
+		case Op::mix: return 3u;
+		case Op::radians: return 1u;
+		case Op::step: return 2u;
+		case Op::smoothstep: return 3u;
+		case Op::sign: return 1u;
+		case Op::cross: return 2u;
+		case Op::distance: return 2u;
+		case Op::length: return 1u;
+		case Op::normalize: return 1u;
+		case Op::fast_distance: return 2u;
+		case Op::fast_length: return 1u;
+		case Op::fast_normalize: return 1u;
+		case Op::bitselect: return 3u;
+		case Op::select: return 3u;
+		case Op::vloadn: return 3u;
+		case Op::vstoren: return 3u;
+		case Op::vload_half: return 2u;
+		case Op::vload_halfn: return 3u;
+		case Op::vstore_half: return 3u;
+		case Op::vstore_half_r: return 4u;
+		case Op::vstore_halfn: return 3u;
+		case Op::vstore_halfn_r: return 4u;
+		case Op::vloada_halfn: return 3u;
+		case Op::vstorea_halfn: return 3u;
+		case Op::vstorea_halfn_r: return 4u;
+		case Op::shuffle: return 2u;
+		case Op::shuffle2: return 3u;
+		case Op::printf: return UnboundedWordCount;
+		case Op::prefetch: return 2u;
+		}
+	}
+	inline constexpr const char* ToString(Op opcode) {
+		switch (opcode) {
+		default: return nullptr; // unknown opcode
+		case Op::acos: return "acos";
+		case Op::acosh: return "acosh";
+		case Op::acospi: return "acospi";
+		case Op::asin: return "asin";
+		case Op::asinh: return "asinh";
+		case Op::asinpi: return "asinpi";
+		case Op::atan: return "atan";
+		case Op::atan2: return "atan2";
+		case Op::atanh: return "atanh";
+		case Op::atanpi: return "atanpi";
+		case Op::atan2pi: return "atan2pi";
+		case Op::cbrt: return "cbrt";
+		case Op::ceil: return "ceil";
+		case Op::copysign: return "copysign";
+		case Op::cos: return "cos";
+		case Op::cosh: return "cosh";
+		case Op::cospi: return "cospi";
+		case Op::erfc: return "erfc";
+		case Op::erf: return "erf";
+		case Op::exp: return "exp";
+		case Op::exp2: return "exp2";
+		case Op::exp10: return "exp10";
+		case Op::expm1: return "expm1";
+		case Op::fabs: return "fabs";
+		case Op::fdim: return "fdim";
+		case Op::floor: return "floor";
+		case Op::fma: return "fma";
+		case Op::fmax: return "fmax";
+		case Op::fmin: return "fmin";
+		case Op::fmod: return "fmod";
+		case Op::fract: return "fract";
+		case Op::frexp: return "frexp";
+		case Op::hypot: return "hypot";
+		case Op::ilogb: return "ilogb";
+		case Op::ldexp: return "ldexp";
+		case Op::lgamma: return "lgamma";
+		case Op::lgamma_r: return "lgamma_r";
+		case Op::log: return "log";
+		case Op::log2: return "log2";
+		case Op::log10: return "log10";
+		case Op::log1p: return "log1p";
+		case Op::logb: return "logb";
+		case Op::mad: return "mad";
+		case Op::maxmag: return "maxmag";
+		case Op::minmag: return "minmag";
+		case Op::modf: return "modf";
+		case Op::nan: return "nan";
+		case Op::nextafter: return "nextafter";
+		case Op::pow: return "pow";
+		case Op::pown: return "pown";
+		case Op::powr: return "powr";
+		case Op::remainder: return "remainder";
+		case Op::remquo: return "remquo";
+		case Op::rint: return "rint";
+		case Op::rootn: return "rootn";
+		case Op::round: return "round";
+		case Op::rsqrt: return "rsqrt";
+		case Op::sin: return "sin";
+		case Op::sincos: return "sincos";
+		case Op::sinh: return "sinh";
+		case Op::sinpi: return "sinpi";
+		case Op::sqrt: return "sqrt";
+		case Op::tan: return "tan";
+		case Op::tanh: return "tanh";
+		case Op::tanpi: return "tanpi";
+		case Op::tgamma: return "tgamma";
+		case Op::trunc: return "trunc";
+		case Op::half_cos: return "half_cos";
+		case Op::half_divide: return "half_divide";
+		case Op::half_exp: return "half_exp";
+		case Op::half_exp2: return "half_exp2";
+		case Op::half_exp10: return "half_exp10";
+		case Op::half_log: return "half_log";
+		case Op::half_log2: return "half_log2";
+		case Op::half_log10: return "half_log10";
+		case Op::half_powr: return "half_powr";
+		case Op::half_recip: return "half_recip";
+		case Op::half_rsqrt: return "half_rsqrt";
+		case Op::half_sin: return "half_sin";
+		case Op::half_sqrt: return "half_sqrt";
+		case Op::half_tan: return "half_tan";
+		case Op::native_cos: return "native_cos";
+		case Op::native_divide: return "native_divide";
+		case Op::native_exp: return "native_exp";
+		case Op::native_exp2: return "native_exp2";
+		case Op::native_exp10: return "native_exp10";
+		case Op::native_log: return "native_log";
+		case Op::native_log2: return "native_log2";
+		case Op::native_log10: return "native_log10";
+		case Op::native_powr: return "native_powr";
+		case Op::native_recip: return "native_recip";
+		case Op::native_rsqrt: return "native_rsqrt";
+		case Op::native_sin: return "native_sin";
+		case Op::native_sqrt: return "native_sqrt";
+		case Op::native_tan: return "native_tan";
+		case Op::s_abs: return "s_abs";
+		case Op::s_abs_diff: return "s_abs_diff";
+		case Op::s_add_sat: return "s_add_sat";
+		case Op::u_add_sat: return "u_add_sat";
+		case Op::s_hadd: return "s_hadd";
+		case Op::u_hadd: return "u_hadd";
+		case Op::s_rhadd: return "s_rhadd";
+		case Op::u_rhadd: return "u_rhadd";
+		case Op::s_clamp: return "s_clamp";
+		case Op::u_clamp: return "u_clamp";
+		case Op::clz: return "clz";
+		case Op::ctz: return "ctz";
+		case Op::s_mad_hi: return "s_mad_hi";
+		case Op::u_mad_sat: return "u_mad_sat";
+		case Op::s_mad_sat: return "s_mad_sat";
+		case Op::s_max: return "s_max";
+		case Op::u_max: return "u_max";
+		case Op::s_min: return "s_min";
+		case Op::u_min: return "u_min";
+		case Op::s_mul_hi: return "s_mul_hi";
+		case Op::rotate: return "rotate";
+		case Op::s_sub_sat: return "s_sub_sat";
+		case Op::u_sub_sat: return "u_sub_sat";
+		case Op::u_upsample: return "u_upsample";
+		case Op::s_upsample: return "s_upsample";
+		case Op::popcount: return "popcount";
+		case Op::s_mad24: return "s_mad24";
+		case Op::u_mad24: return "u_mad24";
+		case Op::s_mul24: return "s_mul24";
+		case Op::u_mul24: return "u_mul24";
+		case Op::u_abs: return "u_abs";
+		case Op::u_abs_diff: return "u_abs_diff";
+		case Op::u_mul_hi: return "u_mul_hi";
+		case Op::u_mad_hi: return "u_mad_hi";
+		case Op::fclamp: return "fclamp";
+		case Op::degrees: return "degrees";
+		case Op::fmax_common: return "fmax_common";
+		case Op::fmin_common: return "fmin_common";
+		case Op::mix: return "mix";
+		case Op::radians: return "radians";
+		case Op::step: return "step";
+		case Op::smoothstep: return "smoothstep";
+		case Op::sign: return "sign";
+		case Op::cross: return "cross";
+		case Op::distance: return "distance";
+		case Op::length: return "length";
+		case Op::normalize: return "normalize";
+		case Op::fast_distance: return "fast_distance";
+		case Op::fast_length: return "fast_length";
+		case Op::fast_normalize: return "fast_normalize";
+		case Op::bitselect: return "bitselect";
+		case Op::select: return "select";
+		case Op::vloadn: return "vloadn";
+		case Op::vstoren: return "vstoren";
+		case Op::vload_half: return "vload_half";
+		case Op::vload_halfn: return "vload_halfn";
+		case Op::vstore_half: return "vstore_half";
+		case Op::vstore_half_r: return "vstore_half_r";
+		case Op::vstore_halfn: return "vstore_halfn";
+		case Op::vstore_halfn_r: return "vstore_halfn_r";
+		case Op::vloada_halfn: return "vloada_halfn";
+		case Op::vstorea_halfn: return "vstorea_halfn";
+		case Op::vstorea_halfn_r: return "vstorea_halfn_r";
+		case Op::shuffle: return "shuffle";
+		case Op::shuffle2: return "shuffle2";
+		case Op::printf: return "printf";
+		case Op::prefetch: return "prefetch";
+		}
+	}
+	enum class OperandKind : unsigned short
+	{
+		IdRef,
+		LiteralInteger,
+		FPRoundingMode,
+	};
+	enum class Quantifier : unsigned short
+	{
+		ZeroOrOne, // zero or one
+		ZeroOrAny, // zero or any
+		One, // exactly once
+	};
+	struct Operand
+	{
+		OperandKind kind;
+		Quantifier quantifier;
+		const char* name;
+	};
+	struct OperandSpan
+	{
+		const Operand* operands;
+		unsigned int count;
+		constexpr const Operand* begin() const { return operands; }
+		constexpr const Operand* end() const { return operands + count; }
+	};
+	static constexpr Operand Operands[] =
+	{
+		// acos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// acosh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// acospi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// asin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// asinh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// asinpi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// atan
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// atan2
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// atanh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// atanpi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// atan2pi
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// cbrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// ceil
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// copysign
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// cos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// cosh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// cospi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// erfc
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// erf
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// exp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// exp2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// exp10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// expm1
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// fabs
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// fdim
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// floor
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// fma
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// fmax
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// fmin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// fmod
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// fract
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "ptr" },
+		// frexp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "exp" },
+		// hypot
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// ilogb
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// ldexp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "k" },
+		// lgamma
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// lgamma_r
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "signp" },
+		// log
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// log2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// log10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// log1p
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// logb
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// mad
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// maxmag
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// minmag
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// modf
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "iptr" },
+		// nan
+		{ OperandKind::IdRef, Quantifier::One, "nancode" },
+		// nextafter
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// pow
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// pown
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// powr
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// remainder
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// remquo
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "quo" },
+		// rint
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// rootn
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// round
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// rsqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// sin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// sincos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "cosval" },
+		// sinh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// sinpi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// sqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// tan
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// tanh
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// tanpi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// tgamma
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// trunc
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_cos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_divide
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// half_exp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_exp2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_exp10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_log
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_log2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_log10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_powr
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// half_recip
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_rsqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_sin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_sqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// half_tan
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_cos
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_divide
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// native_exp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_exp2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_exp10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_log
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_log2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_log10
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_powr
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// native_recip
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_rsqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_sin
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_sqrt
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// native_tan
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// s_abs
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// s_abs_diff
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_add_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_add_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_hadd
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_hadd
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_rhadd
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_rhadd
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_clamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minval" },
+		{ OperandKind::IdRef, Quantifier::One, "maxval" },
+		// u_clamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minval" },
+		{ OperandKind::IdRef, Quantifier::One, "maxval" },
+		// clz
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// ctz
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// s_mad_hi
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// u_mad_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "z" },
+		// s_mad_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "z" },
+		// s_max
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_max
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_min
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_min
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// s_mul_hi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// rotate
+		{ OperandKind::IdRef, Quantifier::One, "v" },
+		{ OperandKind::IdRef, Quantifier::One, "i" },
+		// s_sub_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_sub_sat
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_upsample
+		{ OperandKind::IdRef, Quantifier::One, "hi" },
+		{ OperandKind::IdRef, Quantifier::One, "lo" },
+		// s_upsample
+		{ OperandKind::IdRef, Quantifier::One, "hi" },
+		{ OperandKind::IdRef, Quantifier::One, "lo" },
+		// popcount
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// s_mad24
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "z" },
+		// u_mad24
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "z" },
+		// s_mul24
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_mul24
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_abs
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// u_abs_diff
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_mul_hi
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// u_mad_hi
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// fclamp
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "minval" },
+		{ OperandKind::IdRef, Quantifier::One, "maxval" },
+		// degrees
+		{ OperandKind::IdRef, Quantifier::One, "radians" },
+		// fmax_common
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// fmin_common
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		// mix
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		// radians
+		{ OperandKind::IdRef, Quantifier::One, "degrees" },
+		// step
+		{ OperandKind::IdRef, Quantifier::One, "edge" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// smoothstep
+		{ OperandKind::IdRef, Quantifier::One, "edge0" },
+		{ OperandKind::IdRef, Quantifier::One, "edge1" },
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// sign
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		// cross
+		{ OperandKind::IdRef, Quantifier::One, "p0" },
+		{ OperandKind::IdRef, Quantifier::One, "p1" },
+		// distance
+		{ OperandKind::IdRef, Quantifier::One, "p0" },
+		{ OperandKind::IdRef, Quantifier::One, "p1" },
+		// length
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// normalize
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// fast_distance
+		{ OperandKind::IdRef, Quantifier::One, "p0" },
+		{ OperandKind::IdRef, Quantifier::One, "p1" },
+		// fast_length
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// fast_normalize
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// bitselect
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// select
+		{ OperandKind::IdRef, Quantifier::One, "a" },
+		{ OperandKind::IdRef, Quantifier::One, "b" },
+		{ OperandKind::IdRef, Quantifier::One, "c" },
+		// vloadn
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::LiteralInteger, Quantifier::One, "n" },
+		// vstoren
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// vload_half
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// vload_halfn
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::LiteralInteger, Quantifier::One, "n" },
+		// vstore_half
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// vstore_half_r
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::FPRoundingMode, Quantifier::One, "mode" },
+		// vstore_halfn
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// vstore_halfn_r
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::FPRoundingMode, Quantifier::One, "mode" },
+		// vloada_halfn
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::LiteralInteger, Quantifier::One, "n" },
+		// vstorea_halfn
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		// vstorea_halfn_r
+		{ OperandKind::IdRef, Quantifier::One, "data" },
+		{ OperandKind::IdRef, Quantifier::One, "offset" },
+		{ OperandKind::IdRef, Quantifier::One, "p" },
+		{ OperandKind::FPRoundingMode, Quantifier::One, "mode" },
+		// shuffle
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "shuffle mask" },
+		// shuffle2
+		{ OperandKind::IdRef, Quantifier::One, "x" },
+		{ OperandKind::IdRef, Quantifier::One, "y" },
+		{ OperandKind::IdRef, Quantifier::One, "shuffle mask" },
+		// printf
+		{ OperandKind::IdRef, Quantifier::One, "format" },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny, "additional arguments" },
+		// prefetch
+		{ OperandKind::IdRef, Quantifier::One, "ptr" },
+		{ OperandKind::IdRef, Quantifier::One, "num elements" },
+	};
+	inline constexpr OperandSpan GetOperands(Op opcode) {
+		switch (opcode) {
+		default: return { Operands, 0u }; // unknown opcode
+		case Op::acos: return { Operands + 0, 1u };
+		case Op::acosh: return { Operands + 1, 1u };
+		case Op::acospi: return { Operands + 2, 1u };
+		case Op::asin: return { Operands + 3, 1u };
+		case Op::asinh: return { Operands + 4, 1u };
+		case Op::asinpi: return { Operands + 5, 1u };
+		case Op::atan: return { Operands + 6, 1u };
+		case Op::atan2: return { Operands + 7, 2u };
+		case Op::atanh: return { Operands + 9, 1u };
+		case Op::atanpi: return { Operands + 10, 1u };
+		case Op::atan2pi: return { Operands + 11, 2u };
+		case Op::cbrt: return { Operands + 13, 1u };
+		case Op::ceil: return { Operands + 14, 1u };
+		case Op::copysign: return { Operands + 15, 2u };
+		case Op::cos: return { Operands + 17, 1u };
+		case Op::cosh: return { Operands + 18, 1u };
+		case Op::cospi: return { Operands + 19, 1u };
+		case Op::erfc: return { Operands + 20, 1u };
+		case Op::erf: return { Operands + 21, 1u };
+		case Op::exp: return { Operands + 22, 1u };
+		case Op::exp2: return { Operands + 23, 1u };
+		case Op::exp10: return { Operands + 24, 1u };
+		case Op::expm1: return { Operands + 25, 1u };
+		case Op::fabs: return { Operands + 26, 1u };
+		case Op::fdim: return { Operands + 27, 2u };
+		case Op::floor: return { Operands + 29, 1u };
+		case Op::fma: return { Operands + 30, 3u };
+		case Op::fmax: return { Operands + 33, 2u };
+		case Op::fmin: return { Operands + 35, 2u };
+		case Op::fmod: return { Operands + 37, 2u };
+		case Op::fract: return { Operands + 39, 2u };
+		case Op::frexp: return { Operands + 41, 2u };
+		case Op::hypot: return { Operands + 43, 2u };
+		case Op::ilogb: return { Operands + 45, 1u };
+		case Op::ldexp: return { Operands + 46, 2u };
+		case Op::lgamma: return { Operands + 48, 1u };
+		case Op::lgamma_r: return { Operands + 49, 2u };
+		case Op::log: return { Operands + 51, 1u };
+		case Op::log2: return { Operands + 52, 1u };
+		case Op::log10: return { Operands + 53, 1u };
+		case Op::log1p: return { Operands + 54, 1u };
+		case Op::logb: return { Operands + 55, 1u };
+		case Op::mad: return { Operands + 56, 3u };
+		case Op::maxmag: return { Operands + 59, 2u };
+		case Op::minmag: return { Operands + 61, 2u };
+		case Op::modf: return { Operands + 63, 2u };
+		case Op::nan: return { Operands + 65, 1u };
+		case Op::nextafter: return { Operands + 66, 2u };
+		case Op::pow: return { Operands + 68, 2u };
+		case Op::pown: return { Operands + 70, 2u };
+		case Op::powr: return { Operands + 72, 2u };
+		case Op::remainder: return { Operands + 74, 2u };
+		case Op::remquo: return { Operands + 76, 3u };
+		case Op::rint: return { Operands + 79, 1u };
+		case Op::rootn: return { Operands + 80, 2u };
+		case Op::round: return { Operands + 82, 1u };
+		case Op::rsqrt: return { Operands + 83, 1u };
+		case Op::sin: return { Operands + 84, 1u };
+		case Op::sincos: return { Operands + 85, 2u };
+		case Op::sinh: return { Operands + 87, 1u };
+		case Op::sinpi: return { Operands + 88, 1u };
+		case Op::sqrt: return { Operands + 89, 1u };
+		case Op::tan: return { Operands + 90, 1u };
+		case Op::tanh: return { Operands + 91, 1u };
+		case Op::tanpi: return { Operands + 92, 1u };
+		case Op::tgamma: return { Operands + 93, 1u };
+		case Op::trunc: return { Operands + 94, 1u };
+		case Op::half_cos: return { Operands + 95, 1u };
+		case Op::half_divide: return { Operands + 96, 2u };
+		case Op::half_exp: return { Operands + 98, 1u };
+		case Op::half_exp2: return { Operands + 99, 1u };
+		case Op::half_exp10: return { Operands + 100, 1u };
+		case Op::half_log: return { Operands + 101, 1u };
+		case Op::half_log2: return { Operands + 102, 1u };
+		case Op::half_log10: return { Operands + 103, 1u };
+		case Op::half_powr: return { Operands + 104, 2u };
+		case Op::half_recip: return { Operands + 106, 1u };
+		case Op::half_rsqrt: return { Operands + 107, 1u };
+		case Op::half_sin: return { Operands + 108, 1u };
+		case Op::half_sqrt: return { Operands + 109, 1u };
+		case Op::half_tan: return { Operands + 110, 1u };
+		case Op::native_cos: return { Operands + 111, 1u };
+		case Op::native_divide: return { Operands + 112, 2u };
+		case Op::native_exp: return { Operands + 114, 1u };
+		case Op::native_exp2: return { Operands + 115, 1u };
+		case Op::native_exp10: return { Operands + 116, 1u };
+		case Op::native_log: return { Operands + 117, 1u };
+		case Op::native_log2: return { Operands + 118, 1u };
+		case Op::native_log10: return { Operands + 119, 1u };
+		case Op::native_powr: return { Operands + 120, 2u };
+		case Op::native_recip: return { Operands + 122, 1u };
+		case Op::native_rsqrt: return { Operands + 123, 1u };
+		case Op::native_sin: return { Operands + 124, 1u };
+		case Op::native_sqrt: return { Operands + 125, 1u };
+		case Op::native_tan: return { Operands + 126, 1u };
+		case Op::s_abs: return { Operands + 127, 1u };
+		case Op::s_abs_diff: return { Operands + 128, 2u };
+		case Op::s_add_sat: return { Operands + 130, 2u };
+		case Op::u_add_sat: return { Operands + 132, 2u };
+		case Op::s_hadd: return { Operands + 134, 2u };
+		case Op::u_hadd: return { Operands + 136, 2u };
+		case Op::s_rhadd: return { Operands + 138, 2u };
+		case Op::u_rhadd: return { Operands + 140, 2u };
+		case Op::s_clamp: return { Operands + 142, 3u };
+		case Op::u_clamp: return { Operands + 145, 3u };
+		case Op::clz: return { Operands + 148, 1u };
+		case Op::ctz: return { Operands + 149, 1u };
+		case Op::s_mad_hi: return { Operands + 150, 3u };
+		case Op::u_mad_sat: return { Operands + 153, 3u };
+		case Op::s_mad_sat: return { Operands + 156, 3u };
+		case Op::s_max: return { Operands + 159, 2u };
+		case Op::u_max: return { Operands + 161, 2u };
+		case Op::s_min: return { Operands + 163, 2u };
+		case Op::u_min: return { Operands + 165, 2u };
+		case Op::s_mul_hi: return { Operands + 167, 2u };
+		case Op::rotate: return { Operands + 169, 2u };
+		case Op::s_sub_sat: return { Operands + 171, 2u };
+		case Op::u_sub_sat: return { Operands + 173, 2u };
+		case Op::u_upsample: return { Operands + 175, 2u };
+		case Op::s_upsample: return { Operands + 177, 2u };
+		case Op::popcount: return { Operands + 179, 1u };
+		case Op::s_mad24: return { Operands + 180, 3u };
+		case Op::u_mad24: return { Operands + 183, 3u };
+		case Op::s_mul24: return { Operands + 186, 2u };
+		case Op::u_mul24: return { Operands + 188, 2u };
+		case Op::u_abs: return { Operands + 190, 1u };
+		case Op::u_abs_diff: return { Operands + 191, 2u };
+		case Op::u_mul_hi: return { Operands + 193, 2u };
+		case Op::u_mad_hi: return { Operands + 195, 3u };
+		case Op::fclamp: return { Operands + 198, 3u };
+		case Op::degrees: return { Operands + 201, 1u };
+		case Op::fmax_common: return { Operands + 202, 2u };
+		case Op::fmin_common: return { Operands + 204, 2u };
+		case Op::mix: return { Operands + 206, 3u };
+		case Op::radians: return { Operands + 209, 1u };
+		case Op::step: return { Operands + 210, 2u };
+		case Op::smoothstep: return { Operands + 212, 3u };
+		case Op::sign: return { Operands + 215, 1u };
+		case Op::cross: return { Operands + 216, 2u };
+		case Op::distance: return { Operands + 218, 2u };
+		case Op::length: return { Operands + 220, 1u };
+		case Op::normalize: return { Operands + 221, 1u };
+		case Op::fast_distance: return { Operands + 222, 2u };
+		case Op::fast_length: return { Operands + 224, 1u };
+		case Op::fast_normalize: return { Operands + 225, 1u };
+		case Op::bitselect: return { Operands + 226, 3u };
+		case Op::select: return { Operands + 229, 3u };
+		case Op::vloadn: return { Operands + 232, 3u };
+		case Op::vstoren: return { Operands + 235, 3u };
+		case Op::vload_half: return { Operands + 238, 2u };
+		case Op::vload_halfn: return { Operands + 240, 3u };
+		case Op::vstore_half: return { Operands + 243, 3u };
+		case Op::vstore_half_r: return { Operands + 246, 4u };
+		case Op::vstore_halfn: return { Operands + 250, 3u };
+		case Op::vstore_halfn_r: return { Operands + 253, 4u };
+		case Op::vloada_halfn: return { Operands + 257, 3u };
+		case Op::vstorea_halfn: return { Operands + 260, 3u };
+		case Op::vstorea_halfn_r: return { Operands + 263, 4u };
+		case Op::shuffle: return { Operands + 267, 2u };
+		case Op::shuffle2: return { Operands + 269, 3u };
+		case Op::printf: return { Operands + 272, 2u };
+		case Op::prefetch: return { Operands + 274, 2u };
+		}
+	}
+	// number of operands listed in the grammar, optional and variadic ones count once
+	inline constexpr unsigned int OperandCount(Op opcode) { return GetOperands(opcode).count; }
+	// true if every call passes exactly OperandCount(opcode) operands, false for optional or variadic operands and unknown opcodes
+	inline constexpr bool HasFixedArity(Op opcode) {
+		switch (opcode) {
+		default: return false; // unknown opcode
+		case Op::acos: return true;
+		case Op::acosh: return true;
+		case Op::acospi: return true;
+		case Op::asin: return true;
+		case Op::asinh: return true;
+		case Op::asinpi: return true;
+		case Op::atan: return true;
+		case Op::atan2: return true;
+		case Op::atanh: return true;
+		case Op::atanpi: return true;
+		case Op::atan2pi: return true;
+		case Op::cbrt: return true;
+		case Op::ceil: return true;
+		case Op::copysign: return true;
+		case Op::cos: return true;
+		case Op::cosh: return true;
+		case Op::cospi: return true;
+		case Op::erfc: return true;
+		case Op::erf: return true;
+		case Op::exp: return true;
+		case Op::exp2: return true;
+		case Op::exp10: return true;
+		case Op::expm1: return true;
+		case Op::fabs: return true;
+		case Op::fdim: return true;
+		case Op::floor: return true;
+		case Op::fma: return true;
+		case Op::fmax: return true;
+		case Op::fmin: return true;
+		case Op::fmod: return true;
+		case Op::fract: return true;
+		case Op::frexp: return true;
+		case Op::hypot: return true;
+		case Op::ilogb: return true;
+		case Op::ldexp: return true;
+		case Op::lgamma: return true;
+		case Op::lgamma_r: return true;
+		case Op::log: return true;
+		case Op::log2: return true;
+		case Op::log10: return true;
+		case Op::log1p: return true;
+		case Op::logb: return true;
+		case Op::mad: return true;
+		case Op::maxmag: return true;
+		case Op::minmag: return true;
+		case Op::modf: return true;
+		case Op::nan: return true;
+		case Op::nextafter: return true;
+		case Op::pow: return true;
+		case Op::pown: return true;
+		case Op::powr: return true;
+		case Op::remainder: return true;
+		case Op::remquo: return true;
+		case Op::rint: return true;
+		case Op::rootn: return true;
+		case Op::round: return true;
+		case Op::rsqrt: return true;
+		case Op::sin: return true;
+		case Op::sincos: return true;
+		case Op::sinh: return true;
+		case Op::sinpi: return true;
+		case Op::sqrt: return true;
+		case Op::tan: return true;
+		case Op::tanh: return true;
+		case Op::tanpi: return true;
+		case Op::tgamma: return true;
+		case Op::trunc: return true;
+		case Op::half_cos: return true;
+		case Op::half_divide: return true;
+		case Op::half_exp: return true;
+		case Op::half_exp2: return true;
+		case Op::half_exp10: return true;
+		case Op::half_log: return true;
+		case Op::half_log2: return true;
+		case Op::half_log10: return true;
+		case Op::half_powr: return true;
+		case Op::half_recip: return true;
+		case Op::half_rsqrt: return true;
+		case Op::half_sin: return true;
+		case Op::half_sqrt: return true;
+		case Op::half_tan: return true;
+		case Op::native_cos: return true;
+		case Op::native_divide: return true;
+		case Op::native_exp: return true;
+		case Op::native_exp2: return true;
+		case Op::native_exp10: return true;
+		case Op::native_log: return true;
+		case Op::native_log2: return true;
+		case Op::native_log10: return true;
+		case Op::native_powr: return true;
+		case Op::native_recip: return true;
+		case Op::native_rsqrt: return true;
+		case Op::native_sin: return true;
+		case Op::native_sqrt: return true;
+		case Op::native_tan: return true;
+		case Op::s_abs: return true;
+		case Op::s_abs_diff: return true;
+		case Op::s_add_sat: return true;
+		case Op::u_add_sat: return true;
+		case Op::s_hadd: return true;
+		case Op::u_hadd: return true;
+		case Op::s_rhadd: return true;
+		case Op::u_rhadd: return true;
+		case Op::s_clamp: return true;
+		case Op::u_clamp: return true;
+		case Op::clz: return true;
+		case Op::ctz: return true;
+		case Op::s_mad_hi: return true;
+		case Op::u_mad_sat: return true;
+		case Op::s_mad_sat: return true;
+		case Op::s_max: return true;
+		case Op::u_max: return true;
+		case Op::s_min: return true;
+		case Op::u_min: return true;
+		case Op::s_mul_hi: return true;
+		case Op::rotate: return true;
+		case Op::s_sub_sat: return true;
+		case Op::u_sub_sat: return true;
+		case Op::u_upsample: return true;
+		case Op::s_upsample: return true;
+		case Op::popcount: return true;
+		case Op::s_mad24: return true;
+		case Op::u_mad24: return true;
+		case Op::s_mul24: return true;
+		case Op::u_mul24: return true;
+		case Op::u_abs: return true;
+		case Op::u_abs_diff: return true;
+		case Op::u_mul_hi: return true;
+		case Op::u_mad_hi: return true;
+		case Op::fclamp: return true;
+		case Op::degrees: return true;
+		case Op::fmax_common: return true;
+		case Op::fmin_common: return true;
+		case Op::mix: return true;
+		case Op::radians: return true;
+		case Op::step: return true;
+		case Op::smoothstep: return true;
+		case Op::sign: return true;
+		case Op::cross: return true;
+		case Op::distance: return true;
+		case Op::length: return true;
+		case Op::normalize: return true;
+		case Op::fast_distance: return true;
+		case Op::fast_length: return true;
+		case Op::fast_normalize: return true;
+		case Op::bitselect: return true;
+		case Op::select: return true;
+		case Op::vloadn: return true;
+		case Op::vstoren: return true;
+		case Op::vload_half: return true;
+		case Op::vload_halfn: return true;
+		case Op::vstore_half: return true;
+		case Op::vstore_half_r: return true;
+		case Op::vstore_halfn: return true;
+		case Op::vstore_halfn_r: return true;
+		case Op::vloada_halfn: return true;
+		case Op::vstorea_halfn: return true;
+		case Op::vstorea_halfn_r: return true;
+		case Op::shuffle: return true;
+		case Op::shuffle2: return true;
+		case Op::prefetch: return true;
+		}
+	}
 } // spvgentwo::opencl100
@@ -0,0 +1,4631 @@
+// Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+#pragma once
+
+#include "Grammar.h"
+
+namespace spvgentwo
+{
+	namespace detail
+	{
+		struct OperandCursor
+		{
+			const unsigned int* it;
+			const unsigned int* end;
+			bool empty() const { return it == end; }
+		};
+		inline unsigned int literalStringLength(const OperandCursor& _cursor)
+		{
+			for (const unsigned int* it = _cursor.it; it != _cursor.end; ++it)
+			{
+				if ((*it & 0xffu) == 0u || (*it & 0xff00u) == 0u || (*it & 0xff0000u) == 0u || (*it & 0xff000000u) == 0u)
+					return static_cast<unsigned int>(it - _cursor.it) + 1u;
+			}
+			return 0u;
+		}
+		template <class Visitor>
+		bool visitOperand(Grammar::OperandKind _kind, const char* _name, OperandCursor& _cursor, Visitor& _visitor)
+		{
+			if (_cursor.empty()) return false;
+			unsigned int wordCount = 1u;
+			switch (_kind)
+			{
+			default: break;
+			case Grammar::OperandKind::PairLiteralIntegerIdRef: return visitOperand(Grammar::OperandKind::LiteralInteger, _name, _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, _name, _cursor, _visitor);
+			case Grammar::OperandKind::PairIdRefLiteralInteger: return visitOperand(Grammar::OperandKind::IdRef, _name, _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, _name, _cursor, _visitor);
+			case Grammar::OperandKind::PairIdRefIdRef: return visitOperand(Grammar::OperandKind::IdRef, _name, _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, _name, _cursor, _visitor);
+			case Grammar::OperandKind::LiteralString: wordCount = literalStringLength(_cursor); if (wordCount == 0u) return false; break;
+			case Grammar::OperandKind::LiteralContextDependentNumber: wordCount = static_cast<unsigned int>(_cursor.end - _cursor.it); break; // width depends on the result type
+			}
+			const unsigned int* pWords = _cursor.it;
+			_cursor.it += wordCount;
+			_visitor(_kind, _name, pWords, wordCount);
+			switch (_kind)
+			{
+			default: break;
+			case Grammar::OperandKind::ImageOperands:
+				if ((*pWords & 0x1u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x2u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x4u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x8u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x20u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x40u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x80u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x100u) != 0u && !(visitOperand(Grammar::OperandKind::IdScope, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x200u) != 0u && !(visitOperand(Grammar::OperandKind::IdScope, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10000u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				break;
+			case Grammar::OperandKind::LoopControl:
+				if ((*pWords & 0x8u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x20u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x40u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x80u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x100u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x20000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x40000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x80000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x100000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x200000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x400000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x1000000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x2000000u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				break;
+			case Grammar::OperandKind::MemoryAccess:
+				if ((*pWords & 0x2u) != 0u && !(visitOperand(Grammar::OperandKind::LiteralInteger, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x8u) != 0u && !(visitOperand(Grammar::OperandKind::IdScope, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10u) != 0u && !(visitOperand(Grammar::OperandKind::IdScope, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x10000u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				if ((*pWords & 0x20000u) != 0u && !(visitOperand(Grammar::OperandKind::IdRef, "", _cursor, _visitor))) return false;
+				break;
+			case Grammar::OperandKind::ExecutionMode:
+				switch (*pWords)
+				{
+				default: break;
+				case 0u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Number of <<Invocation,invocations>>", _cursor, _visitor);
+				case 17u: return visitOperand(Grammar::OperandKind::LiteralInteger, "x size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "y size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "z size", _cursor, _visitor);
+				case 18u: return visitOperand(Grammar::OperandKind::LiteralInteger, "x size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "y size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "z size", _cursor, _visitor);
+				case 26u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Vertex count", _cursor, _visitor);
+				case 30u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Vector type", _cursor, _visitor);
+				case 35u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Subgroup Size", _cursor, _visitor);
+				case 36u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Subgroups Per Workgroup", _cursor, _visitor);
+				case 37u: return visitOperand(Grammar::OperandKind::IdRef, "Subgroups Per Workgroup", _cursor, _visitor);
+				case 38u: return visitOperand(Grammar::OperandKind::IdRef, "x size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "y size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "z size", _cursor, _visitor);
+				case 39u: return visitOperand(Grammar::OperandKind::IdRef, "x size hint", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "y size hint", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "z size hint", _cursor, _visitor);
+				case 4459u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 4460u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 4461u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 4462u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 4463u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 5071u: return visitOperand(Grammar::OperandKind::IdRef, "Number of recursions", _cursor, _visitor);
+				case 5072u: return visitOperand(Grammar::OperandKind::IdRef, "x size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "y size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "z size", _cursor, _visitor);
+				case 5073u: return visitOperand(Grammar::OperandKind::IdRef, "Shader Index", _cursor, _visitor);
+				case 5077u: return visitOperand(Grammar::OperandKind::IdRef, "x size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "y size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::IdRef, "z size", _cursor, _visitor);
+				case 5270u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Primitive count", _cursor, _visitor);
+				case 5618u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Size", _cursor, _visitor);
+				case 5620u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 5621u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 5622u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 5623u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor);
+				case 5893u: return visitOperand(Grammar::OperandKind::LiteralInteger, "max_x_size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "max_y_size", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "max_z_size", _cursor, _visitor);
+				case 5894u: return visitOperand(Grammar::OperandKind::LiteralInteger, "max_dimensions", _cursor, _visitor);
+				case 5896u: return visitOperand(Grammar::OperandKind::LiteralInteger, "vector_width", _cursor, _visitor);
+				case 5903u: return visitOperand(Grammar::OperandKind::LiteralInteger, "target_fmax", _cursor, _visitor);
+				case 6154u: return visitOperand(Grammar::OperandKind::LiteralInteger, "StallFreeReturn", _cursor, _visitor);
+				case 6160u: return visitOperand(Grammar::OperandKind::LiteralInteger, "WaitForDoneWrite", _cursor, _visitor);
+				case 6417u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Barrier Count", _cursor, _visitor);
+				}
+				break;
+			case Grammar::OperandKind::Decoration:
+				switch (*pWords)
+				{
+				default: break;
+				case 1u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Specialization Constant ID", _cursor, _visitor);
+				case 6u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Array Stride", _cursor, _visitor);
+				case 7u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Matrix Stride", _cursor, _visitor);
+				case 11u: return visitOperand(Grammar::OperandKind::BuiltIn, "", _cursor, _visitor);
+				case 27u: return visitOperand(Grammar::OperandKind::IdScope, "Execution", _cursor, _visitor);
+				case 29u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Stream Number", _cursor, _visitor);
+				case 30u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Location", _cursor, _visitor);
+				case 31u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Component", _cursor, _visitor);
+				case 32u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Index", _cursor, _visitor);
+				case 33u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Binding Point", _cursor, _visitor);
+				case 34u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Descriptor Set", _cursor, _visitor);
+				case 35u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Byte Offset", _cursor, _visitor);
+				case 36u: return visitOperand(Grammar::OperandKind::LiteralInteger, "XFB Buffer Number", _cursor, _visitor);
+				case 37u: return visitOperand(Grammar::OperandKind::LiteralInteger, "XFB Stride", _cursor, _visitor);
+				case 38u: return visitOperand(Grammar::OperandKind::FunctionParameterAttribute, "Function Parameter Attribute", _cursor, _visitor);
+				case 39u: return visitOperand(Grammar::OperandKind::FPRoundingMode, "Floating-Point Rounding Mode", _cursor, _visitor);
+				case 40u: return visitOperand(Grammar::OperandKind::FPFastMathMode, "Fast-Math Mode", _cursor, _visitor);
+				case 41u: return visitOperand(Grammar::OperandKind::LiteralString, "Name", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LinkageType, "Linkage Type", _cursor, _visitor);
+				case 43u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Attachment Index", _cursor, _visitor);
+				case 44u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Alignment", _cursor, _visitor);
+				case 45u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Max Byte Offset", _cursor, _visitor);
+				case 46u: return visitOperand(Grammar::OperandKind::IdRef, "Alignment", _cursor, _visitor);
+				case 47u: return visitOperand(Grammar::OperandKind::IdRef, "Max Byte Offset", _cursor, _visitor);
+				case 5019u: return visitOperand(Grammar::OperandKind::IdRef, "Payload Array", _cursor, _visitor);
+				case 5020u: return visitOperand(Grammar::OperandKind::IdRef, "Max number of payloads", _cursor, _visitor);
+				case 5091u: return visitOperand(Grammar::OperandKind::LiteralString, "Node Name", _cursor, _visitor);
+				case 5256u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Offset", _cursor, _visitor);
+				case 5599u: return visitOperand(Grammar::OperandKind::LiteralInteger, "N", _cursor, _visitor);
+				case 5607u: return visitOperand(Grammar::OperandKind::LiteralString, "Register", _cursor, _visitor);
+				case 5625u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Kind", _cursor, _visitor);
+				case 5628u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Offset", _cursor, _visitor);
+				case 5634u: return visitOperand(Grammar::OperandKind::IdRef, "Counter Buffer", _cursor, _visitor);
+				case 5635u: return visitOperand(Grammar::OperandKind::LiteralString, "Semantic", _cursor, _visitor);
+				case 5636u: return visitOperand(Grammar::OperandKind::LiteralString, "User Type", _cursor, _visitor);
+				case 5822u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor) && visitOperand(Grammar::OperandKind::FPRoundingMode, "FP Rounding Mode", _cursor, _visitor);
+				case 5823u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor) && visitOperand(Grammar::OperandKind::FPDenormMode, "FP Denorm Mode", _cursor, _visitor);
+				case 5826u: return visitOperand(Grammar::OperandKind::LiteralString, "Memory Type", _cursor, _visitor);
+				case 5827u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Banks", _cursor, _visitor);
+				case 5828u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Bank Width", _cursor, _visitor);
+				case 5829u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Maximum Copies", _cursor, _visitor);
+				case 5832u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Maximum Replicates", _cursor, _visitor);
+				case 5834u: return visitOperand(Grammar::OperandKind::LiteralString, "Merge Key", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralString, "Merge Type", _cursor, _visitor);
+				case 5835u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Bank Bits", _cursor, _visitor);
+				case 5836u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Force Key", _cursor, _visitor);
+				case 5900u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Cache Size in bytes", _cursor, _visitor);
+				case 5902u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Prefetcher Size in bytes", _cursor, _visitor);
+				case 5909u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Mode", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "Propagate", _cursor, _visitor);
+				case 5914u: return visitOperand(Grammar::OperandKind::IdRef, "Aliasing Scopes List", _cursor, _visitor);
+				case 5915u: return visitOperand(Grammar::OperandKind::IdRef, "Aliasing Scopes List", _cursor, _visitor);
+				case 5917u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Cycles", _cursor, _visitor);
+				case 5918u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Invocations", _cursor, _visitor);
+				case 5919u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Enable", _cursor, _visitor);
+				case 5921u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Buffer Location ID", _cursor, _visitor);
+				case 5944u: return visitOperand(Grammar::OperandKind::LiteralInteger, "IO Pipe ID", _cursor, _visitor);
+				case 6080u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Target Width", _cursor, _visitor) && visitOperand(Grammar::OperandKind::FPOperationMode, "FP Operation Mode", _cursor, _visitor);
+				case 6147u: return visitOperand(Grammar::OperandKind::InitializationModeQualifier, "Trigger", _cursor, _visitor);
+				case 6148u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Value", _cursor, _visitor);
+				case 6168u: return visitOperand(Grammar::OperandKind::HostAccessQualifier, "Access", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralString, "Name", _cursor, _visitor);
+				case 6170u: return visitOperand(Grammar::OperandKind::LiteralFloat, "Max Error", _cursor, _visitor);
+				case 6172u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Latency Label", _cursor, _visitor);
+				case 6173u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Relative To", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "Control Type", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LiteralInteger, "Relative Cycle", _cursor, _visitor);
+				case 6177u: return visitOperand(Grammar::OperandKind::LiteralInteger, "AddressWidth", _cursor, _visitor);
+				case 6178u: return visitOperand(Grammar::OperandKind::LiteralInteger, "DataWidth", _cursor, _visitor);
+				case 6179u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Latency", _cursor, _visitor);
+				case 6180u: return visitOperand(Grammar::OperandKind::AccessQualifier, "ReadWriteMode", _cursor, _visitor);
+				case 6181u: return visitOperand(Grammar::OperandKind::LiteralInteger, "MaxBurstCount", _cursor, _visitor);
+				case 6182u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Waitrequest", _cursor, _visitor);
+				case 6442u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Cache Level", _cursor, _visitor) && visitOperand(Grammar::OperandKind::LoadCacheControl, "Cache Control", _cursor, _visitor);
+				case 6443u: return visitOperand(Grammar::OperandKind::LiteralInteger, "Cache Level", _cursor, _visitor) && visitOperand(Grammar::OperandKind::StoreCacheControl, "Cache Control", _cursor, _visitor);
+				}
+				break;
+			}
+			return true;
+		}
+	} // detail
+
+	// calls _visitor(Grammar::OperandKind _kind, const char* _name, const unsigned int* _pWords, unsigned int _wordCount) for every operand of _opcode
+	// _pOperands points to the first word after the opcode, returns false if the words don't match the grammar
+	template <class Visitor>
+	bool visitOperands(spv::Op _opcode, const unsigned int* _pOperands, unsigned int _operandWordCount, Visitor&& _visitor)
+	{
+		detail::OperandCursor cursor{ _pOperands, _pOperands + _operandWordCount };
+		switch (_opcode)
+		{
+		default: return false; // unknown opcode
+		case spv::Op::OpNop:
+			break;
+		case spv::Op::OpUndef:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSourceContinued:
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Continued Source", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSource:
+			if (!detail::visitOperand(Grammar::OperandKind::SourceLanguage, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Version", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "File", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::LiteralString, "Source", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSourceExtension:
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Extension", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpName:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMemberName:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Member", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpString:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "String", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLine:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "File", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Line", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Column", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExtension:
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExtInstImport:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExtInst:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Set", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralExtInstInteger, "Instruction", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1, +Operand 2, +...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpMemoryModel:
+			if (!detail::visitOperand(Grammar::OperandKind::AddressingModel, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::MemoryModel, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEntryPoint:
+			if (!detail::visitOperand(Grammar::OperandKind::ExecutionModel, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Entry Point", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Name", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Interface", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpExecutionMode:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Entry Point", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ExecutionMode, "Mode", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCapability:
+			if (!detail::visitOperand(Grammar::OperandKind::Capability, "Capability", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeVoid:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeBool:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeInt:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Width", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Signedness", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeFloat:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Width", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeVector:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Component Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeMatrix:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Column Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Column Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeImage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Dim, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Depth", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Arrayed", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "MS", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Sampled", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageFormat, "", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::AccessQualifier, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeSampler:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeSampledImage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeArray:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Element Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Length", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeRuntimeArray:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Element Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeStruct:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Member 0 type, +member 1 type, +...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpTypeOpaque:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "The name of the opaque type.", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypePointer:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::StorageClass, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeFunction:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Return Type", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Parameter 0 Type, +Parameter 1 Type, +...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpTypeEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeDeviceEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeReserveId:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeQueue:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypePipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::AccessQualifier, "Qualifier", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeForwardPointer:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::StorageClass, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantTrue:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantFalse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstant:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralContextDependentNumber, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantComposite:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Constituents", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpConstantSampler:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::SamplerAddressingMode, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::SamplerFilterMode, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantNull:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSpecConstantTrue:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSpecConstantFalse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSpecConstant:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralContextDependentNumber, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSpecConstantComposite:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Constituents", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpSpecConstantOp:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralSpecConstantOpInteger, "Opcode", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFunction:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::FunctionControl, "", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Function Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFunctionParameter:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFunctionEnd:
+			break;
+		case spv::Op::OpFunctionCall:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Function", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Argument 0, +Argument 1, +...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpVariable:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::StorageClass, "", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Initializer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageTexelPointer:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sample", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLoad:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpStore:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCopyMemory:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Source", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCopyMemorySized:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Source", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Size", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAccessChain:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpInBoundsAccessChain:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpPtrAccessChain:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Element", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpArrayLength:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Array member", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGenericPtrMemSemantics:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpInBoundsPtrAccessChain:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Element", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpDecorate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Decoration, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMemberDecorate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Structure Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Member", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Decoration, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDecorationGroup:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupDecorate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Decoration Group", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Targets", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpGroupMemberDecorate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Decoration Group", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::PairIdRefLiteralInteger, "Targets", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpVectorExtractDynamic:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVectorInsertDynamic:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVectorShuffle:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Components", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpCompositeConstruct:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Constituents", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpCompositeExtract:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Composite", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpCompositeInsert:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Composite", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Indexes", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpCopyObject:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTranspose:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Matrix", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSampledImage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampler", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleDrefImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleDrefExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleProjImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleProjExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleProjDrefImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleProjDrefExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageFetch:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageGather:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageDrefGather:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageRead:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageWrite:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Texel", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQueryFormat:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQueryOrder:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQuerySizeLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Level of Detail", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQuerySize:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQueryLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQueryLevels:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageQuerySamples:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertFToU:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Float Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertFToS:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Float Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertSToF:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Signed Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToF:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Unsigned Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUConvert:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Unsigned Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSConvert:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Signed Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFConvert:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Float Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpQuantizeToF16:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertPtrToU:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSatConvertSToU:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Signed Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSatConvertUToS:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Unsigned Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToPtr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Integer Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPtrCastToGeneric:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGenericCastToPtr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGenericCastToPtrExplicit:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::StorageClass, "Storage", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitcast:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSNegate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFNegate:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpISub:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFSub:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIMul:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFMul:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUDiv:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSDiv:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFDiv:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUMod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSRem:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSMod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFRem:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFMod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVectorTimesScalar:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Scalar", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMatrixTimesScalar:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Matrix", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Scalar", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVectorTimesMatrix:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Matrix", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMatrixTimesVector:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Matrix", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMatrixTimesMatrix:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "LeftMatrix", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RightMatrix", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpOuterProduct:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDot:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIAddCarry:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpISubBorrow:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUMulExtended:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSMulExtended:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAny:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAll:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsNan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsInf:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsFinite:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsNormal:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSignBitSet:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLessOrGreater:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "y", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpOrdered:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "y", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUnordered:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "x", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "y", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLogicalEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLogicalNotEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLogicalOr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLogicalAnd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLogicalNot:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSelect:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Condition", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpINotEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUGreaterThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSGreaterThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUGreaterThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSGreaterThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpULessThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSLessThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpULessThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSLessThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdNotEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordNotEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdLessThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordLessThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdGreaterThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordGreaterThan:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdLessThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordLessThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFOrdGreaterThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFUnordGreaterThanEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpShiftRightLogical:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Shift", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpShiftRightArithmetic:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Shift", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpShiftLeftLogical:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Shift", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitwiseOr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitwiseXor:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitwiseAnd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpNot:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitFieldInsert:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Insert", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitFieldSExtract:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitFieldUExtract:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitReverse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBitCount:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Base", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdx:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdy:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFwidth:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdxFine:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdyFine:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFwidthFine:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdxCoarse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDPdyCoarse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFwidthCoarse:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "P", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEmitVertex:
+			break;
+		case spv::Op::OpEndPrimitive:
+			break;
+		case spv::Op::OpEmitStreamVertex:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Stream", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEndStreamPrimitive:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Stream", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpControlBarrier:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMemoryBarrier:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicLoad:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicStore:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicExchange:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicCompareExchange:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Equal", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Unequal", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Comparator", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicCompareExchangeWeak:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Equal", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Unequal", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Comparator", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicIIncrement:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicIDecrement:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicIAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicISub:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicSMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicUMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicSMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicUMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicAnd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicOr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicXor:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPhi:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::PairIdRefIdRef, "Variable, Parent, ...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpLoopMerge:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Merge Block", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Continue Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LoopControl, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSelectionMerge:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Merge Block", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::SelectionControl, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLabel:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBranch:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target Label", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBranchConditional:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Condition", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "True Label", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "False Label", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Branch weights", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpSwitch:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Selector", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Default", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::PairLiteralIntegerIdRef, "Target", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpKill:
+			break;
+		case spv::Op::OpReturn:
+			break;
+		case spv::Op::OpReturnValue:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUnreachable:
+			break;
+		case spv::Op::OpLifetimeStart:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLifetimeStop:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupAsyncCopy:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Destination", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Source", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Elements", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupWaitEvents:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Events", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Events List", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupAll:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupAny:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupBroadcast:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "LocalId", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupIAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupUMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupSMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupUMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupSMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReadPipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpWritePipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReservedReadPipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReservedWritePipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReserveReadPipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Packets", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReserveWritePipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Packets", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCommitReadPipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCommitWritePipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsValidReserveId:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetNumPipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetMaxPipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupReserveReadPipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Packets", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupReserveWritePipePackets:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Packets", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupCommitReadPipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupCommitWritePipe:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reserve Id", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEnqueueMarker:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Queue", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Events", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Wait Events", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ret Event", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEnqueueKernel:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Queue", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Flags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "ND Range", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Num Events", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Wait Events", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ret Event", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Local Size", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpGetKernelNDrangeSubGroupCount:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "ND Range", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetKernelNDrangeMaxSubGroupSize:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "ND Range", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetKernelWorkGroupSize:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetKernelPreferredWorkGroupSizeMultiple:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRetainEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReleaseEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCreateUserEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIsValidEvent:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSetUserEventStatus:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Status", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCaptureEventProfilingInfo:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Event", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Profiling Info", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetDefaultQueue:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBuildNDRange:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GlobalWorkSize", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "LocalWorkSize", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GlobalWorkOffset", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleDrefImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleDrefExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleProjImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleProjExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleProjDrefImplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseSampleProjDrefExplicitLod:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseFetch:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseGather:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseDrefGather:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "D~ref~", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseTexelsResident:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Resident Code", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpNoLine:
+			break;
+		case spv::Op::OpAtomicFlagTestAndSet:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicFlagClear:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSparseRead:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSizeOf:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypePipeStorage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantPipeStorage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Packet Alignment", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Capacity", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCreatePipeFromPipeStorage:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pipe Storage", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetKernelLocalSizeForSubgroupCount:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Subgroup Count", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGetKernelMaxNumSubgroups:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Invoke", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Param Align", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeNamedBarrier:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpNamedBarrierInitialize:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Subgroup Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMemoryNamedBarrier:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Named Barrier", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpModuleProcessed:
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Process", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExecutionModeId:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Entry Point", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::ExecutionMode, "Mode", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDecorateId:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Decoration, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformElect:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformAll:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformAny:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformAllEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBroadcast:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Id", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBroadcastFirst:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBallot:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformInverseBallot:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBallotBitExtract:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBallotBitCount:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBallotFindLSB:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBallotFindMSB:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformShuffle:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Id", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformShuffleXor:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Mask", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformShuffleUp:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Delta", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformShuffleDown:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Delta", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformIAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformFAdd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformIMul:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformFMul:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformSMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformUMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformFMin:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformSMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformUMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformFMax:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBitwiseAnd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBitwiseOr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformBitwiseXor:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformLogicalAnd:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformLogicalOr:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformLogicalXor:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformQuadBroadcast:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformQuadSwap:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCopyLogical:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPtrEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPtrNotEqual:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPtrDiff:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpColorAttachmentReadEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Attachment", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Sample", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDepthAttachmentReadEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Sample", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpStencilAttachmentReadEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Sample", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTerminateInvocation:
+			break;
+		case spv::Op::OpSubgroupBallotKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupFirstInvocationKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAllKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAnyKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAllEqualKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Predicate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformRotateKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Delta", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "ClusterSize", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupReadInvocationKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTraceRayKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Flags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cull Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExecuteCallableKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Callable Data", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToAccelerationStructureKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIgnoreIntersectionKHR:
+			break;
+		case spv::Op::OpTerminateRayKHR:
+			break;
+		case spv::Op::OpSDotKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUDotKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSUDotKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSDotAccSatKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accumulator", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUDotAccSatKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accumulator", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSUDotAccSatKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vector 2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accumulator", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::PackedVectorFormat, "Packed Vector Format", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeCooperativeMatrixKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Scope", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Rows", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Columns", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Use", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixLoadKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "MemoryLayout", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Stride", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "Memory Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixStoreKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "MemoryLayout", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Stride", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "Memory Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixMulAddKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "C", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::CooperativeMatrixOperands, "Cooperative Matrix Operands", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixLengthKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeRayQueryKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryInitializeKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayFlags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "CullMask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayOrigin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayTMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayDirection", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayTMax", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryTerminateKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGenerateIntersectionKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitT", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryConfirmIntersectionKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryProceedKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionTypeKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleWeightedQCOM:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Texture", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Weights", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageBoxFilterQCOM:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Texture", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Box Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageBlockMatchSSDQCOM:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Block Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageBlockMatchSADQCOM:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference Coordinates", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Block Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupIAddNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFAddNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFMinNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupUMinNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupSMinNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFMaxNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupUMaxNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupSMaxNonUniformAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFragmentMaskFetchAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFragmentFetchAMD:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fragment Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReadClockKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Scope", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFinalizeNodePayloadsAMDX:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload Array", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFinishWritingNodePayloadAMDX:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpInitializeNodePayloadsAMDX:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload Array", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Visibility", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload Count", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Node Index", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordHitMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "InstanceId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PrimitiveId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GeometryIndex", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Kind", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Current Time", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitObject Attributes", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordHitWithIndexMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "InstanceId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PrimitiveId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GeometryIndex", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Kind", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Current Time", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitObject Attributes", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordMissMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Current Time", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetWorldToObjectNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetObjectToWorldNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetObjectRayDirectionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetObjectRayOriginNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectTraceRayMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayFlags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cullmask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Time", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetShaderRecordBufferHandleNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetShaderBindingTableRecordIndexNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordEmptyNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectTraceRayNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayFlags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cullmask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordHitNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "InstanceId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PrimitiveId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GeometryIndex", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Kind", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitObject Attributes", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordHitWithIndexNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Acceleration Structure", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "InstanceId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PrimitiveId", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "GeometryIndex", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Kind", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Record Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitObject Attributes", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectRecordMissNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "TMax", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectExecuteShaderNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetCurrentTimeNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetAttributesNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object Attribute", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetHitKindNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetPrimitiveIndexNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetGeometryIndexNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetInstanceIdNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetInstanceCustomIndexNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetWorldRayDirectionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetWorldRayOriginNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetRayTMaxNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectGetRayTMinNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectIsEmptyNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectIsHitNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpHitObjectIsMissNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReorderThreadWithHitObjectNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit Object", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Hint", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Bits", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReorderThreadWithHintNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hint", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bits", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeHitObjectNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpImageSampleFootprintNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampled Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Granularity", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coarse", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::ImageOperands, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpEmitMeshTasksEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Group Count X", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Group Count Y", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Group Count Z", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSetMeshOutputsEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Vertex Count", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Primitive Count", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupNonUniformPartitionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpWritePackedPrimitiveIndices4x8NV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Index Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Indices", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReportIntersectionKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Hit", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "HitKind", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIgnoreIntersectionNV:
+			break;
+		case spv::Op::OpTerminateRayNV:
+			break;
+		case spv::Op::OpTraceNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Flags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cull Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PayloadId", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTraceMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Flags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cull Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Time", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "PayloadId", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTraceRayMotionNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Accel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Flags", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cull Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Miss Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Origin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmin", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ray Tmax", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Time", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAccelerationStructureKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExecuteCallableNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SBT Index", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Callable DataId", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeCooperativeMatrixNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Component Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Rows", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Columns", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixLoadNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Column Major", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixStoreNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Object", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Stride", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Column Major", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::MemoryAccess, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixMulAddNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "C", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCooperativeMatrixLengthNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpBeginInvocationInterlockEXT:
+			break;
+		case spv::Op::OpEndInvocationInterlockEXT:
+			break;
+		case spv::Op::OpDemoteToHelperInvocationEXT:
+			break;
+		case spv::Op::OpIsHelperInvocationEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToImageNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToSamplerNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertImageToUNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertSamplerToUNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertUToSampledImageNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertSampledImageToUNV:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSamplerImageAddressingModeNV:
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Bit Width", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupShuffleINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Data", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "InvocationId", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupShuffleDownINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Current", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Next", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Delta", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupShuffleUpINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Previous", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Current", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Delta", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupShuffleXorINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Data", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupBlockReadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ptr", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupBlockWriteINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ptr", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Data", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupImageBlockReadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupImageBlockWriteINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Data", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupImageMediaBlockReadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Width", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Height", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupImageMediaBlockWriteINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Coordinate", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Width", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Height", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Data", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUCountLeadingZerosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUCountTrailingZerosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAbsISubINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAbsUSubINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIAddSatINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUAddSatINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIAverageINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUAverageINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIAverageRoundedINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUAverageRoundedINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpISubSatINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUSubSatINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpIMul32x16INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpUMul32x16INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConstantFunctionPointerINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Function", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFunctionPointerCallINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Operand 1", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpAsmTargetINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Asm target", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAsmINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Asm type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Asm instructions", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralString, "Constraints", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAsmCallINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Asm", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Argument 0", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpAtomicFMinEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicFMaxEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAssumeTrueKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Condition", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpExpectKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "ExpectedValue", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpDecorateString:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Target", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Decoration, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpMemberDecorateString:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Struct Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Member", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::Decoration, "", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVmeImageINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sampler", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeVmeImageINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image Type", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcRefPayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcSicPayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcMcePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcMceResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImeResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImeSingleReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcImeDualReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcRefResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeAvcSicResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference Base Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Shape Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction Cost", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Cost Center Delta", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Cost Table", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Cost Precision", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Slice Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Qp", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Source Field Polarity", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Reference Field Polarity", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Forward Reference Field Polarity", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Backward Reference Field Polarity", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToImePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToImeResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToRefPayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToRefResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToSicPayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceConvertToSicResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetMotionVectorsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterDistortionsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterMajorShapeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterMinorShapeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterDirectionsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Ids", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Parameter Field Polarities", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeInitializeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Coord", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Partition Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "SAD Adjustment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetSingleReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Search Window Config", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetDualReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "id> Search Window Config", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeRefWindowSizeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Search Window Config", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Dual Ref", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeAdjustRefOffsetINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Offset", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Coord", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Window Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image Size", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeConvertToMcePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Max Motion Vector Count", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Threshold", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeSetWeightedSadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Sad Weights", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Streamin Components", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Streamin Components", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Streamin Components", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Streamin Components", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeConvertToMceResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shape", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetBorderReachedINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Image Select", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcFmeInitializeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Coord", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Motion Vectors", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shapes", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Minor Shapes", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pixel Resolution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sad Adjustment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcBmeInitializeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Coord", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Motion Vectors", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Major Shapes", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Minor Shapes", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pixel Resolution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bidirectional Weight", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sad Adjustment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefConvertToMcePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Ids", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Ids", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Field Polarities", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcRefConvertToMceResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicInitializeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Coord", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicConfigureSkcINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Skip Block Partition Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Skip Motion Vector Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Motion Vectors", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bidirectional Weight", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sad Adjustment", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicConfigureIpeLumaINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Luma Intra Partition Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intra Neighbour Availabilty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Left Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Left Corner Luma Pixel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Right Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sad Adjustment", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Luma Intra Partition Mask", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intra Neighbour Availabilty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Left Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Left Corner Luma Pixel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Right Edge Luma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Left Edge Chroma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Left Corner Chroma Pixel", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Upper Edge Chroma Pixels", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Sad Adjustment", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Skip Block Partition Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Direction", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicConvertToMcePayloadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Shape Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Luma Mode Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Luma Packed Neighbor Modes", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Luma Packed Non Dc Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Chroma Mode Base Penalty", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Sad Coefficients", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Block Based Skip Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicEvaluateIpeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Fwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Bwd Ref Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Ids", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Src Image", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Ids", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packed Reference Field Polarities", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicConvertToMceResultINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetIpeChromaModeINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSubgroupAvcSicGetInterRawSadsINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Payload", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpVariableLengthArrayINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Lenght", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpSaveMemoryINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRestoreMemoryINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Ptr", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSinCosPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "FromSign", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCastINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCastFromIntINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "FromSign", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCastToIntINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatAddINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSubINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatMulINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatDivINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatGTINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatGEINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLTINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLEINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatEQINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatRecipINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatRSqrtINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCbrtINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatHypotINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSqrtINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLogINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLog2INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLog10INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatLog1pINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatExpINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatExp2INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatExp10INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatExpm1INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSinINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSinCosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatSinPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatCosPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatASinINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatASinPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatACosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatACosPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatATanINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatATanPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatATan2INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatPowINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatPowRINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M2", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpArbitraryFloatPowNINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "A", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "M1", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "B", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Mout", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "EnableSubnormals", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingMode", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "RoundingAccuracy", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpLoopControlINTEL:
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Loop Control Parameters", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpAliasDomainDeclINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAliasScopeDeclINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Alias Domain", cursor, _visitor)) return false;
+			if (!cursor.empty() && !detail::visitOperand(Grammar::OperandKind::IdRef, "Name", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAliasScopeListDeclINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "AliasScope1, AliasScope2, ...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpFixedSqrtINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedRecipINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedRsqrtINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedSinINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedCosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedSinCosINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedSinPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedCosPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedSinCosPiINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedLogINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFixedExpINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input Type", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "S", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "I", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "rI", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "Q", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::LiteralInteger, "O", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpPtrCastToCrossWorkgroupINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpCrossWorkgroupCastToPtrINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpReadPipeBlockingINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpWritePipeBlockingINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Size", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Packet Alignment", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpFPGARegINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Input", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetRayTMinKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetRayFlagsKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionTKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionInstanceIdKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionGeometryIndexKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionPrimitiveIndexKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionBarycentricsKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionFrontFaceKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionObjectRayDirectionKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionObjectRayOriginKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetWorldRayDirectionKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetWorldRayOriginKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionObjectToWorldKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpRayQueryGetIntersectionWorldToObjectKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "RayQuery", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Intersection", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpAtomicFAddEXT:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Pointer", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeBufferSurfaceINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::AccessQualifier, "AccessQualifier", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpTypeStructContinuedINTEL:
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Member 0 type, +member 1 type, +...", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpConstantCompositeContinuedINTEL:
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Constituents", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpSpecConstantCompositeContinuedINTEL:
+			while (!cursor.empty()) { if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Constituents", cursor, _visitor)) return false; }
+			break;
+		case spv::Op::OpConvertFToBF16INTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "Float Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpConvertBF16ToFINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "BFloat16 Value", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpControlBarrierArriveINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpControlBarrierWaitINTEL:
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Memory", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdMemorySemantics, "Semantics", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupIMulKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupFMulKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupBitwiseAndKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupBitwiseOrKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupBitwiseXorKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupLogicalAndKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupLogicalOrKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		case spv::Op::OpGroupLogicalXorKHR:
+			if (!detail::visitOperand(Grammar::OperandKind::IdResultType, "ResultType", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdResult, "Result", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdScope, "Execution", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::GroupOperation, "Operation", cursor, _visitor)) return false;
+			if (!detail::visitOperand(Grammar::OperandKind::IdRef, "X", cursor, _visitor)) return false;
+			break;
+		}
+		return cursor.empty();
+	}
+} // spvgentwo
@@ -1,4 +1,6 @@
 // Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
 // Copyright (c) 2014-2020 The Khronos Group Inc.
 // 
 // Permission is hereby granted, free of charge, to any person obtaining a copy
@@ -2313,890 +2315,1906 @@ namespace spvgentwo::spv
 		"SPV_NV_viewport_array2",
 		"SPV_QCOM_image_processing",
 	};
+	static constexpr unsigned int NotInCore = 0xffffffff;
+	struct ExtensionEnumerant
+	{
+		const char* kind; // operand kind like "Capability"
+		const char* name;
+		unsigned int value;
+	};
+	struct ExtensionInfo
+	{
+		const char* vendor; // KHR, EXT, NV, ...
+		unsigned int coreSince; // core version like 0x00010300 that contains everything the extension enables, NotInCore if never promoted
+		const Op* ops;
+		unsigned short opCount;
+		const ExtensionEnumerant* enumerants;
+		unsigned short enumerantCount;
+	};
+	static constexpr Op ExtensionOps[] =
+	{
+		Op::OpGroupIAddNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupFAddNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupFMinNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupUMinNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupSMinNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupFMaxNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupUMaxNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpGroupSMaxNonUniformAMD, // SPV_AMD_shader_ballot
+		Op::OpFragmentMaskFetchAMD, // SPV_AMD_shader_fragment_mask
+		Op::OpFragmentFetchAMD, // SPV_AMD_shader_fragment_mask
+		Op::OpIsHelperInvocationEXT, // SPV_EXT_demote_to_helper_invocation
+		Op::OpBeginInvocationInterlockEXT, // SPV_EXT_fragment_shader_interlock
+		Op::OpEndInvocationInterlockEXT, // SPV_EXT_fragment_shader_interlock
+		Op::OpAtomicFAddEXT, // SPV_EXT_shader_atomic_float_add
+		Op::OpDecorateString, // SPV_GOOGLE_decorate_string
+		Op::OpMemberDecorateString, // SPV_GOOGLE_decorate_string
+		Op::OpDecorateId, // SPV_GOOGLE_hlsl_functionality1
+		Op::OpDecorateString, // SPV_GOOGLE_hlsl_functionality1
+		Op::OpMemberDecorateString, // SPV_GOOGLE_hlsl_functionality1
+		Op::OpReadPipeBlockingINTEL, // SPV_INTEL_blocking_pipes
+		Op::OpWritePipeBlockingINTEL, // SPV_INTEL_blocking_pipes
+		Op::OpFPGARegINTEL, // SPV_INTEL_fpga_reg
+		Op::OpConstantFunctionPointerINTEL, // SPV_INTEL_function_pointers
+		Op::OpFunctionPointerCallINTEL, // SPV_INTEL_function_pointers
+		Op::OpAliasDomainDeclINTEL, // SPV_INTEL_memory_access_aliasing
+		Op::OpAliasScopeDeclINTEL, // SPV_INTEL_memory_access_aliasing
+		Op::OpAliasScopeListDeclINTEL, // SPV_INTEL_memory_access_aliasing
+		Op::OpLoopControlINTEL, // SPV_INTEL_unstructured_loop_controls
+		Op::OpAssumeTrueKHR, // SPV_KHR_expect_assume
+		Op::OpExpectKHR, // SPV_KHR_expect_assume
+		Op::OpSDotKHR, // SPV_KHR_integer_dot_product
+		Op::OpUDotKHR, // SPV_KHR_integer_dot_product
+		Op::OpSUDotKHR, // SPV_KHR_integer_dot_product
+		Op::OpSDotAccSatKHR, // SPV_KHR_integer_dot_product
+		Op::OpUDotAccSatKHR, // SPV_KHR_integer_dot_product
+		Op::OpSUDotAccSatKHR, // SPV_KHR_integer_dot_product
+		Op::OpConvertUToAccelerationStructureKHR, // SPV_KHR_ray_query
+		Op::OpTypeRayQueryKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryInitializeKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryTerminateKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGenerateIntersectionKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryConfirmIntersectionKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryProceedKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionTypeKHR, // SPV_KHR_ray_query
+		Op::OpTypeAccelerationStructureNV, // SPV_KHR_ray_query
+		Op::OpRayQueryGetRayTMinKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetRayFlagsKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionTKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionInstanceIdKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionGeometryIndexKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionPrimitiveIndexKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionBarycentricsKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionFrontFaceKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionObjectRayDirectionKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionObjectRayOriginKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetWorldRayDirectionKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetWorldRayOriginKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionObjectToWorldKHR, // SPV_KHR_ray_query
+		Op::OpRayQueryGetIntersectionWorldToObjectKHR, // SPV_KHR_ray_query
+		Op::OpTraceRayKHR, // SPV_KHR_ray_tracing
+		Op::OpExecuteCallableKHR, // SPV_KHR_ray_tracing
+		Op::OpConvertUToAccelerationStructureKHR, // SPV_KHR_ray_tracing
+		Op::OpIgnoreIntersectionKHR, // SPV_KHR_ray_tracing
+		Op::OpTerminateRayKHR, // SPV_KHR_ray_tracing
+		Op::OpReportIntersectionNV, // SPV_KHR_ray_tracing
+		Op::OpTypeAccelerationStructureNV, // SPV_KHR_ray_tracing
+		Op::OpSubgroupBallotKHR, // SPV_KHR_shader_ballot
+		Op::OpSubgroupFirstInvocationKHR, // SPV_KHR_shader_ballot
+		Op::OpSubgroupReadInvocationKHR, // SPV_KHR_shader_ballot
+		Op::OpSubgroupAllKHR, // SPV_KHR_subgroup_vote
+		Op::OpSubgroupAnyKHR, // SPV_KHR_subgroup_vote
+		Op::OpSubgroupAllEqualKHR, // SPV_KHR_subgroup_vote
+		Op::OpTerminateInvocation, // SPV_KHR_terminate_invocation
+		Op::OpTypeCooperativeMatrixNV, // SPV_NV_cooperative_matrix
+		Op::OpCooperativeMatrixLoadNV, // SPV_NV_cooperative_matrix
+		Op::OpCooperativeMatrixStoreNV, // SPV_NV_cooperative_matrix
+		Op::OpCooperativeMatrixMulAddNV, // SPV_NV_cooperative_matrix
+		Op::OpCooperativeMatrixLengthNV, // SPV_NV_cooperative_matrix
+		Op::OpWritePackedPrimitiveIndices4x8NV, // SPV_NV_mesh_shader
+		Op::OpReportIntersectionNV, // SPV_NV_ray_tracing
+		Op::OpIgnoreIntersectionNV, // SPV_NV_ray_tracing
+		Op::OpTerminateRayNV, // SPV_NV_ray_tracing
+		Op::OpTraceNV, // SPV_NV_ray_tracing
+		Op::OpTypeAccelerationStructureNV, // SPV_NV_ray_tracing
+		Op::OpExecuteCallableNV, // SPV_NV_ray_tracing
+		Op::OpTraceMotionNV, // SPV_NV_ray_tracing_motion_blur
+		Op::OpTraceRayMotionNV, // SPV_NV_ray_tracing_motion_blur
+		Op::OpImageSampleFootprintNV, // SPV_NV_shader_image_footprint
+		Op::OpGroupNonUniformPartitionNV, // SPV_NV_shader_subgroup_partitioned
+	};
+	static constexpr ExtensionEnumerant ExtensionEnumerants[] =
+	{
+		{ "Capability", "ShaderEnqueueAMDX", 5067u }, // SPV_AMDX_shader_enqueue
+		{ "Capability", "Float16ImageAMD", 5008u }, // SPV_AMD_gpu_shader_half_float_fetch
+		{ "Capability", "Groups", 18u }, // SPV_AMD_shader_ballot
+		{ "ExecutionMode", "EarlyAndLateFragmentTestsAMD", 5017u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefUnchangedFrontAMD", 5079u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefGreaterFrontAMD", 5080u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefLessFrontAMD", 5081u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefUnchangedBackAMD", 5082u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefGreaterBackAMD", 5083u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "ExecutionMode", "StencilRefLessBackAMD", 5084u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "Decoration", "ExplicitInterpAMD", 4999u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordNoPerspAMD", 4992u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordNoPerspCentroidAMD", 4993u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordNoPerspSampleAMD", 4994u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordSmoothAMD", 4995u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordSmoothCentroidAMD", 4996u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordSmoothSampleAMD", 4997u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "BuiltIn", "BaryCoordPullModelAMD", 4998u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "Capability", "FragmentMaskAMD", 5010u }, // SPV_AMD_shader_fragment_mask
+		{ "Capability", "ImageReadWriteLodAMD", 5015u }, // SPV_AMD_shader_image_load_store_lod
+		{ "Capability", "ImageGatherBiasLodAMD", 5009u }, // SPV_AMD_texture_gather_bias_lod
+		{ "Capability", "CoreBuiltinsARM", 4165u }, // SPV_ARM_core_builtins
+		{ "Capability", "DemoteToHelperInvocationEXT", 5379u }, // SPV_EXT_demote_to_helper_invocation
+		{ "Decoration", "NonUniformEXT", 5300u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "ShaderNonUniformEXT", 5301u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "RuntimeDescriptorArrayEXT", 5302u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "InputAttachmentArrayDynamicIndexingEXT", 5303u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "UniformTexelBufferArrayDynamicIndexingEXT", 5304u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "StorageTexelBufferArrayDynamicIndexingEXT", 5305u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "UniformBufferArrayNonUniformIndexingEXT", 5306u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "SampledImageArrayNonUniformIndexingEXT", 5307u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "StorageBufferArrayNonUniformIndexingEXT", 5308u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "StorageImageArrayNonUniformIndexingEXT", 5309u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "InputAttachmentArrayNonUniformIndexingEXT", 5310u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "UniformTexelBufferArrayNonUniformIndexingEXT", 5311u }, // SPV_EXT_descriptor_indexing
+		{ "Capability", "StorageTexelBufferArrayNonUniformIndexingEXT", 5312u }, // SPV_EXT_descriptor_indexing
+		{ "BuiltIn", "FullyCoveredEXT", 5264u }, // SPV_EXT_fragment_fully_covered
+		{ "Capability", "FragmentFullyCoveredEXT", 5265u }, // SPV_EXT_fragment_fully_covered
+		{ "BuiltIn", "FragSizeEXT", 5292u }, // SPV_EXT_fragment_invocation_density
+		{ "BuiltIn", "FragInvocationCountEXT", 5293u }, // SPV_EXT_fragment_invocation_density
+		{ "Capability", "FragmentDensityEXT", 5291u }, // SPV_EXT_fragment_invocation_density
+		{ "ExecutionMode", "PixelInterlockOrderedEXT", 5366u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "PixelInterlockUnorderedEXT", 5367u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "SampleInterlockOrderedEXT", 5368u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "SampleInterlockUnorderedEXT", 5369u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "ShadingRateInterlockOrderedEXT", 5370u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "ShadingRateInterlockUnorderedEXT", 5371u }, // SPV_EXT_fragment_shader_interlock
+		{ "Capability", "FragmentShaderSampleInterlockEXT", 5363u }, // SPV_EXT_fragment_shader_interlock
+		{ "Capability", "FragmentShaderShadingRateInterlockEXT", 5372u }, // SPV_EXT_fragment_shader_interlock
+		{ "Capability", "FragmentShaderPixelInterlockEXT", 5378u }, // SPV_EXT_fragment_shader_interlock
+		{ "ExecutionMode", "OutputLinesNV", 5269u }, // SPV_EXT_mesh_shader
+		{ "ExecutionMode", "OutputPrimitivesNV", 5270u }, // SPV_EXT_mesh_shader
+		{ "ExecutionMode", "OutputTrianglesNV", 5298u }, // SPV_EXT_mesh_shader
+		{ "StorageClass", "TaskPayloadWorkgroupEXT", 5402u }, // SPV_EXT_mesh_shader
+		{ "Decoration", "PerPrimitiveNV", 5271u }, // SPV_EXT_mesh_shader
+		{ "Decoration", "PerTaskNV", 5273u }, // SPV_EXT_mesh_shader
+		{ "BuiltIn", "DrawIndex", 4426u }, // SPV_EXT_mesh_shader
+		{ "BuiltIn", "PrimitivePointIndicesEXT", 5294u }, // SPV_EXT_mesh_shader
+		{ "BuiltIn", "PrimitiveLineIndicesEXT", 5295u }, // SPV_EXT_mesh_shader
+		{ "BuiltIn", "PrimitiveTriangleIndicesEXT", 5296u }, // SPV_EXT_mesh_shader
+		{ "BuiltIn", "CullPrimitiveEXT", 5299u }, // SPV_EXT_mesh_shader
+		{ "Capability", "MeshShadingEXT", 5283u }, // SPV_EXT_mesh_shader
+		{ "Capability", "RayTracingOpacityMicromapEXT", 5381u }, // SPV_EXT_opacity_micromap
+		{ "AddressingModel", "PhysicalStorageBuffer64", 5348u }, // SPV_EXT_physical_storage_buffer
+		{ "StorageClass", "PhysicalStorageBuffer", 5349u }, // SPV_EXT_physical_storage_buffer
+		{ "Decoration", "RestrictPointer", 5355u }, // SPV_EXT_physical_storage_buffer
+		{ "Decoration", "AliasedPointer", 5356u }, // SPV_EXT_physical_storage_buffer
+		{ "Capability", "PhysicalStorageBufferAddresses", 5347u }, // SPV_EXT_physical_storage_buffer
+		{ "Capability", "AtomicFloat16AddEXT", 6095u }, // SPV_EXT_shader_atomic_float16_add
+		{ "Capability", "AtomicFloat32AddEXT", 6033u }, // SPV_EXT_shader_atomic_float_add
+		{ "Capability", "AtomicFloat64AddEXT", 6034u }, // SPV_EXT_shader_atomic_float_add
+		{ "Capability", "AtomicFloat32MinMaxEXT", 5612u }, // SPV_EXT_shader_atomic_float_min_max
+		{ "Capability", "AtomicFloat64MinMaxEXT", 5613u }, // SPV_EXT_shader_atomic_float_min_max
+		{ "Capability", "AtomicFloat16MinMaxEXT", 5616u }, // SPV_EXT_shader_atomic_float_min_max
+		{ "Capability", "Int64ImageEXT", 5016u }, // SPV_EXT_shader_image_int64
+		{ "ExecutionMode", "StencilRefReplacingEXT", 5027u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefUnchangedFrontAMD", 5079u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefGreaterFrontAMD", 5080u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefLessFrontAMD", 5081u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefUnchangedBackAMD", 5082u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefGreaterBackAMD", 5083u }, // SPV_EXT_shader_stencil_export
+		{ "ExecutionMode", "StencilRefLessBackAMD", 5084u }, // SPV_EXT_shader_stencil_export
+		{ "BuiltIn", "FragStencilRefEXT", 5014u }, // SPV_EXT_shader_stencil_export
+		{ "Capability", "StencilExportEXT", 5013u }, // SPV_EXT_shader_stencil_export
+		{ "Capability", "TileImageColorReadAccessEXT", 4166u }, // SPV_EXT_shader_tile_image
+		{ "Capability", "TileImageDepthReadAccessEXT", 4167u }, // SPV_EXT_shader_tile_image
+		{ "Capability", "TileImageStencilReadAccessEXT", 4168u }, // SPV_EXT_shader_tile_image
+		{ "Capability", "ShaderViewportIndexLayerEXT", 5254u }, // SPV_EXT_shader_viewport_index_layer
+		{ "Decoration", "HlslCounterBufferGOOGLE", 5634u }, // SPV_GOOGLE_hlsl_functionality1
+		{ "Decoration", "HlslSemanticGOOGLE", 5635u }, // SPV_GOOGLE_hlsl_functionality1
+		{ "Decoration", "UserTypeGOOGLE", 5636u }, // SPV_GOOGLE_user_type
+		{ "Capability", "ArbitraryPrecisionFixedPointINTEL", 5922u }, // SPV_INTEL_arbitrary_precision_fixed_point
+		{ "Capability", "ArbitraryPrecisionFloatingPointINTEL", 5845u }, // SPV_INTEL_arbitrary_precision_floating_point
+		{ "Capability", "ArbitraryPrecisionIntegersINTEL", 5844u }, // SPV_INTEL_arbitrary_precision_integers
+		{ "Capability", "BFloat16ConversionINTEL", 6115u }, // SPV_INTEL_bfloat16_conversion
+		{ "Capability", "BlockingPipesINTEL", 5945u }, // SPV_INTEL_blocking_pipes
+		{ "Capability", "CacheControlsINTEL", 6441u }, // SPV_INTEL_cache_controls
+		{ "Capability", "DebugInfoModuleINTEL", 6114u }, // SPV_INTEL_debug_module
+		{ "Capability", "SubgroupAvcMotionEstimationINTEL", 5696u }, // SPV_INTEL_device_side_avc_motion_estimation
+		{ "Capability", "SubgroupAvcMotionEstimationIntraINTEL", 5697u }, // SPV_INTEL_device_side_avc_motion_estimation
+		{ "Capability", "SubgroupAvcMotionEstimationChromaINTEL", 5698u }, // SPV_INTEL_device_side_avc_motion_estimation
+		{ "Capability", "RoundToInfinityINTEL", 5582u }, // SPV_INTEL_float_controls2
+		{ "Capability", "FloatingPointModeINTEL", 5583u }, // SPV_INTEL_float_controls2
+		{ "Capability", "FunctionFloatControlINTEL", 5821u }, // SPV_INTEL_float_controls2
+		{ "Capability", "FPFastMathModeINTEL", 5837u }, // SPV_INTEL_fp_fast_math_mode
+		{ "Capability", "FPMaxErrorINTEL", 6169u }, // SPV_INTEL_fp_max_error
+		{ "Capability", "FPGAArgumentInterfacesINTEL", 6174u }, // SPV_INTEL_fpga_argument_interfaces
+		{ "Capability", "FPGABufferLocationINTEL", 5920u }, // SPV_INTEL_fpga_buffer_location
+		{ "Capability", "FPGAClusterAttributesINTEL", 5904u }, // SPV_INTEL_fpga_cluster_attributes
+		{ "Capability", "FPGADSPControlINTEL", 5908u }, // SPV_INTEL_fpga_dsp_control
+		{ "Capability", "FPGAInvocationPipeliningAttributesINTEL", 5916u }, // SPV_INTEL_fpga_invocation_pipelining_attributes
+		{ "Capability", "FPGALatencyControlINTEL", 6171u }, // SPV_INTEL_fpga_latency_control
+		{ "Capability", "FPGALoopControlsINTEL", 5888u }, // SPV_INTEL_fpga_loop_controls
+		{ "Capability", "FPGAMemoryAccessesINTEL", 5898u }, // SPV_INTEL_fpga_memory_accesses
+		{ "Decoration", "RegisterINTEL", 5825u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "MemoryINTEL", 5826u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "NumbanksINTEL", 5827u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "BankwidthINTEL", 5828u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "MaxPrivateCopiesINTEL", 5829u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "SinglepumpINTEL", 5830u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "DoublepumpINTEL", 5831u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "MaxReplicatesINTEL", 5832u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "SimpleDualPortINTEL", 5833u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "MergeINTEL", 5834u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "BankBitsINTEL", 5835u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Decoration", "ForcePow2DepthINTEL", 5836u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Capability", "FPGAMemoryAttributesINTEL", 5824u }, // SPV_INTEL_fpga_memory_attributes
+		{ "Capability", "FPGARegINTEL", 5948u }, // SPV_INTEL_fpga_reg
+		{ "StorageClass", "CodeSectionINTEL", 5605u }, // SPV_INTEL_function_pointers
+		{ "Decoration", "ReferencedIndirectlyINTEL", 5602u }, // SPV_INTEL_function_pointers
+		{ "Capability", "FunctionPointersINTEL", 5603u }, // SPV_INTEL_function_pointers
+		{ "Capability", "IndirectReferencesINTEL", 5604u }, // SPV_INTEL_function_pointers
+		{ "Capability", "GlobalVariableFPGADecorationsINTEL", 6146u }, // SPV_INTEL_global_variable_fpga_decorations
+		{ "Capability", "GlobalVariableHostAccessINTEL", 6167u }, // SPV_INTEL_global_variable_host_access
+		{ "Capability", "AsmINTEL", 5606u }, // SPV_INTEL_inline_assembly
+		{ "Capability", "IOPipesINTEL", 5943u }, // SPV_INTEL_io_pipes
+		{ "ExecutionMode", "MaxWorkgroupSizeINTEL", 5893u }, // SPV_INTEL_kernel_attributes
+		{ "ExecutionMode", "MaxWorkDimINTEL", 5894u }, // SPV_INTEL_kernel_attributes
+		{ "ExecutionMode", "NoGlobalOffsetINTEL", 5895u }, // SPV_INTEL_kernel_attributes
+		{ "ExecutionMode", "NumSIMDWorkitemsINTEL", 5896u }, // SPV_INTEL_kernel_attributes
+		{ "Capability", "KernelAttributesINTEL", 5892u }, // SPV_INTEL_kernel_attributes
+		{ "Capability", "FPGAKernelAttributesINTEL", 5897u }, // SPV_INTEL_kernel_attributes
+		{ "Capability", "FPGAKernelAttributesv2INTEL", 6161u }, // SPV_INTEL_kernel_attributes
+		{ "Capability", "LongConstantCompositeINTEL", 6089u }, // SPV_INTEL_long_constant_composite
+		{ "Capability", "LoopFuseINTEL", 5906u }, // SPV_INTEL_loop_fuse
+		{ "Capability", "SubgroupImageMediaBlockIOINTEL", 5579u }, // SPV_INTEL_media_block_io
+		{ "MemoryAccess", "AliasScopeINTELMask", 65536u }, // SPV_INTEL_memory_access_aliasing
+		{ "MemoryAccess", "NoAliasINTELMask", 131072u }, // SPV_INTEL_memory_access_aliasing
+		{ "Capability", "MemoryAccessAliasingINTEL", 5910u }, // SPV_INTEL_memory_access_aliasing
+		{ "Capability", "OptNoneINTEL", 6094u }, // SPV_INTEL_optnone
+		{ "Capability", "RuntimeAlignedAttributeINTEL", 5939u }, // SPV_INTEL_runtime_aligned
+		{ "Capability", "IntegerFunctions2INTEL", 5584u }, // SPV_INTEL_shader_integer_functions2
+		{ "Capability", "SplitBarrierINTEL", 6141u }, // SPV_INTEL_split_barrier
+		{ "Capability", "SubgroupShuffleINTEL", 5568u }, // SPV_INTEL_subgroups
+		{ "Capability", "SubgroupBufferBlockIOINTEL", 5569u }, // SPV_INTEL_subgroups
+		{ "Capability", "SubgroupImageBlockIOINTEL", 5570u }, // SPV_INTEL_subgroups
+		{ "Capability", "UnstructuredLoopControlsINTEL", 5886u }, // SPV_INTEL_unstructured_loop_controls
+		{ "StorageClass", "DeviceOnlyINTEL", 5936u }, // SPV_INTEL_usm_storage_classes
+		{ "StorageClass", "HostOnlyINTEL", 5937u }, // SPV_INTEL_usm_storage_classes
+		{ "Capability", "USMStorageClassesINTEL", 5935u }, // SPV_INTEL_usm_storage_classes
+		{ "Capability", "VariableLengthArrayINTEL", 5817u }, // SPV_INTEL_variable_length_array
+		{ "Capability", "VectorComputeINTEL", 5617u }, // SPV_INTEL_vector_compute
+		{ "Capability", "VectorAnyINTEL", 5619u }, // SPV_INTEL_vector_compute
+		{ "Capability", "StorageBuffer16BitAccess", 4433u }, // SPV_KHR_16bit_storage
+		{ "Capability", "UniformAndStorageBuffer16BitAccess", 4434u }, // SPV_KHR_16bit_storage
+		{ "Capability", "StoragePushConstant16", 4435u }, // SPV_KHR_16bit_storage
+		{ "Capability", "StorageInputOutput16", 4436u }, // SPV_KHR_16bit_storage
+		{ "Capability", "StorageBuffer8BitAccess", 4448u }, // SPV_KHR_8bit_storage
+		{ "Capability", "UniformAndStorageBuffer8BitAccess", 4449u }, // SPV_KHR_8bit_storage
+		{ "Capability", "StoragePushConstant8", 4450u }, // SPV_KHR_8bit_storage
+		{ "Capability", "BitInstructions", 6025u }, // SPV_KHR_bit_instructions
+		{ "Capability", "CooperativeMatrixKHR", 6022u }, // SPV_KHR_cooperative_matrix
+		{ "BuiltIn", "DeviceIndex", 4438u }, // SPV_KHR_device_group
+		{ "Capability", "DeviceGroup", 4437u }, // SPV_KHR_device_group
+		{ "Capability", "ExpectAssumeKHR", 5629u }, // SPV_KHR_expect_assume
+		{ "ExecutionMode", "DenormPreserve", 4459u }, // SPV_KHR_float_controls
+		{ "ExecutionMode", "DenormFlushToZero", 4460u }, // SPV_KHR_float_controls
+		{ "ExecutionMode", "SignedZeroInfNanPreserve", 4461u }, // SPV_KHR_float_controls
+		{ "ExecutionMode", "RoundingModeRTE", 4462u }, // SPV_KHR_float_controls
+		{ "ExecutionMode", "RoundingModeRTZ", 4463u }, // SPV_KHR_float_controls
+		{ "Capability", "DenormPreserve", 4464u }, // SPV_KHR_float_controls
+		{ "Capability", "DenormFlushToZero", 4465u }, // SPV_KHR_float_controls
+		{ "Capability", "SignedZeroInfNanPreserve", 4466u }, // SPV_KHR_float_controls
+		{ "Capability", "RoundingModeRTE", 4467u }, // SPV_KHR_float_controls
+		{ "Capability", "RoundingModeRTZ", 4468u }, // SPV_KHR_float_controls
+		{ "Decoration", "PerVertexKHR", 5285u }, // SPV_KHR_fragment_shader_barycentric
+		{ "BuiltIn", "BaryCoordKHR", 5286u }, // SPV_KHR_fragment_shader_barycentric
+		{ "BuiltIn", "BaryCoordNoPerspKHR", 5287u }, // SPV_KHR_fragment_shader_barycentric
+		{ "Capability", "FragmentBarycentricKHR", 5284u }, // SPV_KHR_fragment_shader_barycentric
+		{ "BuiltIn", "PrimitiveShadingRateKHR", 4432u }, // SPV_KHR_fragment_shading_rate
+		{ "BuiltIn", "ShadingRateKHR", 4444u }, // SPV_KHR_fragment_shading_rate
+		{ "Capability", "FragmentShadingRateKHR", 4422u }, // SPV_KHR_fragment_shading_rate
+		{ "Capability", "DotProductInputAllKHR", 6016u }, // SPV_KHR_integer_dot_product
+		{ "Capability", "DotProductInput4x8BitKHR", 6017u }, // SPV_KHR_integer_dot_product
+		{ "Capability", "DotProductInput4x8BitPackedKHR", 6018u }, // SPV_KHR_integer_dot_product
+		{ "Capability", "DotProductKHR", 6019u }, // SPV_KHR_integer_dot_product
+		{ "PackedVectorFormat", "PackedVectorFormat4x8BitKHR", 0u }, // SPV_KHR_integer_dot_product
+		{ "LinkageType", "LinkOnceODR", 2u }, // SPV_KHR_linkonce_odr
+		{ "BuiltIn", "ViewIndex", 4440u }, // SPV_KHR_multiview
+		{ "Capability", "MultiView", 4439u }, // SPV_KHR_multiview
+		{ "Decoration", "NoSignedWrap", 4469u }, // SPV_KHR_no_integer_wrap_decoration
+		{ "Decoration", "NoUnsignedWrap", 4470u }, // SPV_KHR_no_integer_wrap_decoration
+		{ "AddressingModel", "PhysicalStorageBuffer64", 5348u }, // SPV_KHR_physical_storage_buffer
+		{ "StorageClass", "PhysicalStorageBuffer", 5349u }, // SPV_KHR_physical_storage_buffer
+		{ "Decoration", "RestrictPointer", 5355u }, // SPV_KHR_physical_storage_buffer
+		{ "Decoration", "AliasedPointer", 5356u }, // SPV_KHR_physical_storage_buffer
+		{ "Capability", "PhysicalStorageBufferAddresses", 5347u }, // SPV_KHR_physical_storage_buffer
+		{ "ExecutionMode", "PostDepthCoverage", 4446u }, // SPV_KHR_post_depth_coverage
+		{ "Capability", "SampleMaskPostDepthCoverage", 4447u }, // SPV_KHR_post_depth_coverage
+		{ "BuiltIn", "CullMaskKHR", 6021u }, // SPV_KHR_ray_cull_mask
+		{ "Capability", "RayCullMaskKHR", 6020u }, // SPV_KHR_ray_cull_mask
+		{ "Capability", "RayQueryProvisionalKHR", 4471u }, // SPV_KHR_ray_query
+		{ "Capability", "RayQueryKHR", 4472u }, // SPV_KHR_ray_query
+		{ "Capability", "RayTraversalPrimitiveCullingKHR", 4478u }, // SPV_KHR_ray_query
+		{ "StorageClass", "CallableDataNV", 5328u }, // SPV_KHR_ray_tracing
+		{ "StorageClass", "IncomingCallableDataNV", 5329u }, // SPV_KHR_ray_tracing
+		{ "StorageClass", "RayPayloadNV", 5338u }, // SPV_KHR_ray_tracing
+		{ "StorageClass", "HitAttributeNV", 5339u }, // SPV_KHR_ray_tracing
+		{ "StorageClass", "IncomingRayPayloadNV", 5342u }, // SPV_KHR_ray_tracing
+		{ "StorageClass", "ShaderRecordBufferNV", 5343u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "LaunchIdNV", 5319u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "LaunchSizeNV", 5320u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "WorldRayOriginNV", 5321u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "WorldRayDirectionNV", 5322u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "ObjectRayOriginNV", 5323u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "ObjectRayDirectionNV", 5324u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "RayTminNV", 5325u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "RayTmaxNV", 5326u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "InstanceCustomIndexNV", 5327u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "ObjectToWorldNV", 5330u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "WorldToObjectNV", 5331u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "HitKindNV", 5333u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "IncomingRayFlagsNV", 5351u }, // SPV_KHR_ray_tracing
+		{ "BuiltIn", "RayGeometryIndexKHR", 5352u }, // SPV_KHR_ray_tracing
+		{ "Capability", "RayTraversalPrimitiveCullingKHR", 4478u }, // SPV_KHR_ray_tracing
+		{ "Capability", "RayTracingKHR", 4479u }, // SPV_KHR_ray_tracing
+		{ "Capability", "RayTracingProvisionalKHR", 5353u }, // SPV_KHR_ray_tracing
+		{ "Capability", "RayTracingPositionFetchKHR", 5336u }, // SPV_KHR_ray_tracing_position_fetch
+		{ "Capability", "RayQueryPositionFetchKHR", 5391u }, // SPV_KHR_ray_tracing_position_fetch
+		{ "Capability", "AtomicStorageOps", 4445u }, // SPV_KHR_shader_atomic_counter_ops
+		{ "BuiltIn", "SubgroupEqMaskKHR", 4416u }, // SPV_KHR_shader_ballot
+		{ "BuiltIn", "SubgroupGeMaskKHR", 4417u }, // SPV_KHR_shader_ballot
+		{ "BuiltIn", "SubgroupGtMaskKHR", 4418u }, // SPV_KHR_shader_ballot
+		{ "BuiltIn", "SubgroupLeMaskKHR", 4419u }, // SPV_KHR_shader_ballot
+		{ "BuiltIn", "SubgroupLtMaskKHR", 4420u }, // SPV_KHR_shader_ballot
+		{ "Capability", "SubgroupBallotKHR", 4423u }, // SPV_KHR_shader_ballot
+		{ "Capability", "ShaderClockKHR", 5055u }, // SPV_KHR_shader_clock
+		{ "BuiltIn", "BaseVertex", 4424u }, // SPV_KHR_shader_draw_parameters
+		{ "BuiltIn", "BaseInstance", 4425u }, // SPV_KHR_shader_draw_parameters
+		{ "BuiltIn", "DrawIndex", 4426u }, // SPV_KHR_shader_draw_parameters
+		{ "Capability", "DrawParameters", 4427u }, // SPV_KHR_shader_draw_parameters
+		{ "StorageClass", "StorageBuffer", 12u }, // SPV_KHR_storage_buffer_storage_class
+		{ "Capability", "GroupNonUniformRotateKHR", 6026u }, // SPV_KHR_subgroup_rotate
+		{ "ExecutionMode", "SubgroupUniformControlFlowKHR", 4421u }, // SPV_KHR_subgroup_uniform_control_flow
+		{ "Capability", "SubgroupVoteKHR", 4431u }, // SPV_KHR_subgroup_vote
+		{ "Capability", "GroupUniformArithmeticKHR", 6400u }, // SPV_KHR_uniform_group_instructions
+		{ "StorageClass", "StorageBuffer", 12u }, // SPV_KHR_variable_pointers
+		{ "Capability", "VariablePointersStorageBuffer", 4441u }, // SPV_KHR_variable_pointers
+		{ "Capability", "VariablePointers", 4442u }, // SPV_KHR_variable_pointers
+		{ "ImageOperands", "MakeTexelAvailableKHR", 256u }, // SPV_KHR_vulkan_memory_model
+		{ "ImageOperands", "MakeTexelVisibleKHR", 512u }, // SPV_KHR_vulkan_memory_model
+		{ "ImageOperands", "NonPrivateTexelKHR", 1024u }, // SPV_KHR_vulkan_memory_model
+		{ "ImageOperands", "VolatileTexelKHR", 2048u }, // SPV_KHR_vulkan_memory_model
+		{ "MemorySemantics", "OutputMemoryKHR", 4096u }, // SPV_KHR_vulkan_memory_model
+		{ "MemorySemantics", "MakeAvailableKHR", 8192u }, // SPV_KHR_vulkan_memory_model
+		{ "MemorySemantics", "MakeVisibleKHR", 16384u }, // SPV_KHR_vulkan_memory_model
+		{ "MemorySemantics", "Volatile", 32768u }, // SPV_KHR_vulkan_memory_model
+		{ "MemoryAccess", "MakePointerAvailableKHR", 8u }, // SPV_KHR_vulkan_memory_model
+		{ "MemoryAccess", "MakePointerVisibleKHR", 16u }, // SPV_KHR_vulkan_memory_model
+		{ "MemoryAccess", "NonPrivatePointerKHR", 32u }, // SPV_KHR_vulkan_memory_model
+		{ "MemoryModel", "VulkanKHR", 3u }, // SPV_KHR_vulkan_memory_model
+		{ "Capability", "VulkanMemoryModelKHR", 5345u }, // SPV_KHR_vulkan_memory_model
+		{ "Capability", "VulkanMemoryModelDeviceScopeKHR", 5346u }, // SPV_KHR_vulkan_memory_model
+		{ "Capability", "WorkgroupMemoryExplicitLayoutKHR", 4428u }, // SPV_KHR_workgroup_memory_explicit_layout
+		{ "Capability", "WorkgroupMemoryExplicitLayout8BitAccessKHR", 4429u }, // SPV_KHR_workgroup_memory_explicit_layout
+		{ "Capability", "WorkgroupMemoryExplicitLayout16BitAccessKHR", 4430u }, // SPV_KHR_workgroup_memory_explicit_layout
+		{ "BuiltIn", "PositionPerViewNV", 5261u }, // SPV_NVX_multiview_per_view_attributes
+		{ "BuiltIn", "ViewportMaskPerViewNV", 5262u }, // SPV_NVX_multiview_per_view_attributes
+		{ "Capability", "PerViewAttributesNV", 5260u }, // SPV_NVX_multiview_per_view_attributes
+		{ "Capability", "BindlessTextureNV", 5390u }, // SPV_NV_bindless_texture
+		{ "ExecutionMode", "DerivativeGroupQuadsNV", 5289u }, // SPV_NV_compute_shader_derivatives
+		{ "ExecutionMode", "DerivativeGroupLinearNV", 5290u }, // SPV_NV_compute_shader_derivatives
+		{ "Capability", "ComputeDerivativeGroupQuadsNV", 5288u }, // SPV_NV_compute_shader_derivatives
+		{ "Capability", "ComputeDerivativeGroupLinearNV", 5350u }, // SPV_NV_compute_shader_derivatives
+		{ "Capability", "CooperativeMatrixNV", 5357u }, // SPV_NV_cooperative_matrix
+		{ "Decoration", "PerVertexKHR", 5285u }, // SPV_NV_fragment_shader_barycentric
+		{ "BuiltIn", "BaryCoordKHR", 5286u }, // SPV_NV_fragment_shader_barycentric
+		{ "BuiltIn", "BaryCoordNoPerspKHR", 5287u }, // SPV_NV_fragment_shader_barycentric
+		{ "Capability", "FragmentBarycentricKHR", 5284u }, // SPV_NV_fragment_shader_barycentric
+		{ "Decoration", "PassthroughNV", 5250u }, // SPV_NV_geometry_shader_passthrough
+		{ "Capability", "GeometryShaderPassthroughNV", 5251u }, // SPV_NV_geometry_shader_passthrough
+		{ "ExecutionMode", "OutputLinesNV", 5269u }, // SPV_NV_mesh_shader
+		{ "ExecutionMode", "OutputPrimitivesNV", 5270u }, // SPV_NV_mesh_shader
+		{ "ExecutionMode", "OutputTrianglesNV", 5298u }, // SPV_NV_mesh_shader
+		{ "Decoration", "PerPrimitiveNV", 5271u }, // SPV_NV_mesh_shader
+		{ "Decoration", "PerViewNV", 5272u }, // SPV_NV_mesh_shader
+		{ "Decoration", "PerTaskNV", 5273u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "DrawIndex", 4426u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "ViewportMaskNV", 5253u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "PositionPerViewNV", 5261u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "ViewportMaskPerViewNV", 5262u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "TaskCountNV", 5274u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "PrimitiveCountNV", 5275u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "PrimitiveIndicesNV", 5276u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "ClipDistancePerViewNV", 5277u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "CullDistancePerViewNV", 5278u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "LayerPerViewNV", 5279u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "MeshViewCountNV", 5280u }, // SPV_NV_mesh_shader
+		{ "BuiltIn", "MeshViewIndicesNV", 5281u }, // SPV_NV_mesh_shader
+		{ "Capability", "MeshShadingNV", 5266u }, // SPV_NV_mesh_shader
+		{ "StorageClass", "CallableDataNV", 5328u }, // SPV_NV_ray_tracing
+		{ "StorageClass", "IncomingCallableDataNV", 5329u }, // SPV_NV_ray_tracing
+		{ "StorageClass", "RayPayloadNV", 5338u }, // SPV_NV_ray_tracing
+		{ "StorageClass", "HitAttributeNV", 5339u }, // SPV_NV_ray_tracing
+		{ "StorageClass", "IncomingRayPayloadNV", 5342u }, // SPV_NV_ray_tracing
+		{ "StorageClass", "ShaderRecordBufferNV", 5343u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "LaunchIdNV", 5319u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "LaunchSizeNV", 5320u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "WorldRayOriginNV", 5321u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "WorldRayDirectionNV", 5322u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "ObjectRayOriginNV", 5323u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "ObjectRayDirectionNV", 5324u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "RayTminNV", 5325u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "RayTmaxNV", 5326u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "InstanceCustomIndexNV", 5327u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "ObjectToWorldNV", 5330u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "WorldToObjectNV", 5331u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "HitTNV", 5332u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "HitKindNV", 5333u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "IncomingRayFlagsNV", 5351u }, // SPV_NV_ray_tracing
+		{ "Capability", "RayTracingNV", 5340u }, // SPV_NV_ray_tracing
+		{ "BuiltIn", "CurrentRayTimeNV", 5334u }, // SPV_NV_ray_tracing_motion_blur
+		{ "Capability", "RayTracingMotionBlurNV", 5341u }, // SPV_NV_ray_tracing_motion_blur
+		{ "Decoration", "OverrideCoverageNV", 5248u }, // SPV_NV_sample_mask_override_coverage
+		{ "Capability", "SampleMaskOverrideCoverageNV", 5249u }, // SPV_NV_sample_mask_override_coverage
+		{ "Capability", "ImageFootprintNV", 5282u }, // SPV_NV_shader_image_footprint
+		{ "Capability", "ShaderInvocationReorderNV", 5383u }, // SPV_NV_shader_invocation_reorder
+		{ "BuiltIn", "WarpsPerSMNV", 5374u }, // SPV_NV_shader_sm_builtins
+		{ "BuiltIn", "SMCountNV", 5375u }, // SPV_NV_shader_sm_builtins
+		{ "BuiltIn", "WarpIDNV", 5376u }, // SPV_NV_shader_sm_builtins
+		{ "BuiltIn", "SMIDNV", 5377u }, // SPV_NV_shader_sm_builtins
+		{ "Capability", "ShaderSMBuiltinsNV", 5373u }, // SPV_NV_shader_sm_builtins
+		{ "GroupOperation", "PartitionedReduceNV", 6u }, // SPV_NV_shader_subgroup_partitioned
+		{ "GroupOperation", "PartitionedInclusiveScanNV", 7u }, // SPV_NV_shader_subgroup_partitioned
+		{ "GroupOperation", "PartitionedExclusiveScanNV", 8u }, // SPV_NV_shader_subgroup_partitioned
+		{ "Capability", "GroupNonUniformPartitionedNV", 5297u }, // SPV_NV_shader_subgroup_partitioned
+		{ "BuiltIn", "FragSizeEXT", 5292u }, // SPV_NV_shading_rate
+		{ "BuiltIn", "FragInvocationCountEXT", 5293u }, // SPV_NV_shading_rate
+		{ "Capability", "FragmentDensityEXT", 5291u }, // SPV_NV_shading_rate
+		{ "Decoration", "SecondaryViewportRelativeNV", 5256u }, // SPV_NV_stereo_view_rendering
+		{ "BuiltIn", "SecondaryPositionNV", 5257u }, // SPV_NV_stereo_view_rendering
+		{ "BuiltIn", "SecondaryViewportMaskNV", 5258u }, // SPV_NV_stereo_view_rendering
+		{ "Capability", "ShaderStereoViewNV", 5259u }, // SPV_NV_stereo_view_rendering
+		{ "BuiltIn", "ViewportMaskNV", 5253u }, // SPV_NV_viewport_array2
+		{ "Capability", "ShaderViewportIndexLayerNV", 5254u }, // SPV_NV_viewport_array2
+		{ "Capability", "ShaderViewportMaskNV", 5255u }, // SPV_NV_viewport_array2
+		{ "Decoration", "WeightTextureQCOM", 4487u }, // SPV_QCOM_image_processing
+		{ "Decoration", "BlockMatchTextureQCOM", 4488u }, // SPV_QCOM_image_processing
+		{ "Capability", "TextureSampleWeightedQCOM", 4484u }, // SPV_QCOM_image_processing
+		{ "Capability", "TextureBoxFilterQCOM", 4485u }, // SPV_QCOM_image_processing
+		{ "Capability", "TextureBlockMatchQCOM", 4486u }, // SPV_QCOM_image_processing
+	};
+	static constexpr ExtensionInfo ExtensionInfos[] =
+	{
+		{ "AMDX", NotInCore, ExtensionOps + 0, 0u, ExtensionEnumerants + 0, 1u }, // SPV_AMDX_shader_enqueue
+		{ "AMD", NotInCore, ExtensionOps + 0, 0u, ExtensionEnumerants + 1, 1u }, // SPV_AMD_gpu_shader_half_float_fetch
+		{ "AMD", NotInCore, ExtensionOps + 0, 8u, ExtensionEnumerants + 2, 1u }, // SPV_AMD_shader_ballot
+		{ "AMD", NotInCore, ExtensionOps + 8, 0u, ExtensionEnumerants + 3, 7u }, // SPV_AMD_shader_early_and_late_fragment_tests
+		{ "AMD", NotInCore, ExtensionOps + 8, 0u, ExtensionEnumerants + 10, 8u }, // SPV_AMD_shader_explicit_vertex_parameter
+		{ "AMD", NotInCore, ExtensionOps + 8, 2u, ExtensionEnumerants + 18, 1u }, // SPV_AMD_shader_fragment_mask
+		{ "AMD", NotInCore, ExtensionOps + 10, 0u, ExtensionEnumerants + 19, 1u }, // SPV_AMD_shader_image_load_store_lod
+		{ "AMD", NotInCore, ExtensionOps + 10, 0u, ExtensionEnumerants + 20, 1u }, // SPV_AMD_texture_gather_bias_lod
+		{ "ARM", NotInCore, ExtensionOps + 10, 0u, ExtensionEnumerants + 21, 1u }, // SPV_ARM_core_builtins
+		{ "EXT", NotInCore, ExtensionOps + 10, 1u, ExtensionEnumerants + 22, 1u }, // SPV_EXT_demote_to_helper_invocation
+		{ "EXT", 0x10500u, ExtensionOps + 11, 0u, ExtensionEnumerants + 23, 13u }, // SPV_EXT_descriptor_indexing
+		{ "EXT", NotInCore, ExtensionOps + 11, 0u, ExtensionEnumerants + 36, 2u }, // SPV_EXT_fragment_fully_covered
+		{ "EXT", NotInCore, ExtensionOps + 11, 0u, ExtensionEnumerants + 38, 3u }, // SPV_EXT_fragment_invocation_density
+		{ "EXT", NotInCore, ExtensionOps + 11, 2u, ExtensionEnumerants + 41, 9u }, // SPV_EXT_fragment_shader_interlock
+		{ "EXT", NotInCore, ExtensionOps + 13, 0u, ExtensionEnumerants + 50, 12u }, // SPV_EXT_mesh_shader
+		{ "EXT", NotInCore, ExtensionOps + 13, 0u, ExtensionEnumerants + 62, 1u }, // SPV_EXT_opacity_micromap
+		{ "EXT", 0x10500u, ExtensionOps + 13, 0u, ExtensionEnumerants + 63, 5u }, // SPV_EXT_physical_storage_buffer
+		{ "EXT", NotInCore, ExtensionOps + 13, 0u, ExtensionEnumerants + 68, 1u }, // SPV_EXT_shader_atomic_float16_add
+		{ "EXT", NotInCore, ExtensionOps + 13, 1u, ExtensionEnumerants + 69, 2u }, // SPV_EXT_shader_atomic_float_add
+		{ "EXT", NotInCore, ExtensionOps + 14, 0u, ExtensionEnumerants + 71, 3u }, // SPV_EXT_shader_atomic_float_min_max
+		{ "EXT", NotInCore, ExtensionOps + 14, 0u, ExtensionEnumerants + 74, 1u }, // SPV_EXT_shader_image_int64
+		{ "EXT", NotInCore, ExtensionOps + 14, 0u, ExtensionEnumerants + 75, 9u }, // SPV_EXT_shader_stencil_export
+		{ "EXT", NotInCore, ExtensionOps + 14, 0u, ExtensionEnumerants + 84, 3u }, // SPV_EXT_shader_tile_image
+		{ "EXT", NotInCore, ExtensionOps + 14, 0u, ExtensionEnumerants + 87, 1u }, // SPV_EXT_shader_viewport_index_layer
+		{ "GOOGLE", 0x10400u, ExtensionOps + 14, 2u, ExtensionEnumerants + 88, 0u }, // SPV_GOOGLE_decorate_string
+		{ "GOOGLE", NotInCore, ExtensionOps + 16, 3u, ExtensionEnumerants + 88, 2u }, // SPV_GOOGLE_hlsl_functionality1
+		{ "GOOGLE", NotInCore, ExtensionOps + 19, 0u, ExtensionEnumerants + 90, 1u }, // SPV_GOOGLE_user_type
+		{ "INTEL", NotInCore, ExtensionOps + 19, 0u, ExtensionEnumerants + 91, 1u }, // SPV_INTEL_arbitrary_precision_fixed_point
+		{ "INTEL", NotInCore, ExtensionOps + 19, 0u, ExtensionEnumerants + 92, 1u }, // SPV_INTEL_arbitrary_precision_floating_point
+		{ "INTEL", NotInCore, ExtensionOps + 19, 0u, ExtensionEnumerants + 93, 1u }, // SPV_INTEL_arbitrary_precision_integers
+		{ "INTEL", NotInCore, ExtensionOps + 19, 0u, ExtensionEnumerants + 94, 1u }, // SPV_INTEL_bfloat16_conversion
+		{ "INTEL", NotInCore, ExtensionOps + 19, 2u, ExtensionEnumerants + 95, 1u }, // SPV_INTEL_blocking_pipes
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 96, 1u }, // SPV_INTEL_cache_controls
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 97, 1u }, // SPV_INTEL_debug_module
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 98, 3u }, // SPV_INTEL_device_side_avc_motion_estimation
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 101, 3u }, // SPV_INTEL_float_controls2
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 104, 1u }, // SPV_INTEL_fp_fast_math_mode
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 105, 1u }, // SPV_INTEL_fp_max_error
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 106, 1u }, // SPV_INTEL_fpga_argument_interfaces
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 107, 1u }, // SPV_INTEL_fpga_buffer_location
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 108, 1u }, // SPV_INTEL_fpga_cluster_attributes
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 109, 1u }, // SPV_INTEL_fpga_dsp_control
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 110, 1u }, // SPV_INTEL_fpga_invocation_pipelining_attributes
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 111, 1u }, // SPV_INTEL_fpga_latency_control
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 112, 1u }, // SPV_INTEL_fpga_loop_controls
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 113, 1u }, // SPV_INTEL_fpga_memory_accesses
+		{ "INTEL", NotInCore, ExtensionOps + 21, 0u, ExtensionEnumerants + 114, 13u }, // SPV_INTEL_fpga_memory_attributes
+		{ "INTEL", NotInCore, ExtensionOps + 21, 1u, ExtensionEnumerants + 127, 1u }, // SPV_INTEL_fpga_reg
+		{ "INTEL", NotInCore, ExtensionOps + 22, 2u, ExtensionEnumerants + 128, 4u }, // SPV_INTEL_function_pointers
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 132, 1u }, // SPV_INTEL_global_variable_fpga_decorations
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 133, 1u }, // SPV_INTEL_global_variable_host_access
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 134, 1u }, // SPV_INTEL_inline_assembly
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 135, 1u }, // SPV_INTEL_io_pipes
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 136, 7u }, // SPV_INTEL_kernel_attributes
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 143, 1u }, // SPV_INTEL_long_constant_composite
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 144, 1u }, // SPV_INTEL_loop_fuse
+		{ "INTEL", NotInCore, ExtensionOps + 24, 0u, ExtensionEnumerants + 145, 1u }, // SPV_INTEL_media_block_io
+		{ "INTEL", NotInCore, ExtensionOps + 24, 3u, ExtensionEnumerants + 146, 3u }, // SPV_INTEL_memory_access_aliasing
+		{ "INTEL", NotInCore, ExtensionOps + 27, 0u, ExtensionEnumerants + 149, 1u }, // SPV_INTEL_optnone
+		{ "INTEL", NotInCore, ExtensionOps + 27, 0u, ExtensionEnumerants + 150, 1u }, // SPV_INTEL_runtime_aligned
+		{ "INTEL", NotInCore, ExtensionOps + 27, 0u, ExtensionEnumerants + 151, 1u }, // SPV_INTEL_shader_integer_functions2
+		{ "INTEL", NotInCore, ExtensionOps + 27, 0u, ExtensionEnumerants + 152, 1u }, // SPV_INTEL_split_barrier
+		{ "INTEL", NotInCore, ExtensionOps + 27, 0u, ExtensionEnumerants + 153, 3u }, // SPV_INTEL_subgroups
+		{ "INTEL", NotInCore, ExtensionOps + 27, 1u, ExtensionEnumerants + 156, 1u }, // SPV_INTEL_unstructured_loop_controls
+		{ "INTEL", NotInCore, ExtensionOps + 28, 0u, ExtensionEnumerants + 157, 3u }, // SPV_INTEL_usm_storage_classes
+		{ "INTEL", NotInCore, ExtensionOps + 28, 0u, ExtensionEnumerants + 160, 1u }, // SPV_INTEL_variable_length_array
+		{ "INTEL", NotInCore, ExtensionOps + 28, 0u, ExtensionEnumerants + 161, 2u }, // SPV_INTEL_vector_compute
+		{ "KHR", 0x10300u, ExtensionOps + 28, 0u, ExtensionEnumerants + 163, 4u }, // SPV_KHR_16bit_storage
+		{ "KHR", 0x10500u, ExtensionOps + 28, 0u, ExtensionEnumerants + 167, 3u }, // SPV_KHR_8bit_storage
+		{ "KHR", NotInCore, ExtensionOps + 28, 0u, ExtensionEnumerants + 170, 1u }, // SPV_KHR_bit_instructions
+		{ "KHR", NotInCore, ExtensionOps + 28, 0u, ExtensionEnumerants + 171, 1u }, // SPV_KHR_cooperative_matrix
+		{ "KHR", 0x10300u, ExtensionOps + 28, 0u, ExtensionEnumerants + 172, 2u }, // SPV_KHR_device_group
+		{ "KHR", NotInCore, ExtensionOps + 28, 2u, ExtensionEnumerants + 174, 1u }, // SPV_KHR_expect_assume
+		{ "KHR", 0x10400u, ExtensionOps + 30, 0u, ExtensionEnumerants + 175, 10u }, // SPV_KHR_float_controls
+		{ "KHR", NotInCore, ExtensionOps + 30, 0u, ExtensionEnumerants + 185, 4u }, // SPV_KHR_fragment_shader_barycentric
+		{ "KHR", NotInCore, ExtensionOps + 30, 0u, ExtensionEnumerants + 189, 3u }, // SPV_KHR_fragment_shading_rate
+		{ "KHR", 0x10600u, ExtensionOps + 30, 6u, ExtensionEnumerants + 192, 5u }, // SPV_KHR_integer_dot_product
+		{ "KHR", NotInCore, ExtensionOps + 36, 0u, ExtensionEnumerants + 197, 1u }, // SPV_KHR_linkonce_odr
+		{ "KHR", 0x10300u, ExtensionOps + 36, 0u, ExtensionEnumerants + 198, 2u }, // SPV_KHR_multiview
+		{ "KHR", 0x10400u, ExtensionOps + 36, 0u, ExtensionEnumerants + 200, 2u }, // SPV_KHR_no_integer_wrap_decoration
+		{ "KHR", 0x10500u, ExtensionOps + 36, 0u, ExtensionEnumerants + 202, 5u }, // SPV_KHR_physical_storage_buffer
+		{ "KHR", NotInCore, ExtensionOps + 36, 0u, ExtensionEnumerants + 207, 2u }, // SPV_KHR_post_depth_coverage
+		{ "KHR", NotInCore, ExtensionOps + 36, 0u, ExtensionEnumerants + 209, 2u }, // SPV_KHR_ray_cull_mask
+		{ "KHR", NotInCore, ExtensionOps + 36, 26u, ExtensionEnumerants + 211, 3u }, // SPV_KHR_ray_query
+		{ "KHR", NotInCore, ExtensionOps + 62, 7u, ExtensionEnumerants + 214, 23u }, // SPV_KHR_ray_tracing
+		{ "KHR", NotInCore, ExtensionOps + 69, 0u, ExtensionEnumerants + 237, 2u }, // SPV_KHR_ray_tracing_position_fetch
+		{ "KHR", NotInCore, ExtensionOps + 69, 0u, ExtensionEnumerants + 239, 1u }, // SPV_KHR_shader_atomic_counter_ops
+		{ "KHR", NotInCore, ExtensionOps + 69, 3u, ExtensionEnumerants + 240, 6u }, // SPV_KHR_shader_ballot
+		{ "KHR", NotInCore, ExtensionOps + 72, 0u, ExtensionEnumerants + 246, 1u }, // SPV_KHR_shader_clock
+		{ "KHR", 0x10300u, ExtensionOps + 72, 0u, ExtensionEnumerants + 247, 4u }, // SPV_KHR_shader_draw_parameters
+		{ "KHR", 0x10300u, ExtensionOps + 72, 0u, ExtensionEnumerants + 251, 1u }, // SPV_KHR_storage_buffer_storage_class
+		{ "KHR", NotInCore, ExtensionOps + 72, 0u, ExtensionEnumerants + 252, 1u }, // SPV_KHR_subgroup_rotate
+		{ "KHR", NotInCore, ExtensionOps + 72, 0u, ExtensionEnumerants + 253, 1u }, // SPV_KHR_subgroup_uniform_control_flow
+		{ "KHR", NotInCore, ExtensionOps + 72, 3u, ExtensionEnumerants + 254, 1u }, // SPV_KHR_subgroup_vote
+		{ "KHR", 0x10600u, ExtensionOps + 75, 1u, ExtensionEnumerants + 255, 0u }, // SPV_KHR_terminate_invocation
+		{ "KHR", NotInCore, ExtensionOps + 76, 0u, ExtensionEnumerants + 255, 1u }, // SPV_KHR_uniform_group_instructions
+		{ "KHR", 0x10300u, ExtensionOps + 76, 0u, ExtensionEnumerants + 256, 3u }, // SPV_KHR_variable_pointers
+		{ "KHR", 0x10500u, ExtensionOps + 76, 0u, ExtensionEnumerants + 259, 14u }, // SPV_KHR_vulkan_memory_model
+		{ "KHR", NotInCore, ExtensionOps + 76, 0u, ExtensionEnumerants + 273, 3u }, // SPV_KHR_workgroup_memory_explicit_layout
+		{ "NVX", NotInCore, ExtensionOps + 76, 0u, ExtensionEnumerants + 276, 3u }, // SPV_NVX_multiview_per_view_attributes
+		{ "NV", NotInCore, ExtensionOps + 76, 0u, ExtensionEnumerants + 279, 1u }, // SPV_NV_bindless_texture
+		{ "NV", NotInCore, ExtensionOps + 76, 0u, ExtensionEnumerants + 280, 4u }, // SPV_NV_compute_shader_derivatives
+		{ "NV", NotInCore, ExtensionOps + 76, 5u, ExtensionEnumerants + 284, 1u }, // SPV_NV_cooperative_matrix
+		{ "NV", NotInCore, ExtensionOps + 81, 0u, ExtensionEnumerants + 285, 4u }, // SPV_NV_fragment_shader_barycentric
+		{ "NV", NotInCore, ExtensionOps + 81, 0u, ExtensionEnumerants + 289, 2u }, // SPV_NV_geometry_shader_passthrough
+		{ "NV", NotInCore, ExtensionOps + 81, 1u, ExtensionEnumerants + 291, 19u }, // SPV_NV_mesh_shader
+		{ "NV", NotInCore, ExtensionOps + 82, 6u, ExtensionEnumerants + 310, 21u }, // SPV_NV_ray_tracing
+		{ "NV", NotInCore, ExtensionOps + 88, 2u, ExtensionEnumerants + 331, 2u }, // SPV_NV_ray_tracing_motion_blur
+		{ "NV", NotInCore, ExtensionOps + 90, 0u, ExtensionEnumerants + 333, 2u }, // SPV_NV_sample_mask_override_coverage
+		{ "NV", NotInCore, ExtensionOps + 90, 1u, ExtensionEnumerants + 335, 1u }, // SPV_NV_shader_image_footprint
+		{ "NV", NotInCore, ExtensionOps + 91, 0u, ExtensionEnumerants + 336, 1u }, // SPV_NV_shader_invocation_reorder
+		{ "NV", NotInCore, ExtensionOps + 91, 0u, ExtensionEnumerants + 337, 5u }, // SPV_NV_shader_sm_builtins
+		{ "NV", NotInCore, ExtensionOps + 91, 1u, ExtensionEnumerants + 342, 4u }, // SPV_NV_shader_subgroup_partitioned
+		{ "NV", NotInCore, ExtensionOps + 92, 0u, ExtensionEnumerants + 346, 3u }, // SPV_NV_shading_rate
+		{ "NV", NotInCore, ExtensionOps + 92, 0u, ExtensionEnumerants + 349, 4u }, // SPV_NV_stereo_view_rendering
+		{ "NV", NotInCore, ExtensionOps + 92, 0u, ExtensionEnumerants + 353, 3u }, // SPV_NV_viewport_array2
+		{ "QCOM", NotInCore, ExtensionOps + 92, 0u, ExtensionEnumerants + 356, 5u }, // SPV_QCOM_image_processing
+	};
+	inline constexpr const ExtensionInfo& GetExtensionInfo(Extension ext) { return ExtensionInfos[static_cast<unsigned>(ext)]; }
+	// OpExtension can be skipped when targeting a version that already includes everything the extension enables
+	inline constexpr bool IsExtensionInCore(Extension ext, unsigned int version) { const unsigned int since = GetExtensionInfo(ext).coreSince; return since != NotInCore && version >= since; }
+	enum class InstructionClass : unsigned char
+	{
+		None, // unknown opcode or no meaningful class
+		Annotation,
+		Arithmetic,
+		Atomic,
+		Barrier,
+		Bit,
+		Composite,
+		ConstantCreation,
+		ControlFlow,
+		Conversion,
+		Debug,
+		Derivative,
+		DeviceSideEnqueue,
+		Extension,
+		Function,
+		Group,
+		Image,
+		Memory,
+		Miscellaneous,
+		ModeSetting,
+		NonUniform,
+		Pipe,
+		Primitive,
+		RelationalAndLogical,
+		TypeDeclaration,
+	};
+	struct InstructionInfo
+	{
+		bool valid : 1; // false for unknown opcodes
+		bool hasResult : 1;
+		bool hasResultType : 1;
+		bool isType : 1;
+		bool isConstant : 1;
+		bool isSpecConstant : 1;
+		InstructionClass instructionClass;
+		unsigned short operandCount; // operands listed in the grammar, optional and variadic ones count once
+		unsigned int minVersion; // first core version like 0x00010300, 0 if only available through extensions
+	};
+	inline constexpr InstructionInfo GetInfo(Op opcode) {
+		switch (opcode) {
+		default: return { false, false, false, false, false, false, InstructionClass::None, 0u, 0u }; // unknown opcode
+		case Op::OpNop: return { true, false, false, false, false, false, InstructionClass::Miscellaneous, 0u, 0x10000u };
+		case Op::OpUndef: return { true, true, true, false, false, false, InstructionClass::Miscellaneous, 2u, 0x10000u };
+		case Op::OpSourceContinued: return { true, false, false, false, false, false, InstructionClass::Debug, 1u, 0x10000u };
+		case Op::OpSource: return { true, false, false, false, false, false, InstructionClass::Debug, 4u, 0x10000u };
+		case Op::OpSourceExtension: return { true, false, false, false, false, false, InstructionClass::Debug, 1u, 0x10000u };
+		case Op::OpName: return { true, false, false, false, false, false, InstructionClass::Debug, 2u, 0x10000u };
+		case Op::OpMemberName: return { true, false, false, false, false, false, InstructionClass::Debug, 3u, 0x10000u };
+		case Op::OpString: return { true, true, false, false, false, false, InstructionClass::Debug, 2u, 0x10000u };
+		case Op::OpLine: return { true, false, false, false, false, false, InstructionClass::Debug, 3u, 0x10000u };
+		case Op::OpExtension: return { true, false, false, false, false, false, InstructionClass::Extension, 1u, 0x10000u };
+		case Op::OpExtInstImport: return { true, true, false, false, false, false, InstructionClass::Extension, 2u, 0x10000u };
+		case Op::OpExtInst: return { true, true, true, false, false, false, InstructionClass::Extension, 5u, 0x10000u };
+		case Op::OpMemoryModel: return { true, false, false, false, false, false, InstructionClass::ModeSetting, 2u, 0x10000u };
+		case Op::OpEntryPoint: return { true, false, false, false, false, false, InstructionClass::ModeSetting, 4u, 0x10000u };
+		case Op::OpExecutionMode: return { true, false, false, false, false, false, InstructionClass::ModeSetting, 2u, 0x10000u };
+		case Op::OpCapability: return { true, false, false, false, false, false, InstructionClass::ModeSetting, 1u, 0x10000u };
+		case Op::OpTypeVoid: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeBool: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeInt: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeFloat: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypeVector: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeMatrix: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeImage: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 9u, 0x10000u };
+		case Op::OpTypeSampler: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeSampledImage: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypeArray: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeRuntimeArray: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypeStruct: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypeOpaque: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypePointer: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeFunction: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 3u, 0x10000u };
+		case Op::OpTypeEvent: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeDeviceEvent: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeReserveId: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypeQueue: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10000u };
+		case Op::OpTypePipe: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpTypeForwardPointer: return { true, false, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x10000u };
+		case Op::OpConstantTrue: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 2u, 0x10000u };
+		case Op::OpConstantFalse: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 2u, 0x10000u };
+		case Op::OpConstant: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 3u, 0x10000u };
+		case Op::OpConstantComposite: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 3u, 0x10000u };
+		case Op::OpConstantSampler: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 5u, 0x10000u };
+		case Op::OpConstantNull: return { true, true, true, false, true, false, InstructionClass::ConstantCreation, 2u, 0x10000u };
+		case Op::OpSpecConstantTrue: return { true, true, true, false, false, true, InstructionClass::ConstantCreation, 2u, 0x10000u };
+		case Op::OpSpecConstantFalse: return { true, true, true, false, false, true, InstructionClass::ConstantCreation, 2u, 0x10000u };
+		case Op::OpSpecConstant: return { true, true, true, false, false, true, InstructionClass::ConstantCreation, 3u, 0x10000u };
+		case Op::OpSpecConstantComposite: return { true, true, true, false, false, true, InstructionClass::ConstantCreation, 3u, 0x10000u };
+		case Op::OpSpecConstantOp: return { true, true, true, false, false, true, InstructionClass::ConstantCreation, 3u, 0x10000u };
+		case Op::OpFunction: return { true, true, true, false, false, false, InstructionClass::Function, 4u, 0x10000u };
+		case Op::OpFunctionParameter: return { true, true, true, false, false, false, InstructionClass::Function, 2u, 0x10000u };
+		case Op::OpFunctionEnd: return { true, false, false, false, false, false, InstructionClass::Function, 0u, 0x10000u };
+		case Op::OpFunctionCall: return { true, true, true, false, false, false, InstructionClass::Function, 4u, 0x10000u };
+		case Op::OpVariable: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpImageTexelPointer: return { true, true, true, false, false, false, InstructionClass::Memory, 5u, 0x10000u };
+		case Op::OpLoad: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpStore: return { true, false, false, false, false, false, InstructionClass::Memory, 3u, 0x10000u };
+		case Op::OpCopyMemory: return { true, false, false, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpCopyMemorySized: return { true, false, false, false, false, false, InstructionClass::Memory, 5u, 0x10000u };
+		case Op::OpAccessChain: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpInBoundsAccessChain: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpPtrAccessChain: return { true, true, true, false, false, false, InstructionClass::Memory, 5u, 0x10000u };
+		case Op::OpArrayLength: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10000u };
+		case Op::OpGenericPtrMemSemantics: return { true, true, true, false, false, false, InstructionClass::Memory, 3u, 0x10000u };
+		case Op::OpInBoundsPtrAccessChain: return { true, true, true, false, false, false, InstructionClass::Memory, 5u, 0x10000u };
+		case Op::OpDecorate: return { true, false, false, false, false, false, InstructionClass::Annotation, 2u, 0x10000u };
+		case Op::OpMemberDecorate: return { true, false, false, false, false, false, InstructionClass::Annotation, 3u, 0x10000u };
+		case Op::OpDecorationGroup: return { true, true, false, false, false, false, InstructionClass::Annotation, 1u, 0x10000u };
+		case Op::OpGroupDecorate: return { true, false, false, false, false, false, InstructionClass::Annotation, 2u, 0x10000u };
+		case Op::OpGroupMemberDecorate: return { true, false, false, false, false, false, InstructionClass::Annotation, 2u, 0x10000u };
+		case Op::OpVectorExtractDynamic: return { true, true, true, false, false, false, InstructionClass::Composite, 4u, 0x10000u };
+		case Op::OpVectorInsertDynamic: return { true, true, true, false, false, false, InstructionClass::Composite, 5u, 0x10000u };
+		case Op::OpVectorShuffle: return { true, true, true, false, false, false, InstructionClass::Composite, 5u, 0x10000u };
+		case Op::OpCompositeConstruct: return { true, true, true, false, false, false, InstructionClass::Composite, 3u, 0x10000u };
+		case Op::OpCompositeExtract: return { true, true, true, false, false, false, InstructionClass::Composite, 4u, 0x10000u };
+		case Op::OpCompositeInsert: return { true, true, true, false, false, false, InstructionClass::Composite, 5u, 0x10000u };
+		case Op::OpCopyObject: return { true, true, true, false, false, false, InstructionClass::Composite, 3u, 0x10000u };
+		case Op::OpTranspose: return { true, true, true, false, false, false, InstructionClass::Composite, 3u, 0x10000u };
+		case Op::OpSampledImage: return { true, true, true, false, false, false, InstructionClass::Image, 4u, 0x10000u };
+		case Op::OpImageSampleImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSampleExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSampleDrefImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSampleDrefExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSampleProjImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSampleProjExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSampleProjDrefImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSampleProjDrefExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageFetch: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageGather: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageDrefGather: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageRead: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageWrite: return { true, false, false, false, false, false, InstructionClass::Image, 4u, 0x10000u };
+		case Op::OpImage: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpImageQueryFormat: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpImageQueryOrder: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpImageQuerySizeLod: return { true, true, true, false, false, false, InstructionClass::Image, 4u, 0x10000u };
+		case Op::OpImageQuerySize: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpImageQueryLod: return { true, true, true, false, false, false, InstructionClass::Image, 4u, 0x10000u };
+		case Op::OpImageQueryLevels: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpImageQuerySamples: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpConvertFToU: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpConvertFToS: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpConvertSToF: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpConvertUToF: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpUConvert: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpSConvert: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpFConvert: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpQuantizeToF16: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpConvertPtrToU: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpSatConvertSToU: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpSatConvertUToS: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpConvertUToPtr: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpPtrCastToGeneric: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpGenericCastToPtr: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpGenericCastToPtrExplicit: return { true, true, true, false, false, false, InstructionClass::Conversion, 4u, 0x10000u };
+		case Op::OpBitcast: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x10000u };
+		case Op::OpSNegate: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 3u, 0x10000u };
+		case Op::OpFNegate: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 3u, 0x10000u };
+		case Op::OpIAdd: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFAdd: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpISub: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFSub: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpIMul: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFMul: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpUDiv: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpSDiv: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFDiv: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpUMod: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpSRem: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpSMod: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFRem: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpFMod: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpVectorTimesScalar: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpMatrixTimesScalar: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpVectorTimesMatrix: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpMatrixTimesVector: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpMatrixTimesMatrix: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpOuterProduct: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpDot: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpIAddCarry: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpISubBorrow: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpUMulExtended: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpSMulExtended: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 4u, 0x10000u };
+		case Op::OpAny: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpAll: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpIsNan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpIsInf: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpIsFinite: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpIsNormal: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpSignBitSet: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpLessOrGreater: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpOrdered: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpUnordered: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpLogicalEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpLogicalNotEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpLogicalOr: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpLogicalAnd: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpLogicalNot: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 3u, 0x10000u };
+		case Op::OpSelect: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 5u, 0x10000u };
+		case Op::OpIEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpINotEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpUGreaterThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpSGreaterThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpUGreaterThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpSGreaterThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpULessThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpSLessThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpULessThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpSLessThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdNotEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordNotEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdLessThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordLessThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdGreaterThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordGreaterThan: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdLessThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordLessThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFOrdGreaterThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpFUnordGreaterThanEqual: return { true, true, true, false, false, false, InstructionClass::RelationalAndLogical, 4u, 0x10000u };
+		case Op::OpShiftRightLogical: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpShiftRightArithmetic: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpShiftLeftLogical: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpBitwiseOr: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpBitwiseXor: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpBitwiseAnd: return { true, true, true, false, false, false, InstructionClass::Bit, 4u, 0x10000u };
+		case Op::OpNot: return { true, true, true, false, false, false, InstructionClass::Bit, 3u, 0x10000u };
+		case Op::OpBitFieldInsert: return { true, true, true, false, false, false, InstructionClass::Bit, 6u, 0x10000u };
+		case Op::OpBitFieldSExtract: return { true, true, true, false, false, false, InstructionClass::Bit, 5u, 0x10000u };
+		case Op::OpBitFieldUExtract: return { true, true, true, false, false, false, InstructionClass::Bit, 5u, 0x10000u };
+		case Op::OpBitReverse: return { true, true, true, false, false, false, InstructionClass::Bit, 3u, 0x10000u };
+		case Op::OpBitCount: return { true, true, true, false, false, false, InstructionClass::Bit, 3u, 0x10000u };
+		case Op::OpDPdx: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpDPdy: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpFwidth: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpDPdxFine: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpDPdyFine: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpFwidthFine: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpDPdxCoarse: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpDPdyCoarse: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpFwidthCoarse: return { true, true, true, false, false, false, InstructionClass::Derivative, 3u, 0x10000u };
+		case Op::OpEmitVertex: return { true, false, false, false, false, false, InstructionClass::Primitive, 0u, 0x10000u };
+		case Op::OpEndPrimitive: return { true, false, false, false, false, false, InstructionClass::Primitive, 0u, 0x10000u };
+		case Op::OpEmitStreamVertex: return { true, false, false, false, false, false, InstructionClass::Primitive, 1u, 0x10000u };
+		case Op::OpEndStreamPrimitive: return { true, false, false, false, false, false, InstructionClass::Primitive, 1u, 0x10000u };
+		case Op::OpControlBarrier: return { true, false, false, false, false, false, InstructionClass::Barrier, 3u, 0x10000u };
+		case Op::OpMemoryBarrier: return { true, false, false, false, false, false, InstructionClass::Barrier, 2u, 0x10000u };
+		case Op::OpAtomicLoad: return { true, true, true, false, false, false, InstructionClass::Atomic, 5u, 0x10000u };
+		case Op::OpAtomicStore: return { true, false, false, false, false, false, InstructionClass::Atomic, 4u, 0x10000u };
+		case Op::OpAtomicExchange: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicCompareExchange: return { true, true, true, false, false, false, InstructionClass::Atomic, 8u, 0x10000u };
+		case Op::OpAtomicCompareExchangeWeak: return { true, true, true, false, false, false, InstructionClass::Atomic, 8u, 0x10000u };
+		case Op::OpAtomicIIncrement: return { true, true, true, false, false, false, InstructionClass::Atomic, 5u, 0x10000u };
+		case Op::OpAtomicIDecrement: return { true, true, true, false, false, false, InstructionClass::Atomic, 5u, 0x10000u };
+		case Op::OpAtomicIAdd: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicISub: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicSMin: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicUMin: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicSMax: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicUMax: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicAnd: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicOr: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpAtomicXor: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x10000u };
+		case Op::OpPhi: return { true, true, true, false, false, false, InstructionClass::ControlFlow, 3u, 0x10000u };
+		case Op::OpLoopMerge: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 3u, 0x10000u };
+		case Op::OpSelectionMerge: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 2u, 0x10000u };
+		case Op::OpLabel: return { true, true, false, false, false, false, InstructionClass::ControlFlow, 1u, 0x10000u };
+		case Op::OpBranch: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 1u, 0x10000u };
+		case Op::OpBranchConditional: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 4u, 0x10000u };
+		case Op::OpSwitch: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 3u, 0x10000u };
+		case Op::OpKill: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x10000u };
+		case Op::OpReturn: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x10000u };
+		case Op::OpReturnValue: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 1u, 0x10000u };
+		case Op::OpUnreachable: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x10000u };
+		case Op::OpLifetimeStart: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 2u, 0x10000u };
+		case Op::OpLifetimeStop: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 2u, 0x10000u };
+		case Op::OpGroupAsyncCopy: return { true, true, true, false, false, false, InstructionClass::Group, 8u, 0x10000u };
+		case Op::OpGroupWaitEvents: return { true, false, false, false, false, false, InstructionClass::Group, 3u, 0x10000u };
+		case Op::OpGroupAll: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x10000u };
+		case Op::OpGroupAny: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x10000u };
+		case Op::OpGroupBroadcast: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupIAdd: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupFAdd: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupFMin: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupUMin: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupSMin: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupFMax: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupUMax: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpGroupSMax: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x10000u };
+		case Op::OpReadPipe: return { true, true, true, false, false, false, InstructionClass::Pipe, 6u, 0x10000u };
+		case Op::OpWritePipe: return { true, true, true, false, false, false, InstructionClass::Pipe, 6u, 0x10000u };
+		case Op::OpReservedReadPipe: return { true, true, true, false, false, false, InstructionClass::Pipe, 8u, 0x10000u };
+		case Op::OpReservedWritePipe: return { true, true, true, false, false, false, InstructionClass::Pipe, 8u, 0x10000u };
+		case Op::OpReserveReadPipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 6u, 0x10000u };
+		case Op::OpReserveWritePipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 6u, 0x10000u };
+		case Op::OpCommitReadPipe: return { true, false, false, false, false, false, InstructionClass::Pipe, 4u, 0x10000u };
+		case Op::OpCommitWritePipe: return { true, false, false, false, false, false, InstructionClass::Pipe, 4u, 0x10000u };
+		case Op::OpIsValidReserveId: return { true, true, true, false, false, false, InstructionClass::Pipe, 3u, 0x10000u };
+		case Op::OpGetNumPipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 5u, 0x10000u };
+		case Op::OpGetMaxPipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 5u, 0x10000u };
+		case Op::OpGroupReserveReadPipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 7u, 0x10000u };
+		case Op::OpGroupReserveWritePipePackets: return { true, true, true, false, false, false, InstructionClass::Pipe, 7u, 0x10000u };
+		case Op::OpGroupCommitReadPipe: return { true, false, false, false, false, false, InstructionClass::Pipe, 5u, 0x10000u };
+		case Op::OpGroupCommitWritePipe: return { true, false, false, false, false, false, InstructionClass::Pipe, 5u, 0x10000u };
+		case Op::OpEnqueueMarker: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 6u, 0x10000u };
+		case Op::OpEnqueueKernel: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 13u, 0x10000u };
+		case Op::OpGetKernelNDrangeSubGroupCount: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 7u, 0x10000u };
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 7u, 0x10000u };
+		case Op::OpGetKernelWorkGroupSize: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 6u, 0x10000u };
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 6u, 0x10000u };
+		case Op::OpRetainEvent: return { true, false, false, false, false, false, InstructionClass::DeviceSideEnqueue, 1u, 0x10000u };
+		case Op::OpReleaseEvent: return { true, false, false, false, false, false, InstructionClass::DeviceSideEnqueue, 1u, 0x10000u };
+		case Op::OpCreateUserEvent: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 2u, 0x10000u };
+		case Op::OpIsValidEvent: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 3u, 0x10000u };
+		case Op::OpSetUserEventStatus: return { true, false, false, false, false, false, InstructionClass::DeviceSideEnqueue, 2u, 0x10000u };
+		case Op::OpCaptureEventProfilingInfo: return { true, false, false, false, false, false, InstructionClass::DeviceSideEnqueue, 3u, 0x10000u };
+		case Op::OpGetDefaultQueue: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 2u, 0x10000u };
+		case Op::OpBuildNDRange: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 5u, 0x10000u };
+		case Op::OpImageSparseSampleImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSparseSampleExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSparseSampleDrefImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSparseSampleDrefExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSparseSampleProjImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x0u };
+		case Op::OpImageSparseSampleProjExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x0u };
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x0u };
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x0u };
+		case Op::OpImageSparseFetch: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpImageSparseGather: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSparseDrefGather: return { true, true, true, false, false, false, InstructionClass::Image, 6u, 0x10000u };
+		case Op::OpImageSparseTexelsResident: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x10000u };
+		case Op::OpNoLine: return { true, false, false, false, false, false, InstructionClass::Debug, 0u, 0x10000u };
+		case Op::OpAtomicFlagTestAndSet: return { true, true, true, false, false, false, InstructionClass::Atomic, 5u, 0x10000u };
+		case Op::OpAtomicFlagClear: return { true, false, false, false, false, false, InstructionClass::Atomic, 3u, 0x10000u };
+		case Op::OpImageSparseRead: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x10000u };
+		case Op::OpSizeOf: return { true, true, true, false, false, false, InstructionClass::Miscellaneous, 3u, 0x10100u };
+		case Op::OpTypePipeStorage: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10100u };
+		case Op::OpConstantPipeStorage: return { true, true, true, false, true, false, InstructionClass::Pipe, 5u, 0x10100u };
+		case Op::OpCreatePipeFromPipeStorage: return { true, true, true, false, false, false, InstructionClass::Pipe, 3u, 0x10100u };
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 7u, 0x10100u };
+		case Op::OpGetKernelMaxNumSubgroups: return { true, true, true, false, false, false, InstructionClass::DeviceSideEnqueue, 6u, 0x10100u };
+		case Op::OpTypeNamedBarrier: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x10100u };
+		case Op::OpNamedBarrierInitialize: return { true, true, true, false, false, false, InstructionClass::Barrier, 3u, 0x10100u };
+		case Op::OpMemoryNamedBarrier: return { true, false, false, false, false, false, InstructionClass::Barrier, 3u, 0x10100u };
+		case Op::OpModuleProcessed: return { true, false, false, false, false, false, InstructionClass::Debug, 1u, 0x10100u };
+		case Op::OpExecutionModeId: return { true, false, false, false, false, false, InstructionClass::ModeSetting, 2u, 0x10200u };
+		case Op::OpDecorateId: return { true, false, false, false, false, false, InstructionClass::Annotation, 2u, 0x10200u };
+		case Op::OpGroupNonUniformElect: return { true, true, true, false, false, false, InstructionClass::NonUniform, 3u, 0x10300u };
+		case Op::OpGroupNonUniformAll: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformAny: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformAllEqual: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformBroadcast: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformBroadcastFirst: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformBallot: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformInverseBallot: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformBallotBitExtract: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformBallotBitCount: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformBallotFindLSB: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformBallotFindMSB: return { true, true, true, false, false, false, InstructionClass::NonUniform, 4u, 0x10300u };
+		case Op::OpGroupNonUniformShuffle: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformShuffleXor: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformShuffleUp: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformShuffleDown: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformIAdd: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformFAdd: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformIMul: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformFMul: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformSMin: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformUMin: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformFMin: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformSMax: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformUMax: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformFMax: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformBitwiseAnd: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformBitwiseOr: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformBitwiseXor: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformLogicalAnd: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformLogicalOr: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformLogicalXor: return { true, true, true, false, false, false, InstructionClass::NonUniform, 6u, 0x10300u };
+		case Op::OpGroupNonUniformQuadBroadcast: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpGroupNonUniformQuadSwap: return { true, true, true, false, false, false, InstructionClass::NonUniform, 5u, 0x10300u };
+		case Op::OpCopyLogical: return { true, true, true, false, false, false, InstructionClass::Composite, 3u, 0x10400u };
+		case Op::OpPtrEqual: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10400u };
+		case Op::OpPtrNotEqual: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10400u };
+		case Op::OpPtrDiff: return { true, true, true, false, false, false, InstructionClass::Memory, 4u, 0x10400u };
+		case Op::OpColorAttachmentReadEXT: return { true, true, true, false, false, false, InstructionClass::Image, 4u, 0x0u };
+		case Op::OpDepthAttachmentReadEXT: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x0u };
+		case Op::OpStencilAttachmentReadEXT: return { true, true, true, false, false, false, InstructionClass::Image, 3u, 0x0u };
+		case Op::OpTerminateInvocation: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x10600u };
+		case Op::OpSubgroupBallotKHR: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupFirstInvocationKHR: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupAllKHR: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupAnyKHR: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupAllEqualKHR: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpGroupNonUniformRotateKHR: return { true, true, true, false, false, false, InstructionClass::Group, 6u, 0x0u };
+		case Op::OpSubgroupReadInvocationKHR: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x0u };
+		case Op::OpTraceRayKHR: return { true, false, false, false, false, false, InstructionClass::None, 11u, 0x0u };
+		case Op::OpExecuteCallableKHR: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpConvertUToAccelerationStructureKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpIgnoreIntersectionKHR: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x0u };
+		case Op::OpTerminateRayKHR: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x0u };
+		case Op::OpSDot: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 5u, 0x10600u };
+		case Op::OpUDot: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 5u, 0x10600u };
+		case Op::OpSUDot: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 5u, 0x10600u };
+		case Op::OpSDotAccSat: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 6u, 0x10600u };
+		case Op::OpUDotAccSat: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 6u, 0x10600u };
+		case Op::OpSUDotAccSat: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 6u, 0x10600u };
+		case Op::OpTypeCooperativeMatrixKHR: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 6u, 0x0u };
+		case Op::OpCooperativeMatrixLoadKHR: return { true, true, true, false, false, false, InstructionClass::Memory, 6u, 0x0u };
+		case Op::OpCooperativeMatrixStoreKHR: return { true, false, false, false, false, false, InstructionClass::Memory, 5u, 0x0u };
+		case Op::OpCooperativeMatrixMulAddKHR: return { true, true, true, false, false, false, InstructionClass::Arithmetic, 6u, 0x0u };
+		case Op::OpCooperativeMatrixLengthKHR: return { true, true, true, false, false, false, InstructionClass::Miscellaneous, 3u, 0x0u };
+		case Op::OpTypeRayQueryKHR: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x0u };
+		case Op::OpRayQueryInitializeKHR: return { true, false, false, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpRayQueryTerminateKHR: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpRayQueryGenerateIntersectionKHR: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpRayQueryConfirmIntersectionKHR: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpRayQueryProceedKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetIntersectionTypeKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpImageSampleWeightedQCOM: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x0u };
+		case Op::OpImageBoxFilterQCOM: return { true, true, true, false, false, false, InstructionClass::Image, 5u, 0x0u };
+		case Op::OpImageBlockMatchSSDQCOM: return { true, true, true, false, false, false, InstructionClass::Image, 7u, 0x0u };
+		case Op::OpImageBlockMatchSADQCOM: return { true, true, true, false, false, false, InstructionClass::Image, 7u, 0x0u };
+		case Op::OpGroupIAddNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupFAddNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupFMinNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupUMinNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupSMinNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupFMaxNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupUMaxNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupSMaxNonUniformAMD: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpFragmentMaskFetchAMD: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpFragmentFetchAMD: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpReadClockKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpFinalizeNodePayloadsAMDX: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpFinishWritingNodePayloadAMDX: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpInitializeNodePayloadsAMDX: return { true, false, false, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpHitObjectRecordHitMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 14u, 0x0u };
+		case Op::OpHitObjectRecordHitWithIndexMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 13u, 0x0u };
+		case Op::OpHitObjectRecordMissMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 7u, 0x0u };
+		case Op::OpHitObjectGetWorldToObjectNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetObjectToWorldNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetObjectRayDirectionNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetObjectRayOriginNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectTraceRayMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 13u, 0x0u };
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectRecordEmptyNV: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpHitObjectTraceRayNV: return { true, false, false, false, false, false, InstructionClass::None, 12u, 0x0u };
+		case Op::OpHitObjectRecordHitNV: return { true, false, false, false, false, false, InstructionClass::None, 13u, 0x0u };
+		case Op::OpHitObjectRecordHitWithIndexNV: return { true, false, false, false, false, false, InstructionClass::None, 12u, 0x0u };
+		case Op::OpHitObjectRecordMissNV: return { true, false, false, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpHitObjectExecuteShaderNV: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpHitObjectGetCurrentTimeNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetAttributesNV: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpHitObjectGetHitKindNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetPrimitiveIndexNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetGeometryIndexNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetInstanceIdNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetInstanceCustomIndexNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetWorldRayDirectionNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetWorldRayOriginNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetRayTMaxNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectGetRayTMinNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectIsEmptyNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectIsHitNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpHitObjectIsMissNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpReorderThreadWithHitObjectNV: return { true, false, false, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpReorderThreadWithHintNV: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpTypeHitObjectNV: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x0u };
+		case Op::OpImageSampleFootprintNV: return { true, true, true, false, false, false, InstructionClass::Image, 7u, 0x0u };
+		case Op::OpEmitMeshTasksEXT: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 4u, 0x0u };
+		case Op::OpSetMeshOutputsEXT: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpGroupNonUniformPartitionNV: return { true, true, true, false, false, false, InstructionClass::NonUniform, 3u, 0x0u };
+		case Op::OpWritePackedPrimitiveIndices4x8NV: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpReportIntersectionNV: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpIgnoreIntersectionNV: return { true, false, false, false, false, false, InstructionClass::None, 0u, 0x0u };
+		case Op::OpTerminateRayNV: return { true, false, false, false, false, false, InstructionClass::None, 0u, 0x0u };
+		case Op::OpTraceNV: return { true, false, false, false, false, false, InstructionClass::None, 11u, 0x0u };
+		case Op::OpTraceMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 12u, 0x0u };
+		case Op::OpTraceRayMotionNV: return { true, false, false, false, false, false, InstructionClass::None, 12u, 0x0u };
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpTypeAccelerationStructureNV: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x0u };
+		case Op::OpExecuteCallableNV: return { true, false, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpTypeCooperativeMatrixNV: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 5u, 0x0u };
+		case Op::OpCooperativeMatrixLoadNV: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpCooperativeMatrixStoreNV: return { true, false, false, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpCooperativeMatrixMulAddNV: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpCooperativeMatrixLengthNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpBeginInvocationInterlockEXT: return { true, false, false, false, false, false, InstructionClass::None, 0u, 0x0u };
+		case Op::OpEndInvocationInterlockEXT: return { true, false, false, false, false, false, InstructionClass::None, 0u, 0x0u };
+		case Op::OpDemoteToHelperInvocation: return { true, false, false, false, false, false, InstructionClass::ControlFlow, 0u, 0x10600u };
+		case Op::OpIsHelperInvocationEXT: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpConvertUToImageNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpConvertUToSamplerNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpConvertImageToUNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpConvertSamplerToUNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpConvertUToSampledImageNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpConvertSampledImageToUNV: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSamplerImageAddressingModeNV: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpSubgroupShuffleINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x0u };
+		case Op::OpSubgroupShuffleDownINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpSubgroupShuffleUpINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpSubgroupShuffleXorINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x0u };
+		case Op::OpSubgroupBlockReadINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupBlockWriteINTEL: return { true, false, false, false, false, false, InstructionClass::Group, 2u, 0x0u };
+		case Op::OpSubgroupImageBlockReadINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 4u, 0x0u };
+		case Op::OpSubgroupImageBlockWriteINTEL: return { true, false, false, false, false, false, InstructionClass::Group, 3u, 0x0u };
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return { true, true, true, false, false, false, InstructionClass::Group, 6u, 0x0u };
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return { true, false, false, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpUCountLeadingZerosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpUCountTrailingZerosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpAbsISubINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpAbsUSubINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpIAddSatINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpUAddSatINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpIAverageINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpUAverageINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpIAverageRoundedINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpUAverageRoundedINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpISubSatINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpUSubSatINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpIMul32x16INTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpUMul32x16INTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpConstantFunctionPointerINTEL: return { true, true, true, false, true, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpFunctionPointerCallINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpAsmTargetINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpAsmINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpAsmCallINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpAtomicFMinEXT: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x0u };
+		case Op::OpAtomicFMaxEXT: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x0u };
+		case Op::OpAssumeTrueKHR: return { true, false, false, false, false, false, InstructionClass::Miscellaneous, 1u, 0x0u };
+		case Op::OpExpectKHR: return { true, true, true, false, false, false, InstructionClass::Miscellaneous, 4u, 0x0u };
+		case Op::OpDecorateString: return { true, false, false, false, false, false, InstructionClass::Annotation, 2u, 0x10400u };
+		case Op::OpMemberDecorateString: return { true, false, false, false, false, false, InstructionClass::Annotation, 3u, 0x10400u };
+		case Op::OpVmeImageINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpTypeVmeImageINTEL: return { true, true, false, true, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpTypeAvcImePayloadINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcRefPayloadINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcSicPayloadINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcMcePayloadINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcMceResultINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcImeResultINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcRefResultINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpTypeAvcSicResultINTEL: return { true, true, false, true, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeInitializeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return { true, true, true, false, false, false, InstructionClass::None, 7u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 7u, 0x0u };
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcFmeInitializeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpSubgroupAvcBmeInitializeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicInitializeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return { true, true, true, false, false, false, InstructionClass::None, 13u, 0x0u };
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return { true, true, true, false, false, false, InstructionClass::None, 5u, 0x0u };
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpVariableLengthArrayINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpSaveMemoryINTEL: return { true, true, true, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpRestoreMemoryINTEL: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpArbitraryFloatSinCosPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpArbitraryFloatCastINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatCastFromIntINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatCastToIntINTEL: return { true, true, true, false, false, false, InstructionClass::None, 7u, 0x0u };
+		case Op::OpArbitraryFloatAddINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatSubINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatMulINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatDivINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatGTINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpArbitraryFloatGEINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpArbitraryFloatLTINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpArbitraryFloatLEINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpArbitraryFloatEQINTEL: return { true, true, true, false, false, false, InstructionClass::None, 6u, 0x0u };
+		case Op::OpArbitraryFloatRecipINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatRSqrtINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatCbrtINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatHypotINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatSqrtINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatLogINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatLog2INTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatLog10INTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatLog1pINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatExpINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatExp2INTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatExp10INTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatExpm1INTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatSinINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatCosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatSinCosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatSinPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatCosPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatASinINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatASinPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatACosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatACosPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatATanINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatATanPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 8u, 0x0u };
+		case Op::OpArbitraryFloatATan2INTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatPowINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatPowRINTEL: return { true, true, true, false, false, false, InstructionClass::None, 10u, 0x0u };
+		case Op::OpArbitraryFloatPowNINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpLoopControlINTEL: return { true, false, false, false, false, false, InstructionClass::None, 1u, 0x0u };
+		case Op::OpAliasDomainDeclINTEL: return { true, true, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpAliasScopeDeclINTEL: return { true, true, false, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpAliasScopeListDeclINTEL: return { true, true, false, false, false, false, InstructionClass::None, 2u, 0x0u };
+		case Op::OpFixedSqrtINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedRecipINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedRsqrtINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedSinINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedCosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedSinCosINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedSinPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedCosPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedSinCosPiINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedLogINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpFixedExpINTEL: return { true, true, true, false, false, false, InstructionClass::None, 9u, 0x0u };
+		case Op::OpPtrCastToCrossWorkgroupINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpCrossWorkgroupCastToPtrINTEL: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpReadPipeBlockingINTEL: return { true, true, true, false, false, false, InstructionClass::Pipe, 4u, 0x0u };
+		case Op::OpWritePipeBlockingINTEL: return { true, true, true, false, false, false, InstructionClass::Pipe, 4u, 0x0u };
+		case Op::OpFPGARegINTEL: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetRayTMinKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetRayFlagsKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetIntersectionTKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetWorldRayDirectionKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetWorldRayOriginKHR: return { true, true, true, false, false, false, InstructionClass::None, 3u, 0x0u };
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return { true, true, true, false, false, false, InstructionClass::None, 4u, 0x0u };
+		case Op::OpAtomicFAddEXT: return { true, true, true, false, false, false, InstructionClass::Atomic, 6u, 0x0u };
+		case Op::OpTypeBufferSurfaceINTEL: return { true, true, false, true, false, false, InstructionClass::TypeDeclaration, 2u, 0x0u };
+		case Op::OpTypeStructContinuedINTEL: return { true, false, false, true, false, false, InstructionClass::TypeDeclaration, 1u, 0x0u };
+		case Op::OpConstantCompositeContinuedINTEL: return { true, false, false, false, true, false, InstructionClass::ConstantCreation, 1u, 0x0u };
+		case Op::OpSpecConstantCompositeContinuedINTEL: return { true, false, false, false, false, true, InstructionClass::ConstantCreation, 1u, 0x0u };
+		case Op::OpConvertFToBF16INTEL: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x0u };
+		case Op::OpConvertBF16ToFINTEL: return { true, true, true, false, false, false, InstructionClass::Conversion, 3u, 0x0u };
+		case Op::OpControlBarrierArriveINTEL: return { true, false, false, false, false, false, InstructionClass::Barrier, 3u, 0x0u };
+		case Op::OpControlBarrierWaitINTEL: return { true, false, false, false, false, false, InstructionClass::Barrier, 3u, 0x0u };
+		case Op::OpGroupIMulKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupFMulKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupBitwiseAndKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupBitwiseOrKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupBitwiseXorKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupLogicalAndKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupLogicalOrKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		case Op::OpGroupLogicalXorKHR: return { true, true, true, false, false, false, InstructionClass::Group, 5u, 0x0u };
+		}
+	}
 	inline void HasResultAndType(Op opcode, bool *hasResult, bool *hasResultType) {
-		*hasResult = *hasResultType = false;
+		const InstructionInfo info = GetInfo(opcode);
+		*hasResult = info.hasResult;
+		*hasResultType = info.hasResultType;
+	}
+	// majority of instructions has a result, unknown opcodes too
+	inline constexpr bool HasResult(Op opcode) { const InstructionInfo info = GetInfo(opcode); return !info.valid || info.hasResult; }
+	// majority of instructions has a result type, unknown opcodes too
+	inline constexpr bool HasResultType(Op opcode) { const InstructionInfo info = GetInfo(opcode); return !info.valid || info.hasResultType; }
+	inline constexpr bool IsTypeOp(Op opcode) { return GetInfo(opcode).isType; }
+	inline constexpr bool IsConstantOp(Op opcode) { return GetInfo(opcode).isConstant; }
+	inline constexpr bool IsSpecConstantOp(Op opcode) { return GetInfo(opcode).isSpecConstant; }
+	// instruction class Annotation
+	inline constexpr bool IsAnnotation(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpDecorate: return true;
+		case Op::OpMemberDecorate: return true;
+		case Op::OpDecorationGroup: return true;
+		case Op::OpGroupDecorate: return true;
+		case Op::OpGroupMemberDecorate: return true;
+		case Op::OpDecorateId: return true;
+		case Op::OpDecorateString: return true;
+		case Op::OpMemberDecorateString: return true;
+		}
+	}
+	// instruction class Arithmetic
+	inline constexpr bool IsArithmetic(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpSNegate: return true;
+		case Op::OpFNegate: return true;
+		case Op::OpIAdd: return true;
+		case Op::OpFAdd: return true;
+		case Op::OpISub: return true;
+		case Op::OpFSub: return true;
+		case Op::OpIMul: return true;
+		case Op::OpFMul: return true;
+		case Op::OpUDiv: return true;
+		case Op::OpSDiv: return true;
+		case Op::OpFDiv: return true;
+		case Op::OpUMod: return true;
+		case Op::OpSRem: return true;
+		case Op::OpSMod: return true;
+		case Op::OpFRem: return true;
+		case Op::OpFMod: return true;
+		case Op::OpVectorTimesScalar: return true;
+		case Op::OpMatrixTimesScalar: return true;
+		case Op::OpVectorTimesMatrix: return true;
+		case Op::OpMatrixTimesVector: return true;
+		case Op::OpMatrixTimesMatrix: return true;
+		case Op::OpOuterProduct: return true;
+		case Op::OpDot: return true;
+		case Op::OpIAddCarry: return true;
+		case Op::OpISubBorrow: return true;
+		case Op::OpUMulExtended: return true;
+		case Op::OpSMulExtended: return true;
+		case Op::OpSDot: return true;
+		case Op::OpUDot: return true;
+		case Op::OpSUDot: return true;
+		case Op::OpSDotAccSat: return true;
+		case Op::OpUDotAccSat: return true;
+		case Op::OpSUDotAccSat: return true;
+		case Op::OpCooperativeMatrixMulAddKHR: return true;
+		}
+	}
+	// instruction class Atomic
+	inline constexpr bool IsAtomic(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpAtomicLoad: return true;
+		case Op::OpAtomicStore: return true;
+		case Op::OpAtomicExchange: return true;
+		case Op::OpAtomicCompareExchange: return true;
+		case Op::OpAtomicCompareExchangeWeak: return true;
+		case Op::OpAtomicIIncrement: return true;
+		case Op::OpAtomicIDecrement: return true;
+		case Op::OpAtomicIAdd: return true;
+		case Op::OpAtomicISub: return true;
+		case Op::OpAtomicSMin: return true;
+		case Op::OpAtomicUMin: return true;
+		case Op::OpAtomicSMax: return true;
+		case Op::OpAtomicUMax: return true;
+		case Op::OpAtomicAnd: return true;
+		case Op::OpAtomicOr: return true;
+		case Op::OpAtomicXor: return true;
+		case Op::OpAtomicFlagTestAndSet: return true;
+		case Op::OpAtomicFlagClear: return true;
+		case Op::OpAtomicFMinEXT: return true;
+		case Op::OpAtomicFMaxEXT: return true;
+		case Op::OpAtomicFAddEXT: return true;
+		}
+	}
+	// instruction class Barrier
+	inline constexpr bool IsBarrier(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpControlBarrier: return true;
+		case Op::OpMemoryBarrier: return true;
+		case Op::OpNamedBarrierInitialize: return true;
+		case Op::OpMemoryNamedBarrier: return true;
+		case Op::OpControlBarrierArriveINTEL: return true;
+		case Op::OpControlBarrierWaitINTEL: return true;
+		}
+	}
+	// instruction class Bit
+	inline constexpr bool IsBit(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpShiftRightLogical: return true;
+		case Op::OpShiftRightArithmetic: return true;
+		case Op::OpShiftLeftLogical: return true;
+		case Op::OpBitwiseOr: return true;
+		case Op::OpBitwiseXor: return true;
+		case Op::OpBitwiseAnd: return true;
+		case Op::OpNot: return true;
+		case Op::OpBitFieldInsert: return true;
+		case Op::OpBitFieldSExtract: return true;
+		case Op::OpBitFieldUExtract: return true;
+		case Op::OpBitReverse: return true;
+		case Op::OpBitCount: return true;
+		}
+	}
+	// instruction class Composite
+	inline constexpr bool IsComposite(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpVectorExtractDynamic: return true;
+		case Op::OpVectorInsertDynamic: return true;
+		case Op::OpVectorShuffle: return true;
+		case Op::OpCompositeConstruct: return true;
+		case Op::OpCompositeExtract: return true;
+		case Op::OpCompositeInsert: return true;
+		case Op::OpCopyObject: return true;
+		case Op::OpTranspose: return true;
+		case Op::OpCopyLogical: return true;
+		}
+	}
+	// instruction class Constant-Creation
+	inline constexpr bool IsConstantCreation(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpConstantTrue: return true;
+		case Op::OpConstantFalse: return true;
+		case Op::OpConstant: return true;
+		case Op::OpConstantComposite: return true;
+		case Op::OpConstantSampler: return true;
+		case Op::OpConstantNull: return true;
+		case Op::OpSpecConstantTrue: return true;
+		case Op::OpSpecConstantFalse: return true;
+		case Op::OpSpecConstant: return true;
+		case Op::OpSpecConstantComposite: return true;
+		case Op::OpSpecConstantOp: return true;
+		case Op::OpConstantCompositeContinuedINTEL: return true;
+		case Op::OpSpecConstantCompositeContinuedINTEL: return true;
+		}
+	}
+	// instruction class Control-Flow
+	inline constexpr bool IsControlFlow(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpPhi: return true;
+		case Op::OpLoopMerge: return true;
+		case Op::OpSelectionMerge: return true;
+		case Op::OpLabel: return true;
+		case Op::OpBranch: return true;
+		case Op::OpBranchConditional: return true;
+		case Op::OpSwitch: return true;
+		case Op::OpKill: return true;
+		case Op::OpReturn: return true;
+		case Op::OpReturnValue: return true;
+		case Op::OpUnreachable: return true;
+		case Op::OpLifetimeStart: return true;
+		case Op::OpLifetimeStop: return true;
+		case Op::OpTerminateInvocation: return true;
+		case Op::OpIgnoreIntersectionKHR: return true;
+		case Op::OpTerminateRayKHR: return true;
+		case Op::OpEmitMeshTasksEXT: return true;
+		case Op::OpDemoteToHelperInvocation: return true;
+		}
+	}
+	// instruction class Conversion
+	inline constexpr bool IsConversion(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpConvertFToU: return true;
+		case Op::OpConvertFToS: return true;
+		case Op::OpConvertSToF: return true;
+		case Op::OpConvertUToF: return true;
+		case Op::OpUConvert: return true;
+		case Op::OpSConvert: return true;
+		case Op::OpFConvert: return true;
+		case Op::OpQuantizeToF16: return true;
+		case Op::OpConvertPtrToU: return true;
+		case Op::OpSatConvertSToU: return true;
+		case Op::OpSatConvertUToS: return true;
+		case Op::OpConvertUToPtr: return true;
+		case Op::OpPtrCastToGeneric: return true;
+		case Op::OpGenericCastToPtr: return true;
+		case Op::OpGenericCastToPtrExplicit: return true;
+		case Op::OpBitcast: return true;
+		case Op::OpConvertFToBF16INTEL: return true;
+		case Op::OpConvertBF16ToFINTEL: return true;
+		}
+	}
+	// instruction class Debug
+	inline constexpr bool IsDebug(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpSourceContinued: return true;
+		case Op::OpSource: return true;
+		case Op::OpSourceExtension: return true;
+		case Op::OpName: return true;
+		case Op::OpMemberName: return true;
+		case Op::OpString: return true;
+		case Op::OpLine: return true;
+		case Op::OpNoLine: return true;
+		case Op::OpModuleProcessed: return true;
+		}
+	}
+	// instruction class Derivative
+	inline constexpr bool IsDerivative(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpDPdx: return true;
+		case Op::OpDPdy: return true;
+		case Op::OpFwidth: return true;
+		case Op::OpDPdxFine: return true;
+		case Op::OpDPdyFine: return true;
+		case Op::OpFwidthFine: return true;
+		case Op::OpDPdxCoarse: return true;
+		case Op::OpDPdyCoarse: return true;
+		case Op::OpFwidthCoarse: return true;
+		}
+	}
+	// instruction class Device-Side_Enqueue
+	inline constexpr bool IsDeviceSideEnqueue(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpEnqueueMarker: return true;
+		case Op::OpEnqueueKernel: return true;
+		case Op::OpGetKernelNDrangeSubGroupCount: return true;
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return true;
+		case Op::OpGetKernelWorkGroupSize: return true;
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return true;
+		case Op::OpRetainEvent: return true;
+		case Op::OpReleaseEvent: return true;
+		case Op::OpCreateUserEvent: return true;
+		case Op::OpIsValidEvent: return true;
+		case Op::OpSetUserEventStatus: return true;
+		case Op::OpCaptureEventProfilingInfo: return true;
+		case Op::OpGetDefaultQueue: return true;
+		case Op::OpBuildNDRange: return true;
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return true;
+		case Op::OpGetKernelMaxNumSubgroups: return true;
+		}
+	}
+	// instruction class Extension
+	inline constexpr bool IsExtension(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpExtension: return true;
+		case Op::OpExtInstImport: return true;
+		case Op::OpExtInst: return true;
+		}
+	}
+	// instruction class Function
+	inline constexpr bool IsFunction(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpFunction: return true;
+		case Op::OpFunctionParameter: return true;
+		case Op::OpFunctionEnd: return true;
+		case Op::OpFunctionCall: return true;
+		}
+	}
+	// instruction class Group
+	inline constexpr bool IsGroup(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpGroupAsyncCopy: return true;
+		case Op::OpGroupWaitEvents: return true;
+		case Op::OpGroupAll: return true;
+		case Op::OpGroupAny: return true;
+		case Op::OpGroupBroadcast: return true;
+		case Op::OpGroupIAdd: return true;
+		case Op::OpGroupFAdd: return true;
+		case Op::OpGroupFMin: return true;
+		case Op::OpGroupUMin: return true;
+		case Op::OpGroupSMin: return true;
+		case Op::OpGroupFMax: return true;
+		case Op::OpGroupUMax: return true;
+		case Op::OpGroupSMax: return true;
+		case Op::OpSubgroupBallotKHR: return true;
+		case Op::OpSubgroupFirstInvocationKHR: return true;
+		case Op::OpSubgroupAllKHR: return true;
+		case Op::OpSubgroupAnyKHR: return true;
+		case Op::OpSubgroupAllEqualKHR: return true;
+		case Op::OpGroupNonUniformRotateKHR: return true;
+		case Op::OpSubgroupReadInvocationKHR: return true;
+		case Op::OpGroupIAddNonUniformAMD: return true;
+		case Op::OpGroupFAddNonUniformAMD: return true;
+		case Op::OpGroupFMinNonUniformAMD: return true;
+		case Op::OpGroupUMinNonUniformAMD: return true;
+		case Op::OpGroupSMinNonUniformAMD: return true;
+		case Op::OpGroupFMaxNonUniformAMD: return true;
+		case Op::OpGroupUMaxNonUniformAMD: return true;
+		case Op::OpGroupSMaxNonUniformAMD: return true;
+		case Op::OpSubgroupShuffleINTEL: return true;
+		case Op::OpSubgroupShuffleDownINTEL: return true;
+		case Op::OpSubgroupShuffleUpINTEL: return true;
+		case Op::OpSubgroupShuffleXorINTEL: return true;
+		case Op::OpSubgroupBlockReadINTEL: return true;
+		case Op::OpSubgroupBlockWriteINTEL: return true;
+		case Op::OpSubgroupImageBlockReadINTEL: return true;
+		case Op::OpSubgroupImageBlockWriteINTEL: return true;
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return true;
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return true;
+		case Op::OpGroupIMulKHR: return true;
+		case Op::OpGroupFMulKHR: return true;
+		case Op::OpGroupBitwiseAndKHR: return true;
+		case Op::OpGroupBitwiseOrKHR: return true;
+		case Op::OpGroupBitwiseXorKHR: return true;
+		case Op::OpGroupLogicalAndKHR: return true;
+		case Op::OpGroupLogicalOrKHR: return true;
+		case Op::OpGroupLogicalXorKHR: return true;
+		}
+	}
+	// instruction class Image
+	inline constexpr bool IsImage(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpSampledImage: return true;
+		case Op::OpImageSampleImplicitLod: return true;
+		case Op::OpImageSampleExplicitLod: return true;
+		case Op::OpImageSampleDrefImplicitLod: return true;
+		case Op::OpImageSampleDrefExplicitLod: return true;
+		case Op::OpImageSampleProjImplicitLod: return true;
+		case Op::OpImageSampleProjExplicitLod: return true;
+		case Op::OpImageSampleProjDrefImplicitLod: return true;
+		case Op::OpImageSampleProjDrefExplicitLod: return true;
+		case Op::OpImageFetch: return true;
+		case Op::OpImageGather: return true;
+		case Op::OpImageDrefGather: return true;
+		case Op::OpImageRead: return true;
+		case Op::OpImageWrite: return true;
+		case Op::OpImage: return true;
+		case Op::OpImageQueryFormat: return true;
+		case Op::OpImageQueryOrder: return true;
+		case Op::OpImageQuerySizeLod: return true;
+		case Op::OpImageQuerySize: return true;
+		case Op::OpImageQueryLod: return true;
+		case Op::OpImageQueryLevels: return true;
+		case Op::OpImageQuerySamples: return true;
+		case Op::OpImageSparseSampleImplicitLod: return true;
+		case Op::OpImageSparseSampleExplicitLod: return true;
+		case Op::OpImageSparseSampleDrefImplicitLod: return true;
+		case Op::OpImageSparseSampleDrefExplicitLod: return true;
+		case Op::OpImageSparseSampleProjImplicitLod: return true;
+		case Op::OpImageSparseSampleProjExplicitLod: return true;
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return true;
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return true;
+		case Op::OpImageSparseFetch: return true;
+		case Op::OpImageSparseGather: return true;
+		case Op::OpImageSparseDrefGather: return true;
+		case Op::OpImageSparseTexelsResident: return true;
+		case Op::OpImageSparseRead: return true;
+		case Op::OpColorAttachmentReadEXT: return true;
+		case Op::OpDepthAttachmentReadEXT: return true;
+		case Op::OpStencilAttachmentReadEXT: return true;
+		case Op::OpImageSampleWeightedQCOM: return true;
+		case Op::OpImageBoxFilterQCOM: return true;
+		case Op::OpImageBlockMatchSSDQCOM: return true;
+		case Op::OpImageBlockMatchSADQCOM: return true;
+		case Op::OpImageSampleFootprintNV: return true;
+		}
+	}
+	// instruction class Memory
+	inline constexpr bool IsMemory(Op opcode) {
 		switch (opcode) {
-		default: /* unknown opcode */ break;
-		case Op::OpUndef: *hasResult = true; *hasResultType = true; break;
-		case Op::OpString: *hasResult = true; *hasResultType = false; break;
-		case Op::OpExtInstImport: *hasResult = true; *hasResultType = false; break;
-		case Op::OpExtInst: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeVoid: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeBool: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeInt: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeFloat: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeVector: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeMatrix: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeImage: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeSampler: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeSampledImage: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeArray: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeRuntimeArray: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeStruct: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeOpaque: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypePointer: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeFunction: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeEvent: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeDeviceEvent: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeReserveId: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeQueue: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypePipe: *hasResult = true; *hasResultType = false; break;
-		case Op::OpConstantTrue: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstantFalse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstant: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstantComposite: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstantSampler: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstantNull: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSpecConstantTrue: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSpecConstantFalse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSpecConstant: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSpecConstantComposite: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSpecConstantOp: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFunction: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFunctionParameter: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFunctionCall: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVariable: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageTexelPointer: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLoad: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAccessChain: *hasResult = true; *hasResultType = true; break;
-		case Op::OpInBoundsAccessChain: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrAccessChain: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArrayLength: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGenericPtrMemSemantics: *hasResult = true; *hasResultType = true; break;
-		case Op::OpInBoundsPtrAccessChain: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDecorationGroup: *hasResult = true; *hasResultType = false; break;
-		case Op::OpVectorExtractDynamic: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVectorInsertDynamic: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVectorShuffle: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCompositeConstruct: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCompositeExtract: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCompositeInsert: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCopyObject: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTranspose: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSampledImage: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleDrefImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleDrefExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleProjImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleProjExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleProjDrefImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleProjDrefExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageFetch: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageGather: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageDrefGather: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageRead: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImage: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQueryFormat: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQueryOrder: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQuerySizeLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQuerySize: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQueryLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQueryLevels: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageQuerySamples: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertFToU: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertFToS: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertSToF: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToF: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUConvert: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSConvert: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFConvert: *hasResult = true; *hasResultType = true; break;
-		case Op::OpQuantizeToF16: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertPtrToU: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSatConvertSToU: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSatConvertUToS: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToPtr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrCastToGeneric: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGenericCastToPtr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGenericCastToPtrExplicit: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitcast: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSNegate: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFNegate: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpISub: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFSub: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIMul: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFMul: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUDiv: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSDiv: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFDiv: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUMod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSRem: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSMod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFRem: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFMod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVectorTimesScalar: *hasResult = true; *hasResultType = true; break;
-		case Op::OpMatrixTimesScalar: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVectorTimesMatrix: *hasResult = true; *hasResultType = true; break;
-		case Op::OpMatrixTimesVector: *hasResult = true; *hasResultType = true; break;
-		case Op::OpMatrixTimesMatrix: *hasResult = true; *hasResultType = true; break;
-		case Op::OpOuterProduct: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIAddCarry: *hasResult = true; *hasResultType = true; break;
-		case Op::OpISubBorrow: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUMulExtended: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSMulExtended: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAny: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAll: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsNan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsInf: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsFinite: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsNormal: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSignBitSet: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLessOrGreater: *hasResult = true; *hasResultType = true; break;
-		case Op::OpOrdered: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUnordered: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLogicalEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLogicalNotEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLogicalOr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLogicalAnd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLogicalNot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSelect: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpINotEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUGreaterThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSGreaterThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUGreaterThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSGreaterThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpULessThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSLessThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpULessThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSLessThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdNotEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordNotEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdLessThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordLessThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdGreaterThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordGreaterThan: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdLessThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordLessThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFOrdGreaterThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFUnordGreaterThanEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpShiftRightLogical: *hasResult = true; *hasResultType = true; break;
-		case Op::OpShiftRightArithmetic: *hasResult = true; *hasResultType = true; break;
-		case Op::OpShiftLeftLogical: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitwiseOr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitwiseXor: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitwiseAnd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpNot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitFieldInsert: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitFieldSExtract: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitFieldUExtract: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitReverse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBitCount: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdx: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdy: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFwidth: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdxFine: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdyFine: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFwidthFine: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdxCoarse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDPdyCoarse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFwidthCoarse: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicLoad: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicExchange: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicCompareExchange: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicCompareExchangeWeak: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicIIncrement: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicIDecrement: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicIAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicISub: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicSMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicUMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicSMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicUMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicAnd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicOr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicXor: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPhi: *hasResult = true; *hasResultType = true; break;
-		case Op::OpLabel: *hasResult = true; *hasResultType = false; break;
-		case Op::OpGroupAsyncCopy: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupAll: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupAny: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupBroadcast: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupIAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupUMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupSMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupUMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupSMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReadPipe: *hasResult = true; *hasResultType = true; break;
-		case Op::OpWritePipe: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReservedReadPipe: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReservedWritePipe: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReserveReadPipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReserveWritePipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsValidReserveId: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetNumPipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetMaxPipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupReserveReadPipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupReserveWritePipePackets: *hasResult = true; *hasResultType = true; break;
-		case Op::OpEnqueueMarker: *hasResult = true; *hasResultType = true; break;
-		case Op::OpEnqueueKernel: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelNDrangeSubGroupCount: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelNDrangeMaxSubGroupSize: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelWorkGroupSize: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCreateUserEvent: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsValidEvent: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetDefaultQueue: *hasResult = true; *hasResultType = true; break;
-		case Op::OpBuildNDRange: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleDrefImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleDrefExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleProjImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleProjExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleProjDrefImplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseSampleProjDrefExplicitLod: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseFetch: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseGather: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseDrefGather: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseTexelsResident: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicFlagTestAndSet: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSparseRead: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSizeOf: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypePipeStorage: *hasResult = true; *hasResultType = false; break;
-		case Op::OpConstantPipeStorage: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCreatePipeFromPipeStorage: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelLocalSizeForSubgroupCount: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGetKernelMaxNumSubgroups: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeNamedBarrier: *hasResult = true; *hasResultType = false; break;
-		case Op::OpNamedBarrierInitialize: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformElect: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformAll: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformAny: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformAllEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBroadcast: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBroadcastFirst: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBallot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformInverseBallot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBallotBitExtract: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBallotBitCount: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBallotFindLSB: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBallotFindMSB: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformShuffle: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformShuffleXor: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformShuffleUp: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformShuffleDown: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformIAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformFAdd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformIMul: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformFMul: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformSMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformUMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformFMin: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformSMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformUMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformFMax: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBitwiseAnd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBitwiseOr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformBitwiseXor: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformLogicalAnd: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformLogicalOr: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformLogicalXor: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformQuadBroadcast: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformQuadSwap: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCopyLogical: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrNotEqual: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrDiff: *hasResult = true; *hasResultType = true; break;
-		case Op::OpColorAttachmentReadEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpDepthAttachmentReadEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpStencilAttachmentReadEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupBallotKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupFirstInvocationKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAllKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAnyKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAllEqualKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformRotateKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupReadInvocationKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToAccelerationStructureKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSDot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUDot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSUDot: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSDotAccSat: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUDotAccSat: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSUDotAccSat: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeCooperativeMatrixKHR: *hasResult = true; *hasResultType = false; break;
-		case Op::OpCooperativeMatrixLoadKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCooperativeMatrixMulAddKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCooperativeMatrixLengthKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeRayQueryKHR: *hasResult = true; *hasResultType = false; break;
-		case Op::OpRayQueryProceedKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionTypeKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageSampleWeightedQCOM: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageBoxFilterQCOM: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageBlockMatchSSDQCOM: *hasResult = true; *hasResultType = true; break;
-		case Op::OpImageBlockMatchSADQCOM: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupIAddNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFAddNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFMinNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupUMinNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupSMinNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFMaxNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupUMaxNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupSMaxNonUniformAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFragmentMaskFetchAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFragmentFetchAMD: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReadClockKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFinishWritingNodePayloadAMDX: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetWorldToObjectNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetObjectToWorldNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetObjectRayDirectionNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetObjectRayOriginNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetShaderRecordBufferHandleNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetCurrentTimeNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetHitKindNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetPrimitiveIndexNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetGeometryIndexNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetInstanceIdNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetInstanceCustomIndexNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetWorldRayDirectionNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetWorldRayOriginNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetRayTMaxNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectGetRayTMinNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectIsEmptyNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectIsHitNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpHitObjectIsMissNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeHitObjectNV: *hasResult = true; *hasResultType = false; break;
-		case Op::OpImageSampleFootprintNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupNonUniformPartitionNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReportIntersectionNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeAccelerationStructureNV: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeCooperativeMatrixNV: *hasResult = true; *hasResultType = false; break;
-		case Op::OpCooperativeMatrixLoadNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCooperativeMatrixMulAddNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCooperativeMatrixLengthNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIsHelperInvocationEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToImageNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToSamplerNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertImageToUNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertSamplerToUNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertUToSampledImageNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertSampledImageToUNV: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupShuffleINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupShuffleDownINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupShuffleUpINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupShuffleXorINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupBlockReadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupImageBlockReadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupImageMediaBlockReadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUCountLeadingZerosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUCountTrailingZerosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAbsISubINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAbsUSubINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIAddSatINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUAddSatINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIAverageINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUAverageINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIAverageRoundedINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUAverageRoundedINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpISubSatINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUSubSatINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpIMul32x16INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpUMul32x16INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConstantFunctionPointerINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFunctionPointerCallINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAsmTargetINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAsmINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAsmCallINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicFMinEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicFMaxEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpExpectKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVmeImageINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeVmeImageINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImePayloadINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcRefPayloadINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcSicPayloadINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcMcePayloadINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcMceResultINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImeResultINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcRefResultINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpTypeAvcSicResultINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeInitializeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcFmeInitializeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcBmeInitializeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicInitializeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicConfigureSkcINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpVariableLengthArrayINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpSaveMemoryINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSinCosPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCastINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCastFromIntINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCastToIntINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatAddINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSubINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatMulINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatDivINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatGTINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatGEINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLTINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLEINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatEQINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatRecipINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatRSqrtINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCbrtINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatHypotINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSqrtINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLogINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLog2INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLog10INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatLog1pINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatExpINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatExp2INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatExp10INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatExpm1INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSinINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSinCosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatSinPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatCosPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatASinINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatASinPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatACosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatACosPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatATanINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatATanPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatATan2INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatPowINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatPowRINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpArbitraryFloatPowNINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAliasDomainDeclINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpAliasScopeDeclINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpAliasScopeListDeclINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpFixedSqrtINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedRecipINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedRsqrtINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedSinINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedCosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedSinCosINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedSinPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedCosPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedSinCosPiINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedLogINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFixedExpINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpPtrCastToCrossWorkgroupINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpCrossWorkgroupCastToPtrINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpReadPipeBlockingINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpWritePipeBlockingINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpFPGARegINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetRayTMinKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetRayFlagsKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionTKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionInstanceIdKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionBarycentricsKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionFrontFaceKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetWorldRayDirectionKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetWorldRayOriginKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpAtomicFAddEXT: *hasResult = true; *hasResultType = true; break;
-		case Op::OpTypeBufferSurfaceINTEL: *hasResult = true; *hasResultType = false; break;
-		case Op::OpConvertFToBF16INTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpConvertBF16ToFINTEL: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupIMulKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupFMulKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupBitwiseAndKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupBitwiseOrKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupBitwiseXorKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupLogicalAndKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupLogicalOrKHR: *hasResult = true; *hasResultType = true; break;
-		case Op::OpGroupLogicalXorKHR: *hasResult = true; *hasResultType = true; break;
-		}
-	}
-	inline constexpr bool HasResult(Op opcode) {
+		default: return false;
+		case Op::OpVariable: return true;
+		case Op::OpImageTexelPointer: return true;
+		case Op::OpLoad: return true;
+		case Op::OpStore: return true;
+		case Op::OpCopyMemory: return true;
+		case Op::OpCopyMemorySized: return true;
+		case Op::OpAccessChain: return true;
+		case Op::OpInBoundsAccessChain: return true;
+		case Op::OpPtrAccessChain: return true;
+		case Op::OpArrayLength: return true;
+		case Op::OpGenericPtrMemSemantics: return true;
+		case Op::OpInBoundsPtrAccessChain: return true;
+		case Op::OpPtrEqual: return true;
+		case Op::OpPtrNotEqual: return true;
+		case Op::OpPtrDiff: return true;
+		case Op::OpCooperativeMatrixLoadKHR: return true;
+		case Op::OpCooperativeMatrixStoreKHR: return true;
+		}
+	}
+	// instruction class Miscellaneous
+	inline constexpr bool IsMiscellaneous(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpNop: return true;
+		case Op::OpUndef: return true;
+		case Op::OpSizeOf: return true;
+		case Op::OpCooperativeMatrixLengthKHR: return true;
+		case Op::OpAssumeTrueKHR: return true;
+		case Op::OpExpectKHR: return true;
+		}
+	}
+	// instruction class Mode-Setting
+	inline constexpr bool IsModeSetting(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpMemoryModel: return true;
+		case Op::OpEntryPoint: return true;
+		case Op::OpExecutionMode: return true;
+		case Op::OpCapability: return true;
+		case Op::OpExecutionModeId: return true;
+		}
+	}
+	// instruction class Non-Uniform
+	inline constexpr bool IsNonUniform(Op opcode) {
 		switch (opcode) {
-		default: return true; // majority of instructions has a result
-		case Op::OpNop: return false;
-		case Op::OpSourceContinued: return false;
-		case Op::OpSource: return false;
-		case Op::OpSourceExtension: return false;
-		case Op::OpName: return false;
-		case Op::OpMemberName: return false;
-		case Op::OpLine: return false;
-		case Op::OpExtension: return false;
-		case Op::OpMemoryModel: return false;
-		case Op::OpEntryPoint: return false;
-		case Op::OpExecutionMode: return false;
-		case Op::OpCapability: return false;
-		case Op::OpTypeForwardPointer: return false;
-		case Op::OpFunctionEnd: return false;
-		case Op::OpStore: return false;
-		case Op::OpCopyMemory: return false;
-		case Op::OpCopyMemorySized: return false;
-		case Op::OpDecorate: return false;
-		case Op::OpMemberDecorate: return false;
-		case Op::OpGroupDecorate: return false;
-		case Op::OpGroupMemberDecorate: return false;
-		case Op::OpImageWrite: return false;
-		case Op::OpEmitVertex: return false;
-		case Op::OpEndPrimitive: return false;
-		case Op::OpEmitStreamVertex: return false;
-		case Op::OpEndStreamPrimitive: return false;
-		case Op::OpControlBarrier: return false;
-		case Op::OpMemoryBarrier: return false;
-		case Op::OpAtomicStore: return false;
-		case Op::OpLoopMerge: return false;
-		case Op::OpSelectionMerge: return false;
-		case Op::OpBranch: return false;
-		case Op::OpBranchConditional: return false;
-		case Op::OpSwitch: return false;
-		case Op::OpKill: return false;
-		case Op::OpReturn: return false;
-		case Op::OpReturnValue: return false;
-		case Op::OpUnreachable: return false;
-		case Op::OpLifetimeStart: return false;
-		case Op::OpLifetimeStop: return false;
-		case Op::OpGroupWaitEvents: return false;
-		case Op::OpCommitReadPipe: return false;
-		case Op::OpCommitWritePipe: return false;
-		case Op::OpGroupCommitReadPipe: return false;
-		case Op::OpGroupCommitWritePipe: return false;
-		case Op::OpRetainEvent: return false;
-		case Op::OpReleaseEvent: return false;
-		case Op::OpSetUserEventStatus: return false;
-		case Op::OpCaptureEventProfilingInfo: return false;
-		case Op::OpNoLine: return false;
-		case Op::OpAtomicFlagClear: return false;
-		case Op::OpMemoryNamedBarrier: return false;
-		case Op::OpModuleProcessed: return false;
-		case Op::OpExecutionModeId: return false;
-		case Op::OpDecorateId: return false;
-		case Op::OpTerminateInvocation: return false;
-		case Op::OpTraceRayKHR: return false;
-		case Op::OpExecuteCallableKHR: return false;
-		case Op::OpIgnoreIntersectionKHR: return false;
-		case Op::OpTerminateRayKHR: return false;
-		case Op::OpCooperativeMatrixStoreKHR: return false;
-		case Op::OpRayQueryInitializeKHR: return false;
-		case Op::OpRayQueryTerminateKHR: return false;
-		case Op::OpRayQueryGenerateIntersectionKHR: return false;
-		case Op::OpRayQueryConfirmIntersectionKHR: return false;
-		case Op::OpFinalizeNodePayloadsAMDX: return false;
-		case Op::OpInitializeNodePayloadsAMDX: return false;
-		case Op::OpHitObjectRecordHitMotionNV: return false;
-		case Op::OpHitObjectRecordHitWithIndexMotionNV: return false;
-		case Op::OpHitObjectRecordMissMotionNV: return false;
-		case Op::OpHitObjectTraceRayMotionNV: return false;
-		case Op::OpHitObjectRecordEmptyNV: return false;
-		case Op::OpHitObjectTraceRayNV: return false;
-		case Op::OpHitObjectRecordHitNV: return false;
-		case Op::OpHitObjectRecordHitWithIndexNV: return false;
-		case Op::OpHitObjectRecordMissNV: return false;
-		case Op::OpHitObjectExecuteShaderNV: return false;
-		case Op::OpHitObjectGetAttributesNV: return false;
-		case Op::OpReorderThreadWithHitObjectNV: return false;
-		case Op::OpReorderThreadWithHintNV: return false;
-		case Op::OpEmitMeshTasksEXT: return false;
-		case Op::OpSetMeshOutputsEXT: return false;
-		case Op::OpWritePackedPrimitiveIndices4x8NV: return false;
-		case Op::OpIgnoreIntersectionNV: return false;
-		case Op::OpTerminateRayNV: return false;
-		case Op::OpTraceNV: return false;
-		case Op::OpTraceMotionNV: return false;
-		case Op::OpTraceRayMotionNV: return false;
-		case Op::OpExecuteCallableNV: return false;
-		case Op::OpCooperativeMatrixStoreNV: return false;
-		case Op::OpBeginInvocationInterlockEXT: return false;
-		case Op::OpEndInvocationInterlockEXT: return false;
-		case Op::OpDemoteToHelperInvocation: return false;
-		case Op::OpSamplerImageAddressingModeNV: return false;
-		case Op::OpSubgroupBlockWriteINTEL: return false;
-		case Op::OpSubgroupImageBlockWriteINTEL: return false;
-		case Op::OpSubgroupImageMediaBlockWriteINTEL: return false;
-		case Op::OpAssumeTrueKHR: return false;
-		case Op::OpDecorateString: return false;
-		case Op::OpMemberDecorateString: return false;
-		case Op::OpRestoreMemoryINTEL: return false;
-		case Op::OpLoopControlINTEL: return false;
-		case Op::OpTypeStructContinuedINTEL: return false;
-		case Op::OpConstantCompositeContinuedINTEL: return false;
-		case Op::OpSpecConstantCompositeContinuedINTEL: return false;
-		case Op::OpControlBarrierArriveINTEL: return false;
-		case Op::OpControlBarrierWaitINTEL: return false;
-		}
-	}
-	inline constexpr bool HasResultType(Op opcode) {
+		default: return false;
+		case Op::OpGroupNonUniformElect: return true;
+		case Op::OpGroupNonUniformAll: return true;
+		case Op::OpGroupNonUniformAny: return true;
+		case Op::OpGroupNonUniformAllEqual: return true;
+		case Op::OpGroupNonUniformBroadcast: return true;
+		case Op::OpGroupNonUniformBroadcastFirst: return true;
+		case Op::OpGroupNonUniformBallot: return true;
+		case Op::OpGroupNonUniformInverseBallot: return true;
+		case Op::OpGroupNonUniformBallotBitExtract: return true;
+		case Op::OpGroupNonUniformBallotBitCount: return true;
+		case Op::OpGroupNonUniformBallotFindLSB: return true;
+		case Op::OpGroupNonUniformBallotFindMSB: return true;
+		case Op::OpGroupNonUniformShuffle: return true;
+		case Op::OpGroupNonUniformShuffleXor: return true;
+		case Op::OpGroupNonUniformShuffleUp: return true;
+		case Op::OpGroupNonUniformShuffleDown: return true;
+		case Op::OpGroupNonUniformIAdd: return true;
+		case Op::OpGroupNonUniformFAdd: return true;
+		case Op::OpGroupNonUniformIMul: return true;
+		case Op::OpGroupNonUniformFMul: return true;
+		case Op::OpGroupNonUniformSMin: return true;
+		case Op::OpGroupNonUniformUMin: return true;
+		case Op::OpGroupNonUniformFMin: return true;
+		case Op::OpGroupNonUniformSMax: return true;
+		case Op::OpGroupNonUniformUMax: return true;
+		case Op::OpGroupNonUniformFMax: return true;
+		case Op::OpGroupNonUniformBitwiseAnd: return true;
+		case Op::OpGroupNonUniformBitwiseOr: return true;
+		case Op::OpGroupNonUniformBitwiseXor: return true;
+		case Op::OpGroupNonUniformLogicalAnd: return true;
+		case Op::OpGroupNonUniformLogicalOr: return true;
+		case Op::OpGroupNonUniformLogicalXor: return true;
+		case Op::OpGroupNonUniformQuadBroadcast: return true;
+		case Op::OpGroupNonUniformQuadSwap: return true;
+		case Op::OpGroupNonUniformPartitionNV: return true;
+		}
+	}
+	// instruction class Pipe
+	inline constexpr bool IsPipe(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpReadPipe: return true;
+		case Op::OpWritePipe: return true;
+		case Op::OpReservedReadPipe: return true;
+		case Op::OpReservedWritePipe: return true;
+		case Op::OpReserveReadPipePackets: return true;
+		case Op::OpReserveWritePipePackets: return true;
+		case Op::OpCommitReadPipe: return true;
+		case Op::OpCommitWritePipe: return true;
+		case Op::OpIsValidReserveId: return true;
+		case Op::OpGetNumPipePackets: return true;
+		case Op::OpGetMaxPipePackets: return true;
+		case Op::OpGroupReserveReadPipePackets: return true;
+		case Op::OpGroupReserveWritePipePackets: return true;
+		case Op::OpGroupCommitReadPipe: return true;
+		case Op::OpGroupCommitWritePipe: return true;
+		case Op::OpConstantPipeStorage: return true;
+		case Op::OpCreatePipeFromPipeStorage: return true;
+		case Op::OpReadPipeBlockingINTEL: return true;
+		case Op::OpWritePipeBlockingINTEL: return true;
+		}
+	}
+	// instruction class Primitive
+	inline constexpr bool IsPrimitive(Op opcode) {
 		switch (opcode) {
-		default: return true; // majority of instructions has a result type
-		case Op::OpNop: return false;
-		case Op::OpSourceContinued: return false;
-		case Op::OpSource: return false;
-		case Op::OpSourceExtension: return false;
-		case Op::OpName: return false;
-		case Op::OpMemberName: return false;
-		case Op::OpString: return false;
-		case Op::OpLine: return false;
-		case Op::OpExtension: return false;
-		case Op::OpExtInstImport: return false;
-		case Op::OpMemoryModel: return false;
-		case Op::OpEntryPoint: return false;
-		case Op::OpExecutionMode: return false;
-		case Op::OpCapability: return false;
-		case Op::OpTypeVoid: return false;
-		case Op::OpTypeBool: return false;
-		case Op::OpTypeInt: return false;
-		case Op::OpTypeFloat: return false;
-		case Op::OpTypeVector: return false;
-		case Op::OpTypeMatrix: return false;
-		case Op::OpTypeImage: return false;
-		case Op::OpTypeSampler: return false;
-		case Op::OpTypeSampledImage: return false;
-		case Op::OpTypeArray: return false;
-		case Op::OpTypeRuntimeArray: return false;
-		case Op::OpTypeStruct: return false;
-		case Op::OpTypeOpaque: return false;
-		case Op::OpTypePointer: return false;
-		case Op::OpTypeFunction: return false;
-		case Op::OpTypeEvent: return false;
-		case Op::OpTypeDeviceEvent: return false;
-		case Op::OpTypeReserveId: return false;
-		case Op::OpTypeQueue: return false;
-		case Op::OpTypePipe: return false;
-		case Op::OpTypeForwardPointer: return false;
-		case Op::OpFunctionEnd: return false;
-		case Op::OpStore: return false;
-		case Op::OpCopyMemory: return false;
-		case Op::OpCopyMemorySized: return false;
-		case Op::OpDecorate: return false;
-		case Op::OpMemberDecorate: return false;
-		case Op::OpDecorationGroup: return false;
-		case Op::OpGroupDecorate: return false;
-		case Op::OpGroupMemberDecorate: return false;
-		case Op::OpImageWrite: return false;
-		case Op::OpEmitVertex: return false;
-		case Op::OpEndPrimitive: return false;
-		case Op::OpEmitStreamVertex: return false;
-		case Op::OpEndStreamPrimitive: return false;
-		case Op::OpControlBarrier: return false;
-		case Op::OpMemoryBarrier: return false;
-		case Op::OpAtomicStore: return false;
-		case Op::OpLoopMerge: return false;
-		case Op::OpSelectionMerge: return false;
-		case Op::OpLabel: return false;
-		case Op::OpBranch: return false;
-		case Op::OpBranchConditional: return false;
-		case Op::OpSwitch: return false;
-		case Op::OpKill: return false;
-		case Op::OpReturn: return false;
-		case Op::OpReturnValue: return false;
-		case Op::OpUnreachable: return false;
-		case Op::OpLifetimeStart: return false;
-		case Op::OpLifetimeStop: return false;
-		case Op::OpGroupWaitEvents: return false;
-		case Op::OpCommitReadPipe: return false;
-		case Op::OpCommitWritePipe: return false;
-		case Op::OpGroupCommitReadPipe: return false;
-		case Op::OpGroupCommitWritePipe: return false;
-		case Op::OpRetainEvent: return false;
-		case Op::OpReleaseEvent: return false;
-		case Op::OpSetUserEventStatus: return false;
-		case Op::OpCaptureEventProfilingInfo: return false;
-		case Op::OpNoLine: return false;
-		case Op::OpAtomicFlagClear: return false;
-		case Op::OpTypePipeStorage: return false;
-		case Op::OpTypeNamedBarrier: return false;
-		case Op::OpMemoryNamedBarrier: return false;
-		case Op::OpModuleProcessed: return false;
-		case Op::OpExecutionModeId: return false;
-		case Op::OpDecorateId: return false;
-		case Op::OpTerminateInvocation: return false;
-		case Op::OpTraceRayKHR: return false;
-		case Op::OpExecuteCallableKHR: return false;
-		case Op::OpIgnoreIntersectionKHR: return false;
-		case Op::OpTerminateRayKHR: return false;
-		case Op::OpTypeCooperativeMatrixKHR: return false;
-		case Op::OpCooperativeMatrixStoreKHR: return false;
-		case Op::OpTypeRayQueryKHR: return false;
-		case Op::OpRayQueryInitializeKHR: return false;
-		case Op::OpRayQueryTerminateKHR: return false;
-		case Op::OpRayQueryGenerateIntersectionKHR: return false;
-		case Op::OpRayQueryConfirmIntersectionKHR: return false;
-		case Op::OpFinalizeNodePayloadsAMDX: return false;
-		case Op::OpInitializeNodePayloadsAMDX: return false;
-		case Op::OpHitObjectRecordHitMotionNV: return false;
-		case Op::OpHitObjectRecordHitWithIndexMotionNV: return false;
-		case Op::OpHitObjectRecordMissMotionNV: return false;
-		case Op::OpHitObjectTraceRayMotionNV: return false;
-		case Op::OpHitObjectRecordEmptyNV: return false;
-		case Op::OpHitObjectTraceRayNV: return false;
-		case Op::OpHitObjectRecordHitNV: return false;
-		case Op::OpHitObjectRecordHitWithIndexNV: return false;
-		case Op::OpHitObjectRecordMissNV: return false;
-		case Op::OpHitObjectExecuteShaderNV: return false;
-		case Op::OpHitObjectGetAttributesNV: return false;
-		case Op::OpReorderThreadWithHitObjectNV: return false;
-		case Op::OpReorderThreadWithHintNV: return false;
-		case Op::OpTypeHitObjectNV: return false;
-		case Op::OpEmitMeshTasksEXT: return false;
-		case Op::OpSetMeshOutputsEXT: return false;
-		case Op::OpWritePackedPrimitiveIndices4x8NV: return false;
-		case Op::OpIgnoreIntersectionNV: return false;
-		case Op::OpTerminateRayNV: return false;
-		case Op::OpTraceNV: return false;
-		case Op::OpTraceMotionNV: return false;
-		case Op::OpTraceRayMotionNV: return false;
-		case Op::OpTypeAccelerationStructureNV: return false;
-		case Op::OpExecuteCallableNV: return false;
-		case Op::OpTypeCooperativeMatrixNV: return false;
-		case Op::OpCooperativeMatrixStoreNV: return false;
-		case Op::OpBeginInvocationInterlockEXT: return false;
-		case Op::OpEndInvocationInterlockEXT: return false;
-		case Op::OpDemoteToHelperInvocation: return false;
-		case Op::OpSamplerImageAddressingModeNV: return false;
-		case Op::OpSubgroupBlockWriteINTEL: return false;
-		case Op::OpSubgroupImageBlockWriteINTEL: return false;
-		case Op::OpSubgroupImageMediaBlockWriteINTEL: return false;
-		case Op::OpAssumeTrueKHR: return false;
-		case Op::OpDecorateString: return false;
-		case Op::OpMemberDecorateString: return false;
-		case Op::OpTypeVmeImageINTEL: return false;
-		case Op::OpTypeAvcImePayloadINTEL: return false;
-		case Op::OpTypeAvcRefPayloadINTEL: return false;
-		case Op::OpTypeAvcSicPayloadINTEL: return false;
-		case Op::OpTypeAvcMcePayloadINTEL: return false;
-		case Op::OpTypeAvcMceResultINTEL: return false;
-		case Op::OpTypeAvcImeResultINTEL: return false;
-		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return false;
-		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return false;
-		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return false;
-		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return false;
-		case Op::OpTypeAvcRefResultINTEL: return false;
-		case Op::OpTypeAvcSicResultINTEL: return false;
-		case Op::OpRestoreMemoryINTEL: return false;
-		case Op::OpLoopControlINTEL: return false;
-		case Op::OpAliasDomainDeclINTEL: return false;
-		case Op::OpAliasScopeDeclINTEL: return false;
-		case Op::OpAliasScopeListDeclINTEL: return false;
-		case Op::OpTypeBufferSurfaceINTEL: return false;
-		case Op::OpTypeStructContinuedINTEL: return false;
-		case Op::OpConstantCompositeContinuedINTEL: return false;
-		case Op::OpSpecConstantCompositeContinuedINTEL: return false;
-		case Op::OpControlBarrierArriveINTEL: return false;
-		case Op::OpControlBarrierWaitINTEL: return false;
-		}
-	}
-	inline constexpr bool IsTypeOp(Op opcode) {
+		default: return false;
+		case Op::OpEmitVertex: return true;
+		case Op::OpEndPrimitive: return true;
+		case Op::OpEmitStreamVertex: return true;
+		case Op::OpEndStreamPrimitive: return true;
+		}
+	}
+	// instruction class Relational_and_Logical
+	inline constexpr bool IsRelationalAndLogical(Op opcode) {
 		switch (opcode) {
-		default: return false; // majority of instructions are not types
+		default: return false;
+		case Op::OpAny: return true;
+		case Op::OpAll: return true;
+		case Op::OpIsNan: return true;
+		case Op::OpIsInf: return true;
+		case Op::OpIsFinite: return true;
+		case Op::OpIsNormal: return true;
+		case Op::OpSignBitSet: return true;
+		case Op::OpLessOrGreater: return true;
+		case Op::OpOrdered: return true;
+		case Op::OpUnordered: return true;
+		case Op::OpLogicalEqual: return true;
+		case Op::OpLogicalNotEqual: return true;
+		case Op::OpLogicalOr: return true;
+		case Op::OpLogicalAnd: return true;
+		case Op::OpLogicalNot: return true;
+		case Op::OpSelect: return true;
+		case Op::OpIEqual: return true;
+		case Op::OpINotEqual: return true;
+		case Op::OpUGreaterThan: return true;
+		case Op::OpSGreaterThan: return true;
+		case Op::OpUGreaterThanEqual: return true;
+		case Op::OpSGreaterThanEqual: return true;
+		case Op::OpULessThan: return true;
+		case Op::OpSLessThan: return true;
+		case Op::OpULessThanEqual: return true;
+		case Op::OpSLessThanEqual: return true;
+		case Op::OpFOrdEqual: return true;
+		case Op::OpFUnordEqual: return true;
+		case Op::OpFOrdNotEqual: return true;
+		case Op::OpFUnordNotEqual: return true;
+		case Op::OpFOrdLessThan: return true;
+		case Op::OpFUnordLessThan: return true;
+		case Op::OpFOrdGreaterThan: return true;
+		case Op::OpFUnordGreaterThan: return true;
+		case Op::OpFOrdLessThanEqual: return true;
+		case Op::OpFUnordLessThanEqual: return true;
+		case Op::OpFOrdGreaterThanEqual: return true;
+		case Op::OpFUnordGreaterThanEqual: return true;
+		}
+	}
+	// instruction class Type-Declaration
+	inline constexpr bool IsTypeDeclaration(Op opcode) {
+		switch (opcode) {
+		default: return false;
 		case Op::OpTypeVoid: return true;
 		case Op::OpTypeBool: return true;
 		case Op::OpTypeInt: return true;
@@ -3225,46 +4243,8282 @@ namespace spvgentwo::spv
 		case Op::OpTypeHitObjectNV: return true;
 		case Op::OpTypeAccelerationStructureNV: return true;
 		case Op::OpTypeCooperativeMatrixNV: return true;
-		case Op::OpTypeVmeImageINTEL: return true;
-		case Op::OpTypeAvcImePayloadINTEL: return true;
-		case Op::OpTypeAvcRefPayloadINTEL: return true;
-		case Op::OpTypeAvcSicPayloadINTEL: return true;
-		case Op::OpTypeAvcMcePayloadINTEL: return true;
-		case Op::OpTypeAvcMceResultINTEL: return true;
-		case Op::OpTypeAvcImeResultINTEL: return true;
-		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return true;
-		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return true;
-		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return true;
-		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return true;
-		case Op::OpTypeAvcRefResultINTEL: return true;
-		case Op::OpTypeAvcSicResultINTEL: return true;
 		case Op::OpTypeBufferSurfaceINTEL: return true;
 		case Op::OpTypeStructContinuedINTEL: return true;
 		}
 	}
-	inline constexpr bool IsConstantOp(Op opcode) {
+	// block terminators with successor labels
+	inline constexpr bool IsBranch(Op opcode) {
 		switch (opcode) {
-		default: return false; // majority of instructions are not constants
-		case Op::OpConstantTrue: return true;
-		case Op::OpConstantFalse: return true;
-		case Op::OpConstant: return true;
-		case Op::OpConstantComposite: return true;
-		case Op::OpConstantSampler: return true;
-		case Op::OpConstantNull: return true;
-		case Op::OpConstantPipeStorage: return true;
-		case Op::OpConstantFunctionPointerINTEL: return true;
-		case Op::OpConstantCompositeContinuedINTEL: return true;
+		default: return false;
+		case Op::OpBranch: return true;
+		case Op::OpBranchConditional: return true;
+		case Op::OpSwitch: return true;
 		}
 	}
-	inline constexpr bool IsSpecConstantOp(Op opcode) {
+	// function returns
+	inline constexpr bool IsReturn(Op opcode) {
 		switch (opcode) {
-		default: return false; // majority of instructions are not spec constants
-		case Op::OpSpecConstantTrue: return true;
-		case Op::OpSpecConstantFalse: return true;
-		case Op::OpSpecConstant: return true;
-		case Op::OpSpecConstantComposite: return true;
-		case Op::OpSpecConstantOp: return true;
-		case Op::OpSpecConstantCompositeContinuedINTEL: return true;
+		default: return false;
+		case Op::OpReturn: return true;
+		case Op::OpReturnValue: return true;
+		}
+	}
+	// unreachable block end
+	inline constexpr bool IsUnreachable(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpUnreachable: return true;
+		}
+	}
+	// last instruction of a block: branches, returns, unreachable and the ones from --control-flow-overrides
+	inline constexpr bool IsBlockTerminator(Op opcode) {
+		switch (opcode) {
+		default: return false;
+		case Op::OpBranch: return true;
+		case Op::OpBranchConditional: return true;
+		case Op::OpSwitch: return true;
+		case Op::OpKill: return true;
+		case Op::OpReturn: return true;
+		case Op::OpReturnValue: return true;
+		case Op::OpUnreachable: return true;
+		case Op::OpTerminateInvocation: return true;
+		case Op::OpIgnoreIntersectionKHR: return true;
+		case Op::OpTerminateRayKHR: return true;
+		case Op::OpEmitMeshTasksEXT: return true;
+		}
+	}
+	// operand indices (counting IdResultType and IdResult) of the successor labels of a branch,
+	// pairsIndex is the trailing operand list of pairs whose last word is a label (OpSwitch targets)
+	struct SuccessorOperands {
+		unsigned int count;
+		unsigned int indices[2];
+		unsigned int pairsIndex;
+	};
+	static constexpr unsigned int NoSuccessorPairs = 0xffffffff;
+	inline constexpr SuccessorOperands GetSuccessorOperands(Op opcode) {
+		switch (opcode) {
+		default: return { 0u, {}, NoSuccessorPairs };
+		case Op::OpBranch: return { 1u, { 0u }, NoSuccessorPairs };
+		case Op::OpBranchConditional: return { 2u, { 1u, 2u }, NoSuccessorPairs };
+		case Op::OpSwitch: return { 1u, { 1u }, 2u };
+		}
+	}
+	static constexpr unsigned int UnboundedWordCount = 0xffffffff;
+	inline constexpr unsigned int MinWordCount(Op opcode) {
+		switch (opcode) {
+		default: return 1u; // unknown opcode
+		case Op::OpNop: return 1u;
+		case Op::OpUndef: return 3u;
+		case Op::OpSourceContinued: return 2u;
+		case Op::OpSource: return 3u;
+		case Op::OpSourceExtension: return 2u;
+		case Op::OpName: return 3u;
+		case Op::OpMemberName: return 4u;
+		case Op::OpString: return 3u;
+		case Op::OpLine: return 4u;
+		case Op::OpExtension: return 2u;
+		case Op::OpExtInstImport: return 3u;
+		case Op::OpExtInst: return 5u;
+		case Op::OpMemoryModel: return 3u;
+		case Op::OpEntryPoint: return 4u;
+		case Op::OpExecutionMode: return 3u;
+		case Op::OpCapability: return 2u;
+		case Op::OpTypeVoid: return 2u;
+		case Op::OpTypeBool: return 2u;
+		case Op::OpTypeInt: return 4u;
+		case Op::OpTypeFloat: return 3u;
+		case Op::OpTypeVector: return 4u;
+		case Op::OpTypeMatrix: return 4u;
+		case Op::OpTypeImage: return 9u;
+		case Op::OpTypeSampler: return 2u;
+		case Op::OpTypeSampledImage: return 3u;
+		case Op::OpTypeArray: return 4u;
+		case Op::OpTypeRuntimeArray: return 3u;
+		case Op::OpTypeStruct: return 2u;
+		case Op::OpTypeOpaque: return 3u;
+		case Op::OpTypePointer: return 4u;
+		case Op::OpTypeFunction: return 3u;
+		case Op::OpTypeEvent: return 2u;
+		case Op::OpTypeDeviceEvent: return 2u;
+		case Op::OpTypeReserveId: return 2u;
+		case Op::OpTypeQueue: return 2u;
+		case Op::OpTypePipe: return 3u;
+		case Op::OpTypeForwardPointer: return 3u;
+		case Op::OpConstantTrue: return 3u;
+		case Op::OpConstantFalse: return 3u;
+		case Op::OpConstant: return 4u;
+		case Op::OpConstantComposite: return 3u;
+		case Op::OpConstantSampler: return 6u;
+		case Op::OpConstantNull: return 3u;
+		case Op::OpSpecConstantTrue: return 3u;
+		case Op::OpSpecConstantFalse: return 3u;
+		case Op::OpSpecConstant: return 4u;
+		case Op::OpSpecConstantComposite: return 3u;
+		case Op::OpSpecConstantOp: return 4u;
+		case Op::OpFunction: return 5u;
+		case Op::OpFunctionParameter: return 3u;
+		case Op::OpFunctionEnd: return 1u;
+		case Op::OpFunctionCall: return 4u;
+		case Op::OpVariable: return 4u;
+		case Op::OpImageTexelPointer: return 6u;
+		case Op::OpLoad: return 4u;
+		case Op::OpStore: return 3u;
+		case Op::OpCopyMemory: return 3u;
+		case Op::OpCopyMemorySized: return 4u;
+		case Op::OpAccessChain: return 4u;
+		case Op::OpInBoundsAccessChain: return 4u;
+		case Op::OpPtrAccessChain: return 5u;
+		case Op::OpArrayLength: return 5u;
+		case Op::OpGenericPtrMemSemantics: return 4u;
+		case Op::OpInBoundsPtrAccessChain: return 5u;
+		case Op::OpDecorate: return 3u;
+		case Op::OpMemberDecorate: return 4u;
+		case Op::OpDecorationGroup: return 2u;
+		case Op::OpGroupDecorate: return 2u;
+		case Op::OpGroupMemberDecorate: return 2u;
+		case Op::OpVectorExtractDynamic: return 5u;
+		case Op::OpVectorInsertDynamic: return 6u;
+		case Op::OpVectorShuffle: return 5u;
+		case Op::OpCompositeConstruct: return 3u;
+		case Op::OpCompositeExtract: return 4u;
+		case Op::OpCompositeInsert: return 5u;
+		case Op::OpCopyObject: return 4u;
+		case Op::OpTranspose: return 4u;
+		case Op::OpSampledImage: return 5u;
+		case Op::OpImageSampleImplicitLod: return 5u;
+		case Op::OpImageSampleExplicitLod: return 6u;
+		case Op::OpImageSampleDrefImplicitLod: return 6u;
+		case Op::OpImageSampleDrefExplicitLod: return 7u;
+		case Op::OpImageSampleProjImplicitLod: return 5u;
+		case Op::OpImageSampleProjExplicitLod: return 6u;
+		case Op::OpImageSampleProjDrefImplicitLod: return 6u;
+		case Op::OpImageSampleProjDrefExplicitLod: return 7u;
+		case Op::OpImageFetch: return 5u;
+		case Op::OpImageGather: return 6u;
+		case Op::OpImageDrefGather: return 6u;
+		case Op::OpImageRead: return 5u;
+		case Op::OpImageWrite: return 4u;
+		case Op::OpImage: return 4u;
+		case Op::OpImageQueryFormat: return 4u;
+		case Op::OpImageQueryOrder: return 4u;
+		case Op::OpImageQuerySizeLod: return 5u;
+		case Op::OpImageQuerySize: return 4u;
+		case Op::OpImageQueryLod: return 5u;
+		case Op::OpImageQueryLevels: return 4u;
+		case Op::OpImageQuerySamples: return 4u;
+		case Op::OpConvertFToU: return 4u;
+		case Op::OpConvertFToS: return 4u;
+		case Op::OpConvertSToF: return 4u;
+		case Op::OpConvertUToF: return 4u;
+		case Op::OpUConvert: return 4u;
+		case Op::OpSConvert: return 4u;
+		case Op::OpFConvert: return 4u;
+		case Op::OpQuantizeToF16: return 4u;
+		case Op::OpConvertPtrToU: return 4u;
+		case Op::OpSatConvertSToU: return 4u;
+		case Op::OpSatConvertUToS: return 4u;
+		case Op::OpConvertUToPtr: return 4u;
+		case Op::OpPtrCastToGeneric: return 4u;
+		case Op::OpGenericCastToPtr: return 4u;
+		case Op::OpGenericCastToPtrExplicit: return 5u;
+		case Op::OpBitcast: return 4u;
+		case Op::OpSNegate: return 4u;
+		case Op::OpFNegate: return 4u;
+		case Op::OpIAdd: return 5u;
+		case Op::OpFAdd: return 5u;
+		case Op::OpISub: return 5u;
+		case Op::OpFSub: return 5u;
+		case Op::OpIMul: return 5u;
+		case Op::OpFMul: return 5u;
+		case Op::OpUDiv: return 5u;
+		case Op::OpSDiv: return 5u;
+		case Op::OpFDiv: return 5u;
+		case Op::OpUMod: return 5u;
+		case Op::OpSRem: return 5u;
+		case Op::OpSMod: return 5u;
+		case Op::OpFRem: return 5u;
+		case Op::OpFMod: return 5u;
+		case Op::OpVectorTimesScalar: return 5u;
+		case Op::OpMatrixTimesScalar: return 5u;
+		case Op::OpVectorTimesMatrix: return 5u;
+		case Op::OpMatrixTimesVector: return 5u;
+		case Op::OpMatrixTimesMatrix: return 5u;
+		case Op::OpOuterProduct: return 5u;
+		case Op::OpDot: return 5u;
+		case Op::OpIAddCarry: return 5u;
+		case Op::OpISubBorrow: return 5u;
+		case Op::OpUMulExtended: return 5u;
+		case Op::OpSMulExtended: return 5u;
+		case Op::OpAny: return 4u;
+		case Op::OpAll: return 4u;
+		case Op::OpIsNan: return 4u;
+		case Op::OpIsInf: return 4u;
+		case Op::OpIsFinite: return 4u;
+		case Op::OpIsNormal: return 4u;
+		case Op::OpSignBitSet: return 4u;
+		case Op::OpLessOrGreater: return 5u;
+		case Op::OpOrdered: return 5u;
+		case Op::OpUnordered: return 5u;
+		case Op::OpLogicalEqual: return 5u;
+		case Op::OpLogicalNotEqual: return 5u;
+		case Op::OpLogicalOr: return 5u;
+		case Op::OpLogicalAnd: return 5u;
+		case Op::OpLogicalNot: return 4u;
+		case Op::OpSelect: return 6u;
+		case Op::OpIEqual: return 5u;
+		case Op::OpINotEqual: return 5u;
+		case Op::OpUGreaterThan: return 5u;
+		case Op::OpSGreaterThan: return 5u;
+		case Op::OpUGreaterThanEqual: return 5u;
+		case Op::OpSGreaterThanEqual: return 5u;
+		case Op::OpULessThan: return 5u;
+		case Op::OpSLessThan: return 5u;
+		case Op::OpULessThanEqual: return 5u;
+		case Op::OpSLessThanEqual: return 5u;
+		case Op::OpFOrdEqual: return 5u;
+		case Op::OpFUnordEqual: return 5u;
+		case Op::OpFOrdNotEqual: return 5u;
+		case Op::OpFUnordNotEqual: return 5u;
+		case Op::OpFOrdLessThan: return 5u;
+		case Op::OpFUnordLessThan: return 5u;
+		case Op::OpFOrdGreaterThan: return 5u;
+		case Op::OpFUnordGreaterThan: return 5u;
+		case Op::OpFOrdLessThanEqual: return 5u;
+		case Op::OpFUnordLessThanEqual: return 5u;
+		case Op::OpFOrdGreaterThanEqual: return 5u;
+		case Op::OpFUnordGreaterThanEqual: return 5u;
+		case Op::OpShiftRightLogical: return 5u;
+		case Op::OpShiftRightArithmetic: return 5u;
+		case Op::OpShiftLeftLogical: return 5u;
+		case Op::OpBitwiseOr: return 5u;
+		case Op::OpBitwiseXor: return 5u;
+		case Op::OpBitwiseAnd: return 5u;
+		case Op::OpNot: return 4u;
+		case Op::OpBitFieldInsert: return 7u;
+		case Op::OpBitFieldSExtract: return 6u;
+		case Op::OpBitFieldUExtract: return 6u;
+		case Op::OpBitReverse: return 4u;
+		case Op::OpBitCount: return 4u;
+		case Op::OpDPdx: return 4u;
+		case Op::OpDPdy: return 4u;
+		case Op::OpFwidth: return 4u;
+		case Op::OpDPdxFine: return 4u;
+		case Op::OpDPdyFine: return 4u;
+		case Op::OpFwidthFine: return 4u;
+		case Op::OpDPdxCoarse: return 4u;
+		case Op::OpDPdyCoarse: return 4u;
+		case Op::OpFwidthCoarse: return 4u;
+		case Op::OpEmitVertex: return 1u;
+		case Op::OpEndPrimitive: return 1u;
+		case Op::OpEmitStreamVertex: return 2u;
+		case Op::OpEndStreamPrimitive: return 2u;
+		case Op::OpControlBarrier: return 4u;
+		case Op::OpMemoryBarrier: return 3u;
+		case Op::OpAtomicLoad: return 6u;
+		case Op::OpAtomicStore: return 5u;
+		case Op::OpAtomicExchange: return 7u;
+		case Op::OpAtomicCompareExchange: return 9u;
+		case Op::OpAtomicCompareExchangeWeak: return 9u;
+		case Op::OpAtomicIIncrement: return 6u;
+		case Op::OpAtomicIDecrement: return 6u;
+		case Op::OpAtomicIAdd: return 7u;
+		case Op::OpAtomicISub: return 7u;
+		case Op::OpAtomicSMin: return 7u;
+		case Op::OpAtomicUMin: return 7u;
+		case Op::OpAtomicSMax: return 7u;
+		case Op::OpAtomicUMax: return 7u;
+		case Op::OpAtomicAnd: return 7u;
+		case Op::OpAtomicOr: return 7u;
+		case Op::OpAtomicXor: return 7u;
+		case Op::OpPhi: return 3u;
+		case Op::OpLoopMerge: return 4u;
+		case Op::OpSelectionMerge: return 3u;
+		case Op::OpLabel: return 2u;
+		case Op::OpBranch: return 2u;
+		case Op::OpBranchConditional: return 4u;
+		case Op::OpSwitch: return 3u;
+		case Op::OpKill: return 1u;
+		case Op::OpReturn: return 1u;
+		case Op::OpReturnValue: return 2u;
+		case Op::OpUnreachable: return 1u;
+		case Op::OpLifetimeStart: return 3u;
+		case Op::OpLifetimeStop: return 3u;
+		case Op::OpGroupAsyncCopy: return 9u;
+		case Op::OpGroupWaitEvents: return 4u;
+		case Op::OpGroupAll: return 5u;
+		case Op::OpGroupAny: return 5u;
+		case Op::OpGroupBroadcast: return 6u;
+		case Op::OpGroupIAdd: return 6u;
+		case Op::OpGroupFAdd: return 6u;
+		case Op::OpGroupFMin: return 6u;
+		case Op::OpGroupUMin: return 6u;
+		case Op::OpGroupSMin: return 6u;
+		case Op::OpGroupFMax: return 6u;
+		case Op::OpGroupUMax: return 6u;
+		case Op::OpGroupSMax: return 6u;
+		case Op::OpReadPipe: return 7u;
+		case Op::OpWritePipe: return 7u;
+		case Op::OpReservedReadPipe: return 9u;
+		case Op::OpReservedWritePipe: return 9u;
+		case Op::OpReserveReadPipePackets: return 7u;
+		case Op::OpReserveWritePipePackets: return 7u;
+		case Op::OpCommitReadPipe: return 5u;
+		case Op::OpCommitWritePipe: return 5u;
+		case Op::OpIsValidReserveId: return 4u;
+		case Op::OpGetNumPipePackets: return 6u;
+		case Op::OpGetMaxPipePackets: return 6u;
+		case Op::OpGroupReserveReadPipePackets: return 8u;
+		case Op::OpGroupReserveWritePipePackets: return 8u;
+		case Op::OpGroupCommitReadPipe: return 6u;
+		case Op::OpGroupCommitWritePipe: return 6u;
+		case Op::OpEnqueueMarker: return 7u;
+		case Op::OpEnqueueKernel: return 13u;
+		case Op::OpGetKernelNDrangeSubGroupCount: return 8u;
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return 8u;
+		case Op::OpGetKernelWorkGroupSize: return 7u;
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return 7u;
+		case Op::OpRetainEvent: return 2u;
+		case Op::OpReleaseEvent: return 2u;
+		case Op::OpCreateUserEvent: return 3u;
+		case Op::OpIsValidEvent: return 4u;
+		case Op::OpSetUserEventStatus: return 3u;
+		case Op::OpCaptureEventProfilingInfo: return 4u;
+		case Op::OpGetDefaultQueue: return 3u;
+		case Op::OpBuildNDRange: return 6u;
+		case Op::OpImageSparseSampleImplicitLod: return 5u;
+		case Op::OpImageSparseSampleExplicitLod: return 6u;
+		case Op::OpImageSparseSampleDrefImplicitLod: return 6u;
+		case Op::OpImageSparseSampleDrefExplicitLod: return 7u;
+		case Op::OpImageSparseSampleProjImplicitLod: return 5u;
+		case Op::OpImageSparseSampleProjExplicitLod: return 6u;
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return 6u;
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return 7u;
+		case Op::OpImageSparseFetch: return 5u;
+		case Op::OpImageSparseGather: return 6u;
+		case Op::OpImageSparseDrefGather: return 6u;
+		case Op::OpImageSparseTexelsResident: return 4u;
+		case Op::OpNoLine: return 1u;
+		case Op::OpAtomicFlagTestAndSet: return 6u;
+		case Op::OpAtomicFlagClear: return 4u;
+		case Op::OpImageSparseRead: return 5u;
+		case Op::OpSizeOf: return 4u;
+		case Op::OpTypePipeStorage: return 2u;
+		case Op::OpConstantPipeStorage: return 6u;
+		case Op::OpCreatePipeFromPipeStorage: return 4u;
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return 8u;
+		case Op::OpGetKernelMaxNumSubgroups: return 7u;
+		case Op::OpTypeNamedBarrier: return 2u;
+		case Op::OpNamedBarrierInitialize: return 4u;
+		case Op::OpMemoryNamedBarrier: return 4u;
+		case Op::OpModuleProcessed: return 2u;
+		case Op::OpExecutionModeId: return 3u;
+		case Op::OpDecorateId: return 3u;
+		case Op::OpGroupNonUniformElect: return 4u;
+		case Op::OpGroupNonUniformAll: return 5u;
+		case Op::OpGroupNonUniformAny: return 5u;
+		case Op::OpGroupNonUniformAllEqual: return 5u;
+		case Op::OpGroupNonUniformBroadcast: return 6u;
+		case Op::OpGroupNonUniformBroadcastFirst: return 5u;
+		case Op::OpGroupNonUniformBallot: return 5u;
+		case Op::OpGroupNonUniformInverseBallot: return 5u;
+		case Op::OpGroupNonUniformBallotBitExtract: return 6u;
+		case Op::OpGroupNonUniformBallotBitCount: return 6u;
+		case Op::OpGroupNonUniformBallotFindLSB: return 5u;
+		case Op::OpGroupNonUniformBallotFindMSB: return 5u;
+		case Op::OpGroupNonUniformShuffle: return 6u;
+		case Op::OpGroupNonUniformShuffleXor: return 6u;
+		case Op::OpGroupNonUniformShuffleUp: return 6u;
+		case Op::OpGroupNonUniformShuffleDown: return 6u;
+		case Op::OpGroupNonUniformIAdd: return 6u;
+		case Op::OpGroupNonUniformFAdd: return 6u;
+		case Op::OpGroupNonUniformIMul: return 6u;
+		case Op::OpGroupNonUniformFMul: return 6u;
+		case Op::OpGroupNonUniformSMin: return 6u;
+		case Op::OpGroupNonUniformUMin: return 6u;
+		case Op::OpGroupNonUniformFMin: return 6u;
+		case Op::OpGroupNonUniformSMax: return 6u;
+		case Op::OpGroupNonUniformUMax: return 6u;
+		case Op::OpGroupNonUniformFMax: return 6u;
+		case Op::OpGroupNonUniformBitwiseAnd: return 6u;
+		case Op::OpGroupNonUniformBitwiseOr: return 6u;
+		case Op::OpGroupNonUniformBitwiseXor: return 6u;
+		case Op::OpGroupNonUniformLogicalAnd: return 6u;
+		case Op::OpGroupNonUniformLogicalOr: return 6u;
+		case Op::OpGroupNonUniformLogicalXor: return 6u;
+		case Op::OpGroupNonUniformQuadBroadcast: return 6u;
+		case Op::OpGroupNonUniformQuadSwap: return 6u;
+		case Op::OpCopyLogical: return 4u;
+		case Op::OpPtrEqual: return 5u;
+		case Op::OpPtrNotEqual: return 5u;
+		case Op::OpPtrDiff: return 5u;
+		case Op::OpColorAttachmentReadEXT: return 4u;
+		case Op::OpDepthAttachmentReadEXT: return 3u;
+		case Op::OpStencilAttachmentReadEXT: return 3u;
+		case Op::OpTerminateInvocation: return 1u;
+		case Op::OpSubgroupBallotKHR: return 4u;
+		case Op::OpSubgroupFirstInvocationKHR: return 4u;
+		case Op::OpSubgroupAllKHR: return 4u;
+		case Op::OpSubgroupAnyKHR: return 4u;
+		case Op::OpSubgroupAllEqualKHR: return 4u;
+		case Op::OpGroupNonUniformRotateKHR: return 6u;
+		case Op::OpSubgroupReadInvocationKHR: return 5u;
+		case Op::OpTraceRayKHR: return 12u;
+		case Op::OpExecuteCallableKHR: return 3u;
+		case Op::OpConvertUToAccelerationStructureKHR: return 4u;
+		case Op::OpIgnoreIntersectionKHR: return 1u;
+		case Op::OpTerminateRayKHR: return 1u;
+		case Op::OpSDot: return 5u;
+		case Op::OpUDot: return 5u;
+		case Op::OpSUDot: return 5u;
+		case Op::OpSDotAccSat: return 6u;
+		case Op::OpUDotAccSat: return 6u;
+		case Op::OpSUDotAccSat: return 6u;
+		case Op::OpTypeCooperativeMatrixKHR: return 7u;
+		case Op::OpCooperativeMatrixLoadKHR: return 5u;
+		case Op::OpCooperativeMatrixStoreKHR: return 4u;
+		case Op::OpCooperativeMatrixMulAddKHR: return 6u;
+		case Op::OpCooperativeMatrixLengthKHR: return 4u;
+		case Op::OpTypeRayQueryKHR: return 2u;
+		case Op::OpRayQueryInitializeKHR: return 9u;
+		case Op::OpRayQueryTerminateKHR: return 2u;
+		case Op::OpRayQueryGenerateIntersectionKHR: return 3u;
+		case Op::OpRayQueryConfirmIntersectionKHR: return 2u;
+		case Op::OpRayQueryProceedKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionTypeKHR: return 5u;
+		case Op::OpImageSampleWeightedQCOM: return 6u;
+		case Op::OpImageBoxFilterQCOM: return 6u;
+		case Op::OpImageBlockMatchSSDQCOM: return 8u;
+		case Op::OpImageBlockMatchSADQCOM: return 8u;
+		case Op::OpGroupIAddNonUniformAMD: return 6u;
+		case Op::OpGroupFAddNonUniformAMD: return 6u;
+		case Op::OpGroupFMinNonUniformAMD: return 6u;
+		case Op::OpGroupUMinNonUniformAMD: return 6u;
+		case Op::OpGroupSMinNonUniformAMD: return 6u;
+		case Op::OpGroupFMaxNonUniformAMD: return 6u;
+		case Op::OpGroupUMaxNonUniformAMD: return 6u;
+		case Op::OpGroupSMaxNonUniformAMD: return 6u;
+		case Op::OpFragmentMaskFetchAMD: return 5u;
+		case Op::OpFragmentFetchAMD: return 6u;
+		case Op::OpReadClockKHR: return 4u;
+		case Op::OpFinalizeNodePayloadsAMDX: return 2u;
+		case Op::OpFinishWritingNodePayloadAMDX: return 4u;
+		case Op::OpInitializeNodePayloadsAMDX: return 5u;
+		case Op::OpHitObjectRecordHitMotionNV: return 15u;
+		case Op::OpHitObjectRecordHitWithIndexMotionNV: return 14u;
+		case Op::OpHitObjectRecordMissMotionNV: return 8u;
+		case Op::OpHitObjectGetWorldToObjectNV: return 4u;
+		case Op::OpHitObjectGetObjectToWorldNV: return 4u;
+		case Op::OpHitObjectGetObjectRayDirectionNV: return 4u;
+		case Op::OpHitObjectGetObjectRayOriginNV: return 4u;
+		case Op::OpHitObjectTraceRayMotionNV: return 14u;
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV: return 4u;
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return 4u;
+		case Op::OpHitObjectRecordEmptyNV: return 2u;
+		case Op::OpHitObjectTraceRayNV: return 13u;
+		case Op::OpHitObjectRecordHitNV: return 14u;
+		case Op::OpHitObjectRecordHitWithIndexNV: return 13u;
+		case Op::OpHitObjectRecordMissNV: return 7u;
+		case Op::OpHitObjectExecuteShaderNV: return 3u;
+		case Op::OpHitObjectGetCurrentTimeNV: return 4u;
+		case Op::OpHitObjectGetAttributesNV: return 3u;
+		case Op::OpHitObjectGetHitKindNV: return 4u;
+		case Op::OpHitObjectGetPrimitiveIndexNV: return 4u;
+		case Op::OpHitObjectGetGeometryIndexNV: return 4u;
+		case Op::OpHitObjectGetInstanceIdNV: return 4u;
+		case Op::OpHitObjectGetInstanceCustomIndexNV: return 4u;
+		case Op::OpHitObjectGetWorldRayDirectionNV: return 4u;
+		case Op::OpHitObjectGetWorldRayOriginNV: return 4u;
+		case Op::OpHitObjectGetRayTMaxNV: return 4u;
+		case Op::OpHitObjectGetRayTMinNV: return 4u;
+		case Op::OpHitObjectIsEmptyNV: return 4u;
+		case Op::OpHitObjectIsHitNV: return 4u;
+		case Op::OpHitObjectIsMissNV: return 4u;
+		case Op::OpReorderThreadWithHitObjectNV: return 2u;
+		case Op::OpReorderThreadWithHintNV: return 3u;
+		case Op::OpTypeHitObjectNV: return 2u;
+		case Op::OpImageSampleFootprintNV: return 7u;
+		case Op::OpEmitMeshTasksEXT: return 4u;
+		case Op::OpSetMeshOutputsEXT: return 3u;
+		case Op::OpGroupNonUniformPartitionNV: return 4u;
+		case Op::OpWritePackedPrimitiveIndices4x8NV: return 3u;
+		case Op::OpReportIntersectionNV: return 5u;
+		case Op::OpIgnoreIntersectionNV: return 1u;
+		case Op::OpTerminateRayNV: return 1u;
+		case Op::OpTraceNV: return 12u;
+		case Op::OpTraceMotionNV: return 13u;
+		case Op::OpTraceRayMotionNV: return 13u;
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return 5u;
+		case Op::OpTypeAccelerationStructureNV: return 2u;
+		case Op::OpExecuteCallableNV: return 3u;
+		case Op::OpTypeCooperativeMatrixNV: return 6u;
+		case Op::OpCooperativeMatrixLoadNV: return 6u;
+		case Op::OpCooperativeMatrixStoreNV: return 5u;
+		case Op::OpCooperativeMatrixMulAddNV: return 6u;
+		case Op::OpCooperativeMatrixLengthNV: return 4u;
+		case Op::OpBeginInvocationInterlockEXT: return 1u;
+		case Op::OpEndInvocationInterlockEXT: return 1u;
+		case Op::OpDemoteToHelperInvocation: return 1u;
+		case Op::OpIsHelperInvocationEXT: return 3u;
+		case Op::OpConvertUToImageNV: return 4u;
+		case Op::OpConvertUToSamplerNV: return 4u;
+		case Op::OpConvertImageToUNV: return 4u;
+		case Op::OpConvertSamplerToUNV: return 4u;
+		case Op::OpConvertUToSampledImageNV: return 4u;
+		case Op::OpConvertSampledImageToUNV: return 4u;
+		case Op::OpSamplerImageAddressingModeNV: return 2u;
+		case Op::OpSubgroupShuffleINTEL: return 5u;
+		case Op::OpSubgroupShuffleDownINTEL: return 6u;
+		case Op::OpSubgroupShuffleUpINTEL: return 6u;
+		case Op::OpSubgroupShuffleXorINTEL: return 5u;
+		case Op::OpSubgroupBlockReadINTEL: return 4u;
+		case Op::OpSubgroupBlockWriteINTEL: return 3u;
+		case Op::OpSubgroupImageBlockReadINTEL: return 5u;
+		case Op::OpSubgroupImageBlockWriteINTEL: return 4u;
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return 7u;
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return 6u;
+		case Op::OpUCountLeadingZerosINTEL: return 4u;
+		case Op::OpUCountTrailingZerosINTEL: return 4u;
+		case Op::OpAbsISubINTEL: return 5u;
+		case Op::OpAbsUSubINTEL: return 5u;
+		case Op::OpIAddSatINTEL: return 5u;
+		case Op::OpUAddSatINTEL: return 5u;
+		case Op::OpIAverageINTEL: return 5u;
+		case Op::OpUAverageINTEL: return 5u;
+		case Op::OpIAverageRoundedINTEL: return 5u;
+		case Op::OpUAverageRoundedINTEL: return 5u;
+		case Op::OpISubSatINTEL: return 5u;
+		case Op::OpUSubSatINTEL: return 5u;
+		case Op::OpIMul32x16INTEL: return 5u;
+		case Op::OpUMul32x16INTEL: return 5u;
+		case Op::OpConstantFunctionPointerINTEL: return 4u;
+		case Op::OpFunctionPointerCallINTEL: return 3u;
+		case Op::OpAsmTargetINTEL: return 4u;
+		case Op::OpAsmINTEL: return 7u;
+		case Op::OpAsmCallINTEL: return 4u;
+		case Op::OpAtomicFMinEXT: return 7u;
+		case Op::OpAtomicFMaxEXT: return 7u;
+		case Op::OpAssumeTrueKHR: return 2u;
+		case Op::OpExpectKHR: return 5u;
+		case Op::OpDecorateString: return 3u;
+		case Op::OpMemberDecorateString: return 4u;
+		case Op::OpVmeImageINTEL: return 5u;
+		case Op::OpTypeVmeImageINTEL: return 3u;
+		case Op::OpTypeAvcImePayloadINTEL: return 2u;
+		case Op::OpTypeAvcRefPayloadINTEL: return 2u;
+		case Op::OpTypeAvcSicPayloadINTEL: return 2u;
+		case Op::OpTypeAvcMcePayloadINTEL: return 2u;
+		case Op::OpTypeAvcMceResultINTEL: return 2u;
+		case Op::OpTypeAvcImeResultINTEL: return 2u;
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return 2u;
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return 2u;
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return 2u;
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return 2u;
+		case Op::OpTypeAvcRefResultINTEL: return 2u;
+		case Op::OpTypeAvcSicResultINTEL: return 2u;
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return 7u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return 3u;
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return 4u;
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return 6u;
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return 6u;
+		case Op::OpSubgroupAvcImeInitializeINTEL: return 6u;
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return 5u;
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return 7u;
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return 5u;
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return 4u;
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return 5u;
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return 5u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return 8u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return 6u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return 8u;
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return 4u;
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return 4u;
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcFmeInitializeINTEL: return 10u;
+		case Op::OpSubgroupAvcBmeInitializeINTEL: return 11u;
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return 4u;
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return 4u;
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return 7u;
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcSicInitializeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL: return 9u;
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return 11u;
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return 14u;
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return 5u;
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return 7u;
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return 4u;
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return 5u;
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return 5u;
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return 7u;
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return 4u;
+		case Op::OpVariableLengthArrayINTEL: return 4u;
+		case Op::OpSaveMemoryINTEL: return 3u;
+		case Op::OpRestoreMemoryINTEL: return 2u;
+		case Op::OpArbitraryFloatSinCosPiINTEL: return 10u;
+		case Op::OpArbitraryFloatCastINTEL: return 9u;
+		case Op::OpArbitraryFloatCastFromIntINTEL: return 9u;
+		case Op::OpArbitraryFloatCastToIntINTEL: return 8u;
+		case Op::OpArbitraryFloatAddINTEL: return 11u;
+		case Op::OpArbitraryFloatSubINTEL: return 11u;
+		case Op::OpArbitraryFloatMulINTEL: return 11u;
+		case Op::OpArbitraryFloatDivINTEL: return 11u;
+		case Op::OpArbitraryFloatGTINTEL: return 7u;
+		case Op::OpArbitraryFloatGEINTEL: return 7u;
+		case Op::OpArbitraryFloatLTINTEL: return 7u;
+		case Op::OpArbitraryFloatLEINTEL: return 7u;
+		case Op::OpArbitraryFloatEQINTEL: return 7u;
+		case Op::OpArbitraryFloatRecipINTEL: return 9u;
+		case Op::OpArbitraryFloatRSqrtINTEL: return 9u;
+		case Op::OpArbitraryFloatCbrtINTEL: return 9u;
+		case Op::OpArbitraryFloatHypotINTEL: return 11u;
+		case Op::OpArbitraryFloatSqrtINTEL: return 9u;
+		case Op::OpArbitraryFloatLogINTEL: return 9u;
+		case Op::OpArbitraryFloatLog2INTEL: return 9u;
+		case Op::OpArbitraryFloatLog10INTEL: return 9u;
+		case Op::OpArbitraryFloatLog1pINTEL: return 9u;
+		case Op::OpArbitraryFloatExpINTEL: return 9u;
+		case Op::OpArbitraryFloatExp2INTEL: return 9u;
+		case Op::OpArbitraryFloatExp10INTEL: return 9u;
+		case Op::OpArbitraryFloatExpm1INTEL: return 9u;
+		case Op::OpArbitraryFloatSinINTEL: return 9u;
+		case Op::OpArbitraryFloatCosINTEL: return 9u;
+		case Op::OpArbitraryFloatSinCosINTEL: return 9u;
+		case Op::OpArbitraryFloatSinPiINTEL: return 9u;
+		case Op::OpArbitraryFloatCosPiINTEL: return 9u;
+		case Op::OpArbitraryFloatASinINTEL: return 9u;
+		case Op::OpArbitraryFloatASinPiINTEL: return 9u;
+		case Op::OpArbitraryFloatACosINTEL: return 9u;
+		case Op::OpArbitraryFloatACosPiINTEL: return 9u;
+		case Op::OpArbitraryFloatATanINTEL: return 9u;
+		case Op::OpArbitraryFloatATanPiINTEL: return 9u;
+		case Op::OpArbitraryFloatATan2INTEL: return 11u;
+		case Op::OpArbitraryFloatPowINTEL: return 11u;
+		case Op::OpArbitraryFloatPowRINTEL: return 11u;
+		case Op::OpArbitraryFloatPowNINTEL: return 10u;
+		case Op::OpLoopControlINTEL: return 1u;
+		case Op::OpAliasDomainDeclINTEL: return 2u;
+		case Op::OpAliasScopeDeclINTEL: return 3u;
+		case Op::OpAliasScopeListDeclINTEL: return 2u;
+		case Op::OpFixedSqrtINTEL: return 10u;
+		case Op::OpFixedRecipINTEL: return 10u;
+		case Op::OpFixedRsqrtINTEL: return 10u;
+		case Op::OpFixedSinINTEL: return 10u;
+		case Op::OpFixedCosINTEL: return 10u;
+		case Op::OpFixedSinCosINTEL: return 10u;
+		case Op::OpFixedSinPiINTEL: return 10u;
+		case Op::OpFixedCosPiINTEL: return 10u;
+		case Op::OpFixedSinCosPiINTEL: return 10u;
+		case Op::OpFixedLogINTEL: return 10u;
+		case Op::OpFixedExpINTEL: return 10u;
+		case Op::OpPtrCastToCrossWorkgroupINTEL: return 4u;
+		case Op::OpCrossWorkgroupCastToPtrINTEL: return 4u;
+		case Op::OpReadPipeBlockingINTEL: return 5u;
+		case Op::OpWritePipeBlockingINTEL: return 5u;
+		case Op::OpFPGARegINTEL: return 5u;
+		case Op::OpRayQueryGetRayTMinKHR: return 4u;
+		case Op::OpRayQueryGetRayFlagsKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionTKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return 5u;
+		case Op::OpRayQueryGetWorldRayDirectionKHR: return 4u;
+		case Op::OpRayQueryGetWorldRayOriginKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return 5u;
+		case Op::OpAtomicFAddEXT: return 7u;
+		case Op::OpTypeBufferSurfaceINTEL: return 3u;
+		case Op::OpTypeStructContinuedINTEL: return 1u;
+		case Op::OpConstantCompositeContinuedINTEL: return 1u;
+		case Op::OpSpecConstantCompositeContinuedINTEL: return 1u;
+		case Op::OpConvertFToBF16INTEL: return 4u;
+		case Op::OpConvertBF16ToFINTEL: return 4u;
+		case Op::OpControlBarrierArriveINTEL: return 4u;
+		case Op::OpControlBarrierWaitINTEL: return 4u;
+		case Op::OpGroupIMulKHR: return 6u;
+		case Op::OpGroupFMulKHR: return 6u;
+		case Op::OpGroupBitwiseAndKHR: return 6u;
+		case Op::OpGroupBitwiseOrKHR: return 6u;
+		case Op::OpGroupBitwiseXorKHR: return 6u;
+		case Op::OpGroupLogicalAndKHR: return 6u;
+		case Op::OpGroupLogicalOrKHR: return 6u;
+		case Op::OpGroupLogicalXorKHR: return 6u;
+		}
+	}
+	inline constexpr unsigned int MaxWordCount(Op opcode) {
+		switch (opcode) {
+		default: return UnboundedWordCount; // unknown opcode
+		case Op::OpNop: return 1u;
+		case Op::OpUndef: return 3u;
+		case Op::OpSourceContinued: return UnboundedWordCount;
+		case Op::OpSource: return UnboundedWordCount;
+		case Op::OpSourceExtension: return UnboundedWordCount;
+		case Op::OpName: return UnboundedWordCount;
+		case Op::OpMemberName: return UnboundedWordCount;
+		case Op::OpString: return UnboundedWordCount;
+		case Op::OpLine: return 4u;
+		case Op::OpExtension: return UnboundedWordCount;
+		case Op::OpExtInstImport: return UnboundedWordCount;
+		case Op::OpExtInst: return UnboundedWordCount;
+		case Op::OpMemoryModel: return 3u;
+		case Op::OpEntryPoint: return UnboundedWordCount;
+		case Op::OpExecutionMode: return 6u;
+		case Op::OpCapability: return 2u;
+		case Op::OpTypeVoid: return 2u;
+		case Op::OpTypeBool: return 2u;
+		case Op::OpTypeInt: return 4u;
+		case Op::OpTypeFloat: return 3u;
+		case Op::OpTypeVector: return 4u;
+		case Op::OpTypeMatrix: return 4u;
+		case Op::OpTypeImage: return 10u;
+		case Op::OpTypeSampler: return 2u;
+		case Op::OpTypeSampledImage: return 3u;
+		case Op::OpTypeArray: return 4u;
+		case Op::OpTypeRuntimeArray: return 3u;
+		case Op::OpTypeStruct: return UnboundedWordCount;
+		case Op::OpTypeOpaque: return UnboundedWordCount;
+		case Op::OpTypePointer: return 4u;
+		case Op::OpTypeFunction: return UnboundedWordCount;
+		case Op::OpTypeEvent: return 2u;
+		case Op::OpTypeDeviceEvent: return 2u;
+		case Op::OpTypeReserveId: return 2u;
+		case Op::OpTypeQueue: return 2u;
+		case Op::OpTypePipe: return 3u;
+		case Op::OpTypeForwardPointer: return 3u;
+		case Op::OpConstantTrue: return 3u;
+		case Op::OpConstantFalse: return 3u;
+		case Op::OpConstant: return UnboundedWordCount;
+		case Op::OpConstantComposite: return UnboundedWordCount;
+		case Op::OpConstantSampler: return 6u;
+		case Op::OpConstantNull: return 3u;
+		case Op::OpSpecConstantTrue: return 3u;
+		case Op::OpSpecConstantFalse: return 3u;
+		case Op::OpSpecConstant: return UnboundedWordCount;
+		case Op::OpSpecConstantComposite: return UnboundedWordCount;
+		case Op::OpSpecConstantOp: return 4u;
+		case Op::OpFunction: return 5u;
+		case Op::OpFunctionParameter: return 3u;
+		case Op::OpFunctionEnd: return 1u;
+		case Op::OpFunctionCall: return UnboundedWordCount;
+		case Op::OpVariable: return 5u;
+		case Op::OpImageTexelPointer: return 6u;
+		case Op::OpLoad: return 10u;
+		case Op::OpStore: return 9u;
+		case Op::OpCopyMemory: return 15u;
+		case Op::OpCopyMemorySized: return 16u;
+		case Op::OpAccessChain: return UnboundedWordCount;
+		case Op::OpInBoundsAccessChain: return UnboundedWordCount;
+		case Op::OpPtrAccessChain: return UnboundedWordCount;
+		case Op::OpArrayLength: return 5u;
+		case Op::OpGenericPtrMemSemantics: return 4u;
+		case Op::OpInBoundsPtrAccessChain: return UnboundedWordCount;
+		case Op::OpDecorate: return UnboundedWordCount;
+		case Op::OpMemberDecorate: return UnboundedWordCount;
+		case Op::OpDecorationGroup: return 2u;
+		case Op::OpGroupDecorate: return UnboundedWordCount;
+		case Op::OpGroupMemberDecorate: return UnboundedWordCount;
+		case Op::OpVectorExtractDynamic: return 5u;
+		case Op::OpVectorInsertDynamic: return 6u;
+		case Op::OpVectorShuffle: return UnboundedWordCount;
+		case Op::OpCompositeConstruct: return UnboundedWordCount;
+		case Op::OpCompositeExtract: return UnboundedWordCount;
+		case Op::OpCompositeInsert: return UnboundedWordCount;
+		case Op::OpCopyObject: return 4u;
+		case Op::OpTranspose: return 4u;
+		case Op::OpSampledImage: return 5u;
+		case Op::OpImageSampleImplicitLod: return 18u;
+		case Op::OpImageSampleExplicitLod: return 18u;
+		case Op::OpImageSampleDrefImplicitLod: return 19u;
+		case Op::OpImageSampleDrefExplicitLod: return 19u;
+		case Op::OpImageSampleProjImplicitLod: return 18u;
+		case Op::OpImageSampleProjExplicitLod: return 18u;
+		case Op::OpImageSampleProjDrefImplicitLod: return 19u;
+		case Op::OpImageSampleProjDrefExplicitLod: return 19u;
+		case Op::OpImageFetch: return 18u;
+		case Op::OpImageGather: return 19u;
+		case Op::OpImageDrefGather: return 19u;
+		case Op::OpImageRead: return 18u;
+		case Op::OpImageWrite: return 17u;
+		case Op::OpImage: return 4u;
+		case Op::OpImageQueryFormat: return 4u;
+		case Op::OpImageQueryOrder: return 4u;
+		case Op::OpImageQuerySizeLod: return 5u;
+		case Op::OpImageQuerySize: return 4u;
+		case Op::OpImageQueryLod: return 5u;
+		case Op::OpImageQueryLevels: return 4u;
+		case Op::OpImageQuerySamples: return 4u;
+		case Op::OpConvertFToU: return 4u;
+		case Op::OpConvertFToS: return 4u;
+		case Op::OpConvertSToF: return 4u;
+		case Op::OpConvertUToF: return 4u;
+		case Op::OpUConvert: return 4u;
+		case Op::OpSConvert: return 4u;
+		case Op::OpFConvert: return 4u;
+		case Op::OpQuantizeToF16: return 4u;
+		case Op::OpConvertPtrToU: return 4u;
+		case Op::OpSatConvertSToU: return 4u;
+		case Op::OpSatConvertUToS: return 4u;
+		case Op::OpConvertUToPtr: return 4u;
+		case Op::OpPtrCastToGeneric: return 4u;
+		case Op::OpGenericCastToPtr: return 4u;
+		case Op::OpGenericCastToPtrExplicit: return 5u;
+		case Op::OpBitcast: return 4u;
+		case Op::OpSNegate: return 4u;
+		case Op::OpFNegate: return 4u;
+		case Op::OpIAdd: return 5u;
+		case Op::OpFAdd: return 5u;
+		case Op::OpISub: return 5u;
+		case Op::OpFSub: return 5u;
+		case Op::OpIMul: return 5u;
+		case Op::OpFMul: return 5u;
+		case Op::OpUDiv: return 5u;
+		case Op::OpSDiv: return 5u;
+		case Op::OpFDiv: return 5u;
+		case Op::OpUMod: return 5u;
+		case Op::OpSRem: return 5u;
+		case Op::OpSMod: return 5u;
+		case Op::OpFRem: return 5u;
+		case Op::OpFMod: return 5u;
+		case Op::OpVectorTimesScalar: return 5u;
+		case Op::OpMatrixTimesScalar: return 5u;
+		case Op::OpVectorTimesMatrix: return 5u;
+		case Op::OpMatrixTimesVector: return 5u;
+		case Op::OpMatrixTimesMatrix: return 5u;
+		case Op::OpOuterProduct: return 5u;
+		case Op::OpDot: return 5u;
+		case Op::OpIAddCarry: return 5u;
+		case Op::OpISubBorrow: return 5u;
+		case Op::OpUMulExtended: return 5u;
+		case Op::OpSMulExtended: return 5u;
+		case Op::OpAny: return 4u;
+		case Op::OpAll: return 4u;
+		case Op::OpIsNan: return 4u;
+		case Op::OpIsInf: return 4u;
+		case Op::OpIsFinite: return 4u;
+		case Op::OpIsNormal: return 4u;
+		case Op::OpSignBitSet: return 4u;
+		case Op::OpLessOrGreater: return 5u;
+		case Op::OpOrdered: return 5u;
+		case Op::OpUnordered: return 5u;
+		case Op::OpLogicalEqual: return 5u;
+		case Op::OpLogicalNotEqual: return 5u;
+		case Op::OpLogicalOr: return 5u;
+		case Op::OpLogicalAnd: return 5u;
+		case Op::OpLogicalNot: return 4u;
+		case Op::OpSelect: return 6u;
+		case Op::OpIEqual: return 5u;
+		case Op::OpINotEqual: return 5u;
+		case Op::OpUGreaterThan: return 5u;
+		case Op::OpSGreaterThan: return 5u;
+		case Op::OpUGreaterThanEqual: return 5u;
+		case Op::OpSGreaterThanEqual: return 5u;
+		case Op::OpULessThan: return 5u;
+		case Op::OpSLessThan: return 5u;
+		case Op::OpULessThanEqual: return 5u;
+		case Op::OpSLessThanEqual: return 5u;
+		case Op::OpFOrdEqual: return 5u;
+		case Op::OpFUnordEqual: return 5u;
+		case Op::OpFOrdNotEqual: return 5u;
+		case Op::OpFUnordNotEqual: return 5u;
+		case Op::OpFOrdLessThan: return 5u;
+		case Op::OpFUnordLessThan: return 5u;
+		case Op::OpFOrdGreaterThan: return 5u;
+		case Op::OpFUnordGreaterThan: return 5u;
+		case Op::OpFOrdLessThanEqual: return 5u;
+		case Op::OpFUnordLessThanEqual: return 5u;
+		case Op::OpFOrdGreaterThanEqual: return 5u;
+		case Op::OpFUnordGreaterThanEqual: return 5u;
+		case Op::OpShiftRightLogical: return 5u;
+		case Op::OpShiftRightArithmetic: return 5u;
+		case Op::OpShiftLeftLogical: return 5u;
+		case Op::OpBitwiseOr: return 5u;
+		case Op::OpBitwiseXor: return 5u;
+		case Op::OpBitwiseAnd: return 5u;
+		case Op::OpNot: return 4u;
+		case Op::OpBitFieldInsert: return 7u;
+		case Op::OpBitFieldSExtract: return 6u;
+		case Op::OpBitFieldUExtract: return 6u;
+		case Op::OpBitReverse: return 4u;
+		case Op::OpBitCount: return 4u;
+		case Op::OpDPdx: return 4u;
+		case Op::OpDPdy: return 4u;
+		case Op::OpFwidth: return 4u;
+		case Op::OpDPdxFine: return 4u;
+		case Op::OpDPdyFine: return 4u;
+		case Op::OpFwidthFine: return 4u;
+		case Op::OpDPdxCoarse: return 4u;
+		case Op::OpDPdyCoarse: return 4u;
+		case Op::OpFwidthCoarse: return 4u;
+		case Op::OpEmitVertex: return 1u;
+		case Op::OpEndPrimitive: return 1u;
+		case Op::OpEmitStreamVertex: return 2u;
+		case Op::OpEndStreamPrimitive: return 2u;
+		case Op::OpControlBarrier: return 4u;
+		case Op::OpMemoryBarrier: return 3u;
+		case Op::OpAtomicLoad: return 6u;
+		case Op::OpAtomicStore: return 5u;
+		case Op::OpAtomicExchange: return 7u;
+		case Op::OpAtomicCompareExchange: return 9u;
+		case Op::OpAtomicCompareExchangeWeak: return 9u;
+		case Op::OpAtomicIIncrement: return 6u;
+		case Op::OpAtomicIDecrement: return 6u;
+		case Op::OpAtomicIAdd: return 7u;
+		case Op::OpAtomicISub: return 7u;
+		case Op::OpAtomicSMin: return 7u;
+		case Op::OpAtomicUMin: return 7u;
+		case Op::OpAtomicSMax: return 7u;
+		case Op::OpAtomicUMax: return 7u;
+		case Op::OpAtomicAnd: return 7u;
+		case Op::OpAtomicOr: return 7u;
+		case Op::OpAtomicXor: return 7u;
+		case Op::OpPhi: return UnboundedWordCount;
+		case Op::OpLoopMerge: return 19u;
+		case Op::OpSelectionMerge: return 3u;
+		case Op::OpLabel: return 2u;
+		case Op::OpBranch: return 2u;
+		case Op::OpBranchConditional: return UnboundedWordCount;
+		case Op::OpSwitch: return UnboundedWordCount;
+		case Op::OpKill: return 1u;
+		case Op::OpReturn: return 1u;
+		case Op::OpReturnValue: return 2u;
+		case Op::OpUnreachable: return 1u;
+		case Op::OpLifetimeStart: return 3u;
+		case Op::OpLifetimeStop: return 3u;
+		case Op::OpGroupAsyncCopy: return 9u;
+		case Op::OpGroupWaitEvents: return 4u;
+		case Op::OpGroupAll: return 5u;
+		case Op::OpGroupAny: return 5u;
+		case Op::OpGroupBroadcast: return 6u;
+		case Op::OpGroupIAdd: return 6u;
+		case Op::OpGroupFAdd: return 6u;
+		case Op::OpGroupFMin: return 6u;
+		case Op::OpGroupUMin: return 6u;
+		case Op::OpGroupSMin: return 6u;
+		case Op::OpGroupFMax: return 6u;
+		case Op::OpGroupUMax: return 6u;
+		case Op::OpGroupSMax: return 6u;
+		case Op::OpReadPipe: return 7u;
+		case Op::OpWritePipe: return 7u;
+		case Op::OpReservedReadPipe: return 9u;
+		case Op::OpReservedWritePipe: return 9u;
+		case Op::OpReserveReadPipePackets: return 7u;
+		case Op::OpReserveWritePipePackets: return 7u;
+		case Op::OpCommitReadPipe: return 5u;
+		case Op::OpCommitWritePipe: return 5u;
+		case Op::OpIsValidReserveId: return 4u;
+		case Op::OpGetNumPipePackets: return 6u;
+		case Op::OpGetMaxPipePackets: return 6u;
+		case Op::OpGroupReserveReadPipePackets: return 8u;
+		case Op::OpGroupReserveWritePipePackets: return 8u;
+		case Op::OpGroupCommitReadPipe: return 6u;
+		case Op::OpGroupCommitWritePipe: return 6u;
+		case Op::OpEnqueueMarker: return 7u;
+		case Op::OpEnqueueKernel: return UnboundedWordCount;
+		case Op::OpGetKernelNDrangeSubGroupCount: return 8u;
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return 8u;
+		case Op::OpGetKernelWorkGroupSize: return 7u;
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return 7u;
+		case Op::OpRetainEvent: return 2u;
+		case Op::OpReleaseEvent: return 2u;
+		case Op::OpCreateUserEvent: return 3u;
+		case Op::OpIsValidEvent: return 4u;
+		case Op::OpSetUserEventStatus: return 3u;
+		case Op::OpCaptureEventProfilingInfo: return 4u;
+		case Op::OpGetDefaultQueue: return 3u;
+		case Op::OpBuildNDRange: return 6u;
+		case Op::OpImageSparseSampleImplicitLod: return 18u;
+		case Op::OpImageSparseSampleExplicitLod: return 18u;
+		case Op::OpImageSparseSampleDrefImplicitLod: return 19u;
+		case Op::OpImageSparseSampleDrefExplicitLod: return 19u;
+		case Op::OpImageSparseSampleProjImplicitLod: return 18u;
+		case Op::OpImageSparseSampleProjExplicitLod: return 18u;
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return 19u;
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return 19u;
+		case Op::OpImageSparseFetch: return 18u;
+		case Op::OpImageSparseGather: return 19u;
+		case Op::OpImageSparseDrefGather: return 19u;
+		case Op::OpImageSparseTexelsResident: return 4u;
+		case Op::OpNoLine: return 1u;
+		case Op::OpAtomicFlagTestAndSet: return 6u;
+		case Op::OpAtomicFlagClear: return 4u;
+		case Op::OpImageSparseRead: return 18u;
+		case Op::OpSizeOf: return 4u;
+		case Op::OpTypePipeStorage: return 2u;
+		case Op::OpConstantPipeStorage: return 6u;
+		case Op::OpCreatePipeFromPipeStorage: return 4u;
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return 8u;
+		case Op::OpGetKernelMaxNumSubgroups: return 7u;
+		case Op::OpTypeNamedBarrier: return 2u;
+		case Op::OpNamedBarrierInitialize: return 4u;
+		case Op::OpMemoryNamedBarrier: return 4u;
+		case Op::OpModuleProcessed: return UnboundedWordCount;
+		case Op::OpExecutionModeId: return 6u;
+		case Op::OpDecorateId: return UnboundedWordCount;
+		case Op::OpGroupNonUniformElect: return 4u;
+		case Op::OpGroupNonUniformAll: return 5u;
+		case Op::OpGroupNonUniformAny: return 5u;
+		case Op::OpGroupNonUniformAllEqual: return 5u;
+		case Op::OpGroupNonUniformBroadcast: return 6u;
+		case Op::OpGroupNonUniformBroadcastFirst: return 5u;
+		case Op::OpGroupNonUniformBallot: return 5u;
+		case Op::OpGroupNonUniformInverseBallot: return 5u;
+		case Op::OpGroupNonUniformBallotBitExtract: return 6u;
+		case Op::OpGroupNonUniformBallotBitCount: return 6u;
+		case Op::OpGroupNonUniformBallotFindLSB: return 5u;
+		case Op::OpGroupNonUniformBallotFindMSB: return 5u;
+		case Op::OpGroupNonUniformShuffle: return 6u;
+		case Op::OpGroupNonUniformShuffleXor: return 6u;
+		case Op::OpGroupNonUniformShuffleUp: return 6u;
+		case Op::OpGroupNonUniformShuffleDown: return 6u;
+		case Op::OpGroupNonUniformIAdd: return 7u;
+		case Op::OpGroupNonUniformFAdd: return 7u;
+		case Op::OpGroupNonUniformIMul: return 7u;
+		case Op::OpGroupNonUniformFMul: return 7u;
+		case Op::OpGroupNonUniformSMin: return 7u;
+		case Op::OpGroupNonUniformUMin: return 7u;
+		case Op::OpGroupNonUniformFMin: return 7u;
+		case Op::OpGroupNonUniformSMax: return 7u;
+		case Op::OpGroupNonUniformUMax: return 7u;
+		case Op::OpGroupNonUniformFMax: return 7u;
+		case Op::OpGroupNonUniformBitwiseAnd: return 7u;
+		case Op::OpGroupNonUniformBitwiseOr: return 7u;
+		case Op::OpGroupNonUniformBitwiseXor: return 7u;
+		case Op::OpGroupNonUniformLogicalAnd: return 7u;
+		case Op::OpGroupNonUniformLogicalOr: return 7u;
+		case Op::OpGroupNonUniformLogicalXor: return 7u;
+		case Op::OpGroupNonUniformQuadBroadcast: return 6u;
+		case Op::OpGroupNonUniformQuadSwap: return 6u;
+		case Op::OpCopyLogical: return 4u;
+		case Op::OpPtrEqual: return 5u;
+		case Op::OpPtrNotEqual: return 5u;
+		case Op::OpPtrDiff: return 5u;
+		case Op::OpColorAttachmentReadEXT: return 5u;
+		case Op::OpDepthAttachmentReadEXT: return 4u;
+		case Op::OpStencilAttachmentReadEXT: return 4u;
+		case Op::OpTerminateInvocation: return 1u;
+		case Op::OpSubgroupBallotKHR: return 4u;
+		case Op::OpSubgroupFirstInvocationKHR: return 4u;
+		case Op::OpSubgroupAllKHR: return 4u;
+		case Op::OpSubgroupAnyKHR: return 4u;
+		case Op::OpSubgroupAllEqualKHR: return 4u;
+		case Op::OpGroupNonUniformRotateKHR: return 7u;
+		case Op::OpSubgroupReadInvocationKHR: return 5u;
+		case Op::OpTraceRayKHR: return 12u;
+		case Op::OpExecuteCallableKHR: return 3u;
+		case Op::OpConvertUToAccelerationStructureKHR: return 4u;
+		case Op::OpIgnoreIntersectionKHR: return 1u;
+		case Op::OpTerminateRayKHR: return 1u;
+		case Op::OpSDot: return 6u;
+		case Op::OpUDot: return 6u;
+		case Op::OpSUDot: return 6u;
+		case Op::OpSDotAccSat: return 7u;
+		case Op::OpUDotAccSat: return 7u;
+		case Op::OpSUDotAccSat: return 7u;
+		case Op::OpTypeCooperativeMatrixKHR: return 7u;
+		case Op::OpCooperativeMatrixLoadKHR: return 12u;
+		case Op::OpCooperativeMatrixStoreKHR: return 11u;
+		case Op::OpCooperativeMatrixMulAddKHR: return 7u;
+		case Op::OpCooperativeMatrixLengthKHR: return 4u;
+		case Op::OpTypeRayQueryKHR: return 2u;
+		case Op::OpRayQueryInitializeKHR: return 9u;
+		case Op::OpRayQueryTerminateKHR: return 2u;
+		case Op::OpRayQueryGenerateIntersectionKHR: return 3u;
+		case Op::OpRayQueryConfirmIntersectionKHR: return 2u;
+		case Op::OpRayQueryProceedKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionTypeKHR: return 5u;
+		case Op::OpImageSampleWeightedQCOM: return 6u;
+		case Op::OpImageBoxFilterQCOM: return 6u;
+		case Op::OpImageBlockMatchSSDQCOM: return 8u;
+		case Op::OpImageBlockMatchSADQCOM: return 8u;
+		case Op::OpGroupIAddNonUniformAMD: return 6u;
+		case Op::OpGroupFAddNonUniformAMD: return 6u;
+		case Op::OpGroupFMinNonUniformAMD: return 6u;
+		case Op::OpGroupUMinNonUniformAMD: return 6u;
+		case Op::OpGroupSMinNonUniformAMD: return 6u;
+		case Op::OpGroupFMaxNonUniformAMD: return 6u;
+		case Op::OpGroupUMaxNonUniformAMD: return 6u;
+		case Op::OpGroupSMaxNonUniformAMD: return 6u;
+		case Op::OpFragmentMaskFetchAMD: return 5u;
+		case Op::OpFragmentFetchAMD: return 6u;
+		case Op::OpReadClockKHR: return 4u;
+		case Op::OpFinalizeNodePayloadsAMDX: return 2u;
+		case Op::OpFinishWritingNodePayloadAMDX: return 4u;
+		case Op::OpInitializeNodePayloadsAMDX: return 5u;
+		case Op::OpHitObjectRecordHitMotionNV: return 15u;
+		case Op::OpHitObjectRecordHitWithIndexMotionNV: return 14u;
+		case Op::OpHitObjectRecordMissMotionNV: return 8u;
+		case Op::OpHitObjectGetWorldToObjectNV: return 4u;
+		case Op::OpHitObjectGetObjectToWorldNV: return 4u;
+		case Op::OpHitObjectGetObjectRayDirectionNV: return 4u;
+		case Op::OpHitObjectGetObjectRayOriginNV: return 4u;
+		case Op::OpHitObjectTraceRayMotionNV: return 14u;
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV: return 4u;
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return 4u;
+		case Op::OpHitObjectRecordEmptyNV: return 2u;
+		case Op::OpHitObjectTraceRayNV: return 13u;
+		case Op::OpHitObjectRecordHitNV: return 14u;
+		case Op::OpHitObjectRecordHitWithIndexNV: return 13u;
+		case Op::OpHitObjectRecordMissNV: return 7u;
+		case Op::OpHitObjectExecuteShaderNV: return 3u;
+		case Op::OpHitObjectGetCurrentTimeNV: return 4u;
+		case Op::OpHitObjectGetAttributesNV: return 3u;
+		case Op::OpHitObjectGetHitKindNV: return 4u;
+		case Op::OpHitObjectGetPrimitiveIndexNV: return 4u;
+		case Op::OpHitObjectGetGeometryIndexNV: return 4u;
+		case Op::OpHitObjectGetInstanceIdNV: return 4u;
+		case Op::OpHitObjectGetInstanceCustomIndexNV: return 4u;
+		case Op::OpHitObjectGetWorldRayDirectionNV: return 4u;
+		case Op::OpHitObjectGetWorldRayOriginNV: return 4u;
+		case Op::OpHitObjectGetRayTMaxNV: return 4u;
+		case Op::OpHitObjectGetRayTMinNV: return 4u;
+		case Op::OpHitObjectIsEmptyNV: return 4u;
+		case Op::OpHitObjectIsHitNV: return 4u;
+		case Op::OpHitObjectIsMissNV: return 4u;
+		case Op::OpReorderThreadWithHitObjectNV: return 4u;
+		case Op::OpReorderThreadWithHintNV: return 3u;
+		case Op::OpTypeHitObjectNV: return 2u;
+		case Op::OpImageSampleFootprintNV: return 20u;
+		case Op::OpEmitMeshTasksEXT: return 5u;
+		case Op::OpSetMeshOutputsEXT: return 3u;
+		case Op::OpGroupNonUniformPartitionNV: return 4u;
+		case Op::OpWritePackedPrimitiveIndices4x8NV: return 3u;
+		case Op::OpReportIntersectionNV: return 5u;
+		case Op::OpIgnoreIntersectionNV: return 1u;
+		case Op::OpTerminateRayNV: return 1u;
+		case Op::OpTraceNV: return 12u;
+		case Op::OpTraceMotionNV: return 13u;
+		case Op::OpTraceRayMotionNV: return 13u;
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return 5u;
+		case Op::OpTypeAccelerationStructureNV: return 2u;
+		case Op::OpExecuteCallableNV: return 3u;
+		case Op::OpTypeCooperativeMatrixNV: return 6u;
+		case Op::OpCooperativeMatrixLoadNV: return 12u;
+		case Op::OpCooperativeMatrixStoreNV: return 11u;
+		case Op::OpCooperativeMatrixMulAddNV: return 6u;
+		case Op::OpCooperativeMatrixLengthNV: return 4u;
+		case Op::OpBeginInvocationInterlockEXT: return 1u;
+		case Op::OpEndInvocationInterlockEXT: return 1u;
+		case Op::OpDemoteToHelperInvocation: return 1u;
+		case Op::OpIsHelperInvocationEXT: return 3u;
+		case Op::OpConvertUToImageNV: return 4u;
+		case Op::OpConvertUToSamplerNV: return 4u;
+		case Op::OpConvertImageToUNV: return 4u;
+		case Op::OpConvertSamplerToUNV: return 4u;
+		case Op::OpConvertUToSampledImageNV: return 4u;
+		case Op::OpConvertSampledImageToUNV: return 4u;
+		case Op::OpSamplerImageAddressingModeNV: return 2u;
+		case Op::OpSubgroupShuffleINTEL: return 5u;
+		case Op::OpSubgroupShuffleDownINTEL: return 6u;
+		case Op::OpSubgroupShuffleUpINTEL: return 6u;
+		case Op::OpSubgroupShuffleXorINTEL: return 5u;
+		case Op::OpSubgroupBlockReadINTEL: return 4u;
+		case Op::OpSubgroupBlockWriteINTEL: return 3u;
+		case Op::OpSubgroupImageBlockReadINTEL: return 5u;
+		case Op::OpSubgroupImageBlockWriteINTEL: return 4u;
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return 7u;
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return 6u;
+		case Op::OpUCountLeadingZerosINTEL: return 4u;
+		case Op::OpUCountTrailingZerosINTEL: return 4u;
+		case Op::OpAbsISubINTEL: return 5u;
+		case Op::OpAbsUSubINTEL: return 5u;
+		case Op::OpIAddSatINTEL: return 5u;
+		case Op::OpUAddSatINTEL: return 5u;
+		case Op::OpIAverageINTEL: return 5u;
+		case Op::OpUAverageINTEL: return 5u;
+		case Op::OpIAverageRoundedINTEL: return 5u;
+		case Op::OpUAverageRoundedINTEL: return 5u;
+		case Op::OpISubSatINTEL: return 5u;
+		case Op::OpUSubSatINTEL: return 5u;
+		case Op::OpIMul32x16INTEL: return 5u;
+		case Op::OpUMul32x16INTEL: return 5u;
+		case Op::OpConstantFunctionPointerINTEL: return 4u;
+		case Op::OpFunctionPointerCallINTEL: return UnboundedWordCount;
+		case Op::OpAsmTargetINTEL: return UnboundedWordCount;
+		case Op::OpAsmINTEL: return UnboundedWordCount;
+		case Op::OpAsmCallINTEL: return UnboundedWordCount;
+		case Op::OpAtomicFMinEXT: return 7u;
+		case Op::OpAtomicFMaxEXT: return 7u;
+		case Op::OpAssumeTrueKHR: return 2u;
+		case Op::OpExpectKHR: return 5u;
+		case Op::OpDecorateString: return UnboundedWordCount;
+		case Op::OpMemberDecorateString: return UnboundedWordCount;
+		case Op::OpVmeImageINTEL: return 5u;
+		case Op::OpTypeVmeImageINTEL: return 3u;
+		case Op::OpTypeAvcImePayloadINTEL: return 2u;
+		case Op::OpTypeAvcRefPayloadINTEL: return 2u;
+		case Op::OpTypeAvcSicPayloadINTEL: return 2u;
+		case Op::OpTypeAvcMcePayloadINTEL: return 2u;
+		case Op::OpTypeAvcMceResultINTEL: return 2u;
+		case Op::OpTypeAvcImeResultINTEL: return 2u;
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return 2u;
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return 2u;
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return 2u;
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return 2u;
+		case Op::OpTypeAvcRefResultINTEL: return 2u;
+		case Op::OpTypeAvcSicResultINTEL: return 2u;
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return 3u;
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return 7u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return 3u;
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return 3u;
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return 4u;
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return 5u;
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return 6u;
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return 4u;
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return 6u;
+		case Op::OpSubgroupAvcImeInitializeINTEL: return 6u;
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return 5u;
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return 7u;
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return 5u;
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return 4u;
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return 5u;
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return 5u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return 8u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return 6u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return 7u;
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return 8u;
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return 4u;
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return 4u;
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return 6u;
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return 5u;
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return 4u;
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcFmeInitializeINTEL: return 10u;
+		case Op::OpSubgroupAvcBmeInitializeINTEL: return 11u;
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return 4u;
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return 4u;
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return 7u;
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcSicInitializeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL: return 9u;
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return 11u;
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return 14u;
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return 5u;
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return 4u;
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return 7u;
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return 4u;
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return 5u;
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return 5u;
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return 5u;
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return 7u;
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return 6u;
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return 7u;
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return 4u;
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return 4u;
+		case Op::OpVariableLengthArrayINTEL: return 4u;
+		case Op::OpSaveMemoryINTEL: return 3u;
+		case Op::OpRestoreMemoryINTEL: return 2u;
+		case Op::OpArbitraryFloatSinCosPiINTEL: return 10u;
+		case Op::OpArbitraryFloatCastINTEL: return 9u;
+		case Op::OpArbitraryFloatCastFromIntINTEL: return 9u;
+		case Op::OpArbitraryFloatCastToIntINTEL: return 8u;
+		case Op::OpArbitraryFloatAddINTEL: return 11u;
+		case Op::OpArbitraryFloatSubINTEL: return 11u;
+		case Op::OpArbitraryFloatMulINTEL: return 11u;
+		case Op::OpArbitraryFloatDivINTEL: return 11u;
+		case Op::OpArbitraryFloatGTINTEL: return 7u;
+		case Op::OpArbitraryFloatGEINTEL: return 7u;
+		case Op::OpArbitraryFloatLTINTEL: return 7u;
+		case Op::OpArbitraryFloatLEINTEL: return 7u;
+		case Op::OpArbitraryFloatEQINTEL: return 7u;
+		case Op::OpArbitraryFloatRecipINTEL: return 9u;
+		case Op::OpArbitraryFloatRSqrtINTEL: return 9u;
+		case Op::OpArbitraryFloatCbrtINTEL: return 9u;
+		case Op::OpArbitraryFloatHypotINTEL: return 11u;
+		case Op::OpArbitraryFloatSqrtINTEL: return 9u;
+		case Op::OpArbitraryFloatLogINTEL: return 9u;
+		case Op::OpArbitraryFloatLog2INTEL: return 9u;
+		case Op::OpArbitraryFloatLog10INTEL: return 9u;
+		case Op::OpArbitraryFloatLog1pINTEL: return 9u;
+		case Op::OpArbitraryFloatExpINTEL: return 9u;
+		case Op::OpArbitraryFloatExp2INTEL: return 9u;
+		case Op::OpArbitraryFloatExp10INTEL: return 9u;
+		case Op::OpArbitraryFloatExpm1INTEL: return 9u;
+		case Op::OpArbitraryFloatSinINTEL: return 9u;
+		case Op::OpArbitraryFloatCosINTEL: return 9u;
+		case Op::OpArbitraryFloatSinCosINTEL: return 9u;
+		case Op::OpArbitraryFloatSinPiINTEL: return 9u;
+		case Op::OpArbitraryFloatCosPiINTEL: return 9u;
+		case Op::OpArbitraryFloatASinINTEL: return 9u;
+		case Op::OpArbitraryFloatASinPiINTEL: return 9u;
+		case Op::OpArbitraryFloatACosINTEL: return 9u;
+		case Op::OpArbitraryFloatACosPiINTEL: return 9u;
+		case Op::OpArbitraryFloatATanINTEL: return 9u;
+		case Op::OpArbitraryFloatATanPiINTEL: return 9u;
+		case Op::OpArbitraryFloatATan2INTEL: return 11u;
+		case Op::OpArbitraryFloatPowINTEL: return 11u;
+		case Op::OpArbitraryFloatPowRINTEL: return 11u;
+		case Op::OpArbitraryFloatPowNINTEL: return 10u;
+		case Op::OpLoopControlINTEL: return UnboundedWordCount;
+		case Op::OpAliasDomainDeclINTEL: return 3u;
+		case Op::OpAliasScopeDeclINTEL: return 4u;
+		case Op::OpAliasScopeListDeclINTEL: return UnboundedWordCount;
+		case Op::OpFixedSqrtINTEL: return 10u;
+		case Op::OpFixedRecipINTEL: return 10u;
+		case Op::OpFixedRsqrtINTEL: return 10u;
+		case Op::OpFixedSinINTEL: return 10u;
+		case Op::OpFixedCosINTEL: return 10u;
+		case Op::OpFixedSinCosINTEL: return 10u;
+		case Op::OpFixedSinPiINTEL: return 10u;
+		case Op::OpFixedCosPiINTEL: return 10u;
+		case Op::OpFixedSinCosPiINTEL: return 10u;
+		case Op::OpFixedLogINTEL: return 10u;
+		case Op::OpFixedExpINTEL: return 10u;
+		case Op::OpPtrCastToCrossWorkgroupINTEL: return 4u;
+		case Op::OpCrossWorkgroupCastToPtrINTEL: return 4u;
+		case Op::OpReadPipeBlockingINTEL: return 5u;
+		case Op::OpWritePipeBlockingINTEL: return 5u;
+		case Op::OpFPGARegINTEL: return 5u;
+		case Op::OpRayQueryGetRayTMinKHR: return 4u;
+		case Op::OpRayQueryGetRayFlagsKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionTKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return 5u;
+		case Op::OpRayQueryGetWorldRayDirectionKHR: return 4u;
+		case Op::OpRayQueryGetWorldRayOriginKHR: return 4u;
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return 5u;
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return 5u;
+		case Op::OpAtomicFAddEXT: return 7u;
+		case Op::OpTypeBufferSurfaceINTEL: return 3u;
+		case Op::OpTypeStructContinuedINTEL: return UnboundedWordCount;
+		case Op::OpConstantCompositeContinuedINTEL: return UnboundedWordCount;
+		case Op::OpSpecConstantCompositeContinuedINTEL: return UnboundedWordCount;
+		case Op::OpConvertFToBF16INTEL: return 4u;
+		case Op::OpConvertBF16ToFINTEL: return 4u;
+		case Op::OpControlBarrierArriveINTEL: return 4u;
+		case Op::OpControlBarrierWaitINTEL: return 4u;
+		case Op::OpGroupIMulKHR: return 6u;
+		case Op::OpGroupFMulKHR: return 6u;
+		case Op::OpGroupBitwiseAndKHR: return 6u;
+		case Op::OpGroupBitwiseOrKHR: return 6u;
+		case Op::OpGroupBitwiseXorKHR: return 6u;
+		case Op::OpGroupLogicalAndKHR: return 6u;
+		case Op::OpGroupLogicalOrKHR: return 6u;
+		case Op::OpGroupLogicalXorKHR: return 6u;
+		}
+	}
+#ifdef SPVGENTWO_OPERAND_LAYOUT
+	enum class OperandKind : unsigned short
+	{
+		ImageOperands = 0u,
+		FPFastMathMode = 1u,
+		SelectionControl = 2u,
+		LoopControl = 3u,
+		FunctionControl = 4u,
+		MemorySemantics = 5u,
+		MemoryAccess = 6u,
+		KernelProfilingInfo = 7u,
+		RayFlags = 8u,
+		FragmentShadingRate = 9u,
+		SourceLanguage = 10u,
+		ExecutionModel = 11u,
+		AddressingModel = 12u,
+		MemoryModel = 13u,
+		ExecutionMode = 14u,
+		StorageClass = 15u,
+		Dim = 16u,
+		SamplerAddressingMode = 17u,
+		SamplerFilterMode = 18u,
+		ImageFormat = 19u,
+		ImageChannelOrder = 20u,
+		ImageChannelDataType = 21u,
+		FPRoundingMode = 22u,
+		FPDenormMode = 23u,
+		QuantizationModes = 24u,
+		FPOperationMode = 25u,
+		OverflowModes = 26u,
+		LinkageType = 27u,
+		AccessQualifier = 28u,
+		HostAccessQualifier = 29u,
+		FunctionParameterAttribute = 30u,
+		Decoration = 31u,
+		BuiltIn = 32u,
+		Scope = 33u,
+		GroupOperation = 34u,
+		KernelEnqueueFlags = 35u,
+		Capability = 36u,
+		RayQueryIntersection = 37u,
+		RayQueryCommittedIntersectionType = 38u,
+		RayQueryCandidateIntersectionType = 39u,
+		PackedVectorFormat = 40u,
+		CooperativeMatrixOperands = 41u,
+		CooperativeMatrixLayout = 42u,
+		CooperativeMatrixUse = 43u,
+		InitializationModeQualifier = 44u,
+		LoadCacheControl = 45u,
+		StoreCacheControl = 46u,
+		IdResultType = 47u,
+		IdResult = 48u,
+		IdMemorySemantics = 49u,
+		IdScope = 50u,
+		IdRef = 51u,
+		LiteralInteger = 52u,
+		LiteralString = 53u,
+		LiteralFloat = 54u,
+		LiteralContextDependentNumber = 55u,
+		LiteralExtInstInteger = 56u,
+		LiteralSpecConstantOpInteger = 57u,
+		PairLiteralIntegerIdRef = 58u,
+		PairIdRefLiteralInteger = 59u,
+		PairIdRefIdRef = 60u,
+	};
+	enum class OperandCategory : unsigned short
+	{
+		BitEnum,
+		ValueEnum,
+		Id,
+		Literal,
+		Composite,
+	};
+	enum class Quantifier : unsigned short
+	{
+		ZeroOrOne, // zero or one
+		ZeroOrAny, // zero or any
+		One, // exactly once
+	};
+	static constexpr OperandCategory OperandCategories[] =
+	{
+		OperandCategory::BitEnum, // ImageOperands
+		OperandCategory::BitEnum, // FPFastMathMode
+		OperandCategory::BitEnum, // SelectionControl
+		OperandCategory::BitEnum, // LoopControl
+		OperandCategory::BitEnum, // FunctionControl
+		OperandCategory::BitEnum, // MemorySemantics
+		OperandCategory::BitEnum, // MemoryAccess
+		OperandCategory::BitEnum, // KernelProfilingInfo
+		OperandCategory::BitEnum, // RayFlags
+		OperandCategory::BitEnum, // FragmentShadingRate
+		OperandCategory::ValueEnum, // SourceLanguage
+		OperandCategory::ValueEnum, // ExecutionModel
+		OperandCategory::ValueEnum, // AddressingModel
+		OperandCategory::ValueEnum, // MemoryModel
+		OperandCategory::ValueEnum, // ExecutionMode
+		OperandCategory::ValueEnum, // StorageClass
+		OperandCategory::ValueEnum, // Dim
+		OperandCategory::ValueEnum, // SamplerAddressingMode
+		OperandCategory::ValueEnum, // SamplerFilterMode
+		OperandCategory::ValueEnum, // ImageFormat
+		OperandCategory::ValueEnum, // ImageChannelOrder
+		OperandCategory::ValueEnum, // ImageChannelDataType
+		OperandCategory::ValueEnum, // FPRoundingMode
+		OperandCategory::ValueEnum, // FPDenormMode
+		OperandCategory::ValueEnum, // QuantizationModes
+		OperandCategory::ValueEnum, // FPOperationMode
+		OperandCategory::ValueEnum, // OverflowModes
+		OperandCategory::ValueEnum, // LinkageType
+		OperandCategory::ValueEnum, // AccessQualifier
+		OperandCategory::ValueEnum, // HostAccessQualifier
+		OperandCategory::ValueEnum, // FunctionParameterAttribute
+		OperandCategory::ValueEnum, // Decoration
+		OperandCategory::ValueEnum, // BuiltIn
+		OperandCategory::ValueEnum, // Scope
+		OperandCategory::ValueEnum, // GroupOperation
+		OperandCategory::ValueEnum, // KernelEnqueueFlags
+		OperandCategory::ValueEnum, // Capability
+		OperandCategory::ValueEnum, // RayQueryIntersection
+		OperandCategory::ValueEnum, // RayQueryCommittedIntersectionType
+		OperandCategory::ValueEnum, // RayQueryCandidateIntersectionType
+		OperandCategory::ValueEnum, // PackedVectorFormat
+		OperandCategory::BitEnum, // CooperativeMatrixOperands
+		OperandCategory::ValueEnum, // CooperativeMatrixLayout
+		OperandCategory::ValueEnum, // CooperativeMatrixUse
+		OperandCategory::ValueEnum, // InitializationModeQualifier
+		OperandCategory::ValueEnum, // LoadCacheControl
+		OperandCategory::ValueEnum, // StoreCacheControl
+		OperandCategory::Id, // IdResultType
+		OperandCategory::Id, // IdResult
+		OperandCategory::Id, // IdMemorySemantics
+		OperandCategory::Id, // IdScope
+		OperandCategory::Id, // IdRef
+		OperandCategory::Literal, // LiteralInteger
+		OperandCategory::Literal, // LiteralString
+		OperandCategory::Literal, // LiteralFloat
+		OperandCategory::Literal, // LiteralContextDependentNumber
+		OperandCategory::Literal, // LiteralExtInstInteger
+		OperandCategory::Literal, // LiteralSpecConstantOpInteger
+		OperandCategory::Composite, // PairLiteralIntegerIdRef
+		OperandCategory::Composite, // PairIdRefLiteralInteger
+		OperandCategory::Composite, // PairIdRefIdRef
+	};
+	inline constexpr OperandCategory GetOperandCategory(OperandKind kind) { return OperandCategories[static_cast<unsigned short>(kind)]; }
+	enum class WordEncoding : unsigned char
+	{
+		Fixed, // wordCount words, enumerant parameters follow separately
+		String, // null-terminated UTF-8 padded to whole words, at least one word
+		ContextDependent, // width given by the result type of the instruction, at least one word
+		Composite, // expands to its bases in order
+	};
+	struct OperandEncoding
+	{
+		WordEncoding encoding;
+		unsigned short wordCount; // minimum, composites add up their bases
+		bool exact; // wordCount is the exact width, false if a string or context dependent number is involved
+		const OperandKind* bases; // Composite only
+		unsigned short baseCount;
+	};
+	static constexpr OperandKind OperandBases[] =
+	{
+		OperandKind::LiteralInteger,
+		OperandKind::IdRef,
+		OperandKind::IdRef,
+		OperandKind::LiteralInteger,
+		OperandKind::IdRef,
+		OperandKind::IdRef,
+	};
+	static constexpr OperandEncoding OperandEncodings[] =
+	{
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ImageOperands
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FPFastMathMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // SelectionControl
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LoopControl
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FunctionControl
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // MemorySemantics
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // MemoryAccess
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // KernelProfilingInfo
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // RayFlags
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FragmentShadingRate
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // SourceLanguage
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ExecutionModel
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // AddressingModel
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // MemoryModel
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ExecutionMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // StorageClass
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // Dim
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // SamplerAddressingMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // SamplerFilterMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ImageFormat
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ImageChannelOrder
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // ImageChannelDataType
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FPRoundingMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FPDenormMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // QuantizationModes
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FPOperationMode
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // OverflowModes
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LinkageType
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // AccessQualifier
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // HostAccessQualifier
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // FunctionParameterAttribute
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // Decoration
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // BuiltIn
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // Scope
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // GroupOperation
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // KernelEnqueueFlags
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // Capability
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // RayQueryIntersection
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // RayQueryCommittedIntersectionType
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // RayQueryCandidateIntersectionType
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // PackedVectorFormat
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // CooperativeMatrixOperands
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // CooperativeMatrixLayout
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // CooperativeMatrixUse
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // InitializationModeQualifier
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LoadCacheControl
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // StoreCacheControl
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // IdResultType
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // IdResult
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // IdMemorySemantics
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // IdScope
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // IdRef
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LiteralInteger
+		{ WordEncoding::String, 1u, false, nullptr, 0u }, // LiteralString
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LiteralFloat
+		{ WordEncoding::ContextDependent, 1u, false, nullptr, 0u }, // LiteralContextDependentNumber
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LiteralExtInstInteger
+		{ WordEncoding::Fixed, 1u, true, nullptr, 0u }, // LiteralSpecConstantOpInteger
+		{ WordEncoding::Composite, 2u, true, OperandBases + 0, 2u }, // PairLiteralIntegerIdRef
+		{ WordEncoding::Composite, 2u, true, OperandBases + 2, 2u }, // PairIdRefLiteralInteger
+		{ WordEncoding::Composite, 2u, true, OperandBases + 4, 2u }, // PairIdRefIdRef
+	};
+	inline constexpr const OperandEncoding& GetOperandEncoding(OperandKind kind) { return OperandEncodings[static_cast<unsigned short>(kind)]; }
+	struct OperandLayout
+	{
+		OperandKind kind;
+		Quantifier quantifier;
+	};
+	struct OperandLayoutSpan
+	{
+		const OperandLayout* operands;
+		unsigned int count;
+		constexpr const OperandLayout* begin() const { return operands; }
+		constexpr const OperandLayout* end() const { return operands + count; }
+	};
+	static constexpr OperandLayout OperandLayouts[] =
+	{
+		// OpUndef
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSourceContinued
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpSource
+		{ OperandKind::SourceLanguage, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		{ OperandKind::LiteralString, Quantifier::ZeroOrOne },
+		// OpSourceExtension
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpName
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpMemberName
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpString
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpLine
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpExtension
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpExtInstImport
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpExtInst
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralExtInstInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpMemoryModel
+		{ OperandKind::AddressingModel, Quantifier::One },
+		{ OperandKind::MemoryModel, Quantifier::One },
+		// OpEntryPoint
+		{ OperandKind::ExecutionModel, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpExecutionMode
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ExecutionMode, Quantifier::One },
+		// OpCapability
+		{ OperandKind::Capability, Quantifier::One },
+		// OpTypeVoid
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeBool
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeInt
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpTypeFloat
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpTypeVector
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpTypeMatrix
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpTypeImage
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::Dim, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::ImageFormat, Quantifier::One },
+		{ OperandKind::AccessQualifier, Quantifier::ZeroOrOne },
+		// OpTypeSampler
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeSampledImage
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeArray
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeRuntimeArray
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeStruct
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpTypeOpaque
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpTypePointer
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::StorageClass, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeFunction
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpTypeEvent
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeDeviceEvent
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeReserveId
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeQueue
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypePipe
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::AccessQualifier, Quantifier::One },
+		// OpTypeForwardPointer
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::StorageClass, Quantifier::One },
+		// OpConstantTrue
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpConstantFalse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpConstant
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralContextDependentNumber, Quantifier::One },
+		// OpConstantComposite
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpConstantSampler
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::SamplerAddressingMode, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::SamplerFilterMode, Quantifier::One },
+		// OpConstantNull
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSpecConstantTrue
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSpecConstantFalse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSpecConstant
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralContextDependentNumber, Quantifier::One },
+		// OpSpecConstantComposite
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpSpecConstantOp
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralSpecConstantOpInteger, Quantifier::One },
+		// OpFunction
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::FunctionControl, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFunctionParameter
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpFunctionCall
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpVariable
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::StorageClass, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpImageTexelPointer
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLoad
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpStore
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCopyMemory
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCopyMemorySized
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpAccessChain
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpInBoundsAccessChain
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpPtrAccessChain
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpArrayLength
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpGenericPtrMemSemantics
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpInBoundsPtrAccessChain
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpDecorate
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::Decoration, Quantifier::One },
+		// OpMemberDecorate
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::Decoration, Quantifier::One },
+		// OpDecorationGroup
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpGroupDecorate
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpGroupMemberDecorate
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PairIdRefLiteralInteger, Quantifier::ZeroOrAny },
+		// OpVectorExtractDynamic
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpVectorInsertDynamic
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpVectorShuffle
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::ZeroOrAny },
+		// OpCompositeConstruct
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpCompositeExtract
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::ZeroOrAny },
+		// OpCompositeInsert
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::ZeroOrAny },
+		// OpCopyObject
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTranspose
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSampledImage
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageSampleImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSampleExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSampleDrefImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSampleDrefExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSampleProjImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSampleProjExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSampleProjDrefImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSampleProjDrefExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageFetch
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageGather
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageDrefGather
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageRead
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageWrite
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImage
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQueryFormat
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQueryOrder
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQuerySizeLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQuerySize
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQueryLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQueryLevels
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageQuerySamples
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertFToU
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertFToS
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertSToF
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertUToF
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUConvert
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSConvert
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFConvert
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpQuantizeToF16
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertPtrToU
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSatConvertSToU
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSatConvertUToS
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertUToPtr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpPtrCastToGeneric
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGenericCastToPtr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGenericCastToPtrExplicit
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::StorageClass, Quantifier::One },
+		// OpBitcast
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSNegate
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFNegate
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpISub
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFSub
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIMul
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFMul
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUDiv
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSDiv
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFDiv
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUMod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSRem
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSMod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFRem
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFMod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpVectorTimesScalar
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpMatrixTimesScalar
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpVectorTimesMatrix
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpMatrixTimesVector
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpMatrixTimesMatrix
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpOuterProduct
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIAddCarry
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpISubBorrow
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUMulExtended
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSMulExtended
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAny
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAll
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsNan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsInf
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsFinite
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsNormal
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSignBitSet
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLessOrGreater
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpOrdered
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUnordered
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLogicalEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLogicalNotEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLogicalOr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLogicalAnd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLogicalNot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSelect
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpINotEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUGreaterThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSGreaterThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUGreaterThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSGreaterThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpULessThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSLessThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpULessThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSLessThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdNotEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordNotEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdLessThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordLessThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdGreaterThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordGreaterThan
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdLessThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordLessThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFOrdGreaterThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFUnordGreaterThanEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpShiftRightLogical
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpShiftRightArithmetic
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpShiftLeftLogical
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitwiseOr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitwiseXor
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitwiseAnd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpNot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitFieldInsert
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitFieldSExtract
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitFieldUExtract
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitReverse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBitCount
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdx
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdy
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFwidth
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdxFine
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdyFine
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFwidthFine
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdxCoarse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDPdyCoarse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFwidthCoarse
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpEmitStreamVertex
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpEndStreamPrimitive
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpControlBarrier
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpMemoryBarrier
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpAtomicLoad
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpAtomicStore
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicExchange
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicCompareExchange
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicCompareExchangeWeak
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicIIncrement
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpAtomicIDecrement
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpAtomicIAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicISub
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicSMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicUMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicSMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicUMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicAnd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicOr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicXor
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpPhi
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::PairIdRefIdRef, Quantifier::ZeroOrAny },
+		// OpLoopMerge
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LoopControl, Quantifier::One },
+		// OpSelectionMerge
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::SelectionControl, Quantifier::One },
+		// OpLabel
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpBranch
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpBranchConditional
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::ZeroOrAny },
+		// OpSwitch
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PairLiteralIntegerIdRef, Quantifier::ZeroOrAny },
+		// OpReturnValue
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpLifetimeStart
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpLifetimeStop
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpGroupAsyncCopy
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupWaitEvents
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupAll
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupAny
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupBroadcast
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupIAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupUMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupSMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupUMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupSMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReadPipe
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpWritePipe
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReservedReadPipe
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReservedWritePipe
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReserveReadPipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReserveWritePipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCommitReadPipe
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCommitWritePipe
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsValidReserveId
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetNumPipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetMaxPipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupReserveReadPipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupReserveWritePipePackets
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupCommitReadPipe
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupCommitWritePipe
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpEnqueueMarker
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpEnqueueKernel
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpGetKernelNDrangeSubGroupCount
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetKernelNDrangeMaxSubGroupSize
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetKernelWorkGroupSize
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetKernelPreferredWorkGroupSizeMultiple
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRetainEvent
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReleaseEvent
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCreateUserEvent
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpIsValidEvent
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSetUserEventStatus
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCaptureEventProfilingInfo
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetDefaultQueue
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpBuildNDRange
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageSparseSampleImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseSampleExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSparseSampleDrefImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseSampleDrefExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSparseSampleProjImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseSampleProjExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSparseSampleProjDrefImplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseSampleProjDrefExplicitLod
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::One },
+		// OpImageSparseFetch
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseGather
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseDrefGather
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpImageSparseTexelsResident
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicFlagTestAndSet
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpAtomicFlagClear
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpImageSparseRead
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpSizeOf
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypePipeStorage
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpConstantPipeStorage
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpCreatePipeFromPipeStorage
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetKernelLocalSizeForSubgroupCount
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGetKernelMaxNumSubgroups
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeNamedBarrier
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpNamedBarrierInitialize
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpMemoryNamedBarrier
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpModuleProcessed
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpExecutionModeId
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ExecutionMode, Quantifier::One },
+		// OpDecorateId
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::Decoration, Quantifier::One },
+		// OpGroupNonUniformElect
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		// OpGroupNonUniformAll
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformAny
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformAllEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBroadcast
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBroadcastFirst
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBallot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformInverseBallot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBallotBitExtract
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBallotBitCount
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBallotFindLSB
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformBallotFindMSB
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformShuffle
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformShuffleXor
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformShuffleUp
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformShuffleDown
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformIAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformFAdd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformIMul
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformFMul
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformSMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformUMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformFMin
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformSMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformUMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformFMax
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformBitwiseAnd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformBitwiseOr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformBitwiseXor
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformLogicalAnd
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformLogicalOr
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformLogicalXor
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpGroupNonUniformQuadBroadcast
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformQuadSwap
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCopyLogical
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpPtrEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpPtrNotEqual
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpPtrDiff
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpColorAttachmentReadEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpDepthAttachmentReadEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpStencilAttachmentReadEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpSubgroupBallotKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupFirstInvocationKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAllKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAnyKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAllEqualKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformRotateKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpSubgroupReadInvocationKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTraceRayKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpExecuteCallableKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertUToAccelerationStructureKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSDot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpUDot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpSUDot
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpSDotAccSat
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpUDotAccSat
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpSUDotAccSat
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::PackedVectorFormat, Quantifier::ZeroOrOne },
+		// OpTypeCooperativeMatrixKHR
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCooperativeMatrixLoadKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCooperativeMatrixStoreKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCooperativeMatrixMulAddKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::CooperativeMatrixOperands, Quantifier::ZeroOrOne },
+		// OpCooperativeMatrixLengthKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeRayQueryKHR
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpRayQueryInitializeKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryTerminateKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGenerateIntersectionKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryConfirmIntersectionKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryProceedKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionTypeKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageSampleWeightedQCOM
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageBoxFilterQCOM
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageBlockMatchSSDQCOM
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpImageBlockMatchSADQCOM
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupIAddNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFAddNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFMinNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupUMinNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupSMinNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFMaxNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupUMaxNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupSMaxNonUniformAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFragmentMaskFetchAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFragmentFetchAMD
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReadClockKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		// OpFinalizeNodePayloadsAMDX
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFinishWritingNodePayloadAMDX
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpInitializeNodePayloadsAMDX
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordHitMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordHitWithIndexMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordMissMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetWorldToObjectNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetObjectToWorldNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetObjectRayDirectionNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetObjectRayOriginNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectTraceRayMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetShaderRecordBufferHandleNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetShaderBindingTableRecordIndexNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordEmptyNV
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectTraceRayNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordHitNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordHitWithIndexNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectRecordMissNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectExecuteShaderNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetCurrentTimeNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetAttributesNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetHitKindNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetPrimitiveIndexNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetGeometryIndexNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetInstanceIdNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetInstanceCustomIndexNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetWorldRayDirectionNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetWorldRayOriginNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetRayTMaxNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectGetRayTMinNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectIsEmptyNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectIsHitNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpHitObjectIsMissNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReorderThreadWithHitObjectNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpReorderThreadWithHintNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeHitObjectNV
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpImageSampleFootprintNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::ImageOperands, Quantifier::ZeroOrOne },
+		// OpEmitMeshTasksEXT
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpSetMeshOutputsEXT
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupNonUniformPartitionNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpWritePackedPrimitiveIndices4x8NV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReportIntersectionNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTraceNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTraceMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTraceRayMotionNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionTriangleVertexPositionsKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeAccelerationStructureNV
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpExecuteCallableNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeCooperativeMatrixNV
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCooperativeMatrixLoadNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCooperativeMatrixStoreNV
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::MemoryAccess, Quantifier::ZeroOrOne },
+		// OpCooperativeMatrixMulAddNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCooperativeMatrixLengthNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIsHelperInvocationEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpConvertUToImageNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertUToSamplerNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertImageToUNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertSamplerToUNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertUToSampledImageNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertSampledImageToUNV
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSamplerImageAddressingModeNV
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpSubgroupShuffleINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupShuffleDownINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupShuffleUpINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupShuffleXorINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupBlockReadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupBlockWriteINTEL
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupImageBlockReadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupImageBlockWriteINTEL
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupImageMediaBlockReadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupImageMediaBlockWriteINTEL
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUCountLeadingZerosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUCountTrailingZerosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAbsISubINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAbsUSubINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIAddSatINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUAddSatINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIAverageINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUAverageINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIAverageRoundedINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUAverageRoundedINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpISubSatINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUSubSatINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpIMul32x16INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpUMul32x16INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConstantFunctionPointerINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFunctionPointerCallINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpAsmTargetINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpAsmINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		{ OperandKind::LiteralString, Quantifier::One },
+		// OpAsmCallINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpAtomicFMinEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicFMaxEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAssumeTrueKHR
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpExpectKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpDecorateString
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::Decoration, Quantifier::One },
+		// OpMemberDecorateString
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::Decoration, Quantifier::One },
+		// OpVmeImageINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeVmeImageINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeAvcImePayloadINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcRefPayloadINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcSicPayloadINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcMcePayloadINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcMceResultINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcImeResultINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcImeResultSingleReferenceStreamoutINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcImeResultDualReferenceStreamoutINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcImeSingleReferenceStreaminINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcImeDualReferenceStreaminINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcRefResultINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpTypeAvcSicResultINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetInterShapePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetInterDirectionPenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpSubgroupAvcMceSetAcOnlyHaarINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToImePayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToImeResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToRefPayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToRefResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToSicPayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceConvertToSicResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetMotionVectorsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterDistortionsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetBestInterDistortionsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterMajorShapeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterMinorShapeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterDirectionsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterMotionVectorCountINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterReferenceIdsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeInitializeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetSingleReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetDualReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeRefWindowSizeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeAdjustRefOffsetINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeConvertToMcePayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetMaxMotionVectorCountINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeSetWeightedSadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithDualReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeConvertToMceResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetSingleReferenceStreaminINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetDualReferenceStreaminINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeStripDualReferenceStreamoutINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetBorderReachedINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcFmeInitializeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcBmeInitializeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefConvertToMcePayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefSetBidirectionalMixDisableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefSetBilinearFilterEnableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefEvaluateWithDualReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcRefConvertToMceResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicInitializeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicConfigureSkcINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicConfigureIpeLumaINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicConfigureIpeLumaChromaINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetMotionVectorMaskINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicConvertToMcePayloadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetBilinearFilterEnableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicEvaluateIpeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicEvaluateWithDualReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicConvertToMceResultINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetIpeLumaShapeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetPackedIpeLumaModesINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetIpeChromaModeINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSubgroupAvcSicGetInterRawSadsINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpVariableLengthArrayINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpSaveMemoryINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		// OpRestoreMemoryINTEL
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpArbitraryFloatSinCosPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCastINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCastFromIntINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCastToIntINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatAddINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatSubINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatMulINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatDivINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatGTINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatGEINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLTINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLEINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatEQINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatRecipINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatRSqrtINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCbrtINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatHypotINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatSqrtINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLogINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLog2INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLog10INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatLog1pINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatExpINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatExp2INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatExp10INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatExpm1INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatSinINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatSinCosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatSinPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatCosPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatASinINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatASinPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatACosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatACosPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatATanINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatATanPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatATan2INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatPowINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatPowRINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpArbitraryFloatPowNINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpLoopControlINTEL
+		{ OperandKind::LiteralInteger, Quantifier::ZeroOrAny },
+		// OpAliasDomainDeclINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpAliasScopeDeclINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrOne },
+		// OpAliasScopeListDeclINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpFixedSqrtINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedRecipINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedRsqrtINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedSinINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedCosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedSinCosINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedSinPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedCosPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedSinCosPiINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedLogINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpFixedExpINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		{ OperandKind::LiteralInteger, Quantifier::One },
+		// OpPtrCastToCrossWorkgroupINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpCrossWorkgroupCastToPtrINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpReadPipeBlockingINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpWritePipeBlockingINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpFPGARegINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetRayTMinKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetRayFlagsKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionTKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionInstanceCustomIndexKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionInstanceIdKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionGeometryIndexKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionPrimitiveIndexKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionBarycentricsKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionFrontFaceKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionCandidateAABBOpaqueKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionObjectRayDirectionKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionObjectRayOriginKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetWorldRayDirectionKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetWorldRayOriginKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionObjectToWorldKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpRayQueryGetIntersectionWorldToObjectKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpAtomicFAddEXT
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpTypeBufferSurfaceINTEL
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::AccessQualifier, Quantifier::One },
+		// OpTypeStructContinuedINTEL
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpConstantCompositeContinuedINTEL
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpSpecConstantCompositeContinuedINTEL
+		{ OperandKind::IdRef, Quantifier::ZeroOrAny },
+		// OpConvertFToBF16INTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpConvertBF16ToFINTEL
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpControlBarrierArriveINTEL
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpControlBarrierWaitINTEL
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::IdMemorySemantics, Quantifier::One },
+		// OpGroupIMulKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupFMulKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupBitwiseAndKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupBitwiseOrKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupBitwiseXorKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupLogicalAndKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupLogicalOrKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+		// OpGroupLogicalXorKHR
+		{ OperandKind::IdResultType, Quantifier::One },
+		{ OperandKind::IdResult, Quantifier::One },
+		{ OperandKind::IdScope, Quantifier::One },
+		{ OperandKind::GroupOperation, Quantifier::One },
+		{ OperandKind::IdRef, Quantifier::One },
+	};
+	inline constexpr OperandLayoutSpan GetOperandLayout(Op opcode) {
+		switch (opcode) {
+		default: return { OperandLayouts, 0u }; // unknown opcode
+		case Op::OpNop: return { OperandLayouts + 0, 0u };
+		case Op::OpUndef: return { OperandLayouts + 0, 2u };
+		case Op::OpSourceContinued: return { OperandLayouts + 2, 1u };
+		case Op::OpSource: return { OperandLayouts + 3, 4u };
+		case Op::OpSourceExtension: return { OperandLayouts + 7, 1u };
+		case Op::OpName: return { OperandLayouts + 8, 2u };
+		case Op::OpMemberName: return { OperandLayouts + 10, 3u };
+		case Op::OpString: return { OperandLayouts + 13, 2u };
+		case Op::OpLine: return { OperandLayouts + 15, 3u };
+		case Op::OpExtension: return { OperandLayouts + 18, 1u };
+		case Op::OpExtInstImport: return { OperandLayouts + 19, 2u };
+		case Op::OpExtInst: return { OperandLayouts + 21, 5u };
+		case Op::OpMemoryModel: return { OperandLayouts + 26, 2u };
+		case Op::OpEntryPoint: return { OperandLayouts + 28, 4u };
+		case Op::OpExecutionMode: return { OperandLayouts + 32, 2u };
+		case Op::OpCapability: return { OperandLayouts + 34, 1u };
+		case Op::OpTypeVoid: return { OperandLayouts + 35, 1u };
+		case Op::OpTypeBool: return { OperandLayouts + 36, 1u };
+		case Op::OpTypeInt: return { OperandLayouts + 37, 3u };
+		case Op::OpTypeFloat: return { OperandLayouts + 40, 2u };
+		case Op::OpTypeVector: return { OperandLayouts + 42, 3u };
+		case Op::OpTypeMatrix: return { OperandLayouts + 45, 3u };
+		case Op::OpTypeImage: return { OperandLayouts + 48, 9u };
+		case Op::OpTypeSampler: return { OperandLayouts + 57, 1u };
+		case Op::OpTypeSampledImage: return { OperandLayouts + 58, 2u };
+		case Op::OpTypeArray: return { OperandLayouts + 60, 3u };
+		case Op::OpTypeRuntimeArray: return { OperandLayouts + 63, 2u };
+		case Op::OpTypeStruct: return { OperandLayouts + 65, 2u };
+		case Op::OpTypeOpaque: return { OperandLayouts + 67, 2u };
+		case Op::OpTypePointer: return { OperandLayouts + 69, 3u };
+		case Op::OpTypeFunction: return { OperandLayouts + 72, 3u };
+		case Op::OpTypeEvent: return { OperandLayouts + 75, 1u };
+		case Op::OpTypeDeviceEvent: return { OperandLayouts + 76, 1u };
+		case Op::OpTypeReserveId: return { OperandLayouts + 77, 1u };
+		case Op::OpTypeQueue: return { OperandLayouts + 78, 1u };
+		case Op::OpTypePipe: return { OperandLayouts + 79, 2u };
+		case Op::OpTypeForwardPointer: return { OperandLayouts + 81, 2u };
+		case Op::OpConstantTrue: return { OperandLayouts + 83, 2u };
+		case Op::OpConstantFalse: return { OperandLayouts + 85, 2u };
+		case Op::OpConstant: return { OperandLayouts + 87, 3u };
+		case Op::OpConstantComposite: return { OperandLayouts + 90, 3u };
+		case Op::OpConstantSampler: return { OperandLayouts + 93, 5u };
+		case Op::OpConstantNull: return { OperandLayouts + 98, 2u };
+		case Op::OpSpecConstantTrue: return { OperandLayouts + 100, 2u };
+		case Op::OpSpecConstantFalse: return { OperandLayouts + 102, 2u };
+		case Op::OpSpecConstant: return { OperandLayouts + 104, 3u };
+		case Op::OpSpecConstantComposite: return { OperandLayouts + 107, 3u };
+		case Op::OpSpecConstantOp: return { OperandLayouts + 110, 3u };
+		case Op::OpFunction: return { OperandLayouts + 113, 4u };
+		case Op::OpFunctionParameter: return { OperandLayouts + 117, 2u };
+		case Op::OpFunctionEnd: return { OperandLayouts + 119, 0u };
+		case Op::OpFunctionCall: return { OperandLayouts + 119, 4u };
+		case Op::OpVariable: return { OperandLayouts + 123, 4u };
+		case Op::OpImageTexelPointer: return { OperandLayouts + 127, 5u };
+		case Op::OpLoad: return { OperandLayouts + 132, 4u };
+		case Op::OpStore: return { OperandLayouts + 136, 3u };
+		case Op::OpCopyMemory: return { OperandLayouts + 139, 4u };
+		case Op::OpCopyMemorySized: return { OperandLayouts + 143, 5u };
+		case Op::OpAccessChain: return { OperandLayouts + 148, 4u };
+		case Op::OpInBoundsAccessChain: return { OperandLayouts + 152, 4u };
+		case Op::OpPtrAccessChain: return { OperandLayouts + 156, 5u };
+		case Op::OpArrayLength: return { OperandLayouts + 161, 4u };
+		case Op::OpGenericPtrMemSemantics: return { OperandLayouts + 165, 3u };
+		case Op::OpInBoundsPtrAccessChain: return { OperandLayouts + 168, 5u };
+		case Op::OpDecorate: return { OperandLayouts + 173, 2u };
+		case Op::OpMemberDecorate: return { OperandLayouts + 175, 3u };
+		case Op::OpDecorationGroup: return { OperandLayouts + 178, 1u };
+		case Op::OpGroupDecorate: return { OperandLayouts + 179, 2u };
+		case Op::OpGroupMemberDecorate: return { OperandLayouts + 181, 2u };
+		case Op::OpVectorExtractDynamic: return { OperandLayouts + 183, 4u };
+		case Op::OpVectorInsertDynamic: return { OperandLayouts + 187, 5u };
+		case Op::OpVectorShuffle: return { OperandLayouts + 192, 5u };
+		case Op::OpCompositeConstruct: return { OperandLayouts + 197, 3u };
+		case Op::OpCompositeExtract: return { OperandLayouts + 200, 4u };
+		case Op::OpCompositeInsert: return { OperandLayouts + 204, 5u };
+		case Op::OpCopyObject: return { OperandLayouts + 209, 3u };
+		case Op::OpTranspose: return { OperandLayouts + 212, 3u };
+		case Op::OpSampledImage: return { OperandLayouts + 215, 4u };
+		case Op::OpImageSampleImplicitLod: return { OperandLayouts + 219, 5u };
+		case Op::OpImageSampleExplicitLod: return { OperandLayouts + 224, 5u };
+		case Op::OpImageSampleDrefImplicitLod: return { OperandLayouts + 229, 6u };
+		case Op::OpImageSampleDrefExplicitLod: return { OperandLayouts + 235, 6u };
+		case Op::OpImageSampleProjImplicitLod: return { OperandLayouts + 241, 5u };
+		case Op::OpImageSampleProjExplicitLod: return { OperandLayouts + 246, 5u };
+		case Op::OpImageSampleProjDrefImplicitLod: return { OperandLayouts + 251, 6u };
+		case Op::OpImageSampleProjDrefExplicitLod: return { OperandLayouts + 257, 6u };
+		case Op::OpImageFetch: return { OperandLayouts + 263, 5u };
+		case Op::OpImageGather: return { OperandLayouts + 268, 6u };
+		case Op::OpImageDrefGather: return { OperandLayouts + 274, 6u };
+		case Op::OpImageRead: return { OperandLayouts + 280, 5u };
+		case Op::OpImageWrite: return { OperandLayouts + 285, 4u };
+		case Op::OpImage: return { OperandLayouts + 289, 3u };
+		case Op::OpImageQueryFormat: return { OperandLayouts + 292, 3u };
+		case Op::OpImageQueryOrder: return { OperandLayouts + 295, 3u };
+		case Op::OpImageQuerySizeLod: return { OperandLayouts + 298, 4u };
+		case Op::OpImageQuerySize: return { OperandLayouts + 302, 3u };
+		case Op::OpImageQueryLod: return { OperandLayouts + 305, 4u };
+		case Op::OpImageQueryLevels: return { OperandLayouts + 309, 3u };
+		case Op::OpImageQuerySamples: return { OperandLayouts + 312, 3u };
+		case Op::OpConvertFToU: return { OperandLayouts + 315, 3u };
+		case Op::OpConvertFToS: return { OperandLayouts + 318, 3u };
+		case Op::OpConvertSToF: return { OperandLayouts + 321, 3u };
+		case Op::OpConvertUToF: return { OperandLayouts + 324, 3u };
+		case Op::OpUConvert: return { OperandLayouts + 327, 3u };
+		case Op::OpSConvert: return { OperandLayouts + 330, 3u };
+		case Op::OpFConvert: return { OperandLayouts + 333, 3u };
+		case Op::OpQuantizeToF16: return { OperandLayouts + 336, 3u };
+		case Op::OpConvertPtrToU: return { OperandLayouts + 339, 3u };
+		case Op::OpSatConvertSToU: return { OperandLayouts + 342, 3u };
+		case Op::OpSatConvertUToS: return { OperandLayouts + 345, 3u };
+		case Op::OpConvertUToPtr: return { OperandLayouts + 348, 3u };
+		case Op::OpPtrCastToGeneric: return { OperandLayouts + 351, 3u };
+		case Op::OpGenericCastToPtr: return { OperandLayouts + 354, 3u };
+		case Op::OpGenericCastToPtrExplicit: return { OperandLayouts + 357, 4u };
+		case Op::OpBitcast: return { OperandLayouts + 361, 3u };
+		case Op::OpSNegate: return { OperandLayouts + 364, 3u };
+		case Op::OpFNegate: return { OperandLayouts + 367, 3u };
+		case Op::OpIAdd: return { OperandLayouts + 370, 4u };
+		case Op::OpFAdd: return { OperandLayouts + 374, 4u };
+		case Op::OpISub: return { OperandLayouts + 378, 4u };
+		case Op::OpFSub: return { OperandLayouts + 382, 4u };
+		case Op::OpIMul: return { OperandLayouts + 386, 4u };
+		case Op::OpFMul: return { OperandLayouts + 390, 4u };
+		case Op::OpUDiv: return { OperandLayouts + 394, 4u };
+		case Op::OpSDiv: return { OperandLayouts + 398, 4u };
+		case Op::OpFDiv: return { OperandLayouts + 402, 4u };
+		case Op::OpUMod: return { OperandLayouts + 406, 4u };
+		case Op::OpSRem: return { OperandLayouts + 410, 4u };
+		case Op::OpSMod: return { OperandLayouts + 414, 4u };
+		case Op::OpFRem: return { OperandLayouts + 418, 4u };
+		case Op::OpFMod: return { OperandLayouts + 422, 4u };
+		case Op::OpVectorTimesScalar: return { OperandLayouts + 426, 4u };
+		case Op::OpMatrixTimesScalar: return { OperandLayouts + 430, 4u };
+		case Op::OpVectorTimesMatrix: return { OperandLayouts + 434, 4u };
+		case Op::OpMatrixTimesVector: return { OperandLayouts + 438, 4u };
+		case Op::OpMatrixTimesMatrix: return { OperandLayouts + 442, 4u };
+		case Op::OpOuterProduct: return { OperandLayouts + 446, 4u };
+		case Op::OpDot: return { OperandLayouts + 450, 4u };
+		case Op::OpIAddCarry: return { OperandLayouts + 454, 4u };
+		case Op::OpISubBorrow: return { OperandLayouts + 458, 4u };
+		case Op::OpUMulExtended: return { OperandLayouts + 462, 4u };
+		case Op::OpSMulExtended: return { OperandLayouts + 466, 4u };
+		case Op::OpAny: return { OperandLayouts + 470, 3u };
+		case Op::OpAll: return { OperandLayouts + 473, 3u };
+		case Op::OpIsNan: return { OperandLayouts + 476, 3u };
+		case Op::OpIsInf: return { OperandLayouts + 479, 3u };
+		case Op::OpIsFinite: return { OperandLayouts + 482, 3u };
+		case Op::OpIsNormal: return { OperandLayouts + 485, 3u };
+		case Op::OpSignBitSet: return { OperandLayouts + 488, 3u };
+		case Op::OpLessOrGreater: return { OperandLayouts + 491, 4u };
+		case Op::OpOrdered: return { OperandLayouts + 495, 4u };
+		case Op::OpUnordered: return { OperandLayouts + 499, 4u };
+		case Op::OpLogicalEqual: return { OperandLayouts + 503, 4u };
+		case Op::OpLogicalNotEqual: return { OperandLayouts + 507, 4u };
+		case Op::OpLogicalOr: return { OperandLayouts + 511, 4u };
+		case Op::OpLogicalAnd: return { OperandLayouts + 515, 4u };
+		case Op::OpLogicalNot: return { OperandLayouts + 519, 3u };
+		case Op::OpSelect: return { OperandLayouts + 522, 5u };
+		case Op::OpIEqual: return { OperandLayouts + 527, 4u };
+		case Op::OpINotEqual: return { OperandLayouts + 531, 4u };
+		case Op::OpUGreaterThan: return { OperandLayouts + 535, 4u };
+		case Op::OpSGreaterThan: return { OperandLayouts + 539, 4u };
+		case Op::OpUGreaterThanEqual: return { OperandLayouts + 543, 4u };
+		case Op::OpSGreaterThanEqual: return { OperandLayouts + 547, 4u };
+		case Op::OpULessThan: return { OperandLayouts + 551, 4u };
+		case Op::OpSLessThan: return { OperandLayouts + 555, 4u };
+		case Op::OpULessThanEqual: return { OperandLayouts + 559, 4u };
+		case Op::OpSLessThanEqual: return { OperandLayouts + 563, 4u };
+		case Op::OpFOrdEqual: return { OperandLayouts + 567, 4u };
+		case Op::OpFUnordEqual: return { OperandLayouts + 571, 4u };
+		case Op::OpFOrdNotEqual: return { OperandLayouts + 575, 4u };
+		case Op::OpFUnordNotEqual: return { OperandLayouts + 579, 4u };
+		case Op::OpFOrdLessThan: return { OperandLayouts + 583, 4u };
+		case Op::OpFUnordLessThan: return { OperandLayouts + 587, 4u };
+		case Op::OpFOrdGreaterThan: return { OperandLayouts + 591, 4u };
+		case Op::OpFUnordGreaterThan: return { OperandLayouts + 595, 4u };
+		case Op::OpFOrdLessThanEqual: return { OperandLayouts + 599, 4u };
+		case Op::OpFUnordLessThanEqual: return { OperandLayouts + 603, 4u };
+		case Op::OpFOrdGreaterThanEqual: return { OperandLayouts + 607, 4u };
+		case Op::OpFUnordGreaterThanEqual: return { OperandLayouts + 611, 4u };
+		case Op::OpShiftRightLogical: return { OperandLayouts + 615, 4u };
+		case Op::OpShiftRightArithmetic: return { OperandLayouts + 619, 4u };
+		case Op::OpShiftLeftLogical: return { OperandLayouts + 623, 4u };
+		case Op::OpBitwiseOr: return { OperandLayouts + 627, 4u };
+		case Op::OpBitwiseXor: return { OperandLayouts + 631, 4u };
+		case Op::OpBitwiseAnd: return { OperandLayouts + 635, 4u };
+		case Op::OpNot: return { OperandLayouts + 639, 3u };
+		case Op::OpBitFieldInsert: return { OperandLayouts + 642, 6u };
+		case Op::OpBitFieldSExtract: return { OperandLayouts + 648, 5u };
+		case Op::OpBitFieldUExtract: return { OperandLayouts + 653, 5u };
+		case Op::OpBitReverse: return { OperandLayouts + 658, 3u };
+		case Op::OpBitCount: return { OperandLayouts + 661, 3u };
+		case Op::OpDPdx: return { OperandLayouts + 664, 3u };
+		case Op::OpDPdy: return { OperandLayouts + 667, 3u };
+		case Op::OpFwidth: return { OperandLayouts + 670, 3u };
+		case Op::OpDPdxFine: return { OperandLayouts + 673, 3u };
+		case Op::OpDPdyFine: return { OperandLayouts + 676, 3u };
+		case Op::OpFwidthFine: return { OperandLayouts + 679, 3u };
+		case Op::OpDPdxCoarse: return { OperandLayouts + 682, 3u };
+		case Op::OpDPdyCoarse: return { OperandLayouts + 685, 3u };
+		case Op::OpFwidthCoarse: return { OperandLayouts + 688, 3u };
+		case Op::OpEmitVertex: return { OperandLayouts + 691, 0u };
+		case Op::OpEndPrimitive: return { OperandLayouts + 691, 0u };
+		case Op::OpEmitStreamVertex: return { OperandLayouts + 691, 1u };
+		case Op::OpEndStreamPrimitive: return { OperandLayouts + 692, 1u };
+		case Op::OpControlBarrier: return { OperandLayouts + 693, 3u };
+		case Op::OpMemoryBarrier: return { OperandLayouts + 696, 2u };
+		case Op::OpAtomicLoad: return { OperandLayouts + 698, 5u };
+		case Op::OpAtomicStore: return { OperandLayouts + 703, 4u };
+		case Op::OpAtomicExchange: return { OperandLayouts + 707, 6u };
+		case Op::OpAtomicCompareExchange: return { OperandLayouts + 713, 8u };
+		case Op::OpAtomicCompareExchangeWeak: return { OperandLayouts + 721, 8u };
+		case Op::OpAtomicIIncrement: return { OperandLayouts + 729, 5u };
+		case Op::OpAtomicIDecrement: return { OperandLayouts + 734, 5u };
+		case Op::OpAtomicIAdd: return { OperandLayouts + 739, 6u };
+		case Op::OpAtomicISub: return { OperandLayouts + 745, 6u };
+		case Op::OpAtomicSMin: return { OperandLayouts + 751, 6u };
+		case Op::OpAtomicUMin: return { OperandLayouts + 757, 6u };
+		case Op::OpAtomicSMax: return { OperandLayouts + 763, 6u };
+		case Op::OpAtomicUMax: return { OperandLayouts + 769, 6u };
+		case Op::OpAtomicAnd: return { OperandLayouts + 775, 6u };
+		case Op::OpAtomicOr: return { OperandLayouts + 781, 6u };
+		case Op::OpAtomicXor: return { OperandLayouts + 787, 6u };
+		case Op::OpPhi: return { OperandLayouts + 793, 3u };
+		case Op::OpLoopMerge: return { OperandLayouts + 796, 3u };
+		case Op::OpSelectionMerge: return { OperandLayouts + 799, 2u };
+		case Op::OpLabel: return { OperandLayouts + 801, 1u };
+		case Op::OpBranch: return { OperandLayouts + 802, 1u };
+		case Op::OpBranchConditional: return { OperandLayouts + 803, 4u };
+		case Op::OpSwitch: return { OperandLayouts + 807, 3u };
+		case Op::OpKill: return { OperandLayouts + 810, 0u };
+		case Op::OpReturn: return { OperandLayouts + 810, 0u };
+		case Op::OpReturnValue: return { OperandLayouts + 810, 1u };
+		case Op::OpUnreachable: return { OperandLayouts + 811, 0u };
+		case Op::OpLifetimeStart: return { OperandLayouts + 811, 2u };
+		case Op::OpLifetimeStop: return { OperandLayouts + 813, 2u };
+		case Op::OpGroupAsyncCopy: return { OperandLayouts + 815, 8u };
+		case Op::OpGroupWaitEvents: return { OperandLayouts + 823, 3u };
+		case Op::OpGroupAll: return { OperandLayouts + 826, 4u };
+		case Op::OpGroupAny: return { OperandLayouts + 830, 4u };
+		case Op::OpGroupBroadcast: return { OperandLayouts + 834, 5u };
+		case Op::OpGroupIAdd: return { OperandLayouts + 839, 5u };
+		case Op::OpGroupFAdd: return { OperandLayouts + 844, 5u };
+		case Op::OpGroupFMin: return { OperandLayouts + 849, 5u };
+		case Op::OpGroupUMin: return { OperandLayouts + 854, 5u };
+		case Op::OpGroupSMin: return { OperandLayouts + 859, 5u };
+		case Op::OpGroupFMax: return { OperandLayouts + 864, 5u };
+		case Op::OpGroupUMax: return { OperandLayouts + 869, 5u };
+		case Op::OpGroupSMax: return { OperandLayouts + 874, 5u };
+		case Op::OpReadPipe: return { OperandLayouts + 879, 6u };
+		case Op::OpWritePipe: return { OperandLayouts + 885, 6u };
+		case Op::OpReservedReadPipe: return { OperandLayouts + 891, 8u };
+		case Op::OpReservedWritePipe: return { OperandLayouts + 899, 8u };
+		case Op::OpReserveReadPipePackets: return { OperandLayouts + 907, 6u };
+		case Op::OpReserveWritePipePackets: return { OperandLayouts + 913, 6u };
+		case Op::OpCommitReadPipe: return { OperandLayouts + 919, 4u };
+		case Op::OpCommitWritePipe: return { OperandLayouts + 923, 4u };
+		case Op::OpIsValidReserveId: return { OperandLayouts + 927, 3u };
+		case Op::OpGetNumPipePackets: return { OperandLayouts + 930, 5u };
+		case Op::OpGetMaxPipePackets: return { OperandLayouts + 935, 5u };
+		case Op::OpGroupReserveReadPipePackets: return { OperandLayouts + 940, 7u };
+		case Op::OpGroupReserveWritePipePackets: return { OperandLayouts + 947, 7u };
+		case Op::OpGroupCommitReadPipe: return { OperandLayouts + 954, 5u };
+		case Op::OpGroupCommitWritePipe: return { OperandLayouts + 959, 5u };
+		case Op::OpEnqueueMarker: return { OperandLayouts + 964, 6u };
+		case Op::OpEnqueueKernel: return { OperandLayouts + 970, 13u };
+		case Op::OpGetKernelNDrangeSubGroupCount: return { OperandLayouts + 983, 7u };
+		case Op::OpGetKernelNDrangeMaxSubGroupSize: return { OperandLayouts + 990, 7u };
+		case Op::OpGetKernelWorkGroupSize: return { OperandLayouts + 997, 6u };
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple: return { OperandLayouts + 1003, 6u };
+		case Op::OpRetainEvent: return { OperandLayouts + 1009, 1u };
+		case Op::OpReleaseEvent: return { OperandLayouts + 1010, 1u };
+		case Op::OpCreateUserEvent: return { OperandLayouts + 1011, 2u };
+		case Op::OpIsValidEvent: return { OperandLayouts + 1013, 3u };
+		case Op::OpSetUserEventStatus: return { OperandLayouts + 1016, 2u };
+		case Op::OpCaptureEventProfilingInfo: return { OperandLayouts + 1018, 3u };
+		case Op::OpGetDefaultQueue: return { OperandLayouts + 1021, 2u };
+		case Op::OpBuildNDRange: return { OperandLayouts + 1023, 5u };
+		case Op::OpImageSparseSampleImplicitLod: return { OperandLayouts + 1028, 5u };
+		case Op::OpImageSparseSampleExplicitLod: return { OperandLayouts + 1033, 5u };
+		case Op::OpImageSparseSampleDrefImplicitLod: return { OperandLayouts + 1038, 6u };
+		case Op::OpImageSparseSampleDrefExplicitLod: return { OperandLayouts + 1044, 6u };
+		case Op::OpImageSparseSampleProjImplicitLod: return { OperandLayouts + 1050, 5u };
+		case Op::OpImageSparseSampleProjExplicitLod: return { OperandLayouts + 1055, 5u };
+		case Op::OpImageSparseSampleProjDrefImplicitLod: return { OperandLayouts + 1060, 6u };
+		case Op::OpImageSparseSampleProjDrefExplicitLod: return { OperandLayouts + 1066, 6u };
+		case Op::OpImageSparseFetch: return { OperandLayouts + 1072, 5u };
+		case Op::OpImageSparseGather: return { OperandLayouts + 1077, 6u };
+		case Op::OpImageSparseDrefGather: return { OperandLayouts + 1083, 6u };
+		case Op::OpImageSparseTexelsResident: return { OperandLayouts + 1089, 3u };
+		case Op::OpNoLine: return { OperandLayouts + 1092, 0u };
+		case Op::OpAtomicFlagTestAndSet: return { OperandLayouts + 1092, 5u };
+		case Op::OpAtomicFlagClear: return { OperandLayouts + 1097, 3u };
+		case Op::OpImageSparseRead: return { OperandLayouts + 1100, 5u };
+		case Op::OpSizeOf: return { OperandLayouts + 1105, 3u };
+		case Op::OpTypePipeStorage: return { OperandLayouts + 1108, 1u };
+		case Op::OpConstantPipeStorage: return { OperandLayouts + 1109, 5u };
+		case Op::OpCreatePipeFromPipeStorage: return { OperandLayouts + 1114, 3u };
+		case Op::OpGetKernelLocalSizeForSubgroupCount: return { OperandLayouts + 1117, 7u };
+		case Op::OpGetKernelMaxNumSubgroups: return { OperandLayouts + 1124, 6u };
+		case Op::OpTypeNamedBarrier: return { OperandLayouts + 1130, 1u };
+		case Op::OpNamedBarrierInitialize: return { OperandLayouts + 1131, 3u };
+		case Op::OpMemoryNamedBarrier: return { OperandLayouts + 1134, 3u };
+		case Op::OpModuleProcessed: return { OperandLayouts + 1137, 1u };
+		case Op::OpExecutionModeId: return { OperandLayouts + 1138, 2u };
+		case Op::OpDecorateId: return { OperandLayouts + 1140, 2u };
+		case Op::OpGroupNonUniformElect: return { OperandLayouts + 1142, 3u };
+		case Op::OpGroupNonUniformAll: return { OperandLayouts + 1145, 4u };
+		case Op::OpGroupNonUniformAny: return { OperandLayouts + 1149, 4u };
+		case Op::OpGroupNonUniformAllEqual: return { OperandLayouts + 1153, 4u };
+		case Op::OpGroupNonUniformBroadcast: return { OperandLayouts + 1157, 5u };
+		case Op::OpGroupNonUniformBroadcastFirst: return { OperandLayouts + 1162, 4u };
+		case Op::OpGroupNonUniformBallot: return { OperandLayouts + 1166, 4u };
+		case Op::OpGroupNonUniformInverseBallot: return { OperandLayouts + 1170, 4u };
+		case Op::OpGroupNonUniformBallotBitExtract: return { OperandLayouts + 1174, 5u };
+		case Op::OpGroupNonUniformBallotBitCount: return { OperandLayouts + 1179, 5u };
+		case Op::OpGroupNonUniformBallotFindLSB: return { OperandLayouts + 1184, 4u };
+		case Op::OpGroupNonUniformBallotFindMSB: return { OperandLayouts + 1188, 4u };
+		case Op::OpGroupNonUniformShuffle: return { OperandLayouts + 1192, 5u };
+		case Op::OpGroupNonUniformShuffleXor: return { OperandLayouts + 1197, 5u };
+		case Op::OpGroupNonUniformShuffleUp: return { OperandLayouts + 1202, 5u };
+		case Op::OpGroupNonUniformShuffleDown: return { OperandLayouts + 1207, 5u };
+		case Op::OpGroupNonUniformIAdd: return { OperandLayouts + 1212, 6u };
+		case Op::OpGroupNonUniformFAdd: return { OperandLayouts + 1218, 6u };
+		case Op::OpGroupNonUniformIMul: return { OperandLayouts + 1224, 6u };
+		case Op::OpGroupNonUniformFMul: return { OperandLayouts + 1230, 6u };
+		case Op::OpGroupNonUniformSMin: return { OperandLayouts + 1236, 6u };
+		case Op::OpGroupNonUniformUMin: return { OperandLayouts + 1242, 6u };
+		case Op::OpGroupNonUniformFMin: return { OperandLayouts + 1248, 6u };
+		case Op::OpGroupNonUniformSMax: return { OperandLayouts + 1254, 6u };
+		case Op::OpGroupNonUniformUMax: return { OperandLayouts + 1260, 6u };
+		case Op::OpGroupNonUniformFMax: return { OperandLayouts + 1266, 6u };
+		case Op::OpGroupNonUniformBitwiseAnd: return { OperandLayouts + 1272, 6u };
+		case Op::OpGroupNonUniformBitwiseOr: return { OperandLayouts + 1278, 6u };
+		case Op::OpGroupNonUniformBitwiseXor: return { OperandLayouts + 1284, 6u };
+		case Op::OpGroupNonUniformLogicalAnd: return { OperandLayouts + 1290, 6u };
+		case Op::OpGroupNonUniformLogicalOr: return { OperandLayouts + 1296, 6u };
+		case Op::OpGroupNonUniformLogicalXor: return { OperandLayouts + 1302, 6u };
+		case Op::OpGroupNonUniformQuadBroadcast: return { OperandLayouts + 1308, 5u };
+		case Op::OpGroupNonUniformQuadSwap: return { OperandLayouts + 1313, 5u };
+		case Op::OpCopyLogical: return { OperandLayouts + 1318, 3u };
+		case Op::OpPtrEqual: return { OperandLayouts + 1321, 4u };
+		case Op::OpPtrNotEqual: return { OperandLayouts + 1325, 4u };
+		case Op::OpPtrDiff: return { OperandLayouts + 1329, 4u };
+		case Op::OpColorAttachmentReadEXT: return { OperandLayouts + 1333, 4u };
+		case Op::OpDepthAttachmentReadEXT: return { OperandLayouts + 1337, 3u };
+		case Op::OpStencilAttachmentReadEXT: return { OperandLayouts + 1340, 3u };
+		case Op::OpTerminateInvocation: return { OperandLayouts + 1343, 0u };
+		case Op::OpSubgroupBallotKHR: return { OperandLayouts + 1343, 3u };
+		case Op::OpSubgroupFirstInvocationKHR: return { OperandLayouts + 1346, 3u };
+		case Op::OpSubgroupAllKHR: return { OperandLayouts + 1349, 3u };
+		case Op::OpSubgroupAnyKHR: return { OperandLayouts + 1352, 3u };
+		case Op::OpSubgroupAllEqualKHR: return { OperandLayouts + 1355, 3u };
+		case Op::OpGroupNonUniformRotateKHR: return { OperandLayouts + 1358, 6u };
+		case Op::OpSubgroupReadInvocationKHR: return { OperandLayouts + 1364, 4u };
+		case Op::OpTraceRayKHR: return { OperandLayouts + 1368, 11u };
+		case Op::OpExecuteCallableKHR: return { OperandLayouts + 1379, 2u };
+		case Op::OpConvertUToAccelerationStructureKHR: return { OperandLayouts + 1381, 3u };
+		case Op::OpIgnoreIntersectionKHR: return { OperandLayouts + 1384, 0u };
+		case Op::OpTerminateRayKHR: return { OperandLayouts + 1384, 0u };
+		case Op::OpSDot: return { OperandLayouts + 1384, 5u };
+		case Op::OpUDot: return { OperandLayouts + 1389, 5u };
+		case Op::OpSUDot: return { OperandLayouts + 1394, 5u };
+		case Op::OpSDotAccSat: return { OperandLayouts + 1399, 6u };
+		case Op::OpUDotAccSat: return { OperandLayouts + 1405, 6u };
+		case Op::OpSUDotAccSat: return { OperandLayouts + 1411, 6u };
+		case Op::OpTypeCooperativeMatrixKHR: return { OperandLayouts + 1417, 6u };
+		case Op::OpCooperativeMatrixLoadKHR: return { OperandLayouts + 1423, 6u };
+		case Op::OpCooperativeMatrixStoreKHR: return { OperandLayouts + 1429, 5u };
+		case Op::OpCooperativeMatrixMulAddKHR: return { OperandLayouts + 1434, 6u };
+		case Op::OpCooperativeMatrixLengthKHR: return { OperandLayouts + 1440, 3u };
+		case Op::OpTypeRayQueryKHR: return { OperandLayouts + 1443, 1u };
+		case Op::OpRayQueryInitializeKHR: return { OperandLayouts + 1444, 8u };
+		case Op::OpRayQueryTerminateKHR: return { OperandLayouts + 1452, 1u };
+		case Op::OpRayQueryGenerateIntersectionKHR: return { OperandLayouts + 1453, 2u };
+		case Op::OpRayQueryConfirmIntersectionKHR: return { OperandLayouts + 1455, 1u };
+		case Op::OpRayQueryProceedKHR: return { OperandLayouts + 1456, 3u };
+		case Op::OpRayQueryGetIntersectionTypeKHR: return { OperandLayouts + 1459, 4u };
+		case Op::OpImageSampleWeightedQCOM: return { OperandLayouts + 1463, 5u };
+		case Op::OpImageBoxFilterQCOM: return { OperandLayouts + 1468, 5u };
+		case Op::OpImageBlockMatchSSDQCOM: return { OperandLayouts + 1473, 7u };
+		case Op::OpImageBlockMatchSADQCOM: return { OperandLayouts + 1480, 7u };
+		case Op::OpGroupIAddNonUniformAMD: return { OperandLayouts + 1487, 5u };
+		case Op::OpGroupFAddNonUniformAMD: return { OperandLayouts + 1492, 5u };
+		case Op::OpGroupFMinNonUniformAMD: return { OperandLayouts + 1497, 5u };
+		case Op::OpGroupUMinNonUniformAMD: return { OperandLayouts + 1502, 5u };
+		case Op::OpGroupSMinNonUniformAMD: return { OperandLayouts + 1507, 5u };
+		case Op::OpGroupFMaxNonUniformAMD: return { OperandLayouts + 1512, 5u };
+		case Op::OpGroupUMaxNonUniformAMD: return { OperandLayouts + 1517, 5u };
+		case Op::OpGroupSMaxNonUniformAMD: return { OperandLayouts + 1522, 5u };
+		case Op::OpFragmentMaskFetchAMD: return { OperandLayouts + 1527, 4u };
+		case Op::OpFragmentFetchAMD: return { OperandLayouts + 1531, 5u };
+		case Op::OpReadClockKHR: return { OperandLayouts + 1536, 3u };
+		case Op::OpFinalizeNodePayloadsAMDX: return { OperandLayouts + 1539, 1u };
+		case Op::OpFinishWritingNodePayloadAMDX: return { OperandLayouts + 1540, 3u };
+		case Op::OpInitializeNodePayloadsAMDX: return { OperandLayouts + 1543, 4u };
+		case Op::OpHitObjectRecordHitMotionNV: return { OperandLayouts + 1547, 14u };
+		case Op::OpHitObjectRecordHitWithIndexMotionNV: return { OperandLayouts + 1561, 13u };
+		case Op::OpHitObjectRecordMissMotionNV: return { OperandLayouts + 1574, 7u };
+		case Op::OpHitObjectGetWorldToObjectNV: return { OperandLayouts + 1581, 3u };
+		case Op::OpHitObjectGetObjectToWorldNV: return { OperandLayouts + 1584, 3u };
+		case Op::OpHitObjectGetObjectRayDirectionNV: return { OperandLayouts + 1587, 3u };
+		case Op::OpHitObjectGetObjectRayOriginNV: return { OperandLayouts + 1590, 3u };
+		case Op::OpHitObjectTraceRayMotionNV: return { OperandLayouts + 1593, 13u };
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV: return { OperandLayouts + 1606, 3u };
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV: return { OperandLayouts + 1609, 3u };
+		case Op::OpHitObjectRecordEmptyNV: return { OperandLayouts + 1612, 1u };
+		case Op::OpHitObjectTraceRayNV: return { OperandLayouts + 1613, 12u };
+		case Op::OpHitObjectRecordHitNV: return { OperandLayouts + 1625, 13u };
+		case Op::OpHitObjectRecordHitWithIndexNV: return { OperandLayouts + 1638, 12u };
+		case Op::OpHitObjectRecordMissNV: return { OperandLayouts + 1650, 6u };
+		case Op::OpHitObjectExecuteShaderNV: return { OperandLayouts + 1656, 2u };
+		case Op::OpHitObjectGetCurrentTimeNV: return { OperandLayouts + 1658, 3u };
+		case Op::OpHitObjectGetAttributesNV: return { OperandLayouts + 1661, 2u };
+		case Op::OpHitObjectGetHitKindNV: return { OperandLayouts + 1663, 3u };
+		case Op::OpHitObjectGetPrimitiveIndexNV: return { OperandLayouts + 1666, 3u };
+		case Op::OpHitObjectGetGeometryIndexNV: return { OperandLayouts + 1669, 3u };
+		case Op::OpHitObjectGetInstanceIdNV: return { OperandLayouts + 1672, 3u };
+		case Op::OpHitObjectGetInstanceCustomIndexNV: return { OperandLayouts + 1675, 3u };
+		case Op::OpHitObjectGetWorldRayDirectionNV: return { OperandLayouts + 1678, 3u };
+		case Op::OpHitObjectGetWorldRayOriginNV: return { OperandLayouts + 1681, 3u };
+		case Op::OpHitObjectGetRayTMaxNV: return { OperandLayouts + 1684, 3u };
+		case Op::OpHitObjectGetRayTMinNV: return { OperandLayouts + 1687, 3u };
+		case Op::OpHitObjectIsEmptyNV: return { OperandLayouts + 1690, 3u };
+		case Op::OpHitObjectIsHitNV: return { OperandLayouts + 1693, 3u };
+		case Op::OpHitObjectIsMissNV: return { OperandLayouts + 1696, 3u };
+		case Op::OpReorderThreadWithHitObjectNV: return { OperandLayouts + 1699, 3u };
+		case Op::OpReorderThreadWithHintNV: return { OperandLayouts + 1702, 2u };
+		case Op::OpTypeHitObjectNV: return { OperandLayouts + 1704, 1u };
+		case Op::OpImageSampleFootprintNV: return { OperandLayouts + 1705, 7u };
+		case Op::OpEmitMeshTasksEXT: return { OperandLayouts + 1712, 4u };
+		case Op::OpSetMeshOutputsEXT: return { OperandLayouts + 1716, 2u };
+		case Op::OpGroupNonUniformPartitionNV: return { OperandLayouts + 1718, 3u };
+		case Op::OpWritePackedPrimitiveIndices4x8NV: return { OperandLayouts + 1721, 2u };
+		case Op::OpReportIntersectionNV: return { OperandLayouts + 1723, 4u };
+		case Op::OpIgnoreIntersectionNV: return { OperandLayouts + 1727, 0u };
+		case Op::OpTerminateRayNV: return { OperandLayouts + 1727, 0u };
+		case Op::OpTraceNV: return { OperandLayouts + 1727, 11u };
+		case Op::OpTraceMotionNV: return { OperandLayouts + 1738, 12u };
+		case Op::OpTraceRayMotionNV: return { OperandLayouts + 1750, 12u };
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR: return { OperandLayouts + 1762, 4u };
+		case Op::OpTypeAccelerationStructureNV: return { OperandLayouts + 1766, 1u };
+		case Op::OpExecuteCallableNV: return { OperandLayouts + 1767, 2u };
+		case Op::OpTypeCooperativeMatrixNV: return { OperandLayouts + 1769, 5u };
+		case Op::OpCooperativeMatrixLoadNV: return { OperandLayouts + 1774, 6u };
+		case Op::OpCooperativeMatrixStoreNV: return { OperandLayouts + 1780, 5u };
+		case Op::OpCooperativeMatrixMulAddNV: return { OperandLayouts + 1785, 5u };
+		case Op::OpCooperativeMatrixLengthNV: return { OperandLayouts + 1790, 3u };
+		case Op::OpBeginInvocationInterlockEXT: return { OperandLayouts + 1793, 0u };
+		case Op::OpEndInvocationInterlockEXT: return { OperandLayouts + 1793, 0u };
+		case Op::OpDemoteToHelperInvocation: return { OperandLayouts + 1793, 0u };
+		case Op::OpIsHelperInvocationEXT: return { OperandLayouts + 1793, 2u };
+		case Op::OpConvertUToImageNV: return { OperandLayouts + 1795, 3u };
+		case Op::OpConvertUToSamplerNV: return { OperandLayouts + 1798, 3u };
+		case Op::OpConvertImageToUNV: return { OperandLayouts + 1801, 3u };
+		case Op::OpConvertSamplerToUNV: return { OperandLayouts + 1804, 3u };
+		case Op::OpConvertUToSampledImageNV: return { OperandLayouts + 1807, 3u };
+		case Op::OpConvertSampledImageToUNV: return { OperandLayouts + 1810, 3u };
+		case Op::OpSamplerImageAddressingModeNV: return { OperandLayouts + 1813, 1u };
+		case Op::OpSubgroupShuffleINTEL: return { OperandLayouts + 1814, 4u };
+		case Op::OpSubgroupShuffleDownINTEL: return { OperandLayouts + 1818, 5u };
+		case Op::OpSubgroupShuffleUpINTEL: return { OperandLayouts + 1823, 5u };
+		case Op::OpSubgroupShuffleXorINTEL: return { OperandLayouts + 1828, 4u };
+		case Op::OpSubgroupBlockReadINTEL: return { OperandLayouts + 1832, 3u };
+		case Op::OpSubgroupBlockWriteINTEL: return { OperandLayouts + 1835, 2u };
+		case Op::OpSubgroupImageBlockReadINTEL: return { OperandLayouts + 1837, 4u };
+		case Op::OpSubgroupImageBlockWriteINTEL: return { OperandLayouts + 1841, 3u };
+		case Op::OpSubgroupImageMediaBlockReadINTEL: return { OperandLayouts + 1844, 6u };
+		case Op::OpSubgroupImageMediaBlockWriteINTEL: return { OperandLayouts + 1850, 5u };
+		case Op::OpUCountLeadingZerosINTEL: return { OperandLayouts + 1855, 3u };
+		case Op::OpUCountTrailingZerosINTEL: return { OperandLayouts + 1858, 3u };
+		case Op::OpAbsISubINTEL: return { OperandLayouts + 1861, 4u };
+		case Op::OpAbsUSubINTEL: return { OperandLayouts + 1865, 4u };
+		case Op::OpIAddSatINTEL: return { OperandLayouts + 1869, 4u };
+		case Op::OpUAddSatINTEL: return { OperandLayouts + 1873, 4u };
+		case Op::OpIAverageINTEL: return { OperandLayouts + 1877, 4u };
+		case Op::OpUAverageINTEL: return { OperandLayouts + 1881, 4u };
+		case Op::OpIAverageRoundedINTEL: return { OperandLayouts + 1885, 4u };
+		case Op::OpUAverageRoundedINTEL: return { OperandLayouts + 1889, 4u };
+		case Op::OpISubSatINTEL: return { OperandLayouts + 1893, 4u };
+		case Op::OpUSubSatINTEL: return { OperandLayouts + 1897, 4u };
+		case Op::OpIMul32x16INTEL: return { OperandLayouts + 1901, 4u };
+		case Op::OpUMul32x16INTEL: return { OperandLayouts + 1905, 4u };
+		case Op::OpConstantFunctionPointerINTEL: return { OperandLayouts + 1909, 3u };
+		case Op::OpFunctionPointerCallINTEL: return { OperandLayouts + 1912, 3u };
+		case Op::OpAsmTargetINTEL: return { OperandLayouts + 1915, 3u };
+		case Op::OpAsmINTEL: return { OperandLayouts + 1918, 6u };
+		case Op::OpAsmCallINTEL: return { OperandLayouts + 1924, 4u };
+		case Op::OpAtomicFMinEXT: return { OperandLayouts + 1928, 6u };
+		case Op::OpAtomicFMaxEXT: return { OperandLayouts + 1934, 6u };
+		case Op::OpAssumeTrueKHR: return { OperandLayouts + 1940, 1u };
+		case Op::OpExpectKHR: return { OperandLayouts + 1941, 4u };
+		case Op::OpDecorateString: return { OperandLayouts + 1945, 2u };
+		case Op::OpMemberDecorateString: return { OperandLayouts + 1947, 3u };
+		case Op::OpVmeImageINTEL: return { OperandLayouts + 1950, 4u };
+		case Op::OpTypeVmeImageINTEL: return { OperandLayouts + 1954, 2u };
+		case Op::OpTypeAvcImePayloadINTEL: return { OperandLayouts + 1956, 1u };
+		case Op::OpTypeAvcRefPayloadINTEL: return { OperandLayouts + 1957, 1u };
+		case Op::OpTypeAvcSicPayloadINTEL: return { OperandLayouts + 1958, 1u };
+		case Op::OpTypeAvcMcePayloadINTEL: return { OperandLayouts + 1959, 1u };
+		case Op::OpTypeAvcMceResultINTEL: return { OperandLayouts + 1960, 1u };
+		case Op::OpTypeAvcImeResultINTEL: return { OperandLayouts + 1961, 1u };
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL: return { OperandLayouts + 1962, 1u };
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL: return { OperandLayouts + 1963, 1u };
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL: return { OperandLayouts + 1964, 1u };
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL: return { OperandLayouts + 1965, 1u };
+		case Op::OpTypeAvcRefResultINTEL: return { OperandLayouts + 1966, 1u };
+		case Op::OpTypeAvcSicResultINTEL: return { OperandLayouts + 1967, 1u };
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: return { OperandLayouts + 1968, 4u };
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: return { OperandLayouts + 1972, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: return { OperandLayouts + 1976, 4u };
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL: return { OperandLayouts + 1980, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: return { OperandLayouts + 1984, 4u };
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: return { OperandLayouts + 1988, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: return { OperandLayouts + 1992, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: return { OperandLayouts + 1996, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: return { OperandLayouts + 2000, 2u };
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: return { OperandLayouts + 2002, 2u };
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: return { OperandLayouts + 2004, 2u };
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: return { OperandLayouts + 2006, 6u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: return { OperandLayouts + 2012, 4u };
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: return { OperandLayouts + 2016, 2u };
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: return { OperandLayouts + 2018, 2u };
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL: return { OperandLayouts + 2020, 3u };
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: return { OperandLayouts + 2023, 4u };
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: return { OperandLayouts + 2027, 4u };
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: return { OperandLayouts + 2031, 5u };
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL: return { OperandLayouts + 2036, 3u };
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL: return { OperandLayouts + 2039, 3u };
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL: return { OperandLayouts + 2042, 3u };
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL: return { OperandLayouts + 2045, 3u };
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL: return { OperandLayouts + 2048, 3u };
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL: return { OperandLayouts + 2051, 3u };
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL: return { OperandLayouts + 2054, 3u };
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL: return { OperandLayouts + 2057, 3u };
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL: return { OperandLayouts + 2060, 3u };
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL: return { OperandLayouts + 2063, 3u };
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL: return { OperandLayouts + 2066, 3u };
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL: return { OperandLayouts + 2069, 3u };
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL: return { OperandLayouts + 2072, 3u };
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL: return { OperandLayouts + 2075, 3u };
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: return { OperandLayouts + 2078, 5u };
+		case Op::OpSubgroupAvcImeInitializeINTEL: return { OperandLayouts + 2083, 5u };
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL: return { OperandLayouts + 2088, 5u };
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL: return { OperandLayouts + 2093, 6u };
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL: return { OperandLayouts + 2099, 4u };
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL: return { OperandLayouts + 2103, 6u };
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL: return { OperandLayouts + 2109, 3u };
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: return { OperandLayouts + 2112, 4u };
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: return { OperandLayouts + 2116, 3u };
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: return { OperandLayouts + 2119, 4u };
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL: return { OperandLayouts + 2123, 4u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: return { OperandLayouts + 2127, 5u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: return { OperandLayouts + 2132, 6u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: return { OperandLayouts + 2138, 6u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: return { OperandLayouts + 2144, 7u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: return { OperandLayouts + 2151, 5u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: return { OperandLayouts + 2156, 6u };
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: return { OperandLayouts + 2162, 6u };
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: return { OperandLayouts + 2168, 7u };
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL: return { OperandLayouts + 2175, 3u };
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: return { OperandLayouts + 2178, 3u };
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL: return { OperandLayouts + 2181, 3u };
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: return { OperandLayouts + 2184, 3u };
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: return { OperandLayouts + 2187, 3u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: return { OperandLayouts + 2190, 4u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: return { OperandLayouts + 2194, 4u };
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: return { OperandLayouts + 2198, 4u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: return { OperandLayouts + 2202, 5u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: return { OperandLayouts + 2207, 5u };
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: return { OperandLayouts + 2212, 5u };
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL: return { OperandLayouts + 2217, 4u };
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: return { OperandLayouts + 2221, 3u };
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: return { OperandLayouts + 2224, 3u };
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: return { OperandLayouts + 2227, 3u };
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: return { OperandLayouts + 2230, 3u };
+		case Op::OpSubgroupAvcFmeInitializeINTEL: return { OperandLayouts + 2233, 9u };
+		case Op::OpSubgroupAvcBmeInitializeINTEL: return { OperandLayouts + 2242, 10u };
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL: return { OperandLayouts + 2252, 3u };
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: return { OperandLayouts + 2255, 3u };
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL: return { OperandLayouts + 2258, 3u };
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: return { OperandLayouts + 2261, 5u };
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: return { OperandLayouts + 2266, 6u };
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: return { OperandLayouts + 2272, 5u };
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: return { OperandLayouts + 2277, 6u };
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL: return { OperandLayouts + 2283, 3u };
+		case Op::OpSubgroupAvcSicInitializeINTEL: return { OperandLayouts + 2286, 3u };
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL: return { OperandLayouts + 2289, 8u };
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL: return { OperandLayouts + 2297, 10u };
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: return { OperandLayouts + 2307, 13u };
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL: return { OperandLayouts + 2320, 4u };
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL: return { OperandLayouts + 2324, 3u };
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: return { OperandLayouts + 2327, 4u };
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: return { OperandLayouts + 2331, 6u };
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: return { OperandLayouts + 2337, 4u };
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL: return { OperandLayouts + 2341, 3u };
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: return { OperandLayouts + 2344, 4u };
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: return { OperandLayouts + 2348, 4u };
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL: return { OperandLayouts + 2352, 4u };
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: return { OperandLayouts + 2356, 5u };
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: return { OperandLayouts + 2361, 6u };
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: return { OperandLayouts + 2367, 5u };
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: return { OperandLayouts + 2372, 6u };
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL: return { OperandLayouts + 2378, 3u };
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL: return { OperandLayouts + 2381, 3u };
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: return { OperandLayouts + 2384, 3u };
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: return { OperandLayouts + 2387, 3u };
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: return { OperandLayouts + 2390, 3u };
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL: return { OperandLayouts + 2393, 3u };
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: return { OperandLayouts + 2396, 3u };
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: return { OperandLayouts + 2399, 3u };
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL: return { OperandLayouts + 2402, 3u };
+		case Op::OpVariableLengthArrayINTEL: return { OperandLayouts + 2405, 3u };
+		case Op::OpSaveMemoryINTEL: return { OperandLayouts + 2408, 2u };
+		case Op::OpRestoreMemoryINTEL: return { OperandLayouts + 2410, 1u };
+		case Op::OpArbitraryFloatSinCosPiINTEL: return { OperandLayouts + 2411, 9u };
+		case Op::OpArbitraryFloatCastINTEL: return { OperandLayouts + 2420, 8u };
+		case Op::OpArbitraryFloatCastFromIntINTEL: return { OperandLayouts + 2428, 8u };
+		case Op::OpArbitraryFloatCastToIntINTEL: return { OperandLayouts + 2436, 7u };
+		case Op::OpArbitraryFloatAddINTEL: return { OperandLayouts + 2443, 10u };
+		case Op::OpArbitraryFloatSubINTEL: return { OperandLayouts + 2453, 10u };
+		case Op::OpArbitraryFloatMulINTEL: return { OperandLayouts + 2463, 10u };
+		case Op::OpArbitraryFloatDivINTEL: return { OperandLayouts + 2473, 10u };
+		case Op::OpArbitraryFloatGTINTEL: return { OperandLayouts + 2483, 6u };
+		case Op::OpArbitraryFloatGEINTEL: return { OperandLayouts + 2489, 6u };
+		case Op::OpArbitraryFloatLTINTEL: return { OperandLayouts + 2495, 6u };
+		case Op::OpArbitraryFloatLEINTEL: return { OperandLayouts + 2501, 6u };
+		case Op::OpArbitraryFloatEQINTEL: return { OperandLayouts + 2507, 6u };
+		case Op::OpArbitraryFloatRecipINTEL: return { OperandLayouts + 2513, 8u };
+		case Op::OpArbitraryFloatRSqrtINTEL: return { OperandLayouts + 2521, 8u };
+		case Op::OpArbitraryFloatCbrtINTEL: return { OperandLayouts + 2529, 8u };
+		case Op::OpArbitraryFloatHypotINTEL: return { OperandLayouts + 2537, 10u };
+		case Op::OpArbitraryFloatSqrtINTEL: return { OperandLayouts + 2547, 8u };
+		case Op::OpArbitraryFloatLogINTEL: return { OperandLayouts + 2555, 8u };
+		case Op::OpArbitraryFloatLog2INTEL: return { OperandLayouts + 2563, 8u };
+		case Op::OpArbitraryFloatLog10INTEL: return { OperandLayouts + 2571, 8u };
+		case Op::OpArbitraryFloatLog1pINTEL: return { OperandLayouts + 2579, 8u };
+		case Op::OpArbitraryFloatExpINTEL: return { OperandLayouts + 2587, 8u };
+		case Op::OpArbitraryFloatExp2INTEL: return { OperandLayouts + 2595, 8u };
+		case Op::OpArbitraryFloatExp10INTEL: return { OperandLayouts + 2603, 8u };
+		case Op::OpArbitraryFloatExpm1INTEL: return { OperandLayouts + 2611, 8u };
+		case Op::OpArbitraryFloatSinINTEL: return { OperandLayouts + 2619, 8u };
+		case Op::OpArbitraryFloatCosINTEL: return { OperandLayouts + 2627, 8u };
+		case Op::OpArbitraryFloatSinCosINTEL: return { OperandLayouts + 2635, 8u };
+		case Op::OpArbitraryFloatSinPiINTEL: return { OperandLayouts + 2643, 8u };
+		case Op::OpArbitraryFloatCosPiINTEL: return { OperandLayouts + 2651, 8u };
+		case Op::OpArbitraryFloatASinINTEL: return { OperandLayouts + 2659, 8u };
+		case Op::OpArbitraryFloatASinPiINTEL: return { OperandLayouts + 2667, 8u };
+		case Op::OpArbitraryFloatACosINTEL: return { OperandLayouts + 2675, 8u };
+		case Op::OpArbitraryFloatACosPiINTEL: return { OperandLayouts + 2683, 8u };
+		case Op::OpArbitraryFloatATanINTEL: return { OperandLayouts + 2691, 8u };
+		case Op::OpArbitraryFloatATanPiINTEL: return { OperandLayouts + 2699, 8u };
+		case Op::OpArbitraryFloatATan2INTEL: return { OperandLayouts + 2707, 10u };
+		case Op::OpArbitraryFloatPowINTEL: return { OperandLayouts + 2717, 10u };
+		case Op::OpArbitraryFloatPowRINTEL: return { OperandLayouts + 2727, 10u };
+		case Op::OpArbitraryFloatPowNINTEL: return { OperandLayouts + 2737, 9u };
+		case Op::OpLoopControlINTEL: return { OperandLayouts + 2746, 1u };
+		case Op::OpAliasDomainDeclINTEL: return { OperandLayouts + 2747, 2u };
+		case Op::OpAliasScopeDeclINTEL: return { OperandLayouts + 2749, 3u };
+		case Op::OpAliasScopeListDeclINTEL: return { OperandLayouts + 2752, 2u };
+		case Op::OpFixedSqrtINTEL: return { OperandLayouts + 2754, 9u };
+		case Op::OpFixedRecipINTEL: return { OperandLayouts + 2763, 9u };
+		case Op::OpFixedRsqrtINTEL: return { OperandLayouts + 2772, 9u };
+		case Op::OpFixedSinINTEL: return { OperandLayouts + 2781, 9u };
+		case Op::OpFixedCosINTEL: return { OperandLayouts + 2790, 9u };
+		case Op::OpFixedSinCosINTEL: return { OperandLayouts + 2799, 9u };
+		case Op::OpFixedSinPiINTEL: return { OperandLayouts + 2808, 9u };
+		case Op::OpFixedCosPiINTEL: return { OperandLayouts + 2817, 9u };
+		case Op::OpFixedSinCosPiINTEL: return { OperandLayouts + 2826, 9u };
+		case Op::OpFixedLogINTEL: return { OperandLayouts + 2835, 9u };
+		case Op::OpFixedExpINTEL: return { OperandLayouts + 2844, 9u };
+		case Op::OpPtrCastToCrossWorkgroupINTEL: return { OperandLayouts + 2853, 3u };
+		case Op::OpCrossWorkgroupCastToPtrINTEL: return { OperandLayouts + 2856, 3u };
+		case Op::OpReadPipeBlockingINTEL: return { OperandLayouts + 2859, 4u };
+		case Op::OpWritePipeBlockingINTEL: return { OperandLayouts + 2863, 4u };
+		case Op::OpFPGARegINTEL: return { OperandLayouts + 2867, 4u };
+		case Op::OpRayQueryGetRayTMinKHR: return { OperandLayouts + 2871, 3u };
+		case Op::OpRayQueryGetRayFlagsKHR: return { OperandLayouts + 2874, 3u };
+		case Op::OpRayQueryGetIntersectionTKHR: return { OperandLayouts + 2877, 4u };
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR: return { OperandLayouts + 2881, 4u };
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR: return { OperandLayouts + 2885, 4u };
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: return { OperandLayouts + 2889, 4u };
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR: return { OperandLayouts + 2893, 4u };
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR: return { OperandLayouts + 2897, 4u };
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR: return { OperandLayouts + 2901, 4u };
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR: return { OperandLayouts + 2905, 4u };
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: return { OperandLayouts + 2909, 3u };
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR: return { OperandLayouts + 2912, 4u };
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR: return { OperandLayouts + 2916, 4u };
+		case Op::OpRayQueryGetWorldRayDirectionKHR: return { OperandLayouts + 2920, 3u };
+		case Op::OpRayQueryGetWorldRayOriginKHR: return { OperandLayouts + 2923, 3u };
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR: return { OperandLayouts + 2926, 4u };
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR: return { OperandLayouts + 2930, 4u };
+		case Op::OpAtomicFAddEXT: return { OperandLayouts + 2934, 6u };
+		case Op::OpTypeBufferSurfaceINTEL: return { OperandLayouts + 2940, 2u };
+		case Op::OpTypeStructContinuedINTEL: return { OperandLayouts + 2942, 1u };
+		case Op::OpConstantCompositeContinuedINTEL: return { OperandLayouts + 2943, 1u };
+		case Op::OpSpecConstantCompositeContinuedINTEL: return { OperandLayouts + 2944, 1u };
+		case Op::OpConvertFToBF16INTEL: return { OperandLayouts + 2945, 3u };
+		case Op::OpConvertBF16ToFINTEL: return { OperandLayouts + 2948, 3u };
+		case Op::OpControlBarrierArriveINTEL: return { OperandLayouts + 2951, 3u };
+		case Op::OpControlBarrierWaitINTEL: return { OperandLayouts + 2954, 3u };
+		case Op::OpGroupIMulKHR: return { OperandLayouts + 2957, 5u };
+		case Op::OpGroupFMulKHR: return { OperandLayouts + 2962, 5u };
+		case Op::OpGroupBitwiseAndKHR: return { OperandLayouts + 2967, 5u };
+		case Op::OpGroupBitwiseOrKHR: return { OperandLayouts + 2972, 5u };
+		case Op::OpGroupBitwiseXorKHR: return { OperandLayouts + 2977, 5u };
+		case Op::OpGroupLogicalAndKHR: return { OperandLayouts + 2982, 5u };
+		case Op::OpGroupLogicalOrKHR: return { OperandLayouts + 2987, 5u };
+		case Op::OpGroupLogicalXorKHR: return { OperandLayouts + 2992, 5u };
+		}
+	}
+#endif // SPVGENTWO_OPERAND_LAYOUT
+	namespace flags
+	{
+		inline constexpr ImageOperandsMask operator|(ImageOperandsMask l, ImageOperandsMask r) { return static_cast<ImageOperandsMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr ImageOperandsMask operator&(ImageOperandsMask l, ImageOperandsMask r) { return static_cast<ImageOperandsMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr ImageOperandsMask operator^(ImageOperandsMask l, ImageOperandsMask r) { return static_cast<ImageOperandsMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr ImageOperandsMask operator~(ImageOperandsMask m) { return static_cast<ImageOperandsMask>(~static_cast<unsigned>(m)); }
+		inline constexpr ImageOperandsMask& operator|=(ImageOperandsMask& l, ImageOperandsMask r) { return l = l | r; }
+		inline constexpr ImageOperandsMask& operator&=(ImageOperandsMask& l, ImageOperandsMask r) { return l = l & r; }
+		inline constexpr ImageOperandsMask& operator^=(ImageOperandsMask& l, ImageOperandsMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(ImageOperandsMask mask, ImageOperandsMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr ImageOperandsMask MaskFromShift(ImageOperandsShift shift) {
+			switch (shift) {
+			default: return static_cast<ImageOperandsMask>(0u); // unknown shift
+			case ImageOperandsShift::Bias: return ImageOperandsMask::Bias;
+			case ImageOperandsShift::Lod: return ImageOperandsMask::Lod;
+			case ImageOperandsShift::Grad: return ImageOperandsMask::Grad;
+			case ImageOperandsShift::ConstOffset: return ImageOperandsMask::ConstOffset;
+			case ImageOperandsShift::Offset: return ImageOperandsMask::Offset;
+			case ImageOperandsShift::ConstOffsets: return ImageOperandsMask::ConstOffsets;
+			case ImageOperandsShift::Sample: return ImageOperandsMask::Sample;
+			case ImageOperandsShift::MinLod: return ImageOperandsMask::MinLod;
+			case ImageOperandsShift::MakeTexelAvailable: return ImageOperandsMask::MakeTexelAvailable;
+			case ImageOperandsShift::MakeTexelAvailableKHR: return ImageOperandsMask::MakeTexelAvailableKHR;
+			case ImageOperandsShift::MakeTexelVisible: return ImageOperandsMask::MakeTexelVisible;
+			case ImageOperandsShift::MakeTexelVisibleKHR: return ImageOperandsMask::MakeTexelVisibleKHR;
+			case ImageOperandsShift::NonPrivateTexel: return ImageOperandsMask::NonPrivateTexel;
+			case ImageOperandsShift::NonPrivateTexelKHR: return ImageOperandsMask::NonPrivateTexelKHR;
+			case ImageOperandsShift::VolatileTexel: return ImageOperandsMask::VolatileTexel;
+			case ImageOperandsShift::VolatileTexelKHR: return ImageOperandsMask::VolatileTexelKHR;
+			case ImageOperandsShift::SignExtend: return ImageOperandsMask::SignExtend;
+			case ImageOperandsShift::ZeroExtend: return ImageOperandsMask::ZeroExtend;
+			case ImageOperandsShift::Nontemporal: return ImageOperandsMask::Nontemporal;
+			case ImageOperandsShift::Offsets: return ImageOperandsMask::Offsets;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(ImageOperandsMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<ImageOperandsMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr FPFastMathModeMask operator|(FPFastMathModeMask l, FPFastMathModeMask r) { return static_cast<FPFastMathModeMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr FPFastMathModeMask operator&(FPFastMathModeMask l, FPFastMathModeMask r) { return static_cast<FPFastMathModeMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr FPFastMathModeMask operator^(FPFastMathModeMask l, FPFastMathModeMask r) { return static_cast<FPFastMathModeMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr FPFastMathModeMask operator~(FPFastMathModeMask m) { return static_cast<FPFastMathModeMask>(~static_cast<unsigned>(m)); }
+		inline constexpr FPFastMathModeMask& operator|=(FPFastMathModeMask& l, FPFastMathModeMask r) { return l = l | r; }
+		inline constexpr FPFastMathModeMask& operator&=(FPFastMathModeMask& l, FPFastMathModeMask r) { return l = l & r; }
+		inline constexpr FPFastMathModeMask& operator^=(FPFastMathModeMask& l, FPFastMathModeMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(FPFastMathModeMask mask, FPFastMathModeMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr FPFastMathModeMask MaskFromShift(FPFastMathModeShift shift) {
+			switch (shift) {
+			default: return static_cast<FPFastMathModeMask>(0u); // unknown shift
+			case FPFastMathModeShift::NotNaN: return FPFastMathModeMask::NotNaN;
+			case FPFastMathModeShift::NotInf: return FPFastMathModeMask::NotInf;
+			case FPFastMathModeShift::NSZ: return FPFastMathModeMask::NSZ;
+			case FPFastMathModeShift::AllowRecip: return FPFastMathModeMask::AllowRecip;
+			case FPFastMathModeShift::Fast: return FPFastMathModeMask::Fast;
+			case FPFastMathModeShift::AllowContractFastINTEL: return FPFastMathModeMask::AllowContractFastINTEL;
+			case FPFastMathModeShift::AllowReassocINTEL: return FPFastMathModeMask::AllowReassocINTEL;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(FPFastMathModeMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<FPFastMathModeMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr SelectionControlMask operator|(SelectionControlMask l, SelectionControlMask r) { return static_cast<SelectionControlMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr SelectionControlMask operator&(SelectionControlMask l, SelectionControlMask r) { return static_cast<SelectionControlMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr SelectionControlMask operator^(SelectionControlMask l, SelectionControlMask r) { return static_cast<SelectionControlMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr SelectionControlMask operator~(SelectionControlMask m) { return static_cast<SelectionControlMask>(~static_cast<unsigned>(m)); }
+		inline constexpr SelectionControlMask& operator|=(SelectionControlMask& l, SelectionControlMask r) { return l = l | r; }
+		inline constexpr SelectionControlMask& operator&=(SelectionControlMask& l, SelectionControlMask r) { return l = l & r; }
+		inline constexpr SelectionControlMask& operator^=(SelectionControlMask& l, SelectionControlMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(SelectionControlMask mask, SelectionControlMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr SelectionControlMask MaskFromShift(SelectionControlShift shift) {
+			switch (shift) {
+			default: return static_cast<SelectionControlMask>(0u); // unknown shift
+			case SelectionControlShift::Flatten: return SelectionControlMask::Flatten;
+			case SelectionControlShift::DontFlatten: return SelectionControlMask::DontFlatten;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(SelectionControlMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<SelectionControlMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr LoopControlMask operator|(LoopControlMask l, LoopControlMask r) { return static_cast<LoopControlMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr LoopControlMask operator&(LoopControlMask l, LoopControlMask r) { return static_cast<LoopControlMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr LoopControlMask operator^(LoopControlMask l, LoopControlMask r) { return static_cast<LoopControlMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr LoopControlMask operator~(LoopControlMask m) { return static_cast<LoopControlMask>(~static_cast<unsigned>(m)); }
+		inline constexpr LoopControlMask& operator|=(LoopControlMask& l, LoopControlMask r) { return l = l | r; }
+		inline constexpr LoopControlMask& operator&=(LoopControlMask& l, LoopControlMask r) { return l = l & r; }
+		inline constexpr LoopControlMask& operator^=(LoopControlMask& l, LoopControlMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(LoopControlMask mask, LoopControlMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr LoopControlMask MaskFromShift(LoopControlShift shift) {
+			switch (shift) {
+			default: return static_cast<LoopControlMask>(0u); // unknown shift
+			case LoopControlShift::Unroll: return LoopControlMask::Unroll;
+			case LoopControlShift::DontUnroll: return LoopControlMask::DontUnroll;
+			case LoopControlShift::DependencyInfinite: return LoopControlMask::DependencyInfinite;
+			case LoopControlShift::DependencyLength: return LoopControlMask::DependencyLength;
+			case LoopControlShift::MinIterations: return LoopControlMask::MinIterations;
+			case LoopControlShift::MaxIterations: return LoopControlMask::MaxIterations;
+			case LoopControlShift::IterationMultiple: return LoopControlMask::IterationMultiple;
+			case LoopControlShift::PeelCount: return LoopControlMask::PeelCount;
+			case LoopControlShift::PartialCount: return LoopControlMask::PartialCount;
+			case LoopControlShift::InitiationIntervalINTEL: return LoopControlMask::InitiationIntervalINTEL;
+			case LoopControlShift::MaxConcurrencyINTEL: return LoopControlMask::MaxConcurrencyINTEL;
+			case LoopControlShift::DependencyArrayINTEL: return LoopControlMask::DependencyArrayINTEL;
+			case LoopControlShift::PipelineEnableINTEL: return LoopControlMask::PipelineEnableINTEL;
+			case LoopControlShift::LoopCoalesceINTEL: return LoopControlMask::LoopCoalesceINTEL;
+			case LoopControlShift::MaxInterleavingINTEL: return LoopControlMask::MaxInterleavingINTEL;
+			case LoopControlShift::SpeculatedIterationsINTEL: return LoopControlMask::SpeculatedIterationsINTEL;
+			case LoopControlShift::NoFusionINTEL: return LoopControlMask::NoFusionINTEL;
+			case LoopControlShift::LoopCountINTEL: return LoopControlMask::LoopCountINTEL;
+			case LoopControlShift::MaxReinvocationDelayINTEL: return LoopControlMask::MaxReinvocationDelayINTEL;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(LoopControlMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<LoopControlMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr FunctionControlMask operator|(FunctionControlMask l, FunctionControlMask r) { return static_cast<FunctionControlMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr FunctionControlMask operator&(FunctionControlMask l, FunctionControlMask r) { return static_cast<FunctionControlMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr FunctionControlMask operator^(FunctionControlMask l, FunctionControlMask r) { return static_cast<FunctionControlMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr FunctionControlMask operator~(FunctionControlMask m) { return static_cast<FunctionControlMask>(~static_cast<unsigned>(m)); }
+		inline constexpr FunctionControlMask& operator|=(FunctionControlMask& l, FunctionControlMask r) { return l = l | r; }
+		inline constexpr FunctionControlMask& operator&=(FunctionControlMask& l, FunctionControlMask r) { return l = l & r; }
+		inline constexpr FunctionControlMask& operator^=(FunctionControlMask& l, FunctionControlMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(FunctionControlMask mask, FunctionControlMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr FunctionControlMask MaskFromShift(FunctionControlShift shift) {
+			switch (shift) {
+			default: return static_cast<FunctionControlMask>(0u); // unknown shift
+			case FunctionControlShift::Inline: return FunctionControlMask::Inline;
+			case FunctionControlShift::DontInline: return FunctionControlMask::DontInline;
+			case FunctionControlShift::Pure: return FunctionControlMask::Pure;
+			case FunctionControlShift::Const: return FunctionControlMask::Const;
+			case FunctionControlShift::OptNoneINTEL: return FunctionControlMask::OptNoneINTEL;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(FunctionControlMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<FunctionControlMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr MemorySemanticsMask operator|(MemorySemanticsMask l, MemorySemanticsMask r) { return static_cast<MemorySemanticsMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr MemorySemanticsMask operator&(MemorySemanticsMask l, MemorySemanticsMask r) { return static_cast<MemorySemanticsMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr MemorySemanticsMask operator^(MemorySemanticsMask l, MemorySemanticsMask r) { return static_cast<MemorySemanticsMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr MemorySemanticsMask operator~(MemorySemanticsMask m) { return static_cast<MemorySemanticsMask>(~static_cast<unsigned>(m)); }
+		inline constexpr MemorySemanticsMask& operator|=(MemorySemanticsMask& l, MemorySemanticsMask r) { return l = l | r; }
+		inline constexpr MemorySemanticsMask& operator&=(MemorySemanticsMask& l, MemorySemanticsMask r) { return l = l & r; }
+		inline constexpr MemorySemanticsMask& operator^=(MemorySemanticsMask& l, MemorySemanticsMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(MemorySemanticsMask mask, MemorySemanticsMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr MemorySemanticsMask MaskFromShift(MemorySemanticsShift shift) {
+			switch (shift) {
+			default: return static_cast<MemorySemanticsMask>(0u); // unknown shift
+			case MemorySemanticsShift::Relaxed: return MemorySemanticsMask::Relaxed;
+			case MemorySemanticsShift::Acquire: return MemorySemanticsMask::Acquire;
+			case MemorySemanticsShift::Release: return MemorySemanticsMask::Release;
+			case MemorySemanticsShift::AcquireRelease: return MemorySemanticsMask::AcquireRelease;
+			case MemorySemanticsShift::SequentiallyConsistent: return MemorySemanticsMask::SequentiallyConsistent;
+			case MemorySemanticsShift::UniformMemory: return MemorySemanticsMask::UniformMemory;
+			case MemorySemanticsShift::SubgroupMemory: return MemorySemanticsMask::SubgroupMemory;
+			case MemorySemanticsShift::WorkgroupMemory: return MemorySemanticsMask::WorkgroupMemory;
+			case MemorySemanticsShift::CrossWorkgroupMemory: return MemorySemanticsMask::CrossWorkgroupMemory;
+			case MemorySemanticsShift::AtomicCounterMemory: return MemorySemanticsMask::AtomicCounterMemory;
+			case MemorySemanticsShift::ImageMemory: return MemorySemanticsMask::ImageMemory;
+			case MemorySemanticsShift::OutputMemory: return MemorySemanticsMask::OutputMemory;
+			case MemorySemanticsShift::OutputMemoryKHR: return MemorySemanticsMask::OutputMemoryKHR;
+			case MemorySemanticsShift::MakeAvailable: return MemorySemanticsMask::MakeAvailable;
+			case MemorySemanticsShift::MakeAvailableKHR: return MemorySemanticsMask::MakeAvailableKHR;
+			case MemorySemanticsShift::MakeVisible: return MemorySemanticsMask::MakeVisible;
+			case MemorySemanticsShift::MakeVisibleKHR: return MemorySemanticsMask::MakeVisibleKHR;
+			case MemorySemanticsShift::Volatile: return MemorySemanticsMask::Volatile;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(MemorySemanticsMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<MemorySemanticsMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr MemoryAccessMask operator|(MemoryAccessMask l, MemoryAccessMask r) { return static_cast<MemoryAccessMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr MemoryAccessMask operator&(MemoryAccessMask l, MemoryAccessMask r) { return static_cast<MemoryAccessMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr MemoryAccessMask operator^(MemoryAccessMask l, MemoryAccessMask r) { return static_cast<MemoryAccessMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr MemoryAccessMask operator~(MemoryAccessMask m) { return static_cast<MemoryAccessMask>(~static_cast<unsigned>(m)); }
+		inline constexpr MemoryAccessMask& operator|=(MemoryAccessMask& l, MemoryAccessMask r) { return l = l | r; }
+		inline constexpr MemoryAccessMask& operator&=(MemoryAccessMask& l, MemoryAccessMask r) { return l = l & r; }
+		inline constexpr MemoryAccessMask& operator^=(MemoryAccessMask& l, MemoryAccessMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(MemoryAccessMask mask, MemoryAccessMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr MemoryAccessMask MaskFromShift(MemoryAccessShift shift) {
+			switch (shift) {
+			default: return static_cast<MemoryAccessMask>(0u); // unknown shift
+			case MemoryAccessShift::Volatile: return MemoryAccessMask::Volatile;
+			case MemoryAccessShift::Aligned: return MemoryAccessMask::Aligned;
+			case MemoryAccessShift::Nontemporal: return MemoryAccessMask::Nontemporal;
+			case MemoryAccessShift::MakePointerAvailable: return MemoryAccessMask::MakePointerAvailable;
+			case MemoryAccessShift::MakePointerAvailableKHR: return MemoryAccessMask::MakePointerAvailableKHR;
+			case MemoryAccessShift::MakePointerVisible: return MemoryAccessMask::MakePointerVisible;
+			case MemoryAccessShift::MakePointerVisibleKHR: return MemoryAccessMask::MakePointerVisibleKHR;
+			case MemoryAccessShift::NonPrivatePointer: return MemoryAccessMask::NonPrivatePointer;
+			case MemoryAccessShift::NonPrivatePointerKHR: return MemoryAccessMask::NonPrivatePointerKHR;
+			case MemoryAccessShift::AliasScopeINTELMask: return MemoryAccessMask::AliasScopeINTELMask;
+			case MemoryAccessShift::NoAliasINTELMask: return MemoryAccessMask::NoAliasINTELMask;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(MemoryAccessMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<MemoryAccessMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr KernelProfilingInfoMask operator|(KernelProfilingInfoMask l, KernelProfilingInfoMask r) { return static_cast<KernelProfilingInfoMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr KernelProfilingInfoMask operator&(KernelProfilingInfoMask l, KernelProfilingInfoMask r) { return static_cast<KernelProfilingInfoMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr KernelProfilingInfoMask operator^(KernelProfilingInfoMask l, KernelProfilingInfoMask r) { return static_cast<KernelProfilingInfoMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr KernelProfilingInfoMask operator~(KernelProfilingInfoMask m) { return static_cast<KernelProfilingInfoMask>(~static_cast<unsigned>(m)); }
+		inline constexpr KernelProfilingInfoMask& operator|=(KernelProfilingInfoMask& l, KernelProfilingInfoMask r) { return l = l | r; }
+		inline constexpr KernelProfilingInfoMask& operator&=(KernelProfilingInfoMask& l, KernelProfilingInfoMask r) { return l = l & r; }
+		inline constexpr KernelProfilingInfoMask& operator^=(KernelProfilingInfoMask& l, KernelProfilingInfoMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(KernelProfilingInfoMask mask, KernelProfilingInfoMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr KernelProfilingInfoMask MaskFromShift(KernelProfilingInfoShift shift) {
+			switch (shift) {
+			default: return static_cast<KernelProfilingInfoMask>(0u); // unknown shift
+			case KernelProfilingInfoShift::CmdExecTime: return KernelProfilingInfoMask::CmdExecTime;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(KernelProfilingInfoMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<KernelProfilingInfoMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr RayFlagsMask operator|(RayFlagsMask l, RayFlagsMask r) { return static_cast<RayFlagsMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr RayFlagsMask operator&(RayFlagsMask l, RayFlagsMask r) { return static_cast<RayFlagsMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr RayFlagsMask operator^(RayFlagsMask l, RayFlagsMask r) { return static_cast<RayFlagsMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr RayFlagsMask operator~(RayFlagsMask m) { return static_cast<RayFlagsMask>(~static_cast<unsigned>(m)); }
+		inline constexpr RayFlagsMask& operator|=(RayFlagsMask& l, RayFlagsMask r) { return l = l | r; }
+		inline constexpr RayFlagsMask& operator&=(RayFlagsMask& l, RayFlagsMask r) { return l = l & r; }
+		inline constexpr RayFlagsMask& operator^=(RayFlagsMask& l, RayFlagsMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(RayFlagsMask mask, RayFlagsMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr RayFlagsMask MaskFromShift(RayFlagsShift shift) {
+			switch (shift) {
+			default: return static_cast<RayFlagsMask>(0u); // unknown shift
+			case RayFlagsShift::NoneKHR: return RayFlagsMask::NoneKHR;
+			case RayFlagsShift::OpaqueKHR: return RayFlagsMask::OpaqueKHR;
+			case RayFlagsShift::NoOpaqueKHR: return RayFlagsMask::NoOpaqueKHR;
+			case RayFlagsShift::TerminateOnFirstHitKHR: return RayFlagsMask::TerminateOnFirstHitKHR;
+			case RayFlagsShift::SkipClosestHitShaderKHR: return RayFlagsMask::SkipClosestHitShaderKHR;
+			case RayFlagsShift::CullBackFacingTrianglesKHR: return RayFlagsMask::CullBackFacingTrianglesKHR;
+			case RayFlagsShift::CullFrontFacingTrianglesKHR: return RayFlagsMask::CullFrontFacingTrianglesKHR;
+			case RayFlagsShift::CullOpaqueKHR: return RayFlagsMask::CullOpaqueKHR;
+			case RayFlagsShift::CullNoOpaqueKHR: return RayFlagsMask::CullNoOpaqueKHR;
+			case RayFlagsShift::SkipTrianglesKHR: return RayFlagsMask::SkipTrianglesKHR;
+			case RayFlagsShift::SkipAABBsKHR: return RayFlagsMask::SkipAABBsKHR;
+			case RayFlagsShift::ForceOpacityMicromap2StateEXT: return RayFlagsMask::ForceOpacityMicromap2StateEXT;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(RayFlagsMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<RayFlagsMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr FragmentShadingRateMask operator|(FragmentShadingRateMask l, FragmentShadingRateMask r) { return static_cast<FragmentShadingRateMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr FragmentShadingRateMask operator&(FragmentShadingRateMask l, FragmentShadingRateMask r) { return static_cast<FragmentShadingRateMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr FragmentShadingRateMask operator^(FragmentShadingRateMask l, FragmentShadingRateMask r) { return static_cast<FragmentShadingRateMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr FragmentShadingRateMask operator~(FragmentShadingRateMask m) { return static_cast<FragmentShadingRateMask>(~static_cast<unsigned>(m)); }
+		inline constexpr FragmentShadingRateMask& operator|=(FragmentShadingRateMask& l, FragmentShadingRateMask r) { return l = l | r; }
+		inline constexpr FragmentShadingRateMask& operator&=(FragmentShadingRateMask& l, FragmentShadingRateMask r) { return l = l & r; }
+		inline constexpr FragmentShadingRateMask& operator^=(FragmentShadingRateMask& l, FragmentShadingRateMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(FragmentShadingRateMask mask, FragmentShadingRateMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr FragmentShadingRateMask MaskFromShift(FragmentShadingRateShift shift) {
+			switch (shift) {
+			default: return static_cast<FragmentShadingRateMask>(0u); // unknown shift
+			case FragmentShadingRateShift::Vertical2Pixels: return FragmentShadingRateMask::Vertical2Pixels;
+			case FragmentShadingRateShift::Vertical4Pixels: return FragmentShadingRateMask::Vertical4Pixels;
+			case FragmentShadingRateShift::Horizontal2Pixels: return FragmentShadingRateMask::Horizontal2Pixels;
+			case FragmentShadingRateShift::Horizontal4Pixels: return FragmentShadingRateMask::Horizontal4Pixels;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(FragmentShadingRateMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<FragmentShadingRateMask>(bits & (~bits + 1u))); }
+		}
+		inline constexpr CooperativeMatrixOperandsMask operator|(CooperativeMatrixOperandsMask l, CooperativeMatrixOperandsMask r) { return static_cast<CooperativeMatrixOperandsMask>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }
+		inline constexpr CooperativeMatrixOperandsMask operator&(CooperativeMatrixOperandsMask l, CooperativeMatrixOperandsMask r) { return static_cast<CooperativeMatrixOperandsMask>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }
+		inline constexpr CooperativeMatrixOperandsMask operator^(CooperativeMatrixOperandsMask l, CooperativeMatrixOperandsMask r) { return static_cast<CooperativeMatrixOperandsMask>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }
+		inline constexpr CooperativeMatrixOperandsMask operator~(CooperativeMatrixOperandsMask m) { return static_cast<CooperativeMatrixOperandsMask>(~static_cast<unsigned>(m)); }
+		inline constexpr CooperativeMatrixOperandsMask& operator|=(CooperativeMatrixOperandsMask& l, CooperativeMatrixOperandsMask r) { return l = l | r; }
+		inline constexpr CooperativeMatrixOperandsMask& operator&=(CooperativeMatrixOperandsMask& l, CooperativeMatrixOperandsMask r) { return l = l & r; }
+		inline constexpr CooperativeMatrixOperandsMask& operator^=(CooperativeMatrixOperandsMask& l, CooperativeMatrixOperandsMask r) { return l = l ^ r; }
+		inline constexpr bool HasFlag(CooperativeMatrixOperandsMask mask, CooperativeMatrixOperandsMask flag) { return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }
+		inline constexpr CooperativeMatrixOperandsMask MaskFromShift(CooperativeMatrixOperandsShift shift) {
+			switch (shift) {
+			default: return static_cast<CooperativeMatrixOperandsMask>(0u); // unknown shift
+			case CooperativeMatrixOperandsShift::NoneKHR: return CooperativeMatrixOperandsMask::NoneKHR;
+			case CooperativeMatrixOperandsShift::MatrixASignedComponentsKHR: return CooperativeMatrixOperandsMask::MatrixASignedComponentsKHR;
+			case CooperativeMatrixOperandsShift::MatrixBSignedComponentsKHR: return CooperativeMatrixOperandsMask::MatrixBSignedComponentsKHR;
+			case CooperativeMatrixOperandsShift::MatrixCSignedComponentsKHR: return CooperativeMatrixOperandsMask::MatrixCSignedComponentsKHR;
+			case CooperativeMatrixOperandsShift::MatrixResultSignedComponentsKHR: return CooperativeMatrixOperandsMask::MatrixResultSignedComponentsKHR;
+			case CooperativeMatrixOperandsShift::SaturatingAccumulationKHR: return CooperativeMatrixOperandsMask::SaturatingAccumulationKHR;
+			}
+		}
+		template <class Func>
+		inline constexpr void ForEachFlag(CooperativeMatrixOperandsMask mask, Func&& func) {
+			for (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) { func(static_cast<CooperativeMatrixOperandsMask>(bits & (~bits + 1u))); }
+		}
+	} // flags
+	template <class Enum>
+	inline constexpr unsigned int EnumerantCount = 0u;
+	template <>
+	inline constexpr unsigned int EnumerantCount<ImageOperandsMask> = 17u;
+	inline constexpr bool IsValidMask(ImageOperandsMask mask) { return (static_cast<unsigned>(mask) & ~0x17fffu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<FPFastMathModeMask> = 8u;
+	inline constexpr bool IsValidMask(FPFastMathModeMask mask) { return (static_cast<unsigned>(mask) & ~0x3001fu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<SelectionControlMask> = 3u;
+	inline constexpr bool IsValidMask(SelectionControlMask mask) { return (static_cast<unsigned>(mask) & ~0x3u) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<LoopControlMask> = 20u;
+	inline constexpr bool IsValidMask(LoopControlMask mask) { return (static_cast<unsigned>(mask) & ~0x3ff01ffu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<FunctionControlMask> = 6u;
+	inline constexpr bool IsValidMask(FunctionControlMask mask) { return (static_cast<unsigned>(mask) & ~0x1000fu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<MemorySemanticsMask> = 15u;
+	inline constexpr bool IsValidMask(MemorySemanticsMask mask) { return (static_cast<unsigned>(mask) & ~0xffdeu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<MemoryAccessMask> = 9u;
+	inline constexpr bool IsValidMask(MemoryAccessMask mask) { return (static_cast<unsigned>(mask) & ~0x3003fu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<KernelProfilingInfoMask> = 2u;
+	inline constexpr bool IsValidMask(KernelProfilingInfoMask mask) { return (static_cast<unsigned>(mask) & ~0x1u) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<RayFlagsMask> = 12u;
+	inline constexpr bool IsValidMask(RayFlagsMask mask) { return (static_cast<unsigned>(mask) & ~0x7ffu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<FragmentShadingRateMask> = 4u;
+	inline constexpr bool IsValidMask(FragmentShadingRateMask mask) { return (static_cast<unsigned>(mask) & ~0xfu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<SourceLanguage> = 11u;
+	inline constexpr bool IsValid(SourceLanguage value) {
+		switch (value) {
+		case SourceLanguage::Unknown:
+		case SourceLanguage::ESSL:
+		case SourceLanguage::GLSL:
+		case SourceLanguage::OpenCL_C:
+		case SourceLanguage::OpenCL_CPP:
+		case SourceLanguage::HLSL:
+		case SourceLanguage::CPP_for_OpenCL:
+		case SourceLanguage::SYCL:
+		case SourceLanguage::HERO_C:
+		case SourceLanguage::NZSL:
+		case SourceLanguage::WGSL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<ExecutionModel> = 17u;
+	inline constexpr bool IsValid(ExecutionModel value) {
+		switch (value) {
+		case ExecutionModel::Vertex:
+		case ExecutionModel::TessellationControl:
+		case ExecutionModel::TessellationEvaluation:
+		case ExecutionModel::Geometry:
+		case ExecutionModel::Fragment:
+		case ExecutionModel::GLCompute:
+		case ExecutionModel::Kernel:
+		case ExecutionModel::TaskNV:
+		case ExecutionModel::MeshNV:
+		case ExecutionModel::RayGenerationNV:
+		case ExecutionModel::IntersectionNV:
+		case ExecutionModel::AnyHitNV:
+		case ExecutionModel::ClosestHitNV:
+		case ExecutionModel::MissNV:
+		case ExecutionModel::CallableNV:
+		case ExecutionModel::TaskEXT:
+		case ExecutionModel::MeshEXT:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<AddressingModel> = 4u;
+	inline constexpr bool IsValid(AddressingModel value) {
+		switch (value) {
+		case AddressingModel::Logical:
+		case AddressingModel::Physical32:
+		case AddressingModel::Physical64:
+		case AddressingModel::PhysicalStorageBuffer64:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<MemoryModel> = 4u;
+	inline constexpr bool IsValid(MemoryModel value) {
+		switch (value) {
+		case MemoryModel::Simple:
+		case MemoryModel::GLSL450:
+		case MemoryModel::OpenCL:
+		case MemoryModel::Vulkan:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<ExecutionMode> = 85u;
+	inline constexpr bool IsValid(ExecutionMode value) {
+		switch (value) {
+		case ExecutionMode::Invocations:
+		case ExecutionMode::SpacingEqual:
+		case ExecutionMode::SpacingFractionalEven:
+		case ExecutionMode::SpacingFractionalOdd:
+		case ExecutionMode::VertexOrderCw:
+		case ExecutionMode::VertexOrderCcw:
+		case ExecutionMode::PixelCenterInteger:
+		case ExecutionMode::OriginUpperLeft:
+		case ExecutionMode::OriginLowerLeft:
+		case ExecutionMode::EarlyFragmentTests:
+		case ExecutionMode::PointMode:
+		case ExecutionMode::Xfb:
+		case ExecutionMode::DepthReplacing:
+		case ExecutionMode::DepthGreater:
+		case ExecutionMode::DepthLess:
+		case ExecutionMode::DepthUnchanged:
+		case ExecutionMode::LocalSize:
+		case ExecutionMode::LocalSizeHint:
+		case ExecutionMode::InputPoints:
+		case ExecutionMode::InputLines:
+		case ExecutionMode::InputLinesAdjacency:
+		case ExecutionMode::Triangles:
+		case ExecutionMode::InputTrianglesAdjacency:
+		case ExecutionMode::Quads:
+		case ExecutionMode::Isolines:
+		case ExecutionMode::OutputVertices:
+		case ExecutionMode::OutputPoints:
+		case ExecutionMode::OutputLineStrip:
+		case ExecutionMode::OutputTriangleStrip:
+		case ExecutionMode::VecTypeHint:
+		case ExecutionMode::ContractionOff:
+		case ExecutionMode::Initializer:
+		case ExecutionMode::Finalizer:
+		case ExecutionMode::SubgroupSize:
+		case ExecutionMode::SubgroupsPerWorkgroup:
+		case ExecutionMode::SubgroupsPerWorkgroupId:
+		case ExecutionMode::LocalSizeId:
+		case ExecutionMode::LocalSizeHintId:
+		case ExecutionMode::NonCoherentColorAttachmentReadEXT:
+		case ExecutionMode::NonCoherentDepthAttachmentReadEXT:
+		case ExecutionMode::NonCoherentStencilAttachmentReadEXT:
+		case ExecutionMode::SubgroupUniformControlFlowKHR:
+		case ExecutionMode::PostDepthCoverage:
+		case ExecutionMode::DenormPreserve:
+		case ExecutionMode::DenormFlushToZero:
+		case ExecutionMode::SignedZeroInfNanPreserve:
+		case ExecutionMode::RoundingModeRTE:
+		case ExecutionMode::RoundingModeRTZ:
+		case ExecutionMode::EarlyAndLateFragmentTestsAMD:
+		case ExecutionMode::StencilRefReplacingEXT:
+		case ExecutionMode::CoalescingAMDX:
+		case ExecutionMode::MaxNodeRecursionAMDX:
+		case ExecutionMode::StaticNumWorkgroupsAMDX:
+		case ExecutionMode::ShaderIndexAMDX:
+		case ExecutionMode::MaxNumWorkgroupsAMDX:
+		case ExecutionMode::StencilRefUnchangedFrontAMD:
+		case ExecutionMode::StencilRefGreaterFrontAMD:
+		case ExecutionMode::StencilRefLessFrontAMD:
+		case ExecutionMode::StencilRefUnchangedBackAMD:
+		case ExecutionMode::StencilRefGreaterBackAMD:
+		case ExecutionMode::StencilRefLessBackAMD:
+		case ExecutionMode::OutputLinesNV:
+		case ExecutionMode::OutputPrimitivesNV:
+		case ExecutionMode::DerivativeGroupQuadsNV:
+		case ExecutionMode::DerivativeGroupLinearNV:
+		case ExecutionMode::OutputTrianglesNV:
+		case ExecutionMode::PixelInterlockOrderedEXT:
+		case ExecutionMode::PixelInterlockUnorderedEXT:
+		case ExecutionMode::SampleInterlockOrderedEXT:
+		case ExecutionMode::SampleInterlockUnorderedEXT:
+		case ExecutionMode::ShadingRateInterlockOrderedEXT:
+		case ExecutionMode::ShadingRateInterlockUnorderedEXT:
+		case ExecutionMode::SharedLocalMemorySizeINTEL:
+		case ExecutionMode::RoundingModeRTPINTEL:
+		case ExecutionMode::RoundingModeRTNINTEL:
+		case ExecutionMode::FloatingPointModeALTINTEL:
+		case ExecutionMode::FloatingPointModeIEEEINTEL:
+		case ExecutionMode::MaxWorkgroupSizeINTEL:
+		case ExecutionMode::MaxWorkDimINTEL:
+		case ExecutionMode::NoGlobalOffsetINTEL:
+		case ExecutionMode::NumSIMDWorkitemsINTEL:
+		case ExecutionMode::SchedulerTargetFmaxMhzINTEL:
+		case ExecutionMode::StreamingInterfaceINTEL:
+		case ExecutionMode::RegisterMapInterfaceINTEL:
+		case ExecutionMode::NamedBarrierCountINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<StorageClass> = 28u;
+	inline constexpr bool IsValid(StorageClass value) {
+		switch (value) {
+		case StorageClass::UniformConstant:
+		case StorageClass::Input:
+		case StorageClass::Uniform:
+		case StorageClass::Output:
+		case StorageClass::Workgroup:
+		case StorageClass::CrossWorkgroup:
+		case StorageClass::Private:
+		case StorageClass::Function:
+		case StorageClass::Generic:
+		case StorageClass::PushConstant:
+		case StorageClass::AtomicCounter:
+		case StorageClass::Image:
+		case StorageClass::StorageBuffer:
+		case StorageClass::TileImageEXT:
+		case StorageClass::NodePayloadAMDX:
+		case StorageClass::NodeOutputPayloadAMDX:
+		case StorageClass::CallableDataNV:
+		case StorageClass::IncomingCallableDataNV:
+		case StorageClass::RayPayloadNV:
+		case StorageClass::HitAttributeNV:
+		case StorageClass::IncomingRayPayloadNV:
+		case StorageClass::ShaderRecordBufferNV:
+		case StorageClass::PhysicalStorageBuffer:
+		case StorageClass::HitObjectAttributeNV:
+		case StorageClass::TaskPayloadWorkgroupEXT:
+		case StorageClass::CodeSectionINTEL:
+		case StorageClass::DeviceOnlyINTEL:
+		case StorageClass::HostOnlyINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<Dim> = 8u;
+	inline constexpr bool IsValid(Dim value) {
+		switch (value) {
+		case Dim::Dim1D:
+		case Dim::Dim2D:
+		case Dim::Dim3D:
+		case Dim::Cube:
+		case Dim::Rect:
+		case Dim::Buffer:
+		case Dim::SubpassData:
+		case Dim::TileImageDataEXT:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<SamplerAddressingMode> = 5u;
+	inline constexpr bool IsValid(SamplerAddressingMode value) {
+		switch (value) {
+		case SamplerAddressingMode::None:
+		case SamplerAddressingMode::ClampToEdge:
+		case SamplerAddressingMode::Clamp:
+		case SamplerAddressingMode::Repeat:
+		case SamplerAddressingMode::RepeatMirrored:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<SamplerFilterMode> = 2u;
+	inline constexpr bool IsValid(SamplerFilterMode value) {
+		switch (value) {
+		case SamplerFilterMode::Nearest:
+		case SamplerFilterMode::Linear:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<ImageFormat> = 42u;
+	inline constexpr bool IsValid(ImageFormat value) {
+		switch (value) {
+		case ImageFormat::Unknown:
+		case ImageFormat::Rgba32f:
+		case ImageFormat::Rgba16f:
+		case ImageFormat::R32f:
+		case ImageFormat::Rgba8:
+		case ImageFormat::Rgba8Snorm:
+		case ImageFormat::Rg32f:
+		case ImageFormat::Rg16f:
+		case ImageFormat::R11fG11fB10f:
+		case ImageFormat::R16f:
+		case ImageFormat::Rgba16:
+		case ImageFormat::Rgb10A2:
+		case ImageFormat::Rg16:
+		case ImageFormat::Rg8:
+		case ImageFormat::R16:
+		case ImageFormat::R8:
+		case ImageFormat::Rgba16Snorm:
+		case ImageFormat::Rg16Snorm:
+		case ImageFormat::Rg8Snorm:
+		case ImageFormat::R16Snorm:
+		case ImageFormat::R8Snorm:
+		case ImageFormat::Rgba32i:
+		case ImageFormat::Rgba16i:
+		case ImageFormat::Rgba8i:
+		case ImageFormat::R32i:
+		case ImageFormat::Rg32i:
+		case ImageFormat::Rg16i:
+		case ImageFormat::Rg8i:
+		case ImageFormat::R16i:
+		case ImageFormat::R8i:
+		case ImageFormat::Rgba32ui:
+		case ImageFormat::Rgba16ui:
+		case ImageFormat::Rgba8ui:
+		case ImageFormat::R32ui:
+		case ImageFormat::Rgb10a2ui:
+		case ImageFormat::Rg32ui:
+		case ImageFormat::Rg16ui:
+		case ImageFormat::Rg8ui:
+		case ImageFormat::R16ui:
+		case ImageFormat::R8ui:
+		case ImageFormat::R64ui:
+		case ImageFormat::R64i:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<ImageChannelOrder> = 20u;
+	inline constexpr bool IsValid(ImageChannelOrder value) {
+		switch (value) {
+		case ImageChannelOrder::R:
+		case ImageChannelOrder::A:
+		case ImageChannelOrder::RG:
+		case ImageChannelOrder::RA:
+		case ImageChannelOrder::RGB:
+		case ImageChannelOrder::RGBA:
+		case ImageChannelOrder::BGRA:
+		case ImageChannelOrder::ARGB:
+		case ImageChannelOrder::Intensity:
+		case ImageChannelOrder::Luminance:
+		case ImageChannelOrder::Rx:
+		case ImageChannelOrder::RGx:
+		case ImageChannelOrder::RGBx:
+		case ImageChannelOrder::Depth:
+		case ImageChannelOrder::DepthStencil:
+		case ImageChannelOrder::sRGB:
+		case ImageChannelOrder::sRGBx:
+		case ImageChannelOrder::sRGBA:
+		case ImageChannelOrder::sBGRA:
+		case ImageChannelOrder::ABGR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<ImageChannelDataType> = 19u;
+	inline constexpr bool IsValid(ImageChannelDataType value) {
+		switch (value) {
+		case ImageChannelDataType::SnormInt8:
+		case ImageChannelDataType::SnormInt16:
+		case ImageChannelDataType::UnormInt8:
+		case ImageChannelDataType::UnormInt16:
+		case ImageChannelDataType::UnormShort565:
+		case ImageChannelDataType::UnormShort555:
+		case ImageChannelDataType::UnormInt101010:
+		case ImageChannelDataType::SignedInt8:
+		case ImageChannelDataType::SignedInt16:
+		case ImageChannelDataType::SignedInt32:
+		case ImageChannelDataType::UnsignedInt8:
+		case ImageChannelDataType::UnsignedInt16:
+		case ImageChannelDataType::UnsignedInt32:
+		case ImageChannelDataType::HalfFloat:
+		case ImageChannelDataType::Float:
+		case ImageChannelDataType::UnormInt24:
+		case ImageChannelDataType::UnormInt101010_2:
+		case ImageChannelDataType::UnsignedIntRaw10EXT:
+		case ImageChannelDataType::UnsignedIntRaw12EXT:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<FPRoundingMode> = 4u;
+	inline constexpr bool IsValid(FPRoundingMode value) {
+		switch (value) {
+		case FPRoundingMode::RTE:
+		case FPRoundingMode::RTZ:
+		case FPRoundingMode::RTP:
+		case FPRoundingMode::RTN:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<FPDenormMode> = 2u;
+	inline constexpr bool IsValid(FPDenormMode value) {
+		switch (value) {
+		case FPDenormMode::Preserve:
+		case FPDenormMode::FlushToZero:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<QuantizationModes> = 8u;
+	inline constexpr bool IsValid(QuantizationModes value) {
+		switch (value) {
+		case QuantizationModes::TRN:
+		case QuantizationModes::TRN_ZERO:
+		case QuantizationModes::RND:
+		case QuantizationModes::RND_ZERO:
+		case QuantizationModes::RND_INF:
+		case QuantizationModes::RND_MIN_INF:
+		case QuantizationModes::RND_CONV:
+		case QuantizationModes::RND_CONV_ODD:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<FPOperationMode> = 2u;
+	inline constexpr bool IsValid(FPOperationMode value) {
+		switch (value) {
+		case FPOperationMode::IEEE:
+		case FPOperationMode::ALT:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<OverflowModes> = 4u;
+	inline constexpr bool IsValid(OverflowModes value) {
+		switch (value) {
+		case OverflowModes::WRAP:
+		case OverflowModes::SAT:
+		case OverflowModes::SAT_ZERO:
+		case OverflowModes::SAT_SYM:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<LinkageType> = 3u;
+	inline constexpr bool IsValid(LinkageType value) {
+		switch (value) {
+		case LinkageType::Export:
+		case LinkageType::Import:
+		case LinkageType::LinkOnceODR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<AccessQualifier> = 3u;
+	inline constexpr bool IsValid(AccessQualifier value) {
+		switch (value) {
+		case AccessQualifier::ReadOnly:
+		case AccessQualifier::WriteOnly:
+		case AccessQualifier::ReadWrite:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<HostAccessQualifier> = 4u;
+	inline constexpr bool IsValid(HostAccessQualifier value) {
+		switch (value) {
+		case HostAccessQualifier::NoneINTEL:
+		case HostAccessQualifier::ReadINTEL:
+		case HostAccessQualifier::WriteINTEL:
+		case HostAccessQualifier::ReadWriteINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<FunctionParameterAttribute> = 9u;
+	inline constexpr bool IsValid(FunctionParameterAttribute value) {
+		switch (value) {
+		case FunctionParameterAttribute::Zext:
+		case FunctionParameterAttribute::Sext:
+		case FunctionParameterAttribute::ByVal:
+		case FunctionParameterAttribute::Sret:
+		case FunctionParameterAttribute::NoAlias:
+		case FunctionParameterAttribute::NoCapture:
+		case FunctionParameterAttribute::NoWrite:
+		case FunctionParameterAttribute::NoReadWrite:
+		case FunctionParameterAttribute::RuntimeAlignedINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<Decoration> = 133u;
+	inline constexpr bool IsValid(Decoration value) {
+		switch (value) {
+		case Decoration::RelaxedPrecision:
+		case Decoration::SpecId:
+		case Decoration::Block:
+		case Decoration::BufferBlock:
+		case Decoration::RowMajor:
+		case Decoration::ColMajor:
+		case Decoration::ArrayStride:
+		case Decoration::MatrixStride:
+		case Decoration::GLSLShared:
+		case Decoration::GLSLPacked:
+		case Decoration::CPacked:
+		case Decoration::BuiltIn:
+		case Decoration::NoPerspective:
+		case Decoration::Flat:
+		case Decoration::Patch:
+		case Decoration::Centroid:
+		case Decoration::Sample:
+		case Decoration::Invariant:
+		case Decoration::Restrict:
+		case Decoration::Aliased:
+		case Decoration::Volatile:
+		case Decoration::Constant:
+		case Decoration::Coherent:
+		case Decoration::NonWritable:
+		case Decoration::NonReadable:
+		case Decoration::Uniform:
+		case Decoration::UniformId:
+		case Decoration::SaturatedConversion:
+		case Decoration::Stream:
+		case Decoration::Location:
+		case Decoration::Component:
+		case Decoration::Index:
+		case Decoration::Binding:
+		case Decoration::DescriptorSet:
+		case Decoration::Offset:
+		case Decoration::XfbBuffer:
+		case Decoration::XfbStride:
+		case Decoration::FuncParamAttr:
+		case Decoration::FPRoundingMode:
+		case Decoration::FPFastMathMode:
+		case Decoration::LinkageAttributes:
+		case Decoration::NoContraction:
+		case Decoration::InputAttachmentIndex:
+		case Decoration::Alignment:
+		case Decoration::MaxByteOffset:
+		case Decoration::AlignmentId:
+		case Decoration::MaxByteOffsetId:
+		case Decoration::NoSignedWrap:
+		case Decoration::NoUnsignedWrap:
+		case Decoration::WeightTextureQCOM:
+		case Decoration::BlockMatchTextureQCOM:
+		case Decoration::ExplicitInterpAMD:
+		case Decoration::NodeSharesPayloadLimitsWithAMDX:
+		case Decoration::NodeMaxPayloadsAMDX:
+		case Decoration::TrackFinishWritingAMDX:
+		case Decoration::PayloadNodeNameAMDX:
+		case Decoration::OverrideCoverageNV:
+		case Decoration::PassthroughNV:
+		case Decoration::ViewportRelativeNV:
+		case Decoration::SecondaryViewportRelativeNV:
+		case Decoration::PerPrimitiveNV:
+		case Decoration::PerViewNV:
+		case Decoration::PerTaskNV:
+		case Decoration::PerVertexKHR:
+		case Decoration::NonUniform:
+		case Decoration::RestrictPointer:
+		case Decoration::AliasedPointer:
+		case Decoration::HitObjectShaderRecordBufferNV:
+		case Decoration::BindlessSamplerNV:
+		case Decoration::BindlessImageNV:
+		case Decoration::BoundSamplerNV:
+		case Decoration::BoundImageNV:
+		case Decoration::SIMTCallINTEL:
+		case Decoration::ReferencedIndirectlyINTEL:
+		case Decoration::ClobberINTEL:
+		case Decoration::SideEffectsINTEL:
+		case Decoration::VectorComputeVariableINTEL:
+		case Decoration::FuncParamIOKindINTEL:
+		case Decoration::VectorComputeFunctionINTEL:
+		case Decoration::StackCallINTEL:
+		case Decoration::GlobalVariableOffsetINTEL:
+		case Decoration::CounterBuffer:
+		case Decoration::UserSemantic:
+		case Decoration::UserTypeGOOGLE:
+		case Decoration::FunctionRoundingModeINTEL:
+		case Decoration::FunctionDenormModeINTEL:
+		case Decoration::RegisterINTEL:
+		case Decoration::MemoryINTEL:
+		case Decoration::NumbanksINTEL:
+		case Decoration::BankwidthINTEL:
+		case Decoration::MaxPrivateCopiesINTEL:
+		case Decoration::SinglepumpINTEL:
+		case Decoration::DoublepumpINTEL:
+		case Decoration::MaxReplicatesINTEL:
+		case Decoration::SimpleDualPortINTEL:
+		case Decoration::MergeINTEL:
+		case Decoration::BankBitsINTEL:
+		case Decoration::ForcePow2DepthINTEL:
+		case Decoration::BurstCoalesceINTEL:
+		case Decoration::CacheSizeINTEL:
+		case Decoration::DontStaticallyCoalesceINTEL:
+		case Decoration::PrefetchINTEL:
+		case Decoration::StallEnableINTEL:
+		case Decoration::FuseLoopsInFunctionINTEL:
+		case Decoration::MathOpDSPModeINTEL:
+		case Decoration::AliasScopeINTEL:
+		case Decoration::NoAliasINTEL:
+		case Decoration::InitiationIntervalINTEL:
+		case Decoration::MaxConcurrencyINTEL:
+		case Decoration::PipelineEnableINTEL:
+		case Decoration::BufferLocationINTEL:
+		case Decoration::IOPipeStorageINTEL:
+		case Decoration::FunctionFloatingPointModeINTEL:
+		case Decoration::SingleElementVectorINTEL:
+		case Decoration::VectorComputeCallableFunctionINTEL:
+		case Decoration::MediaBlockIOINTEL:
+		case Decoration::InitModeINTEL:
+		case Decoration::ImplementInRegisterMapINTEL:
+		case Decoration::HostAccessINTEL:
+		case Decoration::FPMaxErrorDecorationINTEL:
+		case Decoration::LatencyControlLabelINTEL:
+		case Decoration::LatencyControlConstraintINTEL:
+		case Decoration::ConduitKernelArgumentINTEL:
+		case Decoration::RegisterMapKernelArgumentINTEL:
+		case Decoration::MMHostInterfaceAddressWidthINTEL:
+		case Decoration::MMHostInterfaceDataWidthINTEL:
+		case Decoration::MMHostInterfaceLatencyINTEL:
+		case Decoration::MMHostInterfaceReadWriteModeINTEL:
+		case Decoration::MMHostInterfaceMaxBurstINTEL:
+		case Decoration::MMHostInterfaceWaitRequestINTEL:
+		case Decoration::StableKernelArgumentINTEL:
+		case Decoration::CacheControlLoadINTEL:
+		case Decoration::CacheControlStoreINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<BuiltIn> = 112u;
+	inline constexpr bool IsValid(BuiltIn value) {
+		switch (value) {
+		case BuiltIn::Position:
+		case BuiltIn::PointSize:
+		case BuiltIn::ClipDistance:
+		case BuiltIn::CullDistance:
+		case BuiltIn::VertexId:
+		case BuiltIn::InstanceId:
+		case BuiltIn::PrimitiveId:
+		case BuiltIn::InvocationId:
+		case BuiltIn::Layer:
+		case BuiltIn::ViewportIndex:
+		case BuiltIn::TessLevelOuter:
+		case BuiltIn::TessLevelInner:
+		case BuiltIn::TessCoord:
+		case BuiltIn::PatchVertices:
+		case BuiltIn::FragCoord:
+		case BuiltIn::PointCoord:
+		case BuiltIn::FrontFacing:
+		case BuiltIn::SampleId:
+		case BuiltIn::SamplePosition:
+		case BuiltIn::SampleMask:
+		case BuiltIn::FragDepth:
+		case BuiltIn::HelperInvocation:
+		case BuiltIn::NumWorkgroups:
+		case BuiltIn::WorkgroupSize:
+		case BuiltIn::WorkgroupId:
+		case BuiltIn::LocalInvocationId:
+		case BuiltIn::GlobalInvocationId:
+		case BuiltIn::LocalInvocationIndex:
+		case BuiltIn::WorkDim:
+		case BuiltIn::GlobalSize:
+		case BuiltIn::EnqueuedWorkgroupSize:
+		case BuiltIn::GlobalOffset:
+		case BuiltIn::GlobalLinearId:
+		case BuiltIn::SubgroupSize:
+		case BuiltIn::SubgroupMaxSize:
+		case BuiltIn::NumSubgroups:
+		case BuiltIn::NumEnqueuedSubgroups:
+		case BuiltIn::SubgroupId:
+		case BuiltIn::SubgroupLocalInvocationId:
+		case BuiltIn::VertexIndex:
+		case BuiltIn::InstanceIndex:
+		case BuiltIn::CoreIDARM:
+		case BuiltIn::CoreCountARM:
+		case BuiltIn::CoreMaxIDARM:
+		case BuiltIn::WarpIDARM:
+		case BuiltIn::WarpMaxIDARM:
+		case BuiltIn::SubgroupEqMask:
+		case BuiltIn::SubgroupGeMask:
+		case BuiltIn::SubgroupGtMask:
+		case BuiltIn::SubgroupLeMask:
+		case BuiltIn::SubgroupLtMask:
+		case BuiltIn::BaseVertex:
+		case BuiltIn::BaseInstance:
+		case BuiltIn::DrawIndex:
+		case BuiltIn::PrimitiveShadingRateKHR:
+		case BuiltIn::DeviceIndex:
+		case BuiltIn::ViewIndex:
+		case BuiltIn::ShadingRateKHR:
+		case BuiltIn::BaryCoordNoPerspAMD:
+		case BuiltIn::BaryCoordNoPerspCentroidAMD:
+		case BuiltIn::BaryCoordNoPerspSampleAMD:
+		case BuiltIn::BaryCoordSmoothAMD:
+		case BuiltIn::BaryCoordSmoothCentroidAMD:
+		case BuiltIn::BaryCoordSmoothSampleAMD:
+		case BuiltIn::BaryCoordPullModelAMD:
+		case BuiltIn::FragStencilRefEXT:
+		case BuiltIn::CoalescedInputCountAMDX:
+		case BuiltIn::ShaderIndexAMDX:
+		case BuiltIn::ViewportMaskNV:
+		case BuiltIn::SecondaryPositionNV:
+		case BuiltIn::SecondaryViewportMaskNV:
+		case BuiltIn::PositionPerViewNV:
+		case BuiltIn::ViewportMaskPerViewNV:
+		case BuiltIn::FullyCoveredEXT:
+		case BuiltIn::TaskCountNV:
+		case BuiltIn::PrimitiveCountNV:
+		case BuiltIn::PrimitiveIndicesNV:
+		case BuiltIn::ClipDistancePerViewNV:
+		case BuiltIn::CullDistancePerViewNV:
+		case BuiltIn::LayerPerViewNV:
+		case BuiltIn::MeshViewCountNV:
+		case BuiltIn::MeshViewIndicesNV:
+		case BuiltIn::BaryCoordKHR:
+		case BuiltIn::BaryCoordNoPerspKHR:
+		case BuiltIn::FragSizeEXT:
+		case BuiltIn::FragInvocationCountEXT:
+		case BuiltIn::PrimitivePointIndicesEXT:
+		case BuiltIn::PrimitiveLineIndicesEXT:
+		case BuiltIn::PrimitiveTriangleIndicesEXT:
+		case BuiltIn::CullPrimitiveEXT:
+		case BuiltIn::LaunchIdNV:
+		case BuiltIn::LaunchSizeNV:
+		case BuiltIn::WorldRayOriginNV:
+		case BuiltIn::WorldRayDirectionNV:
+		case BuiltIn::ObjectRayOriginNV:
+		case BuiltIn::ObjectRayDirectionNV:
+		case BuiltIn::RayTminNV:
+		case BuiltIn::RayTmaxNV:
+		case BuiltIn::InstanceCustomIndexNV:
+		case BuiltIn::ObjectToWorldNV:
+		case BuiltIn::WorldToObjectNV:
+		case BuiltIn::HitTNV:
+		case BuiltIn::HitKindNV:
+		case BuiltIn::CurrentRayTimeNV:
+		case BuiltIn::HitTriangleVertexPositionsKHR:
+		case BuiltIn::IncomingRayFlagsNV:
+		case BuiltIn::RayGeometryIndexKHR:
+		case BuiltIn::WarpsPerSMNV:
+		case BuiltIn::SMCountNV:
+		case BuiltIn::WarpIDNV:
+		case BuiltIn::SMIDNV:
+		case BuiltIn::CullMaskKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<Scope> = 7u;
+	inline constexpr bool IsValid(Scope value) {
+		switch (value) {
+		case Scope::CrossDevice:
+		case Scope::Device:
+		case Scope::Workgroup:
+		case Scope::Subgroup:
+		case Scope::Invocation:
+		case Scope::QueueFamily:
+		case Scope::ShaderCallKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<GroupOperation> = 7u;
+	inline constexpr bool IsValid(GroupOperation value) {
+		switch (value) {
+		case GroupOperation::Reduce:
+		case GroupOperation::InclusiveScan:
+		case GroupOperation::ExclusiveScan:
+		case GroupOperation::ClusteredReduce:
+		case GroupOperation::PartitionedReduceNV:
+		case GroupOperation::PartitionedInclusiveScanNV:
+		case GroupOperation::PartitionedExclusiveScanNV:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<KernelEnqueueFlags> = 3u;
+	inline constexpr bool IsValid(KernelEnqueueFlags value) {
+		switch (value) {
+		case KernelEnqueueFlags::NoWait:
+		case KernelEnqueueFlags::WaitKernel:
+		case KernelEnqueueFlags::WaitWorkGroup:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<Capability> = 224u;
+	inline constexpr bool IsValid(Capability value) {
+		switch (value) {
+		case Capability::Matrix:
+		case Capability::Shader:
+		case Capability::Geometry:
+		case Capability::Tessellation:
+		case Capability::Addresses:
+		case Capability::Linkage:
+		case Capability::Kernel:
+		case Capability::Vector16:
+		case Capability::Float16Buffer:
+		case Capability::Float16:
+		case Capability::Float64:
+		case Capability::Int64:
+		case Capability::Int64Atomics:
+		case Capability::ImageBasic:
+		case Capability::ImageReadWrite:
+		case Capability::ImageMipmap:
+		case Capability::Pipes:
+		case Capability::Groups:
+		case Capability::DeviceEnqueue:
+		case Capability::LiteralSampler:
+		case Capability::AtomicStorage:
+		case Capability::Int16:
+		case Capability::TessellationPointSize:
+		case Capability::GeometryPointSize:
+		case Capability::ImageGatherExtended:
+		case Capability::StorageImageMultisample:
+		case Capability::UniformBufferArrayDynamicIndexing:
+		case Capability::SampledImageArrayDynamicIndexing:
+		case Capability::StorageBufferArrayDynamicIndexing:
+		case Capability::StorageImageArrayDynamicIndexing:
+		case Capability::ClipDistance:
+		case Capability::CullDistance:
+		case Capability::ImageCubeArray:
+		case Capability::SampleRateShading:
+		case Capability::ImageRect:
+		case Capability::SampledRect:
+		case Capability::GenericPointer:
+		case Capability::Int8:
+		case Capability::InputAttachment:
+		case Capability::SparseResidency:
+		case Capability::MinLod:
+		case Capability::Sampled1D:
+		case Capability::Image1D:
+		case Capability::SampledCubeArray:
+		case Capability::SampledBuffer:
+		case Capability::ImageBuffer:
+		case Capability::ImageMSArray:
+		case Capability::StorageImageExtendedFormats:
+		case Capability::ImageQuery:
+		case Capability::DerivativeControl:
+		case Capability::InterpolationFunction:
+		case Capability::TransformFeedback:
+		case Capability::GeometryStreams:
+		case Capability::StorageImageReadWithoutFormat:
+		case Capability::StorageImageWriteWithoutFormat:
+		case Capability::MultiViewport:
+		case Capability::SubgroupDispatch:
+		case Capability::NamedBarrier:
+		case Capability::PipeStorage:
+		case Capability::GroupNonUniform:
+		case Capability::GroupNonUniformVote:
+		case Capability::GroupNonUniformArithmetic:
+		case Capability::GroupNonUniformBallot:
+		case Capability::GroupNonUniformShuffle:
+		case Capability::GroupNonUniformShuffleRelative:
+		case Capability::GroupNonUniformClustered:
+		case Capability::GroupNonUniformQuad:
+		case Capability::ShaderLayer:
+		case Capability::ShaderViewportIndex:
+		case Capability::UniformDecoration:
+		case Capability::CoreBuiltinsARM:
+		case Capability::TileImageColorReadAccessEXT:
+		case Capability::TileImageDepthReadAccessEXT:
+		case Capability::TileImageStencilReadAccessEXT:
+		case Capability::FragmentShadingRateKHR:
+		case Capability::SubgroupBallotKHR:
+		case Capability::DrawParameters:
+		case Capability::WorkgroupMemoryExplicitLayoutKHR:
+		case Capability::WorkgroupMemoryExplicitLayout8BitAccessKHR:
+		case Capability::WorkgroupMemoryExplicitLayout16BitAccessKHR:
+		case Capability::SubgroupVoteKHR:
+		case Capability::StorageBuffer16BitAccess:
+		case Capability::UniformAndStorageBuffer16BitAccess:
+		case Capability::StoragePushConstant16:
+		case Capability::StorageInputOutput16:
+		case Capability::DeviceGroup:
+		case Capability::MultiView:
+		case Capability::VariablePointersStorageBuffer:
+		case Capability::VariablePointers:
+		case Capability::AtomicStorageOps:
+		case Capability::SampleMaskPostDepthCoverage:
+		case Capability::StorageBuffer8BitAccess:
+		case Capability::UniformAndStorageBuffer8BitAccess:
+		case Capability::StoragePushConstant8:
+		case Capability::DenormPreserve:
+		case Capability::DenormFlushToZero:
+		case Capability::SignedZeroInfNanPreserve:
+		case Capability::RoundingModeRTE:
+		case Capability::RoundingModeRTZ:
+		case Capability::RayQueryProvisionalKHR:
+		case Capability::RayQueryKHR:
+		case Capability::RayTraversalPrimitiveCullingKHR:
+		case Capability::RayTracingKHR:
+		case Capability::TextureSampleWeightedQCOM:
+		case Capability::TextureBoxFilterQCOM:
+		case Capability::TextureBlockMatchQCOM:
+		case Capability::Float16ImageAMD:
+		case Capability::ImageGatherBiasLodAMD:
+		case Capability::FragmentMaskAMD:
+		case Capability::StencilExportEXT:
+		case Capability::ImageReadWriteLodAMD:
+		case Capability::Int64ImageEXT:
+		case Capability::ShaderClockKHR:
+		case Capability::ShaderEnqueueAMDX:
+		case Capability::SampleMaskOverrideCoverageNV:
+		case Capability::GeometryShaderPassthroughNV:
+		case Capability::ShaderViewportIndexLayerEXT:
+		case Capability::ShaderViewportMaskNV:
+		case Capability::ShaderStereoViewNV:
+		case Capability::PerViewAttributesNV:
+		case Capability::FragmentFullyCoveredEXT:
+		case Capability::MeshShadingNV:
+		case Capability::ImageFootprintNV:
+		case Capability::MeshShadingEXT:
+		case Capability::FragmentBarycentricKHR:
+		case Capability::ComputeDerivativeGroupQuadsNV:
+		case Capability::FragmentDensityEXT:
+		case Capability::GroupNonUniformPartitionedNV:
+		case Capability::ShaderNonUniform:
+		case Capability::RuntimeDescriptorArray:
+		case Capability::InputAttachmentArrayDynamicIndexing:
+		case Capability::UniformTexelBufferArrayDynamicIndexing:
+		case Capability::StorageTexelBufferArrayDynamicIndexing:
+		case Capability::UniformBufferArrayNonUniformIndexing:
+		case Capability::SampledImageArrayNonUniformIndexing:
+		case Capability::StorageBufferArrayNonUniformIndexing:
+		case Capability::StorageImageArrayNonUniformIndexing:
+		case Capability::InputAttachmentArrayNonUniformIndexing:
+		case Capability::UniformTexelBufferArrayNonUniformIndexing:
+		case Capability::StorageTexelBufferArrayNonUniformIndexing:
+		case Capability::RayTracingPositionFetchKHR:
+		case Capability::RayTracingNV:
+		case Capability::RayTracingMotionBlurNV:
+		case Capability::VulkanMemoryModel:
+		case Capability::VulkanMemoryModelDeviceScope:
+		case Capability::PhysicalStorageBufferAddresses:
+		case Capability::ComputeDerivativeGroupLinearNV:
+		case Capability::RayTracingProvisionalKHR:
+		case Capability::CooperativeMatrixNV:
+		case Capability::FragmentShaderSampleInterlockEXT:
+		case Capability::FragmentShaderShadingRateInterlockEXT:
+		case Capability::ShaderSMBuiltinsNV:
+		case Capability::FragmentShaderPixelInterlockEXT:
+		case Capability::DemoteToHelperInvocation:
+		case Capability::RayTracingOpacityMicromapEXT:
+		case Capability::ShaderInvocationReorderNV:
+		case Capability::BindlessTextureNV:
+		case Capability::RayQueryPositionFetchKHR:
+		case Capability::SubgroupShuffleINTEL:
+		case Capability::SubgroupBufferBlockIOINTEL:
+		case Capability::SubgroupImageBlockIOINTEL:
+		case Capability::SubgroupImageMediaBlockIOINTEL:
+		case Capability::RoundToInfinityINTEL:
+		case Capability::FloatingPointModeINTEL:
+		case Capability::IntegerFunctions2INTEL:
+		case Capability::FunctionPointersINTEL:
+		case Capability::IndirectReferencesINTEL:
+		case Capability::AsmINTEL:
+		case Capability::AtomicFloat32MinMaxEXT:
+		case Capability::AtomicFloat64MinMaxEXT:
+		case Capability::AtomicFloat16MinMaxEXT:
+		case Capability::VectorComputeINTEL:
+		case Capability::VectorAnyINTEL:
+		case Capability::ExpectAssumeKHR:
+		case Capability::SubgroupAvcMotionEstimationINTEL:
+		case Capability::SubgroupAvcMotionEstimationIntraINTEL:
+		case Capability::SubgroupAvcMotionEstimationChromaINTEL:
+		case Capability::VariableLengthArrayINTEL:
+		case Capability::FunctionFloatControlINTEL:
+		case Capability::FPGAMemoryAttributesINTEL:
+		case Capability::FPFastMathModeINTEL:
+		case Capability::ArbitraryPrecisionIntegersINTEL:
+		case Capability::ArbitraryPrecisionFloatingPointINTEL:
+		case Capability::UnstructuredLoopControlsINTEL:
+		case Capability::FPGALoopControlsINTEL:
+		case Capability::KernelAttributesINTEL:
+		case Capability::FPGAKernelAttributesINTEL:
+		case Capability::FPGAMemoryAccessesINTEL:
+		case Capability::FPGAClusterAttributesINTEL:
+		case Capability::LoopFuseINTEL:
+		case Capability::FPGADSPControlINTEL:
+		case Capability::MemoryAccessAliasingINTEL:
+		case Capability::FPGAInvocationPipeliningAttributesINTEL:
+		case Capability::FPGABufferLocationINTEL:
+		case Capability::ArbitraryPrecisionFixedPointINTEL:
+		case Capability::USMStorageClassesINTEL:
+		case Capability::RuntimeAlignedAttributeINTEL:
+		case Capability::IOPipesINTEL:
+		case Capability::BlockingPipesINTEL:
+		case Capability::FPGARegINTEL:
+		case Capability::DotProductInputAll:
+		case Capability::DotProductInput4x8Bit:
+		case Capability::DotProductInput4x8BitPacked:
+		case Capability::DotProduct:
+		case Capability::RayCullMaskKHR:
+		case Capability::CooperativeMatrixKHR:
+		case Capability::BitInstructions:
+		case Capability::GroupNonUniformRotateKHR:
+		case Capability::AtomicFloat32AddEXT:
+		case Capability::AtomicFloat64AddEXT:
+		case Capability::LongConstantCompositeINTEL:
+		case Capability::OptNoneINTEL:
+		case Capability::AtomicFloat16AddEXT:
+		case Capability::DebugInfoModuleINTEL:
+		case Capability::BFloat16ConversionINTEL:
+		case Capability::SplitBarrierINTEL:
+		case Capability::GlobalVariableFPGADecorationsINTEL:
+		case Capability::FPGAKernelAttributesv2INTEL:
+		case Capability::GlobalVariableHostAccessINTEL:
+		case Capability::FPMaxErrorINTEL:
+		case Capability::FPGALatencyControlINTEL:
+		case Capability::FPGAArgumentInterfacesINTEL:
+		case Capability::GroupUniformArithmeticKHR:
+		case Capability::CacheControlsINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<RayQueryIntersection> = 2u;
+	inline constexpr bool IsValid(RayQueryIntersection value) {
+		switch (value) {
+		case RayQueryIntersection::RayQueryCandidateIntersectionKHR:
+		case RayQueryIntersection::RayQueryCommittedIntersectionKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<RayQueryCommittedIntersectionType> = 3u;
+	inline constexpr bool IsValid(RayQueryCommittedIntersectionType value) {
+		switch (value) {
+		case RayQueryCommittedIntersectionType::RayQueryCommittedIntersectionNoneKHR:
+		case RayQueryCommittedIntersectionType::RayQueryCommittedIntersectionTriangleKHR:
+		case RayQueryCommittedIntersectionType::RayQueryCommittedIntersectionGeneratedKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<RayQueryCandidateIntersectionType> = 2u;
+	inline constexpr bool IsValid(RayQueryCandidateIntersectionType value) {
+		switch (value) {
+		case RayQueryCandidateIntersectionType::RayQueryCandidateIntersectionTriangleKHR:
+		case RayQueryCandidateIntersectionType::RayQueryCandidateIntersectionAABBKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<PackedVectorFormat> = 1u;
+	inline constexpr bool IsValid(PackedVectorFormat value) {
+		switch (value) {
+		case PackedVectorFormat::PackedVectorFormat4x8Bit:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<CooperativeMatrixOperandsMask> = 6u;
+	inline constexpr bool IsValidMask(CooperativeMatrixOperandsMask mask) { return (static_cast<unsigned>(mask) & ~0x1fu) == 0u; }
+	template <>
+	inline constexpr unsigned int EnumerantCount<CooperativeMatrixLayout> = 2u;
+	inline constexpr bool IsValid(CooperativeMatrixLayout value) {
+		switch (value) {
+		case CooperativeMatrixLayout::RowMajorKHR:
+		case CooperativeMatrixLayout::ColumnMajorKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<CooperativeMatrixUse> = 3u;
+	inline constexpr bool IsValid(CooperativeMatrixUse value) {
+		switch (value) {
+		case CooperativeMatrixUse::MatrixAKHR:
+		case CooperativeMatrixUse::MatrixBKHR:
+		case CooperativeMatrixUse::MatrixAccumulatorKHR:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<InitializationModeQualifier> = 2u;
+	inline constexpr bool IsValid(InitializationModeQualifier value) {
+		switch (value) {
+		case InitializationModeQualifier::InitOnDeviceReprogramINTEL:
+		case InitializationModeQualifier::InitOnDeviceResetINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<LoadCacheControl> = 5u;
+	inline constexpr bool IsValid(LoadCacheControl value) {
+		switch (value) {
+		case LoadCacheControl::UncachedINTEL:
+		case LoadCacheControl::CachedINTEL:
+		case LoadCacheControl::StreamingINTEL:
+		case LoadCacheControl::InvalidateAfterReadINTEL:
+		case LoadCacheControl::ConstCachedINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<StoreCacheControl> = 4u;
+	inline constexpr bool IsValid(StoreCacheControl value) {
+		switch (value) {
+		case StoreCacheControl::UncachedINTEL:
+		case StoreCacheControl::WriteThroughINTEL:
+		case StoreCacheControl::WriteBackINTEL:
+		case StoreCacheControl::StreamingINTEL:
+			return true;
+		default: return false;
+		}
+	}
+	template <>
+	inline constexpr unsigned int EnumerantCount<Op> = 710u;
+	// HasResult and friends fall back to the common case for unknown opcodes, check IsValid first
+	inline constexpr bool IsValid(Op opcode) {
+		switch (opcode) {
+		case Op::OpNop:
+		case Op::OpUndef:
+		case Op::OpSourceContinued:
+		case Op::OpSource:
+		case Op::OpSourceExtension:
+		case Op::OpName:
+		case Op::OpMemberName:
+		case Op::OpString:
+		case Op::OpLine:
+		case Op::OpExtension:
+		case Op::OpExtInstImport:
+		case Op::OpExtInst:
+		case Op::OpMemoryModel:
+		case Op::OpEntryPoint:
+		case Op::OpExecutionMode:
+		case Op::OpCapability:
+		case Op::OpTypeVoid:
+		case Op::OpTypeBool:
+		case Op::OpTypeInt:
+		case Op::OpTypeFloat:
+		case Op::OpTypeVector:
+		case Op::OpTypeMatrix:
+		case Op::OpTypeImage:
+		case Op::OpTypeSampler:
+		case Op::OpTypeSampledImage:
+		case Op::OpTypeArray:
+		case Op::OpTypeRuntimeArray:
+		case Op::OpTypeStruct:
+		case Op::OpTypeOpaque:
+		case Op::OpTypePointer:
+		case Op::OpTypeFunction:
+		case Op::OpTypeEvent:
+		case Op::OpTypeDeviceEvent:
+		case Op::OpTypeReserveId:
+		case Op::OpTypeQueue:
+		case Op::OpTypePipe:
+		case Op::OpTypeForwardPointer:
+		case Op::OpConstantTrue:
+		case Op::OpConstantFalse:
+		case Op::OpConstant:
+		case Op::OpConstantComposite:
+		case Op::OpConstantSampler:
+		case Op::OpConstantNull:
+		case Op::OpSpecConstantTrue:
+		case Op::OpSpecConstantFalse:
+		case Op::OpSpecConstant:
+		case Op::OpSpecConstantComposite:
+		case Op::OpSpecConstantOp:
+		case Op::OpFunction:
+		case Op::OpFunctionParameter:
+		case Op::OpFunctionEnd:
+		case Op::OpFunctionCall:
+		case Op::OpVariable:
+		case Op::OpImageTexelPointer:
+		case Op::OpLoad:
+		case Op::OpStore:
+		case Op::OpCopyMemory:
+		case Op::OpCopyMemorySized:
+		case Op::OpAccessChain:
+		case Op::OpInBoundsAccessChain:
+		case Op::OpPtrAccessChain:
+		case Op::OpArrayLength:
+		case Op::OpGenericPtrMemSemantics:
+		case Op::OpInBoundsPtrAccessChain:
+		case Op::OpDecorate:
+		case Op::OpMemberDecorate:
+		case Op::OpDecorationGroup:
+		case Op::OpGroupDecorate:
+		case Op::OpGroupMemberDecorate:
+		case Op::OpVectorExtractDynamic:
+		case Op::OpVectorInsertDynamic:
+		case Op::OpVectorShuffle:
+		case Op::OpCompositeConstruct:
+		case Op::OpCompositeExtract:
+		case Op::OpCompositeInsert:
+		case Op::OpCopyObject:
+		case Op::OpTranspose:
+		case Op::OpSampledImage:
+		case Op::OpImageSampleImplicitLod:
+		case Op::OpImageSampleExplicitLod:
+		case Op::OpImageSampleDrefImplicitLod:
+		case Op::OpImageSampleDrefExplicitLod:
+		case Op::OpImageSampleProjImplicitLod:
+		case Op::OpImageSampleProjExplicitLod:
+		case Op::OpImageSampleProjDrefImplicitLod:
+		case Op::OpImageSampleProjDrefExplicitLod:
+		case Op::OpImageFetch:
+		case Op::OpImageGather:
+		case Op::OpImageDrefGather:
+		case Op::OpImageRead:
+		case Op::OpImageWrite:
+		case Op::OpImage:
+		case Op::OpImageQueryFormat:
+		case Op::OpImageQueryOrder:
+		case Op::OpImageQuerySizeLod:
+		case Op::OpImageQuerySize:
+		case Op::OpImageQueryLod:
+		case Op::OpImageQueryLevels:
+		case Op::OpImageQuerySamples:
+		case Op::OpConvertFToU:
+		case Op::OpConvertFToS:
+		case Op::OpConvertSToF:
+		case Op::OpConvertUToF:
+		case Op::OpUConvert:
+		case Op::OpSConvert:
+		case Op::OpFConvert:
+		case Op::OpQuantizeToF16:
+		case Op::OpConvertPtrToU:
+		case Op::OpSatConvertSToU:
+		case Op::OpSatConvertUToS:
+		case Op::OpConvertUToPtr:
+		case Op::OpPtrCastToGeneric:
+		case Op::OpGenericCastToPtr:
+		case Op::OpGenericCastToPtrExplicit:
+		case Op::OpBitcast:
+		case Op::OpSNegate:
+		case Op::OpFNegate:
+		case Op::OpIAdd:
+		case Op::OpFAdd:
+		case Op::OpISub:
+		case Op::OpFSub:
+		case Op::OpIMul:
+		case Op::OpFMul:
+		case Op::OpUDiv:
+		case Op::OpSDiv:
+		case Op::OpFDiv:
+		case Op::OpUMod:
+		case Op::OpSRem:
+		case Op::OpSMod:
+		case Op::OpFRem:
+		case Op::OpFMod:
+		case Op::OpVectorTimesScalar:
+		case Op::OpMatrixTimesScalar:
+		case Op::OpVectorTimesMatrix:
+		case Op::OpMatrixTimesVector:
+		case Op::OpMatrixTimesMatrix:
+		case Op::OpOuterProduct:
+		case Op::OpDot:
+		case Op::OpIAddCarry:
+		case Op::OpISubBorrow:
+		case Op::OpUMulExtended:
+		case Op::OpSMulExtended:
+		case Op::OpAny:
+		case Op::OpAll:
+		case Op::OpIsNan:
+		case Op::OpIsInf:
+		case Op::OpIsFinite:
+		case Op::OpIsNormal:
+		case Op::OpSignBitSet:
+		case Op::OpLessOrGreater:
+		case Op::OpOrdered:
+		case Op::OpUnordered:
+		case Op::OpLogicalEqual:
+		case Op::OpLogicalNotEqual:
+		case Op::OpLogicalOr:
+		case Op::OpLogicalAnd:
+		case Op::OpLogicalNot:
+		case Op::OpSelect:
+		case Op::OpIEqual:
+		case Op::OpINotEqual:
+		case Op::OpUGreaterThan:
+		case Op::OpSGreaterThan:
+		case Op::OpUGreaterThanEqual:
+		case Op::OpSGreaterThanEqual:
+		case Op::OpULessThan:
+		case Op::OpSLessThan:
+		case Op::OpULessThanEqual:
+		case Op::OpSLessThanEqual:
+		case Op::OpFOrdEqual:
+		case Op::OpFUnordEqual:
+		case Op::OpFOrdNotEqual:
+		case Op::OpFUnordNotEqual:
+		case Op::OpFOrdLessThan:
+		case Op::OpFUnordLessThan:
+		case Op::OpFOrdGreaterThan:
+		case Op::OpFUnordGreaterThan:
+		case Op::OpFOrdLessThanEqual:
+		case Op::OpFUnordLessThanEqual:
+		case Op::OpFOrdGreaterThanEqual:
+		case Op::OpFUnordGreaterThanEqual:
+		case Op::OpShiftRightLogical:
+		case Op::OpShiftRightArithmetic:
+		case Op::OpShiftLeftLogical:
+		case Op::OpBitwiseOr:
+		case Op::OpBitwiseXor:
+		case Op::OpBitwiseAnd:
+		case Op::OpNot:
+		case Op::OpBitFieldInsert:
+		case Op::OpBitFieldSExtract:
+		case Op::OpBitFieldUExtract:
+		case Op::OpBitReverse:
+		case Op::OpBitCount:
+		case Op::OpDPdx:
+		case Op::OpDPdy:
+		case Op::OpFwidth:
+		case Op::OpDPdxFine:
+		case Op::OpDPdyFine:
+		case Op::OpFwidthFine:
+		case Op::OpDPdxCoarse:
+		case Op::OpDPdyCoarse:
+		case Op::OpFwidthCoarse:
+		case Op::OpEmitVertex:
+		case Op::OpEndPrimitive:
+		case Op::OpEmitStreamVertex:
+		case Op::OpEndStreamPrimitive:
+		case Op::OpControlBarrier:
+		case Op::OpMemoryBarrier:
+		case Op::OpAtomicLoad:
+		case Op::OpAtomicStore:
+		case Op::OpAtomicExchange:
+		case Op::OpAtomicCompareExchange:
+		case Op::OpAtomicCompareExchangeWeak:
+		case Op::OpAtomicIIncrement:
+		case Op::OpAtomicIDecrement:
+		case Op::OpAtomicIAdd:
+		case Op::OpAtomicISub:
+		case Op::OpAtomicSMin:
+		case Op::OpAtomicUMin:
+		case Op::OpAtomicSMax:
+		case Op::OpAtomicUMax:
+		case Op::OpAtomicAnd:
+		case Op::OpAtomicOr:
+		case Op::OpAtomicXor:
+		case Op::OpPhi:
+		case Op::OpLoopMerge:
+		case Op::OpSelectionMerge:
+		case Op::OpLabel:
+		case Op::OpBranch:
+		case Op::OpBranchConditional:
+		case Op::OpSwitch:
+		case Op::OpKill:
+		case Op::OpReturn:
+		case Op::OpReturnValue:
+		case Op::OpUnreachable:
+		case Op::OpLifetimeStart:
+		case Op::OpLifetimeStop:
+		case Op::OpGroupAsyncCopy:
+		case Op::OpGroupWaitEvents:
+		case Op::OpGroupAll:
+		case Op::OpGroupAny:
+		case Op::OpGroupBroadcast:
+		case Op::OpGroupIAdd:
+		case Op::OpGroupFAdd:
+		case Op::OpGroupFMin:
+		case Op::OpGroupUMin:
+		case Op::OpGroupSMin:
+		case Op::OpGroupFMax:
+		case Op::OpGroupUMax:
+		case Op::OpGroupSMax:
+		case Op::OpReadPipe:
+		case Op::OpWritePipe:
+		case Op::OpReservedReadPipe:
+		case Op::OpReservedWritePipe:
+		case Op::OpReserveReadPipePackets:
+		case Op::OpReserveWritePipePackets:
+		case Op::OpCommitReadPipe:
+		case Op::OpCommitWritePipe:
+		case Op::OpIsValidReserveId:
+		case Op::OpGetNumPipePackets:
+		case Op::OpGetMaxPipePackets:
+		case Op::OpGroupReserveReadPipePackets:
+		case Op::OpGroupReserveWritePipePackets:
+		case Op::OpGroupCommitReadPipe:
+		case Op::OpGroupCommitWritePipe:
+		case Op::OpEnqueueMarker:
+		case Op::OpEnqueueKernel:
+		case Op::OpGetKernelNDrangeSubGroupCount:
+		case Op::OpGetKernelNDrangeMaxSubGroupSize:
+		case Op::OpGetKernelWorkGroupSize:
+		case Op::OpGetKernelPreferredWorkGroupSizeMultiple:
+		case Op::OpRetainEvent:
+		case Op::OpReleaseEvent:
+		case Op::OpCreateUserEvent:
+		case Op::OpIsValidEvent:
+		case Op::OpSetUserEventStatus:
+		case Op::OpCaptureEventProfilingInfo:
+		case Op::OpGetDefaultQueue:
+		case Op::OpBuildNDRange:
+		case Op::OpImageSparseSampleImplicitLod:
+		case Op::OpImageSparseSampleExplicitLod:
+		case Op::OpImageSparseSampleDrefImplicitLod:
+		case Op::OpImageSparseSampleDrefExplicitLod:
+		case Op::OpImageSparseSampleProjImplicitLod:
+		case Op::OpImageSparseSampleProjExplicitLod:
+		case Op::OpImageSparseSampleProjDrefImplicitLod:
+		case Op::OpImageSparseSampleProjDrefExplicitLod:
+		case Op::OpImageSparseFetch:
+		case Op::OpImageSparseGather:
+		case Op::OpImageSparseDrefGather:
+		case Op::OpImageSparseTexelsResident:
+		case Op::OpNoLine:
+		case Op::OpAtomicFlagTestAndSet:
+		case Op::OpAtomicFlagClear:
+		case Op::OpImageSparseRead:
+		case Op::OpSizeOf:
+		case Op::OpTypePipeStorage:
+		case Op::OpConstantPipeStorage:
+		case Op::OpCreatePipeFromPipeStorage:
+		case Op::OpGetKernelLocalSizeForSubgroupCount:
+		case Op::OpGetKernelMaxNumSubgroups:
+		case Op::OpTypeNamedBarrier:
+		case Op::OpNamedBarrierInitialize:
+		case Op::OpMemoryNamedBarrier:
+		case Op::OpModuleProcessed:
+		case Op::OpExecutionModeId:
+		case Op::OpDecorateId:
+		case Op::OpGroupNonUniformElect:
+		case Op::OpGroupNonUniformAll:
+		case Op::OpGroupNonUniformAny:
+		case Op::OpGroupNonUniformAllEqual:
+		case Op::OpGroupNonUniformBroadcast:
+		case Op::OpGroupNonUniformBroadcastFirst:
+		case Op::OpGroupNonUniformBallot:
+		case Op::OpGroupNonUniformInverseBallot:
+		case Op::OpGroupNonUniformBallotBitExtract:
+		case Op::OpGroupNonUniformBallotBitCount:
+		case Op::OpGroupNonUniformBallotFindLSB:
+		case Op::OpGroupNonUniformBallotFindMSB:
+		case Op::OpGroupNonUniformShuffle:
+		case Op::OpGroupNonUniformShuffleXor:
+		case Op::OpGroupNonUniformShuffleUp:
+		case Op::OpGroupNonUniformShuffleDown:
+		case Op::OpGroupNonUniformIAdd:
+		case Op::OpGroupNonUniformFAdd:
+		case Op::OpGroupNonUniformIMul:
+		case Op::OpGroupNonUniformFMul:
+		case Op::OpGroupNonUniformSMin:
+		case Op::OpGroupNonUniformUMin:
+		case Op::OpGroupNonUniformFMin:
+		case Op::OpGroupNonUniformSMax:
+		case Op::OpGroupNonUniformUMax:
+		case Op::OpGroupNonUniformFMax:
+		case Op::OpGroupNonUniformBitwiseAnd:
+		case Op::OpGroupNonUniformBitwiseOr:
+		case Op::OpGroupNonUniformBitwiseXor:
+		case Op::OpGroupNonUniformLogicalAnd:
+		case Op::OpGroupNonUniformLogicalOr:
+		case Op::OpGroupNonUniformLogicalXor:
+		case Op::OpGroupNonUniformQuadBroadcast:
+		case Op::OpGroupNonUniformQuadSwap:
+		case Op::OpCopyLogical:
+		case Op::OpPtrEqual:
+		case Op::OpPtrNotEqual:
+		case Op::OpPtrDiff:
+		case Op::OpColorAttachmentReadEXT:
+		case Op::OpDepthAttachmentReadEXT:
+		case Op::OpStencilAttachmentReadEXT:
+		case Op::OpTerminateInvocation:
+		case Op::OpSubgroupBallotKHR:
+		case Op::OpSubgroupFirstInvocationKHR:
+		case Op::OpSubgroupAllKHR:
+		case Op::OpSubgroupAnyKHR:
+		case Op::OpSubgroupAllEqualKHR:
+		case Op::OpGroupNonUniformRotateKHR:
+		case Op::OpSubgroupReadInvocationKHR:
+		case Op::OpTraceRayKHR:
+		case Op::OpExecuteCallableKHR:
+		case Op::OpConvertUToAccelerationStructureKHR:
+		case Op::OpIgnoreIntersectionKHR:
+		case Op::OpTerminateRayKHR:
+		case Op::OpSDot:
+		case Op::OpUDot:
+		case Op::OpSUDot:
+		case Op::OpSDotAccSat:
+		case Op::OpUDotAccSat:
+		case Op::OpSUDotAccSat:
+		case Op::OpTypeCooperativeMatrixKHR:
+		case Op::OpCooperativeMatrixLoadKHR:
+		case Op::OpCooperativeMatrixStoreKHR:
+		case Op::OpCooperativeMatrixMulAddKHR:
+		case Op::OpCooperativeMatrixLengthKHR:
+		case Op::OpTypeRayQueryKHR:
+		case Op::OpRayQueryInitializeKHR:
+		case Op::OpRayQueryTerminateKHR:
+		case Op::OpRayQueryGenerateIntersectionKHR:
+		case Op::OpRayQueryConfirmIntersectionKHR:
+		case Op::OpRayQueryProceedKHR:
+		case Op::OpRayQueryGetIntersectionTypeKHR:
+		case Op::OpImageSampleWeightedQCOM:
+		case Op::OpImageBoxFilterQCOM:
+		case Op::OpImageBlockMatchSSDQCOM:
+		case Op::OpImageBlockMatchSADQCOM:
+		case Op::OpGroupIAddNonUniformAMD:
+		case Op::OpGroupFAddNonUniformAMD:
+		case Op::OpGroupFMinNonUniformAMD:
+		case Op::OpGroupUMinNonUniformAMD:
+		case Op::OpGroupSMinNonUniformAMD:
+		case Op::OpGroupFMaxNonUniformAMD:
+		case Op::OpGroupUMaxNonUniformAMD:
+		case Op::OpGroupSMaxNonUniformAMD:
+		case Op::OpFragmentMaskFetchAMD:
+		case Op::OpFragmentFetchAMD:
+		case Op::OpReadClockKHR:
+		case Op::OpFinalizeNodePayloadsAMDX:
+		case Op::OpFinishWritingNodePayloadAMDX:
+		case Op::OpInitializeNodePayloadsAMDX:
+		case Op::OpHitObjectRecordHitMotionNV:
+		case Op::OpHitObjectRecordHitWithIndexMotionNV:
+		case Op::OpHitObjectRecordMissMotionNV:
+		case Op::OpHitObjectGetWorldToObjectNV:
+		case Op::OpHitObjectGetObjectToWorldNV:
+		case Op::OpHitObjectGetObjectRayDirectionNV:
+		case Op::OpHitObjectGetObjectRayOriginNV:
+		case Op::OpHitObjectTraceRayMotionNV:
+		case Op::OpHitObjectGetShaderRecordBufferHandleNV:
+		case Op::OpHitObjectGetShaderBindingTableRecordIndexNV:
+		case Op::OpHitObjectRecordEmptyNV:
+		case Op::OpHitObjectTraceRayNV:
+		case Op::OpHitObjectRecordHitNV:
+		case Op::OpHitObjectRecordHitWithIndexNV:
+		case Op::OpHitObjectRecordMissNV:
+		case Op::OpHitObjectExecuteShaderNV:
+		case Op::OpHitObjectGetCurrentTimeNV:
+		case Op::OpHitObjectGetAttributesNV:
+		case Op::OpHitObjectGetHitKindNV:
+		case Op::OpHitObjectGetPrimitiveIndexNV:
+		case Op::OpHitObjectGetGeometryIndexNV:
+		case Op::OpHitObjectGetInstanceIdNV:
+		case Op::OpHitObjectGetInstanceCustomIndexNV:
+		case Op::OpHitObjectGetWorldRayDirectionNV:
+		case Op::OpHitObjectGetWorldRayOriginNV:
+		case Op::OpHitObjectGetRayTMaxNV:
+		case Op::OpHitObjectGetRayTMinNV:
+		case Op::OpHitObjectIsEmptyNV:
+		case Op::OpHitObjectIsHitNV:
+		case Op::OpHitObjectIsMissNV:
+		case Op::OpReorderThreadWithHitObjectNV:
+		case Op::OpReorderThreadWithHintNV:
+		case Op::OpTypeHitObjectNV:
+		case Op::OpImageSampleFootprintNV:
+		case Op::OpEmitMeshTasksEXT:
+		case Op::OpSetMeshOutputsEXT:
+		case Op::OpGroupNonUniformPartitionNV:
+		case Op::OpWritePackedPrimitiveIndices4x8NV:
+		case Op::OpReportIntersectionNV:
+		case Op::OpIgnoreIntersectionNV:
+		case Op::OpTerminateRayNV:
+		case Op::OpTraceNV:
+		case Op::OpTraceMotionNV:
+		case Op::OpTraceRayMotionNV:
+		case Op::OpRayQueryGetIntersectionTriangleVertexPositionsKHR:
+		case Op::OpTypeAccelerationStructureNV:
+		case Op::OpExecuteCallableNV:
+		case Op::OpTypeCooperativeMatrixNV:
+		case Op::OpCooperativeMatrixLoadNV:
+		case Op::OpCooperativeMatrixStoreNV:
+		case Op::OpCooperativeMatrixMulAddNV:
+		case Op::OpCooperativeMatrixLengthNV:
+		case Op::OpBeginInvocationInterlockEXT:
+		case Op::OpEndInvocationInterlockEXT:
+		case Op::OpDemoteToHelperInvocation:
+		case Op::OpIsHelperInvocationEXT:
+		case Op::OpConvertUToImageNV:
+		case Op::OpConvertUToSamplerNV:
+		case Op::OpConvertImageToUNV:
+		case Op::OpConvertSamplerToUNV:
+		case Op::OpConvertUToSampledImageNV:
+		case Op::OpConvertSampledImageToUNV:
+		case Op::OpSamplerImageAddressingModeNV:
+		case Op::OpSubgroupShuffleINTEL:
+		case Op::OpSubgroupShuffleDownINTEL:
+		case Op::OpSubgroupShuffleUpINTEL:
+		case Op::OpSubgroupShuffleXorINTEL:
+		case Op::OpSubgroupBlockReadINTEL:
+		case Op::OpSubgroupBlockWriteINTEL:
+		case Op::OpSubgroupImageBlockReadINTEL:
+		case Op::OpSubgroupImageBlockWriteINTEL:
+		case Op::OpSubgroupImageMediaBlockReadINTEL:
+		case Op::OpSubgroupImageMediaBlockWriteINTEL:
+		case Op::OpUCountLeadingZerosINTEL:
+		case Op::OpUCountTrailingZerosINTEL:
+		case Op::OpAbsISubINTEL:
+		case Op::OpAbsUSubINTEL:
+		case Op::OpIAddSatINTEL:
+		case Op::OpUAddSatINTEL:
+		case Op::OpIAverageINTEL:
+		case Op::OpUAverageINTEL:
+		case Op::OpIAverageRoundedINTEL:
+		case Op::OpUAverageRoundedINTEL:
+		case Op::OpISubSatINTEL:
+		case Op::OpUSubSatINTEL:
+		case Op::OpIMul32x16INTEL:
+		case Op::OpUMul32x16INTEL:
+		case Op::OpConstantFunctionPointerINTEL:
+		case Op::OpFunctionPointerCallINTEL:
+		case Op::OpAsmTargetINTEL:
+		case Op::OpAsmINTEL:
+		case Op::OpAsmCallINTEL:
+		case Op::OpAtomicFMinEXT:
+		case Op::OpAtomicFMaxEXT:
+		case Op::OpAssumeTrueKHR:
+		case Op::OpExpectKHR:
+		case Op::OpDecorateString:
+		case Op::OpMemberDecorateString:
+		case Op::OpVmeImageINTEL:
+		case Op::OpTypeVmeImageINTEL:
+		case Op::OpTypeAvcImePayloadINTEL:
+		case Op::OpTypeAvcRefPayloadINTEL:
+		case Op::OpTypeAvcSicPayloadINTEL:
+		case Op::OpTypeAvcMcePayloadINTEL:
+		case Op::OpTypeAvcMceResultINTEL:
+		case Op::OpTypeAvcImeResultINTEL:
+		case Op::OpTypeAvcImeResultSingleReferenceStreamoutINTEL:
+		case Op::OpTypeAvcImeResultDualReferenceStreamoutINTEL:
+		case Op::OpTypeAvcImeSingleReferenceStreaminINTEL:
+		case Op::OpTypeAvcImeDualReferenceStreaminINTEL:
+		case Op::OpTypeAvcRefResultINTEL:
+		case Op::OpTypeAvcSicResultINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL:
+		case Op::OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL:
+		case Op::OpSubgroupAvcMceSetInterShapePenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL:
+		case Op::OpSubgroupAvcMceSetInterDirectionPenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL:
+		case Op::OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL:
+		case Op::OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL:
+		case Op::OpSubgroupAvcMceSetAcOnlyHaarINTEL:
+		case Op::OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL:
+		case Op::OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL:
+		case Op::OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL:
+		case Op::OpSubgroupAvcMceConvertToImePayloadINTEL:
+		case Op::OpSubgroupAvcMceConvertToImeResultINTEL:
+		case Op::OpSubgroupAvcMceConvertToRefPayloadINTEL:
+		case Op::OpSubgroupAvcMceConvertToRefResultINTEL:
+		case Op::OpSubgroupAvcMceConvertToSicPayloadINTEL:
+		case Op::OpSubgroupAvcMceConvertToSicResultINTEL:
+		case Op::OpSubgroupAvcMceGetMotionVectorsINTEL:
+		case Op::OpSubgroupAvcMceGetInterDistortionsINTEL:
+		case Op::OpSubgroupAvcMceGetBestInterDistortionsINTEL:
+		case Op::OpSubgroupAvcMceGetInterMajorShapeINTEL:
+		case Op::OpSubgroupAvcMceGetInterMinorShapeINTEL:
+		case Op::OpSubgroupAvcMceGetInterDirectionsINTEL:
+		case Op::OpSubgroupAvcMceGetInterMotionVectorCountINTEL:
+		case Op::OpSubgroupAvcMceGetInterReferenceIdsINTEL:
+		case Op::OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL:
+		case Op::OpSubgroupAvcImeInitializeINTEL:
+		case Op::OpSubgroupAvcImeSetSingleReferenceINTEL:
+		case Op::OpSubgroupAvcImeSetDualReferenceINTEL:
+		case Op::OpSubgroupAvcImeRefWindowSizeINTEL:
+		case Op::OpSubgroupAvcImeAdjustRefOffsetINTEL:
+		case Op::OpSubgroupAvcImeConvertToMcePayloadINTEL:
+		case Op::OpSubgroupAvcImeSetMaxMotionVectorCountINTEL:
+		case Op::OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL:
+		case Op::OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL:
+		case Op::OpSubgroupAvcImeSetWeightedSadINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL:
+		case Op::OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL:
+		case Op::OpSubgroupAvcImeConvertToMceResultINTEL:
+		case Op::OpSubgroupAvcImeGetSingleReferenceStreaminINTEL:
+		case Op::OpSubgroupAvcImeGetDualReferenceStreaminINTEL:
+		case Op::OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL:
+		case Op::OpSubgroupAvcImeStripDualReferenceStreamoutINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL:
+		case Op::OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL:
+		case Op::OpSubgroupAvcImeGetBorderReachedINTEL:
+		case Op::OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL:
+		case Op::OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL:
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL:
+		case Op::OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL:
+		case Op::OpSubgroupAvcFmeInitializeINTEL:
+		case Op::OpSubgroupAvcBmeInitializeINTEL:
+		case Op::OpSubgroupAvcRefConvertToMcePayloadINTEL:
+		case Op::OpSubgroupAvcRefSetBidirectionalMixDisableINTEL:
+		case Op::OpSubgroupAvcRefSetBilinearFilterEnableINTEL:
+		case Op::OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL:
+		case Op::OpSubgroupAvcRefEvaluateWithDualReferenceINTEL:
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL:
+		case Op::OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL:
+		case Op::OpSubgroupAvcRefConvertToMceResultINTEL:
+		case Op::OpSubgroupAvcSicInitializeINTEL:
+		case Op::OpSubgroupAvcSicConfigureSkcINTEL:
+		case Op::OpSubgroupAvcSicConfigureIpeLumaINTEL:
+		case Op::OpSubgroupAvcSicConfigureIpeLumaChromaINTEL:
+		case Op::OpSubgroupAvcSicGetMotionVectorMaskINTEL:
+		case Op::OpSubgroupAvcSicConvertToMcePayloadINTEL:
+		case Op::OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL:
+		case Op::OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL:
+		case Op::OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL:
+		case Op::OpSubgroupAvcSicSetBilinearFilterEnableINTEL:
+		case Op::OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL:
+		case Op::OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL:
+		case Op::OpSubgroupAvcSicEvaluateIpeINTEL:
+		case Op::OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL:
+		case Op::OpSubgroupAvcSicEvaluateWithDualReferenceINTEL:
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL:
+		case Op::OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL:
+		case Op::OpSubgroupAvcSicConvertToMceResultINTEL:
+		case Op::OpSubgroupAvcSicGetIpeLumaShapeINTEL:
+		case Op::OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL:
+		case Op::OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL:
+		case Op::OpSubgroupAvcSicGetPackedIpeLumaModesINTEL:
+		case Op::OpSubgroupAvcSicGetIpeChromaModeINTEL:
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL:
+		case Op::OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL:
+		case Op::OpSubgroupAvcSicGetInterRawSadsINTEL:
+		case Op::OpVariableLengthArrayINTEL:
+		case Op::OpSaveMemoryINTEL:
+		case Op::OpRestoreMemoryINTEL:
+		case Op::OpArbitraryFloatSinCosPiINTEL:
+		case Op::OpArbitraryFloatCastINTEL:
+		case Op::OpArbitraryFloatCastFromIntINTEL:
+		case Op::OpArbitraryFloatCastToIntINTEL:
+		case Op::OpArbitraryFloatAddINTEL:
+		case Op::OpArbitraryFloatSubINTEL:
+		case Op::OpArbitraryFloatMulINTEL:
+		case Op::OpArbitraryFloatDivINTEL:
+		case Op::OpArbitraryFloatGTINTEL:
+		case Op::OpArbitraryFloatGEINTEL:
+		case Op::OpArbitraryFloatLTINTEL:
+		case Op::OpArbitraryFloatLEINTEL:
+		case Op::OpArbitraryFloatEQINTEL:
+		case Op::OpArbitraryFloatRecipINTEL:
+		case Op::OpArbitraryFloatRSqrtINTEL:
+		case Op::OpArbitraryFloatCbrtINTEL:
+		case Op::OpArbitraryFloatHypotINTEL:
+		case Op::OpArbitraryFloatSqrtINTEL:
+		case Op::OpArbitraryFloatLogINTEL:
+		case Op::OpArbitraryFloatLog2INTEL:
+		case Op::OpArbitraryFloatLog10INTEL:
+		case Op::OpArbitraryFloatLog1pINTEL:
+		case Op::OpArbitraryFloatExpINTEL:
+		case Op::OpArbitraryFloatExp2INTEL:
+		case Op::OpArbitraryFloatExp10INTEL:
+		case Op::OpArbitraryFloatExpm1INTEL:
+		case Op::OpArbitraryFloatSinINTEL:
+		case Op::OpArbitraryFloatCosINTEL:
+		case Op::OpArbitraryFloatSinCosINTEL:
+		case Op::OpArbitraryFloatSinPiINTEL:
+		case Op::OpArbitraryFloatCosPiINTEL:
+		case Op::OpArbitraryFloatASinINTEL:
+		case Op::OpArbitraryFloatASinPiINTEL:
+		case Op::OpArbitraryFloatACosINTEL:
+		case Op::OpArbitraryFloatACosPiINTEL:
+		case Op::OpArbitraryFloatATanINTEL:
+		case Op::OpArbitraryFloatATanPiINTEL:
+		case Op::OpArbitraryFloatATan2INTEL:
+		case Op::OpArbitraryFloatPowINTEL:
+		case Op::OpArbitraryFloatPowRINTEL:
+		case Op::OpArbitraryFloatPowNINTEL:
+		case Op::OpLoopControlINTEL:
+		case Op::OpAliasDomainDeclINTEL:
+		case Op::OpAliasScopeDeclINTEL:
+		case Op::OpAliasScopeListDeclINTEL:
+		case Op::OpFixedSqrtINTEL:
+		case Op::OpFixedRecipINTEL:
+		case Op::OpFixedRsqrtINTEL:
+		case Op::OpFixedSinINTEL:
+		case Op::OpFixedCosINTEL:
+		case Op::OpFixedSinCosINTEL:
+		case Op::OpFixedSinPiINTEL:
+		case Op::OpFixedCosPiINTEL:
+		case Op::OpFixedSinCosPiINTEL:
+		case Op::OpFixedLogINTEL:
+		case Op::OpFixedExpINTEL:
+		case Op::OpPtrCastToCrossWorkgroupINTEL:
+		case Op::OpCrossWorkgroupCastToPtrINTEL:
+		case Op::OpReadPipeBlockingINTEL:
+		case Op::OpWritePipeBlockingINTEL:
+		case Op::OpFPGARegINTEL:
+		case Op::OpRayQueryGetRayTMinKHR:
+		case Op::OpRayQueryGetRayFlagsKHR:
+		case Op::OpRayQueryGetIntersectionTKHR:
+		case Op::OpRayQueryGetIntersectionInstanceCustomIndexKHR:
+		case Op::OpRayQueryGetIntersectionInstanceIdKHR:
+		case Op::OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR:
+		case Op::OpRayQueryGetIntersectionGeometryIndexKHR:
+		case Op::OpRayQueryGetIntersectionPrimitiveIndexKHR:
+		case Op::OpRayQueryGetIntersectionBarycentricsKHR:
+		case Op::OpRayQueryGetIntersectionFrontFaceKHR:
+		case Op::OpRayQueryGetIntersectionCandidateAABBOpaqueKHR:
+		case Op::OpRayQueryGetIntersectionObjectRayDirectionKHR:
+		case Op::OpRayQueryGetIntersectionObjectRayOriginKHR:
+		case Op::OpRayQueryGetWorldRayDirectionKHR:
+		case Op::OpRayQueryGetWorldRayOriginKHR:
+		case Op::OpRayQueryGetIntersectionObjectToWorldKHR:
+		case Op::OpRayQueryGetIntersectionWorldToObjectKHR:
+		case Op::OpAtomicFAddEXT:
+		case Op::OpTypeBufferSurfaceINTEL:
+		case Op::OpTypeStructContinuedINTEL:
+		case Op::OpConstantCompositeContinuedINTEL:
+		case Op::OpSpecConstantCompositeContinuedINTEL:
+		case Op::OpConvertFToBF16INTEL:
+		case Op::OpConvertBF16ToFINTEL:
+		case Op::OpControlBarrierArriveINTEL:
+		case Op::OpControlBarrierWaitINTEL:
+		case Op::OpGroupIMulKHR:
+		case Op::OpGroupFMulKHR:
+		case Op::OpGroupBitwiseAndKHR:
+		case Op::OpGroupBitwiseOrKHR:
+		case Op::OpGroupBitwiseXorKHR:
+		case Op::OpGroupLogicalAndKHR:
+		case Op::OpGroupLogicalOrKHR:
+		case Op::OpGroupLogicalXorKHR:
+			return true;
+		default: return false;
 		}
 	}
 } // spvgentwo::spv
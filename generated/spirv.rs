@@ -0,0 +1,2247 @@
+// Auto generated - do not modify
+// Generated by rustspvgen 0.1.0
+// spirv.core.grammar.json sha256:05ccd69e0e41403e0fae829efe079bd0dd9d3bda809373ed9586ba8de46eb4ba version 1.6 revision 1
+// extinst.glsl.std.450.grammar.json sha256:3bcf78c13b71a9ebba51e890c55f41a5e0f47ba283bc7c08fd7e1344ea5f47a6 version 100 revision 2
+// extinst.opencl.std.100.grammar.json sha256:b6be3248af8e6150332ec5d9df2e578b364d588c762533831b7550f607a73ff8 version 100 revision 2
+#[allow(non_upper_case_globals, dead_code, clippy::all)]
+pub mod spv {
+	pub const MAGIC_NUMBER: u32 = 0x07230203;
+	pub const VERSION: u32 = 67072;
+	pub const REVISION: u32 = 1;
+	pub const OP_CODE_MASK: u32 = 0xffff;
+	pub const WORD_COUNT_SHIFT: u32 = 16;
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ImageOperandsMask(pub u32);
+	impl ImageOperandsMask {
+		pub const MaskNone: ImageOperandsMask = ImageOperandsMask(0);
+		pub const Bias: ImageOperandsMask = ImageOperandsMask(1);
+		pub const Lod: ImageOperandsMask = ImageOperandsMask(2);
+		pub const Grad: ImageOperandsMask = ImageOperandsMask(4);
+		pub const ConstOffset: ImageOperandsMask = ImageOperandsMask(8);
+		pub const Offset: ImageOperandsMask = ImageOperandsMask(16);
+		pub const ConstOffsets: ImageOperandsMask = ImageOperandsMask(32);
+		pub const Sample: ImageOperandsMask = ImageOperandsMask(64);
+		pub const MinLod: ImageOperandsMask = ImageOperandsMask(128);
+		pub const MakeTexelAvailable: ImageOperandsMask = ImageOperandsMask(256);
+		pub const MakeTexelAvailableKHR: ImageOperandsMask = ImageOperandsMask(256);
+		pub const MakeTexelVisible: ImageOperandsMask = ImageOperandsMask(512);
+		pub const MakeTexelVisibleKHR: ImageOperandsMask = ImageOperandsMask(512);
+		pub const NonPrivateTexel: ImageOperandsMask = ImageOperandsMask(1024);
+		pub const NonPrivateTexelKHR: ImageOperandsMask = ImageOperandsMask(1024);
+		pub const VolatileTexel: ImageOperandsMask = ImageOperandsMask(2048);
+		pub const VolatileTexelKHR: ImageOperandsMask = ImageOperandsMask(2048);
+		pub const SignExtend: ImageOperandsMask = ImageOperandsMask(4096);
+		pub const ZeroExtend: ImageOperandsMask = ImageOperandsMask(8192);
+		pub const Nontemporal: ImageOperandsMask = ImageOperandsMask(16384);
+		pub const Offsets: ImageOperandsMask = ImageOperandsMask(65536);
+	}
+	impl ImageOperandsMask {
+		pub const fn contains(self, other: ImageOperandsMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for ImageOperandsMask { type Output = ImageOperandsMask; fn bitor(self, rhs: ImageOperandsMask) -> ImageOperandsMask { ImageOperandsMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for ImageOperandsMask { type Output = ImageOperandsMask; fn bitand(self, rhs: ImageOperandsMask) -> ImageOperandsMask { ImageOperandsMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for ImageOperandsMask { type Output = ImageOperandsMask; fn bitxor(self, rhs: ImageOperandsMask) -> ImageOperandsMask { ImageOperandsMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for ImageOperandsMask { fn bitor_assign(&mut self, rhs: ImageOperandsMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FPFastMathModeMask(pub u32);
+	impl FPFastMathModeMask {
+		pub const MaskNone: FPFastMathModeMask = FPFastMathModeMask(0);
+		pub const NotNaN: FPFastMathModeMask = FPFastMathModeMask(1);
+		pub const NotInf: FPFastMathModeMask = FPFastMathModeMask(2);
+		pub const NSZ: FPFastMathModeMask = FPFastMathModeMask(4);
+		pub const AllowRecip: FPFastMathModeMask = FPFastMathModeMask(8);
+		pub const Fast: FPFastMathModeMask = FPFastMathModeMask(16);
+		pub const AllowContractFastINTEL: FPFastMathModeMask = FPFastMathModeMask(65536);
+		pub const AllowReassocINTEL: FPFastMathModeMask = FPFastMathModeMask(131072);
+	}
+	impl FPFastMathModeMask {
+		pub const fn contains(self, other: FPFastMathModeMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for FPFastMathModeMask { type Output = FPFastMathModeMask; fn bitor(self, rhs: FPFastMathModeMask) -> FPFastMathModeMask { FPFastMathModeMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for FPFastMathModeMask { type Output = FPFastMathModeMask; fn bitand(self, rhs: FPFastMathModeMask) -> FPFastMathModeMask { FPFastMathModeMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for FPFastMathModeMask { type Output = FPFastMathModeMask; fn bitxor(self, rhs: FPFastMathModeMask) -> FPFastMathModeMask { FPFastMathModeMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for FPFastMathModeMask { fn bitor_assign(&mut self, rhs: FPFastMathModeMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct SelectionControlMask(pub u32);
+	impl SelectionControlMask {
+		pub const MaskNone: SelectionControlMask = SelectionControlMask(0);
+		pub const Flatten: SelectionControlMask = SelectionControlMask(1);
+		pub const DontFlatten: SelectionControlMask = SelectionControlMask(2);
+	}
+	impl SelectionControlMask {
+		pub const fn contains(self, other: SelectionControlMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for SelectionControlMask { type Output = SelectionControlMask; fn bitor(self, rhs: SelectionControlMask) -> SelectionControlMask { SelectionControlMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for SelectionControlMask { type Output = SelectionControlMask; fn bitand(self, rhs: SelectionControlMask) -> SelectionControlMask { SelectionControlMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for SelectionControlMask { type Output = SelectionControlMask; fn bitxor(self, rhs: SelectionControlMask) -> SelectionControlMask { SelectionControlMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for SelectionControlMask { fn bitor_assign(&mut self, rhs: SelectionControlMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct LoopControlMask(pub u32);
+	impl LoopControlMask {
+		pub const MaskNone: LoopControlMask = LoopControlMask(0);
+		pub const Unroll: LoopControlMask = LoopControlMask(1);
+		pub const DontUnroll: LoopControlMask = LoopControlMask(2);
+		pub const DependencyInfinite: LoopControlMask = LoopControlMask(4);
+		pub const DependencyLength: LoopControlMask = LoopControlMask(8);
+		pub const MinIterations: LoopControlMask = LoopControlMask(16);
+		pub const MaxIterations: LoopControlMask = LoopControlMask(32);
+		pub const IterationMultiple: LoopControlMask = LoopControlMask(64);
+		pub const PeelCount: LoopControlMask = LoopControlMask(128);
+		pub const PartialCount: LoopControlMask = LoopControlMask(256);
+		pub const InitiationIntervalINTEL: LoopControlMask = LoopControlMask(65536);
+		pub const MaxConcurrencyINTEL: LoopControlMask = LoopControlMask(131072);
+		pub const DependencyArrayINTEL: LoopControlMask = LoopControlMask(262144);
+		pub const PipelineEnableINTEL: LoopControlMask = LoopControlMask(524288);
+		pub const LoopCoalesceINTEL: LoopControlMask = LoopControlMask(1048576);
+		pub const MaxInterleavingINTEL: LoopControlMask = LoopControlMask(2097152);
+		pub const SpeculatedIterationsINTEL: LoopControlMask = LoopControlMask(4194304);
+		pub const NoFusionINTEL: LoopControlMask = LoopControlMask(8388608);
+		pub const LoopCountINTEL: LoopControlMask = LoopControlMask(16777216);
+		pub const MaxReinvocationDelayINTEL: LoopControlMask = LoopControlMask(33554432);
+	}
+	impl LoopControlMask {
+		pub const fn contains(self, other: LoopControlMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for LoopControlMask { type Output = LoopControlMask; fn bitor(self, rhs: LoopControlMask) -> LoopControlMask { LoopControlMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for LoopControlMask { type Output = LoopControlMask; fn bitand(self, rhs: LoopControlMask) -> LoopControlMask { LoopControlMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for LoopControlMask { type Output = LoopControlMask; fn bitxor(self, rhs: LoopControlMask) -> LoopControlMask { LoopControlMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for LoopControlMask { fn bitor_assign(&mut self, rhs: LoopControlMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FunctionControlMask(pub u32);
+	impl FunctionControlMask {
+		pub const MaskNone: FunctionControlMask = FunctionControlMask(0);
+		pub const Inline: FunctionControlMask = FunctionControlMask(1);
+		pub const DontInline: FunctionControlMask = FunctionControlMask(2);
+		pub const Pure: FunctionControlMask = FunctionControlMask(4);
+		pub const Const: FunctionControlMask = FunctionControlMask(8);
+		pub const OptNoneINTEL: FunctionControlMask = FunctionControlMask(65536);
+	}
+	impl FunctionControlMask {
+		pub const fn contains(self, other: FunctionControlMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for FunctionControlMask { type Output = FunctionControlMask; fn bitor(self, rhs: FunctionControlMask) -> FunctionControlMask { FunctionControlMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for FunctionControlMask { type Output = FunctionControlMask; fn bitand(self, rhs: FunctionControlMask) -> FunctionControlMask { FunctionControlMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for FunctionControlMask { type Output = FunctionControlMask; fn bitxor(self, rhs: FunctionControlMask) -> FunctionControlMask { FunctionControlMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for FunctionControlMask { fn bitor_assign(&mut self, rhs: FunctionControlMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct MemorySemanticsMask(pub u32);
+	impl MemorySemanticsMask {
+		pub const Relaxed: MemorySemanticsMask = MemorySemanticsMask(0);
+		pub const MaskNone: MemorySemanticsMask = MemorySemanticsMask(0);
+		pub const Acquire: MemorySemanticsMask = MemorySemanticsMask(2);
+		pub const Release: MemorySemanticsMask = MemorySemanticsMask(4);
+		pub const AcquireRelease: MemorySemanticsMask = MemorySemanticsMask(8);
+		pub const SequentiallyConsistent: MemorySemanticsMask = MemorySemanticsMask(16);
+		pub const UniformMemory: MemorySemanticsMask = MemorySemanticsMask(64);
+		pub const SubgroupMemory: MemorySemanticsMask = MemorySemanticsMask(128);
+		pub const WorkgroupMemory: MemorySemanticsMask = MemorySemanticsMask(256);
+		pub const CrossWorkgroupMemory: MemorySemanticsMask = MemorySemanticsMask(512);
+		pub const AtomicCounterMemory: MemorySemanticsMask = MemorySemanticsMask(1024);
+		pub const ImageMemory: MemorySemanticsMask = MemorySemanticsMask(2048);
+		pub const OutputMemory: MemorySemanticsMask = MemorySemanticsMask(4096);
+		pub const OutputMemoryKHR: MemorySemanticsMask = MemorySemanticsMask(4096);
+		pub const MakeAvailable: MemorySemanticsMask = MemorySemanticsMask(8192);
+		pub const MakeAvailableKHR: MemorySemanticsMask = MemorySemanticsMask(8192);
+		pub const MakeVisible: MemorySemanticsMask = MemorySemanticsMask(16384);
+		pub const MakeVisibleKHR: MemorySemanticsMask = MemorySemanticsMask(16384);
+		pub const Volatile: MemorySemanticsMask = MemorySemanticsMask(32768);
+	}
+	impl MemorySemanticsMask {
+		pub const fn contains(self, other: MemorySemanticsMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for MemorySemanticsMask { type Output = MemorySemanticsMask; fn bitor(self, rhs: MemorySemanticsMask) -> MemorySemanticsMask { MemorySemanticsMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for MemorySemanticsMask { type Output = MemorySemanticsMask; fn bitand(self, rhs: MemorySemanticsMask) -> MemorySemanticsMask { MemorySemanticsMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for MemorySemanticsMask { type Output = MemorySemanticsMask; fn bitxor(self, rhs: MemorySemanticsMask) -> MemorySemanticsMask { MemorySemanticsMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for MemorySemanticsMask { fn bitor_assign(&mut self, rhs: MemorySemanticsMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct MemoryAccessMask(pub u32);
+	impl MemoryAccessMask {
+		pub const MaskNone: MemoryAccessMask = MemoryAccessMask(0);
+		pub const Volatile: MemoryAccessMask = MemoryAccessMask(1);
+		pub const Aligned: MemoryAccessMask = MemoryAccessMask(2);
+		pub const Nontemporal: MemoryAccessMask = MemoryAccessMask(4);
+		pub const MakePointerAvailable: MemoryAccessMask = MemoryAccessMask(8);
+		pub const MakePointerAvailableKHR: MemoryAccessMask = MemoryAccessMask(8);
+		pub const MakePointerVisible: MemoryAccessMask = MemoryAccessMask(16);
+		pub const MakePointerVisibleKHR: MemoryAccessMask = MemoryAccessMask(16);
+		pub const NonPrivatePointer: MemoryAccessMask = MemoryAccessMask(32);
+		pub const NonPrivatePointerKHR: MemoryAccessMask = MemoryAccessMask(32);
+		pub const AliasScopeINTELMask: MemoryAccessMask = MemoryAccessMask(65536);
+		pub const NoAliasINTELMask: MemoryAccessMask = MemoryAccessMask(131072);
+	}
+	impl MemoryAccessMask {
+		pub const fn contains(self, other: MemoryAccessMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for MemoryAccessMask { type Output = MemoryAccessMask; fn bitor(self, rhs: MemoryAccessMask) -> MemoryAccessMask { MemoryAccessMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for MemoryAccessMask { type Output = MemoryAccessMask; fn bitand(self, rhs: MemoryAccessMask) -> MemoryAccessMask { MemoryAccessMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for MemoryAccessMask { type Output = MemoryAccessMask; fn bitxor(self, rhs: MemoryAccessMask) -> MemoryAccessMask { MemoryAccessMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for MemoryAccessMask { fn bitor_assign(&mut self, rhs: MemoryAccessMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct KernelProfilingInfoMask(pub u32);
+	impl KernelProfilingInfoMask {
+		pub const MaskNone: KernelProfilingInfoMask = KernelProfilingInfoMask(0);
+		pub const CmdExecTime: KernelProfilingInfoMask = KernelProfilingInfoMask(1);
+	}
+	impl KernelProfilingInfoMask {
+		pub const fn contains(self, other: KernelProfilingInfoMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for KernelProfilingInfoMask { type Output = KernelProfilingInfoMask; fn bitor(self, rhs: KernelProfilingInfoMask) -> KernelProfilingInfoMask { KernelProfilingInfoMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for KernelProfilingInfoMask { type Output = KernelProfilingInfoMask; fn bitand(self, rhs: KernelProfilingInfoMask) -> KernelProfilingInfoMask { KernelProfilingInfoMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for KernelProfilingInfoMask { type Output = KernelProfilingInfoMask; fn bitxor(self, rhs: KernelProfilingInfoMask) -> KernelProfilingInfoMask { KernelProfilingInfoMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for KernelProfilingInfoMask { fn bitor_assign(&mut self, rhs: KernelProfilingInfoMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct RayFlagsMask(pub u32);
+	impl RayFlagsMask {
+		pub const NoneKHR: RayFlagsMask = RayFlagsMask(0);
+		pub const OpaqueKHR: RayFlagsMask = RayFlagsMask(1);
+		pub const NoOpaqueKHR: RayFlagsMask = RayFlagsMask(2);
+		pub const TerminateOnFirstHitKHR: RayFlagsMask = RayFlagsMask(4);
+		pub const SkipClosestHitShaderKHR: RayFlagsMask = RayFlagsMask(8);
+		pub const CullBackFacingTrianglesKHR: RayFlagsMask = RayFlagsMask(16);
+		pub const CullFrontFacingTrianglesKHR: RayFlagsMask = RayFlagsMask(32);
+		pub const CullOpaqueKHR: RayFlagsMask = RayFlagsMask(64);
+		pub const CullNoOpaqueKHR: RayFlagsMask = RayFlagsMask(128);
+		pub const SkipTrianglesKHR: RayFlagsMask = RayFlagsMask(256);
+		pub const SkipAABBsKHR: RayFlagsMask = RayFlagsMask(512);
+		pub const ForceOpacityMicromap2StateEXT: RayFlagsMask = RayFlagsMask(1024);
+	}
+	impl RayFlagsMask {
+		pub const fn contains(self, other: RayFlagsMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for RayFlagsMask { type Output = RayFlagsMask; fn bitor(self, rhs: RayFlagsMask) -> RayFlagsMask { RayFlagsMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for RayFlagsMask { type Output = RayFlagsMask; fn bitand(self, rhs: RayFlagsMask) -> RayFlagsMask { RayFlagsMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for RayFlagsMask { type Output = RayFlagsMask; fn bitxor(self, rhs: RayFlagsMask) -> RayFlagsMask { RayFlagsMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for RayFlagsMask { fn bitor_assign(&mut self, rhs: RayFlagsMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FragmentShadingRateMask(pub u32);
+	impl FragmentShadingRateMask {
+		pub const Vertical2Pixels: FragmentShadingRateMask = FragmentShadingRateMask(1);
+		pub const Vertical4Pixels: FragmentShadingRateMask = FragmentShadingRateMask(2);
+		pub const Horizontal2Pixels: FragmentShadingRateMask = FragmentShadingRateMask(4);
+		pub const Horizontal4Pixels: FragmentShadingRateMask = FragmentShadingRateMask(8);
+	}
+	impl FragmentShadingRateMask {
+		pub const fn contains(self, other: FragmentShadingRateMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for FragmentShadingRateMask { type Output = FragmentShadingRateMask; fn bitor(self, rhs: FragmentShadingRateMask) -> FragmentShadingRateMask { FragmentShadingRateMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for FragmentShadingRateMask { type Output = FragmentShadingRateMask; fn bitand(self, rhs: FragmentShadingRateMask) -> FragmentShadingRateMask { FragmentShadingRateMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for FragmentShadingRateMask { type Output = FragmentShadingRateMask; fn bitxor(self, rhs: FragmentShadingRateMask) -> FragmentShadingRateMask { FragmentShadingRateMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for FragmentShadingRateMask { fn bitor_assign(&mut self, rhs: FragmentShadingRateMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct SourceLanguage(pub u32);
+	impl SourceLanguage {
+		pub const Unknown: SourceLanguage = SourceLanguage(0);
+		pub const ESSL: SourceLanguage = SourceLanguage(1);
+		pub const GLSL: SourceLanguage = SourceLanguage(2);
+		pub const OpenCL_C: SourceLanguage = SourceLanguage(3);
+		pub const OpenCL_CPP: SourceLanguage = SourceLanguage(4);
+		pub const HLSL: SourceLanguage = SourceLanguage(5);
+		pub const CPP_for_OpenCL: SourceLanguage = SourceLanguage(6);
+		pub const SYCL: SourceLanguage = SourceLanguage(7);
+		pub const HERO_C: SourceLanguage = SourceLanguage(8);
+		pub const NZSL: SourceLanguage = SourceLanguage(9);
+		pub const WGSL: SourceLanguage = SourceLanguage(10);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ExecutionModel(pub u32);
+	impl ExecutionModel {
+		pub const Vertex: ExecutionModel = ExecutionModel(0);
+		pub const TessellationControl: ExecutionModel = ExecutionModel(1);
+		pub const TessellationEvaluation: ExecutionModel = ExecutionModel(2);
+		pub const Geometry: ExecutionModel = ExecutionModel(3);
+		pub const Fragment: ExecutionModel = ExecutionModel(4);
+		pub const GLCompute: ExecutionModel = ExecutionModel(5);
+		pub const Kernel: ExecutionModel = ExecutionModel(6);
+		pub const TaskNV: ExecutionModel = ExecutionModel(5267);
+		pub const MeshNV: ExecutionModel = ExecutionModel(5268);
+		pub const RayGenerationNV: ExecutionModel = ExecutionModel(5313);
+		pub const RayGenerationKHR: ExecutionModel = ExecutionModel(5313);
+		pub const IntersectionNV: ExecutionModel = ExecutionModel(5314);
+		pub const IntersectionKHR: ExecutionModel = ExecutionModel(5314);
+		pub const AnyHitNV: ExecutionModel = ExecutionModel(5315);
+		pub const AnyHitKHR: ExecutionModel = ExecutionModel(5315);
+		pub const ClosestHitNV: ExecutionModel = ExecutionModel(5316);
+		pub const ClosestHitKHR: ExecutionModel = ExecutionModel(5316);
+		pub const MissNV: ExecutionModel = ExecutionModel(5317);
+		pub const MissKHR: ExecutionModel = ExecutionModel(5317);
+		pub const CallableNV: ExecutionModel = ExecutionModel(5318);
+		pub const CallableKHR: ExecutionModel = ExecutionModel(5318);
+		pub const TaskEXT: ExecutionModel = ExecutionModel(5364);
+		pub const MeshEXT: ExecutionModel = ExecutionModel(5365);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct AddressingModel(pub u32);
+	impl AddressingModel {
+		pub const Logical: AddressingModel = AddressingModel(0);
+		pub const Physical32: AddressingModel = AddressingModel(1);
+		pub const Physical64: AddressingModel = AddressingModel(2);
+		pub const PhysicalStorageBuffer64: AddressingModel = AddressingModel(5348);
+		pub const PhysicalStorageBuffer64EXT: AddressingModel = AddressingModel(5348);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct MemoryModel(pub u32);
+	impl MemoryModel {
+		pub const Simple: MemoryModel = MemoryModel(0);
+		pub const GLSL450: MemoryModel = MemoryModel(1);
+		pub const OpenCL: MemoryModel = MemoryModel(2);
+		pub const Vulkan: MemoryModel = MemoryModel(3);
+		pub const VulkanKHR: MemoryModel = MemoryModel(3);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ExecutionMode(pub u32);
+	impl ExecutionMode {
+		pub const Invocations: ExecutionMode = ExecutionMode(0);
+		pub const SpacingEqual: ExecutionMode = ExecutionMode(1);
+		pub const SpacingFractionalEven: ExecutionMode = ExecutionMode(2);
+		pub const SpacingFractionalOdd: ExecutionMode = ExecutionMode(3);
+		pub const VertexOrderCw: ExecutionMode = ExecutionMode(4);
+		pub const VertexOrderCcw: ExecutionMode = ExecutionMode(5);
+		pub const PixelCenterInteger: ExecutionMode = ExecutionMode(6);
+		pub const OriginUpperLeft: ExecutionMode = ExecutionMode(7);
+		pub const OriginLowerLeft: ExecutionMode = ExecutionMode(8);
+		pub const EarlyFragmentTests: ExecutionMode = ExecutionMode(9);
+		pub const PointMode: ExecutionMode = ExecutionMode(10);
+		pub const Xfb: ExecutionMode = ExecutionMode(11);
+		pub const DepthReplacing: ExecutionMode = ExecutionMode(12);
+		pub const DepthGreater: ExecutionMode = ExecutionMode(14);
+		pub const DepthLess: ExecutionMode = ExecutionMode(15);
+		pub const DepthUnchanged: ExecutionMode = ExecutionMode(16);
+		pub const LocalSize: ExecutionMode = ExecutionMode(17);
+		pub const LocalSizeHint: ExecutionMode = ExecutionMode(18);
+		pub const InputPoints: ExecutionMode = ExecutionMode(19);
+		pub const InputLines: ExecutionMode = ExecutionMode(20);
+		pub const InputLinesAdjacency: ExecutionMode = ExecutionMode(21);
+		pub const Triangles: ExecutionMode = ExecutionMode(22);
+		pub const InputTrianglesAdjacency: ExecutionMode = ExecutionMode(23);
+		pub const Quads: ExecutionMode = ExecutionMode(24);
+		pub const Isolines: ExecutionMode = ExecutionMode(25);
+		pub const OutputVertices: ExecutionMode = ExecutionMode(26);
+		pub const OutputPoints: ExecutionMode = ExecutionMode(27);
+		pub const OutputLineStrip: ExecutionMode = ExecutionMode(28);
+		pub const OutputTriangleStrip: ExecutionMode = ExecutionMode(29);
+		pub const VecTypeHint: ExecutionMode = ExecutionMode(30);
+		pub const ContractionOff: ExecutionMode = ExecutionMode(31);
+		pub const Initializer: ExecutionMode = ExecutionMode(33);
+		pub const Finalizer: ExecutionMode = ExecutionMode(34);
+		pub const SubgroupSize: ExecutionMode = ExecutionMode(35);
+		pub const SubgroupsPerWorkgroup: ExecutionMode = ExecutionMode(36);
+		pub const SubgroupsPerWorkgroupId: ExecutionMode = ExecutionMode(37);
+		pub const LocalSizeId: ExecutionMode = ExecutionMode(38);
+		pub const LocalSizeHintId: ExecutionMode = ExecutionMode(39);
+		pub const NonCoherentColorAttachmentReadEXT: ExecutionMode = ExecutionMode(4169);
+		pub const NonCoherentDepthAttachmentReadEXT: ExecutionMode = ExecutionMode(4170);
+		pub const NonCoherentStencilAttachmentReadEXT: ExecutionMode = ExecutionMode(4171);
+		pub const SubgroupUniformControlFlowKHR: ExecutionMode = ExecutionMode(4421);
+		pub const PostDepthCoverage: ExecutionMode = ExecutionMode(4446);
+		pub const DenormPreserve: ExecutionMode = ExecutionMode(4459);
+		pub const DenormFlushToZero: ExecutionMode = ExecutionMode(4460);
+		pub const SignedZeroInfNanPreserve: ExecutionMode = ExecutionMode(4461);
+		pub const RoundingModeRTE: ExecutionMode = ExecutionMode(4462);
+		pub const RoundingModeRTZ: ExecutionMode = ExecutionMode(4463);
+		pub const EarlyAndLateFragmentTestsAMD: ExecutionMode = ExecutionMode(5017);
+		pub const StencilRefReplacingEXT: ExecutionMode = ExecutionMode(5027);
+		pub const CoalescingAMDX: ExecutionMode = ExecutionMode(5069);
+		pub const MaxNodeRecursionAMDX: ExecutionMode = ExecutionMode(5071);
+		pub const StaticNumWorkgroupsAMDX: ExecutionMode = ExecutionMode(5072);
+		pub const ShaderIndexAMDX: ExecutionMode = ExecutionMode(5073);
+		pub const MaxNumWorkgroupsAMDX: ExecutionMode = ExecutionMode(5077);
+		pub const StencilRefUnchangedFrontAMD: ExecutionMode = ExecutionMode(5079);
+		pub const StencilRefGreaterFrontAMD: ExecutionMode = ExecutionMode(5080);
+		pub const StencilRefLessFrontAMD: ExecutionMode = ExecutionMode(5081);
+		pub const StencilRefUnchangedBackAMD: ExecutionMode = ExecutionMode(5082);
+		pub const StencilRefGreaterBackAMD: ExecutionMode = ExecutionMode(5083);
+		pub const StencilRefLessBackAMD: ExecutionMode = ExecutionMode(5084);
+		pub const OutputLinesNV: ExecutionMode = ExecutionMode(5269);
+		pub const OutputLinesEXT: ExecutionMode = ExecutionMode(5269);
+		pub const OutputPrimitivesNV: ExecutionMode = ExecutionMode(5270);
+		pub const OutputPrimitivesEXT: ExecutionMode = ExecutionMode(5270);
+		pub const DerivativeGroupQuadsNV: ExecutionMode = ExecutionMode(5289);
+		pub const DerivativeGroupLinearNV: ExecutionMode = ExecutionMode(5290);
+		pub const OutputTrianglesNV: ExecutionMode = ExecutionMode(5298);
+		pub const OutputTrianglesEXT: ExecutionMode = ExecutionMode(5298);
+		pub const PixelInterlockOrderedEXT: ExecutionMode = ExecutionMode(5366);
+		pub const PixelInterlockUnorderedEXT: ExecutionMode = ExecutionMode(5367);
+		pub const SampleInterlockOrderedEXT: ExecutionMode = ExecutionMode(5368);
+		pub const SampleInterlockUnorderedEXT: ExecutionMode = ExecutionMode(5369);
+		pub const ShadingRateInterlockOrderedEXT: ExecutionMode = ExecutionMode(5370);
+		pub const ShadingRateInterlockUnorderedEXT: ExecutionMode = ExecutionMode(5371);
+		pub const SharedLocalMemorySizeINTEL: ExecutionMode = ExecutionMode(5618);
+		pub const RoundingModeRTPINTEL: ExecutionMode = ExecutionMode(5620);
+		pub const RoundingModeRTNINTEL: ExecutionMode = ExecutionMode(5621);
+		pub const FloatingPointModeALTINTEL: ExecutionMode = ExecutionMode(5622);
+		pub const FloatingPointModeIEEEINTEL: ExecutionMode = ExecutionMode(5623);
+		pub const MaxWorkgroupSizeINTEL: ExecutionMode = ExecutionMode(5893);
+		pub const MaxWorkDimINTEL: ExecutionMode = ExecutionMode(5894);
+		pub const NoGlobalOffsetINTEL: ExecutionMode = ExecutionMode(5895);
+		pub const NumSIMDWorkitemsINTEL: ExecutionMode = ExecutionMode(5896);
+		pub const SchedulerTargetFmaxMhzINTEL: ExecutionMode = ExecutionMode(5903);
+		pub const StreamingInterfaceINTEL: ExecutionMode = ExecutionMode(6154);
+		pub const RegisterMapInterfaceINTEL: ExecutionMode = ExecutionMode(6160);
+		pub const NamedBarrierCountINTEL: ExecutionMode = ExecutionMode(6417);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct StorageClass(pub u32);
+	impl StorageClass {
+		pub const UniformConstant: StorageClass = StorageClass(0);
+		pub const Input: StorageClass = StorageClass(1);
+		pub const Uniform: StorageClass = StorageClass(2);
+		pub const Output: StorageClass = StorageClass(3);
+		pub const Workgroup: StorageClass = StorageClass(4);
+		pub const CrossWorkgroup: StorageClass = StorageClass(5);
+		pub const Private: StorageClass = StorageClass(6);
+		pub const Function: StorageClass = StorageClass(7);
+		pub const Generic: StorageClass = StorageClass(8);
+		pub const PushConstant: StorageClass = StorageClass(9);
+		pub const AtomicCounter: StorageClass = StorageClass(10);
+		pub const Image: StorageClass = StorageClass(11);
+		pub const StorageBuffer: StorageClass = StorageClass(12);
+		pub const TileImageEXT: StorageClass = StorageClass(4172);
+		pub const NodePayloadAMDX: StorageClass = StorageClass(5068);
+		pub const NodeOutputPayloadAMDX: StorageClass = StorageClass(5076);
+		pub const CallableDataNV: StorageClass = StorageClass(5328);
+		pub const CallableDataKHR: StorageClass = StorageClass(5328);
+		pub const IncomingCallableDataNV: StorageClass = StorageClass(5329);
+		pub const IncomingCallableDataKHR: StorageClass = StorageClass(5329);
+		pub const RayPayloadNV: StorageClass = StorageClass(5338);
+		pub const RayPayloadKHR: StorageClass = StorageClass(5338);
+		pub const HitAttributeNV: StorageClass = StorageClass(5339);
+		pub const HitAttributeKHR: StorageClass = StorageClass(5339);
+		pub const IncomingRayPayloadNV: StorageClass = StorageClass(5342);
+		pub const IncomingRayPayloadKHR: StorageClass = StorageClass(5342);
+		pub const ShaderRecordBufferNV: StorageClass = StorageClass(5343);
+		pub const ShaderRecordBufferKHR: StorageClass = StorageClass(5343);
+		pub const PhysicalStorageBuffer: StorageClass = StorageClass(5349);
+		pub const PhysicalStorageBufferEXT: StorageClass = StorageClass(5349);
+		pub const HitObjectAttributeNV: StorageClass = StorageClass(5385);
+		pub const TaskPayloadWorkgroupEXT: StorageClass = StorageClass(5402);
+		pub const CodeSectionINTEL: StorageClass = StorageClass(5605);
+		pub const DeviceOnlyINTEL: StorageClass = StorageClass(5936);
+		pub const HostOnlyINTEL: StorageClass = StorageClass(5937);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Dim(pub u32);
+	impl Dim {
+		pub const Dim1D: Dim = Dim(0);
+		pub const Dim2D: Dim = Dim(1);
+		pub const Dim3D: Dim = Dim(2);
+		pub const Cube: Dim = Dim(3);
+		pub const Rect: Dim = Dim(4);
+		pub const Buffer: Dim = Dim(5);
+		pub const SubpassData: Dim = Dim(6);
+		pub const TileImageDataEXT: Dim = Dim(4173);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct SamplerAddressingMode(pub u32);
+	impl SamplerAddressingMode {
+		pub const None: SamplerAddressingMode = SamplerAddressingMode(0);
+		pub const ClampToEdge: SamplerAddressingMode = SamplerAddressingMode(1);
+		pub const Clamp: SamplerAddressingMode = SamplerAddressingMode(2);
+		pub const Repeat: SamplerAddressingMode = SamplerAddressingMode(3);
+		pub const RepeatMirrored: SamplerAddressingMode = SamplerAddressingMode(4);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct SamplerFilterMode(pub u32);
+	impl SamplerFilterMode {
+		pub const Nearest: SamplerFilterMode = SamplerFilterMode(0);
+		pub const Linear: SamplerFilterMode = SamplerFilterMode(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ImageFormat(pub u32);
+	impl ImageFormat {
+		pub const Unknown: ImageFormat = ImageFormat(0);
+		pub const Rgba32f: ImageFormat = ImageFormat(1);
+		pub const Rgba16f: ImageFormat = ImageFormat(2);
+		pub const R32f: ImageFormat = ImageFormat(3);
+		pub const Rgba8: ImageFormat = ImageFormat(4);
+		pub const Rgba8Snorm: ImageFormat = ImageFormat(5);
+		pub const Rg32f: ImageFormat = ImageFormat(6);
+		pub const Rg16f: ImageFormat = ImageFormat(7);
+		pub const R11fG11fB10f: ImageFormat = ImageFormat(8);
+		pub const R16f: ImageFormat = ImageFormat(9);
+		pub const Rgba16: ImageFormat = ImageFormat(10);
+		pub const Rgb10A2: ImageFormat = ImageFormat(11);
+		pub const Rg16: ImageFormat = ImageFormat(12);
+		pub const Rg8: ImageFormat = ImageFormat(13);
+		pub const R16: ImageFormat = ImageFormat(14);
+		pub const R8: ImageFormat = ImageFormat(15);
+		pub const Rgba16Snorm: ImageFormat = ImageFormat(16);
+		pub const Rg16Snorm: ImageFormat = ImageFormat(17);
+		pub const Rg8Snorm: ImageFormat = ImageFormat(18);
+		pub const R16Snorm: ImageFormat = ImageFormat(19);
+		pub const R8Snorm: ImageFormat = ImageFormat(20);
+		pub const Rgba32i: ImageFormat = ImageFormat(21);
+		pub const Rgba16i: ImageFormat = ImageFormat(22);
+		pub const Rgba8i: ImageFormat = ImageFormat(23);
+		pub const R32i: ImageFormat = ImageFormat(24);
+		pub const Rg32i: ImageFormat = ImageFormat(25);
+		pub const Rg16i: ImageFormat = ImageFormat(26);
+		pub const Rg8i: ImageFormat = ImageFormat(27);
+		pub const R16i: ImageFormat = ImageFormat(28);
+		pub const R8i: ImageFormat = ImageFormat(29);
+		pub const Rgba32ui: ImageFormat = ImageFormat(30);
+		pub const Rgba16ui: ImageFormat = ImageFormat(31);
+		pub const Rgba8ui: ImageFormat = ImageFormat(32);
+		pub const R32ui: ImageFormat = ImageFormat(33);
+		pub const Rgb10a2ui: ImageFormat = ImageFormat(34);
+		pub const Rg32ui: ImageFormat = ImageFormat(35);
+		pub const Rg16ui: ImageFormat = ImageFormat(36);
+		pub const Rg8ui: ImageFormat = ImageFormat(37);
+		pub const R16ui: ImageFormat = ImageFormat(38);
+		pub const R8ui: ImageFormat = ImageFormat(39);
+		pub const R64ui: ImageFormat = ImageFormat(40);
+		pub const R64i: ImageFormat = ImageFormat(41);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ImageChannelOrder(pub u32);
+	impl ImageChannelOrder {
+		pub const R: ImageChannelOrder = ImageChannelOrder(0);
+		pub const A: ImageChannelOrder = ImageChannelOrder(1);
+		pub const RG: ImageChannelOrder = ImageChannelOrder(2);
+		pub const RA: ImageChannelOrder = ImageChannelOrder(3);
+		pub const RGB: ImageChannelOrder = ImageChannelOrder(4);
+		pub const RGBA: ImageChannelOrder = ImageChannelOrder(5);
+		pub const BGRA: ImageChannelOrder = ImageChannelOrder(6);
+		pub const ARGB: ImageChannelOrder = ImageChannelOrder(7);
+		pub const Intensity: ImageChannelOrder = ImageChannelOrder(8);
+		pub const Luminance: ImageChannelOrder = ImageChannelOrder(9);
+		pub const Rx: ImageChannelOrder = ImageChannelOrder(10);
+		pub const RGx: ImageChannelOrder = ImageChannelOrder(11);
+		pub const RGBx: ImageChannelOrder = ImageChannelOrder(12);
+		pub const Depth: ImageChannelOrder = ImageChannelOrder(13);
+		pub const DepthStencil: ImageChannelOrder = ImageChannelOrder(14);
+		pub const sRGB: ImageChannelOrder = ImageChannelOrder(15);
+		pub const sRGBx: ImageChannelOrder = ImageChannelOrder(16);
+		pub const sRGBA: ImageChannelOrder = ImageChannelOrder(17);
+		pub const sBGRA: ImageChannelOrder = ImageChannelOrder(18);
+		pub const ABGR: ImageChannelOrder = ImageChannelOrder(19);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct ImageChannelDataType(pub u32);
+	impl ImageChannelDataType {
+		pub const SnormInt8: ImageChannelDataType = ImageChannelDataType(0);
+		pub const SnormInt16: ImageChannelDataType = ImageChannelDataType(1);
+		pub const UnormInt8: ImageChannelDataType = ImageChannelDataType(2);
+		pub const UnormInt16: ImageChannelDataType = ImageChannelDataType(3);
+		pub const UnormShort565: ImageChannelDataType = ImageChannelDataType(4);
+		pub const UnormShort555: ImageChannelDataType = ImageChannelDataType(5);
+		pub const UnormInt101010: ImageChannelDataType = ImageChannelDataType(6);
+		pub const SignedInt8: ImageChannelDataType = ImageChannelDataType(7);
+		pub const SignedInt16: ImageChannelDataType = ImageChannelDataType(8);
+		pub const SignedInt32: ImageChannelDataType = ImageChannelDataType(9);
+		pub const UnsignedInt8: ImageChannelDataType = ImageChannelDataType(10);
+		pub const UnsignedInt16: ImageChannelDataType = ImageChannelDataType(11);
+		pub const UnsignedInt32: ImageChannelDataType = ImageChannelDataType(12);
+		pub const HalfFloat: ImageChannelDataType = ImageChannelDataType(13);
+		pub const Float: ImageChannelDataType = ImageChannelDataType(14);
+		pub const UnormInt24: ImageChannelDataType = ImageChannelDataType(15);
+		pub const UnormInt101010_2: ImageChannelDataType = ImageChannelDataType(16);
+		pub const UnsignedIntRaw10EXT: ImageChannelDataType = ImageChannelDataType(19);
+		pub const UnsignedIntRaw12EXT: ImageChannelDataType = ImageChannelDataType(20);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FPRoundingMode(pub u32);
+	impl FPRoundingMode {
+		pub const RTE: FPRoundingMode = FPRoundingMode(0);
+		pub const RTZ: FPRoundingMode = FPRoundingMode(1);
+		pub const RTP: FPRoundingMode = FPRoundingMode(2);
+		pub const RTN: FPRoundingMode = FPRoundingMode(3);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FPDenormMode(pub u32);
+	impl FPDenormMode {
+		pub const Preserve: FPDenormMode = FPDenormMode(0);
+		pub const FlushToZero: FPDenormMode = FPDenormMode(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct QuantizationModes(pub u32);
+	impl QuantizationModes {
+		pub const TRN: QuantizationModes = QuantizationModes(0);
+		pub const TRN_ZERO: QuantizationModes = QuantizationModes(1);
+		pub const RND: QuantizationModes = QuantizationModes(2);
+		pub const RND_ZERO: QuantizationModes = QuantizationModes(3);
+		pub const RND_INF: QuantizationModes = QuantizationModes(4);
+		pub const RND_MIN_INF: QuantizationModes = QuantizationModes(5);
+		pub const RND_CONV: QuantizationModes = QuantizationModes(6);
+		pub const RND_CONV_ODD: QuantizationModes = QuantizationModes(7);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FPOperationMode(pub u32);
+	impl FPOperationMode {
+		pub const IEEE: FPOperationMode = FPOperationMode(0);
+		pub const ALT: FPOperationMode = FPOperationMode(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct OverflowModes(pub u32);
+	impl OverflowModes {
+		pub const WRAP: OverflowModes = OverflowModes(0);
+		pub const SAT: OverflowModes = OverflowModes(1);
+		pub const SAT_ZERO: OverflowModes = OverflowModes(2);
+		pub const SAT_SYM: OverflowModes = OverflowModes(3);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct LinkageType(pub u32);
+	impl LinkageType {
+		pub const Export: LinkageType = LinkageType(0);
+		pub const Import: LinkageType = LinkageType(1);
+		pub const LinkOnceODR: LinkageType = LinkageType(2);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct AccessQualifier(pub u32);
+	impl AccessQualifier {
+		pub const ReadOnly: AccessQualifier = AccessQualifier(0);
+		pub const WriteOnly: AccessQualifier = AccessQualifier(1);
+		pub const ReadWrite: AccessQualifier = AccessQualifier(2);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct HostAccessQualifier(pub u32);
+	impl HostAccessQualifier {
+		pub const NoneINTEL: HostAccessQualifier = HostAccessQualifier(0);
+		pub const ReadINTEL: HostAccessQualifier = HostAccessQualifier(1);
+		pub const WriteINTEL: HostAccessQualifier = HostAccessQualifier(2);
+		pub const ReadWriteINTEL: HostAccessQualifier = HostAccessQualifier(3);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct FunctionParameterAttribute(pub u32);
+	impl FunctionParameterAttribute {
+		pub const Zext: FunctionParameterAttribute = FunctionParameterAttribute(0);
+		pub const Sext: FunctionParameterAttribute = FunctionParameterAttribute(1);
+		pub const ByVal: FunctionParameterAttribute = FunctionParameterAttribute(2);
+		pub const Sret: FunctionParameterAttribute = FunctionParameterAttribute(3);
+		pub const NoAlias: FunctionParameterAttribute = FunctionParameterAttribute(4);
+		pub const NoCapture: FunctionParameterAttribute = FunctionParameterAttribute(5);
+		pub const NoWrite: FunctionParameterAttribute = FunctionParameterAttribute(6);
+		pub const NoReadWrite: FunctionParameterAttribute = FunctionParameterAttribute(7);
+		pub const RuntimeAlignedINTEL: FunctionParameterAttribute = FunctionParameterAttribute(5940);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Decoration(pub u32);
+	impl Decoration {
+		pub const RelaxedPrecision: Decoration = Decoration(0);
+		pub const SpecId: Decoration = Decoration(1);
+		pub const Block: Decoration = Decoration(2);
+		pub const BufferBlock: Decoration = Decoration(3);
+		pub const RowMajor: Decoration = Decoration(4);
+		pub const ColMajor: Decoration = Decoration(5);
+		pub const ArrayStride: Decoration = Decoration(6);
+		pub const MatrixStride: Decoration = Decoration(7);
+		pub const GLSLShared: Decoration = Decoration(8);
+		pub const GLSLPacked: Decoration = Decoration(9);
+		pub const CPacked: Decoration = Decoration(10);
+		pub const BuiltIn: Decoration = Decoration(11);
+		pub const NoPerspective: Decoration = Decoration(13);
+		pub const Flat: Decoration = Decoration(14);
+		pub const Patch: Decoration = Decoration(15);
+		pub const Centroid: Decoration = Decoration(16);
+		pub const Sample: Decoration = Decoration(17);
+		pub const Invariant: Decoration = Decoration(18);
+		pub const Restrict: Decoration = Decoration(19);
+		pub const Aliased: Decoration = Decoration(20);
+		pub const Volatile: Decoration = Decoration(21);
+		pub const Constant: Decoration = Decoration(22);
+		pub const Coherent: Decoration = Decoration(23);
+		pub const NonWritable: Decoration = Decoration(24);
+		pub const NonReadable: Decoration = Decoration(25);
+		pub const Uniform: Decoration = Decoration(26);
+		pub const UniformId: Decoration = Decoration(27);
+		pub const SaturatedConversion: Decoration = Decoration(28);
+		pub const Stream: Decoration = Decoration(29);
+		pub const Location: Decoration = Decoration(30);
+		pub const Component: Decoration = Decoration(31);
+		pub const Index: Decoration = Decoration(32);
+		pub const Binding: Decoration = Decoration(33);
+		pub const DescriptorSet: Decoration = Decoration(34);
+		pub const Offset: Decoration = Decoration(35);
+		pub const XfbBuffer: Decoration = Decoration(36);
+		pub const XfbStride: Decoration = Decoration(37);
+		pub const FuncParamAttr: Decoration = Decoration(38);
+		pub const FPRoundingMode: Decoration = Decoration(39);
+		pub const FPFastMathMode: Decoration = Decoration(40);
+		pub const LinkageAttributes: Decoration = Decoration(41);
+		pub const NoContraction: Decoration = Decoration(42);
+		pub const InputAttachmentIndex: Decoration = Decoration(43);
+		pub const Alignment: Decoration = Decoration(44);
+		pub const MaxByteOffset: Decoration = Decoration(45);
+		pub const AlignmentId: Decoration = Decoration(46);
+		pub const MaxByteOffsetId: Decoration = Decoration(47);
+		pub const NoSignedWrap: Decoration = Decoration(4469);
+		pub const NoUnsignedWrap: Decoration = Decoration(4470);
+		pub const WeightTextureQCOM: Decoration = Decoration(4487);
+		pub const BlockMatchTextureQCOM: Decoration = Decoration(4488);
+		pub const ExplicitInterpAMD: Decoration = Decoration(4999);
+		pub const NodeSharesPayloadLimitsWithAMDX: Decoration = Decoration(5019);
+		pub const NodeMaxPayloadsAMDX: Decoration = Decoration(5020);
+		pub const TrackFinishWritingAMDX: Decoration = Decoration(5078);
+		pub const PayloadNodeNameAMDX: Decoration = Decoration(5091);
+		pub const OverrideCoverageNV: Decoration = Decoration(5248);
+		pub const PassthroughNV: Decoration = Decoration(5250);
+		pub const ViewportRelativeNV: Decoration = Decoration(5252);
+		pub const SecondaryViewportRelativeNV: Decoration = Decoration(5256);
+		pub const PerPrimitiveNV: Decoration = Decoration(5271);
+		pub const PerPrimitiveEXT: Decoration = Decoration(5271);
+		pub const PerViewNV: Decoration = Decoration(5272);
+		pub const PerTaskNV: Decoration = Decoration(5273);
+		pub const PerVertexKHR: Decoration = Decoration(5285);
+		pub const PerVertexNV: Decoration = Decoration(5285);
+		pub const NonUniform: Decoration = Decoration(5300);
+		pub const NonUniformEXT: Decoration = Decoration(5300);
+		pub const RestrictPointer: Decoration = Decoration(5355);
+		pub const RestrictPointerEXT: Decoration = Decoration(5355);
+		pub const AliasedPointer: Decoration = Decoration(5356);
+		pub const AliasedPointerEXT: Decoration = Decoration(5356);
+		pub const HitObjectShaderRecordBufferNV: Decoration = Decoration(5386);
+		pub const BindlessSamplerNV: Decoration = Decoration(5398);
+		pub const BindlessImageNV: Decoration = Decoration(5399);
+		pub const BoundSamplerNV: Decoration = Decoration(5400);
+		pub const BoundImageNV: Decoration = Decoration(5401);
+		pub const SIMTCallINTEL: Decoration = Decoration(5599);
+		pub const ReferencedIndirectlyINTEL: Decoration = Decoration(5602);
+		pub const ClobberINTEL: Decoration = Decoration(5607);
+		pub const SideEffectsINTEL: Decoration = Decoration(5608);
+		pub const VectorComputeVariableINTEL: Decoration = Decoration(5624);
+		pub const FuncParamIOKindINTEL: Decoration = Decoration(5625);
+		pub const VectorComputeFunctionINTEL: Decoration = Decoration(5626);
+		pub const StackCallINTEL: Decoration = Decoration(5627);
+		pub const GlobalVariableOffsetINTEL: Decoration = Decoration(5628);
+		pub const CounterBuffer: Decoration = Decoration(5634);
+		pub const HlslCounterBufferGOOGLE: Decoration = Decoration(5634);
+		pub const UserSemantic: Decoration = Decoration(5635);
+		pub const HlslSemanticGOOGLE: Decoration = Decoration(5635);
+		pub const UserTypeGOOGLE: Decoration = Decoration(5636);
+		pub const FunctionRoundingModeINTEL: Decoration = Decoration(5822);
+		pub const FunctionDenormModeINTEL: Decoration = Decoration(5823);
+		pub const RegisterINTEL: Decoration = Decoration(5825);
+		pub const MemoryINTEL: Decoration = Decoration(5826);
+		pub const NumbanksINTEL: Decoration = Decoration(5827);
+		pub const BankwidthINTEL: Decoration = Decoration(5828);
+		pub const MaxPrivateCopiesINTEL: Decoration = Decoration(5829);
+		pub const SinglepumpINTEL: Decoration = Decoration(5830);
+		pub const DoublepumpINTEL: Decoration = Decoration(5831);
+		pub const MaxReplicatesINTEL: Decoration = Decoration(5832);
+		pub const SimpleDualPortINTEL: Decoration = Decoration(5833);
+		pub const MergeINTEL: Decoration = Decoration(5834);
+		pub const BankBitsINTEL: Decoration = Decoration(5835);
+		pub const ForcePow2DepthINTEL: Decoration = Decoration(5836);
+		pub const BurstCoalesceINTEL: Decoration = Decoration(5899);
+		pub const CacheSizeINTEL: Decoration = Decoration(5900);
+		pub const DontStaticallyCoalesceINTEL: Decoration = Decoration(5901);
+		pub const PrefetchINTEL: Decoration = Decoration(5902);
+		pub const StallEnableINTEL: Decoration = Decoration(5905);
+		pub const FuseLoopsInFunctionINTEL: Decoration = Decoration(5907);
+		pub const MathOpDSPModeINTEL: Decoration = Decoration(5909);
+		pub const AliasScopeINTEL: Decoration = Decoration(5914);
+		pub const NoAliasINTEL: Decoration = Decoration(5915);
+		pub const InitiationIntervalINTEL: Decoration = Decoration(5917);
+		pub const MaxConcurrencyINTEL: Decoration = Decoration(5918);
+		pub const PipelineEnableINTEL: Decoration = Decoration(5919);
+		pub const BufferLocationINTEL: Decoration = Decoration(5921);
+		pub const IOPipeStorageINTEL: Decoration = Decoration(5944);
+		pub const FunctionFloatingPointModeINTEL: Decoration = Decoration(6080);
+		pub const SingleElementVectorINTEL: Decoration = Decoration(6085);
+		pub const VectorComputeCallableFunctionINTEL: Decoration = Decoration(6087);
+		pub const MediaBlockIOINTEL: Decoration = Decoration(6140);
+		pub const InitModeINTEL: Decoration = Decoration(6147);
+		pub const ImplementInRegisterMapINTEL: Decoration = Decoration(6148);
+		pub const HostAccessINTEL: Decoration = Decoration(6168);
+		pub const FPMaxErrorDecorationINTEL: Decoration = Decoration(6170);
+		pub const LatencyControlLabelINTEL: Decoration = Decoration(6172);
+		pub const LatencyControlConstraintINTEL: Decoration = Decoration(6173);
+		pub const ConduitKernelArgumentINTEL: Decoration = Decoration(6175);
+		pub const RegisterMapKernelArgumentINTEL: Decoration = Decoration(6176);
+		pub const MMHostInterfaceAddressWidthINTEL: Decoration = Decoration(6177);
+		pub const MMHostInterfaceDataWidthINTEL: Decoration = Decoration(6178);
+		pub const MMHostInterfaceLatencyINTEL: Decoration = Decoration(6179);
+		pub const MMHostInterfaceReadWriteModeINTEL: Decoration = Decoration(6180);
+		pub const MMHostInterfaceMaxBurstINTEL: Decoration = Decoration(6181);
+		pub const MMHostInterfaceWaitRequestINTEL: Decoration = Decoration(6182);
+		pub const StableKernelArgumentINTEL: Decoration = Decoration(6183);
+		pub const CacheControlLoadINTEL: Decoration = Decoration(6442);
+		pub const CacheControlStoreINTEL: Decoration = Decoration(6443);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct BuiltIn(pub u32);
+	impl BuiltIn {
+		pub const Position: BuiltIn = BuiltIn(0);
+		pub const PointSize: BuiltIn = BuiltIn(1);
+		pub const ClipDistance: BuiltIn = BuiltIn(3);
+		pub const CullDistance: BuiltIn = BuiltIn(4);
+		pub const VertexId: BuiltIn = BuiltIn(5);
+		pub const InstanceId: BuiltIn = BuiltIn(6);
+		pub const PrimitiveId: BuiltIn = BuiltIn(7);
+		pub const InvocationId: BuiltIn = BuiltIn(8);
+		pub const Layer: BuiltIn = BuiltIn(9);
+		pub const ViewportIndex: BuiltIn = BuiltIn(10);
+		pub const TessLevelOuter: BuiltIn = BuiltIn(11);
+		pub const TessLevelInner: BuiltIn = BuiltIn(12);
+		pub const TessCoord: BuiltIn = BuiltIn(13);
+		pub const PatchVertices: BuiltIn = BuiltIn(14);
+		pub const FragCoord: BuiltIn = BuiltIn(15);
+		pub const PointCoord: BuiltIn = BuiltIn(16);
+		pub const FrontFacing: BuiltIn = BuiltIn(17);
+		pub const SampleId: BuiltIn = BuiltIn(18);
+		pub const SamplePosition: BuiltIn = BuiltIn(19);
+		pub const SampleMask: BuiltIn = BuiltIn(20);
+		pub const FragDepth: BuiltIn = BuiltIn(22);
+		pub const HelperInvocation: BuiltIn = BuiltIn(23);
+		pub const NumWorkgroups: BuiltIn = BuiltIn(24);
+		pub const WorkgroupSize: BuiltIn = BuiltIn(25);
+		pub const WorkgroupId: BuiltIn = BuiltIn(26);
+		pub const LocalInvocationId: BuiltIn = BuiltIn(27);
+		pub const GlobalInvocationId: BuiltIn = BuiltIn(28);
+		pub const LocalInvocationIndex: BuiltIn = BuiltIn(29);
+		pub const WorkDim: BuiltIn = BuiltIn(30);
+		pub const GlobalSize: BuiltIn = BuiltIn(31);
+		pub const EnqueuedWorkgroupSize: BuiltIn = BuiltIn(32);
+		pub const GlobalOffset: BuiltIn = BuiltIn(33);
+		pub const GlobalLinearId: BuiltIn = BuiltIn(34);
+		pub const SubgroupSize: BuiltIn = BuiltIn(36);
+		pub const SubgroupMaxSize: BuiltIn = BuiltIn(37);
+		pub const NumSubgroups: BuiltIn = BuiltIn(38);
+		pub const NumEnqueuedSubgroups: BuiltIn = BuiltIn(39);
+		pub const SubgroupId: BuiltIn = BuiltIn(40);
+		pub const SubgroupLocalInvocationId: BuiltIn = BuiltIn(41);
+		pub const VertexIndex: BuiltIn = BuiltIn(42);
+		pub const InstanceIndex: BuiltIn = BuiltIn(43);
+		pub const CoreIDARM: BuiltIn = BuiltIn(4160);
+		pub const CoreCountARM: BuiltIn = BuiltIn(4161);
+		pub const CoreMaxIDARM: BuiltIn = BuiltIn(4162);
+		pub const WarpIDARM: BuiltIn = BuiltIn(4163);
+		pub const WarpMaxIDARM: BuiltIn = BuiltIn(4164);
+		pub const SubgroupEqMask: BuiltIn = BuiltIn(4416);
+		pub const SubgroupEqMaskKHR: BuiltIn = BuiltIn(4416);
+		pub const SubgroupGeMask: BuiltIn = BuiltIn(4417);
+		pub const SubgroupGeMaskKHR: BuiltIn = BuiltIn(4417);
+		pub const SubgroupGtMask: BuiltIn = BuiltIn(4418);
+		pub const SubgroupGtMaskKHR: BuiltIn = BuiltIn(4418);
+		pub const SubgroupLeMask: BuiltIn = BuiltIn(4419);
+		pub const SubgroupLeMaskKHR: BuiltIn = BuiltIn(4419);
+		pub const SubgroupLtMask: BuiltIn = BuiltIn(4420);
+		pub const SubgroupLtMaskKHR: BuiltIn = BuiltIn(4420);
+		pub const BaseVertex: BuiltIn = BuiltIn(4424);
+		pub const BaseInstance: BuiltIn = BuiltIn(4425);
+		pub const DrawIndex: BuiltIn = BuiltIn(4426);
+		pub const PrimitiveShadingRateKHR: BuiltIn = BuiltIn(4432);
+		pub const DeviceIndex: BuiltIn = BuiltIn(4438);
+		pub const ViewIndex: BuiltIn = BuiltIn(4440);
+		pub const ShadingRateKHR: BuiltIn = BuiltIn(4444);
+		pub const BaryCoordNoPerspAMD: BuiltIn = BuiltIn(4992);
+		pub const BaryCoordNoPerspCentroidAMD: BuiltIn = BuiltIn(4993);
+		pub const BaryCoordNoPerspSampleAMD: BuiltIn = BuiltIn(4994);
+		pub const BaryCoordSmoothAMD: BuiltIn = BuiltIn(4995);
+		pub const BaryCoordSmoothCentroidAMD: BuiltIn = BuiltIn(4996);
+		pub const BaryCoordSmoothSampleAMD: BuiltIn = BuiltIn(4997);
+		pub const BaryCoordPullModelAMD: BuiltIn = BuiltIn(4998);
+		pub const FragStencilRefEXT: BuiltIn = BuiltIn(5014);
+		pub const CoalescedInputCountAMDX: BuiltIn = BuiltIn(5021);
+		pub const ShaderIndexAMDX: BuiltIn = BuiltIn(5073);
+		pub const ViewportMaskNV: BuiltIn = BuiltIn(5253);
+		pub const SecondaryPositionNV: BuiltIn = BuiltIn(5257);
+		pub const SecondaryViewportMaskNV: BuiltIn = BuiltIn(5258);
+		pub const PositionPerViewNV: BuiltIn = BuiltIn(5261);
+		pub const ViewportMaskPerViewNV: BuiltIn = BuiltIn(5262);
+		pub const FullyCoveredEXT: BuiltIn = BuiltIn(5264);
+		pub const TaskCountNV: BuiltIn = BuiltIn(5274);
+		pub const PrimitiveCountNV: BuiltIn = BuiltIn(5275);
+		pub const PrimitiveIndicesNV: BuiltIn = BuiltIn(5276);
+		pub const ClipDistancePerViewNV: BuiltIn = BuiltIn(5277);
+		pub const CullDistancePerViewNV: BuiltIn = BuiltIn(5278);
+		pub const LayerPerViewNV: BuiltIn = BuiltIn(5279);
+		pub const MeshViewCountNV: BuiltIn = BuiltIn(5280);
+		pub const MeshViewIndicesNV: BuiltIn = BuiltIn(5281);
+		pub const BaryCoordKHR: BuiltIn = BuiltIn(5286);
+		pub const BaryCoordNV: BuiltIn = BuiltIn(5286);
+		pub const BaryCoordNoPerspKHR: BuiltIn = BuiltIn(5287);
+		pub const BaryCoordNoPerspNV: BuiltIn = BuiltIn(5287);
+		pub const FragSizeEXT: BuiltIn = BuiltIn(5292);
+		pub const FragmentSizeNV: BuiltIn = BuiltIn(5292);
+		pub const FragInvocationCountEXT: BuiltIn = BuiltIn(5293);
+		pub const InvocationsPerPixelNV: BuiltIn = BuiltIn(5293);
+		pub const PrimitivePointIndicesEXT: BuiltIn = BuiltIn(5294);
+		pub const PrimitiveLineIndicesEXT: BuiltIn = BuiltIn(5295);
+		pub const PrimitiveTriangleIndicesEXT: BuiltIn = BuiltIn(5296);
+		pub const CullPrimitiveEXT: BuiltIn = BuiltIn(5299);
+		pub const LaunchIdNV: BuiltIn = BuiltIn(5319);
+		pub const LaunchIdKHR: BuiltIn = BuiltIn(5319);
+		pub const LaunchSizeNV: BuiltIn = BuiltIn(5320);
+		pub const LaunchSizeKHR: BuiltIn = BuiltIn(5320);
+		pub const WorldRayOriginNV: BuiltIn = BuiltIn(5321);
+		pub const WorldRayOriginKHR: BuiltIn = BuiltIn(5321);
+		pub const WorldRayDirectionNV: BuiltIn = BuiltIn(5322);
+		pub const WorldRayDirectionKHR: BuiltIn = BuiltIn(5322);
+		pub const ObjectRayOriginNV: BuiltIn = BuiltIn(5323);
+		pub const ObjectRayOriginKHR: BuiltIn = BuiltIn(5323);
+		pub const ObjectRayDirectionNV: BuiltIn = BuiltIn(5324);
+		pub const ObjectRayDirectionKHR: BuiltIn = BuiltIn(5324);
+		pub const RayTminNV: BuiltIn = BuiltIn(5325);
+		pub const RayTminKHR: BuiltIn = BuiltIn(5325);
+		pub const RayTmaxNV: BuiltIn = BuiltIn(5326);
+		pub const RayTmaxKHR: BuiltIn = BuiltIn(5326);
+		pub const InstanceCustomIndexNV: BuiltIn = BuiltIn(5327);
+		pub const InstanceCustomIndexKHR: BuiltIn = BuiltIn(5327);
+		pub const ObjectToWorldNV: BuiltIn = BuiltIn(5330);
+		pub const ObjectToWorldKHR: BuiltIn = BuiltIn(5330);
+		pub const WorldToObjectNV: BuiltIn = BuiltIn(5331);
+		pub const WorldToObjectKHR: BuiltIn = BuiltIn(5331);
+		pub const HitTNV: BuiltIn = BuiltIn(5332);
+		pub const HitKindNV: BuiltIn = BuiltIn(5333);
+		pub const HitKindKHR: BuiltIn = BuiltIn(5333);
+		pub const CurrentRayTimeNV: BuiltIn = BuiltIn(5334);
+		pub const HitTriangleVertexPositionsKHR: BuiltIn = BuiltIn(5335);
+		pub const IncomingRayFlagsNV: BuiltIn = BuiltIn(5351);
+		pub const IncomingRayFlagsKHR: BuiltIn = BuiltIn(5351);
+		pub const RayGeometryIndexKHR: BuiltIn = BuiltIn(5352);
+		pub const WarpsPerSMNV: BuiltIn = BuiltIn(5374);
+		pub const SMCountNV: BuiltIn = BuiltIn(5375);
+		pub const WarpIDNV: BuiltIn = BuiltIn(5376);
+		pub const SMIDNV: BuiltIn = BuiltIn(5377);
+		pub const CullMaskKHR: BuiltIn = BuiltIn(6021);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Scope(pub u32);
+	impl Scope {
+		pub const CrossDevice: Scope = Scope(0);
+		pub const Device: Scope = Scope(1);
+		pub const Workgroup: Scope = Scope(2);
+		pub const Subgroup: Scope = Scope(3);
+		pub const Invocation: Scope = Scope(4);
+		pub const QueueFamily: Scope = Scope(5);
+		pub const QueueFamilyKHR: Scope = Scope(5);
+		pub const ShaderCallKHR: Scope = Scope(6);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct GroupOperation(pub u32);
+	impl GroupOperation {
+		pub const Reduce: GroupOperation = GroupOperation(0);
+		pub const InclusiveScan: GroupOperation = GroupOperation(1);
+		pub const ExclusiveScan: GroupOperation = GroupOperation(2);
+		pub const ClusteredReduce: GroupOperation = GroupOperation(3);
+		pub const PartitionedReduceNV: GroupOperation = GroupOperation(6);
+		pub const PartitionedInclusiveScanNV: GroupOperation = GroupOperation(7);
+		pub const PartitionedExclusiveScanNV: GroupOperation = GroupOperation(8);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct KernelEnqueueFlags(pub u32);
+	impl KernelEnqueueFlags {
+		pub const NoWait: KernelEnqueueFlags = KernelEnqueueFlags(0);
+		pub const WaitKernel: KernelEnqueueFlags = KernelEnqueueFlags(1);
+		pub const WaitWorkGroup: KernelEnqueueFlags = KernelEnqueueFlags(2);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Capability(pub u32);
+	impl Capability {
+		pub const Matrix: Capability = Capability(0);
+		pub const Shader: Capability = Capability(1);
+		pub const Geometry: Capability = Capability(2);
+		pub const Tessellation: Capability = Capability(3);
+		pub const Addresses: Capability = Capability(4);
+		pub const Linkage: Capability = Capability(5);
+		pub const Kernel: Capability = Capability(6);
+		pub const Vector16: Capability = Capability(7);
+		pub const Float16Buffer: Capability = Capability(8);
+		pub const Float16: Capability = Capability(9);
+		pub const Float64: Capability = Capability(10);
+		pub const Int64: Capability = Capability(11);
+		pub const Int64Atomics: Capability = Capability(12);
+		pub const ImageBasic: Capability = Capability(13);
+		pub const ImageReadWrite: Capability = Capability(14);
+		pub const ImageMipmap: Capability = Capability(15);
+		pub const Pipes: Capability = Capability(17);
+		pub const Groups: Capability = Capability(18);
+		pub const DeviceEnqueue: Capability = Capability(19);
+		pub const LiteralSampler: Capability = Capability(20);
+		pub const AtomicStorage: Capability = Capability(21);
+		pub const Int16: Capability = Capability(22);
+		pub const TessellationPointSize: Capability = Capability(23);
+		pub const GeometryPointSize: Capability = Capability(24);
+		pub const ImageGatherExtended: Capability = Capability(25);
+		pub const StorageImageMultisample: Capability = Capability(27);
+		pub const UniformBufferArrayDynamicIndexing: Capability = Capability(28);
+		pub const SampledImageArrayDynamicIndexing: Capability = Capability(29);
+		pub const StorageBufferArrayDynamicIndexing: Capability = Capability(30);
+		pub const StorageImageArrayDynamicIndexing: Capability = Capability(31);
+		pub const ClipDistance: Capability = Capability(32);
+		pub const CullDistance: Capability = Capability(33);
+		pub const ImageCubeArray: Capability = Capability(34);
+		pub const SampleRateShading: Capability = Capability(35);
+		pub const ImageRect: Capability = Capability(36);
+		pub const SampledRect: Capability = Capability(37);
+		pub const GenericPointer: Capability = Capability(38);
+		pub const Int8: Capability = Capability(39);
+		pub const InputAttachment: Capability = Capability(40);
+		pub const SparseResidency: Capability = Capability(41);
+		pub const MinLod: Capability = Capability(42);
+		pub const Sampled1D: Capability = Capability(43);
+		pub const Image1D: Capability = Capability(44);
+		pub const SampledCubeArray: Capability = Capability(45);
+		pub const SampledBuffer: Capability = Capability(46);
+		pub const ImageBuffer: Capability = Capability(47);
+		pub const ImageMSArray: Capability = Capability(48);
+		pub const StorageImageExtendedFormats: Capability = Capability(49);
+		pub const ImageQuery: Capability = Capability(50);
+		pub const DerivativeControl: Capability = Capability(51);
+		pub const InterpolationFunction: Capability = Capability(52);
+		pub const TransformFeedback: Capability = Capability(53);
+		pub const GeometryStreams: Capability = Capability(54);
+		pub const StorageImageReadWithoutFormat: Capability = Capability(55);
+		pub const StorageImageWriteWithoutFormat: Capability = Capability(56);
+		pub const MultiViewport: Capability = Capability(57);
+		pub const SubgroupDispatch: Capability = Capability(58);
+		pub const NamedBarrier: Capability = Capability(59);
+		pub const PipeStorage: Capability = Capability(60);
+		pub const GroupNonUniform: Capability = Capability(61);
+		pub const GroupNonUniformVote: Capability = Capability(62);
+		pub const GroupNonUniformArithmetic: Capability = Capability(63);
+		pub const GroupNonUniformBallot: Capability = Capability(64);
+		pub const GroupNonUniformShuffle: Capability = Capability(65);
+		pub const GroupNonUniformShuffleRelative: Capability = Capability(66);
+		pub const GroupNonUniformClustered: Capability = Capability(67);
+		pub const GroupNonUniformQuad: Capability = Capability(68);
+		pub const ShaderLayer: Capability = Capability(69);
+		pub const ShaderViewportIndex: Capability = Capability(70);
+		pub const UniformDecoration: Capability = Capability(71);
+		pub const CoreBuiltinsARM: Capability = Capability(4165);
+		pub const TileImageColorReadAccessEXT: Capability = Capability(4166);
+		pub const TileImageDepthReadAccessEXT: Capability = Capability(4167);
+		pub const TileImageStencilReadAccessEXT: Capability = Capability(4168);
+		pub const FragmentShadingRateKHR: Capability = Capability(4422);
+		pub const SubgroupBallotKHR: Capability = Capability(4423);
+		pub const DrawParameters: Capability = Capability(4427);
+		pub const WorkgroupMemoryExplicitLayoutKHR: Capability = Capability(4428);
+		pub const WorkgroupMemoryExplicitLayout8BitAccessKHR: Capability = Capability(4429);
+		pub const WorkgroupMemoryExplicitLayout16BitAccessKHR: Capability = Capability(4430);
+		pub const SubgroupVoteKHR: Capability = Capability(4431);
+		pub const StorageBuffer16BitAccess: Capability = Capability(4433);
+		pub const StorageUniformBufferBlock16: Capability = Capability(4433);
+		pub const UniformAndStorageBuffer16BitAccess: Capability = Capability(4434);
+		pub const StorageUniform16: Capability = Capability(4434);
+		pub const StoragePushConstant16: Capability = Capability(4435);
+		pub const StorageInputOutput16: Capability = Capability(4436);
+		pub const DeviceGroup: Capability = Capability(4437);
+		pub const MultiView: Capability = Capability(4439);
+		pub const VariablePointersStorageBuffer: Capability = Capability(4441);
+		pub const VariablePointers: Capability = Capability(4442);
+		pub const AtomicStorageOps: Capability = Capability(4445);
+		pub const SampleMaskPostDepthCoverage: Capability = Capability(4447);
+		pub const StorageBuffer8BitAccess: Capability = Capability(4448);
+		pub const UniformAndStorageBuffer8BitAccess: Capability = Capability(4449);
+		pub const StoragePushConstant8: Capability = Capability(4450);
+		pub const DenormPreserve: Capability = Capability(4464);
+		pub const DenormFlushToZero: Capability = Capability(4465);
+		pub const SignedZeroInfNanPreserve: Capability = Capability(4466);
+		pub const RoundingModeRTE: Capability = Capability(4467);
+		pub const RoundingModeRTZ: Capability = Capability(4468);
+		pub const RayQueryProvisionalKHR: Capability = Capability(4471);
+		pub const RayQueryKHR: Capability = Capability(4472);
+		pub const RayTraversalPrimitiveCullingKHR: Capability = Capability(4478);
+		pub const RayTracingKHR: Capability = Capability(4479);
+		pub const TextureSampleWeightedQCOM: Capability = Capability(4484);
+		pub const TextureBoxFilterQCOM: Capability = Capability(4485);
+		pub const TextureBlockMatchQCOM: Capability = Capability(4486);
+		pub const Float16ImageAMD: Capability = Capability(5008);
+		pub const ImageGatherBiasLodAMD: Capability = Capability(5009);
+		pub const FragmentMaskAMD: Capability = Capability(5010);
+		pub const StencilExportEXT: Capability = Capability(5013);
+		pub const ImageReadWriteLodAMD: Capability = Capability(5015);
+		pub const Int64ImageEXT: Capability = Capability(5016);
+		pub const ShaderClockKHR: Capability = Capability(5055);
+		pub const ShaderEnqueueAMDX: Capability = Capability(5067);
+		pub const SampleMaskOverrideCoverageNV: Capability = Capability(5249);
+		pub const GeometryShaderPassthroughNV: Capability = Capability(5251);
+		pub const ShaderViewportIndexLayerEXT: Capability = Capability(5254);
+		pub const ShaderViewportIndexLayerNV: Capability = Capability(5254);
+		pub const ShaderViewportMaskNV: Capability = Capability(5255);
+		pub const ShaderStereoViewNV: Capability = Capability(5259);
+		pub const PerViewAttributesNV: Capability = Capability(5260);
+		pub const FragmentFullyCoveredEXT: Capability = Capability(5265);
+		pub const MeshShadingNV: Capability = Capability(5266);
+		pub const ImageFootprintNV: Capability = Capability(5282);
+		pub const MeshShadingEXT: Capability = Capability(5283);
+		pub const FragmentBarycentricKHR: Capability = Capability(5284);
+		pub const FragmentBarycentricNV: Capability = Capability(5284);
+		pub const ComputeDerivativeGroupQuadsNV: Capability = Capability(5288);
+		pub const FragmentDensityEXT: Capability = Capability(5291);
+		pub const ShadingRateNV: Capability = Capability(5291);
+		pub const GroupNonUniformPartitionedNV: Capability = Capability(5297);
+		pub const ShaderNonUniform: Capability = Capability(5301);
+		pub const ShaderNonUniformEXT: Capability = Capability(5301);
+		pub const RuntimeDescriptorArray: Capability = Capability(5302);
+		pub const RuntimeDescriptorArrayEXT: Capability = Capability(5302);
+		pub const InputAttachmentArrayDynamicIndexing: Capability = Capability(5303);
+		pub const InputAttachmentArrayDynamicIndexingEXT: Capability = Capability(5303);
+		pub const UniformTexelBufferArrayDynamicIndexing: Capability = Capability(5304);
+		pub const UniformTexelBufferArrayDynamicIndexingEXT: Capability = Capability(5304);
+		pub const StorageTexelBufferArrayDynamicIndexing: Capability = Capability(5305);
+		pub const StorageTexelBufferArrayDynamicIndexingEXT: Capability = Capability(5305);
+		pub const UniformBufferArrayNonUniformIndexing: Capability = Capability(5306);
+		pub const UniformBufferArrayNonUniformIndexingEXT: Capability = Capability(5306);
+		pub const SampledImageArrayNonUniformIndexing: Capability = Capability(5307);
+		pub const SampledImageArrayNonUniformIndexingEXT: Capability = Capability(5307);
+		pub const StorageBufferArrayNonUniformIndexing: Capability = Capability(5308);
+		pub const StorageBufferArrayNonUniformIndexingEXT: Capability = Capability(5308);
+		pub const StorageImageArrayNonUniformIndexing: Capability = Capability(5309);
+		pub const StorageImageArrayNonUniformIndexingEXT: Capability = Capability(5309);
+		pub const InputAttachmentArrayNonUniformIndexing: Capability = Capability(5310);
+		pub const InputAttachmentArrayNonUniformIndexingEXT: Capability = Capability(5310);
+		pub const UniformTexelBufferArrayNonUniformIndexing: Capability = Capability(5311);
+		pub const UniformTexelBufferArrayNonUniformIndexingEXT: Capability = Capability(5311);
+		pub const StorageTexelBufferArrayNonUniformIndexing: Capability = Capability(5312);
+		pub const StorageTexelBufferArrayNonUniformIndexingEXT: Capability = Capability(5312);
+		pub const RayTracingPositionFetchKHR: Capability = Capability(5336);
+		pub const RayTracingNV: Capability = Capability(5340);
+		pub const RayTracingMotionBlurNV: Capability = Capability(5341);
+		pub const VulkanMemoryModel: Capability = Capability(5345);
+		pub const VulkanMemoryModelKHR: Capability = Capability(5345);
+		pub const VulkanMemoryModelDeviceScope: Capability = Capability(5346);
+		pub const VulkanMemoryModelDeviceScopeKHR: Capability = Capability(5346);
+		pub const PhysicalStorageBufferAddresses: Capability = Capability(5347);
+		pub const PhysicalStorageBufferAddressesEXT: Capability = Capability(5347);
+		pub const ComputeDerivativeGroupLinearNV: Capability = Capability(5350);
+		pub const RayTracingProvisionalKHR: Capability = Capability(5353);
+		pub const CooperativeMatrixNV: Capability = Capability(5357);
+		pub const FragmentShaderSampleInterlockEXT: Capability = Capability(5363);
+		pub const FragmentShaderShadingRateInterlockEXT: Capability = Capability(5372);
+		pub const ShaderSMBuiltinsNV: Capability = Capability(5373);
+		pub const FragmentShaderPixelInterlockEXT: Capability = Capability(5378);
+		pub const DemoteToHelperInvocation: Capability = Capability(5379);
+		pub const DemoteToHelperInvocationEXT: Capability = Capability(5379);
+		pub const RayTracingOpacityMicromapEXT: Capability = Capability(5381);
+		pub const ShaderInvocationReorderNV: Capability = Capability(5383);
+		pub const BindlessTextureNV: Capability = Capability(5390);
+		pub const RayQueryPositionFetchKHR: Capability = Capability(5391);
+		pub const SubgroupShuffleINTEL: Capability = Capability(5568);
+		pub const SubgroupBufferBlockIOINTEL: Capability = Capability(5569);
+		pub const SubgroupImageBlockIOINTEL: Capability = Capability(5570);
+		pub const SubgroupImageMediaBlockIOINTEL: Capability = Capability(5579);
+		pub const RoundToInfinityINTEL: Capability = Capability(5582);
+		pub const FloatingPointModeINTEL: Capability = Capability(5583);
+		pub const IntegerFunctions2INTEL: Capability = Capability(5584);
+		pub const FunctionPointersINTEL: Capability = Capability(5603);
+		pub const IndirectReferencesINTEL: Capability = Capability(5604);
+		pub const AsmINTEL: Capability = Capability(5606);
+		pub const AtomicFloat32MinMaxEXT: Capability = Capability(5612);
+		pub const AtomicFloat64MinMaxEXT: Capability = Capability(5613);
+		pub const AtomicFloat16MinMaxEXT: Capability = Capability(5616);
+		pub const VectorComputeINTEL: Capability = Capability(5617);
+		pub const VectorAnyINTEL: Capability = Capability(5619);
+		pub const ExpectAssumeKHR: Capability = Capability(5629);
+		pub const SubgroupAvcMotionEstimationINTEL: Capability = Capability(5696);
+		pub const SubgroupAvcMotionEstimationIntraINTEL: Capability = Capability(5697);
+		pub const SubgroupAvcMotionEstimationChromaINTEL: Capability = Capability(5698);
+		pub const VariableLengthArrayINTEL: Capability = Capability(5817);
+		pub const FunctionFloatControlINTEL: Capability = Capability(5821);
+		pub const FPGAMemoryAttributesINTEL: Capability = Capability(5824);
+		pub const FPFastMathModeINTEL: Capability = Capability(5837);
+		pub const ArbitraryPrecisionIntegersINTEL: Capability = Capability(5844);
+		pub const ArbitraryPrecisionFloatingPointINTEL: Capability = Capability(5845);
+		pub const UnstructuredLoopControlsINTEL: Capability = Capability(5886);
+		pub const FPGALoopControlsINTEL: Capability = Capability(5888);
+		pub const KernelAttributesINTEL: Capability = Capability(5892);
+		pub const FPGAKernelAttributesINTEL: Capability = Capability(5897);
+		pub const FPGAMemoryAccessesINTEL: Capability = Capability(5898);
+		pub const FPGAClusterAttributesINTEL: Capability = Capability(5904);
+		pub const LoopFuseINTEL: Capability = Capability(5906);
+		pub const FPGADSPControlINTEL: Capability = Capability(5908);
+		pub const MemoryAccessAliasingINTEL: Capability = Capability(5910);
+		pub const FPGAInvocationPipeliningAttributesINTEL: Capability = Capability(5916);
+		pub const FPGABufferLocationINTEL: Capability = Capability(5920);
+		pub const ArbitraryPrecisionFixedPointINTEL: Capability = Capability(5922);
+		pub const USMStorageClassesINTEL: Capability = Capability(5935);
+		pub const RuntimeAlignedAttributeINTEL: Capability = Capability(5939);
+		pub const IOPipesINTEL: Capability = Capability(5943);
+		pub const BlockingPipesINTEL: Capability = Capability(5945);
+		pub const FPGARegINTEL: Capability = Capability(5948);
+		pub const DotProductInputAll: Capability = Capability(6016);
+		pub const DotProductInputAllKHR: Capability = Capability(6016);
+		pub const DotProductInput4x8Bit: Capability = Capability(6017);
+		pub const DotProductInput4x8BitKHR: Capability = Capability(6017);
+		pub const DotProductInput4x8BitPacked: Capability = Capability(6018);
+		pub const DotProductInput4x8BitPackedKHR: Capability = Capability(6018);
+		pub const DotProduct: Capability = Capability(6019);
+		pub const DotProductKHR: Capability = Capability(6019);
+		pub const RayCullMaskKHR: Capability = Capability(6020);
+		pub const CooperativeMatrixKHR: Capability = Capability(6022);
+		pub const BitInstructions: Capability = Capability(6025);
+		pub const GroupNonUniformRotateKHR: Capability = Capability(6026);
+		pub const AtomicFloat32AddEXT: Capability = Capability(6033);
+		pub const AtomicFloat64AddEXT: Capability = Capability(6034);
+		pub const LongConstantCompositeINTEL: Capability = Capability(6089);
+		pub const OptNoneINTEL: Capability = Capability(6094);
+		pub const AtomicFloat16AddEXT: Capability = Capability(6095);
+		pub const DebugInfoModuleINTEL: Capability = Capability(6114);
+		pub const BFloat16ConversionINTEL: Capability = Capability(6115);
+		pub const SplitBarrierINTEL: Capability = Capability(6141);
+		pub const GlobalVariableFPGADecorationsINTEL: Capability = Capability(6146);
+		pub const FPGAKernelAttributesv2INTEL: Capability = Capability(6161);
+		pub const GlobalVariableHostAccessINTEL: Capability = Capability(6167);
+		pub const FPMaxErrorINTEL: Capability = Capability(6169);
+		pub const FPGALatencyControlINTEL: Capability = Capability(6171);
+		pub const FPGAArgumentInterfacesINTEL: Capability = Capability(6174);
+		pub const GroupUniformArithmeticKHR: Capability = Capability(6400);
+		pub const CacheControlsINTEL: Capability = Capability(6441);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct RayQueryIntersection(pub u32);
+	impl RayQueryIntersection {
+		pub const RayQueryCandidateIntersectionKHR: RayQueryIntersection = RayQueryIntersection(0);
+		pub const RayQueryCommittedIntersectionKHR: RayQueryIntersection = RayQueryIntersection(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct RayQueryCommittedIntersectionType(pub u32);
+	impl RayQueryCommittedIntersectionType {
+		pub const RayQueryCommittedIntersectionNoneKHR: RayQueryCommittedIntersectionType = RayQueryCommittedIntersectionType(0);
+		pub const RayQueryCommittedIntersectionTriangleKHR: RayQueryCommittedIntersectionType = RayQueryCommittedIntersectionType(1);
+		pub const RayQueryCommittedIntersectionGeneratedKHR: RayQueryCommittedIntersectionType = RayQueryCommittedIntersectionType(2);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct RayQueryCandidateIntersectionType(pub u32);
+	impl RayQueryCandidateIntersectionType {
+		pub const RayQueryCandidateIntersectionTriangleKHR: RayQueryCandidateIntersectionType = RayQueryCandidateIntersectionType(0);
+		pub const RayQueryCandidateIntersectionAABBKHR: RayQueryCandidateIntersectionType = RayQueryCandidateIntersectionType(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct PackedVectorFormat(pub u32);
+	impl PackedVectorFormat {
+		pub const PackedVectorFormat4x8Bit: PackedVectorFormat = PackedVectorFormat(0);
+		pub const PackedVectorFormat4x8BitKHR: PackedVectorFormat = PackedVectorFormat(0);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct CooperativeMatrixOperandsMask(pub u32);
+	impl CooperativeMatrixOperandsMask {
+		pub const NoneKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(0);
+		pub const MatrixASignedComponentsKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(1);
+		pub const MatrixBSignedComponentsKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(2);
+		pub const MatrixCSignedComponentsKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(4);
+		pub const MatrixResultSignedComponentsKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(8);
+		pub const SaturatingAccumulationKHR: CooperativeMatrixOperandsMask = CooperativeMatrixOperandsMask(16);
+	}
+	impl CooperativeMatrixOperandsMask {
+		pub const fn contains(self, other: CooperativeMatrixOperandsMask) -> bool { self.0 & other.0 == other.0 }
+	}
+	impl core::ops::BitOr for CooperativeMatrixOperandsMask { type Output = CooperativeMatrixOperandsMask; fn bitor(self, rhs: CooperativeMatrixOperandsMask) -> CooperativeMatrixOperandsMask { CooperativeMatrixOperandsMask(self.0 | rhs.0) } }
+	impl core::ops::BitAnd for CooperativeMatrixOperandsMask { type Output = CooperativeMatrixOperandsMask; fn bitand(self, rhs: CooperativeMatrixOperandsMask) -> CooperativeMatrixOperandsMask { CooperativeMatrixOperandsMask(self.0 & rhs.0) } }
+	impl core::ops::BitXor for CooperativeMatrixOperandsMask { type Output = CooperativeMatrixOperandsMask; fn bitxor(self, rhs: CooperativeMatrixOperandsMask) -> CooperativeMatrixOperandsMask { CooperativeMatrixOperandsMask(self.0 ^ rhs.0) } }
+	impl core::ops::BitOrAssign for CooperativeMatrixOperandsMask { fn bitor_assign(&mut self, rhs: CooperativeMatrixOperandsMask) { self.0 |= rhs.0; } }
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct CooperativeMatrixLayout(pub u32);
+	impl CooperativeMatrixLayout {
+		pub const RowMajorKHR: CooperativeMatrixLayout = CooperativeMatrixLayout(0);
+		pub const ColumnMajorKHR: CooperativeMatrixLayout = CooperativeMatrixLayout(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct CooperativeMatrixUse(pub u32);
+	impl CooperativeMatrixUse {
+		pub const MatrixAKHR: CooperativeMatrixUse = CooperativeMatrixUse(0);
+		pub const MatrixBKHR: CooperativeMatrixUse = CooperativeMatrixUse(1);
+		pub const MatrixAccumulatorKHR: CooperativeMatrixUse = CooperativeMatrixUse(2);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct InitializationModeQualifier(pub u32);
+	impl InitializationModeQualifier {
+		pub const InitOnDeviceReprogramINTEL: InitializationModeQualifier = InitializationModeQualifier(0);
+		pub const InitOnDeviceResetINTEL: InitializationModeQualifier = InitializationModeQualifier(1);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct LoadCacheControl(pub u32);
+	impl LoadCacheControl {
+		pub const UncachedINTEL: LoadCacheControl = LoadCacheControl(0);
+		pub const CachedINTEL: LoadCacheControl = LoadCacheControl(1);
+		pub const StreamingINTEL: LoadCacheControl = LoadCacheControl(2);
+		pub const InvalidateAfterReadINTEL: LoadCacheControl = LoadCacheControl(3);
+		pub const ConstCachedINTEL: LoadCacheControl = LoadCacheControl(4);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct StoreCacheControl(pub u32);
+	impl StoreCacheControl {
+		pub const UncachedINTEL: StoreCacheControl = StoreCacheControl(0);
+		pub const WriteThroughINTEL: StoreCacheControl = StoreCacheControl(1);
+		pub const WriteBackINTEL: StoreCacheControl = StoreCacheControl(2);
+		pub const StreamingINTEL: StoreCacheControl = StoreCacheControl(3);
+	}
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Op(pub u32);
+	impl Op {
+		pub const OpNop: Op = Op(0);
+		pub const OpUndef: Op = Op(1);
+		pub const OpSourceContinued: Op = Op(2);
+		pub const OpSource: Op = Op(3);
+		pub const OpSourceExtension: Op = Op(4);
+		pub const OpName: Op = Op(5);
+		pub const OpMemberName: Op = Op(6);
+		pub const OpString: Op = Op(7);
+		pub const OpLine: Op = Op(8);
+		pub const OpExtension: Op = Op(10);
+		pub const OpExtInstImport: Op = Op(11);
+		pub const OpExtInst: Op = Op(12);
+		pub const OpMemoryModel: Op = Op(14);
+		pub const OpEntryPoint: Op = Op(15);
+		pub const OpExecutionMode: Op = Op(16);
+		pub const OpCapability: Op = Op(17);
+		pub const OpTypeVoid: Op = Op(19);
+		pub const OpTypeBool: Op = Op(20);
+		pub const OpTypeInt: Op = Op(21);
+		pub const OpTypeFloat: Op = Op(22);
+		pub const OpTypeVector: Op = Op(23);
+		pub const OpTypeMatrix: Op = Op(24);
+		pub const OpTypeImage: Op = Op(25);
+		pub const OpTypeSampler: Op = Op(26);
+		pub const OpTypeSampledImage: Op = Op(27);
+		pub const OpTypeArray: Op = Op(28);
+		pub const OpTypeRuntimeArray: Op = Op(29);
+		pub const OpTypeStruct: Op = Op(30);
+		pub const OpTypeOpaque: Op = Op(31);
+		pub const OpTypePointer: Op = Op(32);
+		pub const OpTypeFunction: Op = Op(33);
+		pub const OpTypeEvent: Op = Op(34);
+		pub const OpTypeDeviceEvent: Op = Op(35);
+		pub const OpTypeReserveId: Op = Op(36);
+		pub const OpTypeQueue: Op = Op(37);
+		pub const OpTypePipe: Op = Op(38);
+		pub const OpTypeForwardPointer: Op = Op(39);
+		pub const OpConstantTrue: Op = Op(41);
+		pub const OpConstantFalse: Op = Op(42);
+		pub const OpConstant: Op = Op(43);
+		pub const OpConstantComposite: Op = Op(44);
+		pub const OpConstantSampler: Op = Op(45);
+		pub const OpConstantNull: Op = Op(46);
+		pub const OpSpecConstantTrue: Op = Op(48);
+		pub const OpSpecConstantFalse: Op = Op(49);
+		pub const OpSpecConstant: Op = Op(50);
+		pub const OpSpecConstantComposite: Op = Op(51);
+		pub const OpSpecConstantOp: Op = Op(52);
+		pub const OpFunction: Op = Op(54);
+		pub const OpFunctionParameter: Op = Op(55);
+		pub const OpFunctionEnd: Op = Op(56);
+		pub const OpFunctionCall: Op = Op(57);
+		pub const OpVariable: Op = Op(59);
+		pub const OpImageTexelPointer: Op = Op(60);
+		pub const OpLoad: Op = Op(61);
+		pub const OpStore: Op = Op(62);
+		pub const OpCopyMemory: Op = Op(63);
+		pub const OpCopyMemorySized: Op = Op(64);
+		pub const OpAccessChain: Op = Op(65);
+		pub const OpInBoundsAccessChain: Op = Op(66);
+		pub const OpPtrAccessChain: Op = Op(67);
+		pub const OpArrayLength: Op = Op(68);
+		pub const OpGenericPtrMemSemantics: Op = Op(69);
+		pub const OpInBoundsPtrAccessChain: Op = Op(70);
+		pub const OpDecorate: Op = Op(71);
+		pub const OpMemberDecorate: Op = Op(72);
+		pub const OpDecorationGroup: Op = Op(73);
+		pub const OpGroupDecorate: Op = Op(74);
+		pub const OpGroupMemberDecorate: Op = Op(75);
+		pub const OpVectorExtractDynamic: Op = Op(77);
+		pub const OpVectorInsertDynamic: Op = Op(78);
+		pub const OpVectorShuffle: Op = Op(79);
+		pub const OpCompositeConstruct: Op = Op(80);
+		pub const OpCompositeExtract: Op = Op(81);
+		pub const OpCompositeInsert: Op = Op(82);
+		pub const OpCopyObject: Op = Op(83);
+		pub const OpTranspose: Op = Op(84);
+		pub const OpSampledImage: Op = Op(86);
+		pub const OpImageSampleImplicitLod: Op = Op(87);
+		pub const OpImageSampleExplicitLod: Op = Op(88);
+		pub const OpImageSampleDrefImplicitLod: Op = Op(89);
+		pub const OpImageSampleDrefExplicitLod: Op = Op(90);
+		pub const OpImageSampleProjImplicitLod: Op = Op(91);
+		pub const OpImageSampleProjExplicitLod: Op = Op(92);
+		pub const OpImageSampleProjDrefImplicitLod: Op = Op(93);
+		pub const OpImageSampleProjDrefExplicitLod: Op = Op(94);
+		pub const OpImageFetch: Op = Op(95);
+		pub const OpImageGather: Op = Op(96);
+		pub const OpImageDrefGather: Op = Op(97);
+		pub const OpImageRead: Op = Op(98);
+		pub const OpImageWrite: Op = Op(99);
+		pub const OpImage: Op = Op(100);
+		pub const OpImageQueryFormat: Op = Op(101);
+		pub const OpImageQueryOrder: Op = Op(102);
+		pub const OpImageQuerySizeLod: Op = Op(103);
+		pub const OpImageQuerySize: Op = Op(104);
+		pub const OpImageQueryLod: Op = Op(105);
+		pub const OpImageQueryLevels: Op = Op(106);
+		pub const OpImageQuerySamples: Op = Op(107);
+		pub const OpConvertFToU: Op = Op(109);
+		pub const OpConvertFToS: Op = Op(110);
+		pub const OpConvertSToF: Op = Op(111);
+		pub const OpConvertUToF: Op = Op(112);
+		pub const OpUConvert: Op = Op(113);
+		pub const OpSConvert: Op = Op(114);
+		pub const OpFConvert: Op = Op(115);
+		pub const OpQuantizeToF16: Op = Op(116);
+		pub const OpConvertPtrToU: Op = Op(117);
+		pub const OpSatConvertSToU: Op = Op(118);
+		pub const OpSatConvertUToS: Op = Op(119);
+		pub const OpConvertUToPtr: Op = Op(120);
+		pub const OpPtrCastToGeneric: Op = Op(121);
+		pub const OpGenericCastToPtr: Op = Op(122);
+		pub const OpGenericCastToPtrExplicit: Op = Op(123);
+		pub const OpBitcast: Op = Op(124);
+		pub const OpSNegate: Op = Op(126);
+		pub const OpFNegate: Op = Op(127);
+		pub const OpIAdd: Op = Op(128);
+		pub const OpFAdd: Op = Op(129);
+		pub const OpISub: Op = Op(130);
+		pub const OpFSub: Op = Op(131);
+		pub const OpIMul: Op = Op(132);
+		pub const OpFMul: Op = Op(133);
+		pub const OpUDiv: Op = Op(134);
+		pub const OpSDiv: Op = Op(135);
+		pub const OpFDiv: Op = Op(136);
+		pub const OpUMod: Op = Op(137);
+		pub const OpSRem: Op = Op(138);
+		pub const OpSMod: Op = Op(139);
+		pub const OpFRem: Op = Op(140);
+		pub const OpFMod: Op = Op(141);
+		pub const OpVectorTimesScalar: Op = Op(142);
+		pub const OpMatrixTimesScalar: Op = Op(143);
+		pub const OpVectorTimesMatrix: Op = Op(144);
+		pub const OpMatrixTimesVector: Op = Op(145);
+		pub const OpMatrixTimesMatrix: Op = Op(146);
+		pub const OpOuterProduct: Op = Op(147);
+		pub const OpDot: Op = Op(148);
+		pub const OpIAddCarry: Op = Op(149);
+		pub const OpISubBorrow: Op = Op(150);
+		pub const OpUMulExtended: Op = Op(151);
+		pub const OpSMulExtended: Op = Op(152);
+		pub const OpAny: Op = Op(154);
+		pub const OpAll: Op = Op(155);
+		pub const OpIsNan: Op = Op(156);
+		pub const OpIsInf: Op = Op(157);
+		pub const OpIsFinite: Op = Op(158);
+		pub const OpIsNormal: Op = Op(159);
+		pub const OpSignBitSet: Op = Op(160);
+		pub const OpLessOrGreater: Op = Op(161);
+		pub const OpOrdered: Op = Op(162);
+		pub const OpUnordered: Op = Op(163);
+		pub const OpLogicalEqual: Op = Op(164);
+		pub const OpLogicalNotEqual: Op = Op(165);
+		pub const OpLogicalOr: Op = Op(166);
+		pub const OpLogicalAnd: Op = Op(167);
+		pub const OpLogicalNot: Op = Op(168);
+		pub const OpSelect: Op = Op(169);
+		pub const OpIEqual: Op = Op(170);
+		pub const OpINotEqual: Op = Op(171);
+		pub const OpUGreaterThan: Op = Op(172);
+		pub const OpSGreaterThan: Op = Op(173);
+		pub const OpUGreaterThanEqual: Op = Op(174);
+		pub const OpSGreaterThanEqual: Op = Op(175);
+		pub const OpULessThan: Op = Op(176);
+		pub const OpSLessThan: Op = Op(177);
+		pub const OpULessThanEqual: Op = Op(178);
+		pub const OpSLessThanEqual: Op = Op(179);
+		pub const OpFOrdEqual: Op = Op(180);
+		pub const OpFUnordEqual: Op = Op(181);
+		pub const OpFOrdNotEqual: Op = Op(182);
+		pub const OpFUnordNotEqual: Op = Op(183);
+		pub const OpFOrdLessThan: Op = Op(184);
+		pub const OpFUnordLessThan: Op = Op(185);
+		pub const OpFOrdGreaterThan: Op = Op(186);
+		pub const OpFUnordGreaterThan: Op = Op(187);
+		pub const OpFOrdLessThanEqual: Op = Op(188);
+		pub const OpFUnordLessThanEqual: Op = Op(189);
+		pub const OpFOrdGreaterThanEqual: Op = Op(190);
+		pub const OpFUnordGreaterThanEqual: Op = Op(191);
+		pub const OpShiftRightLogical: Op = Op(194);
+		pub const OpShiftRightArithmetic: Op = Op(195);
+		pub const OpShiftLeftLogical: Op = Op(196);
+		pub const OpBitwiseOr: Op = Op(197);
+		pub const OpBitwiseXor: Op = Op(198);
+		pub const OpBitwiseAnd: Op = Op(199);
+		pub const OpNot: Op = Op(200);
+		pub const OpBitFieldInsert: Op = Op(201);
+		pub const OpBitFieldSExtract: Op = Op(202);
+		pub const OpBitFieldUExtract: Op = Op(203);
+		pub const OpBitReverse: Op = Op(204);
+		pub const OpBitCount: Op = Op(205);
+		pub const OpDPdx: Op = Op(207);
+		pub const OpDPdy: Op = Op(208);
+		pub const OpFwidth: Op = Op(209);
+		pub const OpDPdxFine: Op = Op(210);
+		pub const OpDPdyFine: Op = Op(211);
+		pub const OpFwidthFine: Op = Op(212);
+		pub const OpDPdxCoarse: Op = Op(213);
+		pub const OpDPdyCoarse: Op = Op(214);
+		pub const OpFwidthCoarse: Op = Op(215);
+		pub const OpEmitVertex: Op = Op(218);
+		pub const OpEndPrimitive: Op = Op(219);
+		pub const OpEmitStreamVertex: Op = Op(220);
+		pub const OpEndStreamPrimitive: Op = Op(221);
+		pub const OpControlBarrier: Op = Op(224);
+		pub const OpMemoryBarrier: Op = Op(225);
+		pub const OpAtomicLoad: Op = Op(227);
+		pub const OpAtomicStore: Op = Op(228);
+		pub const OpAtomicExchange: Op = Op(229);
+		pub const OpAtomicCompareExchange: Op = Op(230);
+		pub const OpAtomicCompareExchangeWeak: Op = Op(231);
+		pub const OpAtomicIIncrement: Op = Op(232);
+		pub const OpAtomicIDecrement: Op = Op(233);
+		pub const OpAtomicIAdd: Op = Op(234);
+		pub const OpAtomicISub: Op = Op(235);
+		pub const OpAtomicSMin: Op = Op(236);
+		pub const OpAtomicUMin: Op = Op(237);
+		pub const OpAtomicSMax: Op = Op(238);
+		pub const OpAtomicUMax: Op = Op(239);
+		pub const OpAtomicAnd: Op = Op(240);
+		pub const OpAtomicOr: Op = Op(241);
+		pub const OpAtomicXor: Op = Op(242);
+		pub const OpPhi: Op = Op(245);
+		pub const OpLoopMerge: Op = Op(246);
+		pub const OpSelectionMerge: Op = Op(247);
+		pub const OpLabel: Op = Op(248);
+		pub const OpBranch: Op = Op(249);
+		pub const OpBranchConditional: Op = Op(250);
+		pub const OpSwitch: Op = Op(251);
+		pub const OpKill: Op = Op(252);
+		pub const OpReturn: Op = Op(253);
+		pub const OpReturnValue: Op = Op(254);
+		pub const OpUnreachable: Op = Op(255);
+		pub const OpLifetimeStart: Op = Op(256);
+		pub const OpLifetimeStop: Op = Op(257);
+		pub const OpGroupAsyncCopy: Op = Op(259);
+		pub const OpGroupWaitEvents: Op = Op(260);
+		pub const OpGroupAll: Op = Op(261);
+		pub const OpGroupAny: Op = Op(262);
+		pub const OpGroupBroadcast: Op = Op(263);
+		pub const OpGroupIAdd: Op = Op(264);
+		pub const OpGroupFAdd: Op = Op(265);
+		pub const OpGroupFMin: Op = Op(266);
+		pub const OpGroupUMin: Op = Op(267);
+		pub const OpGroupSMin: Op = Op(268);
+		pub const OpGroupFMax: Op = Op(269);
+		pub const OpGroupUMax: Op = Op(270);
+		pub const OpGroupSMax: Op = Op(271);
+		pub const OpReadPipe: Op = Op(274);
+		pub const OpWritePipe: Op = Op(275);
+		pub const OpReservedReadPipe: Op = Op(276);
+		pub const OpReservedWritePipe: Op = Op(277);
+		pub const OpReserveReadPipePackets: Op = Op(278);
+		pub const OpReserveWritePipePackets: Op = Op(279);
+		pub const OpCommitReadPipe: Op = Op(280);
+		pub const OpCommitWritePipe: Op = Op(281);
+		pub const OpIsValidReserveId: Op = Op(282);
+		pub const OpGetNumPipePackets: Op = Op(283);
+		pub const OpGetMaxPipePackets: Op = Op(284);
+		pub const OpGroupReserveReadPipePackets: Op = Op(285);
+		pub const OpGroupReserveWritePipePackets: Op = Op(286);
+		pub const OpGroupCommitReadPipe: Op = Op(287);
+		pub const OpGroupCommitWritePipe: Op = Op(288);
+		pub const OpEnqueueMarker: Op = Op(291);
+		pub const OpEnqueueKernel: Op = Op(292);
+		pub const OpGetKernelNDrangeSubGroupCount: Op = Op(293);
+		pub const OpGetKernelNDrangeMaxSubGroupSize: Op = Op(294);
+		pub const OpGetKernelWorkGroupSize: Op = Op(295);
+		pub const OpGetKernelPreferredWorkGroupSizeMultiple: Op = Op(296);
+		pub const OpRetainEvent: Op = Op(297);
+		pub const OpReleaseEvent: Op = Op(298);
+		pub const OpCreateUserEvent: Op = Op(299);
+		pub const OpIsValidEvent: Op = Op(300);
+		pub const OpSetUserEventStatus: Op = Op(301);
+		pub const OpCaptureEventProfilingInfo: Op = Op(302);
+		pub const OpGetDefaultQueue: Op = Op(303);
+		pub const OpBuildNDRange: Op = Op(304);
+		pub const OpImageSparseSampleImplicitLod: Op = Op(305);
+		pub const OpImageSparseSampleExplicitLod: Op = Op(306);
+		pub const OpImageSparseSampleDrefImplicitLod: Op = Op(307);
+		pub const OpImageSparseSampleDrefExplicitLod: Op = Op(308);
+		pub const OpImageSparseSampleProjImplicitLod: Op = Op(309);
+		pub const OpImageSparseSampleProjExplicitLod: Op = Op(310);
+		pub const OpImageSparseSampleProjDrefImplicitLod: Op = Op(311);
+		pub const OpImageSparseSampleProjDrefExplicitLod: Op = Op(312);
+		pub const OpImageSparseFetch: Op = Op(313);
+		pub const OpImageSparseGather: Op = Op(314);
+		pub const OpImageSparseDrefGather: Op = Op(315);
+		pub const OpImageSparseTexelsResident: Op = Op(316);
+		pub const OpNoLine: Op = Op(317);
+		pub const OpAtomicFlagTestAndSet: Op = Op(318);
+		pub const OpAtomicFlagClear: Op = Op(319);
+		pub const OpImageSparseRead: Op = Op(320);
+		pub const OpSizeOf: Op = Op(321);
+		pub const OpTypePipeStorage: Op = Op(322);
+		pub const OpConstantPipeStorage: Op = Op(323);
+		pub const OpCreatePipeFromPipeStorage: Op = Op(324);
+		pub const OpGetKernelLocalSizeForSubgroupCount: Op = Op(325);
+		pub const OpGetKernelMaxNumSubgroups: Op = Op(326);
+		pub const OpTypeNamedBarrier: Op = Op(327);
+		pub const OpNamedBarrierInitialize: Op = Op(328);
+		pub const OpMemoryNamedBarrier: Op = Op(329);
+		pub const OpModuleProcessed: Op = Op(330);
+		pub const OpExecutionModeId: Op = Op(331);
+		pub const OpDecorateId: Op = Op(332);
+		pub const OpGroupNonUniformElect: Op = Op(333);
+		pub const OpGroupNonUniformAll: Op = Op(334);
+		pub const OpGroupNonUniformAny: Op = Op(335);
+		pub const OpGroupNonUniformAllEqual: Op = Op(336);
+		pub const OpGroupNonUniformBroadcast: Op = Op(337);
+		pub const OpGroupNonUniformBroadcastFirst: Op = Op(338);
+		pub const OpGroupNonUniformBallot: Op = Op(339);
+		pub const OpGroupNonUniformInverseBallot: Op = Op(340);
+		pub const OpGroupNonUniformBallotBitExtract: Op = Op(341);
+		pub const OpGroupNonUniformBallotBitCount: Op = Op(342);
+		pub const OpGroupNonUniformBallotFindLSB: Op = Op(343);
+		pub const OpGroupNonUniformBallotFindMSB: Op = Op(344);
+		pub const OpGroupNonUniformShuffle: Op = Op(345);
+		pub const OpGroupNonUniformShuffleXor: Op = Op(346);
+		pub const OpGroupNonUniformShuffleUp: Op = Op(347);
+		pub const OpGroupNonUniformShuffleDown: Op = Op(348);
+		pub const OpGroupNonUniformIAdd: Op = Op(349);
+		pub const OpGroupNonUniformFAdd: Op = Op(350);
+		pub const OpGroupNonUniformIMul: Op = Op(351);
+		pub const OpGroupNonUniformFMul: Op = Op(352);
+		pub const OpGroupNonUniformSMin: Op = Op(353);
+		pub const OpGroupNonUniformUMin: Op = Op(354);
+		pub const OpGroupNonUniformFMin: Op = Op(355);
+		pub const OpGroupNonUniformSMax: Op = Op(356);
+		pub const OpGroupNonUniformUMax: Op = Op(357);
+		pub const OpGroupNonUniformFMax: Op = Op(358);
+		pub const OpGroupNonUniformBitwiseAnd: Op = Op(359);
+		pub const OpGroupNonUniformBitwiseOr: Op = Op(360);
+		pub const OpGroupNonUniformBitwiseXor: Op = Op(361);
+		pub const OpGroupNonUniformLogicalAnd: Op = Op(362);
+		pub const OpGroupNonUniformLogicalOr: Op = Op(363);
+		pub const OpGroupNonUniformLogicalXor: Op = Op(364);
+		pub const OpGroupNonUniformQuadBroadcast: Op = Op(365);
+		pub const OpGroupNonUniformQuadSwap: Op = Op(366);
+		pub const OpCopyLogical: Op = Op(400);
+		pub const OpPtrEqual: Op = Op(401);
+		pub const OpPtrNotEqual: Op = Op(402);
+		pub const OpPtrDiff: Op = Op(403);
+		pub const OpColorAttachmentReadEXT: Op = Op(4160);
+		pub const OpDepthAttachmentReadEXT: Op = Op(4161);
+		pub const OpStencilAttachmentReadEXT: Op = Op(4162);
+		pub const OpTerminateInvocation: Op = Op(4416);
+		pub const OpSubgroupBallotKHR: Op = Op(4421);
+		pub const OpSubgroupFirstInvocationKHR: Op = Op(4422);
+		pub const OpSubgroupAllKHR: Op = Op(4428);
+		pub const OpSubgroupAnyKHR: Op = Op(4429);
+		pub const OpSubgroupAllEqualKHR: Op = Op(4430);
+		pub const OpGroupNonUniformRotateKHR: Op = Op(4431);
+		pub const OpSubgroupReadInvocationKHR: Op = Op(4432);
+		pub const OpTraceRayKHR: Op = Op(4445);
+		pub const OpExecuteCallableKHR: Op = Op(4446);
+		pub const OpConvertUToAccelerationStructureKHR: Op = Op(4447);
+		pub const OpIgnoreIntersectionKHR: Op = Op(4448);
+		pub const OpTerminateRayKHR: Op = Op(4449);
+		pub const OpSDot: Op = Op(4450);
+		pub const OpSDotKHR: Op = Op(4450);
+		pub const OpUDot: Op = Op(4451);
+		pub const OpUDotKHR: Op = Op(4451);
+		pub const OpSUDot: Op = Op(4452);
+		pub const OpSUDotKHR: Op = Op(4452);
+		pub const OpSDotAccSat: Op = Op(4453);
+		pub const OpSDotAccSatKHR: Op = Op(4453);
+		pub const OpUDotAccSat: Op = Op(4454);
+		pub const OpUDotAccSatKHR: Op = Op(4454);
+		pub const OpSUDotAccSat: Op = Op(4455);
+		pub const OpSUDotAccSatKHR: Op = Op(4455);
+		pub const OpTypeCooperativeMatrixKHR: Op = Op(4456);
+		pub const OpCooperativeMatrixLoadKHR: Op = Op(4457);
+		pub const OpCooperativeMatrixStoreKHR: Op = Op(4458);
+		pub const OpCooperativeMatrixMulAddKHR: Op = Op(4459);
+		pub const OpCooperativeMatrixLengthKHR: Op = Op(4460);
+		pub const OpTypeRayQueryKHR: Op = Op(4472);
+		pub const OpRayQueryInitializeKHR: Op = Op(4473);
+		pub const OpRayQueryTerminateKHR: Op = Op(4474);
+		pub const OpRayQueryGenerateIntersectionKHR: Op = Op(4475);
+		pub const OpRayQueryConfirmIntersectionKHR: Op = Op(4476);
+		pub const OpRayQueryProceedKHR: Op = Op(4477);
+		pub const OpRayQueryGetIntersectionTypeKHR: Op = Op(4479);
+		pub const OpImageSampleWeightedQCOM: Op = Op(4480);
+		pub const OpImageBoxFilterQCOM: Op = Op(4481);
+		pub const OpImageBlockMatchSSDQCOM: Op = Op(4482);
+		pub const OpImageBlockMatchSADQCOM: Op = Op(4483);
+		pub const OpGroupIAddNonUniformAMD: Op = Op(5000);
+		pub const OpGroupFAddNonUniformAMD: Op = Op(5001);
+		pub const OpGroupFMinNonUniformAMD: Op = Op(5002);
+		pub const OpGroupUMinNonUniformAMD: Op = Op(5003);
+		pub const OpGroupSMinNonUniformAMD: Op = Op(5004);
+		pub const OpGroupFMaxNonUniformAMD: Op = Op(5005);
+		pub const OpGroupUMaxNonUniformAMD: Op = Op(5006);
+		pub const OpGroupSMaxNonUniformAMD: Op = Op(5007);
+		pub const OpFragmentMaskFetchAMD: Op = Op(5011);
+		pub const OpFragmentFetchAMD: Op = Op(5012);
+		pub const OpReadClockKHR: Op = Op(5056);
+		pub const OpFinalizeNodePayloadsAMDX: Op = Op(5075);
+		pub const OpFinishWritingNodePayloadAMDX: Op = Op(5078);
+		pub const OpInitializeNodePayloadsAMDX: Op = Op(5090);
+		pub const OpHitObjectRecordHitMotionNV: Op = Op(5249);
+		pub const OpHitObjectRecordHitWithIndexMotionNV: Op = Op(5250);
+		pub const OpHitObjectRecordMissMotionNV: Op = Op(5251);
+		pub const OpHitObjectGetWorldToObjectNV: Op = Op(5252);
+		pub const OpHitObjectGetObjectToWorldNV: Op = Op(5253);
+		pub const OpHitObjectGetObjectRayDirectionNV: Op = Op(5254);
+		pub const OpHitObjectGetObjectRayOriginNV: Op = Op(5255);
+		pub const OpHitObjectTraceRayMotionNV: Op = Op(5256);
+		pub const OpHitObjectGetShaderRecordBufferHandleNV: Op = Op(5257);
+		pub const OpHitObjectGetShaderBindingTableRecordIndexNV: Op = Op(5258);
+		pub const OpHitObjectRecordEmptyNV: Op = Op(5259);
+		pub const OpHitObjectTraceRayNV: Op = Op(5260);
+		pub const OpHitObjectRecordHitNV: Op = Op(5261);
+		pub const OpHitObjectRecordHitWithIndexNV: Op = Op(5262);
+		pub const OpHitObjectRecordMissNV: Op = Op(5263);
+		pub const OpHitObjectExecuteShaderNV: Op = Op(5264);
+		pub const OpHitObjectGetCurrentTimeNV: Op = Op(5265);
+		pub const OpHitObjectGetAttributesNV: Op = Op(5266);
+		pub const OpHitObjectGetHitKindNV: Op = Op(5267);
+		pub const OpHitObjectGetPrimitiveIndexNV: Op = Op(5268);
+		pub const OpHitObjectGetGeometryIndexNV: Op = Op(5269);
+		pub const OpHitObjectGetInstanceIdNV: Op = Op(5270);
+		pub const OpHitObjectGetInstanceCustomIndexNV: Op = Op(5271);
+		pub const OpHitObjectGetWorldRayDirectionNV: Op = Op(5272);
+		pub const OpHitObjectGetWorldRayOriginNV: Op = Op(5273);
+		pub const OpHitObjectGetRayTMaxNV: Op = Op(5274);
+		pub const OpHitObjectGetRayTMinNV: Op = Op(5275);
+		pub const OpHitObjectIsEmptyNV: Op = Op(5276);
+		pub const OpHitObjectIsHitNV: Op = Op(5277);
+		pub const OpHitObjectIsMissNV: Op = Op(5278);
+		pub const OpReorderThreadWithHitObjectNV: Op = Op(5279);
+		pub const OpReorderThreadWithHintNV: Op = Op(5280);
+		pub const OpTypeHitObjectNV: Op = Op(5281);
+		pub const OpImageSampleFootprintNV: Op = Op(5283);
+		pub const OpEmitMeshTasksEXT: Op = Op(5294);
+		pub const OpSetMeshOutputsEXT: Op = Op(5295);
+		pub const OpGroupNonUniformPartitionNV: Op = Op(5296);
+		pub const OpWritePackedPrimitiveIndices4x8NV: Op = Op(5299);
+		pub const OpReportIntersectionNV: Op = Op(5334);
+		pub const OpReportIntersectionKHR: Op = Op(5334);
+		pub const OpIgnoreIntersectionNV: Op = Op(5335);
+		pub const OpTerminateRayNV: Op = Op(5336);
+		pub const OpTraceNV: Op = Op(5337);
+		pub const OpTraceMotionNV: Op = Op(5338);
+		pub const OpTraceRayMotionNV: Op = Op(5339);
+		pub const OpRayQueryGetIntersectionTriangleVertexPositionsKHR: Op = Op(5340);
+		pub const OpTypeAccelerationStructureNV: Op = Op(5341);
+		pub const OpTypeAccelerationStructureKHR: Op = Op(5341);
+		pub const OpExecuteCallableNV: Op = Op(5344);
+		pub const OpTypeCooperativeMatrixNV: Op = Op(5358);
+		pub const OpCooperativeMatrixLoadNV: Op = Op(5359);
+		pub const OpCooperativeMatrixStoreNV: Op = Op(5360);
+		pub const OpCooperativeMatrixMulAddNV: Op = Op(5361);
+		pub const OpCooperativeMatrixLengthNV: Op = Op(5362);
+		pub const OpBeginInvocationInterlockEXT: Op = Op(5364);
+		pub const OpEndInvocationInterlockEXT: Op = Op(5365);
+		pub const OpDemoteToHelperInvocation: Op = Op(5380);
+		pub const OpDemoteToHelperInvocationEXT: Op = Op(5380);
+		pub const OpIsHelperInvocationEXT: Op = Op(5381);
+		pub const OpConvertUToImageNV: Op = Op(5391);
+		pub const OpConvertUToSamplerNV: Op = Op(5392);
+		pub const OpConvertImageToUNV: Op = Op(5393);
+		pub const OpConvertSamplerToUNV: Op = Op(5394);
+		pub const OpConvertUToSampledImageNV: Op = Op(5395);
+		pub const OpConvertSampledImageToUNV: Op = Op(5396);
+		pub const OpSamplerImageAddressingModeNV: Op = Op(5397);
+		pub const OpSubgroupShuffleINTEL: Op = Op(5571);
+		pub const OpSubgroupShuffleDownINTEL: Op = Op(5572);
+		pub const OpSubgroupShuffleUpINTEL: Op = Op(5573);
+		pub const OpSubgroupShuffleXorINTEL: Op = Op(5574);
+		pub const OpSubgroupBlockReadINTEL: Op = Op(5575);
+		pub const OpSubgroupBlockWriteINTEL: Op = Op(5576);
+		pub const OpSubgroupImageBlockReadINTEL: Op = Op(5577);
+		pub const OpSubgroupImageBlockWriteINTEL: Op = Op(5578);
+		pub const OpSubgroupImageMediaBlockReadINTEL: Op = Op(5580);
+		pub const OpSubgroupImageMediaBlockWriteINTEL: Op = Op(5581);
+		pub const OpUCountLeadingZerosINTEL: Op = Op(5585);
+		pub const OpUCountTrailingZerosINTEL: Op = Op(5586);
+		pub const OpAbsISubINTEL: Op = Op(5587);
+		pub const OpAbsUSubINTEL: Op = Op(5588);
+		pub const OpIAddSatINTEL: Op = Op(5589);
+		pub const OpUAddSatINTEL: Op = Op(5590);
+		pub const OpIAverageINTEL: Op = Op(5591);
+		pub const OpUAverageINTEL: Op = Op(5592);
+		pub const OpIAverageRoundedINTEL: Op = Op(5593);
+		pub const OpUAverageRoundedINTEL: Op = Op(5594);
+		pub const OpISubSatINTEL: Op = Op(5595);
+		pub const OpUSubSatINTEL: Op = Op(5596);
+		pub const OpIMul32x16INTEL: Op = Op(5597);
+		pub const OpUMul32x16INTEL: Op = Op(5598);
+		pub const OpConstantFunctionPointerINTEL: Op = Op(5600);
+		pub const OpFunctionPointerCallINTEL: Op = Op(5601);
+		pub const OpAsmTargetINTEL: Op = Op(5609);
+		pub const OpAsmINTEL: Op = Op(5610);
+		pub const OpAsmCallINTEL: Op = Op(5611);
+		pub const OpAtomicFMinEXT: Op = Op(5614);
+		pub const OpAtomicFMaxEXT: Op = Op(5615);
+		pub const OpAssumeTrueKHR: Op = Op(5630);
+		pub const OpExpectKHR: Op = Op(5631);
+		pub const OpDecorateString: Op = Op(5632);
+		pub const OpDecorateStringGOOGLE: Op = Op(5632);
+		pub const OpMemberDecorateString: Op = Op(5633);
+		pub const OpMemberDecorateStringGOOGLE: Op = Op(5633);
+		pub const OpVmeImageINTEL: Op = Op(5699);
+		pub const OpTypeVmeImageINTEL: Op = Op(5700);
+		pub const OpTypeAvcImePayloadINTEL: Op = Op(5701);
+		pub const OpTypeAvcRefPayloadINTEL: Op = Op(5702);
+		pub const OpTypeAvcSicPayloadINTEL: Op = Op(5703);
+		pub const OpTypeAvcMcePayloadINTEL: Op = Op(5704);
+		pub const OpTypeAvcMceResultINTEL: Op = Op(5705);
+		pub const OpTypeAvcImeResultINTEL: Op = Op(5706);
+		pub const OpTypeAvcImeResultSingleReferenceStreamoutINTEL: Op = Op(5707);
+		pub const OpTypeAvcImeResultDualReferenceStreamoutINTEL: Op = Op(5708);
+		pub const OpTypeAvcImeSingleReferenceStreaminINTEL: Op = Op(5709);
+		pub const OpTypeAvcImeDualReferenceStreaminINTEL: Op = Op(5710);
+		pub const OpTypeAvcRefResultINTEL: Op = Op(5711);
+		pub const OpTypeAvcSicResultINTEL: Op = Op(5712);
+		pub const OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL: Op = Op(5713);
+		pub const OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL: Op = Op(5714);
+		pub const OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL: Op = Op(5715);
+		pub const OpSubgroupAvcMceSetInterShapePenaltyINTEL: Op = Op(5716);
+		pub const OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL: Op = Op(5717);
+		pub const OpSubgroupAvcMceSetInterDirectionPenaltyINTEL: Op = Op(5718);
+		pub const OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL: Op = Op(5719);
+		pub const OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL: Op = Op(5720);
+		pub const OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL: Op = Op(5721);
+		pub const OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL: Op = Op(5722);
+		pub const OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL: Op = Op(5723);
+		pub const OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL: Op = Op(5724);
+		pub const OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL: Op = Op(5725);
+		pub const OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL: Op = Op(5726);
+		pub const OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL: Op = Op(5727);
+		pub const OpSubgroupAvcMceSetAcOnlyHaarINTEL: Op = Op(5728);
+		pub const OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL: Op = Op(5729);
+		pub const OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL: Op = Op(5730);
+		pub const OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL: Op = Op(5731);
+		pub const OpSubgroupAvcMceConvertToImePayloadINTEL: Op = Op(5732);
+		pub const OpSubgroupAvcMceConvertToImeResultINTEL: Op = Op(5733);
+		pub const OpSubgroupAvcMceConvertToRefPayloadINTEL: Op = Op(5734);
+		pub const OpSubgroupAvcMceConvertToRefResultINTEL: Op = Op(5735);
+		pub const OpSubgroupAvcMceConvertToSicPayloadINTEL: Op = Op(5736);
+		pub const OpSubgroupAvcMceConvertToSicResultINTEL: Op = Op(5737);
+		pub const OpSubgroupAvcMceGetMotionVectorsINTEL: Op = Op(5738);
+		pub const OpSubgroupAvcMceGetInterDistortionsINTEL: Op = Op(5739);
+		pub const OpSubgroupAvcMceGetBestInterDistortionsINTEL: Op = Op(5740);
+		pub const OpSubgroupAvcMceGetInterMajorShapeINTEL: Op = Op(5741);
+		pub const OpSubgroupAvcMceGetInterMinorShapeINTEL: Op = Op(5742);
+		pub const OpSubgroupAvcMceGetInterDirectionsINTEL: Op = Op(5743);
+		pub const OpSubgroupAvcMceGetInterMotionVectorCountINTEL: Op = Op(5744);
+		pub const OpSubgroupAvcMceGetInterReferenceIdsINTEL: Op = Op(5745);
+		pub const OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL: Op = Op(5746);
+		pub const OpSubgroupAvcImeInitializeINTEL: Op = Op(5747);
+		pub const OpSubgroupAvcImeSetSingleReferenceINTEL: Op = Op(5748);
+		pub const OpSubgroupAvcImeSetDualReferenceINTEL: Op = Op(5749);
+		pub const OpSubgroupAvcImeRefWindowSizeINTEL: Op = Op(5750);
+		pub const OpSubgroupAvcImeAdjustRefOffsetINTEL: Op = Op(5751);
+		pub const OpSubgroupAvcImeConvertToMcePayloadINTEL: Op = Op(5752);
+		pub const OpSubgroupAvcImeSetMaxMotionVectorCountINTEL: Op = Op(5753);
+		pub const OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL: Op = Op(5754);
+		pub const OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL: Op = Op(5755);
+		pub const OpSubgroupAvcImeSetWeightedSadINTEL: Op = Op(5756);
+		pub const OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL: Op = Op(5757);
+		pub const OpSubgroupAvcImeEvaluateWithDualReferenceINTEL: Op = Op(5758);
+		pub const OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL: Op = Op(5759);
+		pub const OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL: Op = Op(5760);
+		pub const OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL: Op = Op(5761);
+		pub const OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL: Op = Op(5762);
+		pub const OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL: Op = Op(5763);
+		pub const OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL: Op = Op(5764);
+		pub const OpSubgroupAvcImeConvertToMceResultINTEL: Op = Op(5765);
+		pub const OpSubgroupAvcImeGetSingleReferenceStreaminINTEL: Op = Op(5766);
+		pub const OpSubgroupAvcImeGetDualReferenceStreaminINTEL: Op = Op(5767);
+		pub const OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL: Op = Op(5768);
+		pub const OpSubgroupAvcImeStripDualReferenceStreamoutINTEL: Op = Op(5769);
+		pub const OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL: Op = Op(5770);
+		pub const OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL: Op = Op(5771);
+		pub const OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL: Op = Op(5772);
+		pub const OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL: Op = Op(5773);
+		pub const OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL: Op = Op(5774);
+		pub const OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL: Op = Op(5775);
+		pub const OpSubgroupAvcImeGetBorderReachedINTEL: Op = Op(5776);
+		pub const OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL: Op = Op(5777);
+		pub const OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL: Op = Op(5778);
+		pub const OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL: Op = Op(5779);
+		pub const OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL: Op = Op(5780);
+		pub const OpSubgroupAvcFmeInitializeINTEL: Op = Op(5781);
+		pub const OpSubgroupAvcBmeInitializeINTEL: Op = Op(5782);
+		pub const OpSubgroupAvcRefConvertToMcePayloadINTEL: Op = Op(5783);
+		pub const OpSubgroupAvcRefSetBidirectionalMixDisableINTEL: Op = Op(5784);
+		pub const OpSubgroupAvcRefSetBilinearFilterEnableINTEL: Op = Op(5785);
+		pub const OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL: Op = Op(5786);
+		pub const OpSubgroupAvcRefEvaluateWithDualReferenceINTEL: Op = Op(5787);
+		pub const OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL: Op = Op(5788);
+		pub const OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL: Op = Op(5789);
+		pub const OpSubgroupAvcRefConvertToMceResultINTEL: Op = Op(5790);
+		pub const OpSubgroupAvcSicInitializeINTEL: Op = Op(5791);
+		pub const OpSubgroupAvcSicConfigureSkcINTEL: Op = Op(5792);
+		pub const OpSubgroupAvcSicConfigureIpeLumaINTEL: Op = Op(5793);
+		pub const OpSubgroupAvcSicConfigureIpeLumaChromaINTEL: Op = Op(5794);
+		pub const OpSubgroupAvcSicGetMotionVectorMaskINTEL: Op = Op(5795);
+		pub const OpSubgroupAvcSicConvertToMcePayloadINTEL: Op = Op(5796);
+		pub const OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL: Op = Op(5797);
+		pub const OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL: Op = Op(5798);
+		pub const OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL: Op = Op(5799);
+		pub const OpSubgroupAvcSicSetBilinearFilterEnableINTEL: Op = Op(5800);
+		pub const OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL: Op = Op(5801);
+		pub const OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL: Op = Op(5802);
+		pub const OpSubgroupAvcSicEvaluateIpeINTEL: Op = Op(5803);
+		pub const OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL: Op = Op(5804);
+		pub const OpSubgroupAvcSicEvaluateWithDualReferenceINTEL: Op = Op(5805);
+		pub const OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL: Op = Op(5806);
+		pub const OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL: Op = Op(5807);
+		pub const OpSubgroupAvcSicConvertToMceResultINTEL: Op = Op(5808);
+		pub const OpSubgroupAvcSicGetIpeLumaShapeINTEL: Op = Op(5809);
+		pub const OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL: Op = Op(5810);
+		pub const OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL: Op = Op(5811);
+		pub const OpSubgroupAvcSicGetPackedIpeLumaModesINTEL: Op = Op(5812);
+		pub const OpSubgroupAvcSicGetIpeChromaModeINTEL: Op = Op(5813);
+		pub const OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL: Op = Op(5814);
+		pub const OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL: Op = Op(5815);
+		pub const OpSubgroupAvcSicGetInterRawSadsINTEL: Op = Op(5816);
+		pub const OpVariableLengthArrayINTEL: Op = Op(5818);
+		pub const OpSaveMemoryINTEL: Op = Op(5819);
+		pub const OpRestoreMemoryINTEL: Op = Op(5820);
+		pub const OpArbitraryFloatSinCosPiINTEL: Op = Op(5840);
+		pub const OpArbitraryFloatCastINTEL: Op = Op(5841);
+		pub const OpArbitraryFloatCastFromIntINTEL: Op = Op(5842);
+		pub const OpArbitraryFloatCastToIntINTEL: Op = Op(5843);
+		pub const OpArbitraryFloatAddINTEL: Op = Op(5846);
+		pub const OpArbitraryFloatSubINTEL: Op = Op(5847);
+		pub const OpArbitraryFloatMulINTEL: Op = Op(5848);
+		pub const OpArbitraryFloatDivINTEL: Op = Op(5849);
+		pub const OpArbitraryFloatGTINTEL: Op = Op(5850);
+		pub const OpArbitraryFloatGEINTEL: Op = Op(5851);
+		pub const OpArbitraryFloatLTINTEL: Op = Op(5852);
+		pub const OpArbitraryFloatLEINTEL: Op = Op(5853);
+		pub const OpArbitraryFloatEQINTEL: Op = Op(5854);
+		pub const OpArbitraryFloatRecipINTEL: Op = Op(5855);
+		pub const OpArbitraryFloatRSqrtINTEL: Op = Op(5856);
+		pub const OpArbitraryFloatCbrtINTEL: Op = Op(5857);
+		pub const OpArbitraryFloatHypotINTEL: Op = Op(5858);
+		pub const OpArbitraryFloatSqrtINTEL: Op = Op(5859);
+		pub const OpArbitraryFloatLogINTEL: Op = Op(5860);
+		pub const OpArbitraryFloatLog2INTEL: Op = Op(5861);
+		pub const OpArbitraryFloatLog10INTEL: Op = Op(5862);
+		pub const OpArbitraryFloatLog1pINTEL: Op = Op(5863);
+		pub const OpArbitraryFloatExpINTEL: Op = Op(5864);
+		pub const OpArbitraryFloatExp2INTEL: Op = Op(5865);
+		pub const OpArbitraryFloatExp10INTEL: Op = Op(5866);
+		pub const OpArbitraryFloatExpm1INTEL: Op = Op(5867);
+		pub const OpArbitraryFloatSinINTEL: Op = Op(5868);
+		pub const OpArbitraryFloatCosINTEL: Op = Op(5869);
+		pub const OpArbitraryFloatSinCosINTEL: Op = Op(5870);
+		pub const OpArbitraryFloatSinPiINTEL: Op = Op(5871);
+		pub const OpArbitraryFloatCosPiINTEL: Op = Op(5872);
+		pub const OpArbitraryFloatASinINTEL: Op = Op(5873);
+		pub const OpArbitraryFloatASinPiINTEL: Op = Op(5874);
+		pub const OpArbitraryFloatACosINTEL: Op = Op(5875);
+		pub const OpArbitraryFloatACosPiINTEL: Op = Op(5876);
+		pub const OpArbitraryFloatATanINTEL: Op = Op(5877);
+		pub const OpArbitraryFloatATanPiINTEL: Op = Op(5878);
+		pub const OpArbitraryFloatATan2INTEL: Op = Op(5879);
+		pub const OpArbitraryFloatPowINTEL: Op = Op(5880);
+		pub const OpArbitraryFloatPowRINTEL: Op = Op(5881);
+		pub const OpArbitraryFloatPowNINTEL: Op = Op(5882);
+		pub const OpLoopControlINTEL: Op = Op(5887);
+		pub const OpAliasDomainDeclINTEL: Op = Op(5911);
+		pub const OpAliasScopeDeclINTEL: Op = Op(5912);
+		pub const OpAliasScopeListDeclINTEL: Op = Op(5913);
+		pub const OpFixedSqrtINTEL: Op = Op(5923);
+		pub const OpFixedRecipINTEL: Op = Op(5924);
+		pub const OpFixedRsqrtINTEL: Op = Op(5925);
+		pub const OpFixedSinINTEL: Op = Op(5926);
+		pub const OpFixedCosINTEL: Op = Op(5927);
+		pub const OpFixedSinCosINTEL: Op = Op(5928);
+		pub const OpFixedSinPiINTEL: Op = Op(5929);
+		pub const OpFixedCosPiINTEL: Op = Op(5930);
+		pub const OpFixedSinCosPiINTEL: Op = Op(5931);
+		pub const OpFixedLogINTEL: Op = Op(5932);
+		pub const OpFixedExpINTEL: Op = Op(5933);
+		pub const OpPtrCastToCrossWorkgroupINTEL: Op = Op(5934);
+		pub const OpCrossWorkgroupCastToPtrINTEL: Op = Op(5938);
+		pub const OpReadPipeBlockingINTEL: Op = Op(5946);
+		pub const OpWritePipeBlockingINTEL: Op = Op(5947);
+		pub const OpFPGARegINTEL: Op = Op(5949);
+		pub const OpRayQueryGetRayTMinKHR: Op = Op(6016);
+		pub const OpRayQueryGetRayFlagsKHR: Op = Op(6017);
+		pub const OpRayQueryGetIntersectionTKHR: Op = Op(6018);
+		pub const OpRayQueryGetIntersectionInstanceCustomIndexKHR: Op = Op(6019);
+		pub const OpRayQueryGetIntersectionInstanceIdKHR: Op = Op(6020);
+		pub const OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR: Op = Op(6021);
+		pub const OpRayQueryGetIntersectionGeometryIndexKHR: Op = Op(6022);
+		pub const OpRayQueryGetIntersectionPrimitiveIndexKHR: Op = Op(6023);
+		pub const OpRayQueryGetIntersectionBarycentricsKHR: Op = Op(6024);
+		pub const OpRayQueryGetIntersectionFrontFaceKHR: Op = Op(6025);
+		pub const OpRayQueryGetIntersectionCandidateAABBOpaqueKHR: Op = Op(6026);
+		pub const OpRayQueryGetIntersectionObjectRayDirectionKHR: Op = Op(6027);
+		pub const OpRayQueryGetIntersectionObjectRayOriginKHR: Op = Op(6028);
+		pub const OpRayQueryGetWorldRayDirectionKHR: Op = Op(6029);
+		pub const OpRayQueryGetWorldRayOriginKHR: Op = Op(6030);
+		pub const OpRayQueryGetIntersectionObjectToWorldKHR: Op = Op(6031);
+		pub const OpRayQueryGetIntersectionWorldToObjectKHR: Op = Op(6032);
+		pub const OpAtomicFAddEXT: Op = Op(6035);
+		pub const OpTypeBufferSurfaceINTEL: Op = Op(6086);
+		pub const OpTypeStructContinuedINTEL: Op = Op(6090);
+		pub const OpConstantCompositeContinuedINTEL: Op = Op(6091);
+		pub const OpSpecConstantCompositeContinuedINTEL: Op = Op(6092);
+		pub const OpConvertFToBF16INTEL: Op = Op(6116);
+		pub const OpConvertBF16ToFINTEL: Op = Op(6117);
+		pub const OpControlBarrierArriveINTEL: Op = Op(6142);
+		pub const OpControlBarrierWaitINTEL: Op = Op(6143);
+		pub const OpGroupIMulKHR: Op = Op(6401);
+		pub const OpGroupFMulKHR: Op = Op(6402);
+		pub const OpGroupBitwiseAndKHR: Op = Op(6403);
+		pub const OpGroupBitwiseOrKHR: Op = Op(6404);
+		pub const OpGroupBitwiseXorKHR: Op = Op(6405);
+		pub const OpGroupLogicalAndKHR: Op = Op(6406);
+		pub const OpGroupLogicalOrKHR: Op = Op(6407);
+		pub const OpGroupLogicalXorKHR: Op = Op(6408);
+	}
+}
+#[allow(non_upper_case_globals, dead_code, clippy::all)]
+pub mod glslstd450 {
+	pub const IMPORT_NAME: &str = "GLSL.std.450";
+	pub const VERSION: u32 = 100;
+	pub const REVISION: u32 = 2;
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Op(pub u32);
+	impl Op {
+		pub const Round: Op = Op(1);
+		pub const RoundEven: Op = Op(2);
+		pub const Trunc: Op = Op(3);
+		pub const FAbs: Op = Op(4);
+		pub const SAbs: Op = Op(5);
+		pub const FSign: Op = Op(6);
+		pub const SSign: Op = Op(7);
+		pub const Floor: Op = Op(8);
+		pub const Ceil: Op = Op(9);
+		pub const Fract: Op = Op(10);
+		pub const Radians: Op = Op(11);
+		pub const Degrees: Op = Op(12);
+		pub const Sin: Op = Op(13);
+		pub const Cos: Op = Op(14);
+		pub const Tan: Op = Op(15);
+		pub const Asin: Op = Op(16);
+		pub const Acos: Op = Op(17);
+		pub const Atan: Op = Op(18);
+		pub const Sinh: Op = Op(19);
+		pub const Cosh: Op = Op(20);
+		pub const Tanh: Op = Op(21);
+		pub const Asinh: Op = Op(22);
+		pub const Acosh: Op = Op(23);
+		pub const Atanh: Op = Op(24);
+		pub const Atan2: Op = Op(25);
+		pub const Pow: Op = Op(26);
+		pub const Exp: Op = Op(27);
+		pub const Log: Op = Op(28);
+		pub const Exp2: Op = Op(29);
+		pub const Log2: Op = Op(30);
+		pub const Sqrt: Op = Op(31);
+		pub const InverseSqrt: Op = Op(32);
+		pub const Determinant: Op = Op(33);
+		pub const MatrixInverse: Op = Op(34);
+		pub const Modf: Op = Op(35);
+		pub const ModfStruct: Op = Op(36);
+		pub const FMin: Op = Op(37);
+		pub const UMin: Op = Op(38);
+		pub const SMin: Op = Op(39);
+		pub const FMax: Op = Op(40);
+		pub const UMax: Op = Op(41);
+		pub const SMax: Op = Op(42);
+		pub const FClamp: Op = Op(43);
+		pub const UClamp: Op = Op(44);
+		pub const SClamp: Op = Op(45);
+		pub const FMix: Op = Op(46);
+		pub const IMix: Op = Op(47);
+		pub const Step: Op = Op(48);
+		pub const SmoothStep: Op = Op(49);
+		pub const Fma: Op = Op(50);
+		pub const Frexp: Op = Op(51);
+		pub const FrexpStruct: Op = Op(52);
+		pub const Ldexp: Op = Op(53);
+		pub const PackSnorm4x8: Op = Op(54);
+		pub const PackUnorm4x8: Op = Op(55);
+		pub const PackSnorm2x16: Op = Op(56);
+		pub const PackUnorm2x16: Op = Op(57);
+		pub const PackHalf2x16: Op = Op(58);
+		pub const PackDouble2x32: Op = Op(59);
+		pub const UnpackSnorm2x16: Op = Op(60);
+		pub const UnpackUnorm2x16: Op = Op(61);
+		pub const UnpackHalf2x16: Op = Op(62);
+		pub const UnpackSnorm4x8: Op = Op(63);
+		pub const UnpackUnorm4x8: Op = Op(64);
+		pub const UnpackDouble2x32: Op = Op(65);
+		pub const Length: Op = Op(66);
+		pub const Distance: Op = Op(67);
+		pub const Cross: Op = Op(68);
+		pub const Normalize: Op = Op(69);
+		pub const FaceForward: Op = Op(70);
+		pub const Reflect: Op = Op(71);
+		pub const Refract: Op = Op(72);
+		pub const FindILsb: Op = Op(73);
+		pub const FindSMsb: Op = Op(74);
+		pub const FindUMsb: Op = Op(75);
+		pub const InterpolateAtCentroid: Op = Op(76);
+		pub const InterpolateAtSample: Op = Op(77);
+		pub const InterpolateAtOffset: Op = Op(78);
+		pub const NMin: Op = Op(79);
+		pub const NMax: Op = Op(80);
+		pub const NClamp: Op = Op(81);
+	}
+}
+#[allow(non_upper_case_globals, dead_code, clippy::all)]
+pub mod openclstd {
+	pub const IMPORT_NAME: &str = "OpenCL.std";
+	pub const VERSION: u32 = 100;
+	pub const REVISION: u32 = 2;
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+	pub struct Op(pub u32);
+	impl Op {
+		pub const acos: Op = Op(0);
+		pub const acosh: Op = Op(1);
+		pub const acospi: Op = Op(2);
+		pub const asin: Op = Op(3);
+		pub const asinh: Op = Op(4);
+		pub const asinpi: Op = Op(5);
+		pub const atan: Op = Op(6);
+		pub const atan2: Op = Op(7);
+		pub const atanh: Op = Op(8);
+		pub const atanpi: Op = Op(9);
+		pub const atan2pi: Op = Op(10);
+		pub const cbrt: Op = Op(11);
+		pub const ceil: Op = Op(12);
+		pub const copysign: Op = Op(13);
+		pub const cos: Op = Op(14);
+		pub const cosh: Op = Op(15);
+		pub const cospi: Op = Op(16);
+		pub const erfc: Op = Op(17);
+		pub const erf: Op = Op(18);
+		pub const exp: Op = Op(19);
+		pub const exp2: Op = Op(20);
+		pub const exp10: Op = Op(21);
+		pub const expm1: Op = Op(22);
+		pub const fabs: Op = Op(23);
+		pub const fdim: Op = Op(24);
+		pub const floor: Op = Op(25);
+		pub const fma: Op = Op(26);
+		pub const fmax: Op = Op(27);
+		pub const fmin: Op = Op(28);
+		pub const fmod: Op = Op(29);
+		pub const fract: Op = Op(30);
+		pub const frexp: Op = Op(31);
+		pub const hypot: Op = Op(32);
+		pub const ilogb: Op = Op(33);
+		pub const ldexp: Op = Op(34);
+		pub const lgamma: Op = Op(35);
+		pub const lgamma_r: Op = Op(36);
+		pub const log: Op = Op(37);
+		pub const log2: Op = Op(38);
+		pub const log10: Op = Op(39);
+		pub const log1p: Op = Op(40);
+		pub const logb: Op = Op(41);
+		pub const mad: Op = Op(42);
+		pub const maxmag: Op = Op(43);
+		pub const minmag: Op = Op(44);
+		pub const modf: Op = Op(45);
+		pub const nan: Op = Op(46);
+		pub const nextafter: Op = Op(47);
+		pub const pow: Op = Op(48);
+		pub const pown: Op = Op(49);
+		pub const powr: Op = Op(50);
+		pub const remainder: Op = Op(51);
+		pub const remquo: Op = Op(52);
+		pub const rint: Op = Op(53);
+		pub const rootn: Op = Op(54);
+		pub const round: Op = Op(55);
+		pub const rsqrt: Op = Op(56);
+		pub const sin: Op = Op(57);
+		pub const sincos: Op = Op(58);
+		pub const sinh: Op = Op(59);
+		pub const sinpi: Op = Op(60);
+		pub const sqrt: Op = Op(61);
+		pub const tan: Op = Op(62);
+		pub const tanh: Op = Op(63);
+		pub const tanpi: Op = Op(64);
+		pub const tgamma: Op = Op(65);
+		pub const trunc: Op = Op(66);
+		pub const half_cos: Op = Op(67);
+		pub const half_divide: Op = Op(68);
+		pub const half_exp: Op = Op(69);
+		pub const half_exp2: Op = Op(70);
+		pub const half_exp10: Op = Op(71);
+		pub const half_log: Op = Op(72);
+		pub const half_log2: Op = Op(73);
+		pub const half_log10: Op = Op(74);
+		pub const half_powr: Op = Op(75);
+		pub const half_recip: Op = Op(76);
+		pub const half_rsqrt: Op = Op(77);
+		pub const half_sin: Op = Op(78);
+		pub const half_sqrt: Op = Op(79);
+		pub const half_tan: Op = Op(80);
+		pub const native_cos: Op = Op(81);
+		pub const native_divide: Op = Op(82);
+		pub const native_exp: Op = Op(83);
+		pub const native_exp2: Op = Op(84);
+		pub const native_exp10: Op = Op(85);
+		pub const native_log: Op = Op(86);
+		pub const native_log2: Op = Op(87);
+		pub const native_log10: Op = Op(88);
+		pub const native_powr: Op = Op(89);
+		pub const native_recip: Op = Op(90);
+		pub const native_rsqrt: Op = Op(91);
+		pub const native_sin: Op = Op(92);
+		pub const native_sqrt: Op = Op(93);
+		pub const native_tan: Op = Op(94);
+		pub const s_abs: Op = Op(141);
+		pub const s_abs_diff: Op = Op(142);
+		pub const s_add_sat: Op = Op(143);
+		pub const u_add_sat: Op = Op(144);
+		pub const s_hadd: Op = Op(145);
+		pub const u_hadd: Op = Op(146);
+		pub const s_rhadd: Op = Op(147);
+		pub const u_rhadd: Op = Op(148);
+		pub const s_clamp: Op = Op(149);
+		pub const u_clamp: Op = Op(150);
+		pub const clz: Op = Op(151);
+		pub const ctz: Op = Op(152);
+		pub const s_mad_hi: Op = Op(153);
+		pub const u_mad_sat: Op = Op(154);
+		pub const s_mad_sat: Op = Op(155);
+		pub const s_max: Op = Op(156);
+		pub const u_max: Op = Op(157);
+		pub const s_min: Op = Op(158);
+		pub const u_min: Op = Op(159);
+		pub const s_mul_hi: Op = Op(160);
+		pub const rotate: Op = Op(161);
+		pub const s_sub_sat: Op = Op(162);
+		pub const u_sub_sat: Op = Op(163);
+		pub const u_upsample: Op = Op(164);
+		pub const s_upsample: Op = Op(165);
+		pub const popcount: Op = Op(166);
+		pub const s_mad24: Op = Op(167);
+		pub const u_mad24: Op = Op(168);
+		pub const s_mul24: Op = Op(169);
+		pub const u_mul24: Op = Op(170);
+		pub const u_abs: Op = Op(201);
+		pub const u_abs_diff: Op = Op(202);
+		pub const u_mul_hi: Op = Op(203);
+		pub const u_mad_hi: Op = Op(204);
+		pub const fclamp: Op = Op(95);
+		pub const degrees: Op = Op(96);
+		pub const fmax_common: Op = Op(97);
+		pub const fmin_common: Op = Op(98);
+		pub const mix: Op = Op(99);
+		pub const radians: Op = Op(100);
+		pub const step: Op = Op(101);
+		pub const smoothstep: Op = Op(102);
+		pub const sign: Op = Op(103);
+		pub const cross: Op = Op(104);
+		pub const distance: Op = Op(105);
+		pub const length: Op = Op(106);
+		pub const normalize: Op = Op(107);
+		pub const fast_distance: Op = Op(108);
+		pub const fast_length: Op = Op(109);
+		pub const fast_normalize: Op = Op(110);
+		pub const bitselect: Op = Op(186);
+		pub const select: Op = Op(187);
+		pub const vloadn: Op = Op(171);
+		pub const vstoren: Op = Op(172);
+		pub const vload_half: Op = Op(173);
+		pub const vload_halfn: Op = Op(174);
+		pub const vstore_half: Op = Op(175);
+		pub const vstore_half_r: Op = Op(176);
+		pub const vstore_halfn: Op = Op(177);
+		pub const vstore_halfn_r: Op = Op(178);
+		pub const vloada_halfn: Op = Op(179);
+		pub const vstorea_halfn: Op = Op(180);
+		pub const vstorea_halfn_r: Op = Op(181);
+		pub const shuffle: Op = Op(182);
+		pub const shuffle2: Op = Op(183);
+		pub const printf: Op = Op(184);
+		pub const prefetch: Op = Op(185);
+	}
+}
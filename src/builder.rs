@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::unique_instructions;
+
+// 'Sampled Type' -> _sampledType, unnamed operands are named after their kind
+fn parameter_name(name: Option<&String>, kind: &str) -> String
+{
+    let name = match name {
+        Some(n) => n.split(',').next().unwrap_or_default().to_string(),
+        None => kind.trim_start_matches("Id").to_string()
+    };
+
+    let mut ident = String::from("_");
+    for word in name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|w| !w.is_empty()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if ident.len() == 1 { ident.extend(first.to_lowercase()); } else { ident.extend(first.to_uppercase()); }
+            ident.push_str(chars.as_str());
+        }
+    }
+    if ident.len() == 1 { ident.push_str("operand"); }
+    ident
+}
+
+fn parameter_type(kind: &str, category: &str) -> String
+{
+    match category {
+        "Id" => "Id".to_string(),
+        "BitEnum" => format!("{}Mask", kind),
+        "ValueEnum" | "Composite" => kind.to_string(),
+        _ => match kind {
+            "LiteralString" => "const char*".to_string(),
+            "LiteralContextDependentNumber" => "Span<unsigned int>".to_string(), // width depends on the result type
+            _ => "unsigned int".to_string()
+        }
+    }
+}
+
+struct Parameter
+{
+    ty: String,
+    name: String,
+    optional: bool
+}
+
+pub fn instruction_builder(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    let mut kind_categories = HashMap::new();
+    let mut kind_has_parameters = HashMap::new();
+    for elem in operand_kinds {
+        kind_categories.insert(elem.kind.as_str(), elem.category.as_str());
+        kind_has_parameters.insert(elem.kind.as_str(), elem.enumerants.iter().flatten().any(|e| e.parameters.is_some()));
+    }
+
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "#include \"Spv.h\"\n")?;
+
+    writeln!(out, "namespace spvgentwo::spv\n{{")?;
+
+    writeln!(out, "\ttemplate <class T>")?;
+    writeln!(out, "\tstruct Span\n\t{{")?;
+        writeln!(out, "\t\tconst T* data = nullptr;")?;
+        writeln!(out, "\t\tunsigned int size = 0u;")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\ttemplate <class T>")?;
+    writeln!(out, "\tstruct Optional\n\t{{")?;
+        writeln!(out, "\t\tOptional() = default;")?;
+        writeln!(out, "\t\tOptional(T _value) : value(_value), valid(true) {{}}")?;
+        writeln!(out, "\t\tT value{{}};")?;
+        writeln!(out, "\t\tbool valid = false;")?;
+    writeln!(out, "\t}};")?;
+
+    // composite operand kinds like PairLiteralIntegerIdRef
+    for op in operand_kinds {
+        if let Some(bases) = &op.bases {
+            writeln!(out, "\tstruct {}\n\t{{", op.kind)?;
+            for (i, b) in bases.iter().enumerate() {
+                writeln!(out, "\t\t{} {};", parameter_type(b, kind_categories[b.as_str()]), if i == 0 { "first" } else { "second" })?;
+            }
+            writeln!(out, "\t}};")?;
+        }
+    }
+
+    writeln!(out, "\tnamespace detail\n\t{{")?;
+        writeln!(out, "\t\ttemplate <class T>")?;
+        writeln!(out, "\t\tconstexpr unsigned int wordCount(const T&) {{ return 1u; }}")?;
+        writeln!(out, "\t\tinline unsigned int wordCount(const char* _str) {{ unsigned int len = 0u; while (_str[len] != '\\0') {{ ++len; }} return len / 4u + 1u; }}")?;
+        writeln!(out, "\t\ttemplate <class Sink, class T>")?;
+        writeln!(out, "\t\tvoid write(Sink& _sink, const T& _value) {{ _sink.emplace_back(static_cast<unsigned int>(_value)); }}")?;
+        writeln!(out, "\t\ttemplate <class Sink>")?;
+        writeln!(out, "\t\tvoid write(Sink& _sink, const char* _str)\n\t\t{{")?;
+            writeln!(out, "\t\t\tunsigned int word = 0u, shift = 0u;")?;
+            writeln!(out, "\t\t\tfor (; *_str != '\\0'; ++_str)\n\t\t\t{{")?;
+                writeln!(out, "\t\t\t\tword |= static_cast<unsigned int>(static_cast<unsigned char>(*_str)) << shift;")?;
+                writeln!(out, "\t\t\t\tif ((shift += 8u) == 32u) {{ _sink.emplace_back(word); word = 0u; shift = 0u; }}")?;
+            writeln!(out, "\t\t\t}}")?;
+            writeln!(out, "\t\t\t_sink.emplace_back(word); // null terminator")?;
+        writeln!(out, "\t\t}}")?;
+        for op in operand_kinds {
+            if let Some(bases) = &op.bases {
+                writeln!(out, "\t\tconstexpr unsigned int wordCount(const {}&) {{ return {}u; }}", op.kind, bases.len())?;
+                writeln!(out, "\t\ttemplate <class Sink>")?;
+                writeln!(out, "\t\tvoid write(Sink& _sink, const {}& _value) {{ write(_sink, _value.first); write(_sink, _value.second); }}", op.kind)?;
+            }
+        }
+
+        writeln!(out, "\t\ttemplate <class T>")?;
+        writeln!(out, "\t\tunsigned int wordCount(const Optional<T>& _value) {{ return _value.valid ? wordCount(_value.value) : 0u; }}")?;
+        writeln!(out, "\t\ttemplate <class T>")?;
+        writeln!(out, "\t\tunsigned int wordCount(const Span<T>& _values) {{ unsigned int count = 0u; for (unsigned int i = 0u; i < _values.size; ++i) {{ count += wordCount(_values.data[i]); }} return count; }}")?;
+
+        writeln!(out, "\t\ttemplate <class Sink, class T>")?;
+        writeln!(out, "\t\tvoid write(Sink& _sink, const Optional<T>& _value) {{ if (_value.valid) {{ write(_sink, _value.value); }} }}")?;
+        writeln!(out, "\t\ttemplate <class Sink, class T>")?;
+        writeln!(out, "\t\tvoid write(Sink& _sink, const Span<T>& _values) {{ for (unsigned int i = 0u; i < _values.size; ++i) {{ write(_sink, _values.data[i]); }} }}")?;
+    writeln!(out, "\t}} // detail\n")?;
+
+    writeln!(out, "\t// Sink needs to provide emplace_back(unsigned int), enumerant parameters are passed as raw words")?;
+    for instr in unique_instructions(&spv.instructions).values() {
+        let mut params: Vec<Parameter> = Vec::new();
+        let mut names: HashMap<String, u32> = HashMap::new();
+        let mut unique_name = |name: String| -> String {
+            let count = names.entry(name.clone()).or_insert(0);
+            *count += 1;
+            if *count > 1 { format!("{}{}", name, count) } else { name }
+        };
+
+        for op in instr.operands.iter().flatten() {
+            let ty = parameter_type(&op.kind, kind_categories[op.kind.as_str()]);
+            let name = unique_name(parameter_name(op.name.as_ref(), &op.kind));
+            match op.quantifier.as_deref() {
+                Some("?") => params.push(Parameter { ty: format!("Optional<{}>", ty), name: name.clone(), optional: true }),
+                Some("*") => params.push(Parameter { ty: format!("Span<{}>", ty), name: name.clone(), optional: true }),
+                _ => params.push(Parameter { ty, name: name.clone(), optional: false })
+            }
+            if kind_has_parameters[op.kind.as_str()] {
+                params.push(Parameter { ty: "Span<unsigned int>".to_string(), name: unique_name(format!("{}Parameters", name)), optional: true });
+            }
+        }
+
+        // only trailing optional operands can be defaulted
+        let first_default = params.iter().rposition(|p| !p.optional).map_or(0, |i| i + 1);
+
+        let mut signature = vec!["Sink& _sink".to_string()];
+        for (i, p) in params.iter().enumerate() {
+            if i >= first_default {
+                signature.push(format!("{} {} = {{}}", p.ty, p.name));
+            } else {
+                signature.push(format!("{} {}", p.ty, p.name));
+            }
+        }
+
+        let mut chars = instr.opname.chars();
+        let fn_name: String = chars.next().into_iter().flat_map(|c| c.to_lowercase()).chain(chars).collect();
+
+        writeln!(out, "\ttemplate <class Sink>")?;
+        writeln!(out, "\tvoid {}({})\n\t{{", fn_name, signature.join(", "))?;
+            let counts: Vec<String> = params.iter().map(|p| format!(" + detail::wordCount({})", p.name)).collect();
+            writeln!(out, "\t\tconst unsigned int wordCount = 1u{};", counts.join(""))?;
+            writeln!(out, "\t\t_sink.emplace_back((wordCount << WordCountShift) | static_cast<unsigned int>(Op::{}));", instr.opname)?;
+            for p in &params {
+                writeln!(out, "\t\tdetail::write(_sink, {});", p.name)?;
+            }
+        writeln!(out, "\t}}")?;
+    }
+
+    writeln!(out, "}} // spvgentwo::spv")?;
+    Ok(())
+}
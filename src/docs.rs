@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::Instruction;
+use crate::Operand;
+use crate::display_name;
+use crate::predicates::IGNORED_CLASSES;
+use crate::quantifier_name;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DocFormat
+{
+    Markdown,
+    Html
+}
+
+fn escape(text: &str, format: DocFormat) -> String
+{
+    match format {
+        // backslash escapes for every character that can start inline markup or an html tag, or end a table cell
+        DocFormat::Markdown => text.chars().fold(String::new(), |mut s, c| {
+            if "\\`*_[]<>|~&".contains(c) { s.push('\\'); }
+            s.push(c);
+            s
+        }),
+        DocFormat::Html => text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+    }
+}
+
+fn heading(level: usize, text: &str, anchor: &str, format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    match format {
+        DocFormat::Markdown => writeln!(out, "<a id=\"{}\"></a>\n\n{} {}\n", anchor, "#".repeat(level), escape(text, format)),
+        DocFormat::Html => writeln!(out, "<h{0} id=\"{1}\">{2}</h{0}>", level, anchor, escape(text, format))
+    }
+}
+
+fn link(text: &str, anchor: &str, format: DocFormat) -> String
+{
+    match format {
+        DocFormat::Markdown => format!("[{}](#{})", escape(text, format), anchor),
+        DocFormat::Html => format!("<a href=\"#{}\">{}</a>", anchor, escape(text, format))
+    }
+}
+
+fn paragraph(text: &str, format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    match format {
+        DocFormat::Markdown => writeln!(out, "{}\n", text),
+        DocFormat::Html => writeln!(out, "<p>{}</p>", text)
+    }
+}
+
+// cells are expected to be escaped already
+fn table(header: &[&str], rows: &[Vec<String>], format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    if rows.is_empty() { return Ok(()); }
+
+    match format {
+        DocFormat::Markdown => {
+            writeln!(out, "| {} |", header.join(" | "))?;
+            writeln!(out, "|{}", " --- |".repeat(header.len()))?;
+            for row in rows {
+                writeln!(out, "| {} |", row.join(" | "))?;
+            }
+            writeln!(out)?;
+        },
+        DocFormat::Html => {
+            writeln!(out, "<table>")?;
+            writeln!(out, "<tr>{}</tr>", header.iter().map(|h| format!("<th>{}</th>", h)).collect::<String>())?;
+            for row in rows {
+                writeln!(out, "<tr>{}</tr>", row.iter().map(|c| format!("<td>{}</td>", c)).collect::<String>())?;
+            }
+            writeln!(out, "</table>")?;
+        }
+    }
+    Ok(())
+}
+
+fn list(items: &[String], format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    match format {
+        DocFormat::Markdown => {
+            for item in items { writeln!(out, "* {}", item)?; }
+            writeln!(out)?;
+        },
+        DocFormat::Html => {
+            writeln!(out, "<ul>")?;
+            for item in items { writeln!(out, "<li>{}</li>", item)?; }
+            writeln!(out, "</ul>")?;
+        }
+    }
+    Ok(())
+}
+
+fn kind_anchor(kind: &str) -> String { format!("kind-{}", kind) }
+fn enumerant_anchor(kind: &str, enumerant: &str) -> String { format!("{}-{}", kind, enumerant) }
+fn class_anchor(prefix: &str, class: &str) -> String { format!("{}-class-{}", prefix, class.replace(|c: char| !c.is_ascii_alphanumeric(), "-")) }
+
+fn capability_links(caps: &Option<Vec<String>>, format: DocFormat) -> String
+{
+    caps.iter().flatten().map(|c| link(c, &enumerant_anchor("Capability", c), format)).collect::<Vec<_>>().join(", ")
+}
+
+fn plain_list(items: &Option<Vec<String>>, format: DocFormat) -> String
+{
+    items.iter().flatten().map(|e| escape(e, format)).collect::<Vec<_>>().join(", ")
+}
+
+fn operand_row(op: &Operand, format: DocFormat) -> Vec<String>
+{
+    vec![link(&op.kind, &kind_anchor(&op.kind), format), escape(&display_name(op.name.as_deref().unwrap_or_default()), format), quantifier_name(&op.quantifier).to_string()]
+}
+
+fn instructions(prefix: &str, instructions: &[Instruction], format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut classes: BTreeMap<&str, Vec<&Instruction>> = BTreeMap::new();
+    for instr in instructions {
+        // classes that don't describe what an instruction does get no section of their own, like in the predicates
+        let class = match instr.class.as_deref() { Some(c) if !IGNORED_CLASSES.contains(&c) => c, _ => "Miscellaneous" };
+        classes.entry(class).or_default().push(instr);
+    }
+
+    let toc: Vec<String> = classes.keys().map(|c| link(c, &class_anchor(prefix, c), format)).collect();
+    list(&toc, format, out)?;
+
+    for (class, instrs) in &classes {
+        heading(3, class, &class_anchor(prefix, class), format, out)?;
+
+        for instr in instrs {
+            heading(4, &instr.opname, &format!("{}-{}", prefix, instr.opname), format, out)?;
+
+            let mut props = vec![format!("Opcode: {}", instr.opcode)];
+            if instr.capabilities.is_some() { props.push(format!("Capabilities: {}", capability_links(&instr.capabilities, format))); }
+            if instr.extensions.is_some() { props.push(format!("Extensions: {}", plain_list(&instr.extensions, format))); }
+            if let Some(version) = &instr.version { props.push(format!("Version: {}", escape(version, format))); }
+            list(&props, format, out)?;
+
+            let rows: Vec<Vec<String>> = instr.operands.iter().flatten().map(|op| operand_row(op, format)).collect();
+            table(&["Kind", "Name", "Quantifier"], &rows, format, out)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn docs(spv: &Grammar, exts: &[(String, Grammar)], provenance: &[String], format: DocFormat, out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    let title = format!("SPIR-V {}.{} Grammar Reference", spv.major_version.unwrap_or_default(), spv.minor_version.unwrap_or_default());
+    if format == DocFormat::Html {
+        writeln!(out, "<!DOCTYPE html>")?;
+    }
+    writeln!(out, "<!-- Auto generated - do not modify -->")?;
+    for line in provenance {
+        writeln!(out, "<!-- {} -->", line)?;
+    }
+
+    match format {
+        DocFormat::Markdown => writeln!(out)?,
+        DocFormat::Html => {
+            writeln!(out, "<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>", title)?;
+            writeln!(out, "<style>body {{ font-family: sans-serif; }} table {{ border-collapse: collapse; }} th, td {{ border: 1px solid #ccc; padding: 2px 6px; text-align: left; }}</style>")?;
+            writeln!(out, "</head>\n<body>")?;
+        }
+    }
+
+    heading(1, &title, "top", format, out)?;
+    paragraph(&format!("Revision {}", spv.revision), format, out)?;
+
+    let mut toc = vec![link("Instructions", "instructions", format)];
+    for (name, _) in exts {
+        toc.push(link(&format!("{} Instructions", name), &format!("{}-instructions", name), format));
+    }
+    toc.push(link("Operand Kinds", "operand-kinds", format));
+    list(&toc, format, out)?;
+
+    heading(2, "Instructions", "instructions", format, out)?;
+    instructions("core", &spv.instructions, format, out)?;
+
+    for (name, ext) in exts {
+        heading(2, &format!("{} Instructions", name), &format!("{}-instructions", name), format, out)?;
+        paragraph(&format!("Version {}, Revision {}", ext.version.unwrap_or_default(), ext.revision), format, out)?;
+        instructions(name, &ext.instructions, format, out)?;
+    }
+
+    heading(2, "Operand Kinds", "operand-kinds", format, out)?;
+    let toc: Vec<String> = operand_kinds.iter().map(|k| link(&k.kind, &kind_anchor(&k.kind), format)).collect();
+    list(&toc, format, out)?;
+
+    for kind in operand_kinds {
+        heading(3, &kind.kind, &kind_anchor(&kind.kind), format, out)?;
+        paragraph(&format!("Category: {}", kind.category), format, out)?;
+
+        if let Some(bases) = &kind.bases {
+            let bases: Vec<String> = bases.iter().map(|b| link(b, &kind_anchor(b), format)).collect();
+            paragraph(&format!("Bases: {}", bases.join(", ")), format, out)?;
+        }
+
+        let mut rows = Vec::new();
+        for en in kind.enumerants.iter().flatten() {
+            let value = match &en.value { serde_json::Value::String(s) => s.clone(), v => v.to_string() };
+            let parameters: Vec<String> = en.parameters.iter().flatten().map(|p| {
+                let name = display_name(p.name.as_deref().unwrap_or_default());
+                format!("{} {}", link(&p.kind, &kind_anchor(&p.kind), format), escape(&name, format)).trim_end().to_string()
+            }).collect();
+            let enumerant = match format {
+                DocFormat::Markdown => format!("<a id=\"{}\"></a>{}", enumerant_anchor(&kind.kind, &en.enumerant), escape(&en.enumerant, format)),
+                DocFormat::Html => format!("<span id=\"{}\">{}</span>", enumerant_anchor(&kind.kind, &en.enumerant), escape(&en.enumerant, format))
+            };
+            rows.push(vec![enumerant, value, parameters.join(", "), capability_links(&en.capabilities, format), plain_list(&en.extensions, format), escape(en.version.as_deref().unwrap_or_default(), format)]);
+        }
+        table(&["Enumerant", "Value", "Parameters", "Capabilities", "Extensions", "Version"], &rows, format, out)?;
+    }
+
+    if format == DocFormat::Html {
+        writeln!(out, "</body>\n</html>")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::enumerant_value;
+use crate::lock;
+use crate::parse_version;
+use crate::profile::extension_vendor;
+
+#[derive(Default)]
+struct Enabled<'a>
+{
+    ops: Vec<&'a str>,
+    enumerants: Vec<(&'a str, &'a str, u32)>,
+    // core version of every item, 0 for items that are only available through extensions
+    versions: Vec<u32>
+}
+
+// coreSince of extensions that were never promoted, 0 would read as core since 1.0
+const NOT_IN_CORE: u32 = 0xffffffff;
+
+// version all items of an extension are core in, NOT_IN_CORE if some of them never got promoted
+fn core_since(versions: &[u32]) -> u32
+{
+    if versions.contains(&0) { NOT_IN_CORE } else { versions.iter().copied().max().unwrap_or_default() }
+}
+
+// vendor, enabled instructions and enumerants and the core version of every spv::Extension, indexed like
+// ExtensionNames. Aliases share their opcode or value and are listed once.
+pub fn extension_info(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut enabled: BTreeMap<&str, Enabled> = BTreeMap::new();
+    let mut opcodes = BTreeSet::new();
+    for instr in &spv.instructions {
+        for ext in instr.extensions.iter().flatten() {
+            let entry = enabled.entry(ext).or_default();
+            entry.versions.push(parse_version(&instr.version));
+            if opcodes.insert((ext, instr.opcode)) { entry.ops.push(&instr.opname); }
+        }
+    }
+    let mut values = BTreeSet::new();
+    for op in spv.operand_kinds.iter().flatten() {
+        for en in op.enumerants.iter().flatten() {
+            let value = enumerant_value(&en.value);
+            for ext in en.extensions.iter().flatten() {
+                let entry = enabled.entry(ext).or_default();
+                entry.versions.push(parse_version(&en.version));
+                if values.insert((ext, &op.kind, value)) { entry.enumerants.push((&op.kind, &en.enumerant, value)); }
+            }
+        }
+    }
+
+    writeln!(out, "\tstatic constexpr unsigned int NotInCore = 0x{:x};", NOT_IN_CORE)?;
+    writeln!(out, "\tstruct ExtensionEnumerant\n\t{{")?;
+    writeln!(out, "\t\tconst char* kind; // operand kind like \"Capability\"")?;
+    writeln!(out, "\t\tconst char* name;")?;
+    writeln!(out, "\t\tunsigned int value;")?;
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tstruct ExtensionInfo\n\t{{")?;
+    writeln!(out, "\t\tconst char* vendor; // KHR, EXT, NV, ...")?;
+    writeln!(out, "\t\tunsigned int coreSince; // core version like 0x00010300 that contains everything the extension enables, NotInCore if never promoted")?;
+    writeln!(out, "\t\tconst Op* ops;")?;
+    writeln!(out, "\t\tunsigned short opCount;")?;
+    writeln!(out, "\t\tconst ExtensionEnumerant* enumerants;")?;
+    writeln!(out, "\t\tunsigned short enumerantCount;")?;
+    writeln!(out, "\t}};")?;
+
+    let numbers = lock::numbering(spv, &[]);
+    let extensions = lock::by_number(&numbers.extensions, 0);
+
+    writeln!(out, "\tstatic constexpr Op ExtensionOps[] =\n\t{{")?;
+    let mut count = 0;
+    for ext in extensions.iter().filter_map(|(_, ext)| *ext) {
+        for op in &enabled[ext].ops {
+            writeln!(out, "\t\tOp::{}, // {}", op, ext)?;
+            count += 1;
+        }
+    }
+    if count == 0 {
+        writeln!(out, "\t\tOp{{}}, // no extension instructions")?;
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tstatic constexpr ExtensionEnumerant ExtensionEnumerants[] =\n\t{{")?;
+    let mut count = 0;
+    for ext in extensions.iter().filter_map(|(_, ext)| *ext) {
+        for (kind, name, value) in &enabled[ext].enumerants {
+            writeln!(out, "\t\t{{ \"{}\", \"{}\", {}u }}, // {}", kind, name, value, ext)?;
+            count += 1;
+        }
+    }
+    if count == 0 {
+        writeln!(out, "\t\t{{ nullptr, nullptr, 0u }}, // no extension enumerants")?;
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tstatic constexpr ExtensionInfo ExtensionInfos[] =\n\t{{")?;
+    let (mut ops, mut enumerants) = (0, 0);
+    for (_, ext) in &extensions {
+        let ext = match ext {
+            Some(ext) => ext,
+            None => { writeln!(out, "\t\t{{ nullptr, NotInCore, nullptr, 0u, nullptr, 0u }}, // removed from the grammar")?; continue; }
+        };
+        let info = &enabled[ext];
+        let since = match core_since(&info.versions) { NOT_IN_CORE => "NotInCore".to_string(), since => format!("0x{:x}u", since) };
+        writeln!(out, "\t\t{{ \"{}\", {}, ExtensionOps + {}, {}u, ExtensionEnumerants + {}, {}u }}, // {}",
+            extension_vendor(ext).unwrap_or_default(), since, ops, info.ops.len(), enumerants, info.enumerants.len(), ext)?;
+        ops += info.ops.len();
+        enumerants += info.enumerants.len();
+    }
+    if extensions.is_empty() {
+        writeln!(out, "\t\t{{ nullptr, NotInCore, nullptr, 0u, nullptr, 0u }}, // no extensions")?;
+    }
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tinline constexpr const ExtensionInfo& GetExtensionInfo(Extension ext) {{ return ExtensionInfos[static_cast<unsigned>(ext)]; }}")?;
+    writeln!(out, "\t// OpExtension can be skipped when targeting a version that already includes everything the extension enables")?;
+    writeln!(out, "\tinline constexpr bool IsExtensionInCore(Extension ext, unsigned int version) {{ const unsigned int since = GetExtensionInfo(ext).coreSince; return since != NotInCore && version >= since; }}")?;
+    Ok(())
+}
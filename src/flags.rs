@@ -0,0 +1,51 @@
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+
+// Mask enumerant as emitted by spv_defs, None clashes with the Shift enumerant
+fn mask_enumerant(enumerant: &str) -> String
+{
+    if enumerant == "None" { "MaskNone".to_string() } else { enumerant.to_string() }
+}
+
+// bitwise operators and flag helpers for every XxxMask enum class. They live in spv::flags so they only apply
+// after `using namespace spvgentwo::spv::flags;` and don't clash with operators users already defined.
+pub fn mask_operators(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "\tnamespace flags\n\t{{")?;
+
+    for op in spv.operand_kinds.iter().flatten().filter(|k| k.category == "BitEnum") {
+        let enumerants = match op.enumerants.as_ref() { Some(v) => v, None => continue };
+        let mask = format!("{}Mask", op.kind);
+        let shift = format!("{}Shift", op.kind);
+
+        writeln!(out, "\t\tinline constexpr {0} operator|({0} l, {0} r) {{ return static_cast<{0}>(static_cast<unsigned>(l) | static_cast<unsigned>(r)); }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0} operator&({0} l, {0} r) {{ return static_cast<{0}>(static_cast<unsigned>(l) & static_cast<unsigned>(r)); }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0} operator^({0} l, {0} r) {{ return static_cast<{0}>(static_cast<unsigned>(l) ^ static_cast<unsigned>(r)); }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0} operator~({0} m) {{ return static_cast<{0}>(~static_cast<unsigned>(m)); }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0}& operator|=({0}& l, {0} r) {{ return l = l | r; }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0}& operator&=({0}& l, {0} r) {{ return l = l & r; }}", mask)?;
+        writeln!(out, "\t\tinline constexpr {0}& operator^=({0}& l, {0} r) {{ return l = l ^ r; }}", mask)?;
+        writeln!(out, "\t\tinline constexpr bool HasFlag({0} mask, {0} flag) {{ return (static_cast<unsigned>(mask) & static_cast<unsigned>(flag)) == static_cast<unsigned>(flag); }}", mask)?;
+
+        // Shift enumerants are numbered in grammar order, not by bit position, so they are mapped by name
+        writeln!(out, "\t\tinline constexpr {} MaskFromShift({} shift) {{", mask, shift)?;
+        writeln!(out, "\t\t\tswitch (shift) {{")?;
+        writeln!(out, "\t\t\tdefault: return static_cast<{}>(0u); // unknown shift", mask)?;
+        for en in enumerants.iter().filter(|en| en.enumerant != "None") {
+            writeln!(out, "\t\t\tcase {}::{}: return {}::{};", shift, en.enumerant, mask, mask_enumerant(&en.enumerant))?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t}}")?;
+
+        // calls func once per set bit, lowest bit first
+        writeln!(out, "\t\ttemplate <class Func>")?;
+        writeln!(out, "\t\tinline constexpr void ForEachFlag({} mask, Func&& func) {{", mask)?;
+        writeln!(out, "\t\t\tfor (unsigned bits = static_cast<unsigned>(mask); bits != 0u; bits &= bits - 1u) {{ func(static_cast<{}>(bits & (~bits + 1u))); }}", mask)?;
+        writeln!(out, "\t\t}}")?;
+    }
+
+    writeln!(out, "\t}} // flags")?;
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use std::cell::OnceCell;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::Extension;
+use crate::Grammar;
+use crate::Profile;
+use crate::Source;
+use crate::ext_import_name;
+use crate::load_grammar;
+use crate::lock::Lock;
+
+/// Generates the SPIR-V tables from a `build.rs`:
+///
+/// ```no_run
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// rustspvgen::Generator::new()
+///     .core("spirv.core.grammar.json")
+///     .ext("extinst.glsl.std.450.grammar.json")
+///     .emit_rust(&out_dir)
+///     .unwrap();
+/// // include!(concat!(env!("OUT_DIR"), "/spirv.rs"));
+/// ```
+///
+/// The grammars are loaded (and the lock file updated) once, on the first emit, which prints `cargo:rerun-if-changed`
+/// for the grammar files so the output is regenerated when they change.
+#[derive(Default)]
+pub struct Generator
+{
+    core: Option<PathBuf>,
+    exts: Vec<PathBuf>,
+    class_overrides: Option<PathBuf>,
+    control_flow_overrides: Option<PathBuf>,
+    lock: Option<PathBuf>,
+    profile: Profile,
+    loaded: OnceCell<Loaded>
+}
+
+struct Loaded
+{
+    spv: Grammar,
+    exts: Vec<(String, Grammar)>,
+    sources: Vec<Source>
+}
+
+impl Generator
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// spirv.core.grammar.json
+    pub fn core<P: AsRef<Path>>(mut self, path: P) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.core = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// extended instruction set grammar like extinst.glsl.std.450.grammar.json
+    pub fn ext<P: AsRef<Path>>(mut self, path: P) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.exts.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// opname to class overrides for the generated IsXxx class predicates, see class_overrides.json
+    pub fn class_overrides<P: AsRef<Path>>(mut self, path: P) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.class_overrides = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// extra block terminators and successor operands for the control flow predicates, see control_flow.json
+    pub fn control_flow_overrides<P: AsRef<Path>>(mut self, path: P) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.control_flow_overrides = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// keeps OperandKind and Extension numbers stable across grammar updates, the file is created on first use
+    /// and new names are appended to it
+    pub fn lock<P: AsRef<Path>>(mut self, path: P) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.lock = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// restricts the output to a target version, capabilities and extensions
+    pub fn profile(mut self, profile: Profile) -> Self
+    {
+        self.loaded = OnceCell::new();
+        self.profile = profile;
+        self
+    }
+
+    fn load(&self) -> io::Result<&Loaded>
+    {
+        if let Some(loaded) = self.loaded.get() {
+            return Ok(loaded);
+        }
+
+        let core = self.core.as_ref().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "core grammar is required"))?;
+
+        let mut sources = Vec::new();
+        for path in self.core.iter().chain(self.exts.iter()).chain(self.class_overrides.iter()).chain(self.control_flow_overrides.iter()) {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let mut spv = load_grammar(core, &mut sources)?;
+        let mut exts = Vec::new();
+        for path in &self.exts {
+            exts.push((ext_import_name(path), load_grammar(path, &mut sources)?));
+        }
+
+        if let Some(path) = &self.class_overrides {
+            crate::predicates::apply_class_overrides(&mut spv, &crate::predicates::load_class_overrides(path)?);
+        }
+        if let Some(path) = &self.control_flow_overrides {
+            crate::predicates::apply_control_flow_overrides(&mut spv, &crate::predicates::load_control_flow_overrides(path)?);
+        }
+        self.profile.apply(&mut std::iter::once(&mut spv).chain(exts.iter_mut().map(|(_, g)| g)).collect::<Vec<_>>());
+
+        if let Some(path) = &self.lock {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let locked = Lock::load(path)?;
+            for removed in locked.removed(&spv, &exts) {
+                println!("cargo:warning={} is locked in {} but missing from the grammar, its number stays reserved", removed, path.display());
+            }
+            let updated = locked.update(&spv, &exts);
+            if updated != locked { updated.save(path)?; }
+            spv.lock = Some(locked);
+        }
+
+        Ok(self.loaded.get_or_init(|| Loaded { spv, exts, sources }))
+    }
+
+    fn create(dir: &Path, file: &str) -> io::Result<BufWriter<File>>
+    {
+        Ok(BufWriter::new(File::create(dir.join(file))?))
+    }
+
+    /// writes spirv.rs with a module per grammar: `spv` and one named after each import name (`glslstd450`, `openclstd`)
+    pub fn emit_rust<P: AsRef<Path>>(&self, out_dir: P) -> io::Result<()>
+    {
+        let loaded = self.load()?;
+
+        let mut out = Self::create(out_dir.as_ref(), "spirv.rs")?;
+        crate::rust::rust_defs(&loaded.spv, &loaded.exts, &loaded.sources, &mut out)?;
+        out.flush()
+    }
+
+    /// writes the SpvGenTwo files Spv.h, Grammar.h, Grammar.cpp and for GLSL.std.450 and OpenCL.std Glsl.h and OpenCl.h
+    pub fn emit_cpp<P: AsRef<Path>>(&self, out_dir: P) -> io::Result<()>
+    {
+        let loaded = self.load()?;
+        let dir = out_dir.as_ref();
+
+        let mut out = Self::create(dir, "Spv.h")?;
+        crate::write_header(&loaded.sources, &mut out)?;
+        crate::spv_defs(&loaded.spv, &mut out)?;
+        out.flush()?;
+
+        let mut out = Self::create(dir, "Grammar.h")?;
+        crate::write_header(&loaded.sources, &mut out)?;
+        crate::grammar_header(&loaded.spv, &loaded.exts, &mut out)?;
+        out.flush()?;
+
+        for (name, grammar) in &loaded.exts {
+            if let Some(ext) = Extension::from_import_name(name) {
+                let mut out = Self::create(dir, ext.header_name())?;
+                crate::write_header(&loaded.sources, &mut out)?;
+                crate::ext_defs(grammar, ext, &mut out)?;
+                out.flush()?;
+            }
+        }
+
+        let mut out = Self::create(dir, "Grammar.cpp")?;
+        crate::write_header(&loaded.sources, &mut out)?;
+        crate::grammar_cpp(&loaded.spv, &loaded.exts, &mut out)?;
+        out.flush()?;
+
+        Ok(())
+    }
+}
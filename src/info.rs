@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::Instruction;
+use crate::parse_version;
+use crate::predicates::IGNORED_CLASSES;
+use crate::template::pascal_case;
+
+// classes that don't describe what an instruction does map to InstructionClass::None
+pub(crate) fn class_enumerant(instr: &Instruction) -> String
+{
+    match instr.class.as_deref() {
+        Some(class) if !IGNORED_CLASSES.contains(&class) => pascal_case(class),
+        _ => "None".to_string()
+    }
+}
+
+// one packed InstructionInfo per opcode, HasResultAndType, HasResult, HasResultType, IsTypeOp, IsConstantOp and
+// IsSpecConstantOp are thin wrappers around GetInfo so hot paths only need a single lookup
+pub fn instruction_info(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    // aliases share their opcode, an opcode is a type or constant if any of its names says so
+    let mut by_opcode: BTreeMap<u32, Vec<&Instruction>> = BTreeMap::new();
+    let mut order = Vec::new();
+    for instr in &spv.instructions {
+        let names = by_opcode.entry(instr.opcode).or_default();
+        if names.is_empty() { order.push(instr.opcode); }
+        names.push(instr);
+    }
+
+    let classes: BTreeSet<String> = spv.instructions.iter().map(class_enumerant).filter(|c| c != "None").collect();
+    writeln!(out, "\tenum class InstructionClass : unsigned char\n\t{{")?;
+    writeln!(out, "\t\tNone, // unknown opcode or no meaningful class")?;
+    for class in &classes {
+        writeln!(out, "\t\t{},", class)?;
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tstruct InstructionInfo\n\t{{")?;
+    writeln!(out, "\t\tbool valid : 1; // false for unknown opcodes")?;
+    writeln!(out, "\t\tbool hasResult : 1;")?;
+    writeln!(out, "\t\tbool hasResultType : 1;")?;
+    writeln!(out, "\t\tbool isType : 1;")?;
+    writeln!(out, "\t\tbool isConstant : 1;")?;
+    writeln!(out, "\t\tbool isSpecConstant : 1;")?;
+    writeln!(out, "\t\tInstructionClass instructionClass;")?;
+    writeln!(out, "\t\tunsigned short operandCount; // operands listed in the grammar, optional and variadic ones count once")?;
+    writeln!(out, "\t\tunsigned int minVersion; // first core version like 0x00010300, 0 if only available through extensions")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tinline constexpr InstructionInfo GetInfo(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return {{ false, false, false, false, false, false, InstructionClass::None, 0u, 0u }}; // unknown opcode")?;
+    for opcode in &order {
+        let names = &by_opcode[opcode];
+        let instr = names[0];
+        let operands = instr.operands.as_deref().unwrap_or_default();
+        let has_result = operands.iter().any(|op| op.kind == "IdResult");
+        let has_result_type = operands.iter().any(|op| op.kind == "IdResultType");
+        let is_type = names.iter().any(|i| i.opname.starts_with("OpType"));
+        let is_constant = names.iter().any(|i| i.opname.starts_with("OpConstant"));
+        let is_spec_constant = names.iter().any(|i| i.opname.starts_with("OpSpecConstant"));
+        writeln!(out, "\t\tcase Op::{}: return {{ true, {}, {}, {}, {}, {}, InstructionClass::{}, {}u, 0x{:x}u }};",
+            instr.opname, has_result, has_result_type, is_type, is_constant, is_spec_constant, class_enumerant(instr), operands.len(), parse_version(&instr.version))?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "\tinline void HasResultAndType(Op opcode, bool *hasResult, bool *hasResultType) {{")?;
+    writeln!(out, "\t\tconst InstructionInfo info = GetInfo(opcode);")?;
+    writeln!(out, "\t\t*hasResult = info.hasResult;")?;
+    writeln!(out, "\t\t*hasResultType = info.hasResultType;")?;
+    writeln!(out, "\t}}")?;
+    writeln!(out, "\t// majority of instructions has a result, unknown opcodes too")?;
+    writeln!(out, "\tinline constexpr bool HasResult(Op opcode) {{ const InstructionInfo info = GetInfo(opcode); return !info.valid || info.hasResult; }}")?;
+    writeln!(out, "\t// majority of instructions has a result type, unknown opcodes too")?;
+    writeln!(out, "\tinline constexpr bool HasResultType(Op opcode) {{ const InstructionInfo info = GetInfo(opcode); return !info.valid || info.hasResultType; }}")?;
+    writeln!(out, "\tinline constexpr bool IsTypeOp(Op opcode) {{ return GetInfo(opcode).isType; }}")?;
+    writeln!(out, "\tinline constexpr bool IsConstantOp(Op opcode) {{ return GetInfo(opcode).isConstant; }}")?;
+    writeln!(out, "\tinline constexpr bool IsSpecConstantOp(Op opcode) {{ return GetInfo(opcode).isSpecConstant; }}")?;
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io;
+use std::io::Write;
+
+use serde_derive::Serialize;
+
+use crate::Grammar;
+use crate::Instruction;
+use crate::OperandKinds;
+use crate::Source;
+use crate::display_name;
+use crate::enumerant_value;
+use crate::operand_name;
+use crate::parse_version;
+use crate::quantifier_name;
+use crate::unique_instructions;
+
+#[derive(Serialize)]
+struct JsonOperand
+{
+    kind: String,
+    // null for kinds no grammar defines
+    category: Option<String>,
+    name: String,
+    quantifier: &'static str
+}
+
+#[derive(Serialize)]
+struct JsonInstruction
+{
+    opname: String,
+    opcode: u32,
+    aliases: Vec<String>,
+    class: Option<String>,
+    operands: Vec<JsonOperand>,
+    capabilities: Vec<String>,
+    extensions: Vec<String>,
+    // null if only available through extensions
+    version: Option<u32>
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonEnumerant
+{
+    enumerant: String,
+    value: u32,
+    aliases: Vec<String>,
+    parameters: Vec<JsonOperand>,
+    capabilities: Vec<String>,
+    extensions: Vec<String>,
+    // null if only available through extensions
+    version: Option<u32>
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonOperandKind
+{
+    pub(crate) kind: String,
+    category: String,
+    bases: Vec<String>,
+    pub(crate) enumerants: Vec<JsonEnumerant>
+}
+
+#[derive(Serialize)]
+struct JsonExtInstSet
+{
+    version: Option<u32>,
+    revision: u32,
+    instructions: Vec<JsonInstruction>,
+    operand_kinds: Vec<JsonOperandKind>
+}
+
+#[derive(Serialize)]
+pub(crate) struct JsonGrammar<'a>
+{
+    generator: String,
+    grammars: &'a [Source],
+    magic_number: String,
+    version: u32,
+    revision: u32,
+    instructions: Vec<JsonInstruction>,
+    pub(crate) operand_kinds: Vec<JsonOperandKind>,
+    ext_inst_sets: BTreeMap<String, JsonExtInstSet>
+}
+
+fn instructions(instructions: &[Instruction], kind_categories: &HashMap<&str, &str>) -> Vec<JsonInstruction>
+{
+    unique_instructions(instructions).values().map(|instr| {
+        JsonInstruction {
+            opname: instr.opname.clone(),
+            opcode: instr.opcode,
+            aliases: instructions.iter().filter(|i| i.opcode == instr.opcode && i.opname != instr.opname).map(|i| i.opname.clone()).collect(),
+            class: instr.class.clone(),
+            operands: instr.operands.iter().flatten().map(|op| JsonOperand {
+                kind: op.kind.clone(),
+                category: kind_categories.get(op.kind.as_str()).map(|c| c.to_string()),
+                name: display_name(&operand_name(op)),
+                quantifier: quantifier_name(&op.quantifier)
+            }).collect(),
+            capabilities: instr.capabilities.clone().unwrap_or_default(),
+            extensions: instr.extensions.clone().unwrap_or_default(),
+            version: json_version(&instr.version)
+        }
+    }).collect()
+}
+
+// "1.3" -> 0x00010300, "None" or no version -> None
+fn json_version(version: &Option<String>) -> Option<u32>
+{
+    version.as_deref().filter(|v| v.contains('.')).map(|_| parse_version(version))
+}
+
+fn json_operand_kinds(kinds: &[OperandKinds], kind_categories: &HashMap<&str, &str>) -> Vec<JsonOperandKind>
+{
+    let mut json_kinds = Vec::new();
+    for kind in kinds {
+        // enumerants sharing a value are aliases of the first one
+        let mut enumerants: Vec<JsonEnumerant> = Vec::new();
+        for en in kind.enumerants.iter().flatten() {
+            let value = enumerant_value(&en.value);
+            if let Some(first) = enumerants.iter_mut().find(|e| e.value == value) {
+                first.aliases.push(en.enumerant.clone());
+                continue;
+            }
+            enumerants.push(JsonEnumerant {
+                enumerant: en.enumerant.clone(),
+                value,
+                aliases: Vec::new(),
+                parameters: en.parameters.iter().flatten().map(|p| JsonOperand {
+                    kind: p.kind.clone(),
+                    category: kind_categories.get(p.kind.as_str()).map(|c| c.to_string()),
+                    name: display_name(p.name.as_deref().unwrap_or_default()),
+                    quantifier: "One"
+                }).collect(),
+                capabilities: en.capabilities.clone().unwrap_or_default(),
+                extensions: en.extensions.clone().unwrap_or_default(),
+                version: json_version(&en.version)
+            });
+        }
+
+        json_kinds.push(JsonOperandKind {
+            kind: kind.kind.clone(),
+            category: kind.category.clone(),
+            bases: kind.bases.clone().unwrap_or_default(),
+            enumerants
+        });
+    }
+    json_kinds
+}
+
+// normalized model shared by --emit json and --template
+pub(crate) fn grammar_model<'a>(spv: &Grammar, exts: &[(String, Grammar)], sources: &'a [Source]) -> JsonGrammar<'a>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    // ext grammars like DebugInfo define their own operand kinds
+    let mut kind_categories = HashMap::new();
+    for elem in operand_kinds.iter().chain(exts.iter().flat_map(|(_, ext)| ext.operand_kinds.iter().flatten())) {
+        kind_categories.entry(elem.kind.as_str()).or_insert(elem.category.as_str());
+    }
+
+    let mut ext_inst_sets = BTreeMap::new();
+    for (name, ext) in exts {
+        ext_inst_sets.insert(name.to_string(), JsonExtInstSet {
+            version: ext.version,
+            revision: ext.revision,
+            instructions: instructions(&ext.instructions, &kind_categories),
+            operand_kinds: json_operand_kinds(ext.operand_kinds.as_deref().unwrap_or_default(), &kind_categories)
+        });
+    }
+
+    JsonGrammar {
+        generator: format!("rustspvgen {}", env!("CARGO_PKG_VERSION")),
+        grammars: sources,
+        magic_number: spv.magic_number.clone().unwrap_or_else(|| "0x07230203".to_string()),
+        version: spv.major_version.unwrap_or_default() << 16 | spv.minor_version.unwrap_or_default() << 8,
+        revision: spv.revision,
+        instructions: instructions(&spv.instructions, &kind_categories),
+        operand_kinds: json_operand_kinds(operand_kinds, &kind_categories),
+        ext_inst_sets
+    }
+}
+
+pub fn emit_json(spv: &Grammar, exts: &[(String, Grammar)], sources: &[Source], out: &mut dyn Write) -> io::Result<()>
+{
+    let grammar = grammar_model(spv, exts, sources);
+    writeln!(out, "{}", serde_json::to_string_pretty(&grammar).expect("grammar should serialize"))?;
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::OperandKinds;
+use crate::display_name;
+use crate::lock;
+use crate::quantifier_name;
+use crate::words;
+
+// WordEncoding, minimum word count and whether that count is exact, composites sum up their bases
+fn operand_encoding(kinds: &[OperandKinds], kind: &OperandKinds) -> io::Result<(&'static str, usize, bool)>
+{
+    let unknown = |what: &str| io::Error::new(io::ErrorKind::InvalidData, format!("no word encoding for {} {}", what, kind.kind));
+    match kind.category.as_str() {
+        "Id" | "ValueEnum" | "BitEnum" => Ok(("Fixed", 1, true)),
+        "Literal" => words::literal_layout(&kind.kind).map(|(encoding, range)| (encoding, range.min as usize, range.max == Some(range.min))),
+        "Composite" => {
+            let (mut words, mut exact) = (0, true);
+            for base in kind.bases.iter().flatten() {
+                let base = kinds.iter().find(|k| &k.kind == base).ok_or_else(|| unknown("a base of"))?;
+                let (_, base_words, base_exact) = operand_encoding(kinds, base)?;
+                words += base_words;
+                exact &= base_exact;
+            }
+            Ok(("Composite", words, exact))
+        }
+        _ => Err(unknown("category of"))
+    }
+}
+
+// constexpr operand tables for consumers that can't use Grammar.h, enabled with SPVGENTWO_OPERAND_LAYOUT.
+// OperandKind, OperandCategory and Quantifier are numbered like their Grammar.h counterparts.
+pub fn operand_layout(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+    // tables indexed by OperandKind, kinds removed from a locked numbering and ext kinds of Grammar.h leave a gap
+    let numbers = lock::numbering(spv, &[]);
+    let by_number: Vec<(u32, Option<&OperandKinds>)> = lock::by_number(&numbers.operand_kinds, 0).into_iter()
+        .map(|(i, kind)| (i, kind.and_then(|kind| operand_kinds.iter().find(|k| k.kind == kind))))
+        .collect();
+
+    writeln!(out, "#ifdef SPVGENTWO_OPERAND_LAYOUT")?;
+
+    writeln!(out, "\tenum class OperandKind : unsigned short\n\t{{")?;
+    for (i, elem) in by_number.iter().filter_map(|(i, elem)| elem.map(|elem| (i, elem))) {
+        writeln!(out, "\t\t{} = {}u,", elem.kind, i)?;
+    }
+    writeln!(out, "\t}};")?;
+
+    // numbered in order of first appearance
+    let mut categories = Vec::new();
+    for elem in operand_kinds {
+        if !categories.contains(&elem.category.as_str()) {
+            categories.push(elem.category.as_str());
+        }
+    }
+    writeln!(out, "\tenum class OperandCategory : unsigned short\n\t{{")?;
+    for category in &categories {
+        writeln!(out, "\t\t{},", category)?;
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tenum class Quantifier : unsigned short\n\t{{")?;
+    writeln!(out, "\t\tZeroOrOne, // zero or one")?;
+    writeln!(out, "\t\tZeroOrAny, // zero or any")?;
+    writeln!(out, "\t\tOne, // exactly once")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tstatic constexpr OperandCategory OperandCategories[] =\n\t{{")?;
+    for (i, elem) in &by_number {
+        match elem {
+            Some(elem) => writeln!(out, "\t\tOperandCategory::{}, // {}", elem.category, elem.kind)?,
+            None => writeln!(out, "\t\tOperandCategory{{}}, // {} unused", i)?
+        }
+    }
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tinline constexpr OperandCategory GetOperandCategory(OperandKind kind) {{ return OperandCategories[static_cast<unsigned short>(kind)]; }}")?;
+
+    writeln!(out, "\tenum class WordEncoding : unsigned char\n\t{{")?;
+    writeln!(out, "\t\tFixed, // wordCount words, enumerant parameters follow separately")?;
+    writeln!(out, "\t\tString, // null-terminated UTF-8 padded to whole words, at least one word")?;
+    writeln!(out, "\t\tContextDependent, // width given by the result type of the instruction, at least one word")?;
+    writeln!(out, "\t\tComposite, // expands to its bases in order")?;
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tstruct OperandEncoding\n\t{{")?;
+    writeln!(out, "\t\tWordEncoding encoding;")?;
+    writeln!(out, "\t\tunsigned short wordCount; // minimum, composites add up their bases")?;
+    writeln!(out, "\t\tbool exact; // wordCount is the exact width, false if a string or context dependent number is involved")?;
+    writeln!(out, "\t\tconst OperandKind* bases; // Composite only")?;
+    writeln!(out, "\t\tunsigned short baseCount;")?;
+    writeln!(out, "\t}};")?;
+
+    let all_bases: Vec<&String> = by_number.iter().filter_map(|(_, k)| *k).flat_map(|k| k.bases.iter().flatten()).collect();
+    writeln!(out, "\tstatic constexpr OperandKind OperandBases[] =\n\t{{")?;
+    for base in &all_bases {
+        writeln!(out, "\t\tOperandKind::{},", base)?;
+    }
+    if all_bases.is_empty() {
+        writeln!(out, "\t\tOperandKind{{}}, // no composite kinds")?;
+    }
+    writeln!(out, "\t}};")?;
+
+    let mut offset = 0;
+    writeln!(out, "\tstatic constexpr OperandEncoding OperandEncodings[] =\n\t{{")?;
+    for (i, elem) in &by_number {
+        let elem = match elem {
+            Some(elem) => elem,
+            None => { writeln!(out, "\t\t{{ WordEncoding::Fixed, 0u, true, nullptr, 0u }}, // {} unused", i)?; continue; }
+        };
+        let (encoding, words, exact) = operand_encoding(operand_kinds, elem)?;
+        match &elem.bases {
+            Some(bases) => {
+                writeln!(out, "\t\t{{ WordEncoding::{}, {}u, {}, OperandBases + {}, {}u }}, // {}", encoding, words, exact, offset, bases.len(), elem.kind)?;
+                offset += bases.len();
+            }
+            None => writeln!(out, "\t\t{{ WordEncoding::{}, {}u, {}, nullptr, 0u }}, // {}", encoding, words, exact, elem.kind)?
+        }
+    }
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tinline constexpr const OperandEncoding& GetOperandEncoding(OperandKind kind) {{ return OperandEncodings[static_cast<unsigned short>(kind)]; }}")?;
+
+    writeln!(out, "\tstruct OperandLayout\n\t{{")?;
+    writeln!(out, "\t\tOperandKind kind;")?;
+    writeln!(out, "\t\tQuantifier quantifier;")?;
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tstruct OperandLayoutSpan\n\t{{")?;
+    writeln!(out, "\t\tconst OperandLayout* operands;")?;
+    writeln!(out, "\t\tunsigned int count;")?;
+    writeln!(out, "\t\tconstexpr const OperandLayout* begin() const {{ return operands; }}")?;
+    writeln!(out, "\t\tconstexpr const OperandLayout* end() const {{ return operands + count; }}")?;
+    writeln!(out, "\t}};")?;
+
+    // all operands of all opcodes in one array, GetOperandLayout points into it
+    let mut opcodes = BTreeSet::new();
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    writeln!(out, "\tstatic constexpr OperandLayout OperandLayouts[] =\n\t{{")?;
+    for instr in &spv.instructions {
+        if !opcodes.insert(instr.opcode) { continue; }
+        let operands = instr.operands.as_deref().unwrap_or_default();
+        if !operands.is_empty() {
+            writeln!(out, "\t\t// {}", instr.opname)?;
+        }
+        for op in operands {
+            writeln!(out, "\t\t{{ OperandKind::{}, Quantifier::{} }},", op.kind, quantifier_name(&op.quantifier))?;
+        }
+        spans.push((&instr.opname, offset, operands.len()));
+        offset += operands.len();
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tinline constexpr OperandLayoutSpan GetOperandLayout(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return {{ OperandLayouts, 0u }}; // unknown opcode")?;
+    for (opname, offset, count) in spans {
+        writeln!(out, "\t\tcase Op::{}: return {{ OperandLayouts + {}, {}u }};", opname, offset, count)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "#endif // SPVGENTWO_OPERAND_LAYOUT")?;
+    Ok(())
+}
+
+// operand kinds, counts and names of an extended instruction set for Glsl.h / OpenCl.h, which don't include Spv.h
+pub fn ext_operands(ext: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut kinds = Vec::new();
+    for op in ext.instructions.iter().flat_map(|i| i.operands.iter().flatten()) {
+        if !kinds.contains(&op.kind.as_str()) {
+            kinds.push(op.kind.as_str());
+        }
+    }
+
+    writeln!(out, "\tinline constexpr const char* ToString(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return nullptr; // unknown opcode")?;
+    for instr in &ext.instructions {
+        writeln!(out, "\t\tcase Op::{0}: return \"{0}\";", instr.opname)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    // numbered in order of first appearance in this grammar
+    writeln!(out, "\tenum class OperandKind : unsigned short\n\t{{")?;
+    for kind in &kinds {
+        writeln!(out, "\t\t{},", kind)?;
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tenum class Quantifier : unsigned short\n\t{{")?;
+    writeln!(out, "\t\tZeroOrOne, // zero or one")?;
+    writeln!(out, "\t\tZeroOrAny, // zero or any")?;
+    writeln!(out, "\t\tOne, // exactly once")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tstruct Operand\n\t{{")?;
+    writeln!(out, "\t\tOperandKind kind;")?;
+    writeln!(out, "\t\tQuantifier quantifier;")?;
+    writeln!(out, "\t\tconst char* name;")?;
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tstruct OperandSpan\n\t{{")?;
+    writeln!(out, "\t\tconst Operand* operands;")?;
+    writeln!(out, "\t\tunsigned int count;")?;
+    writeln!(out, "\t\tconstexpr const Operand* begin() const {{ return operands; }}")?;
+    writeln!(out, "\t\tconstexpr const Operand* end() const {{ return operands + count; }}")?;
+    writeln!(out, "\t}};")?;
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    writeln!(out, "\tstatic constexpr Operand Operands[] =\n\t{{")?;
+    for instr in &ext.instructions {
+        let operands = instr.operands.as_deref().unwrap_or_default();
+        if !operands.is_empty() {
+            writeln!(out, "\t\t// {}", instr.opname)?;
+        }
+        for op in operands {
+            writeln!(out, "\t\t{{ OperandKind::{}, Quantifier::{}, \"{}\" }},", op.kind, quantifier_name(&op.quantifier), display_name(op.name.as_deref().unwrap_or_default()))?;
+        }
+        let fixed = operands.iter().all(|op| op.quantifier.is_none());
+        spans.push((&instr.opname, offset, operands.len(), fixed));
+        offset += operands.len();
+    }
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tinline constexpr OperandSpan GetOperands(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return {{ Operands, 0u }}; // unknown opcode")?;
+    for (opname, offset, count, _) in &spans {
+        writeln!(out, "\t\tcase Op::{}: return {{ Operands + {}, {}u }};", opname, offset, count)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "\t// number of operands listed in the grammar, optional and variadic ones count once")?;
+    writeln!(out, "\tinline constexpr unsigned int OperandCount(Op opcode) {{ return GetOperands(opcode).count; }}")?;
+
+    writeln!(out, "\t// true if every call passes exactly OperandCount(opcode) operands, false for optional or variadic operands and unknown opcodes")?;
+    writeln!(out, "\tinline constexpr bool HasFixedArity(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return false; // unknown opcode")?;
+    for (opname, _, _, fixed) in &spans {
+        if *fixed {
+            writeln!(out, "\t\tcase Op::{}: return true;", opname)?;
+        }
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
@@ -0,0 +1,759 @@
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+extern crate serde_derive;
+extern crate serde_json;
+extern crate serde;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::collections::BTreeSet;
+use std::collections::BTreeMap;
+
+pub mod builder;
+pub mod docs;
+pub mod extensions;
+pub mod flags;
+pub mod info;
+pub mod json;
+pub mod layout;
+pub mod lock;
+pub mod predicates;
+pub mod printer;
+pub mod profile;
+pub mod rust;
+pub mod stats;
+pub mod template;
+pub mod traits;
+pub mod validity;
+pub mod visitor;
+pub mod words;
+mod generator;
+
+pub use generator::Generator;
+pub use profile::Profile;
+
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub enum Extension
+{
+    glslstd450,
+    opencl100
+}
+
+impl Extension
+{
+    // OpExtInstImport name of the instruction set
+    pub fn import_name(&self) -> &'static str
+    {
+        match self { Extension::glslstd450 => "GLSL.std.450", Extension::opencl100 => "OpenCL.std" }
+    }
+
+    // instruction sets with their own --defs header
+    pub fn from_import_name(name: &str) -> Option<Extension>
+    {
+        match name { "GLSL.std.450" => Some(Extension::glslstd450), "OpenCL.std" => Some(Extension::opencl100), _ => None }
+    }
+
+    pub fn header_name(&self) -> &'static str
+    {
+        match self { Extension::glslstd450 => "Glsl.h", Extension::opencl100 => "OpenCl.h" }
+    }
+}
+
+// grammar file, OpExtInstImport name and Grammar::Extension enumerant of the instruction sets in SPIRV-Headers
+const EXT_INST_SETS: &[(&str, &str, &str)] = &[
+    ("glsl.std.450", "GLSL.std.450", "Glsl"),
+    ("opencl.std.100", "OpenCL.std", "OpenCl"),
+    ("opencl.debuginfo.100", "OpenCL.DebugInfo.100", "OpenClDebugInfo100"),
+    ("debuginfo", "DebugInfo", "DebugInfo"),
+    ("nonsemantic.debugprintf", "NonSemantic.DebugPrintf", "NonSemanticDebugPrintf"),
+    ("nonsemantic.debugbreak", "NonSemantic.DebugBreak", "NonSemanticDebugBreak"),
+    ("nonsemantic.shader.debuginfo.100", "NonSemantic.Shader.DebugInfo.100", "NonSemanticShaderDebugInfo100"),
+];
+
+// extinst.*.grammar.json files are extended instruction set grammars
+pub fn is_ext_grammar(path: &str) -> bool
+{
+    Path::new(path).file_name().is_some_and(|f| f.to_string_lossy().starts_with("extinst.") && f.to_string_lossy().ends_with(".grammar.json"))
+}
+
+// import name used by OpExtInstImport for a extinst.*.grammar.json file,
+// extinst.spv-amd-shader-ballot.grammar.json -> SPV_AMD_shader_ballot, unknown sets keep their file name
+pub fn ext_import_name<P: AsRef<Path>>(path: P) -> String
+{
+    let file = path.as_ref().file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+    let set = file.trim_start_matches("extinst.").trim_end_matches(".grammar.json");
+    if let Some((_, name, _)) = EXT_INST_SETS.iter().find(|(file, _, _)| *file == set) {
+        return name.to_string();
+    }
+    match set.strip_prefix("spv-").and_then(|s| s.split_once('-')) {
+        Some((vendor, rest)) => format!("SPV_{}_{}", vendor.to_ascii_uppercase(), rest.replace('-', "_")),
+        None => set.to_string()
+    }
+}
+
+// Grammar::Extension enumerant of an instruction set: GLSL.std.450 -> Glsl, SPV_AMD_gcn_shader -> SpvAmdGcnShader
+fn extension_enumerant(import_name: &str) -> String
+{
+    match EXT_INST_SETS.iter().find(|(_, name, _)| *name == import_name) {
+        Some((_, _, enumerant)) => enumerant.to_string(),
+        None => template::pascal_case(import_name)
+    }
+}
+
+// input grammar file recorded in the provenance block of every generated file
+#[derive(Serialize)]
+pub struct Source
+{
+    pub file: String,
+    pub sha256: String,
+    pub version: String,
+    pub revision: u32
+}
+
+#[derive(Deserialize)]
+pub struct Grammar {
+    pub copyright: Vec<String>,
+    pub magic_number: Option<String>, // spv
+    pub major_version: Option<u32>, // spv
+    pub minor_version: Option<u32>, // spv
+    pub version: Option<u32>, // glsl & opencl
+    pub revision: u32, // both
+    pub instructions: Vec<Instruction>, // both
+    pub operand_kinds: Option<Vec<OperandKinds>>, // spv
+    // set from --lock, not part of the grammar
+    #[serde(skip)]
+    pub lock: Option<lock::Lock>
+}
+
+#[derive(Deserialize)]
+pub struct Instruction
+{
+    pub opname: String,
+    pub class: Option<String>,
+    pub opcode: u32,
+    pub operands: Option<Vec<Operand>>,
+    pub capabilities: Option<Vec<String>>,
+    pub extensions: Option<Vec<String>>,
+    pub version: Option<String>,
+    // set from --control-flow-overrides, not part of the grammar
+    #[serde(skip)]
+    pub terminator: bool,
+    #[serde(skip)]
+    pub successors: Option<Vec<usize>>
+}
+
+#[derive(Deserialize)]
+pub struct Operand
+{
+    pub kind: String,
+    pub quantifier: Option<String>,
+    pub name: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct OperandKinds {
+    pub category: String,
+    pub kind: String,
+    pub enumerants: Option<Vec<Enumerants>>,
+    pub bases: Option<Vec<String>>
+}
+
+#[derive(Deserialize)]
+pub struct Enumerants {
+    pub enumerant: String,
+    pub value: serde_json::Value,
+    pub capabilities: Option<Vec<String>>,
+    pub parameters: Option<Vec<Parameter>>,
+    pub extensions: Option<Vec<String>>,
+    pub version: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct Parameter
+{
+    pub kind: String,
+    pub name: Option<String>
+}
+
+// reads a JSON config file like class_overrides.json, errors name the file
+pub(crate) fn read_json<T: serde::de::DeserializeOwned, P: AsRef<Path>>(path: P) -> io::Result<T>
+{
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    serde_json::from_slice(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+}
+
+// errors name the grammar file
+pub fn load_grammar<P: AsRef<Path>>(path: P, sources: &mut Vec<Source>) -> io::Result<Grammar>
+{
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    let grammar: Grammar = serde_json::from_slice(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+
+    let version = match (grammar.major_version, grammar.minor_version) {
+        (Some(major), Some(minor)) => format!("{}.{}", major, minor),
+        _ => grammar.version.unwrap_or_default().to_string()
+    };
+    // only the file name, the output must not depend on where the grammars are located
+    let file = path.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+    sources.push(Source { file, sha256: format!("{:x}", Sha256::digest(&bytes)), version, revision: grammar.revision });
+
+    Ok(grammar)
+}
+
+// tool version and input grammars, one line each
+pub fn provenance(sources: &[Source]) -> Vec<String>
+{
+    let mut lines = vec![format!("Generated by rustspvgen {}", env!("CARGO_PKG_VERSION"))];
+    for source in sources {
+        lines.push(format!("{} sha256:{} version {} revision {}", source.file, source.sha256, source.version, source.revision));
+    }
+    lines
+}
+
+// C++ style header of every generated file
+pub fn write_header(sources: &[Source], out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "// Auto generated - do not modify")?;
+    for line in provenance(sources) {
+        writeln!(out, "// {}", line)?;
+    }
+    Ok(())
+}
+
+// one instruction per opcode, vendor extension instructions are replaced with their ratified versions
+fn unique_instructions(instructions: &[Instruction]) -> BTreeMap<u32, &Instruction>
+{
+    let mut unique_instructions = BTreeMap::new();
+
+    for instr in instructions
+    {
+        let entry = &unique_instructions.entry(instr.opcode).or_insert(instr);
+        if entry.opname != instr.opname && (instr.opname.ends_with("KHR") || instr.opname.ends_with("EXT")){
+            unique_instructions.insert(instr.opcode, instr);
+        }
+    }
+
+    unique_instructions
+}
+
+// numeric value of an enumerant, BitEnum values are hex strings
+fn enumerant_value(value: &serde_json::Value) -> u32
+{
+    match value
+    {
+        serde_json::Value::Number(x) => x.as_u64().unwrap_or_default() as u32,
+        serde_json::Value::String(s) => u32::from_str_radix(s.trim_start_matches("0x"), 16).unwrap_or_default(),
+        _ => 0
+    }
+}
+
+// "1.3" -> 0x00010300, "None" (not part of any core version) -> 0
+fn parse_version(version: &Option<String>) -> u32
+{
+    match version
+    {
+        Some(s) => {
+            let vec: Vec<&str> = s.split('.').collect();
+            if vec.len() == 2 {
+                let major: u32 = vec[0].parse().unwrap_or_default();
+                let minor: u32 = vec[1].parse().unwrap_or_default();
+                (major << 16) | (minor << 8)
+            }else {0} // fail case
+        },
+        None => 0
+    }
+}
+
+// matches Grammar::Quantifier
+fn quantifier_name(quantifier: &Option<String>) -> &'static str
+{
+    match quantifier.as_deref() { Some("?") => "ZeroOrOne", Some("*") => "ZeroOrAny", _ => "One" }
+}
+
+// grammar names come quoted and sometimes span multiple lines: 'Argument 0', +\n'Argument 1', +\n...
+fn display_name(name: &str) -> String
+{
+    name.replace('\n', " ").replace('\'', "")
+}
+
+// operand name as C++ string literal content
+fn operand_name(op: &Operand) -> String
+{
+    let name = match op.name {Some(ref s) => s, None => if op.kind == "IdResultType" { "ResultType"} else if op.kind == "IdResult" { "Result" } else {""}};
+    name.replace("\n", "")
+}
+
+pub fn spv_defs(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    for line in &spv.copyright {
+        writeln!(out, "// {}", line)?; 
+    }
+
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "namespace spvgentwo::spv\n{{")?;
+
+    writeln!(out, "\tenum class Id : unsigned int;")?;
+    writeln!(out, "\tstatic constexpr unsigned int MagicNumber = {};", spv.magic_number.as_deref().unwrap_or("0x07230203"))?;
+    let version: u32 = spv.major_version.unwrap_or_default() << 16 | spv.minor_version.unwrap_or_default() << 8;
+    writeln!(out, "\tstatic constexpr unsigned int Version = {};", version)?;
+    writeln!(out, "\tstatic constexpr unsigned int Revision = {};", spv.revision)?;
+    writeln!(out, "\tstatic constexpr unsigned int OpCodeMask = 0xffff;")?;
+    writeln!(out, "\tstatic constexpr unsigned int WordCountShift = 16;")?;
+
+    let numbers = lock::numbering(spv, &[]);
+
+    // value enums
+    for op in operand_kinds {
+        if op.category == "ValueEnum" || op.category != "BitEnum" {
+            if let Some(v) = op.enumerants.as_ref() {
+                writeln!(out, "\tenum class {} : unsigned\n\t{{", op.kind)?;
+                for enumval in v {
+                    if op.kind == "Dim" && enumval.enumerant.len() == 2{
+                        write!(out, "\t\tDim{} = ", enumval.enumerant)?;
+                    }else{
+                        write!(out, "\t\t{} = ", enumval.enumerant)?;
+                    }
+                    match &enumval.value
+                    {
+                        serde_json::Value::Number(x) => {writeln!(out, "{},", x)?},
+                        serde_json::Value::String(s) => {writeln!(out, "{},", s)?}
+                        _ => {}
+                    }
+                }
+                writeln!(out, "\t\tMax = 0x7fffffff")?;
+                writeln!(out, "\t}};")?;
+            }
+        }
+        else if op.category == "BitEnum"
+        {
+            if let Some(v) = op.enumerants.as_ref() {
+                writeln!(out, "\tenum class {}Mask : unsigned\n\t{{", op.kind)?;
+                for enumval in v {
+                    if enumval.enumerant == "None"{
+                        write!(out, "\t\tMask{} = ", enumval.enumerant)?;
+                    }else{
+                        write!(out, "\t\t{} = ", enumval.enumerant)?;
+                    }
+                    match &enumval.value
+                    {
+                        serde_json::Value::Number(x) => {writeln!(out, "{},", x)?},
+                        serde_json::Value::String(s) => {writeln!(out, "{},", s)?}
+                        _ => {}
+                    }
+                }
+                writeln!(out, "\t}};")?;
+
+                writeln!(out, "\tenum class {}Shift : unsigned\n\t{{", op.kind)?;
+                for (shift, enumval) in v.iter().enumerate() {
+                    writeln!(out, "\t\t{} = {},", enumval.enumerant, shift)?;
+                }
+                writeln!(out, "\t\tMax = 0x7fffffff")?;
+                writeln!(out, "\t}};")?;
+            }
+        }        
+    }
+
+    // opcode enum
+    writeln!(out, "\tenum class Op : unsigned\n\t{{")?;
+    for instr in &spv.instructions
+    {
+        writeln!(out, "\t\t{} = {},", instr.opname, instr.opcode)?;
+    }
+    writeln!(out, "\t\tMax = 0x7fffffff")?;
+    writeln!(out, "\t}};")?;
+
+    // extensions enum
+    {
+        writeln!(out, "\tenum class Extension : unsigned\n\t{{")?;
+        for (i, ext) in lock::by_number(&numbers.extensions, 0)
+        {
+            if let Some(ext) = ext { writeln!(out, "\t\t{} = {},", ext, i)?; }
+        }
+        writeln!(out, "\t\tMax = 0x7fffffff")?;
+        writeln!(out, "\t}};")?;
+    }
+
+    // extension names array
+    writeln!(out, "\tstatic constexpr const char* ExtensionNames[] =\n\t{{")?;
+    for (_, ext) in lock::by_number(&numbers.extensions, 0)
+    {
+        match ext {
+            Some(ext) => writeln!(out, "\t\t\"{}\",", ext)?,
+            None => writeln!(out, "\t\tnullptr, // removed from the grammar")?
+        }
+    }
+    writeln!(out, "\t}};")?;
+    extensions::extension_info(spv, out)?;
+
+    info::instruction_info(spv, out)?;
+
+    predicates::class_predicates(spv, out)?;
+    predicates::control_flow_predicates(spv, out)?;
+
+    // including the opcode word
+    words::word_count_functions(&spv.instructions, &words::kind_ranges(spv)?, 1, out)?;
+
+    layout::operand_layout(spv, out)?;
+    flags::mask_operators(spv, out)?;
+    validity::validity_checks(spv, out)?;
+
+    writeln!(out, "}} // spvgentwo::spv")?;
+    Ok(())
+}
+
+pub fn grammar_header(spv: &Grammar, exts: &[(String, Grammar)], out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+    let numbers = lock::numbering(spv, exts);
+
+    writeln!(out, "#pragma once\n")?;
+
+    writeln!(out, "#include \"Vector.h\"")?;
+    writeln!(out, "#include \"HashMap.h\"")?;
+    writeln!(out, "#include \"Spv.h\"\n")?;
+
+    writeln!(out, "namespace spvgentwo\n{{")?;
+
+    writeln!(out, "\tclass Grammar\n\t{{")?;
+    writeln!(out, "\t\tpublic:")?;
+        writeln!(out, "\t\tenum class Extension : unsigned short\n\t\t{{")?;
+            writeln!(out, "\t\t\tCore = 0,")?;
+            // numbered in the order the ext grammars are supplied (or as locked), used as the second half of the Hash64 instruction keys
+            for (name, _) in exts {
+                writeln!(out, "\t\t\t{} = {}, // {}", extension_enumerant(name), numbers.ext_inst_sets[name], name)?;
+            }
+            writeln!(out, "\t\t\tUnknown = 0xffff, // import name without grammar")?;
+        writeln!(out, "\t\t}};")?;
+
+        // numbered in order of first appearance
+        let mut categories = BTreeSet::new();
+        writeln!(out, "\t\tenum class OperandCategory : unsigned short\n\t\t{{")?;
+        for elem in operand_kinds.iter().chain(lock::ext_operand_kinds(spv, exts)) {
+            if !categories.contains(&elem.category)
+            {
+                categories.insert(&elem.category);
+                writeln!(out, "\t\t\t{},",elem.category)?;
+            }
+        }
+        writeln!(out, "\t\t}};")?;
+
+        writeln!(out, "\t\tenum class OperandKind : unsigned short\n\t\t{{")?;
+        // ext grammars can define their own kinds, they are numbered after the core ones
+        for (i, kind) in lock::by_number(&numbers.all_operand_kinds(), 0) {
+            if let Some(kind) = kind { writeln!(out, "\t\t\t{}={}u,", kind, i)?; }
+        }
+        writeln!(out, "\t\t}};")?;
+
+        writeln!(out, "\t\tenum class Quantifier\n\t\t{{")?;
+            writeln!(out, "\t\t\tZeroOrOne, // zero or one")?;
+            writeln!(out, "\t\t\tZeroOrAny, // zero or any")?;
+            writeln!(out, "\t\t\tOne, // exactly once")?;
+        writeln!(out, "\t\t}};")?;
+
+        writeln!(out, "\t\tstruct Operand\n\t\t{{")?;
+            writeln!(out, "\t\t\tOperandKind kind;")?;
+            writeln!(out, "\t\t\tOperandCategory category;")?;        
+            writeln!(out, "\t\t\tconst char* name;")?;
+            writeln!(out, "\t\t\tQuantifier quantifier;")?;
+        writeln!(out, "\t\t}};")?;
+
+        writeln!(out, "\t\tstruct Instruction\n\t\t{{")?;
+            writeln!(out, "\t\t\tconst char* name;")?;
+            writeln!(out, "\t\t\tVector<Operand> operands;")?;
+            writeln!(out, "\t\t\tVector<spv::Capability> capabilities;")?;
+            writeln!(out, "\t\t\tVector<spv::Extension> extensions;")?;
+            writeln!(out, "\t\t\tunsigned int version;")?;
+        writeln!(out, "\t\t}};")?;
+
+        writeln!(out, "\t\tGrammar(IAllocator* _pAllocator);")?;
+        writeln!(out, "\t\tconst Instruction* getInfo(unsigned int _opcode, Extension _extension = Extension::Core) const;")?;
+        writeln!(out, "\t\t// OpExtInstImport name like \"GLSL.std.450\" to Extension, Extension::Unknown if no grammar was generated for it")?;
+        writeln!(out, "\t\tstatic Extension getExtensionFromImportName(const char* _importName);")?;
+        writeln!(out, "\t\tconst char* getOperandName(OperandKind _kind, unsigned int _literalValue) const;")?;
+        writeln!(out, "\t\tconst Vector<Operand>* getOperandParameters(OperandKind _kind, unsigned int _literalValue) const;")?;
+        writeln!(out, "\t\tconst Vector<Operand>* getOperandBases(OperandKind _kind) const;")?;
+        writeln!(out, "\t\tstatic bool hasOperandParameters(OperandKind _kind);")?;
+
+    writeln!(out, "\tprivate:")?;
+    writeln!(out, "\t\tHashMap<Hash64, Instruction> m_instructions;")?;
+    writeln!(out, "\t\tHashMap<Hash64, const char*> m_operandNames;")?;
+    writeln!(out, "\t\tHashMap<Hash64, Vector<Operand>> m_operandParameters;")?;
+    writeln!(out, "\t\tHashMap<OperandKind, Vector<Operand>> m_operandBases;")?;
+
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "}} // spvgentwo")?; // namespace
+    Ok(())
+}
+
+// kinds that neither the core nor any ext grammar defines can't be emitted
+fn kind_category<'a>(kind_categories: &HashMap<&String, &'a String>, kind: &String) -> io::Result<&'a String>
+{
+    kind_categories.get(kind).copied().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("unknown operand kind {}", kind)))
+}
+
+fn print_instruction(instr: &Instruction, kind_categories: &std::collections::HashMap<&std::string::String, &std::string::String>, shift: u32, out: &mut dyn Write) -> io::Result<()>
+{
+    let ver = parse_version(&instr.version);
+
+    let has_props = instr.operands.is_some() || instr.capabilities.is_some() || instr.extensions.is_some();
+    if has_props {
+        writeln!(out, "\t{{")?;
+        write!(out, "\t\tauto& instr = ")?;
+    } else {write!(out, "\t")?;}
+
+    write!(out, "m_instructions.emplaceUnique(Hash64({}u, {}u), Instruction{{\"{}\", _pAllocator, _pAllocator, _pAllocator, {}u}})", instr.opcode, shift, instr.opname, ver)?;
+    
+    if has_props {
+        writeln!(out, ".kv.value;")?;
+    } else {writeln!(out, ";")?;}
+    
+    if let Some(ops) = instr.operands.as_ref() {
+        if ops.len() > 1 {
+            writeln!(out, "\t\tinstr.operands.reserve({}u);", ops.len())?;
+        }
+
+        for op in ops {
+            let category = kind_category(kind_categories, &op.kind)?;
+            let quantifier = format!("Quantifier::{}", quantifier_name(&op.quantifier));
+            writeln!(out, "\t\tinstr.operands.emplace_back(OperandKind::{}, OperandCategory::{}, \"{}\", {});", &op.kind, category, operand_name(op), quantifier)?;
+        }
+    }
+
+    if let Some(caps) = instr.capabilities.as_ref() {
+        if caps.len() > 1 {
+            writeln!(out, "\t\tinstr.capabilities.reserve({}u);", caps.len())?;
+        }
+
+        for cap in caps {
+            writeln!(out, "\t\tinstr.capabilities.emplace_back(spv::Capability::{});", cap)?;
+        }
+    }
+
+    if let Some(exts) = instr.extensions.as_ref() {
+        if exts.len() > 1 {
+            writeln!(out, "\t\tinstr.extensions.reserve({}u);", exts.len())?;
+        }
+        for ext in exts {
+            writeln!(out, "\t\tinstr.extensions.emplace_back(spv::Extension::{});", ext)?;
+        }
+    }
+
+    if has_props {
+        writeln!(out, "\t}}")?
+    }
+    Ok(())
+}
+
+pub fn grammar_cpp(spv: &Grammar, exts: &[(String, Grammar)], out: &mut dyn Write) -> io::Result<()>
+{
+    // ext grammars can define their own operand kinds, Grammar.h numbers them after the core ones
+    let operand_kinds: Vec<&OperandKinds> = spv.operand_kinds.iter().flatten().chain(lock::ext_operand_kinds(spv, exts)).collect();
+    let numbers = lock::numbering(spv, exts);
+    let kind_numbers = numbers.all_operand_kinds();
+
+    writeln!(out, "#include \"spvgentwo/Grammar.h\"")?;
+    for ext in exts.iter().filter_map(|(name, _)| Extension::from_import_name(name)) {
+        writeln!(out, "#include \"spvgentwo/{}\"", ext.header_name())?;
+    }
+    writeln!(out, "using namespace spvgentwo;\n")?;
+
+    let mut kind_categories = HashMap::new();
+    for elem in &operand_kinds {
+        kind_categories.insert(&elem.kind, &elem.category);
+    }
+
+    let unique_instructions = unique_instructions(&spv.instructions);
+
+    let mut operand_bases_count = 0;
+    let mut operand_parameter_count = 0;
+    let mut operand_enum_count = 0;
+    for op in &operand_kinds {
+        if op.bases.is_some() {
+            operand_bases_count += 1;
+        }
+        if let Some(v) = op.enumerants.as_ref() {
+            operand_enum_count += v.len();
+
+            for en in v {
+                if en.parameters.is_some() {
+                    operand_parameter_count += 1;
+                }
+            }
+        }
+    }
+
+    writeln!(out, "Grammar::Grammar(IAllocator* _pAllocator) : m_instructions(_pAllocator, {}u), m_operandNames(_pAllocator, {}u), m_operandParameters(_pAllocator, {}u), m_operandBases(_pAllocator, {}u)\n{{", unique_instructions.len() + exts.iter().map(|(_, ext)| ext.instructions.len()).sum::<usize>(), operand_enum_count, operand_parameter_count, operand_bases_count)?;
+    
+    for op in &operand_kinds {
+        if let Some(bases) = op.bases.as_ref() {
+            writeln!(out, "\t{{")?;
+            writeln!(out, "\t\tauto& bases = m_operandBases.emplaceUnique(OperandKind::{}, _pAllocator).kv.value;", &op.kind)?;
+            if bases.len() > 1 {
+                writeln!(out, "\t\tbases.reserve({}u);", bases.len())?;
+            }
+            for b in bases {
+                let category = kind_category(&kind_categories, b)?;
+                writeln!(out, "\t\tbases.emplace_back(OperandKind::{}, OperandCategory::{}, \"\", Quantifier::One);", &b, category)?;
+            }
+            writeln!(out, "\t}}")?;
+        }
+    }
+
+
+    for op in &operand_kinds {
+        let i = kind_numbers[&op.kind];
+        let mut unique_parameters = BTreeMap::new();
+
+        if let Some(v) = op.enumerants.as_ref() {
+            for en in v {
+                if let Some(params) = en.parameters.as_ref() {
+                    let st = serde_json::to_string(&en.value).unwrap_or_default();
+                    if unique_parameters.insert(st, i).is_none() {
+                        writeln!(out, "\t{{")?;
+                        write!(out, "\t\tauto& parameters = m_operandParameters.emplaceUnique(Hash64({}u, ", i)?;
+                        match &en.value {
+                            serde_json::Value::Number(x) => {write!(out, "{}u", x)?},
+                            serde_json::Value::String(s) => {write!(out, "{}", s)?}
+                            _ => {}
+                        }
+                        writeln!(out, "), _pAllocator).kv.value;")?;
+
+                        if params.len() > 1 {
+                            writeln!(out, "\t\tparameters.reserve({}u);", params.len())?;
+                        }
+
+                        for p in params
+                        {
+                            let category = kind_category(&kind_categories, &p.kind)?;
+                            let name = match p.name {Some(ref s) => s, None => {""}};
+                            writeln!(out, "\t\tparameters.emplace_back(OperandKind::{}, OperandCategory::{}, \"{}\", Quantifier::One);", &p.kind, category, name.replace("\n", ""))?;
+                        }
+                        writeln!(out, "\t}}")?;
+                    }
+                }
+            }
+        }
+    }
+
+    for instr in unique_instructions.values()
+    {
+        print_instruction(instr, &kind_categories, 0, out)?;
+    }
+
+    for (name, ext) in exts
+    {
+        for instr in &ext.instructions
+        {
+            print_instruction(instr, &kind_categories, numbers.ext_inst_sets[name], out)?;
+        }
+    }
+
+    // operand name lookup tables
+    for op in &operand_kinds {
+        let i = kind_numbers[&op.kind];
+        if op.category == "ValueEnum" || op.category == "BitEnum" {
+            if let Some(v) = op.enumerants.as_ref() {
+                for enumval in v {
+                    write!(out, "\tm_operandNames.emplaceUnique(Hash64({}u, ", i)?;
+                    match &enumval.value
+                    {
+                        serde_json::Value::Number(x) => {write!(out, "{}u), ", x)?},
+                        serde_json::Value::String(s) => {write!(out, "{}u), ", s)?}
+                        _ => {}
+                    }
+                    if op.kind == "Dim" && enumval.enumerant.len() == 2{
+                        writeln!(out, "\"Dim{}\");", enumval.enumerant)?;
+                    }else{
+                        writeln!(out, "\"{}\");", enumval.enumerant)?;
+                    }
+                }
+            }
+        }
+    }
+
+    writeln!(out, "}}")?; // constructor
+
+    writeln!(out, "const Grammar::Instruction* Grammar::getInfo(unsigned int _opcode, Extension _extension) const\n{{")?;
+        writeln!(out, "\treturn m_instructions.get(Hash64(_opcode, static_cast<unsigned int>(_extension)));")?;
+    writeln!(out, "}}")?; // getInfo
+
+    writeln!(out, "Grammar::Extension Grammar::getExtensionFromImportName(const char* _importName)\n{{")?;
+        writeln!(out, "\tif (_importName == nullptr) return Extension::Unknown;")?;
+        writeln!(out, "\tauto equals = [_importName](const char* _name) {{ const char* n = _importName; while (*n != '\\0' && *n == *_name) {{ ++n; ++_name; }} return *n == *_name; }};")?;
+        for (name, _) in exts {
+            writeln!(out, "\tif (equals(\"{}\")) return Extension::{};", name, extension_enumerant(name))?;
+        }
+        writeln!(out, "\treturn Extension::Unknown;")?;
+    writeln!(out, "}}")?; // getExtensionFromImportName
+
+    writeln!(out, "const char* Grammar::getOperandName(OperandKind _kind, unsigned int _literalValue) const\n{{")?;
+        writeln!(out, "\tconst char** name = m_operandNames.get(Hash64(static_cast<unsigned int>(_kind), _literalValue));")?;
+        writeln!(out, "\treturn name == nullptr ? nullptr : *name;")?;
+    writeln!(out, "}}")?; // getOperandName
+
+    writeln!(out, "const Vector<Grammar::Operand>* Grammar::getOperandParameters(OperandKind _kind, unsigned int _literalValue) const\n{{")?;
+        writeln!(out, "\treturn m_operandParameters.get(Hash64(static_cast<unsigned int>(_kind), _literalValue));")?;
+    writeln!(out, "}}")?; // getOperandParameters
+
+    writeln!(out, "const Vector<Grammar::Operand>* Grammar::getOperandBases(OperandKind _kind) const\n{{")?;
+        writeln!(out, "\treturn m_operandBases.get(_kind);")?;
+    writeln!(out, "}}")?; // getOperandParameters
+
+    writeln!(out, "bool Grammar::hasOperandParameters(OperandKind _kind)\n{{")?;
+    writeln!(out, "\tswitch (_kind) {{")?;
+    writeln!(out, "\tdefault: return false; // majority of operand kinds don't have parameters")?;
+    for op in &operand_kinds {
+        if let Some(v) = op.enumerants.as_ref() {
+            for en in v {
+                if en.parameters.is_some() {
+                    writeln!(out, "\tcase OperandKind::{}: return true;", op.kind)?;
+                    break;
+                }
+            }
+        }
+    }
+    writeln!(out, "\t}};")?;
+    writeln!(out, "}}")?; // hasOperandParameters
+    Ok(())
+}
+
+pub fn ext_defs(spv: &Grammar, ext: Extension, out: &mut dyn Write) -> io::Result<()>
+{
+    for line in &spv.copyright {
+        writeln!(out, "// {}", line)?; 
+    }
+
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "namespace spvgentwo::{:?}\n{{", ext)?;
+
+    writeln!(out, "\tstatic constexpr unsigned int Version = {};", spv.version.unwrap_or_default())?;
+    writeln!(out, "\tstatic constexpr unsigned int Revision = {};", spv.revision)?;
+    writeln!(out, "\tstatic constexpr const char* ExtInstImportName = \"{}\";", ext.import_name())?;
+
+    writeln!(out, "\tenum class Op : unsigned\n\t{{")?;
+    for instr in &spv.instructions
+    {
+        writeln!(out, "\t\t{} = {},", instr.opname, instr.opcode)?;
+    }
+    writeln!(out, "\t\tMax = 0x7fffffff")?;
+    writeln!(out, "\t}};")?;
+
+    // OpExtInst payload, the operands following the instruction number
+    words::word_count_functions(&spv.instructions, &words::kind_ranges(spv)?, 0, out)?;
+
+    layout::ext_operands(spv, out)?;
+
+    writeln!(out, "}} // spvgentwo::{:?}", ext)?;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::Grammar;
+use crate::OperandKinds;
+use crate::read_json;
+
+/// Numbers assigned to OperandKind, spv::Extension and Grammar::Extension enumerants. Once a name got a number it
+/// keeps it, new names are appended after the highest number and numbers of removed names are never reused.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct Lock
+{
+    #[serde(default)]
+    pub operand_kinds: BTreeMap<String, u32>,
+    /// kinds only defined by ext grammars (like DebugInfoFlags), they share the number space of operand_kinds
+    #[serde(default)]
+    pub ext_operand_kinds: BTreeMap<String, u32>,
+    #[serde(default)]
+    pub extensions: BTreeMap<String, u32>,
+    /// import names of extended instruction sets, Grammar::Extension::Core is always 0
+    #[serde(default)]
+    pub ext_inst_sets: BTreeMap<String, u32>
+}
+
+// SPV_* extensions used by instructions and enumerants, spv::Extension is sorted by name
+pub(crate) fn grammar_extensions(spv: &Grammar) -> BTreeSet<&String>
+{
+    let mut extensions = BTreeSet::new();
+    for op in spv.operand_kinds.iter().flatten() {
+        for en in op.enumerants.iter().flatten() {
+            extensions.extend(en.extensions.iter().flatten());
+        }
+    }
+    for instr in &spv.instructions {
+        extensions.extend(instr.extensions.iter().flatten());
+    }
+    extensions
+}
+
+// operand kinds defined by ext grammars that the core grammar doesn't have, in order of first appearance
+pub(crate) fn ext_operand_kinds<'a>(spv: &Grammar, exts: &'a [(String, Grammar)]) -> Vec<&'a OperandKinds>
+{
+    let mut kinds: Vec<&OperandKinds> = Vec::new();
+    for kind in exts.iter().flat_map(|(_, ext)| ext.operand_kinds.iter().flatten()) {
+        let core = spv.operand_kinds.iter().flatten().any(|k| k.kind == kind.kind);
+        if !core && !kinds.iter().any(|k| k.kind == kind.kind) {
+            kinds.push(kind);
+        }
+    }
+    kinds
+}
+
+// first is the lowest number a new name may get
+fn append<'a>(numbers: &mut BTreeMap<String, u32>, names: impl Iterator<Item = &'a String>, first: u32)
+{
+    for name in names {
+        if !numbers.contains_key(name) {
+            let next = numbers.values().max().map_or(first, |max| first.max(max + 1));
+            numbers.insert(name.clone(), next);
+        }
+    }
+}
+
+// name to number ordered by number, numbers without name (removed entries) are None
+pub(crate) fn by_number(numbers: &BTreeMap<String, u32>, first: u32) -> Vec<(u32, Option<&str>)>
+{
+    let names: BTreeMap<u32, &str> = numbers.iter().map(|(name, number)| (*number, name.as_str())).collect();
+    let last = names.keys().next_back().copied().map_or(first, |max| max + 1);
+    (first..last).map(|number| (number, names.get(&number).copied())).collect()
+}
+
+/// Numbering used by the emitters: the lock file set on the core grammar (if any) with new names appended and
+/// names the grammars don't have anymore removed, their numbers become gaps.
+pub fn numbering(spv: &Grammar, exts: &[(String, Grammar)]) -> Lock
+{
+    let mut lock = spv.lock.clone().unwrap_or_default().update(spv, exts);
+    let kinds: BTreeSet<&String> = spv.operand_kinds.iter().flatten().map(|k| &k.kind).collect();
+    let extensions = grammar_extensions(spv);
+    let ext_kinds: BTreeSet<&String> = ext_operand_kinds(spv, exts).into_iter().map(|k| &k.kind).collect();
+    lock.operand_kinds.retain(|k, _| kinds.contains(k));
+    lock.ext_operand_kinds.retain(|k, _| ext_kinds.contains(k));
+    lock.extensions.retain(|e, _| extensions.contains(e));
+    lock.ext_inst_sets.retain(|s, _| exts.iter().any(|(name, _)| name == s));
+    lock
+}
+
+impl Lock
+{
+    /// A missing file is an empty lock, it is created by the first `save`
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Lock>
+    {
+        if !path.as_ref().exists() {
+            return Ok(Lock::default());
+        }
+        read_json(path)
+    }
+
+    /// Appends the names that aren't locked yet: operand kinds in grammar order, extensions by name
+    /// and instruction sets in the order they were supplied
+    pub fn update(&self, spv: &Grammar, exts: &[(String, Grammar)]) -> Lock
+    {
+        let mut lock = self.clone();
+        let after = |numbers: &BTreeMap<String, u32>| numbers.values().max().map_or(0, |max| max + 1);
+        let first = after(&lock.ext_operand_kinds);
+        append(&mut lock.operand_kinds, spv.operand_kinds.iter().flatten().map(|k| &k.kind), first);
+        let first = after(&lock.operand_kinds);
+        append(&mut lock.ext_operand_kinds, ext_operand_kinds(spv, exts).into_iter().map(|k| &k.kind), first);
+        append(&mut lock.extensions, grammar_extensions(spv).into_iter(), 0);
+        append(&mut lock.ext_inst_sets, exts.iter().map(|(name, _)| name), 1);
+        lock
+    }
+
+    /// core and ext operand kinds, Grammar::OperandKind has both
+    pub fn all_operand_kinds(&self) -> BTreeMap<String, u32>
+    {
+        self.operand_kinds.iter().chain(self.ext_operand_kinds.iter()).map(|(k, n)| (k.clone(), *n)).collect()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()>
+    {
+        let json = serde_json::to_string_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Locked names the grammars don't have anymore, their numbers stay reserved.
+    /// Instruction sets and their operand kinds are only checked if ext grammars were supplied.
+    pub fn removed(&self, spv: &Grammar, exts: &[(String, Grammar)]) -> Vec<String>
+    {
+        let kinds: BTreeSet<&String> = spv.operand_kinds.iter().flatten().map(|k| &k.kind).collect();
+        let extensions = grammar_extensions(spv);
+
+        let mut removed = Vec::new();
+        removed.extend(self.operand_kinds.keys().filter(|k| !kinds.contains(k)).map(|k| format!("operand kind {}", k)));
+        removed.extend(self.extensions.keys().filter(|e| !extensions.contains(e)).map(|e| format!("extension {}", e)));
+        if !exts.is_empty() {
+            let ext_kinds: BTreeSet<&String> = ext_operand_kinds(spv, exts).into_iter().map(|k| &k.kind).collect();
+            removed.extend(self.ext_operand_kinds.keys().filter(|k| !ext_kinds.contains(k)).map(|k| format!("operand kind {}", k)));
+            removed.extend(self.ext_inst_sets.keys().filter(|s| !exts.iter().any(|(name, _)| name == *s)).map(|s| format!("instruction set {}", s)));
+        }
+        removed
+    }
+}
@@ -1,766 +1,209 @@
-use std::fs::File;
 use std::env;
-
-extern crate serde_derive;
-extern crate serde_json;
-extern crate serde;
-
-use serde_derive::Deserialize;
-use std::collections::HashSet;
-use std::collections::HashMap;
-use std::collections::BTreeSet;
-use std::collections::BTreeMap;
-
-#[derive(Debug)]
-enum Extension
-{
-    glslstd450,
-    opencl100
-}
-
-#[derive(Deserialize)]
-struct Grammar {
-    copyright: Vec<String>,
-    magic_number: Option<String>, // spv
-    major_version: Option<u32>, // spv
-    minor_version: Option<u32>, // spv
-    version: Option<u32>, // glsl & opencl
-    revision: u32, // both
-    instructions: Vec<Instruction>, // both
-    operand_kinds: Option<Vec<OperandKinds>> // spv
-}
-
-#[derive(Deserialize)]
-struct Instruction
-{
-    opname: String,
-    class: Option<String>,
-    opcode: u32,
-    operands: Option<Vec<Operand>>,
-    capabilities: Option<Vec<String>>,
-    extensions: Option<Vec<String>>,
-    version: Option<String>
-}
-
-#[derive(Deserialize)]
-struct Operand
-{
-    kind: String,
-    quantifier: Option<String>,
-    name: Option<String>
-}
-
-#[derive(Deserialize)]
-struct OperandKinds {
-    category: String,
-    kind: String,
-    enumerants: Option<Vec<Enumerants>>,
-    bases: Option<Vec<String>>
+use std::fs;
+use std::io;
+use std::io::BufWriter;
+use std::io::Write;
+use std::process;
+
+use rustspvgen::Extension;
+use rustspvgen::Grammar;
+use rustspvgen::Profile;
+use rustspvgen::Source;
+use rustspvgen::builder;
+use rustspvgen::docs;
+use rustspvgen::ext_defs;
+use rustspvgen::ext_import_name;
+use rustspvgen::grammar_cpp;
+use rustspvgen::grammar_header;
+use rustspvgen::is_ext_grammar;
+use rustspvgen::json;
+use rustspvgen::load_grammar;
+use rustspvgen::lock::Lock;
+use rustspvgen::predicates;
+use rustspvgen::printer;
+use rustspvgen::profile;
+use rustspvgen::provenance;
+use rustspvgen::rust;
+use rustspvgen::spv_defs;
+use rustspvgen::stats;
+use rustspvgen::template;
+use rustspvgen::traits;
+use rustspvgen::visitor;
+use rustspvgen::write_header;
+
+fn exit_with(error: io::Error) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
 }
 
-#[derive(Deserialize)]
-struct Enumerants {
-    enumerant: String,
-    value: serde_json::Value,
-    capabilities: Option<Vec<String>>,
-    parameters: Option<Vec<Parameter>>,
-    extensions: Option<Vec<String>>,
-    version: Option<String>
-}
-
-#[derive(Deserialize)]
-struct Parameter
-{
-    kind: String,
-    name: Option<String>
-}
-
-fn spv_defs(spv: Grammar)
-{
-    let operand_kinds = spv.operand_kinds.unwrap_or(Vec::new());
-
-    for line in spv.copyright {
-        println!("// {}", line); 
-    }
-
-    println!("#pragma once\n");
-    println!("namespace spvgentwo::spv\n{{");
-
-    println!("\tenum class Id : unsigned int;");
-    println!("\tstatic constexpr unsigned int MagicNumber = {};", spv.magic_number.unwrap_or("0x07230203".to_string()));
-    let version: u32 = spv.major_version.unwrap_or_default() << 16 | spv.minor_version.unwrap_or_default() << 8;
-    println!("\tstatic constexpr unsigned int Version = {};", version);
-    println!("\tstatic constexpr unsigned int Revision = {};", spv.revision);
-    println!("\tstatic constexpr unsigned int OpCodeMask = 0xffff;");
-    println!("\tstatic constexpr unsigned int WordCountShift = 16;");
-
-    let mut extensions = BTreeSet::new();
-
-    // scann for extensions
-    for op in &operand_kinds {
-        match op.enumerants.as_ref()  {
-            Some(v) => {
-                for enumval in v {                    
-                    for ext in enumval.extensions.as_ref() {
-                        for e in ext {
-                            if extensions.contains(e) == false{
-                                extensions.insert(e);
-                            }
-                        }
-                    }
-                } 
-            },
-            None => {}
-        }  
-    }
+fn main() -> io::Result<()> {
+    let mut args = env::args();
 
-    // value enums
-    for op in &operand_kinds {
-        if op.category == "ValueEnum" || op.category != "BitEnum" {
-            match op.enumerants.as_ref()  {
-                Some(v) => {
-                    println!("\tenum class {} : unsigned\n\t{{", op.kind);
-                    for enumval in v {
-                        if op.kind == "Dim" && enumval.enumerant.len() == 2{
-                            print!("\t\tDim{} = ", enumval.enumerant);
-                        }else{
-                            print!("\t\t{} = ", enumval.enumerant);
-                        }
-                        match &enumval.value
-                        {
-                            serde_json::Value::Number(x) => {print!("{},\n", x)},
-                            serde_json::Value::String(s) => {print!("{},\n", s)}
-                            _ => {}
-                        }
-                    }
-                    println!("\t\tMax = 0x7fffffff");
-                    println!("\t}};");    
-                },
-                None => {}
-            }  
-        }
-        else if op.category == "BitEnum"
-        {
-            match op.enumerants.as_ref()  {
-                Some(v) => {
-                    println!("\tenum class {}Mask : unsigned\n\t{{", op.kind);
-                    for enumval in v {
-                        if enumval.enumerant == "None"{
-                            print!("\t\tMask{} = ", enumval.enumerant);
-                        }else{
-                            print!("\t\t{} = ", enumval.enumerant);
-                        }
-                        match &enumval.value
-                        {
-                            serde_json::Value::Number(x) => {print!("{},\n", x)},
-                            serde_json::Value::String(s) => {print!("{},\n", s)}
-                            _ => {}
-                        }
-                    }
-                    println!("\t}};");
-
-                    println!("\tenum class {}Shift : unsigned\n\t{{", op.kind);
-                    let mut shift = 0;
-                    for enumval in v {
-                        println!("\t\t{} = {},", enumval.enumerant, shift); shift += 1
-                    }
-                    println!("\t\tMax = 0x7fffffff");
-                    println!("\t}};");   
-                },
-                None => {}
-            }
-        }        
-    }
+    let mut spv: Option<Grammar> = None;
+    let mut exts: Vec<(String, Grammar)> = Vec::new();
+    let mut sources: Vec<Source> = Vec::new();
 
-    // opcode enum
-    println!("\tenum class Op : unsigned\n\t{{");
-    for instr in &spv.instructions
+    let mut defs = false;
+    let mut header = false;
+    let mut cpp = false;
+    let mut visitor = false;
+    let mut builder = false;
+    let mut printer = false;
+    let mut traits = false;
+    let mut rust = false;
+    let mut doc_format: Option<docs::DocFormat> = None;
+    let mut emit: Option<String> = None;
+    let mut template: Option<String> = None;
+    let mut profile = Profile::default();
+    let mut class_overrides = None;
+    let mut control_flow_overrides = None;
+    let mut lock: Option<String> = None;
+    let mut stats = false;
+    let mut json = false;
+    let mut module: Option<String> = None;
+
+    while let Some(arg) = args.next()
     {
-        println!("\t\t{} = {},", instr.opname, instr.opcode);
-
-        for ext in instr.extensions.as_ref(){
-            for e in ext{
-                if extensions.contains(e) == false{
-                    extensions.insert(e);
-                }
+        if arg.ends_with("spirv.core.grammar.json") {
+            spv = Some(load_grammar(&arg, &mut sources).unwrap_or_else(|e| exit_with(e)));
+        } else if is_ext_grammar(&arg) {
+            exts.push((ext_import_name(&arg), load_grammar(&arg, &mut sources).unwrap_or_else(|e| exit_with(e))));
+        } else if arg == "--defs" {
+            defs = true;
+        } else if arg == "--header" {
+            header = true;
+        } else if arg == "--cpp" {
+            cpp = true;
+        } else if arg == "--visitor" {
+            visitor = true;
+        } else if arg == "--builder" {
+            builder = true;
+        } else if arg == "--printer" {
+            printer = true;
+        } else if arg == "--traits" {
+            traits = true;
+        } else if arg == "--rust" {
+            rust = true;
+        } else if arg == "--markdown" {
+            doc_format = Some(docs::DocFormat::Markdown);
+        } else if arg == "--html" {
+            doc_format = Some(docs::DocFormat::Html);
+        } else if arg == "--emit" {
+            emit = args.next();
+        } else if arg == "--template" {
+            template = args.next();
+        } else if arg == "--max-version" {
+            let version = args.next().unwrap_or_default();
+            profile.max_version = Profile::parse_version(&version);
+            if profile.max_version.is_none() {
+                eprintln!("invalid --max-version {}, expected major.minor like 1.3", version);
+                process::exit(1);
             }
+        } else if arg == "--capabilities" {
+            profile.capabilities = args.next().map(|list| profile::parse_list(&list));
+        } else if arg == "--extensions" {
+            profile.extensions = args.next().map(|list| profile::parse_list(&list));
+        } else if arg == "--include-vendors" {
+            profile.include_vendors = args.next().map(|list| profile::parse_list(&list));
+        } else if arg == "--exclude-vendors" {
+            profile.exclude_vendors = args.next().map(|list| profile::parse_list(&list));
+        } else if arg == "--class-overrides" {
+            class_overrides = args.next().map(|path| predicates::load_class_overrides(path).unwrap_or_else(|e| exit_with(e)));
+        } else if arg == "--control-flow-overrides" {
+            control_flow_overrides = args.next().map(|path| predicates::load_control_flow_overrides(path).unwrap_or_else(|e| exit_with(e)));
+        } else if arg == "--lock" {
+            lock = args.next();
+        } else if arg == "stats" {
+            stats = true;
+        } else if arg == "--json" {
+            json = true;
+        } else if arg.ends_with(".spv") {
+            module = Some(arg);
         }
     }
-    println!("\t\tMax = 0x7fffffff");
-    println!("\t}};");
 
-    // extensions enum
-    {
-        println!("\tenum class Extension : unsigned\n\t{{");
-        let mut i = 0;
-        for ext in &extensions
-        {
-            println!("\t\t{} = {},", ext, i); i += 1;
-        }
-        println!("\t\tMax = 0x7fffffff");
-        println!("\t}};");
-    }
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
 
-    // extension names array
-    println!("\tstatic constexpr const char* ExtensionNames[] =\n\t{{");
-    for ext in &extensions
-    {
-        println!("\t\t\"{}\",", ext);
-    }
-    println!("\t}};");
-
-    // HasResultAndType
-    {
-        println!("\tinline void HasResultAndType(Op opcode, bool *hasResult, bool *hasResultType) {{");
-        println!("\t\t*hasResult = *hasResultType = false;");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: /* unknown opcode */ break;");
-
-        let mut opcodes = HashSet::new();
-        for instr in &spv.instructions
-        {
-            if opcodes.contains(&instr.opcode) { continue; }
-            opcodes.insert(instr.opcode);
-
-            match &instr.operands
-            {
-                Some(ops) =>
-                {
-                    let mut res_type = false;
-                    let mut res = false;
-                    for operand in ops {
-                        if operand.kind == "IdResultType" { res_type = true;}
-                        if operand.kind == "IdResult" { res = true;}
-                        if res && res_type {break;}
-                    }
-                    if res || res_type {
-                        println!("\t\tcase Op::{}: *hasResult = {}; *hasResultType = {}; break;", instr.opname, res, res_type);
-                    }
-                }
-                None => {}
-            }            
+    if stats {
+        match (&spv, &module) {
+            (Some(spv), Some(module)) => if let Err(e) = stats::stats(spv, module, json, &mut out) { exit_with(e) },
+            _ => exit_with(io::Error::new(io::ErrorKind::InvalidInput, "stats needs spirv.core.grammar.json and a .spv module"))
         }
-
-        println!("\t\t}}");
-        println!("\t}}");
+        return out.flush();
     }
 
-    // HasResult
-    {
-        println!("\tinline constexpr bool HasResult(Op opcode) {{");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: return true; // majority of instructions has a result");
-
-        let mut opcodes = HashSet::new();        
-
-        for instr in &spv.instructions
-        {
-            if opcodes.contains(&instr.opcode) { continue; }
-            opcodes.insert(instr.opcode);
-            match &instr.operands
-            {
-                Some(ops) => {    
-                    let mut res = false;                
-                    for operand in ops {
-                        if operand.kind == "IdResult" {res = true; break;}
-                    }
-                    if res == false {println!("\t\tcase Op::{}: return false;", instr.opname);  }
-                }
-                None => { println!("\t\tcase Op::{}: return false;", instr.opname);  }
-            } 
-                   
-        }
-
-        println!("\t\t}}");
-        println!("\t}}");
-    }
-
-    // HasResultType
-    {
-        println!("\tinline constexpr bool HasResultType(Op opcode) {{");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: return true; // majority of instructions has a result type");
-
-        let mut opcodes = HashSet::new();        
-
-        for instr in &spv.instructions
-        {
-            if opcodes.contains(&instr.opcode) { continue; }
-            opcodes.insert(instr.opcode);
-            match &instr.operands {
-                Some(ops) => {
-                    let mut res = false;
-                    for operand in ops {
-                        if operand.kind == "IdResultType" { res = true; break;}
-                    }
-                    if res == false {
-                        println!("\t\tcase Op::{}: return false;", instr.opname);
-                    }
-                }
-                None => {println!("\t\tcase Op::{}: return false;", instr.opname);}
-            }            
-        }
-
-        println!("\t\t}}");
-        println!("\t}}");
-    }
-
-    // IsTypeOp
-    {
-        println!("\tinline constexpr bool IsTypeOp(Op opcode) {{");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: return false; // majority of instructions are not types");
-
-        let mut opcodes = HashSet::new();        
-
-        for instr in &spv.instructions
-        {
-            if instr.opname.starts_with("OpType") {
-                if opcodes.contains(&instr.opcode) { continue; }
-                opcodes.insert(instr.opcode);
-                println!("\t\tcase Op::{}: return true;", instr.opname);
-            }
-        }
-
-        println!("\t\t}}");
-        println!("\t}}");
+    if let (Some(spv), Some(overrides)) = (spv.as_mut(), &class_overrides) {
+        predicates::apply_class_overrides(spv, overrides);
     }
-
-    // IsConstantOp
-    {
-        println!("\tinline constexpr bool IsConstantOp(Op opcode) {{");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: return false; // majority of instructions are not constants");
-
-        let mut opcodes = HashSet::new();        
-
-        for instr in &spv.instructions
-        {
-            if instr.opname.starts_with("OpConstant") {
-                if opcodes.contains(&instr.opcode) { continue; }
-                opcodes.insert(instr.opcode);
-                println!("\t\tcase Op::{}: return true;", instr.opname);
-            }
-        }
-
-        println!("\t\t}}");
-        println!("\t}}");
+    if let (Some(spv), Some(overrides)) = (spv.as_mut(), &control_flow_overrides) {
+        predicates::apply_control_flow_overrides(spv, overrides);
     }
+    profile.apply(&mut spv.iter_mut().chain(exts.iter_mut().map(|(_, ext)| ext)).collect::<Vec<_>>());
 
-    // IsSpecConstantOp
-    {
-        println!("\tinline constexpr bool IsSpecConstantOp(Op opcode) {{");
-        println!("\t\tswitch (opcode) {{");
-        println!("\t\tdefault: return false; // majority of instructions are not spec constants");
-
-        let mut opcodes = HashSet::new();        
-
-        for instr in &spv.instructions
-        {
-            if instr.opname.starts_with("OpSpecConstant") {
-                if opcodes.contains(&instr.opcode) { continue; }
-                opcodes.insert(instr.opcode);
-                println!("\t\tcase Op::{}: return true;", instr.opname);
-            }
+    // pinned OperandKind and Extension numbers, new names are appended and written back
+    if let (Some(spv), Some(path)) = (spv.as_mut(), &lock) {
+        let locked = Lock::load(path).unwrap_or_else(|e| exit_with(e));
+        for removed in locked.removed(spv, &exts) {
+            eprintln!("warning: {} is locked in {} but missing from the grammar, its number stays reserved", removed, path);
         }
-
-        println!("\t\t}}");
-        println!("\t}}");
+        let updated = locked.update(spv, &exts);
+        if updated != locked { updated.save(path)?; }
+        spv.lock = Some(locked);
     }
 
-    println!("}} // spvgentwo::spv");
-}
-
-fn grammar_header(spv: Grammar)
-{
-    let operand_kinds = spv.operand_kinds.unwrap_or(Vec::new());
-
-    println!("#pragma once\n");
-
-    println!("#include \"Vector.h\"");
-    println!("#include \"HashMap.h\"");
-    println!("#include \"Spv.h\"\n");
-
-    println!("namespace spvgentwo\n{{");
-
-    println!("\tclass Grammar\n\t{{");
-    println!("\t\tpublic:");
-        println!("\t\tenum class Extension : unsigned short\n\t\t{{");
-            println!("\t\t\tCore = 0,");
-            println!("\t\t\tGlsl = 1,");
-            println!("\t\t\tOpenCl = 2,");  
-        println!("\t\t}};");
-
-        let mut categories = HashSet::new();
-        println!("\t\tenum class OperandCategory : unsigned short\n\t\t{{");
-        for elem in &operand_kinds {
-            if !categories.contains(&elem.category)
-            {
-                categories.insert(&elem.category);
-                println!("\t\t\t{},",elem.category);
-            }
-        }
-        println!("\t\t}};");
-
-        println!("\t\tenum class OperandKind : unsigned short\n\t\t{{");
-        for (i, elem) in operand_kinds.iter().enumerate() {
-            println!("\t\t\t{}={}u,",elem.kind, i);
-        }
-        println!("\t\t}};");
-
-        println!("\t\tenum class Quantifier\n\t\t{{");
-            println!("\t\t\tZeroOrOne, // zero or one");
-            println!("\t\t\tZeroOrAny, // zero or any");
-            println!("\t\t\tOne, // exactly once");
-        println!("\t\t}};");
-
-        println!("\t\tstruct Operand\n\t\t{{");
-            println!("\t\t\tOperandKind kind;");
-            println!("\t\t\tOperandCategory category;");        
-            println!("\t\t\tconst char* name;");
-            println!("\t\t\tQuantifier quantifier;");
-        println!("\t\t}};");
-
-        println!("\t\tstruct Instruction\n\t\t{{");
-            println!("\t\t\tconst char* name;");
-            println!("\t\t\tVector<Operand> operands;");
-            println!("\t\t\tVector<spv::Capability> capabilities;");
-            println!("\t\t\tVector<spv::Extension> extensions;");
-            println!("\t\t\tunsigned int version;");
-        println!("\t\t}};");
-
-        println!("\t\tGrammar(IAllocator* _pAllocator);");
-        println!("\t\tconst Instruction* getInfo(unsigned int _opcode, Extension _extension = Extension::Core) const;");
-        println!("\t\tconst char* getOperandName(OperandKind _kind, unsigned int _literalValue) const;");
-        println!("\t\tconst Vector<Operand>* getOperandParameters(OperandKind _kind, unsigned int _literalValue) const;");
-        println!("\t\tconst Vector<Operand>* getOperandBases(OperandKind _kind) const;");
-        println!("\t\tstatic bool hasOperandParameters(OperandKind _kind);");
-
-    println!("\tprivate:");
-    println!("\t\tHashMap<Hash64, Instruction> m_instructions;");
-    println!("\t\tHashMap<Hash64, const char*> m_operandNames;");
-    println!("\t\tHashMap<Hash64, Vector<Operand>> m_operandParameters;");
-    println!("\t\tHashMap<OperandKind, Vector<Operand>> m_operandBases;");
-
-    println!("\t}};");
-
-    println!("}} // spvgentwo"); // namespace
-}
-
-fn print_instruction(instr: &Instruction, kind_categories: &std::collections::HashMap<&std::string::String, &std::string::String>, shift: u32)
-{
-    let ver: u32 = match &instr.version
-    {
-        Some(s) => {
-            let vec: Vec<&str> = s.split(".").collect();
-            if vec.len() == 2 {
-                let major: u32 = vec.first().unwrap().to_string().parse().unwrap();
-                let minor: u32 = vec.last().unwrap().to_string().parse().unwrap();
-                (major << 16) | (minor << 8)
-            }else {0} // fail case
-        },
-        None => 0
-    };
-
-    let has_props = instr.operands.is_some() || instr.capabilities.is_some() || instr.extensions.is_some();
-    if has_props {
-        println!("\t{{");
-        print!("\t\tauto& instr = ");
-    } else {print!("\t");}
-
-    print!("m_instructions.emplaceUnique(Hash64({}u, {}u), Instruction{{\"{}\", _pAllocator, _pAllocator, _pAllocator, {}u}})", instr.opcode, shift, instr.opname, ver);
-    
-    if has_props {
-        println!(".kv.value;");
-    } else {println!(";");}
-    
-    if instr.operands.is_some() {
-        let ops = instr.operands.as_ref().unwrap();
-        if ops.len() > 1 {
-            println!("\t\tinstr.operands.reserve({}u);", ops.len());
-        }
-
-        for op in ops {
-            let category = kind_categories[&op.kind];
-            let quantifier = match &op.quantifier {Some(s) => if s == "?" { "Quantifier::ZeroOrOne"} else if s == "*" {"Quantifier::ZeroOrAny"} else {"Quantifier::One"}, None => "Quantifier::One"};
-            let name =  match op.name {Some(ref s) => s, None => if op.kind == "IdResultType" { "ResultType"} else if op.kind == "IdResult" { "Result" } else {""}};
-            println!("\t\tinstr.operands.emplace_back(OperandKind::{}, OperandCategory::{}, \"{}\", {});", &op.kind, category, name.replace("\n", ""), quantifier);
-        }
+    if let Some(format) = doc_format {
+        if let Some(spv) = &spv { docs::docs(spv, &exts, &provenance(&sources), format, &mut out)?; }
+        return out.flush();
     }
 
-    if instr.capabilities.is_some(){
-        let caps = instr.capabilities.as_ref().unwrap();
-        if caps.len() > 1 {
-            println!("\t\tinstr.capabilities.reserve({}u);", caps.len());
-        }
-
-        for cap in caps {
-            println!("\t\tinstr.capabilities.emplace_back(spv::Capability::{});", cap);
+    if let Some(emit) = emit {
+        match emit.as_str() {
+            "json" => if let Some(spv) = &spv { json::emit_json(spv, &exts, &sources, &mut out)?; },
+            _ => eprintln!("unknown --emit format {}, expected json", emit)
         }
+        return out.flush();
     }
 
-    if instr.extensions.is_some(){
-        let exts = instr.extensions.as_ref().unwrap();
-        if exts.len() > 1 {
-            println!("\t\tinstr.extensions.reserve({}u);", exts.len());
-        }
-        for ext in exts {
-            println!("\t\tinstr.extensions.emplace_back(spv::Extension::{});", ext);
+    if let Some(path) = template {
+        let source = fs::read_to_string(&path).unwrap_or_else(|e| exit_with(io::Error::new(e.kind(), format!("{}: {}", path, e))));
+        if let Some(spv) = &spv {
+            if let Err(e) = template::render_template(spv, &exts, &sources, &path, &source, &mut out) { exit_with(e) }
         }
+        return out.flush();
     }
 
-    if has_props {
-        println!("\t}}")
+    if rust {
+        if let Some(spv) = &spv { rust::rust_defs(spv, &exts, &sources, &mut out)?; }
+        return out.flush();
     }
-}
 
-fn grammar_cpp(spv: Grammar, glsl: Grammar, opencl: Grammar)
-{
-    let operand_kinds = spv.operand_kinds.unwrap_or(Vec::new());
-
-    println!("#include \"spvgentwo/Grammar.h\"");
-    println!("#include \"spvgentwo/Glsl.h\"");
-    println!("#include \"spvgentwo/OpenCl.h\"");
-    println!("using namespace spvgentwo;\n");
-
-    let mut kind_categories = HashMap::new();
-    for elem in &operand_kinds {
-        kind_categories.insert(&elem.kind, &elem.category);
-    }
+    write_header(&sources, &mut out)?;
 
-    let mut unique_instructions = BTreeMap::new();
-
-    for instr in &spv.instructions
-    {        
-        let entry = &unique_instructions.entry(instr.opcode).or_insert(instr);
-        // filter out / replace vendor extension instructions with their ratified versions
-        if entry.opname != instr.opname && (instr.opname.ends_with("KHR") || instr.opname.ends_with("EXT")){
-            unique_instructions.insert(instr.opcode, instr);
+    if defs {
+        if let Some(spv) = &spv { spv_defs(spv, &mut out)?; }
+        else if let Some((ext, grammar)) = exts.iter().find_map(|(name, grammar)| Extension::from_import_name(name).map(|ext| (ext, grammar))) {
+            ext_defs(grammar, ext, &mut out)?;
         }
     }
-
-    let mut operand_bases_count = 0;
-    let mut operand_parameter_count = 0;
-    let mut operand_enum_count = 0;
-    for op in &operand_kinds {
-        if op.bases.is_some() {
-            operand_bases_count += 1;
-        }
-        match op.enumerants.as_ref() {
-            Some(v) => {
-                operand_enum_count += v.len();
-
-                for en in v {
-                    if en.parameters.is_some() {
-                        operand_parameter_count += 1;
-                    }
-                }
-            },
-            None => {}
-        }
+    else if header {
+        if let Some(spv) = &spv { grammar_header(spv, &exts, &mut out)?; }
     }
-
-    println!("Grammar::Grammar(IAllocator* _pAllocator) : m_instructions(_pAllocator, {}u), m_operandNames(_pAllocator, {}u), m_operandParameters(_pAllocator, {}u), m_operandBases(_pAllocator, {}u)\n{{", unique_instructions.len() + glsl.instructions.len() + opencl.instructions.len(), operand_enum_count, operand_parameter_count, operand_bases_count);
-    
-    for op in &operand_kinds {
-        match op.bases.as_ref() {
-            Some(bases) => {
-                println!("\t{{");
-                println!("\t\tauto& bases = m_operandBases.emplaceUnique(OperandKind::{}, _pAllocator).kv.value;", &op.kind);
-                if bases.len() > 1 {
-                    println!("\t\tbases.reserve({}u);", bases.len());
-                }
-                for b in bases {
-                    let category = kind_categories[&b];
-                    println!("\t\tbases.emplace_back(OperandKind::{}, OperandCategory::{}, \"\", Quantifier::One);", &b, category);
-                }
-                println!("\t}}");
-            },
-            None => {}
-        }
+    else if cpp {
+        if let Some(spv) = &spv { grammar_cpp(spv, &exts, &mut out)?; }
     }
-
-
-    for (i, op) in operand_kinds.iter().enumerate() {
-        let mut unique_parameters = BTreeMap::new();
-
-        match op.enumerants.as_ref() {
-            Some(v) => {
-                for en in v {
-                    match en.parameters.as_ref() {
-                        Some(params) => {
-                            let st = serde_json::to_string(&en.value).unwrap_or_default();
-                            if unique_parameters.insert(st, i).is_none() {
-                                println!("\t{{");
-                                print!("\t\tauto& parameters = m_operandParameters.emplaceUnique(Hash64({}u, ", i);
-                                match &en.value {
-                                    serde_json::Value::Number(x) => {print!("{}u", x)},
-                                    serde_json::Value::String(s) => {print!("{}", s)}
-                                    _ => {}
-                                }
-                                print!("), _pAllocator).kv.value;\n");
-    
-                                if params.len() > 1 {
-                                    println!("\t\tparameters.reserve({}u);", params.len());
-                                }
-    
-                                for p in params
-                                {
-                                    let category = kind_categories[&p.kind];
-                                    let name = match p.name {Some(ref s) => s, None => {""}};
-                                    println!("\t\tparameters.emplace_back(OperandKind::{}, OperandCategory::{}, \"{}\", Quantifier::One);", &p.kind, category, name.replace("\n", ""));
-                                }
-                                println!("\t}}");
-                            }                      
-                        },
-                        None => {}
-                    }
-                }
-            },
-            None => {}
-        }       
+    else if visitor {
+        if let Some(spv) = &spv { visitor::operand_visitor(spv, &mut out)?; }
     }
-
-    for (opcode, instr) in unique_instructions
-    {
-        print_instruction(instr, &kind_categories, 0);
+    else if builder {
+        if let Some(spv) = &spv { builder::instruction_builder(spv, &mut out)?; }
     }
-
-    for instr in glsl.instructions
-    {
-        print_instruction(&instr, &kind_categories, 1);
+    else if printer {
+        if let Some(spv) = &spv { printer::instruction_printer(spv, &exts, &mut out)?; }
     }
-
-    for instr in opencl.instructions
-    {
-        print_instruction(&instr, &kind_categories, 2);
-    }
-
-    // operand name lookup tables
-    for (i, op) in operand_kinds.iter().enumerate() {
-        if op.category == "ValueEnum" || op.category == "BitEnum" {
-            match op.enumerants.as_ref()  {
-                Some(v) => {
-                    for enumval in v {
-                        print!("\tm_operandNames.emplaceUnique(Hash64({}u, ", i);
-                        match &enumval.value
-                        {
-                            serde_json::Value::Number(x) => {print!("{}u), ", x)},
-                            serde_json::Value::String(s) => {print!("{}u), ", s)}
-                            _ => {}
-                        }
-                        if op.kind == "Dim" && enumval.enumerant.len() == 2{
-                            println!("\"Dim{}\");", enumval.enumerant);
-                        }else{
-                            println!("\"{}\");", enumval.enumerant);
-                        }
-                    }
-                },
-                None => {}
-            }  
-        }        
-    }
-
-    println!("}}"); // constructor
-
-    println!("const Grammar::Instruction* Grammar::getInfo(unsigned int _opcode, Extension _extension) const\n{{");
-        println!("\treturn m_instructions.get(Hash64(_opcode, static_cast<unsigned int>(_extension)));");
-    println!("}}"); // getInfo
-
-    println!("const char* Grammar::getOperandName(OperandKind _kind, unsigned int _literalValue) const\n{{");
-        println!("\tconst char** name = m_operandNames.get(Hash64(static_cast<unsigned int>(_kind), _literalValue));");
-        println!("\treturn name == nullptr ? nullptr : *name;");
-    println!("}}"); // getOperandName
-
-    println!("const Vector<Grammar::Operand>* Grammar::getOperandParameters(OperandKind _kind, unsigned int _literalValue) const\n{{");
-        println!("\treturn m_operandParameters.get(Hash64(static_cast<unsigned int>(_kind), _literalValue));");
-    println!("}}"); // getOperandParameters
-
-    println!("const Vector<Grammar::Operand>* Grammar::getOperandBases(OperandKind _kind) const\n{{");
-        println!("\treturn m_operandBases.get(_kind);");
-    println!("}}"); // getOperandParameters
-
-    println!("bool Grammar::hasOperandParameters(OperandKind _kind)\n{{");
-    println!("\tswitch (_kind) {{");
-    println!("\tdefault: return false; // majority of operand kinds don't have parameters");
-    for op in &operand_kinds {
-        match op.enumerants.as_ref() {
-            Some(v) => {
-                for en in v {
-                    if en.parameters.is_some() {
-                        println!("\tcase OperandKind::{}: return true;", op.kind);
-                        break;
-                    }
-                }
-            },
-            None => {}
-        }       
-    }
-    println!("\t}};");
-    println!("}}"); // hasOperandParameters
-}
-
-fn ext_defs(spv: Grammar, ext: Extension)
-{
-    for line in spv.copyright {
-        println!("// {}", line); 
-    }
-
-    println!("#pragma once\n");
-    println!("namespace spvgentwo::{:?}\n{{", ext);
-
-    println!("\tstatic constexpr unsigned int Version = {};", spv.version.unwrap_or_default());
-    println!("\tstatic constexpr unsigned int Revision = {};", spv.revision);
-
-    println!("\tenum class Op : unsigned\n\t{{");
-    for instr in &spv.instructions
-    {
-        println!("\t\t{} = {},", instr.opname, instr.opcode);
-    }
-    println!("\t\tMax = 0x7fffffff");
-    println!("\t}};");
-
-    println!("}} // spvgentwo::{:?}", ext);
-}
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let mut spv: Option<Grammar> = None;
-    let mut glsl: Option<Grammar> = None;
-    let mut opencl: Option<Grammar> = None;
-
-    let mut defs = false;
-    let mut header = false;
-    let mut cpp = false;
-
-    for arg in args
-    {
-        if arg.ends_with("spirv.core.grammar.json") {
-            spv = serde_json::from_reader(File::open(arg).expect("file should open read only")).expect("file should be proper JSON");
-        } else if arg.ends_with("extinst.glsl.std.450.grammar.json") {
-            glsl = serde_json::from_reader(File::open(arg).expect("file should open read only")).expect("file should be proper JSON"); 
-        } else if arg.ends_with("extinst.opencl.std.100.grammar.json") {
-            opencl = serde_json::from_reader(File::open(arg).expect("file should open read only")).expect("file should be proper JSON"); 
-        } else if arg == "--defs" {
-            defs = true;
-        } else if arg == "--header" {
-            header = true;
-        } else if arg == "--cpp" {
-            cpp = true;
-        }
+    else if traits {
+        if let Some(spv) = &spv { traits::op_traits(spv, &mut out)?; }
     }
-    println!("// Auto generated - do not modify");
 
-    if defs {
-        if spv.is_some() { spv_defs(spv.unwrap()); } else
-        if glsl.is_some() { ext_defs(glsl.unwrap(), Extension::glslstd450); } else
-        if opencl.is_some() { ext_defs(opencl.unwrap(), Extension::opencl100); } 
-    }    
-    else if header && spv.is_some() {
-        grammar_header(spv.unwrap());
-    }
-    else if cpp && spv.is_some() && glsl.is_some() && opencl.is_some() {
-        grammar_cpp(spv.unwrap(), glsl.unwrap(), opencl.unwrap());
-    }
+    out.flush()
 }
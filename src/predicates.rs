@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+use serde_derive::Deserialize;
+
+use crate::Grammar;
+use crate::Instruction;
+use crate::display_name;
+use crate::read_json;
+use crate::template::pascal_case;
+
+// classes that don't describe what an instruction does
+pub(crate) const IGNORED_CLASSES: [&str; 2] = ["@exclude", "Reserved"];
+
+/// Reads an override file mapping opnames to the class they should be treated as: `{ "OpTypeRayQueryKHR": "Type-Declaration" }`
+/// JSON object of opname to class
+pub fn load_class_overrides<P: AsRef<Path>>(path: P) -> io::Result<BTreeMap<String, String>>
+{
+    read_json(path)
+}
+
+pub fn apply_class_overrides(spv: &mut Grammar, overrides: &BTreeMap<String, String>)
+{
+    for instr in &mut spv.instructions {
+        if let Some(class) = overrides.get(&instr.opname) {
+            instr.class = Some(class.clone());
+        }
+    }
+}
+
+// Type-Declaration -> IsTypeDeclaration, Relational_and_Logical -> IsRelationalAndLogical
+pub fn predicate_name(class: &str) -> String
+{
+    format!("Is{}", pascal_case(class))
+}
+
+// one IsXxx(Op) per instruction class of the grammar
+pub fn class_predicates(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut classes: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut opcodes = BTreeSet::new();
+    for instr in &spv.instructions {
+        let class = match instr.class.as_deref() { Some(c) if !IGNORED_CLASSES.contains(&c) => c, _ => continue };
+        if opcodes.insert(instr.opcode) {
+            classes.entry(class).or_default().push(&instr.opname);
+        }
+    }
+
+    for (class, opnames) in &classes {
+        predicate(&predicate_name(class), &format!("instruction class {}", class), opnames, out)?;
+    }
+    Ok(())
+}
+
+/// Control flow edge cases the grammar can't express, see control_flow.json
+#[derive(Deserialize, Default)]
+pub struct ControlFlowOverrides
+{
+    /// block terminators that neither branch, return nor are unreachable, like OpKill
+    #[serde(default)]
+    pub terminators: Vec<String>,
+    /// operand indices of the successor labels per opname, replacing the derived ones
+    #[serde(default)]
+    pub successors: BTreeMap<String, Vec<usize>>
+}
+
+/// JSON object with terminators and successors
+pub fn load_control_flow_overrides<P: AsRef<Path>>(path: P) -> io::Result<ControlFlowOverrides>
+{
+    read_json(path)
+}
+
+pub fn apply_control_flow_overrides(spv: &mut Grammar, overrides: &ControlFlowOverrides)
+{
+    for instr in &mut spv.instructions {
+        instr.terminator |= overrides.terminators.contains(&instr.opname);
+        if let Some(successors) = overrides.successors.get(&instr.opname) {
+            instr.successors = Some(successors.clone());
+        }
+    }
+}
+
+// successor labels of a Control-Flow instruction without result: IdRefs named like 'True Label' or 'Default'
+// and trailing pairs ending in an IdRef like OpSwitch's PairLiteralIntegerIdRef 'Target'.
+// Successors from --control-flow-overrides apply to any instruction.
+fn successors(instr: &Instruction, pair_kinds: &BTreeSet<&str>) -> (Vec<usize>, Option<usize>)
+{
+    let operands = instr.operands.as_deref().unwrap_or_default();
+    let derived = instr.class.as_deref() == Some("Control-Flow") && !operands.iter().any(|op| op.kind == "IdResult");
+    if instr.successors.is_none() && !derived {
+        return (Vec::new(), None);
+    }
+
+    let labels = match &instr.successors {
+        Some(indices) => indices.clone(),
+        None => operands.iter().enumerate().filter(|(_, op)| {
+            let name = display_name(op.name.as_deref().unwrap_or_default());
+            op.kind == "IdRef" && op.quantifier.is_none() && (name.ends_with("Label") || name == "Default")
+        }).map(|(i, _)| i).collect()
+    };
+    let pairs = operands.iter().position(|op| derived && op.quantifier.as_deref() == Some("*") && pair_kinds.contains(op.kind.as_str()));
+    (labels, pairs)
+}
+
+fn predicate(name: &str, comment: &str, opnames: &[&str], out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "\t// {}", comment)?;
+    writeln!(out, "\tinline constexpr bool {}(Op opcode) {{", name)?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return false;")?;
+    for opname in opnames {
+        writeln!(out, "\t\tcase Op::{}: return true;", opname)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
+
+// IsBranch, IsReturn, IsUnreachable, IsBlockTerminator and GetSuccessorOperands for CFG construction
+pub fn control_flow_predicates(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    // composite kinds whose last element is a label
+    let pair_kinds: BTreeSet<&str> = spv.operand_kinds.iter().flatten()
+        .filter(|k| k.bases.as_ref().and_then(|b| b.last()).is_some_and(|b| b == "IdRef"))
+        .map(|k| k.kind.as_str()).collect();
+
+    let mut branches = Vec::new();
+    let mut returns = Vec::new();
+    let mut unreachables = Vec::new();
+    let mut terminators = Vec::new();
+    let mut successor_table = Vec::new();
+
+    let mut opcodes = BTreeSet::new();
+    for instr in &spv.instructions {
+        if !opcodes.insert(instr.opcode) { continue; }
+
+        let control_flow = instr.class.as_deref() == Some("Control-Flow");
+        let (labels, pairs) = successors(instr, &pair_kinds);
+        let name = instr.opname.as_str();
+
+        if !labels.is_empty() || pairs.is_some() {
+            branches.push(name);
+            successor_table.push((name, labels, pairs));
+        } else if control_flow && name.starts_with("OpReturn") {
+            returns.push(name);
+        } else if control_flow && name == "OpUnreachable" {
+            unreachables.push(name);
+        } else if !instr.terminator {
+            continue;
+        }
+        terminators.push(name);
+    }
+
+    predicate("IsBranch", "block terminators with successor labels", &branches, out)?;
+    predicate("IsReturn", "function returns", &returns, out)?;
+    predicate("IsUnreachable", "unreachable block end", &unreachables, out)?;
+    predicate("IsBlockTerminator", "last instruction of a block: branches, returns, unreachable and the ones from --control-flow-overrides", &terminators, out)?;
+
+    let max_labels = successor_table.iter().map(|(_, labels, _)| labels.len()).max().unwrap_or_default().max(1);
+    writeln!(out, "\t// operand indices (counting IdResultType and IdResult) of the successor labels of a branch,")?;
+    writeln!(out, "\t// pairsIndex is the trailing operand list of pairs whose last word is a label (OpSwitch targets)")?;
+    writeln!(out, "\tstruct SuccessorOperands {{")?;
+    writeln!(out, "\t\tunsigned int count;")?;
+    writeln!(out, "\t\tunsigned int indices[{}];", max_labels)?;
+    writeln!(out, "\t\tunsigned int pairsIndex;")?;
+    writeln!(out, "\t}};")?;
+    writeln!(out, "\tstatic constexpr unsigned int NoSuccessorPairs = 0xffffffff;")?;
+    writeln!(out, "\tinline constexpr SuccessorOperands GetSuccessorOperands(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return {{ 0u, {{}}, NoSuccessorPairs }};")?;
+    for (name, labels, pairs) in &successor_table {
+        let indices: Vec<String> = labels.iter().map(|i| format!("{}u", i)).collect();
+        let pairs = pairs.map(|p| format!("{}u", p)).unwrap_or_else(|| "NoSuccessorPairs".to_string());
+        writeln!(out, "\t\tcase Op::{}: return {{ {}u, {{ {} }}, {} }};", name, labels.len(), indices.join(", "), pairs)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
@@ -0,0 +1,536 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::enumerant_value;
+use crate::quantifier_name;
+
+// {kind, quantifier} entries of spv::OperandLayout, kinds unknown to the core grammar make the whole list unknown
+fn layout_entries(kinds: &BTreeSet<&str>, operands: &[(String, Option<String>)]) -> Option<Vec<String>>
+{
+    operands.iter().map(|(kind, quantifier)| {
+        kinds.contains(kind.as_str()).then(|| format!("{{ OperandKind::{}, Quantifier::{} }}", kind, quantifier_name(quantifier)))
+    }).collect()
+}
+
+// InstructionPrinter.h: printInstruction renders one instruction like `spirv-dis --raw-id --no-indent`,
+// walking GetOperandLayout of Spv.h and the ext instruction sets passed along with the core grammar
+pub fn instruction_printer(spv: &Grammar, exts: &[(String, Grammar)], out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+    let kinds: BTreeSet<&str> = operand_kinds.iter().map(|k| k.kind.as_str()).collect();
+
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "// GetOperandLayout is only declared with SPVGENTWO_OPERAND_LAYOUT, include this header before any other include of Spv.h")?;
+    writeln!(out, "#ifndef SPVGENTWO_OPERAND_LAYOUT")?;
+    writeln!(out, "#define SPVGENTWO_OPERAND_LAYOUT")?;
+    writeln!(out, "#endif")?;
+    writeln!(out, "#include \"Spv.h\"\n")?;
+
+    writeln!(out, "namespace spvgentwo\n{{")?;
+
+    // ids of OpExtInstImport results in the order of ExtInstSetNames, 0 if not imported (yet), and the scalar
+    // types OpConstant and OpSpecConstant values are printed with
+    writeln!(out, "\tstatic constexpr unsigned int ExtInstSetCount = {}u;", exts.len())?;
+    writeln!(out, "\tstruct PrinterState\n\t{{")?;
+        writeln!(out, "\t\tenum class NumberKind : unsigned char {{ Unsigned, Signed, Float }};")?;
+        writeln!(out, "\t\tstruct NumberType\n\t\t{{")?;
+            writeln!(out, "\t\t\tunsigned int id;")?;
+            writeln!(out, "\t\t\tunsigned int width;")?;
+            writeln!(out, "\t\t\tNumberKind kind;")?;
+        writeln!(out, "\t\t}};")?;
+        writeln!(out, "\t\tstatic constexpr unsigned int MaxNumberTypes = 32u;\n")?;
+        writeln!(out, "\t\tunsigned int extInstSets[ExtInstSetCount + 1u] = {{}};")?;
+        writeln!(out, "\t\t// OpTypeInt and OpTypeFloat results, constants of types beyond MaxNumberTypes are printed as unsigned integers")?;
+        writeln!(out, "\t\tNumberType numberTypes[MaxNumberTypes] = {{}};")?;
+        writeln!(out, "\t\tunsigned int numberTypeCount = 0u;")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\tnamespace detail\n\t{{")?;
+    writeln!(out, "\t\tusing namespace spv;")?;
+
+    writeln!(out, "\t\tstatic constexpr const char* ExtInstSetNames[] =\n\t\t{{")?;
+    for (name, _) in exts {
+        writeln!(out, "\t\t\t\"{}\",", name)?;
+    }
+    writeln!(out, "\t\t\tnullptr")?;
+    writeln!(out, "\t\t}};")?;
+
+    writeln!(out, "\t\tstruct PrintCursor\n\t\t{{")?;
+        writeln!(out, "\t\t\tconst unsigned int* it;")?;
+        writeln!(out, "\t\t\tconst unsigned int* end;")?;
+        writeln!(out, "\t\t\tunsigned int resultType = 0u; // id of the instruction's result type, 0 if it has none")?;
+        writeln!(out, "\t\t\tbool empty() const {{ return it == end; }}")?;
+    writeln!(out, "\t\t}};")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printUnsigned(unsigned long long _value, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tchar buffer[21] = {{}};")?;
+        writeln!(out, "\t\t\tchar* pos = buffer + 20;")?;
+        writeln!(out, "\t\t\tdo {{ *--pos = static_cast<char>('0' + _value % 10u); _value /= 10u; }} while (_value != 0u);")?;
+        writeln!(out, "\t\t\t_sink(pos);")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printHex(unsigned int _value, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tchar buffer[11] = {{ '0', 'x' }};")?;
+        writeln!(out, "\t\t\tfor (int i = 0; i < 8; ++i) {{ buffer[9 - i] = \"0123456789abcdef\"[(_value >> (i * 4)) & 0xfu]; }}")?;
+        writeln!(out, "\t\t\t_sink(buffer);")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printSigned(long long _value, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tif (_value < 0) {{ _sink(\"-\"); printUnsigned(0ull - static_cast<unsigned long long>(_value), _sink); }}")?;
+        writeln!(out, "\t\t\telse printUnsigned(static_cast<unsigned long long>(_value), _sink);")?;
+    writeln!(out, "\t\t}}")?;
+
+    // floats are printed like the FloatProxy of spirv-dis: normal values in decimal with max_digits10 significant digits,
+    // 16-bit, subnormal, infinite and NaN values as hex floats
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printHexFloat(unsigned long long _bits, unsigned int _exponentBits, unsigned int _fractionBits, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tconst unsigned int nibbles = (_fractionBits + 3u) / 4u;")?;
+        writeln!(out, "\t\t\tconst unsigned long long exponent = (_bits >> _fractionBits) & ((1ull << _exponentBits) - 1u);")?;
+        writeln!(out, "\t\t\tunsigned long long fraction = (_bits & ((1ull << _fractionBits) - 1u)) << (nibbles * 4u - _fractionBits);")?;
+        writeln!(out, "\t\t\tconst bool zero = exponent == 0u && fraction == 0u;")?;
+        writeln!(out, "\t\t\tint power = zero ? 0 : static_cast<int>(exponent) - ((1 << (_exponentBits - 1u)) - 1);")?;
+        writeln!(out, "\t\t\tif (exponent == 0u && !zero)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\t// subnormal, shift the leading 1 out so it becomes implicit")?;
+            writeln!(out, "\t\t\t\twhile ((fraction & (1ull << (nibbles * 4u - 1u))) == 0u) {{ fraction <<= 1u; --power; }}")?;
+            writeln!(out, "\t\t\t\tfraction = (fraction << 1u) & ((1ull << (nibbles * 4u)) - 1u);")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tunsigned int digits = nibbles;")?;
+        writeln!(out, "\t\t\twhile (digits != 0u && (fraction & 0xfu) == 0u) {{ fraction >>= 4u; --digits; }}")?;
+        writeln!(out, "\t\t\tchar buffer[24] = {{ '0', 'x', zero ? '0' : '1', '.' }};")?;
+        writeln!(out, "\t\t\tfor (unsigned int i = 0u; i < digits; ++i) {{ buffer[3u + digits - i] = \"0123456789abcdef\"[(fraction >> (i * 4u)) & 0xfu]; }}")?;
+        writeln!(out, "\t\t\tbuffer[digits != 0u ? 4u + digits : 3u] = '\\0';")?;
+        writeln!(out, "\t\t\tif ((_bits >> (_exponentBits + _fractionBits)) & 1u) _sink(\"-\");")?;
+        writeln!(out, "\t\t\t_sink(buffer);")?;
+        writeln!(out, "\t\t\t_sink(power < 0 ? \"p-\" : \"p+\");")?;
+        writeln!(out, "\t\t\tprintUnsigned(static_cast<unsigned long long>(power < 0 ? -power : power), _sink);")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\t// big enough for the mantissa of the smallest normal double times 5^1074")?;
+    writeln!(out, "\t\tstruct BigUnsigned\n\t\t{{")?;
+        writeln!(out, "\t\t\tunsigned int words[84] = {{}};")?;
+        writeln!(out, "\t\t\tunsigned int count = 0u;")?;
+        writeln!(out, "\t\t\tvoid multiply(unsigned int _factor)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tunsigned long long carry = 0u;")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = 0u; i < count; ++i) {{ carry += static_cast<unsigned long long>(words[i]) * _factor; words[i] = static_cast<unsigned int>(carry); carry >>= 32u; }}")?;
+            writeln!(out, "\t\t\t\tif (carry != 0u) words[count++] = static_cast<unsigned int>(carry);")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tunsigned int divide(unsigned int _divisor) // returns the remainder")?;
+        writeln!(out, "\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tunsigned long long rest = 0u;")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = count; i-- > 0u;) {{ rest = rest << 32u | words[i]; words[i] = static_cast<unsigned int>(rest / _divisor); rest %= _divisor; }}")?;
+            writeln!(out, "\t\t\t\twhile (count != 0u && words[count - 1u] == 0u) --count;")?;
+            writeln!(out, "\t\t\t\treturn static_cast<unsigned int>(rest);")?;
+        writeln!(out, "\t\t\t}}")?;
+    writeln!(out, "\t\t}};")?;
+
+    writeln!(out, "\t\t// normal or zero value formatted like printf %.<_precision>g, from its exact decimal expansion")?;
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printDecimalFloat(unsigned long long _bits, unsigned int _exponentBits, unsigned int _fractionBits, unsigned int _precision, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tif ((_bits >> (_exponentBits + _fractionBits)) & 1u) _sink(\"-\");")?;
+        writeln!(out, "\t\t\tconst unsigned long long exponent = (_bits >> _fractionBits) & ((1ull << _exponentBits) - 1u);")?;
+        writeln!(out, "\t\t\tif (exponent == 0u) {{ _sink(\"0\"); return; }}")?;
+        writeln!(out, "\t\t\tconst unsigned long long mantissa = (_bits & ((1ull << _fractionBits) - 1u)) | 1ull << _fractionBits;")?;
+        writeln!(out, "\t\t\tBigUnsigned value;")?;
+        writeln!(out, "\t\t\tvalue.words[0] = static_cast<unsigned int>(mantissa);")?;
+        writeln!(out, "\t\t\tvalue.words[1] = static_cast<unsigned int>(mantissa >> 32u);")?;
+        writeln!(out, "\t\t\tvalue.count = value.words[1] != 0u ? 2u : 1u;")?;
+        writeln!(out, "\t\t\t// mantissa * 2^power is value * 10^point, for negative powers mantissa * 5^-power * 10^power")?;
+        writeln!(out, "\t\t\tint power = static_cast<int>(exponent) - ((1 << (_exponentBits - 1u)) - 1) - static_cast<int>(_fractionBits);")?;
+        writeln!(out, "\t\t\tint point = 0;")?;
+        writeln!(out, "\t\t\tfor (; power > 0; --power) value.multiply(2u);")?;
+        writeln!(out, "\t\t\tfor (; power < 0; ++power, --point) value.multiply(5u);")?;
+        writeln!(out, "\t\t\tchar digits[800] = {{}};")?;
+        writeln!(out, "\t\t\tunsigned int count = 0u;")?;
+        writeln!(out, "\t\t\twhile (value.count != 0u) digits[count++] = static_cast<char>('0' + value.divide(10u));")?;
+        writeln!(out, "\t\t\tfor (unsigned int i = 0u; i < count / 2u; ++i) {{ const char c = digits[i]; digits[i] = digits[count - 1u - i]; digits[count - 1u - i] = c; }}")?;
+        writeln!(out, "\t\t\tint decimalExponent = static_cast<int>(count) - 1 + point;\n")?;
+
+        writeln!(out, "\t\t\t// round half to even to _precision significant digits")?;
+        writeln!(out, "\t\t\tif (count > _precision)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tbool sticky = false;")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = _precision + 1u; i < count; ++i) sticky |= digits[i] != '0';")?;
+            writeln!(out, "\t\t\t\tconst char next = digits[_precision];")?;
+            writeln!(out, "\t\t\t\tcount = _precision;")?;
+            writeln!(out, "\t\t\t\tif (next > '5' || (next == '5' && (sticky || (digits[count - 1u] - '0') % 2 != 0)))\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tunsigned int i = count;")?;
+                writeln!(out, "\t\t\t\t\twhile (i != 0u && digits[i - 1u] == '9') digits[--i] = '0';")?;
+                writeln!(out, "\t\t\t\t\tif (i != 0u) ++digits[i - 1u]; else {{ digits[0] = '1'; ++decimalExponent; }}")?;
+            writeln!(out, "\t\t\t\t}}")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\twhile (count > 1u && digits[count - 1u] == '0') --count;\n")?;
+
+        writeln!(out, "\t\t\tchar text[48] = {{}};")?;
+        writeln!(out, "\t\t\tunsigned int length = 0u;")?;
+        writeln!(out, "\t\t\tif (decimalExponent < -4 || decimalExponent >= static_cast<int>(_precision))\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\ttext[length++] = digits[0];")?;
+            writeln!(out, "\t\t\t\tif (count > 1u) text[length++] = '.';")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = 1u; i < count; ++i) text[length++] = digits[i];")?;
+            writeln!(out, "\t\t\t\ttext[length++] = 'e';")?;
+            writeln!(out, "\t\t\t\ttext[length++] = decimalExponent < 0 ? '-' : '+';")?;
+            writeln!(out, "\t\t\t\tconst unsigned int magnitude = static_cast<unsigned int>(decimalExponent < 0 ? -decimalExponent : decimalExponent);")?;
+            writeln!(out, "\t\t\t\tif (magnitude >= 100u) text[length++] = static_cast<char>('0' + magnitude / 100u);")?;
+            writeln!(out, "\t\t\t\ttext[length++] = static_cast<char>('0' + magnitude / 10u % 10u);")?;
+            writeln!(out, "\t\t\t\ttext[length++] = static_cast<char>('0' + magnitude % 10u);")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\telse if (decimalExponent < 0)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\ttext[length++] = '0';")?;
+            writeln!(out, "\t\t\t\ttext[length++] = '.';")?;
+            writeln!(out, "\t\t\t\tfor (int i = -1; i > decimalExponent; --i) text[length++] = '0';")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = 0u; i < count; ++i) text[length++] = digits[i];")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\telse\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tconst unsigned int integers = static_cast<unsigned int>(decimalExponent) + 1u;")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = 0u; i < integers; ++i) text[length++] = i < count ? digits[i] : '0';")?;
+            writeln!(out, "\t\t\t\tif (count > integers) text[length++] = '.';")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = integers; i < count; ++i) text[length++] = digits[i];")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\t_sink(text);")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printFloat(unsigned long long _bits, unsigned int _width, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tif (_width == 16u) {{ printHexFloat(_bits, 5u, 10u, _sink); return; }}")?;
+        writeln!(out, "\t\t\tif (_width != 32u && _width != 64u) {{ printUnsigned(_bits, _sink); return; }}")?;
+        writeln!(out, "\t\t\tconst unsigned int exponentBits = _width == 32u ? 8u : 11u;")?;
+        writeln!(out, "\t\t\tconst unsigned int fractionBits = _width == 32u ? 23u : 52u;")?;
+        writeln!(out, "\t\t\tconst unsigned long long exponent = (_bits >> fractionBits) & ((1ull << exponentBits) - 1u);")?;
+        writeln!(out, "\t\t\tconst bool subnormal = exponent == 0u && (_bits & ((1ull << fractionBits) - 1u)) != 0u;")?;
+        writeln!(out, "\t\t\tif (subnormal || exponent == (1ull << exponentBits) - 1u) printHexFloat(_bits, exponentBits, fractionBits, _sink);")?;
+        writeln!(out, "\t\t\telse printDecimalFloat(_bits, exponentBits, fractionBits, _width == 32u ? 9u : 17u, _sink);")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tvoid printNumber(const PrinterState::NumberType& _type, unsigned long long _bits, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tconst unsigned long long mask = _type.width >= 64u ? ~0ull : (1ull << _type.width) - 1u;")?;
+        writeln!(out, "\t\t\tswitch (_type.kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tcase PrinterState::NumberKind::Unsigned: printUnsigned(_bits & mask, _sink); break;")?;
+        writeln!(out, "\t\t\tcase PrinterState::NumberKind::Signed:")?;
+            writeln!(out, "\t\t\t\t// sign extend narrower integers")?;
+            writeln!(out, "\t\t\t\tprintSigned(static_cast<long long>(_type.width != 0u && (_bits >> (_type.width - 1u)) & 1u ? _bits | ~mask : _bits & mask), _sink);")?;
+            writeln!(out, "\t\t\t\tbreak;")?;
+        writeln!(out, "\t\t\tcase PrinterState::NumberKind::Float: printFloat(_bits, _type.width, _sink); break;")?;
+        writeln!(out, "\t\t\t}}")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\tinline const PrinterState::NumberType* numberType(const PrinterState& _state, unsigned int _id)\n\t\t{{")?;
+        writeln!(out, "\t\t\tfor (unsigned int i = 0u; i < _state.numberTypeCount; ++i) {{ if (_state.numberTypes[i].id == _id) return _state.numberTypes + i; }}")?;
+        writeln!(out, "\t\t\treturn nullptr;")?;
+    writeln!(out, "\t\t}}")?;
+
+    // LiteralString with quotes and backslashes escaped, false if it is not null-terminated
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tbool printString(PrintCursor& _cursor, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\t_sink(\"\\\"\");")?;
+        writeln!(out, "\t\t\tfor (; !_cursor.empty(); ++_cursor.it)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int i = 0u; i < 4u; ++i)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tconst char c[3] = {{ static_cast<char>((*_cursor.it >> (i * 8u)) & 0xffu), '\\0', '\\0' }};")?;
+                writeln!(out, "\t\t\t\t\tif (c[0] == '\\0') {{ ++_cursor.it; _sink(\"\\\"\"); return true; }}")?;
+                writeln!(out, "\t\t\t\t\tif (c[0] == '\"' || c[0] == '\\\\') {{ const char escaped[3] = {{ '\\\\', c[0], '\\0' }}; _sink(escaped); }}")?;
+                writeln!(out, "\t\t\t\t\telse {{ _sink(c); }}")?;
+            writeln!(out, "\t\t\t\t}}")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn false;")?;
+    writeln!(out, "\t\t}}")?;
+
+    // names as spirv-dis prints them, aliases print the first name of their opcode or value
+    writeln!(out, "\t\tinline const char* opName(Op _opcode)\n\t\t{{")?;
+        writeln!(out, "\t\t\tswitch (_opcode)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: return nullptr;")?;
+        let mut opcodes = BTreeSet::new();
+        for instr in spv.instructions.iter().filter(|i| opcodes.insert(i.opcode)) {
+            writeln!(out, "\t\t\tcase Op::{0}: return \"{0}\";", instr.opname)?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\tinline const char* enumerantName(OperandKind _kind, unsigned int _value)\n\t\t{{")?;
+        writeln!(out, "\t\t\tswitch (_kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for op in operand_kinds.iter().filter(|k| k.enumerants.is_some()) {
+            writeln!(out, "\t\t\tcase OperandKind::{}:", op.kind)?;
+            writeln!(out, "\t\t\t\tswitch (_value)\n\t\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tdefault: return nullptr;")?;
+            let mut values = BTreeSet::new();
+            for en in op.enumerants.iter().flatten() {
+                let value = enumerant_value(&en.value);
+                if values.insert(value) {
+                    writeln!(out, "\t\t\t\tcase {}u: return \"{}\";", value, en.enumerant)?;
+                }
+            }
+            writeln!(out, "\t\t\t\t}}")?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn nullptr;")?;
+    writeln!(out, "\t\t}}")?;
+
+    // operands following an enumerant (ValueEnum) or one of its bits (BitEnum)
+    let mut parameters = Vec::new();
+    let mut offset = 0;
+    writeln!(out, "\t\tstatic constexpr OperandLayout EnumerantParameters[] =\n\t\t{{")?;
+    for op in operand_kinds {
+        let mut values = BTreeSet::new();
+        for en in op.enumerants.iter().flatten() {
+            let value = enumerant_value(&en.value);
+            let params = match &en.parameters { Some(p) if values.insert(value) => p, _ => continue };
+            writeln!(out, "\t\t\t// {} {}", op.kind, en.enumerant)?;
+            for p in params {
+                writeln!(out, "\t\t\t{{ OperandKind::{}, Quantifier::One }},", p.kind)?;
+            }
+            parameters.push((&op.kind, value, offset, params.len()));
+            offset += params.len();
+        }
+    }
+    writeln!(out, "\t\t}};")?;
+
+    writeln!(out, "\t\tinline OperandLayoutSpan enumerantParameters(OperandKind _kind, unsigned int _value)\n\t\t{{")?;
+        let mut by_kind: BTreeMap<&String, Vec<(u32, usize, usize)>> = BTreeMap::new();
+        for (kind, value, offset, count) in parameters {
+            by_kind.entry(kind).or_default().push((value, offset, count));
+        }
+        writeln!(out, "\t\t\tswitch (_kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for (kind, values) in &by_kind {
+            writeln!(out, "\t\t\tcase OperandKind::{}:", kind)?;
+            writeln!(out, "\t\t\t\tswitch (_value)\n\t\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tdefault: break;")?;
+            for (value, offset, count) in values {
+                writeln!(out, "\t\t\t\tcase {}u: return {{ EnumerantParameters + {}, {}u }};", value, offset, count)?;
+            }
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\tbreak;")?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn {{ EnumerantParameters, 0u }};")?;
+    writeln!(out, "\t\t}}")?;
+
+    // ext instruction names and the operands following the instruction number, indexed like ExtInstSetNames
+    writeln!(out, "\t\tinline const char* extInstName(unsigned int _set, unsigned int _instruction)\n\t\t{{")?;
+        writeln!(out, "\t\t\tswitch (_set)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for (i, (_, ext)) in exts.iter().enumerate() {
+            writeln!(out, "\t\t\tcase {}u:", i)?;
+            writeln!(out, "\t\t\t\tswitch (_instruction)\n\t\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tdefault: break;")?;
+            for instr in &ext.instructions {
+                writeln!(out, "\t\t\t\tcase {}u: return \"{}\";", instr.opcode, instr.opname)?;
+            }
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\tbreak;")?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn nullptr;")?;
+    writeln!(out, "\t\t}}")?;
+
+    let mut ext_layouts = Vec::new();
+    let mut offset = 0;
+    writeln!(out, "\t\tstatic constexpr OperandLayout ExtInstOperands[] =\n\t\t{{")?;
+    for (i, (_, ext)) in exts.iter().enumerate() {
+        for instr in &ext.instructions {
+            let operands: Vec<(String, Option<String>)> = instr.operands.iter().flatten().map(|op| (op.kind.clone(), op.quantifier.clone())).collect();
+            let entries = match layout_entries(&kinds, &operands) { Some(e) => e, None => continue };
+            if !entries.is_empty() {
+                writeln!(out, "\t\t\t// {}", instr.opname)?;
+            }
+            for entry in &entries {
+                writeln!(out, "\t\t\t{},", entry)?;
+            }
+            ext_layouts.push((i, instr.opcode, offset, entries.len()));
+            offset += entries.len();
+        }
+    }
+    if offset == 0 {
+        writeln!(out, "\t\t\t{{ OperandKind::IdRef, Quantifier::ZeroOrAny }}, // no ext instruction sets")?;
+    }
+    writeln!(out, "\t\t}};")?;
+
+    writeln!(out, "\t\t// false for instructions of unknown sets, their operands are printed as ids")?;
+    writeln!(out, "\t\tinline bool extInstOperands(unsigned int _set, unsigned int _instruction, OperandLayoutSpan& _operands)\n\t\t{{")?;
+        writeln!(out, "\t\t\tswitch (_set)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for (i, _) in exts.iter().enumerate() {
+            writeln!(out, "\t\t\tcase {}u:", i)?;
+            writeln!(out, "\t\t\t\tswitch (_instruction)\n\t\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tdefault: break;")?;
+            for (_, opcode, offset, count) in ext_layouts.iter().filter(|(set, _, _, _)| *set == i) {
+                writeln!(out, "\t\t\t\tcase {}u: _operands = {{ ExtInstOperands + {}, {}u }}; return true;", opcode, offset, count)?;
+            }
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\tbreak;")?;
+        }
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn false;")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tbool printOperands(OperandLayoutSpan _operands, PrintCursor& _cursor, PrinterState& _state, Sink& _sink);\n")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tbool printOperand(OperandKind _kind, PrintCursor& _cursor, PrinterState& _state, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tif (_cursor.empty()) return false;")?;
+        writeln!(out, "\t\t\tconst OperandEncoding& encoding = GetOperandEncoding(_kind);")?;
+        writeln!(out, "\t\t\tif (encoding.encoding == WordEncoding::Composite)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tfor (unsigned short i = 0u; i < encoding.baseCount; ++i)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tif (i != 0u) _sink(\" \");")?;
+                writeln!(out, "\t\t\t\t\tif (!printOperand(encoding.bases[i], _cursor, _state, _sink)) return false;")?;
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\treturn true;")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tif (encoding.encoding == WordEncoding::String) return printString(_cursor, _sink);")?;
+        writeln!(out, "\t\t\tif (encoding.encoding == WordEncoding::ContextDependent)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\t// the width and signedness come from the result type")?;
+            writeln!(out, "\t\t\t\tconst unsigned int words = static_cast<unsigned int>(_cursor.end - _cursor.it);")?;
+            writeln!(out, "\t\t\t\tconst PrinterState::NumberType* type = numberType(_state, _cursor.resultType);")?;
+            writeln!(out, "\t\t\t\tif (type != nullptr && words == (type->width + 31u) / 32u)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tprintNumber(*type, _cursor.it[0] | (words == 2u ? static_cast<unsigned long long>(_cursor.it[1]) << 32u : 0u), _sink);")?;
+                writeln!(out, "\t\t\t\t\t_cursor.it = _cursor.end;")?;
+                writeln!(out, "\t\t\t\t\treturn true;")?;
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\t// unknown result type, one or two words are printed as unsigned integers")?;
+            writeln!(out, "\t\t\t\tif (words == 2u) printUnsigned(_cursor.it[0] | static_cast<unsigned long long>(_cursor.it[1]) << 32u, _sink);")?;
+            writeln!(out, "\t\t\t\telse for (unsigned int i = 0u; i < words; ++i) {{ if (i != 0u) _sink(\" \"); printUnsigned(_cursor.it[i], _sink); }}")?;
+            writeln!(out, "\t\t\t\t_cursor.it = _cursor.end;")?;
+            writeln!(out, "\t\t\t\treturn true;")?;
+        writeln!(out, "\t\t\t}}")?;
+
+        writeln!(out, "\t\t\tconst unsigned int word = *_cursor.it++;")?;
+        writeln!(out, "\t\t\tswitch (GetOperandCategory(_kind))\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tcase OperandCategory::Id:")?;
+            writeln!(out, "\t\t\t\t_sink(\"%\");")?;
+            writeln!(out, "\t\t\t\tprintUnsigned(word, _sink);")?;
+            writeln!(out, "\t\t\t\treturn true;")?;
+        writeln!(out, "\t\t\tcase OperandCategory::ValueEnum:\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tconst char* name = enumerantName(_kind, word);")?;
+            writeln!(out, "\t\t\t\tif (name != nullptr) _sink(name); else printUnsigned(word, _sink);")?;
+            writeln!(out, "\t\t\t\tconst OperandLayoutSpan parameters = enumerantParameters(_kind, word);")?;
+            writeln!(out, "\t\t\t\tif (parameters.count != 0u) _sink(\" \");")?;
+            writeln!(out, "\t\t\t\treturn printOperands(parameters, _cursor, _state, _sink);")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tcase OperandCategory::BitEnum:\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tif (word == 0u) {{ const char* name = enumerantName(_kind, 0u); _sink(name != nullptr ? name : \"None\"); return true; }}")?;
+            writeln!(out, "\t\t\t\tunsigned int unknown = 0u;")?;
+            writeln!(out, "\t\t\t\tbool first = true;")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int bits = word; bits != 0u; bits &= bits - 1u)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tconst unsigned int bit = bits & (~bits + 1u);")?;
+                writeln!(out, "\t\t\t\t\tconst char* name = enumerantName(_kind, bit);")?;
+                writeln!(out, "\t\t\t\t\tif (name == nullptr) {{ unknown |= bit; continue; }}")?;
+                writeln!(out, "\t\t\t\t\tif (!first) _sink(\"|\");")?;
+                writeln!(out, "\t\t\t\t\t_sink(name);")?;
+                writeln!(out, "\t\t\t\t\tfirst = false;")?;
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\tif (unknown != 0u) {{ if (!first) _sink(\"|\"); printHex(unknown, _sink); }}")?;
+            writeln!(out, "\t\t\t\t// parameters of all set bits follow in ascending bit order")?;
+            writeln!(out, "\t\t\t\tfor (unsigned int bits = word; bits != 0u; bits &= bits - 1u)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tconst OperandLayoutSpan parameters = enumerantParameters(_kind, bits & (~bits + 1u));")?;
+                writeln!(out, "\t\t\t\t\tif (parameters.count == 0u) continue;")?;
+                writeln!(out, "\t\t\t\t\t_sink(\" \");")?;
+                writeln!(out, "\t\t\t\t\tif (!printOperands(parameters, _cursor, _state, _sink)) return false;")?;
+            writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t\treturn true;")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        writeln!(out, "\t\t\t}}")?;
+
+        writeln!(out, "\t\t\tswitch (_kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tcase OperandKind::LiteralFloat: printFloat(word, 32u, _sink); return true;")?;
+        writeln!(out, "\t\t\tcase OperandKind::LiteralSpecConstantOpInteger:\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tconst char* name = opName(static_cast<Op>(word));")?;
+            writeln!(out, "\t\t\t\tif (name != nullptr) _sink(name + 2); else printUnsigned(word, _sink); // without the Op prefix")?;
+            writeln!(out, "\t\t\t\t// operands of the operation aren't part of the grammar, spec constant operations only take ids")?;
+            writeln!(out, "\t\t\t\tfor (; !_cursor.empty(); ++_cursor.it) {{ _sink(\" %\"); printUnsigned(*_cursor.it, _sink); }}")?;
+            writeln!(out, "\t\t\t\treturn true;")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tcase OperandKind::LiteralExtInstInteger:\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\t// the set id precedes the instruction number in OpExtInst")?;
+            writeln!(out, "\t\t\t\tunsigned int set = 0u;")?;
+            writeln!(out, "\t\t\t\twhile (set < ExtInstSetCount && _state.extInstSets[set] != _cursor.it[-2]) ++set;")?;
+            writeln!(out, "\t\t\t\tconst char* name = extInstName(set, word);")?;
+            writeln!(out, "\t\t\t\tif (name != nullptr) _sink(name); else printUnsigned(word, _sink);")?;
+            writeln!(out, "\t\t\t\tOperandLayoutSpan operands{{}};")?;
+            writeln!(out, "\t\t\t\tif (!extInstOperands(set, word, operands)) return true; // remaining IdRef operands of OpExtInst")?;
+            writeln!(out, "\t\t\t\tif (operands.count != 0u && !_cursor.empty()) _sink(\" \");")?;
+            writeln!(out, "\t\t\t\treturn printOperands(operands, _cursor, _state, _sink) && _cursor.empty();")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\tdefault: printUnsigned(word, _sink); return true;")?;
+        writeln!(out, "\t\t\t}}")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Sink>")?;
+    writeln!(out, "\t\tbool printOperands(OperandLayoutSpan _operands, PrintCursor& _cursor, PrinterState& _state, Sink& _sink)\n\t\t{{")?;
+        writeln!(out, "\t\t\tbool first = true;")?;
+        writeln!(out, "\t\t\tfor (const OperandLayout& operand : _operands)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tconst bool optional = operand.quantifier != Quantifier::One;")?;
+            writeln!(out, "\t\t\t\tdo\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\t\tif (optional && _cursor.empty()) break;")?;
+                writeln!(out, "\t\t\t\t\tif (!first) _sink(\" \");")?;
+                writeln!(out, "\t\t\t\t\tfirst = false;")?;
+                writeln!(out, "\t\t\t\t\tif (!printOperand(operand.kind, _cursor, _state, _sink)) return false;")?;
+            writeln!(out, "\t\t\t\t}} while (operand.quantifier == Quantifier::ZeroOrAny);")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn true;")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t}} // detail\n")?;
+
+    writeln!(out, "\t// prints the instruction starting at _pWords (the opcode and word count word) like `spirv-dis --raw-id --no-indent` without a line break,")?;
+    writeln!(out, "\t// _sink(const char*) is called for every piece of text. _state remembers OpExtInstImport ids to name OpExtInst instructions")?;
+    writeln!(out, "\t// and the OpTypeInt and OpTypeFloat ids to print OpConstant and OpSpecConstant values as signed, unsigned or float numbers.")?;
+    writeln!(out, "\t// Returns false if the words don't match the grammar.")?;
+    writeln!(out, "\ttemplate <class Sink>")?;
+    writeln!(out, "\tbool printInstruction(const unsigned int* _pWords, Sink& _sink, PrinterState& _state)\n\t{{")?;
+        writeln!(out, "\t\tusing namespace spv;")?;
+        writeln!(out, "\t\tconst unsigned int wordCount = _pWords[0] >> WordCountShift;")?;
+        writeln!(out, "\t\tconst Op opcode = static_cast<Op>(_pWords[0] & OpCodeMask);")?;
+        writeln!(out, "\t\tconst char* name = detail::opName(opcode);")?;
+        writeln!(out, "\t\tif (wordCount == 0u || name == nullptr) return false;\n")?;
+
+        writeln!(out, "\t\t// scalar types giving constants their width and signedness, floats with an FP encoding operand are not tracked")?;
+        writeln!(out, "\t\tif (((opcode == Op::OpTypeInt && wordCount == 4u) || (opcode == Op::OpTypeFloat && wordCount == 3u)) && _state.numberTypeCount < PrinterState::MaxNumberTypes)\n\t\t{{")?;
+            writeln!(out, "\t\t\tconst PrinterState::NumberKind kind = opcode == Op::OpTypeFloat ? PrinterState::NumberKind::Float : _pWords[3] != 0u ? PrinterState::NumberKind::Signed : PrinterState::NumberKind::Unsigned;")?;
+            writeln!(out, "\t\t\t_state.numberTypes[_state.numberTypeCount++] = {{ _pWords[1], _pWords[2], kind }};")?;
+        writeln!(out, "\t\t}}\n")?;
+
+        writeln!(out, "\t\tconst OperandLayoutSpan operands = GetOperandLayout(opcode);")?;
+        writeln!(out, "\t\tdetail::PrintCursor cursor{{ _pWords + 1, _pWords + wordCount }};")?;
+        writeln!(out, "\t\tconst InstructionInfo info = GetInfo(opcode);")?;
+        writeln!(out, "\t\tif (info.hasResultType) {{ if (cursor.empty()) return false; cursor.resultType = *cursor.it++; }}")?;
+        writeln!(out, "\t\tif (info.hasResult)\n\t\t{{")?;
+            writeln!(out, "\t\t\tif (cursor.empty()) return false;")?;
+            writeln!(out, "\t\t\tif (opcode == Op::OpExtInstImport)\n\t\t\t{{")?;
+                writeln!(out, "\t\t\t\tfor (unsigned int set = 0u; set < ExtInstSetCount; ++set)\n\t\t\t\t{{")?;
+                    writeln!(out, "\t\t\t\t\tconst char* setName = detail::ExtInstSetNames[set];")?;
+                    writeln!(out, "\t\t\t\t\tconst char* it = reinterpret_cast<const char*>(cursor.it + 1);")?;
+                    writeln!(out, "\t\t\t\t\tconst char* end = reinterpret_cast<const char*>(cursor.end);")?;
+                    writeln!(out, "\t\t\t\t\twhile (it != end && *setName != '\\0' && *it == *setName) {{ ++it; ++setName; }}")?;
+                    writeln!(out, "\t\t\t\t\tif (it != end && *it == '\\0' && *setName == '\\0') _state.extInstSets[set] = *cursor.it;")?;
+                writeln!(out, "\t\t\t\t}}")?;
+            writeln!(out, "\t\t\t}}")?;
+            writeln!(out, "\t\t\t_sink(\"%\");")?;
+            writeln!(out, "\t\t\tdetail::printUnsigned(*cursor.it++, _sink);")?;
+            writeln!(out, "\t\t\t_sink(\" = \");")?;
+        writeln!(out, "\t\t}}")?;
+        writeln!(out, "\t\t_sink(name);")?;
+        writeln!(out, "\t\tif (info.hasResultType) {{ _sink(\" %\"); detail::printUnsigned(cursor.resultType, _sink); }}\n")?;
+
+        writeln!(out, "\t\t// result type and result are the first operands of every instruction that has them")?;
+        writeln!(out, "\t\tconst unsigned int skip = (info.hasResultType ? 1u : 0u) + (info.hasResult ? 1u : 0u);")?;
+        writeln!(out, "\t\tconst OperandLayoutSpan rest{{ operands.operands + skip, operands.count - skip }};")?;
+        writeln!(out, "\t\tif (rest.count != 0u && !cursor.empty()) _sink(\" \");")?;
+        writeln!(out, "\t\treturn detail::printOperands(rest, cursor, _state, _sink) && cursor.empty();")?;
+    writeln!(out, "\t}}\n")?;
+
+    writeln!(out, "\t// single instruction without module context, OpExtInst instructions are printed with their instruction number and constants as unsigned integers")?;
+    writeln!(out, "\ttemplate <class Sink>")?;
+    writeln!(out, "\tbool printInstruction(const unsigned int* _pWords, Sink& _sink)\n\t{{")?;
+        writeln!(out, "\t\tPrinterState state;")?;
+        writeln!(out, "\t\treturn printInstruction(_pWords, _sink, state);")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "}} // spvgentwo")?;
+    Ok(())
+}
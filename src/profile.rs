@@ -0,0 +1,173 @@
+use std::collections::BTreeSet;
+
+use crate::Grammar;
+use crate::parse_version;
+
+/// Target environment the generated tables are restricted to, `None` means unrestricted.
+#[derive(Default, Clone)]
+pub struct Profile
+{
+    /// highest SPIR-V version in parse_version encoding, 1.3 -> 0x00010300
+    pub max_version: Option<u32>,
+    /// enabled capabilities, implicitly declared capabilities are added automatically
+    pub capabilities: Option<BTreeSet<String>>,
+    /// enabled SPV_* extensions
+    pub extensions: Option<BTreeSet<String>>,
+    /// vendors like KHR or EXT whose extensions, instructions and enumerants are kept, core ones are always kept
+    pub include_vendors: Option<BTreeSet<String>>,
+    /// vendors like NV or INTEL whose extensions, instructions and enumerants are dropped
+    pub exclude_vendors: Option<BTreeSet<String>>
+}
+
+// SPV_KHR_storage_buffer_storage_class -> KHR
+pub(crate) fn extension_vendor(ext: &str) -> Option<&str>
+{
+    ext.strip_prefix("SPV_").and_then(|e| e.split('_').next())
+}
+
+// vendors of all extensions mentioned in the grammars, used to recognize vendor suffixes
+fn known_vendors(grammars: &[&mut Grammar]) -> BTreeSet<String>
+{
+    let mut vendors = BTreeSet::new();
+    for grammar in grammars {
+        let instruction_exts = grammar.instructions.iter().flat_map(|i| i.extensions.iter().flatten());
+        let enumerant_exts = grammar.operand_kinds.iter().flatten().flat_map(|k| k.enumerants.iter().flatten()).flat_map(|e| e.extensions.iter().flatten());
+        vendors.extend(instruction_exts.chain(enumerant_exts).filter_map(|e| extension_vendor(e)).map(|v| v.to_string()));
+    }
+    vendors
+}
+
+// comma separated command line list: Shader,Matrix
+pub fn parse_list(arg: &str) -> BTreeSet<String>
+{
+    arg.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).map(|s| s.to_string()).collect()
+}
+
+impl Profile
+{
+    pub fn is_empty(&self) -> bool
+    {
+        self.max_version.is_none() && self.capabilities.is_none() && self.extensions.is_none() && self.include_vendors.is_none() && self.exclude_vendors.is_none()
+    }
+
+    // "1.3" -> Some(0x00010300)
+    pub fn parse_version(arg: &str) -> Option<u32>
+    {
+        match parse_version(&Some(arg.to_string())) { 0 => None, v => Some(v) }
+    }
+
+    fn vendor_enabled(&self, vendor: &str) -> bool
+    {
+        self.include_vendors.as_ref().is_none_or(|set| set.contains(vendor)) && !self.exclude_vendors.as_ref().is_some_and(|set| set.contains(vendor))
+    }
+
+    fn extension_enabled(&self, ext: &str) -> bool
+    {
+        self.extensions.as_ref().is_none_or(|set| set.contains(ext)) && extension_vendor(ext).is_none_or(|v| self.vendor_enabled(v))
+    }
+
+    // vendor suffix of an instruction or enumerant name like OpHitObjectTraceRayNV or RayQueryKHR. The tag has to start
+    // the trailing run of capitals, so INTEL's RND_CONV doesn't end in NV
+    fn name_enabled(&self, name: &str, vendors: &BTreeSet<String>) -> bool
+    {
+        let is_suffix = |v: &str| name.len() > v.len() && name.ends_with(v) && !name.as_bytes()[name.len() - v.len() - 1].is_ascii_uppercase();
+        let suffix = vendors.iter().filter(|v| is_suffix(v)).max_by_key(|v| v.len());
+        suffix.is_none_or(|v| self.vendor_enabled(v))
+    }
+
+    // core since version <= max_version or enabled through one of its extensions,
+    // "None" without extensions is only gated by its capabilities
+    fn version_ok(&self, version: &Option<String>, extensions: &Option<Vec<String>>) -> bool
+    {
+        let exts = extensions.as_deref().unwrap_or_default();
+        let via_extension = exts.iter().any(|e| self.extension_enabled(e));
+        match version.as_deref() {
+            None => true,
+            Some("None") => exts.is_empty() || via_extension,
+            Some(_) => parse_version(version) <= self.max_version.unwrap_or(u32::MAX) || via_extension
+        }
+    }
+
+    // capabilities usable in this profile: the enabled ones and everything they implicitly declare,
+    // restricted to capabilities available in the target version.
+    // Without the core grammar (Glsl.h alone) the enabled ones are taken as is, None means all.
+    fn available_capabilities(&self, grammars: &[&mut Grammar], vendors: &BTreeSet<String>) -> Option<BTreeSet<String>>
+    {
+        let mut implies: Vec<(&str, Vec<&str>, bool)> = Vec::new();
+        for grammar in grammars {
+            for kind in grammar.operand_kinds.iter().flatten().filter(|k| k.kind == "Capability") {
+                for en in kind.enumerants.iter().flatten() {
+                    let deps = en.capabilities.iter().flatten().map(|c| c.as_str()).collect();
+                    let ok = self.version_ok(&en.version, &en.extensions) && self.name_enabled(&en.enumerant, vendors);
+                    implies.push((en.enumerant.as_str(), deps, ok));
+                }
+            }
+        }
+
+        if implies.is_empty() {
+            return self.capabilities.clone();
+        }
+
+        let mut available: BTreeSet<String> = match &self.capabilities {
+            None => return Some(implies.iter().filter(|(_, _, ok)| *ok).map(|(cap, _, _)| cap.to_string()).collect()),
+            Some(enabled) => enabled.clone()
+        };
+
+        let mut pending: Vec<String> = available.iter().cloned().collect();
+        while let Some(cap) = pending.pop() {
+            for (_, deps, _) in implies.iter().filter(|(c, _, _)| *c == cap) {
+                for dep in deps {
+                    if available.insert(dep.to_string()) {
+                        pending.push(dep.to_string());
+                    }
+                }
+            }
+        }
+
+        available.retain(|cap| implies.iter().any(|(c, _, ok)| c == cap && *ok));
+        Some(available)
+    }
+
+    /// Drops all instructions and enumerants that are not available in this profile and removes
+    /// capabilities and extensions outside the profile from the ones that remain.
+    pub fn apply(&self, grammars: &mut [&mut Grammar])
+    {
+        if self.is_empty() { return; }
+
+        let vendors = known_vendors(grammars);
+        let capabilities = self.available_capabilities(grammars, &vendors);
+        let capability_enabled = |cap: &str| capabilities.as_ref().is_none_or(|set| set.contains(cap));
+        let caps_ok = |caps: &Option<Vec<String>>| caps.as_deref().unwrap_or_default().is_empty() || caps.iter().flatten().any(|c| capability_enabled(c));
+        let prune = |caps: &mut Option<Vec<String>>, exts: &mut Option<Vec<String>>| {
+            if let Some(list) = caps.as_mut() { list.retain(|c| capability_enabled(c)); }
+            if let Some(list) = exts.as_mut() { list.retain(|e| self.extension_enabled(e)); }
+            // backends treat an empty list like a missing one
+            if caps.as_ref().is_some_and(|c| c.is_empty()) { *caps = None; }
+            if exts.as_ref().is_some_and(|e| e.is_empty()) { *exts = None; }
+        };
+
+        for grammar in grammars.iter_mut() {
+            if let (Some(max), Some(major), Some(minor)) = (self.max_version, grammar.major_version, grammar.minor_version) {
+                if max < (major << 16 | minor << 8) {
+                    grammar.major_version = Some(max >> 16);
+                    grammar.minor_version = Some(max >> 8 & 0xff);
+                }
+            }
+
+            grammar.instructions.retain(|i| self.version_ok(&i.version, &i.extensions) && caps_ok(&i.capabilities) && self.name_enabled(&i.opname, &vendors));
+            for instr in &mut grammar.instructions {
+                prune(&mut instr.capabilities, &mut instr.extensions);
+            }
+
+            for kind in grammar.operand_kinds.iter_mut().flatten() {
+                let is_capability = kind.kind == "Capability";
+                if let Some(enumerants) = kind.enumerants.as_mut() {
+                    enumerants.retain(|e| if is_capability { capability_enabled(&e.enumerant) } else { self.version_ok(&e.version, &e.extensions) && caps_ok(&e.capabilities) && self.name_enabled(&e.enumerant, &vendors) });
+                    for en in enumerants.iter_mut() {
+                        prune(&mut en.capabilities, &mut en.extensions);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::enumerant_value;
+use crate::provenance;
+use crate::Source;
+
+// Dim enumerants like 1D are not valid identifiers, same renaming as in Spv.h
+fn enumerant_name(kind: &str, enumerant: &str) -> String
+{
+    if enumerant.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}{}", kind, enumerant)
+    } else {
+        enumerant.to_string()
+    }
+}
+
+// newtype with associated constants, unlike a Rust enum this allows aliased values and unknown words
+fn value_type(name: &str, values: &[(String, u32)], out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "\t#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]")?;
+    writeln!(out, "\tpub struct {}(pub u32);", name)?;
+    writeln!(out, "\timpl {} {{", name)?;
+    for (enumerant, value) in values {
+        writeln!(out, "\t\tpub const {}: {} = {}({});", enumerant, name, name, value)?;
+    }
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
+
+fn mask_ops(name: &str, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "\timpl {} {{", name)?;
+    writeln!(out, "\t\tpub const fn contains(self, other: {}) -> bool {{ self.0 & other.0 == other.0 }}", name)?;
+    writeln!(out, "\t}}")?;
+    for (op, func, sym) in &[("BitOr", "bitor", "|"), ("BitAnd", "bitand", "&"), ("BitXor", "bitxor", "^")] {
+        writeln!(out, "\timpl core::ops::{} for {} {{ type Output = {}; fn {}(self, rhs: {}) -> {} {{ {}(self.0 {} rhs.0) }} }}", op, name, name, func, name, name, name, sym)?;
+    }
+    writeln!(out, "\timpl core::ops::BitOrAssign for {} {{ fn bitor_assign(&mut self, rhs: {}) {{ self.0 |= rhs.0; }} }}", name, name)?;
+    Ok(())
+}
+
+fn module_begin(name: &str, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "#[allow(non_upper_case_globals, dead_code, clippy::all)]")?;
+    writeln!(out, "pub mod {} {{", name)?;
+    Ok(())
+}
+
+pub fn rust_defs(spv: &Grammar, exts: &[(String, Grammar)], sources: &[Source], out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    writeln!(out, "// Auto generated - do not modify")?;
+    for line in provenance(sources) {
+        writeln!(out, "// {}", line)?;
+    }
+
+    module_begin("spv", out)?;
+    writeln!(out, "\tpub const MAGIC_NUMBER: u32 = {};", spv.magic_number.as_deref().unwrap_or("0x07230203"))?;
+    writeln!(out, "\tpub const VERSION: u32 = {};", spv.major_version.unwrap_or_default() << 16 | spv.minor_version.unwrap_or_default() << 8)?;
+    writeln!(out, "\tpub const REVISION: u32 = {};", spv.revision)?;
+    writeln!(out, "\tpub const OP_CODE_MASK: u32 = 0xffff;")?;
+    writeln!(out, "\tpub const WORD_COUNT_SHIFT: u32 = 16;")?;
+
+    for kind in operand_kinds {
+        let enumerants = match &kind.enumerants { Some(e) => e, None => continue };
+        if kind.category == "BitEnum" {
+            let name = format!("{}Mask", kind.kind);
+            let values: Vec<(String, u32)> = enumerants.iter().map(|e| {
+                (if e.enumerant == "None" { "MaskNone".to_string() } else { e.enumerant.clone() }, enumerant_value(&e.value))
+            }).collect();
+            value_type(&name, &values, out)?;
+            mask_ops(&name, out)?;
+        } else {
+            let values: Vec<(String, u32)> = enumerants.iter().map(|e| (enumerant_name(&kind.kind, &e.enumerant), enumerant_value(&e.value))).collect();
+            value_type(&kind.kind, &values, out)?;
+        }
+    }
+
+    let ops: Vec<(String, u32)> = spv.instructions.iter().map(|i| (i.opname.clone(), i.opcode)).collect();
+    value_type("Op", &ops, out)?;
+    writeln!(out, "}}")?;
+
+    for (name, ext) in exts {
+        let module: String = name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase();
+        module_begin(&module, out)?;
+        writeln!(out, "\tpub const IMPORT_NAME: &str = \"{}\";", name)?;
+        writeln!(out, "\tpub const VERSION: u32 = {};", ext.version.unwrap_or_default())?;
+        writeln!(out, "\tpub const REVISION: u32 = {};", ext.revision)?;
+        let ops: Vec<(String, u32)> = ext.instructions.iter().map(|i| (i.opname.clone(), i.opcode)).collect();
+        value_type("Op", &ops, out)?;
+        writeln!(out, "}}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::Write;
+
+use serde_derive::Serialize;
+
+use crate::Grammar;
+use crate::Instruction;
+
+#[derive(Serialize, Default)]
+struct Histogram
+{
+    count: usize,
+    words: usize
+}
+
+#[derive(Serialize)]
+struct FunctionStats
+{
+    id: u32,
+    name: Option<String>,
+    instructions: usize,
+    words: usize
+}
+
+#[derive(Serialize, Default)]
+struct ModuleStats
+{
+    words: usize,
+    bound: u32,
+    instructions: usize,
+    opcodes: BTreeMap<String, Histogram>,
+    classes: BTreeMap<String, Histogram>,
+    functions: Vec<FunctionStats>,
+    capabilities: BTreeSet<String>,
+    extensions: BTreeSet<String>,
+    ext_inst_imports: BTreeSet<String>
+}
+
+fn invalid(path: &str, offset: usize, message: &str) -> io::Error
+{
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {} at word {}", path, message, offset))
+}
+
+// reads a SPIR-V binary, swapping the byte order if the magic number is reversed
+fn read_words(path: &str) -> io::Result<Vec<u32>>
+{
+    let bytes = fs::read(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path, e)))?;
+    if bytes.len() % 4 != 0 {
+        return Err(invalid(path, bytes.len() / 4, "truncated word"));
+    }
+
+    let mut words: Vec<u32> = bytes.chunks_exact(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect();
+    if words.first() == Some(&0x0302_2307) {
+        for w in &mut words { *w = w.swap_bytes(); }
+    }
+    if words.first() != Some(&0x0723_0203) {
+        return Err(invalid(path, 0, "not a SPIR-V module, bad magic number"));
+    }
+    if words.len() < 5 {
+        return Err(invalid(path, words.len(), "truncated header"));
+    }
+    Ok(words)
+}
+
+// decodes a null terminated LiteralString
+fn literal_string(words: &[u32]) -> String
+{
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).take_while(|b| *b != 0).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn opcode(instructions: &HashMap<u32, &Instruction>, opname: &str) -> u32
+{
+    instructions.values().find(|i| i.opname == opname).map(|i| i.opcode).unwrap_or(u32::MAX)
+}
+
+fn module_stats(spv: &Grammar, path: &str, words: &[u32]) -> io::Result<ModuleStats>
+{
+    // first entry wins for aliased opcodes
+    let mut instructions = HashMap::new();
+    for instr in &spv.instructions {
+        instructions.entry(instr.opcode).or_insert(instr);
+    }
+
+    let op_capability = opcode(&instructions, "OpCapability");
+    let op_extension = opcode(&instructions, "OpExtension");
+    let op_ext_inst_import = opcode(&instructions, "OpExtInstImport");
+    let op_name = opcode(&instructions, "OpName");
+    let op_function = opcode(&instructions, "OpFunction");
+    let op_function_end = opcode(&instructions, "OpFunctionEnd");
+
+    let mut capability_names = HashMap::new();
+    if let Some(kinds) = &spv.operand_kinds {
+        for kind in kinds.iter().filter(|k| k.kind == "Capability") {
+            for en in kind.enumerants.iter().flatten() {
+                if let Some(value) = en.value.as_u64() {
+                    capability_names.insert(value as u32, en.enumerant.clone());
+                }
+            }
+        }
+    }
+
+    let mut stats = ModuleStats { words: words.len(), bound: words[3], ..Default::default() };
+    let mut names = HashMap::new();
+    let mut function: Option<FunctionStats> = None;
+    // word offset of the open OpFunction
+    let mut function_offset = 0;
+
+    let mut offset = 5;
+    while offset < words.len() {
+        let word_count = (words[offset] >> 16) as usize;
+        let op = words[offset] & 0xffff;
+        if word_count == 0 {
+            return Err(invalid(path, offset, "word count 0"));
+        }
+        if offset + word_count > words.len() {
+            return Err(invalid(path, offset, &format!("truncated instruction of {} words", word_count)));
+        }
+        let instr = &words[offset..offset + word_count];
+        let instr_offset = offset;
+        offset += word_count;
+
+        let (opname, class) = match instructions.get(&op) {
+            Some(i) => (i.opname.clone(), i.class.clone().unwrap_or_else(|| "Unclassified".to_string())),
+            None => (format!("Unknown{}", op), "Unknown".to_string())
+        };
+
+        stats.instructions += 1;
+        let entry = stats.opcodes.entry(opname).or_default();
+        entry.count += 1;
+        entry.words += word_count;
+        let entry = stats.classes.entry(class).or_default();
+        entry.count += 1;
+        entry.words += word_count;
+
+        if op == op_capability && word_count > 1 {
+            stats.capabilities.insert(capability_names.get(&instr[1]).cloned().unwrap_or_else(|| instr[1].to_string()));
+        } else if op == op_extension && word_count > 1 {
+            stats.extensions.insert(literal_string(&instr[1..]));
+        } else if op == op_ext_inst_import && word_count > 2 {
+            stats.ext_inst_imports.insert(literal_string(&instr[2..]));
+        } else if op == op_name && word_count > 2 {
+            names.insert(instr[1], literal_string(&instr[2..]));
+        } else if op == op_function && word_count > 2 {
+            if function.is_some() {
+                return Err(invalid(path, instr_offset, "OpFunction before the OpFunctionEnd of the previous function"));
+            }
+            function = Some(FunctionStats { id: instr[2], name: None, instructions: 0, words: 0 });
+            function_offset = instr_offset;
+        }
+
+        if let Some(func) = function.as_mut() {
+            func.instructions += 1;
+            func.words += word_count;
+        }
+
+        if op == op_function_end {
+            if let Some(func) = function.take() {
+                stats.functions.push(func);
+            }
+        }
+    }
+
+    if function.is_some() {
+        return Err(invalid(path, function_offset, "function without OpFunctionEnd"));
+    }
+
+    for func in &mut stats.functions {
+        func.name = names.get(&func.id).cloned();
+    }
+
+    Ok(stats)
+}
+
+fn print_histogram(title: &str, histogram: &BTreeMap<String, Histogram>, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut sorted: Vec<(&String, &Histogram)> = histogram.iter().collect();
+    sorted.sort_by(|a, b| b.1.words.cmp(&a.1.words).then(a.0.cmp(b.0)));
+
+    writeln!(out, "{:<40} {:>10} {:>10}", title, "Count", "Words")?;
+    for (name, h) in sorted {
+        writeln!(out, "{:<40} {:>10} {:>10}", name, h.count, h.words)?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+fn print_table(stats: &ModuleStats, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "Words: {}, Bound: {}, Instructions: {}\n", stats.words, stats.bound, stats.instructions)?;
+
+    print_histogram("Opcode", &stats.opcodes, out)?;
+    print_histogram("Class", &stats.classes, out)?;
+
+    writeln!(out, "{:<40} {:>10} {:>10}", "Function", "Count", "Words")?;
+    for func in &stats.functions {
+        let name = match &func.name { Some(n) => format!("%{} {}", func.id, n), None => format!("%{}", func.id) };
+        writeln!(out, "{:<40} {:>10} {:>10}", name, func.instructions, func.words)?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "Capabilities: {}", stats.capabilities.iter().cloned().collect::<Vec<_>>().join(", "))?;
+    writeln!(out, "Extensions: {}", stats.extensions.iter().cloned().collect::<Vec<_>>().join(", "))?;
+    writeln!(out, "ExtInstImports: {}", stats.ext_inst_imports.iter().cloned().collect::<Vec<_>>().join(", "))?;
+    Ok(())
+}
+
+pub fn stats(spv: &Grammar, path: &str, json: bool, out: &mut dyn Write) -> io::Result<()>
+{
+    let words = read_words(path)?;
+    let stats = module_stats(spv, path, &words)?;
+
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stats).expect("stats should serialize"))?;
+    } else {
+        print_table(&stats, out)?;
+    }
+    Ok(())
+}
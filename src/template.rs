@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use minijinja::Environment;
+use minijinja::UndefinedBehavior;
+use minijinja::Value;
+use minijinja::context;
+
+use crate::Grammar;
+use crate::Source;
+use crate::json::grammar_model;
+use crate::provenance;
+
+// splits identifiers like OpTypeVoid, FPRoundingMode, GLSL.std.450 or Float16ImageAMD into words
+fn words(text: &str) -> Vec<String>
+{
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !word.is_empty() { words.push(std::mem::take(&mut word)); }
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase() || prev.is_ascii_digit() || (prev.is_ascii_uppercase() && next_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+
+    if !word.is_empty() { words.push(word); }
+    words
+}
+
+fn capitalize(word: &str) -> String
+{
+    let lower = word.to_ascii_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new()
+    }
+}
+
+fn snake_case(text: &str) -> String
+{
+    words(text).iter().map(|w| w.to_ascii_lowercase()).collect::<Vec<_>>().join("_")
+}
+
+fn screaming_snake_case(text: &str) -> String
+{
+    words(text).iter().map(|w| w.to_ascii_uppercase()).collect::<Vec<_>>().join("_")
+}
+
+pub(crate) fn pascal_case(text: &str) -> String
+{
+    words(text).iter().map(|w| capitalize(w)).collect()
+}
+
+fn camel_case(text: &str) -> String
+{
+    let words = words(text);
+    match words.split_first() {
+        Some((first, rest)) => first.to_ascii_lowercase() + &rest.iter().map(|w| capitalize(w)).collect::<String>(),
+        None => String::new()
+    }
+}
+
+// contents of a C/C++ string literal
+fn c_escape(text: &str) -> String
+{
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_ascii_control() => escaped.push_str(&format!("\\x{:02x}", c as u32)),
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+// renders a Jinja template against the normalized grammar model of --emit json
+pub fn render_template(spv: &Grammar, exts: &[(String, Grammar)], sources: &[Source], name: &str, template: &str, out: &mut dyn Write) -> io::Result<()>
+{
+    let grammar = grammar_model(spv, exts, sources);
+
+    let capabilities = grammar.operand_kinds.iter().find(|k| k.kind == "Capability").map(|k| &k.enumerants);
+
+    let mut extensions = BTreeSet::new();
+    for instr in spv.instructions.iter().chain(exts.iter().flat_map(|(_, ext)| ext.instructions.iter())) {
+        extensions.extend(instr.extensions.iter().flatten().cloned());
+    }
+    for kind in spv.operand_kinds.iter().flatten() {
+        for en in kind.enumerants.iter().flatten() {
+            extensions.extend(en.extensions.iter().flatten().cloned());
+        }
+    }
+
+    let ctx = context! {
+        ..Value::from_serialize(&grammar),
+        ..context! {
+            provenance => provenance(sources),
+            capabilities => Value::from_serialize(capabilities),
+            extensions => extensions
+        }
+    };
+
+    // code generation templates are easier to write when block tags don't leave empty lines behind
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.add_filter("snake_case", |s: &str| snake_case(s));
+    env.add_filter("screaming_snake_case", |s: &str| screaming_snake_case(s));
+    env.add_filter("pascal_case", |s: &str| pascal_case(s));
+    env.add_filter("camel_case", |s: &str| camel_case(s));
+    env.add_filter("c_escape", |s: &str| c_escape(s));
+
+    let rendered = env.render_named_str(name, template, ctx).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:#}", e)))?;
+    out.write_all(rendered.as_bytes())
+}
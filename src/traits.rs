@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::info::class_enumerant;
+
+// OpTraits.h: a traits specialization per opcode so templated passes can specialise per instruction at compile time,
+// Dispatch maps a runtime opcode to them
+pub fn op_traits(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "// OperandKind is only declared with SPVGENTWO_OPERAND_LAYOUT, include this header before any other include of Spv.h")?;
+    writeln!(out, "#ifndef SPVGENTWO_OPERAND_LAYOUT")?;
+    writeln!(out, "#define SPVGENTWO_OPERAND_LAYOUT")?;
+    writeln!(out, "#endif")?;
+    writeln!(out, "#include \"Spv.h\"\n")?;
+
+    writeln!(out, "namespace spvgentwo::spv\n{{")?;
+
+    // values has at least one element so the array can be declared for empty lists too
+    writeln!(out, "\ttemplate <class T, T... Values>")?;
+    writeln!(out, "\tstruct EnumList\n\t{{")?;
+    writeln!(out, "\t\tstatic constexpr unsigned int count = sizeof...(Values);")?;
+    writeln!(out, "\t\tstatic constexpr T values[count != 0u ? count : 1u] = {{ Values... }};")?;
+    writeln!(out, "\t\tstatic constexpr bool contains([[maybe_unused]] T value) {{ return ((Values == value) || ...); }}")?;
+    writeln!(out, "\t}};")?;
+
+    writeln!(out, "\t// opcodes not in the grammar, Dispatch passes OpTraits<Op::Max> for them")?;
+    writeln!(out, "\ttemplate <Op opcode>")?;
+    writeln!(out, "\tstruct OpTraits\n\t{{")?;
+    writeln!(out, "\t\tstatic constexpr Op op = opcode;")?;
+    writeln!(out, "\t\tstatic constexpr bool valid = false;")?;
+    writeln!(out, "\t\tstatic constexpr const char* name = nullptr;")?;
+    writeln!(out, "\t\tstatic constexpr bool hasResult = false;")?;
+    writeln!(out, "\t\tstatic constexpr bool hasResultType = false;")?;
+    writeln!(out, "\t\tstatic constexpr InstructionClass instructionClass = InstructionClass::None;")?;
+    writeln!(out, "\t\tstatic constexpr EnumList<OperandKind> operandKinds{{}};")?;
+    writeln!(out, "\t\tstatic constexpr EnumList<Capability> capabilities{{}};")?;
+    writeln!(out, "\t}};")?;
+
+    // aliases share their opcode, the first name is used like in GetInfo
+    let mut opcodes = BTreeSet::new();
+    let instructions: Vec<_> = spv.instructions.iter().filter(|i| opcodes.insert(i.opcode)).collect();
+
+    for instr in &instructions {
+        let operands = instr.operands.as_deref().unwrap_or_default();
+        let kinds: Vec<String> = operands.iter().map(|op| format!(", OperandKind::{}", op.kind)).collect();
+        let capabilities: Vec<String> = instr.capabilities.iter().flatten().map(|cap| format!(", Capability::{}", cap)).collect();
+
+        writeln!(out, "\ttemplate <>")?;
+        writeln!(out, "\tstruct OpTraits<Op::{}>\n\t{{", instr.opname)?;
+        writeln!(out, "\t\tstatic constexpr Op op = Op::{};", instr.opname)?;
+        writeln!(out, "\t\tstatic constexpr bool valid = true;")?;
+        writeln!(out, "\t\tstatic constexpr const char* name = \"{}\";", instr.opname)?;
+        writeln!(out, "\t\tstatic constexpr bool hasResult = {};", operands.iter().any(|op| op.kind == "IdResult"))?;
+        writeln!(out, "\t\tstatic constexpr bool hasResultType = {};", operands.iter().any(|op| op.kind == "IdResultType"))?;
+        writeln!(out, "\t\tstatic constexpr InstructionClass instructionClass = InstructionClass::{};", class_enumerant(instr))?;
+        writeln!(out, "\t\tstatic constexpr EnumList<OperandKind{}> operandKinds{{}};", kinds.concat())?;
+        writeln!(out, "\t\tstatic constexpr EnumList<Capability{}> capabilities{{}}; // any of them enables the instruction", capabilities.concat())?;
+        writeln!(out, "\t}};")?;
+    }
+
+    writeln!(out, "\t// calls func(OpTraits<opcode>{{}}), every call has to return the same type")?;
+    writeln!(out, "\ttemplate <class Func>")?;
+    writeln!(out, "\tconstexpr decltype(auto) Dispatch(Op opcode, Func&& func)\n\t{{")?;
+    writeln!(out, "\t\tswitch (opcode)\n\t\t{{")?;
+    for instr in &instructions {
+        writeln!(out, "\t\tcase Op::{0}: return func(OpTraits<Op::{0}>{{}});", instr.opname)?;
+    }
+    writeln!(out, "\t\tdefault: return func(OpTraits<Op::Max>{{}});")?;
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "}} // spvgentwo::spv")?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Enumerants;
+use crate::Grammar;
+use crate::enumerant_value;
+
+// enumerant names as spv_defs emits them, aliases share a value and only the first one becomes a case label
+fn unique_enumerants(kind: &str, category: &str, enumerants: &[Enumerants]) -> Vec<(String, u32)>
+{
+    let mut values = BTreeSet::new();
+    let mut unique = Vec::new();
+    for en in enumerants {
+        let value = enumerant_value(&en.value);
+        if !values.insert(value) { continue; }
+        let name = if kind == "Dim" && en.enumerant.len() == 2 {
+            format!("Dim{}", en.enumerant)
+        } else if category == "BitEnum" && en.enumerant == "None" {
+            "MaskNone".to_string()
+        } else {
+            en.enumerant.clone()
+        };
+        unique.push((name, value));
+    }
+    unique
+}
+
+// IsValid for Op and every ValueEnum, IsValidMask for every BitEnum and EnumerantCount<Enum> with the number of
+// distinct values, so words read from untrusted binaries can be checked before they are cast.
+pub fn validity_checks(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    writeln!(out, "\ttemplate <class Enum>")?;
+    writeln!(out, "\tinline constexpr unsigned int EnumerantCount = 0u;")?;
+
+    for op in spv.operand_kinds.iter().flatten() {
+        let enumerants = match op.enumerants.as_deref() { Some(v) => v, None => continue };
+        let unique = unique_enumerants(&op.kind, &op.category, enumerants);
+
+        if op.category == "BitEnum" {
+            let mask = format!("{}Mask", op.kind);
+            let bits = unique.iter().fold(0u32, |bits, (_, value)| bits | value);
+            writeln!(out, "\ttemplate <>")?;
+            writeln!(out, "\tinline constexpr unsigned int EnumerantCount<{}> = {}u;", mask, unique.len())?;
+            writeln!(out, "\tinline constexpr bool IsValidMask({} mask) {{ return (static_cast<unsigned>(mask) & ~0x{:x}u) == 0u; }}", mask, bits)?;
+        } else {
+            writeln!(out, "\ttemplate <>")?;
+            writeln!(out, "\tinline constexpr unsigned int EnumerantCount<{}> = {}u;", op.kind, unique.len())?;
+            writeln!(out, "\tinline constexpr bool IsValid({} value) {{", op.kind)?;
+            writeln!(out, "\t\tswitch (value) {{")?;
+            for (name, _) in &unique {
+                writeln!(out, "\t\tcase {}::{}:", op.kind, name)?;
+            }
+            writeln!(out, "\t\t\treturn true;")?;
+            writeln!(out, "\t\tdefault: return false;")?;
+            writeln!(out, "\t\t}}")?;
+            writeln!(out, "\t}}")?;
+        }
+    }
+
+    let mut opcodes = BTreeSet::new();
+    let instructions: Vec<_> = spv.instructions.iter().filter(|i| opcodes.insert(i.opcode)).collect();
+    writeln!(out, "\ttemplate <>")?;
+    writeln!(out, "\tinline constexpr unsigned int EnumerantCount<Op> = {}u;", instructions.len())?;
+    writeln!(out, "\t// HasResult and friends fall back to the common case for unknown opcodes, check IsValid first")?;
+    writeln!(out, "\tinline constexpr bool IsValid(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    for instr in instructions {
+        writeln!(out, "\t\tcase Op::{}:", instr.opname)?;
+    }
+    writeln!(out, "\t\t\treturn true;")?;
+    writeln!(out, "\t\tdefault: return false;")?;
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
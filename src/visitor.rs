@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::Parameter;
+use crate::display_name;
+use crate::enumerant_value;
+use crate::operand_name;
+use crate::unique_instructions;
+
+fn parameter_calls(params: &[Parameter]) -> String
+{
+    let calls: Vec<String> = params.iter().map(|p| format!("visitOperand(Grammar::OperandKind::{}, \"{}\", _cursor, _visitor)", p.kind, display_name(p.name.as_deref().unwrap_or_default()))).collect();
+    calls.join(" && ")
+}
+
+fn visit_call(kind: &str, name: &str) -> String
+{
+    format!("detail::visitOperand(Grammar::OperandKind::{}, \"{}\", cursor, _visitor)", kind, name)
+}
+
+pub fn operand_visitor(spv: &Grammar, out: &mut dyn Write) -> io::Result<()>
+{
+    let operand_kinds = spv.operand_kinds.as_deref().unwrap_or_default();
+
+    writeln!(out, "#pragma once\n")?;
+    writeln!(out, "#include \"Grammar.h\"\n")?;
+
+    writeln!(out, "namespace spvgentwo\n{{")?;
+    writeln!(out, "\tnamespace detail\n\t{{")?;
+
+    writeln!(out, "\t\tstruct OperandCursor\n\t\t{{")?;
+        writeln!(out, "\t\t\tconst unsigned int* it;")?;
+        writeln!(out, "\t\t\tconst unsigned int* end;")?;
+        writeln!(out, "\t\t\tbool empty() const {{ return it == end; }}")?;
+    writeln!(out, "\t\t}};")?;
+
+    // LiteralString length in words including the null terminator, 0 if it is not terminated
+    writeln!(out, "\t\tinline unsigned int literalStringLength(const OperandCursor& _cursor)\n\t\t{{")?;
+        writeln!(out, "\t\t\tfor (const unsigned int* it = _cursor.it; it != _cursor.end; ++it)\n\t\t\t{{")?;
+            writeln!(out, "\t\t\t\tif ((*it & 0xffu) == 0u || (*it & 0xff00u) == 0u || (*it & 0xff0000u) == 0u || (*it & 0xff000000u) == 0u)")?;
+            writeln!(out, "\t\t\t\t\treturn static_cast<unsigned int>(it - _cursor.it) + 1u;")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t\treturn 0u;")?;
+    writeln!(out, "\t\t}}")?;
+
+    writeln!(out, "\t\ttemplate <class Visitor>")?;
+    writeln!(out, "\t\tbool visitOperand(Grammar::OperandKind _kind, const char* _name, OperandCursor& _cursor, Visitor& _visitor)\n\t\t{{")?;
+        writeln!(out, "\t\t\tif (_cursor.empty()) return false;")?;
+        writeln!(out, "\t\t\tunsigned int wordCount = 1u;")?;
+        writeln!(out, "\t\t\tswitch (_kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for op in operand_kinds {
+            if let Some(bases) = &op.bases {
+                let calls: Vec<String> = bases.iter().map(|b| format!("visitOperand(Grammar::OperandKind::{}, _name, _cursor, _visitor)", b)).collect();
+                writeln!(out, "\t\t\tcase Grammar::OperandKind::{}: return {};", op.kind, calls.join(" && "))?;
+            }
+        }
+        writeln!(out, "\t\t\tcase Grammar::OperandKind::LiteralString: wordCount = literalStringLength(_cursor); if (wordCount == 0u) return false; break;")?;
+        writeln!(out, "\t\t\tcase Grammar::OperandKind::LiteralContextDependentNumber: wordCount = static_cast<unsigned int>(_cursor.end - _cursor.it); break; // width depends on the result type")?;
+        writeln!(out, "\t\t\t}}")?;
+
+        writeln!(out, "\t\t\tconst unsigned int* pWords = _cursor.it;")?;
+        writeln!(out, "\t\t\t_cursor.it += wordCount;")?;
+        writeln!(out, "\t\t\t_visitor(_kind, _name, pWords, wordCount);")?;
+
+        // enumerant parameters follow the enumerant (ValueEnum) or all set bits in ascending order (BitEnum)
+        writeln!(out, "\t\t\tswitch (_kind)\n\t\t\t{{")?;
+        writeln!(out, "\t\t\tdefault: break;")?;
+        for op in operand_kinds {
+            let mut parameters = BTreeMap::new();
+            for en in op.enumerants.iter().flatten() {
+                if let Some(params) = &en.parameters {
+                    parameters.entry(enumerant_value(&en.value)).or_insert(params);
+                }
+            }
+            if parameters.is_empty() { continue; }
+
+            writeln!(out, "\t\t\tcase Grammar::OperandKind::{}:", op.kind)?;
+            if op.category == "BitEnum" {
+                for (value, params) in &parameters {
+                    writeln!(out, "\t\t\t\tif ((*pWords & 0x{:x}u) != 0u && !({})) return false;", value, parameter_calls(params))?;
+                }
+                writeln!(out, "\t\t\t\tbreak;")?;
+            } else {
+                writeln!(out, "\t\t\t\tswitch (*pWords)\n\t\t\t\t{{")?;
+                writeln!(out, "\t\t\t\tdefault: break;")?;
+                for (value, params) in &parameters {
+                    writeln!(out, "\t\t\t\tcase {}u: return {};", value, parameter_calls(params))?;
+                }
+                writeln!(out, "\t\t\t\t}}")?;
+                writeln!(out, "\t\t\t\tbreak;")?;
+            }
+        }
+        writeln!(out, "\t\t\t}}")?;
+
+        writeln!(out, "\t\t\treturn true;")?;
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}} // detail\n")?;
+
+    writeln!(out, "\t// calls _visitor(Grammar::OperandKind _kind, const char* _name, const unsigned int* _pWords, unsigned int _wordCount) for every operand of _opcode")?;
+    writeln!(out, "\t// _pOperands points to the first word after the opcode, returns false if the words don't match the grammar")?;
+    writeln!(out, "\ttemplate <class Visitor>")?;
+    writeln!(out, "\tbool visitOperands(spv::Op _opcode, const unsigned int* _pOperands, unsigned int _operandWordCount, Visitor&& _visitor)\n\t{{")?;
+        writeln!(out, "\t\tdetail::OperandCursor cursor{{ _pOperands, _pOperands + _operandWordCount }};")?;
+        writeln!(out, "\t\tswitch (_opcode)\n\t\t{{")?;
+        writeln!(out, "\t\tdefault: return false; // unknown opcode")?;
+
+        for instr in unique_instructions(&spv.instructions).values() {
+            writeln!(out, "\t\tcase spv::Op::{}:", instr.opname)?;
+            for op in instr.operands.iter().flatten() {
+                let call = visit_call(&op.kind, &display_name(&operand_name(op)));
+                match op.quantifier.as_deref() {
+                    Some("?") => writeln!(out, "\t\t\tif (!cursor.empty() && !{}) return false;", call)?,
+                    Some("*") => writeln!(out, "\t\t\twhile (!cursor.empty()) {{ if (!{}) return false; }}", call)?,
+                    _ => writeln!(out, "\t\t\tif (!{}) return false;", call)?
+                }
+            }
+            writeln!(out, "\t\t\tbreak;")?;
+        }
+
+        writeln!(out, "\t\t}}")?;
+        writeln!(out, "\t\treturn cursor.empty();")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "}} // spvgentwo")?;
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::Write;
+
+use crate::Grammar;
+use crate::Instruction;
+use crate::OperandKinds;
+use crate::enumerant_value;
+
+/// Minimum and maximum number of words, `None` is unbounded
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WordRange
+{
+    pub min: u32,
+    pub max: Option<u32>
+}
+
+impl WordRange
+{
+    const ONE: WordRange = WordRange { min: 1, max: Some(1) };
+
+    fn add(self, other: WordRange) -> WordRange
+    {
+        WordRange { min: self.min + other.min, max: self.max.zip(other.max).map(|(a, b)| a + b) }
+    }
+
+    fn optional(self) -> WordRange
+    {
+        WordRange { min: 0, max: self.max }
+    }
+
+    fn any(self) -> WordRange
+    {
+        WordRange { min: 0, max: None }
+    }
+}
+
+// word layout of the Literal kinds, the grammar only describes it in their docs: (kind, WordEncoding of Spv.h, words).
+// LiteralInteger is one word except for OpSwitch cases of 64-bit selectors, which consumers handle by opcode.
+const LITERAL_LAYOUTS: &[(&str, &str, WordRange)] = &[
+    ("LiteralInteger", "Fixed", WordRange::ONE),
+    ("LiteralFloat", "Fixed", WordRange::ONE),
+    ("LiteralExtInstInteger", "Fixed", WordRange::ONE),
+    ("LiteralSpecConstantOpInteger", "Fixed", WordRange::ONE),
+    ("LiteralString", "String", WordRange { min: 1, max: None }),
+    ("LiteralContextDependentNumber", "ContextDependent", WordRange { min: 1, max: None })
+];
+
+// WordEncoding and word range of a Literal kind, kinds missing from LITERAL_LAYOUTS are an error instead of a guess
+pub(crate) fn literal_layout(kind: &str) -> io::Result<(&'static str, WordRange)>
+{
+    LITERAL_LAYOUTS.iter().find(|(k, _, _)| *k == kind).map(|(_, encoding, range)| (*encoding, *range))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("no word encoding for literal kind {}", kind)))
+}
+
+// kinds missing from the grammar (ext grammars without the core one), Id* and enums without parameters are one word
+fn literal_range(kind: &str) -> io::Result<WordRange>
+{
+    if kind.starts_with("Literal") { literal_layout(kind).map(|(_, range)| range) } else { Ok(WordRange::ONE) }
+}
+
+// words of operands following each other, like the bases of a composite or the parameters of an enumerant
+fn sum_ranges<'a>(mut kinds_of: impl Iterator<Item = &'a String>, kinds: &BTreeMap<&str, &OperandKinds>, ranges: &mut BTreeMap<String, WordRange>) -> io::Result<WordRange>
+{
+    kinds_of.try_fold(WordRange { min: 0, max: Some(0) }, |sum, k| Ok(sum.add(kind_range(k, kinds, ranges)?)))
+}
+
+fn kind_range(kind: &str, kinds: &BTreeMap<&str, &OperandKinds>, ranges: &mut BTreeMap<String, WordRange>) -> io::Result<WordRange>
+{
+    if let Some(range) = ranges.get(kind) {
+        return Ok(*range);
+    }
+
+    let op = match kinds.get(kind) { Some(op) => op, None => return literal_range(kind) };
+    // guards against parameters referring back to their own kind
+    ranges.insert(kind.to_string(), WordRange { min: 1, max: None });
+
+    let range = if let Some(bases) = &op.bases {
+        sum_ranges(bases.iter(), kinds, ranges)?
+    } else if let Some(enumerants) = &op.enumerants {
+        // aliases share a value and are only counted once
+        let mut values = BTreeSet::new();
+        let mut params = Vec::new();
+        for en in enumerants {
+            if !values.insert(enumerant_value(&en.value)) { continue; }
+            params.push(sum_ranges(en.parameters.iter().flatten().map(|p| &p.kind), kinds, ranges)?);
+        }
+
+        if op.category == "BitEnum" {
+            // no bit set up to every bit with all of its parameters
+            params.iter().fold(WordRange::ONE, |sum, p| WordRange { min: 1, max: sum.add(*p).max })
+        } else {
+            let min = params.iter().map(|p| p.min).min().unwrap_or_default();
+            let max = params.iter().try_fold(0, |max, p| p.max.map(|m| max.max(m)));
+            WordRange::ONE.add(WordRange { min, max })
+        }
+    } else if op.category == "Literal" {
+        literal_layout(kind)?.1
+    } else {
+        WordRange::ONE
+    };
+
+    ranges.insert(kind.to_string(), range);
+    Ok(range)
+}
+
+/// Word range of every operand kind of the grammar, an empty grammar still knows the literal and id kinds.
+/// Literal kinds without a known layout are an error.
+pub fn kind_ranges(spv: &Grammar) -> io::Result<BTreeMap<String, WordRange>>
+{
+    let kinds: BTreeMap<&str, &OperandKinds> = spv.operand_kinds.iter().flatten().map(|k| (k.kind.as_str(), k)).collect();
+    let mut ranges = BTreeMap::new();
+    for kind in kinds.keys() {
+        kind_range(kind, &kinds, &mut ranges)?;
+    }
+    Ok(ranges)
+}
+
+/// Words taken by the operands of an instruction, without the opcode word
+pub fn operand_range(instr: &Instruction, ranges: &BTreeMap<String, WordRange>) -> io::Result<WordRange>
+{
+    instr.operands.iter().flatten().try_fold(WordRange { min: 0, max: Some(0) }, |sum, op| {
+        let range = match ranges.get(&op.kind) { Some(range) => *range, None => literal_range(&op.kind)? };
+        Ok(sum.add(match op.quantifier.as_deref() { Some("?") => range.optional(), Some("*") => range.any(), _ => range }))
+    })
+}
+
+// MinWordCount / MaxWordCount switches, header_words is added to every instruction
+pub fn word_count_functions(instructions: &[Instruction], ranges: &BTreeMap<String, WordRange>, header_words: u32, out: &mut dyn Write) -> io::Result<()>
+{
+    let mut opcodes = BTreeSet::new();
+    let mut counts = Vec::new();
+    for instr in instructions {
+        if opcodes.insert(instr.opcode) {
+            counts.push((instr.opname.as_str(), WordRange { min: header_words, max: Some(header_words) }.add(operand_range(instr, ranges)?)));
+        }
+    }
+
+    writeln!(out, "\tstatic constexpr unsigned int UnboundedWordCount = 0xffffffff;")?;
+
+    writeln!(out, "\tinline constexpr unsigned int MinWordCount(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return {}u; // unknown opcode", header_words)?;
+    for (opname, range) in &counts {
+        writeln!(out, "\t\tcase Op::{}: return {}u;", opname, range.min)?;
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+
+    writeln!(out, "\tinline constexpr unsigned int MaxWordCount(Op opcode) {{")?;
+    writeln!(out, "\t\tswitch (opcode) {{")?;
+    writeln!(out, "\t\tdefault: return UnboundedWordCount; // unknown opcode")?;
+    for (opname, range) in &counts {
+        match range.max {
+            Some(max) => writeln!(out, "\t\tcase Op::{}: return {}u;", opname, max)?,
+            None => writeln!(out, "\t\tcase Op::{}: return UnboundedWordCount;", opname)?
+        }
+    }
+    writeln!(out, "\t\t}}")?;
+    writeln!(out, "\t}}")?;
+    Ok(())
+}
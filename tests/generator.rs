@@ -0,0 +1,231 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// the checked in snapshot must compile as Rust
+include!("../generated/spirv.rs");
+
+#[test]
+fn rust_defs_compile()
+{
+    assert_eq!(spv::MAGIC_NUMBER, 0x07230203);
+    assert_eq!(spv::Op::OpNop, spv::Op(0));
+    assert!((spv::ImageOperandsMask::Bias | spv::ImageOperandsMask::Lod).contains(spv::ImageOperandsMask::Lod));
+    assert_eq!(spv::Dim::Dim2D, spv::Dim(1));
+    assert_eq!(glslstd450::IMPORT_NAME, "GLSL.std.450");
+    assert_eq!(openclstd::IMPORT_NAME, "OpenCL.std");
+}
+
+// emitting through the library must produce the same files as the CLI
+#[test]
+fn generator_matches_snapshots()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-generator-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let generator = rustspvgen::Generator::new()
+        .core(root.join("spirv.core.grammar.json"))
+        .ext(root.join("extinst.glsl.std.450.grammar.json"))
+        .ext(root.join("extinst.opencl.std.100.grammar.json"))
+        .class_overrides(root.join("class_overrides.json"))
+        .control_flow_overrides(root.join("control_flow.json"));
+    generator.emit_rust(&out_dir).expect("emit_rust should succeed");
+    generator.emit_cpp(&out_dir).expect("emit_cpp should succeed");
+
+    // the generator lists all its grammars in the provenance header, the CLI snapshots only the ones passed
+    let body = |text: String| text.replace("\r\n", "\n").lines().skip_while(|l| l.starts_with("// ")).collect::<Vec<_>>().join("\n");
+    for file in &["spirv.rs", "Spv.h", "Grammar.h", "Glsl.h", "OpenCl.h", "Grammar.cpp"] {
+        let expected = body(fs::read_to_string(root.join("generated").join(file)).expect("snapshot should exist"));
+        let actual = body(fs::read_to_string(out_dir.join(file)).expect("file should be generated"));
+        assert!(expected == actual, "{} differs from the snapshot", file);
+    }
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+// a third instruction set only needs its grammar, Grammar::Extension and the Hash64 keys follow the supplied order
+#[test]
+fn additional_ext_grammar()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-ext-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let printf = out_dir.join("extinst.nonsemantic.debugprintf.grammar.json");
+    fs::write(&printf, r#"{ "copyright" : [], "version" : 100, "revision" : 1, "instructions" : [ { "opname" : "DebugPrintf", "opcode" : 1,
+        "operands" : [ { "kind" : "IdRef", "name" : "'Format'" }, { "kind" : "IdRef", "quantifier" : "*" } ] } ] }"#).expect("grammar should be writable");
+
+    rustspvgen::Generator::new()
+        .core(root.join("spirv.core.grammar.json"))
+        .ext(root.join("extinst.glsl.std.450.grammar.json"))
+        .ext(root.join("extinst.opencl.std.100.grammar.json"))
+        .ext(&printf)
+        .emit_cpp(&out_dir)
+        .expect("emit_cpp should succeed");
+
+    let header = fs::read_to_string(out_dir.join("Grammar.h")).expect("Grammar.h should be generated");
+    assert!(header.contains("NonSemanticDebugPrintf = 3, // NonSemantic.DebugPrintf"));
+
+    let cpp = fs::read_to_string(out_dir.join("Grammar.cpp")).expect("Grammar.cpp should be generated");
+    assert!(cpp.contains("Hash64(1u, 3u), Instruction{\"DebugPrintf\""));
+    assert!(cpp.contains("if (equals(\"NonSemantic.DebugPrintf\")) return Extension::NonSemanticDebugPrintf;"));
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+const DEBUGINFO: &str = r#"{ "copyright" : [], "version" : 100, "revision" : 1,
+        "instructions" : [ { "opname" : "DebugTypeBasic", "opcode" : 2, "operands" : [ { "kind" : "IdRef", "name" : "'Name'" },
+            { "kind" : "DebugBaseTypeAttributeEncoding", "name" : "'Encoding'" }, { "kind" : "DebugInfoFlags", "quantifier" : "?" } ] } ],
+        "operand_kinds" : [
+            { "category" : "BitEnum", "kind" : "DebugInfoFlags", "enumerants" : [ { "enumerant" : "FlagIsProtected", "value" : "0x01" },
+                { "enumerant" : "FlagIsPrivate", "value" : "0x02", "parameters" : [ { "kind" : "LiteralInteger" } ] } ] },
+            { "category" : "ValueEnum", "kind" : "DebugBaseTypeAttributeEncoding", "enumerants" : [ { "enumerant" : "Unspecified", "value" : 0 } ] } ] }"#;
+
+// debuginfo style sets define their own operand kinds, Grammar.h numbers them after the core kinds
+#[test]
+fn ext_grammar_with_operand_kinds()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-ext-kinds-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let debuginfo = out_dir.join("extinst.debuginfo.grammar.json");
+    fs::write(&debuginfo, DEBUGINFO).expect("grammar should be writable");
+
+    rustspvgen::Generator::new()
+        .core(root.join("spirv.core.grammar.json"))
+        .ext(&debuginfo)
+        .emit_cpp(&out_dir)
+        .expect("emit_cpp should succeed");
+
+    let core_kinds = rustspvgen::load_grammar(root.join("spirv.core.grammar.json"), &mut Vec::new()).unwrap().operand_kinds.unwrap().len();
+    let header = fs::read_to_string(out_dir.join("Grammar.h")).expect("Grammar.h should be generated");
+    assert!(header.contains(&format!("\t\t\tDebugInfoFlags={}u,\n\t\t\tDebugBaseTypeAttributeEncoding={}u,\n", core_kinds, core_kinds + 1)));
+
+    let cpp = fs::read_to_string(out_dir.join("Grammar.cpp")).expect("Grammar.cpp should be generated");
+    assert!(cpp.contains("instr.operands.emplace_back(OperandKind::DebugInfoFlags, OperandCategory::BitEnum, \"\", Quantifier::ZeroOrOne);"));
+    assert!(cpp.contains(&format!("m_operandNames.emplaceUnique(Hash64({}u, 0x02u), \"FlagIsPrivate\");", core_kinds)));
+    assert!(cpp.contains(&format!("m_operandParameters.emplaceUnique(Hash64({}u, 0x02), _pAllocator)", core_kinds)));
+    assert!(cpp.contains("case OperandKind::DebugInfoFlags: return true;"));
+
+    // a kind no grammar defines is an error instead of a panic
+    let mut ext = rustspvgen::load_grammar(&debuginfo, &mut Vec::new()).unwrap();
+    ext.operand_kinds = None;
+    let spv = rustspvgen::load_grammar(root.join("spirv.core.grammar.json"), &mut Vec::new()).unwrap();
+    let err = rustspvgen::grammar_cpp(&spv, &[("DebugInfo".to_string(), ext)], &mut Vec::new()).unwrap_err();
+    assert_eq!(err.to_string(), "unknown operand kind DebugBaseTypeAttributeEncoding");
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+// ext operand kinds are part of the JSON model, versions and categories that don't exist are null
+#[test]
+fn json_ext_operand_kinds()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let spv = rustspvgen::load_grammar(root.join("spirv.core.grammar.json"), &mut Vec::new()).unwrap();
+    let mut ext: rustspvgen::Grammar = serde_json::from_str(DEBUGINFO).unwrap();
+    ext.instructions[0].operands.as_mut().unwrap().push(serde_json::from_str(r#"{ "kind" : "UndefinedKind" }"#).unwrap());
+
+    let mut out = Vec::new();
+    rustspvgen::json::emit_json(&spv, &[("DebugInfo".to_string(), ext)], &[], &mut out).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+    let set = &json["ext_inst_sets"]["DebugInfo"];
+    assert_eq!(set["operand_kinds"][0]["kind"], "DebugInfoFlags");
+    assert_eq!(set["operand_kinds"][0]["enumerants"][1]["version"], serde_json::Value::Null);
+    let operands = &set["instructions"][0]["operands"];
+    assert_eq!(operands[1]["category"], "ValueEnum");
+    assert_eq!(operands[3]["category"], serde_json::Value::Null);
+
+    let instructions = json["instructions"].as_array().unwrap();
+    let version = |opname: &str| instructions.iter().find(|i| i["opname"] == opname).unwrap()["version"].clone();
+    assert_eq!(version("OpNop"), serde_json::json!(0x10000));
+    assert_eq!(version("OpTraceRayKHR"), serde_json::Value::Null);
+}
+
+// a missing or broken grammar is an error of the emit, not a panic in build.rs
+#[test]
+fn generator_errors()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-errors-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let err = rustspvgen::Generator::new().core(out_dir.join("spirv.core.grammar.json")).emit_rust(&out_dir).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+
+    let broken = out_dir.join("extinst.broken.grammar.json");
+    fs::write(&broken, "{ \"instructions\" : [").expect("grammar should be writable");
+    let err = rustspvgen::Generator::new().core(root.join("spirv.core.grammar.json")).ext(&broken).emit_cpp(&out_dir).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.to_string().starts_with(&broken.display().to_string()));
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+// the grammars are loaded and the lock file written once, however many files are emitted
+#[test]
+fn generator_loads_once()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-once-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+    let lock = out_dir.join("spvgentwo.lock.json");
+
+    let generator = rustspvgen::Generator::new().core(root.join("spirv.core.grammar.json")).lock(&lock);
+    generator.emit_rust(&out_dir).expect("emit_rust should succeed");
+    assert!(lock.exists());
+    fs::remove_file(&lock).expect("lock should be removable");
+    generator.emit_cpp(&out_dir).expect("emit_cpp should succeed");
+    assert!(!lock.exists());
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+// a literal kind without a known word layout must fail instead of being emitted or counted as one fixed word
+#[test]
+fn unknown_literal_encoding()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-literal-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let core = fs::read_to_string(root.join("spirv.core.grammar.json")).expect("grammar should exist");
+    let core = core.replacen("\"operand_kinds\" : [", "\"operand_kinds\" : [ { \"category\" : \"Literal\", \"kind\" : \"LiteralHalf\" },", 1);
+    fs::write(out_dir.join("spirv.core.grammar.json"), core).expect("grammar should be writable");
+
+    let err = rustspvgen::Generator::new().core(out_dir.join("spirv.core.grammar.json")).lock(out_dir.join("spvgentwo.lock.json")).emit_cpp(&out_dir).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("LiteralHalf"), "{}", err);
+
+    // MinWordCount and MaxWordCount share the table with the operand encodings
+    let grammar = rustspvgen::load_grammar(out_dir.join("spirv.core.grammar.json"), &mut Vec::new()).expect("grammar should load");
+    let err = rustspvgen::words::kind_ranges(&grammar).unwrap_err();
+    assert_eq!(err.to_string(), "no word encoding for literal kind LiteralHalf");
+
+    fs::remove_dir_all(&out_dir).ok();
+}
+
+// successor overrides also apply to instructions that are not Control-Flow or have a result
+#[test]
+fn control_flow_override_with_result()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = env::temp_dir().join(format!("rustspvgen-successors-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).expect("temp dir should be writable");
+
+    let overrides = out_dir.join("control_flow.json");
+    fs::write(&overrides, r#"{ "successors": { "OpFunctionCall": [2] } }"#).expect("overrides should be writable");
+    rustspvgen::Generator::new()
+        .core(root.join("spirv.core.grammar.json"))
+        .control_flow_overrides(&overrides)
+        .lock(out_dir.join("spvgentwo.lock.json"))
+        .emit_cpp(&out_dir).expect("emit_cpp should succeed");
+
+    let spv = fs::read_to_string(out_dir.join("Spv.h")).expect("Spv.h should be generated");
+    assert!(spv.contains("\t\tcase Op::OpFunctionCall: return { 1u, { 2u }, NoSuccessorPairs };"));
+
+    fs::remove_dir_all(&out_dir).ok();
+}
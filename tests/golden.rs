@@ -0,0 +1,110 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const CORE: &str = "spirv.core.grammar.json";
+const GLSL: &str = "extinst.glsl.std.450.grammar.json";
+const OPENCL: &str = "extinst.opencl.std.100.grammar.json";
+
+// runs rustspvgen on the checked in grammars and compares stdout with generated/<snapshot>,
+// run with BLESS=1 to overwrite the snapshots after an intentional change
+fn golden(args: &[&str], snapshot: &str)
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_rustspvgen")).args(args).current_dir(root).output().expect("rustspvgen should run");
+    assert!(output.status.success(), "rustspvgen {:?} failed: {}", args, String::from_utf8_lossy(&output.stderr));
+
+    let actual = String::from_utf8(output.stdout).expect("output should be utf8");
+    let path = root.join("generated").join(snapshot);
+
+    if env::var_os("BLESS").is_some() {
+        fs::write(&path, &actual).expect("snapshot should be writable");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_default().replace("\r\n", "\n");
+    if expected != actual {
+        let line = expected.lines().zip(actual.lines()).position(|(e, a)| e != a).unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+        panic!("{} differs from the snapshot at line {}:\n  expected: {:?}\n  actual:   {:?}\nrun `BLESS=1 cargo test` if the change is intentional",
+            snapshot, line + 1, expected.lines().nth(line).unwrap_or_default(), actual.lines().nth(line).unwrap_or_default());
+    }
+}
+
+#[test]
+fn spv_defs() { golden(&[CORE, "--class-overrides", "class_overrides.json", "--control-flow-overrides", "control_flow.json", "--defs"], "Spv.h"); }
+
+#[test]
+fn glsl_defs() { golden(&[GLSL, "--defs"], "Glsl.h"); }
+
+#[test]
+fn opencl_defs() { golden(&[OPENCL, "--defs"], "OpenCl.h"); }
+
+#[test]
+fn grammar_header() { golden(&[CORE, GLSL, OPENCL, "--header"], "Grammar.h"); }
+
+#[test]
+fn grammar_cpp() { golden(&[CORE, GLSL, OPENCL, "--cpp"], "Grammar.cpp"); }
+
+#[test]
+fn operand_visitor() { golden(&[CORE, "--visitor"], "OperandVisitor.h"); }
+
+// visitor callbacks get the operand names without the quotes of the grammar, like the other generated tables
+#[test]
+fn operand_visitor_names()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_rustspvgen")).args([CORE, "--visitor"]).current_dir(root).output().expect("rustspvgen should run");
+    let header = String::from_utf8(output.stdout).expect("output should be utf8");
+    assert!(header.contains("visitOperand(Grammar::OperandKind::IdRef, \"Base\", cursor, _visitor)"));
+    assert!(!header.contains("\"'"), "quoted operand name in OperandVisitor.h");
+}
+
+#[test]
+fn instruction_builder() { golden(&[CORE, "--builder"], "InstructionBuilder.h"); }
+
+#[test]
+fn instruction_printer() { golden(&[CORE, GLSL, OPENCL, "--printer"], "InstructionPrinter.h"); }
+
+#[test]
+fn op_traits() { golden(&[CORE, "--class-overrides", "class_overrides.json", "--traits"], "OpTraits.h"); }
+
+#[test]
+fn markdown() { golden(&[CORE, GLSL, OPENCL, "--markdown"], "SpirvReference.md"); }
+
+// @exclude is no section and markup characters of the grammar strings are escaped
+#[test]
+fn markdown_escaping()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_rustspvgen")).args([CORE, "--markdown"]).current_dir(root).output().expect("rustspvgen should run");
+    let markdown = String::from_utf8(output.stdout).expect("output should be utf8");
+    assert!(!markdown.contains("@exclude"));
+    assert!(markdown.contains("Number of \\<\\<Invocation,invocations\\>\\>"));
+    assert!(markdown.contains("SPV\\_KHR\\_integer\\_dot\\_product"));
+}
+
+#[test]
+fn html() { golden(&[CORE, GLSL, OPENCL, "--html"], "SpirvReference.html"); }
+
+#[test]
+fn json() { golden(&[CORE, GLSL, OPENCL, "--emit", "json"], "Grammar.json"); }
+
+#[test]
+fn rust() { golden(&[CORE, GLSL, OPENCL, "--rust"], "spirv.rs"); }
+
+#[test]
+fn intrinsics_template() { golden(&[CORE, GLSL, OPENCL, "--template", "templates/Intrinsics.h.j2"], "Intrinsics.h"); }
+
+#[test]
+fn dictionary_template() { golden(&[CORE, GLSL, OPENCL, "--template", "templates/spirv.dict.j2"], "spirv.dict"); }
+
+// a missing template is reported with its path like a missing grammar, not a panic
+#[test]
+fn missing_template()
+{
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_rustspvgen")).args([CORE, "--template", "templates/missing.j2"]).current_dir(root).output().expect("rustspvgen should run");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("templates/missing.j2: "));
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rustspvgen::Grammar;
+use rustspvgen::grammar_header;
+use rustspvgen::load_grammar;
+use rustspvgen::lock::Lock;
+use rustspvgen::lock::numbering;
+use rustspvgen::spv_defs;
+
+fn core() -> Grammar
+{
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("spirv.core.grammar.json");
+    load_grammar(path, &mut Vec::new()).expect("core grammar should load")
+}
+
+fn numbers(entries: &[(&str, u32)]) -> BTreeMap<String, u32>
+{
+    entries.iter().map(|(name, number)| (name.to_string(), *number)).collect()
+}
+
+fn header(spv: &Grammar) -> String
+{
+    let mut out = Vec::new();
+    grammar_header(spv, &[], &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn without_lock_numbers_follow_the_grammar()
+{
+    let spv = core();
+    let numbers = numbering(&spv, &[]);
+    let kinds = spv.operand_kinds.as_deref().unwrap();
+    assert_eq!(numbers.operand_kinds[&kinds[0].kind], 0);
+    assert_eq!(numbers.operand_kinds[&kinds[kinds.len() - 1].kind], kinds.len() as u32 - 1);
+    assert_eq!(numbers.extensions.keys().next().map(|e| numbers.extensions[e]), Some(0));
+}
+
+#[test]
+fn locked_numbers_are_kept_and_new_ones_appended()
+{
+    let mut spv = core();
+    let kinds = spv.operand_kinds.as_deref().unwrap().len() as u32;
+    spv.lock = Some(Lock { operand_kinds: numbers(&[("ImageOperands", 1000), ("IdRef", 7)]), ..Default::default() });
+
+    let numbers = numbering(&spv, &[]);
+    assert_eq!(numbers.operand_kinds["ImageOperands"], 1000);
+    assert_eq!(numbers.operand_kinds["IdRef"], 7);
+    // ImageOperands is the first kind of the grammar, everything else follows the highest locked number
+    assert_eq!(numbers.operand_kinds.values().max(), Some(&(1000 + kinds - 2)));
+
+    let header = header(&spv);
+    assert!(header.contains("\t\t\tImageOperands=1000u,\n"));
+    assert!(header.contains("\t\t\tIdRef=7u,\n"));
+}
+
+#[test]
+fn removed_names_are_reported_and_stay_reserved()
+{
+    let mut spv = core();
+    let locked = Lock { operand_kinds: numbers(&[("ImageOperands", 0), ("RemovedKind", 1)]), extensions: numbers(&[("SPV_XYZ_removed", 0)]), ..Default::default() };
+    assert_eq!(locked.removed(&spv, &[]), vec!["operand kind RemovedKind".to_string(), "extension SPV_XYZ_removed".to_string()]);
+
+    // the updated lock written back still contains the removed names
+    let updated = locked.update(&spv, &[]);
+    assert_eq!(updated.operand_kinds["RemovedKind"], 1);
+    assert_eq!(updated.operand_kinds["FPFastMathMode"], 2);
+
+    spv.lock = Some(locked);
+    let numbers = numbering(&spv, &[]);
+    assert!(!numbers.operand_kinds.contains_key("RemovedKind"));
+    assert!(!numbers.operand_kinds.values().any(|n| *n == 1));
+
+    let mut out = Vec::new();
+    spv_defs(&spv, &mut out).unwrap();
+    let defs = String::from_utf8(out).unwrap();
+    assert!(defs.contains("\t\tnullptr, // removed from the grammar\n"));
+    assert!(defs.contains("OperandCategory{}, // 1 unused"));
+    assert!(!defs.contains("SPV_XYZ_removed"));
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use rustspvgen::Grammar;
+use rustspvgen::Profile;
+use rustspvgen::load_grammar;
+use rustspvgen::profile::parse_list;
+
+fn core(profile: &Profile) -> Grammar
+{
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("spirv.core.grammar.json");
+    let mut spv = load_grammar(path, &mut Vec::new()).expect("core grammar should load");
+    profile.apply(&mut [&mut spv]);
+    spv
+}
+
+fn has_instruction(spv: &Grammar, opname: &str) -> bool
+{
+    spv.instructions.iter().any(|i| i.opname == opname)
+}
+
+fn enumerants(spv: &Grammar, kind: &str) -> Vec<String>
+{
+    spv.operand_kinds.iter().flatten().filter(|k| k.kind == kind).flat_map(|k| k.enumerants.iter().flatten()).map(|e| e.enumerant.clone()).collect()
+}
+
+#[test]
+fn empty_profile_keeps_everything()
+{
+    let all = core(&Profile::default());
+    assert_eq!(all.instructions.len(), 721);
+    assert!(has_instruction(&all, "OpHitObjectTraceRayNV"));
+}
+
+#[test]
+fn max_version()
+{
+    let spv = core(&Profile { max_version: Profile::parse_version("1.3"), ..Default::default() });
+    assert_eq!((spv.major_version, spv.minor_version), (Some(1), Some(3)));
+    assert!(has_instruction(&spv, "OpGroupNonUniformBallot"));
+    // 1.4 core without an extension
+    assert!(!has_instruction(&spv, "OpCopyLogical"));
+    // 1.6 core but also available through SPV_KHR_terminate_invocation
+    assert!(has_instruction(&spv, "OpTerminateInvocation"));
+}
+
+#[test]
+fn extensions()
+{
+    let spv = core(&Profile { max_version: Profile::parse_version("1.3"), extensions: Some(parse_list("SPV_KHR_storage_buffer_storage_class")), ..Default::default() });
+    assert!(!has_instruction(&spv, "OpTerminateInvocation"));
+    assert!(enumerants(&spv, "StorageClass").contains(&"StorageBuffer".to_string()));
+    assert!(!enumerants(&spv, "StorageClass").contains(&"PhysicalStorageBuffer".to_string()));
+    assert!(spv.instructions.iter().flat_map(|i| i.extensions.iter().flatten()).all(|e| e == "SPV_KHR_storage_buffer_storage_class"));
+}
+
+#[test]
+fn capabilities_include_implicit_ones()
+{
+    let spv = core(&Profile { capabilities: Some(parse_list("Shader")), ..Default::default() });
+    let caps = enumerants(&spv, "Capability");
+    // Shader implicitly declares Matrix
+    assert!(caps.contains(&"Matrix".to_string()));
+    assert!(!caps.contains(&"Kernel".to_string()));
+    assert!(has_instruction(&spv, "OpTranspose"));
+    assert!(!has_instruction(&spv, "OpEnqueueKernel"));
+    assert!(!has_instruction(&spv, "OpTypeCooperativeMatrixKHR"));
+}
+
+#[test]
+fn exclude_vendors()
+{
+    let spv = core(&Profile { exclude_vendors: Some(parse_list("NV,INTEL")), ..Default::default() });
+    assert!(!has_instruction(&spv, "OpHitObjectTraceRayNV"));
+    assert!(!has_instruction(&spv, "OpSubgroupShuffleINTEL"));
+    assert!(has_instruction(&spv, "OpTraceRayKHR"));
+    assert!(enumerants(&spv, "ExecutionModel").contains(&"RayGenerationKHR".to_string()));
+    assert!(!enumerants(&spv, "ExecutionModel").contains(&"RayGenerationNV".to_string()));
+    assert!(!enumerants(&spv, "Capability").contains(&"MeshShadingNV".to_string()));
+    let exts = spv.instructions.iter().flat_map(|i| i.extensions.iter().flatten());
+    assert!(exts.clone().all(|e| !e.starts_with("SPV_NV_") && !e.starts_with("SPV_INTEL_")));
+    assert!(exts.clone().any(|e| e == "SPV_KHR_ray_tracing"));
+}
+
+#[test]
+fn include_vendors()
+{
+    let spv = core(&Profile { include_vendors: Some(parse_list("KHR,EXT")), ..Default::default() });
+    assert!(has_instruction(&spv, "OpNop"));
+    assert!(has_instruction(&spv, "OpTraceRayKHR"));
+    assert!(has_instruction(&spv, "OpEmitMeshTasksEXT"));
+    assert!(!has_instruction(&spv, "OpHitObjectTraceRayNV"));
+    assert!(!has_instruction(&spv, "OpFinalizeNodePayloadsAMDX"));
+    // core since 1.3, also listed with SPV_KHR_storage_buffer_storage_class
+    assert!(enumerants(&spv, "StorageClass").contains(&"StorageBuffer".to_string()));
+}
+
+// a vendor tag only counts when it starts the trailing run of capitals
+#[test]
+fn vendor_suffix_boundary()
+{
+    let spv = core(&Profile { exclude_vendors: Some(parse_list("NV")), ..Default::default() });
+    // INTEL's QuantizationModes, not RND_CO + NV
+    assert!(enumerants(&spv, "QuantizationModes").contains(&"RND_CONV".to_string()));
+    assert!(!enumerants(&spv, "BuiltIn").contains(&"PrimitiveCountNV".to_string()));
+    // capitals before the tag, still dropped through SPV_NV_shader_sm_builtins
+    assert!(!enumerants(&spv, "BuiltIn").contains(&"WarpIDNV".to_string()));
+
+    let spv = core(&Profile { exclude_vendors: Some(parse_list("INTEL")), ..Default::default() });
+    assert!(!enumerants(&spv, "QuantizationModes").contains(&"RND_CONV".to_string()));
+    // GTINTEL isn't split at the tag but its ArbitraryPrecisionFloatingPointINTEL capability is dropped
+    assert!(!has_instruction(&spv, "OpArbitraryFloatGTINTEL"));
+}
@@ -0,0 +1,127 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use rustspvgen::Grammar;
+use rustspvgen::load_grammar;
+use rustspvgen::stats::stats;
+
+fn core() -> Grammar
+{
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("spirv.core.grammar.json");
+    load_grammar(path, &mut Vec::new()).expect("core grammar should load")
+}
+
+// null terminated and padded to whole words
+fn string(s: &str) -> Vec<u32>
+{
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.resize(s.len() / 4 * 4 + 4, 0);
+    bytes.chunks(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect()
+}
+
+fn instruction(opcode: u32, operands: &[u32]) -> Vec<u32>
+{
+    let mut words = vec![(operands.len() as u32 + 1) << 16 | opcode];
+    words.extend_from_slice(operands);
+    words
+}
+
+// a shader with an empty main function
+fn module() -> Vec<u32>
+{
+    let mut words = vec![0x0723_0203, 0x0001_0000, 0, 6, 0];
+    words.extend(instruction(17, &[1])); // OpCapability Shader
+    words.extend(instruction(11, &[[1].as_slice(), &string("GLSL.std.450")].concat())); // OpExtInstImport
+    words.extend(instruction(14, &[0, 1])); // OpMemoryModel Logical GLSL450
+    words.extend(instruction(5, &[[3].as_slice(), &string("main")].concat())); // OpName
+    words.extend(instruction(19, &[2])); // OpTypeVoid
+    words.extend(instruction(33, &[4, 2])); // OpTypeFunction
+    words.extend(instruction(54, &[2, 3, 0, 4])); // OpFunction
+    words.extend(instruction(248, &[5])); // OpLabel
+    words.extend(instruction(253, &[])); // OpReturn
+    words.extend(instruction(56, &[])); // OpFunctionEnd
+    words
+}
+
+fn run(name: &str, bytes: &[u8]) -> std::io::Result<serde_json::Value>
+{
+    let path = env::temp_dir().join(format!("rustspvgen-stats-{}-{}.spv", std::process::id(), name));
+    fs::write(&path, bytes).expect("module should be writable");
+    let mut out = Vec::new();
+    let result = stats(&core(), path.to_str().unwrap(), true, &mut out);
+    fs::remove_file(&path).ok();
+    result.map(|_| serde_json::from_slice(&out).expect("stats should be JSON"))
+}
+
+fn bytes(words: &[u32]) -> Vec<u8>
+{
+    words.iter().flat_map(|w| w.to_le_bytes()).collect()
+}
+
+#[test]
+fn module_stats()
+{
+    let stats = run("module", &bytes(&module())).expect("module should be valid");
+    assert_eq!(stats["words"], 34);
+    assert_eq!(stats["bound"], 6);
+    assert_eq!(stats["instructions"], 10);
+    assert_eq!(stats["opcodes"]["OpFunction"]["words"], 5);
+    assert_eq!(stats["capabilities"], serde_json::json!(["Shader"]));
+    assert_eq!(stats["ext_inst_imports"], serde_json::json!(["GLSL.std.450"]));
+    assert_eq!(stats["functions"], serde_json::json!([{ "id": 3, "name": "main", "instructions": 4, "words": 9 }]));
+
+    // big endian modules are swapped
+    let swapped: Vec<u8> = module().iter().flat_map(|w| w.to_be_bytes()).collect();
+    assert_eq!(run("swapped", &swapped).expect("module should be valid"), stats);
+}
+
+#[test]
+fn truncated_module()
+{
+    // OpFunction announces 5 words, only 3 are left
+    let words = module();
+    let err = run("truncated", &bytes(&words[..27])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().ends_with("truncated instruction of 5 words at word 25"), "{}", err);
+
+    let mut partial = bytes(&words);
+    partial.pop();
+    assert!(run("partial", &partial).unwrap_err().to_string().ends_with("truncated word at word 33"));
+
+    assert!(run("header", &bytes(&words[..3])).unwrap_err().to_string().ends_with("truncated header at word 3"));
+}
+
+#[test]
+fn bad_magic_and_word_count()
+{
+    let mut words = module();
+    words[0] = 0xdead_beef;
+    let err = run("magic", &bytes(&words)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().ends_with("bad magic number at word 0"));
+
+    let mut words = module();
+    words[5] &= 0xffff;
+    assert!(run("count", &bytes(&words)).unwrap_err().to_string().ends_with("word count 0 at word 5"));
+
+    assert_eq!(run("empty", &[]).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn unterminated_function_and_short_extension()
+{
+    // the module ends before the OpFunctionEnd of main
+    let words = module();
+    let err = run("unterminated", &bytes(&words[..33])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().ends_with("function without OpFunctionEnd at word 25"), "{}", err);
+
+    // a one word OpExtension has no name to record
+    let mut words = module();
+    words.splice(7..7, instruction(10, &[]));
+    let stats = run("extension", &bytes(&words)).expect("module should be valid");
+    assert_eq!(stats["extensions"], serde_json::json!([]));
+    assert_eq!(stats["opcodes"]["OpExtension"]["count"], 1);
+}